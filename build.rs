@@ -0,0 +1,29 @@
+// Build script — checks that the SvelteKit SPA has been built before a
+// `--features web` build embeds it.
+//
+// src/web/mod.rs embeds web/build/ with include_dir! at compile time. If the
+// directory is empty or missing index.html, the build still succeeds and the
+// resulting server answers every page with a 503. Failing here instead turns
+// that into an error at the point where it can be fixed.
+
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=web/build/index.html");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_WEB");
+
+    // Cargo sets CARGO_FEATURE_<NAME> for each enabled feature
+    if std::env::var_os("CARGO_FEATURE_WEB").is_none() {
+        return;
+    }
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("set by cargo");
+    let index = Path::new(&manifest_dir).join("web/build/index.html");
+    if !index.exists() {
+        panic!(
+            "\n\nThe `web` feature embeds the dashboard from web/build/, but {} is missing.\n\
+             Build the dashboard first:\n\n    cd web && npm ci && npm run build\n\n",
+            index.display()
+        );
+    }
+}
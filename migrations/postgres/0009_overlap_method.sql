@@ -0,0 +1,11 @@
+-- Migration v9: record how topic_overlap was computed on account_scores.
+--
+-- 'keyword' (TF-IDF cosine) and 'embedding' (sentence-embedding cosine)
+-- produce scores on different scales, so readers need to know which one a
+-- row used. Rows scored before this column existed default to 'unknown'.
+--
+-- Mirrors the SQLite v9 migration in src/db/schema.rs.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS overlap_method TEXT DEFAULT 'unknown';
+
+INSERT INTO schema_version (version) VALUES (9) ON CONFLICT DO NOTHING;
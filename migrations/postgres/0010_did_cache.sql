@@ -0,0 +1,15 @@
+-- Migration v10: DID → handle resolution cache.
+--
+-- Shared across users — a DID resolves to the same handle whoever asks.
+-- Filled by `charcoal warm-cache` and `charcoal resolve-handles` so reports
+-- and later backfills can skip the network for DIDs already seen.
+--
+-- Mirrors the SQLite v10 migration in src/db/schema.rs.
+
+CREATE TABLE IF NOT EXISTS did_cache (
+    did TEXT PRIMARY KEY,
+    handle TEXT NOT NULL,
+    resolved_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO schema_version (version) VALUES (10) ON CONFLICT DO NOTHING;
@@ -0,0 +1,18 @@
+-- Migration v11: latest follower snapshot per amplifier.
+--
+-- Stores hashed follower DIDs so consecutive scans can spot accounts that
+-- followed an amplifier in between (the recently_followed_amplifier
+-- behavioral signal). Only the newest snapshot is kept per amplifier.
+--
+-- Mirrors the SQLite v11 migration in src/db/schema.rs, except the hashes
+-- are a native TEXT[] instead of a JSON string.
+
+CREATE TABLE IF NOT EXISTS follower_snapshots (
+    user_did TEXT NOT NULL,
+    amplifier_did TEXT NOT NULL,
+    follower_hashes TEXT[] NOT NULL,
+    taken_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_did, amplifier_did)
+);
+
+INSERT INTO schema_version (version) VALUES (11) ON CONFLICT DO NOTHING;
@@ -0,0 +1,15 @@
+-- Migration v12: link scored followers to the amplifier they came through.
+--
+-- Backs `charcoal amplifiers`, which rolls follower threat up per amplifier.
+-- A follower reached through several amplifiers gets one row per amplifier.
+--
+-- Mirrors the SQLite v12 migration in src/db/schema.rs.
+
+CREATE TABLE IF NOT EXISTS amplifier_followers (
+    user_did TEXT NOT NULL,
+    amplifier_did TEXT NOT NULL,
+    follower_did TEXT NOT NULL,
+    PRIMARY KEY (user_did, amplifier_did, follower_did)
+);
+
+INSERT INTO schema_version (version) VALUES (12) ON CONFLICT DO NOTHING;
@@ -0,0 +1,10 @@
+-- Migration v13: when each scored account last posted.
+--
+-- Lets `charcoal report --active-within N` set dormant accounts aside.
+-- NULL for accounts scored before this column existed.
+--
+-- Mirrors the SQLite v13 migration in src/db/schema.rs.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS last_active_at TIMESTAMPTZ;
+
+INSERT INTO schema_version (version) VALUES (13) ON CONFLICT DO NOTHING;
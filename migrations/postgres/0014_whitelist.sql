@@ -0,0 +1,19 @@
+-- Migration v14: accounts the user trusts, excluded from scoring.
+--
+-- Backs `charcoal whitelist`. Pipelines skip whitelisted accounts before any
+-- toxicity inference; the handle is kept for display. The placeholder scores
+-- recorded for them are flagged `whitelisted`.
+--
+-- Mirrors the SQLite v14 migration in src/db/schema.rs.
+
+CREATE TABLE IF NOT EXISTS whitelist (
+    user_did TEXT NOT NULL,
+    did TEXT NOT NULL,
+    handle TEXT NOT NULL,
+    added_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_did, did)
+);
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS whitelisted BOOLEAN NOT NULL DEFAULT FALSE;
+
+INSERT INTO schema_version (version) VALUES (14) ON CONFLICT DO NOTHING;
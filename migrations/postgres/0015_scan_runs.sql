@@ -0,0 +1,21 @@
+-- Migration v15: history of finished scans and sweeps.
+--
+-- One row per pipeline run with its event and scored-account counts, shown
+-- by `charcoal status` so exposure can be compared over time.
+--
+-- Mirrors the SQLite v15 migration in src/db/schema.rs.
+
+CREATE TABLE IF NOT EXISTS scan_runs (
+    id BIGSERIAL PRIMARY KEY,
+    user_did TEXT NOT NULL,
+    command TEXT NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    finished_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    events_detected BIGINT NOT NULL DEFAULT 0,
+    accounts_scored BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE INDEX IF NOT EXISTS idx_scan_runs_user_finished
+    ON scan_runs(user_did, finished_at);
+
+INSERT INTO schema_version (version) VALUES (15) ON CONFLICT DO NOTHING;
@@ -0,0 +1,11 @@
+-- Migration v16: the amplifier whose follower list led to each scored account.
+--
+-- Lets `charcoal report --group-by-amplifier` group accounts by the
+-- amplifier they were found through. NULL for accounts found other ways
+-- (sweeps, `charcoal score`) and for rows scored before this column existed.
+--
+-- Mirrors the SQLite v16 migration in src/db/schema.rs.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS origin_amplifier_did TEXT;
+
+INSERT INTO schema_version (version) VALUES (16) ON CONFLICT DO NOTHING;
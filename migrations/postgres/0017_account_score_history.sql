@@ -0,0 +1,21 @@
+-- Migration v17: append-only history of account scores.
+--
+-- Every upsert into account_scores also adds a row here, so an account's
+-- threat can be followed over time (shown by `charcoal score`).
+-- account_scores keeps only the latest score.
+--
+-- Mirrors the SQLite v17 migration in src/db/schema.rs.
+
+CREATE TABLE IF NOT EXISTS account_score_history (
+    id BIGSERIAL PRIMARY KEY,
+    user_did TEXT NOT NULL,
+    did TEXT NOT NULL,
+    threat_score DOUBLE PRECISION,
+    toxicity_score DOUBLE PRECISION,
+    scored_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_account_score_history_account
+    ON account_score_history(user_did, did, scored_at);
+
+INSERT INTO schema_version (version) VALUES (17) ON CONFLICT DO NOTHING;
@@ -0,0 +1,13 @@
+-- Migration v18: the unsmoothed threat score behind each stored score.
+--
+-- With CHARCOAL_SCORE_EMA_ALPHA set, threat_score holds an exponential
+-- moving average across scans and raw_threat_score the latest scan's own
+-- score, so `charcoal report --raw-scores` can show either. Without
+-- smoothing the two are equal. NULL for rows scored before this column
+-- existed.
+--
+-- Mirrors the SQLite v18 migration in src/db/schema.rs.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS raw_threat_score DOUBLE PRECISION;
+
+INSERT INTO schema_version (version) VALUES (18) ON CONFLICT DO NOTHING;
@@ -0,0 +1,13 @@
+-- Migration v19: how few posts a low-confidence topic overlap rests on.
+--
+-- When an account has fewer analyzable posts than CHARCOAL_MIN_OVERLAP_POSTS,
+-- its topic overlap is computed from TF-IDF keywords instead of a noisy mean
+-- embedding, and overlap_post_count records the post count so reports can
+-- say "overlap based on only 3 posts". NULL when the overlap had enough
+-- posts, and for rows scored before this column existed.
+--
+-- Mirrors the SQLite v19 migration in src/db/schema.rs.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS overlap_post_count INTEGER;
+
+INSERT INTO schema_version (version) VALUES (19) ON CONFLICT DO NOTHING;
@@ -0,0 +1,12 @@
+-- Migration v20: how many of the posts analyzed for a score were replies.
+--
+-- posts_analyzed counts everything scored; replies_analyzed is the reply
+-- share of it, which grows when CHARCOAL_SCORE_REPLIES fetches an account's
+-- recent replies on top of its feed sample. NULL for rows scored before
+-- this column existed.
+--
+-- Mirrors the SQLite v20 migration in src/db/schema.rs.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS replies_analyzed INTEGER;
+
+INSERT INTO schema_version (version) VALUES (20) ON CONFLICT DO NOTHING;
@@ -0,0 +1,23 @@
+-- Migration v21: full-text search over toxic post evidence.
+--
+-- evidence_tsv indexes the text of every post in top_toxic_posts, so
+-- `charcoal search` can find accounts by what they said. It's a generated
+-- column, so existing rows are indexed when it's added and every upsert
+-- keeps it current.
+--
+-- Mirrors the SQLite v21 migration in src/db/schema.rs, which uses an
+-- FTS5 table instead.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS evidence_tsv tsvector
+    GENERATED ALWAYS AS (
+        jsonb_to_tsvector(
+            'english',
+            COALESCE(jsonb_path_query_array(top_toxic_posts, '$[*].text'), '[]'::jsonb),
+            '["string"]'
+        )
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_account_scores_evidence_tsv
+    ON account_scores USING GIN (evidence_tsv);
+
+INSERT INTO schema_version (version) VALUES (21) ON CONFLICT DO NOTHING;
@@ -0,0 +1,13 @@
+-- Migration v22: how much evidence an account's threat score rests on.
+--
+-- A value from 0.0 to 1.0 computed from how many posts were analyzed, how
+-- topic overlap was measured, and how much toxicity varied across the posts
+-- (see scoring::evidence). Unlike scoring_confidence it says nothing about
+-- how deep the pipeline went. NULL for rows scored before this column
+-- existed and for accounts that weren't scored.
+--
+-- Mirrors the SQLite v22 migration in src/db/schema.rs.
+
+ALTER TABLE account_scores ADD COLUMN IF NOT EXISTS evidence_strength DOUBLE PRECISION;
+
+INSERT INTO schema_version (version) VALUES (22) ON CONFLICT DO NOTHING;
@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+# pin-models.sh — Print the revision and SHA-256 pins for the model files
+# `charcoal download-model` fetches (src/toxicity/download.rs).
+#
+# Resolves each HuggingFace repo's current commit, downloads every file at
+# that commit and hashes it. The output is Rust: paste it over the matching
+# `*_REVISION` and `PinnedFile` constants.
+#
+# Usage:
+#   ./scripts/pin-models.sh
+#
+# Requires: curl, jq, sha256sum
+
+set -euo pipefail
+
+# repo|constant prefix|files (constant=path)
+MODELS=(
+    "protectai/unbiased-toxic-roberta-onnx|TOXICITY|TOXICITY_TOKENIZER=tokenizer.json TOXICITY_MODEL=model_quantized.onnx"
+    "sentence-transformers/all-MiniLM-L6-v2|EMBEDDING|EMBEDDING_TOKENIZER=tokenizer.json EMBEDDING_MODEL=onnx/model.onnx"
+    "Xenova/nli-deberta-v3-xsmall|NLI|NLI_TOKENIZER=tokenizer.json NLI_MODEL=onnx/model_quantized.onnx"
+)
+
+for entry in "${MODELS[@]}"; do
+    IFS='|' read -r repo prefix files <<< "$entry"
+    revision="$(curl -fsSL "https://huggingface.co/api/models/${repo}/revision/main" | jq -r .sha)"
+    echo "const ${prefix}_REVISION: &str = \"${revision}\";"
+    for file in $files; do
+        name="${file%%=*}"
+        path="${file#*=}"
+        digest="$(curl -fsSL "https://huggingface.co/${repo}/resolve/${revision}/${path}" \
+            | sha256sum | cut -d' ' -f1)"
+        echo "pub const ${name}: PinnedFile = PinnedFile {"
+        echo "    repo: ${prefix}_REPO,"
+        echo "    revision: ${prefix}_REVISION,"
+        echo "    path: \"${path}\","
+        echo "    sha256: Some(\"${digest}\"),"
+        echo "};"
+    done
+done
@@ -0,0 +1,146 @@
+// Inference throughput benchmark — backs the hidden `charcoal bench` command.
+//
+// Runs synthetic posts through the same scorer and embedder the pipeline
+// uses, so the numbers reflect production paths rather than a stripped-down
+// microbenchmark. Useful for picking batch sizes, thread counts and
+// execution providers on a specific machine.
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::topics::embeddings::SentenceEmbedder;
+use crate::toxicity::traits::{AttributeWeights, ToxicityScorer};
+
+/// Phrases mixed into synthetic posts. A blend of neutral, heated and
+/// hostile wording so the two-stage scorer exercises both its clean-pass
+/// short-circuit and its escalation path.
+const SYNTHETIC_FRAGMENTS: &[&str] = &[
+    "just finished reading a great thread about community moderation",
+    "honestly this take is ridiculous and you should know better",
+    "the new transit plan looks promising for the east side",
+    "people like you are the reason this site is unbearable",
+    "anyone have recommendations for a good sourdough starter",
+    "you clearly didn't read the article before dunking on it",
+    "sharing some photos from the weekend hike, the views were unreal",
+    "this is the dumbest thing I've seen all week, congrats",
+];
+
+/// Build `count` deterministic synthetic posts of varying length (one to
+/// four fragments each), so tokenized lengths resemble a real feed.
+pub fn synthetic_texts(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| {
+            let fragments = 1 + i % 4;
+            (0..fragments)
+                .map(|j| SYNTHETIC_FRAGMENTS[(i * 3 + j) % SYNTHETIC_FRAGMENTS.len()])
+                .collect::<Vec<_>>()
+                .join(". ")
+        })
+        .collect()
+}
+
+/// Timing results for one benchmarked component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchStats {
+    /// Total texts processed
+    pub texts: usize,
+    /// Number of batch calls made
+    pub batches: usize,
+    /// Largest batch submitted in a single call
+    pub peak_batch_size: usize,
+    /// Wall-clock time for the whole run
+    pub total: Duration,
+    /// Median per-batch latency
+    pub p50: Duration,
+    /// 95th-percentile per-batch latency
+    pub p95: Duration,
+}
+
+impl BenchStats {
+    /// Summarize per-batch latencies. `batch_sizes` and `latencies` are
+    /// parallel slices, one entry per batch call.
+    pub fn from_batches(batch_sizes: &[usize], latencies: &[Duration], total: Duration) -> Self {
+        let mut sorted = latencies.to_vec();
+        sorted.sort();
+        Self {
+            texts: batch_sizes.iter().sum(),
+            batches: batch_sizes.len(),
+            peak_batch_size: batch_sizes.iter().copied().max().unwrap_or(0),
+            total,
+            p50: percentile(&sorted, 0.50),
+            p95: percentile(&sorted, 0.95),
+        }
+    }
+
+    /// Texts processed per second of wall-clock time.
+    pub fn texts_per_sec(&self) -> f64 {
+        let secs = self.total.as_secs_f64();
+        if secs > 0.0 {
+            self.texts as f64 / secs
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Nearest-rank percentile of an already-sorted slice. Zero when empty.
+pub fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (p.clamp(0.0, 1.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.saturating_sub(1).min(sorted.len() - 1)]
+}
+
+/// Run `texts` through the scorer's batch classification path in chunks of
+/// `batch_size` — the same call `build_profile` makes per account.
+pub async fn bench_scorer(
+    scorer: &dyn ToxicityScorer,
+    texts: &[String],
+    batch_size: usize,
+) -> Result<BenchStats> {
+    let mut sizes = Vec::new();
+    let mut latencies = Vec::new();
+    let start = Instant::now();
+
+    for chunk in texts.chunks(batch_size.max(1)) {
+        let contexts = vec![None; chunk.len()];
+        let t = Instant::now();
+        scorer
+            .classify_batch_with_contexts(chunk, &contexts, &AttributeWeights::default())
+            .await?;
+        latencies.push(t.elapsed());
+        sizes.push(chunk.len());
+    }
+
+    Ok(BenchStats::from_batches(
+        &sizes,
+        &latencies,
+        start.elapsed(),
+    ))
+}
+
+/// Run `texts` through the sentence embedder in chunks of `batch_size`.
+pub async fn bench_embedder(
+    embedder: &SentenceEmbedder,
+    texts: &[String],
+    batch_size: usize,
+) -> Result<BenchStats> {
+    let mut sizes = Vec::new();
+    let mut latencies = Vec::new();
+    let start = Instant::now();
+
+    for chunk in texts.chunks(batch_size.max(1)) {
+        let t = Instant::now();
+        embedder.embed_batch(chunk).await?;
+        latencies.push(t.elapsed());
+        sizes.push(chunk.len());
+    }
+
+    Ok(BenchStats::from_batches(
+        &sizes,
+        &latencies,
+        start.elapsed(),
+    ))
+}
@@ -1,8 +1,16 @@
-// Amplification event types — shared between Constellation and the pipeline.
+// Amplification event types — shared between event sources and the pipeline.
 //
 // The AmplificationNotification struct represents a quote or repost of the
-// protected user's content. It's produced by the Constellation backlink client
-// and consumed by the amplification pipeline.
+// protected user's content. It's produced by an AmplificationSource — the
+// Constellation backlink client, or the public AppView when Constellation is
+// unreachable — and consumed by the amplification pipeline.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::{debug, info, warn};
 
 /// An amplification event detected from Constellation backlinks.
 #[derive(Debug, Clone)]
@@ -15,4 +23,186 @@ pub struct AmplificationNotification {
     /// The amplifier's post URI (for quotes — the quote-post itself)
     pub amplifier_post_uri: String,
     pub indexed_at: String,
+    /// When the amplifying record was created, decoded from its record key
+    /// (see `tid_timestamp`). None when the key isn't a TID.
+    pub occurred_at: Option<DateTime<Utc>>,
+}
+
+impl AmplificationNotification {
+    /// Identity of an event for deduplication: one person amplifying one
+    /// post one way counts once, however many records they made.
+    pub fn dedup_key(&self) -> (String, Option<String>, String) {
+        (
+            self.amplifier_did.clone(),
+            self.original_post_uri.clone(),
+            self.event_type.clone(),
+        )
+    }
+}
+
+/// Alphabet of the base32-sortable encoding AT Protocol TIDs use.
+const TID_ALPHABET: &[u8] = b"234567abcdefghijklmnopqrstuvwxyz";
+
+/// Creation time encoded in an AT Protocol TID record key.
+///
+/// A TID is 13 base32-sortable characters holding a 64-bit value: the top
+/// bit is zero, the next 53 are microseconds since the Unix epoch and the
+/// low 10 are a clock id. Anything else returns None.
+pub fn tid_timestamp(tid: &str) -> Option<DateTime<Utc>> {
+    if tid.len() != 13 {
+        return None;
+    }
+    let mut value: u64 = 0;
+    for byte in tid.bytes() {
+        let digit = TID_ALPHABET.iter().position(|&c| c == byte)? as u64;
+        value = value.checked_mul(32)?.checked_add(digit)?;
+    }
+    if value >> 63 != 0 {
+        return None;
+    }
+    DateTime::from_timestamp_micros((value >> 10) as i64)
+}
+
+/// `tid_timestamp` of the record key (last path segment) of an AT URI.
+pub fn uri_timestamp(uri: &str) -> Option<DateTime<Utc>> {
+    uri.rsplit('/').next().and_then(tid_timestamp)
+}
+
+/// Deduplicate events on `dedup_key` and order them oldest first, so they
+/// are stored in the order they happened.
+///
+/// When one person amplified the same post several times, the earliest
+/// event is kept. Events without a timestamp go last, in input order.
+pub fn dedup_and_sort(
+    mut events: Vec<AmplificationNotification>,
+) -> Vec<AmplificationNotification> {
+    // Stable sort: None sorts after every timestamp and keeps input order
+    events.sort_by_key(|e| (e.occurred_at.is_none(), e.occurred_at));
+    let mut seen = HashSet::new();
+    events.retain(|e| seen.insert(e.dedup_key()));
+    events
+}
+
+/// Somewhere to look up who quoted or reposted the protected user's posts.
+///
+/// Implementors: `ConstellationClient` (backlink index, also sees blocked
+/// accounts) and `bluesky::quotes::AppviewSource` (public AppView, the
+/// fallback when Constellation is down).
+#[async_trait]
+pub trait AmplificationSource: Send + Sync {
+    /// Short name for logs and scan output, e.g. "constellation".
+    fn name(&self) -> &'static str;
+
+    /// Quotes and reposts of one post. Fails when the source can't answer,
+    /// so the caller can ask the next one instead of recording nothing.
+    async fn post_amplifications(&self, post_uri: &str) -> Result<Vec<AmplificationNotification>>;
+}
+
+/// Consecutive failed posts after which a source is skipped for the rest
+/// of a lookup, so an unreachable source costs a few timeouts, not one per
+/// post.
+pub const SOURCE_FAILURE_LIMIT: usize = 3;
+
+/// Events found by `find_amplification_events`.
+#[derive(Debug, Default)]
+pub struct FoundEvents {
+    pub events: Vec<AmplificationNotification>,
+    /// How many posts each source answered for, by source name
+    pub posts_by_source: BTreeMap<&'static str, usize>,
+    /// Posts no source could answer for
+    pub unanswered: usize,
+}
+
+impl FoundEvents {
+    /// "appview: 3, constellation: 47" — posts answered per source, for
+    /// scan output.
+    pub fn source_summary(&self) -> String {
+        self.posts_by_source
+            .iter()
+            .map(|(name, count)| format!("{name}: {count}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Find amplification events for `post_uris`, asking `sources` in order
+/// for each post until one answers. A source that fails
+/// `SOURCE_FAILURE_LIMIT` posts in a row isn't asked again. Events are
+/// deduplicated by `amplifier_post_uri` across sources.
+///
+/// Fails only when no source answered for any post, so an outage isn't
+/// mistaken for a quiet day.
+pub async fn find_amplification_events(
+    sources: &[&dyn AmplificationSource],
+    post_uris: &[String],
+) -> Result<FoundEvents> {
+    let mut found = FoundEvents::default();
+    let mut seen_uris = HashSet::new();
+    let mut failures: HashMap<&'static str, usize> = HashMap::new();
+
+    for uri in post_uris {
+        let mut answered = false;
+        for source in sources {
+            let name = source.name();
+            if failures.get(name).copied().unwrap_or(0) >= SOURCE_FAILURE_LIMIT {
+                continue;
+            }
+            match source.post_amplifications(uri).await {
+                Ok(events) => {
+                    failures.insert(name, 0);
+                    for event in events {
+                        // Reposts from the AppView have no record URI
+                        if !event.amplifier_post_uri.is_empty()
+                            && !seen_uris.insert(event.amplifier_post_uri.clone())
+                        {
+                            continue;
+                        }
+                        debug!(
+                            source = name,
+                            event_type = event.event_type.as_str(),
+                            amplifier = event.amplifier_did.as_str(),
+                            post = uri.as_str(),
+                            "Amplification event found"
+                        );
+                        found.events.push(event);
+                    }
+                    *found.posts_by_source.entry(name).or_default() += 1;
+                    answered = true;
+                    break;
+                }
+                Err(e) => {
+                    let count = failures.entry(name).or_default();
+                    *count += 1;
+                    warn!(source = name, uri = uri.as_str(), error = %e, "Amplification source failed for post");
+                    if *count == SOURCE_FAILURE_LIMIT {
+                        warn!(
+                            source = name,
+                            "Skipping amplification source after {SOURCE_FAILURE_LIMIT} failures in a row"
+                        );
+                    }
+                }
+            }
+        }
+        if !answered {
+            found.unanswered += 1;
+        }
+    }
+
+    if !post_uris.is_empty() && found.unanswered == post_uris.len() {
+        let names: Vec<&str> = sources.iter().map(|s| s.name()).collect();
+        anyhow::bail!(
+            "no amplification source answered for any of {} posts (tried {})",
+            post_uris.len(),
+            names.join(", ")
+        );
+    }
+
+    info!(
+        total_events = found.events.len(),
+        sources = found.source_summary().as_str(),
+        unanswered = found.unanswered,
+        "Amplification lookup complete"
+    );
+
+    Ok(found)
 }
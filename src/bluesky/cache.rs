@@ -0,0 +1,144 @@
+// In-memory response cache for public API reads.
+//
+// A sweep walks many amplifiers whose follower lists overlap heavily, so the
+// same follower pages and profile batches get requested again and again
+// within a few minutes. ResponseCache keeps the raw JSON bodies of
+// successful XRPC GETs keyed by (endpoint, params), expires them after a
+// TTL, and evicts the least recently used entry once it holds `capacity`
+// responses. Errors are never cached.
+//
+// The cache is shared through an Arc and guarded by a plain mutex — it is
+// only held for map operations, never across an await — so every concurrent
+// scoring task sees the same entries.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+/// How long a cached response stays fresh by default.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Default cap on cached responses. A follower page of 100 profiles is tens
+/// of kilobytes, so this keeps the cache to a few hundred MB at worst.
+pub const DEFAULT_CACHE_CAPACITY: usize = 10_000;
+
+/// An XRPC method name plus its query parameters, in request order.
+type CacheKey = (String, Vec<(String, String)>);
+
+struct Entry {
+    value: Arc<Value>,
+    stored_at: Instant,
+    /// Position in `Inner::recency`; larger is more recently used.
+    tick: u64,
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: HashMap<CacheKey, Entry>,
+    /// Recency order: tick -> key, oldest first.
+    recency: BTreeMap<u64, CacheKey>,
+    next_tick: u64,
+}
+
+impl Inner {
+    fn touch(&mut self, key: &CacheKey) {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.recency.remove(&entry.tick);
+            entry.tick = tick;
+            self.recency.insert(tick, key.clone());
+        }
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.tick);
+        }
+    }
+}
+
+/// A bounded, TTL-expiring cache of XRPC response bodies.
+pub struct ResponseCache {
+    ttl: Duration,
+    capacity: usize,
+    inner: Mutex<Inner>,
+}
+
+impl ResponseCache {
+    /// A cache whose entries expire after `ttl`, holding at most `capacity`
+    /// responses (0 is treated as 1).
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity: capacity.max(1),
+            inner: Mutex::new(Inner::default()),
+        }
+    }
+
+    /// The cached response for this request, if one is still fresh.
+    pub fn get(&self, nsid: &str, params: &[(&str, &str)]) -> Option<Arc<Value>> {
+        let key = cache_key(nsid, params);
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+
+        let stored_at = inner.entries.get(&key)?.stored_at;
+        if stored_at.elapsed() >= self.ttl {
+            inner.remove(&key);
+            return None;
+        }
+        inner.touch(&key);
+        inner.entries.get(&key).map(|e| Arc::clone(&e.value))
+    }
+
+    /// Store a response, evicting the least recently used entry if full.
+    pub fn insert(&self, nsid: &str, params: &[(&str, &str)], value: Arc<Value>) {
+        let key = cache_key(nsid, params);
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+
+        inner.remove(&key);
+        while inner.entries.len() >= self.capacity {
+            let Some((_, oldest)) = inner.recency.pop_first() else {
+                break;
+            };
+            inner.entries.remove(&oldest);
+        }
+
+        let tick = inner.next_tick;
+        inner.next_tick += 1;
+        inner.recency.insert(tick, key.clone());
+        inner.entries.insert(
+            key,
+            Entry {
+                value,
+                stored_at: Instant::now(),
+                tick,
+            },
+        );
+    }
+
+    /// Number of responses currently held (including any not yet found stale).
+    pub fn len(&self) -> usize {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entries
+            .len()
+    }
+
+    /// Whether the cache holds no responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn cache_key(nsid: &str, params: &[(&str, &str)]) -> CacheKey {
+    (
+        nsid.to_string(),
+        params
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    )
+}
@@ -1,26 +1,94 @@
-// Public AT Protocol client — unauthenticated XRPC over HTTP.
+// Public AT Protocol client — XRPC over HTTP.
 //
 // All AT Protocol read endpoints are public and don't require authentication.
 // This client replaces the authenticated BskyAgent for the intelligence
 // pipeline — auth is only needed for write operations (blocking/muting),
 // which is a future feature.
+//
+// Reads can optionally go through an app-password session instead, because
+// Bluesky allows authenticated sessions more requests than anonymous callers.
+// The session is created on the first request, its access token is sent as a
+// bearer token, and an expired token is refreshed once and the request
+// retried. The password and tokens live only in memory. The web server
+// always reads anonymously, so one operator's session is never spent on
+// other users' scans.
 
 use anyhow::{Context, Result};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
-use tracing::debug;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, Semaphore};
+use tracing::{debug, warn};
+
+use super::cache::{ResponseCache, DEFAULT_CACHE_CAPACITY};
+use crate::error::Error;
+use crate::toxicity::rate_limiter::RateLimiter;
 
 /// Default public API endpoint for AT Protocol read operations.
 pub const DEFAULT_PUBLIC_API_URL: &str = "https://public.api.bsky.app";
 
+/// Requests the public AppView allows per IP in one rate-limit window.
+pub const PUBLIC_API_REQUESTS_PER_WINDOW: usize = 3000;
+
+/// Length of the public AppView's rate-limit window.
+pub const PUBLIC_API_WINDOW: Duration = Duration::from_secs(300);
+
+/// Requests per `PUBLIC_API_WINDOW` the client allows itself with an
+/// app-password session, which Bluesky rate-limits per account rather than
+/// per IP.
+pub const AUTHENTICATED_REQUESTS_PER_WINDOW: usize = 5000;
+
+/// Where app-password sessions are created — and authenticated reads sent,
+/// proxied on to the AppView — when `PUBLIC_API_URL` is left at its default.
+/// The public AppView doesn't accept session tokens.
+pub const DEFAULT_AUTH_SERVICE_URL: &str = "https://bsky.social";
+
+/// Default cap on XRPC requests outstanding at once from one client.
+pub const DEFAULT_MAX_IN_FLIGHT_REQUESTS: usize = 16;
+
 /// Unauthenticated HTTP client for public AT Protocol XRPC endpoints.
 ///
 /// Modeled on the ConstellationClient pattern — a thin reqwest wrapper
 /// with a generic XRPC GET helper. Replaces `bsky-sdk::BskyAgent` for
 /// all read-only operations.
+///
+/// Requests are paced to the AppView's published limit
+/// (`PUBLIC_API_REQUESTS_PER_WINDOW` per `PUBLIC_API_WINDOW`), and the
+/// `ratelimit-remaining` / `ratelimit-reset` headers on each response tell
+/// the limiter when to slow down further or wait for the window to reset.
+///
+/// Separately, at most `max_in_flight` XRPC requests are outstanding at once
+/// (`DEFAULT_MAX_IN_FLIGHT_REQUESTS` unless set with `with_max_in_flight`),
+/// however many tasks share the client. The rate limiter spaces request
+/// starts; this cap keeps a burst of slow responses from piling up more
+/// open requests than the AppView will tolerate.
+///
+/// Unauthenticated by default. `with_app_password` signs requests with an
+/// app-password session and raises the pacing to
+/// `AUTHENTICATED_REQUESTS_PER_WINDOW`.
 pub struct PublicAtpClient {
     client: reqwest::Client,
     base_url: String,
+    cache: Option<Arc<ResponseCache>>,
+    rate_limiter: RateLimiter,
+    in_flight: Semaphore,
+    max_in_flight: usize,
+    auth: Option<AppPasswordAuth>,
+}
+
+/// App-password credentials and the session created from them. Never
+/// written anywhere — a new process logs in again.
+struct AppPasswordAuth {
+    identifier: String,
+    password: String,
+    /// `None` until the first request; locked while a session is created
+    /// or refreshed so concurrent requests don't each log in
+    session: Mutex<Option<Session>>,
+    /// Why the server refused to log in, once it has. Every later request
+    /// fails with this instead of trying again — createSession is tightly
+    /// rate-limited per account, and a wrong password won't start working.
+    login_refused: std::sync::OnceLock<String>,
 }
 
 impl PublicAtpClient {
@@ -29,51 +97,326 @@ impl PublicAtpClient {
     /// Defaults to `https://public.api.bsky.app` — pass a different URL
     /// for testing or alternate PDS instances.
     pub fn new(base_url: &str) -> Result<Self> {
-        let client = reqwest::Client::builder()
-            .user_agent("charcoal/0.1 (threat-detection; @chaosgreml.in)")
+        let client = crate::http::client_builder()
             .build()
             .context("Failed to build HTTP client")?;
 
+        let requests_per_second =
+            PUBLIC_API_REQUESTS_PER_WINDOW as f64 / PUBLIC_API_WINDOW.as_secs_f64();
+
         Ok(Self {
             client,
             base_url: base_url.trim_end_matches('/').to_string(),
+            cache: None,
+            rate_limiter: RateLimiter::new(requests_per_second),
+            in_flight: Semaphore::new(DEFAULT_MAX_IN_FLIGHT_REQUESTS),
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT_REQUESTS,
+            auth: None,
         })
     }
 
+    /// A client for the configured API, authenticated when
+    /// `CHARCOAL_BSKY_APP_PASSWORD` is set.
+    ///
+    /// An authenticated client logs in as `BLUESKY_HANDLE` and talks to
+    /// `DEFAULT_AUTH_SERVICE_URL`, unless `PUBLIC_API_URL` names another
+    /// server, which is then expected to accept sessions itself.
+    pub fn from_config(config: &crate::config::Config) -> Result<Self> {
+        let Some(password) = &config.session_app_password else {
+            return Self::new(&config.public_api_url);
+        };
+        if config.bluesky_handle.is_empty() {
+            warn!(
+                "CHARCOAL_BSKY_APP_PASSWORD is set but BLUESKY_HANDLE isn't; using the public API"
+            );
+            return Self::new(&config.public_api_url);
+        }
+
+        let service_url = if config.public_api_url == DEFAULT_PUBLIC_API_URL {
+            DEFAULT_AUTH_SERVICE_URL
+        } else {
+            &config.public_api_url
+        };
+        Ok(Self::new(service_url)?.with_app_password(&config.bluesky_handle, password))
+    }
+
+    /// Sign requests with an app-password session for `identifier` (a
+    /// handle or DID), created against this client's base URL on the first
+    /// request, and pace requests to `AUTHENTICATED_REQUESTS_PER_WINDOW`.
+    pub fn with_app_password(mut self, identifier: &str, password: &str) -> Self {
+        self.auth = Some(AppPasswordAuth {
+            identifier: identifier.to_string(),
+            password: password.to_string(),
+            session: Mutex::new(None),
+            login_refused: std::sync::OnceLock::new(),
+        });
+        self.rate_limiter = RateLimiter::new(
+            AUTHENTICATED_REQUESTS_PER_WINDOW as f64 / PUBLIC_API_WINDOW.as_secs_f64(),
+        );
+        self
+    }
+
+    /// Whether requests are signed with an app-password session.
+    pub fn is_authenticated(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    /// Allow at most `max` XRPC requests outstanding at once (at least 1).
+    pub fn with_max_in_flight(mut self, max: usize) -> Self {
+        let max = max.max(1);
+        self.in_flight = Semaphore::new(max);
+        self.max_in_flight = max;
+        self
+    }
+
+    /// The most XRPC requests this client has outstanding at once.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Cache follower and profile responses for `ttl` (see
+    /// [`DEFAULT_CACHE_TTL`](super::cache::DEFAULT_CACHE_TTL)), up to
+    /// [`DEFAULT_CACHE_CAPACITY`] responses.
+    pub fn with_cache(self, ttl: Duration) -> Self {
+        self.with_shared_cache(Arc::new(ResponseCache::new(ttl, DEFAULT_CACHE_CAPACITY)))
+    }
+
+    /// Use an existing cache, e.g. one shared with another client.
+    pub fn with_shared_cache(mut self, cache: Arc<ResponseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// The response cache, if caching is enabled.
+    pub fn cache(&self) -> Option<&Arc<ResponseCache>> {
+        self.cache.as_ref()
+    }
+
     /// Make a GET request to an XRPC endpoint and deserialize the response.
     ///
     /// `nsid` is the XRPC method name (e.g. "app.bsky.feed.getAuthorFeed").
     /// `params` are query string key-value pairs. Use repeated keys for
     /// array parameters (e.g. `[("actors", "did1"), ("actors", "did2")]`).
+    ///
+    /// With an app-password session, a response saying the access token has
+    /// expired (see `is_expired_session`) refreshes the session and retries
+    /// the request once.
     pub async fn xrpc_get<T: DeserializeOwned>(
         &self,
         nsid: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
+        debug!(nsid = nsid, "XRPC GET request");
+
+        // Held until the body is read, so a slow response keeps its slot
+        let _permit = self
+            .in_flight
+            .acquire()
+            .await
+            .context("In-flight request limiter closed")?;
+        let token = self.access_token().await?;
+        let mut response = self.send_get(nsid, params, token.as_deref()).await?;
+
+        if let Some(stale) = &token {
+            let status = response.status();
+            if matches!(
+                status,
+                reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::BAD_REQUEST
+            ) {
+                let body = response.text().await.unwrap_or_default();
+                if !is_expired_session(status, &body) {
+                    return Err(xrpc_error(nsid, status, &body).into());
+                }
+                debug!(nsid = nsid, "Session token rejected, refreshing");
+                let fresh = self.refresh_session(stale).await?;
+                response = self.send_get(nsid, params, Some(&fresh)).await?;
+            }
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(xrpc_error(nsid, status, &body).into());
+        }
+
+        response
+            .json::<T>()
+            .await
+            .with_context(|| format!("Failed to deserialize {nsid} response"))
+    }
+
+    /// Send one paced GET, with `token` as the bearer token when given, and
+    /// feed the response's rate-limit headers to the limiter.
+    async fn send_get(
+        &self,
+        nsid: &str,
+        params: &[(&str, &str)],
+        token: Option<&str>,
+    ) -> Result<reqwest::Response> {
         let url = format!("{}/xrpc/{}", self.base_url, nsid);
 
-        debug!(nsid = nsid, "XRPC GET request");
+        self.rate_limiter.acquire().await;
+        let mut request = self.client.get(&url).query(params);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("XRPC request failed: {nsid}"))?;
 
-        let response = self
-            .client
-            .get(&url)
-            .query(params)
+        // Error responses (429s above all) carry the budget too
+        if let Some((remaining, reset)) = rate_limit_headers(response.headers()) {
+            debug!(nsid = nsid, remaining, reset, "XRPC rate limit budget");
+            self.rate_limiter
+                .update_from_headers(remaining, reset)
+                .await;
+        }
+        Ok(response)
+    }
+
+    /// The session's access token, logging in first if there's no session
+    /// yet. `None` for an unauthenticated client.
+    async fn access_token(&self) -> Result<Option<String>> {
+        let Some(auth) = &self.auth else {
+            return Ok(None);
+        };
+        let mut session = auth.session.lock().await;
+        if session.is_none() {
+            *session = Some(self.create_session(auth).await?);
+        }
+        Ok(session.as_ref().map(|s| s.access_jwt.clone()))
+    }
+
+    /// Replace a session whose access token `stale` was rejected, returning
+    /// the new access token. Uses the refresh token, and logs in again if
+    /// that's rejected too. If another request already replaced the
+    /// session, its token is returned as is.
+    async fn refresh_session(&self, stale: &str) -> Result<String> {
+        let auth = self
+            .auth
+            .as_ref()
+            .context("Client has no app-password session")?;
+        let mut session = auth.session.lock().await;
+        if let Some(current) = session.as_ref().filter(|s| s.access_jwt != stale) {
+            return Ok(current.access_jwt.clone());
+        }
+
+        let refresh_jwt = session.as_ref().map(|s| s.refresh_jwt.clone());
+        let refreshed = match refresh_jwt {
+            Some(refresh_jwt) => self
+                .post_session(
+                    "com.atproto.server.refreshSession",
+                    Some(&refresh_jwt),
+                    None,
+                )
+                .await
+                .inspect_err(|e| debug!(error = %e, "Session refresh failed, logging in again"))
+                .ok(),
+            None => None,
+        };
+        let fresh = match refreshed {
+            Some(fresh) => fresh,
+            None => self.create_session(auth).await?,
+        };
+        let access_jwt = fresh.access_jwt.clone();
+        *session = Some(fresh);
+        Ok(access_jwt)
+    }
+
+    /// Log in with the app password. Once the server has refused a login,
+    /// this fails straight away with the same reason; network failures
+    /// aren't remembered.
+    async fn create_session(&self, auth: &AppPasswordAuth) -> Result<Session> {
+        if let Some(reason) = auth.login_refused.get() {
+            anyhow::bail!("{reason}");
+        }
+        let body = serde_json::json!({
+            "identifier": auth.identifier,
+            "password": auth.password,
+        });
+        self.post_session("com.atproto.server.createSession", None, Some(&body))
+            .await
+            .map_err(|e| {
+                let e = e.context(format!(
+                    "Failed to log in to Bluesky as {}",
+                    auth.identifier
+                ));
+                if !e.chain().any(|cause| cause.is::<reqwest::Error>()) {
+                    let _ = auth.login_refused.set(format!("{e:#}"));
+                }
+                e
+            })
+    }
+
+    /// POST to a session endpoint and read the session it returns.
+    async fn post_session(
+        &self,
+        nsid: &str,
+        token: Option<&str>,
+        body: Option<&serde_json::Value>,
+    ) -> Result<Session> {
+        let url = format!("{}/xrpc/{}", self.base_url, nsid);
+
+        self.rate_limiter.acquire().await;
+        let mut request = self.client.post(&url);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let response = request
             .send()
             .await
             .with_context(|| format!("XRPC request failed: {nsid}"))?;
 
+        if let Some((remaining, reset)) = rate_limit_headers(response.headers()) {
+            debug!(nsid = nsid, remaining, reset, "XRPC rate limit budget");
+            self.rate_limiter
+                .update_from_headers(remaining, reset)
+                .await;
+        }
+
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("XRPC {nsid} returned {status}: {body}");
+            return Err(xrpc_error(nsid, status, &body).into());
         }
 
         response
-            .json::<T>()
+            .json::<Session>()
             .await
             .with_context(|| format!("Failed to deserialize {nsid} response"))
     }
 
+    /// Like [`xrpc_get`](Self::xrpc_get), but answered from the response
+    /// cache when one is enabled and holds a fresh copy of this exact request.
+    /// Successful responses are cached; errors are not.
+    pub async fn xrpc_get_cached<T: DeserializeOwned>(
+        &self,
+        nsid: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T> {
+        let Some(cache) = &self.cache else {
+            return self.xrpc_get(nsid, params).await;
+        };
+
+        let value = match cache.get(nsid, params) {
+            Some(value) => {
+                debug!(nsid = nsid, "XRPC cache hit");
+                value
+            }
+            None => {
+                let value = Arc::new(self.xrpc_get::<serde_json::Value>(nsid, params).await?);
+                cache.insert(nsid, params, Arc::clone(&value));
+                value
+            }
+        };
+
+        T::deserialize(&*value).with_context(|| format!("Failed to deserialize {nsid} response"))
+    }
+
     /// Resolve a handle to its DID via the public API.
     pub async fn resolve_handle(&self, handle: &str) -> Result<String> {
         let resp: ResolveHandleResponse = self
@@ -116,6 +459,76 @@ impl PublicAtpClient {
     }
 }
 
+/// Classify a failed XRPC response so library callers can match on it.
+///
+/// 429s are rate limits. The AppView reports missing accounts and records
+/// as a 400 with an error name like `NotFound`/`ProfileNotFound` or an
+/// "Unable to resolve handle" message, so those count as not found along
+/// with a plain 404.
+pub fn xrpc_error(nsid: &str, status: reqwest::StatusCode, body: &str) -> Error {
+    let detail: XrpcErrorBody = serde_json::from_str(body).unwrap_or_default();
+    let message = detail.message.unwrap_or_else(|| body.to_string());
+
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Error::RateLimited {
+            service: "AppView".to_string(),
+            message: format!("XRPC {nsid}: {message}"),
+        };
+    }
+
+    let not_found = status == reqwest::StatusCode::NOT_FOUND
+        || detail
+            .error
+            .as_deref()
+            .is_some_and(|name| name.ends_with("NotFound"))
+        || message.contains("Unable to resolve handle")
+        || message.contains("not found");
+    if not_found {
+        return Error::NotFound(format!("XRPC {nsid}: {message}"));
+    }
+
+    Error::Other(anyhow::anyhow!("XRPC {nsid} returned {status}: {body}"))
+}
+
+/// Whether a failed response means the session's access token is no longer
+/// accepted: a 401, or the `ExpiredToken`/`InvalidToken` error a PDS
+/// returns with a 400.
+pub fn is_expired_session(status: reqwest::StatusCode, body: &str) -> bool {
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return true;
+    }
+    let detail: XrpcErrorBody = serde_json::from_str(body).unwrap_or_default();
+    status == reqwest::StatusCode::BAD_REQUEST
+        && matches!(
+            detail.error.as_deref(),
+            Some("ExpiredToken" | "InvalidToken")
+        )
+}
+
+/// The `{"error": ..., "message": ...}` body of an XRPC error response.
+#[derive(Debug, Default, Deserialize)]
+struct XrpcErrorBody {
+    error: Option<String>,
+    message: Option<String>,
+}
+
+/// The `ratelimit-remaining` and `ratelimit-reset` (unix seconds) headers
+/// from an AppView response, when both are present and numeric.
+pub fn rate_limit_headers(headers: &reqwest::header::HeaderMap) -> Option<(u64, u64)> {
+    let number = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse().ok();
+    Some((number("ratelimit-remaining")?, number("ratelimit-reset")?))
+}
+
+// -- Serde types for com.atproto.server sessions --
+
+/// The tokens from `createSession` / `refreshSession`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Session {
+    access_jwt: String,
+    refresh_jwt: String,
+}
+
 // -- Serde types for identity resolution --
 
 #[derive(Deserialize)]
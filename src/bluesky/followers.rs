@@ -18,6 +18,16 @@ pub struct Follower {
     pub display_name: Option<String>,
 }
 
+/// One slice of an account's follower list, plus the cursor that picks up
+/// immediately after it.
+///
+/// `next_cursor` is `None` when the slice reached the end of the list.
+#[derive(Debug, Clone)]
+pub struct FollowerWindow {
+    pub followers: Vec<Follower>,
+    pub next_cursor: Option<String>,
+}
+
 /// Fetch all followers for a given account, handling pagination automatically.
 ///
 /// Warning: accounts with large follower counts (10k+) will require many API
@@ -28,30 +38,48 @@ pub async fn fetch_followers(
     handle: &str,
     max_followers: usize,
 ) -> Result<Vec<Follower>> {
+    Ok(fetch_followers_window(client, handle, None, max_followers)
+        .await?
+        .followers)
+}
+
+/// Fetch up to `max_followers` followers starting from `start_cursor`.
+///
+/// Passing `None` starts at the top of the list (the most recent followers).
+/// Each page request asks for exactly as many followers as are still needed,
+/// so the returned `next_cursor` points at the first follower we did *not*
+/// collect — no one gets skipped between consecutive windows.
+pub async fn fetch_followers_window(
+    client: &PublicAtpClient,
+    handle: &str,
+    start_cursor: Option<&str>,
+    max_followers: usize,
+) -> Result<FollowerWindow> {
     let mut followers = Vec::new();
-    let mut cursor: Option<String> = None;
+    let mut cursor: Option<String> = start_cursor.map(|c| c.to_string());
 
-    loop {
-        let mut params: Vec<(&str, &str)> = vec![("actor", handle), ("limit", "100")];
+    while followers.len() < max_followers {
+        let page_limit = (max_followers - followers.len()).min(100).to_string();
+        let mut params: Vec<(&str, &str)> = vec![("actor", handle), ("limit", &page_limit)];
         if let Some(ref c) = cursor {
             params.push(("cursor", c));
         }
 
         let output: get_followers::Output = client
-            .xrpc_get("app.bsky.graph.getFollowers", &params)
+            .xrpc_get_cached("app.bsky.graph.getFollowers", &params)
             .await
             .with_context(|| format!("Failed to fetch followers for @{}", handle))?;
 
-        for profile in &output.followers {
+        for profile in output
+            .followers
+            .iter()
+            .take(max_followers - followers.len())
+        {
             followers.push(Follower {
                 did: profile.did.as_str().to_string(),
                 handle: profile.handle.as_str().to_string(),
                 display_name: profile.display_name.clone(),
             });
-
-            if followers.len() >= max_followers {
-                break;
-            }
         }
 
         debug!(
@@ -61,12 +89,9 @@ pub async fn fetch_followers(
             handle
         );
 
-        if followers.len() >= max_followers {
-            break;
-        }
-
         cursor = output.data.cursor.clone();
         if cursor.is_none() || output.followers.is_empty() {
+            cursor = None;
             break;
         }
     }
@@ -77,5 +102,8 @@ pub async fn fetch_followers(
         "Collected followers"
     );
 
-    Ok(followers)
+    Ok(FollowerWindow {
+        followers,
+        next_cursor: cursor,
+    })
 }
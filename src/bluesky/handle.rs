@@ -0,0 +1,199 @@
+// Handle and DID syntax checks — run before any network call.
+//
+// A typo'd handle otherwise surfaces as a confusing resolveHandle failure
+// deep in the stack. These checks follow the AT Protocol handle and DID
+// syntax rules closely enough to catch user error; they don't prove the
+// account exists.
+
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use anyhow::{Context, Result};
+
+/// Maximum length of a handle, per the AT Protocol spec.
+const MAX_HANDLE_LEN: usize = 253;
+
+/// Maximum length of a single dot-separated handle segment.
+const MAX_SEGMENT_LEN: usize = 63;
+
+/// Check basic AT Protocol handle syntax: at least two dot-separated
+/// segments of ASCII letters, digits and hyphens, none starting or ending
+/// with a hyphen, and a final segment that starts with a letter.
+///
+/// Expects the bare handle — strip any leading `@` first.
+pub fn is_valid_handle(handle: &str) -> bool {
+    if handle.is_empty() || handle.len() > MAX_HANDLE_LEN {
+        return false;
+    }
+
+    let segments: Vec<&str> = handle.split('.').collect();
+    if segments.len() < 2 {
+        return false;
+    }
+
+    let segments_ok = segments.iter().all(|seg| {
+        !seg.is_empty()
+            && seg.len() <= MAX_SEGMENT_LEN
+            && !seg.starts_with('-')
+            && !seg.ends_with('-')
+            && seg.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    });
+
+    // The TLD can't be all digits (and can't start with one)
+    let tld_ok = segments
+        .last()
+        .and_then(|tld| tld.chars().next())
+        .is_some_and(|c| c.is_ascii_alphabetic());
+
+    segments_ok && tld_ok
+}
+
+/// Check basic DID syntax: `did:<method>:<identifier>` with a lowercase
+/// method name and a non-empty identifier.
+pub fn is_did(s: &str) -> bool {
+    let Some(rest) = s.strip_prefix("did:") else {
+        return false;
+    };
+    let Some((method, id)) = rest.split_once(':') else {
+        return false;
+    };
+    !method.is_empty()
+        && method.chars().all(|c| c.is_ascii_lowercase())
+        && !id.is_empty()
+        && !id.ends_with(':')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | ':' | '%' | '-'))
+}
+
+/// Web profile URL prefixes that `normalize` strips, scheme first.
+const PROFILE_URL_PREFIXES: [&str; 2] = ["bsky.app/profile/", "www.bsky.app/profile/"];
+
+/// Normalize a user-supplied handle or DID before it's used in an API call.
+///
+/// Strips surrounding whitespace, a `https://bsky.app/profile/` URL (with or
+/// without the scheme, and dropping anything after the handle such as
+/// `/post/...`), and a leading `@`. DIDs pass through unchanged; handles are
+/// lowercased and checked against the AT Protocol handle grammar, with a
+/// clear error for anything malformed.
+pub fn normalize(input: &str) -> Result<String> {
+    let trimmed = input.trim();
+    let without_scheme = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+        .unwrap_or(trimmed);
+    let account = PROFILE_URL_PREFIXES
+        .iter()
+        .find_map(|prefix| without_scheme.strip_prefix(prefix))
+        .map(|path| path.split(['/', '?', '#']).next().unwrap_or_default())
+        .unwrap_or(trimmed);
+    let account = account.strip_prefix('@').unwrap_or(account);
+
+    if is_did(account) {
+        return Ok(account.to_string());
+    }
+
+    let handle = account.to_lowercase();
+    if handle.is_empty() {
+        anyhow::bail!("\"{input}\" doesn't contain a handle or DID.");
+    }
+    if handle.ends_with('.') {
+        anyhow::bail!(
+            "\"{input}\" ends with a dot. Handles are written without one, \
+             e.g. someone.bsky.social."
+        );
+    }
+    if !is_valid_handle(&handle) {
+        anyhow::bail!(
+            "\"{input}\" doesn't look like a valid handle. \
+             Expected something like someone.bsky.social (or a did:plc:... DID)."
+        );
+    }
+    Ok(handle)
+}
+
+/// An account reference typed by the user — either a handle or a DID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Actor {
+    /// A normalized handle (lowercase, no leading `@`)
+    Handle(String),
+    /// A DID, used as-is
+    Did(String),
+}
+
+/// Parse a user-supplied account reference, rejecting anything that isn't
+/// a syntactically valid handle or DID before it reaches the network.
+///
+/// Accepts `@handle`, `handle`, a bsky.app profile URL, or a DID (see
+/// [`normalize`]). Handles are lowercased since they're case-insensitive.
+pub fn parse_actor(input: &str) -> Result<Actor> {
+    let account = normalize(input)?;
+    if is_did(&account) {
+        Ok(Actor::Did(account))
+    } else {
+        Ok(Actor::Handle(account))
+    }
+}
+
+/// Read a newline-separated list of accounts, e.g. from stdin in
+/// `cat handles.txt | charcoal score -`.
+///
+/// Blank lines and `#` comments are skipped, each entry is normalized with
+/// [`parse_actor`], and duplicates are dropped (first occurrence wins). An
+/// invalid entry fails the whole list with its line number, and a list with
+/// no entries at all is an error rather than a silent no-op.
+pub fn read_actor_list(reader: impl BufRead) -> Result<Vec<Actor>> {
+    let mut seen = HashSet::new();
+    let mut actors = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.context("Failed to read handle list")?;
+        let entry = line.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+
+        let actor = parse_actor(entry).with_context(|| format!("Line {}", i + 1))?;
+        if seen.insert(actor.clone()) {
+            actors.push(actor);
+        }
+    }
+
+    if actors.is_empty() {
+        anyhow::bail!(
+            "No handles found on stdin. Pipe one handle or DID per line, \
+             e.g. `cat handles.txt | charcoal score -`."
+        );
+    }
+
+    Ok(actors)
+}
+
+/// Parse the account arguments of `charcoal score`: each is a handle or DID,
+/// and `-` reads a list from `stdin` (see [`read_actor_list`]) in its place.
+///
+/// Every argument is validated before anything is scored, and duplicates are
+/// dropped (first occurrence wins) so `score a b a` scores two accounts.
+pub fn parse_actor_args(args: &[String], stdin: impl BufRead) -> Result<Vec<Actor>> {
+    let mut stdin = Some(stdin);
+    let mut seen = HashSet::new();
+    let mut actors = Vec::new();
+
+    for arg in args {
+        let parsed = if arg == "-" {
+            let reader = stdin
+                .take()
+                .context("`-` (read from stdin) can only be given once")?;
+            read_actor_list(reader)?
+        } else {
+            vec![parse_actor(arg)?]
+        };
+        for actor in parsed {
+            if seen.insert(actor.clone()) {
+                actors.push(actor);
+            }
+        }
+    }
+
+    Ok(actors)
+}
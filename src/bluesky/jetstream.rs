@@ -0,0 +1,199 @@
+// Jetstream firehose ingestion — quotes and reposts as they happen.
+//
+// Jetstream re-encodes the AT Protocol firehose as JSON over a WebSocket.
+// Constellation is an index queried after the fact and can lag or miss
+// records; Jetstream delivers every new record the moment it's created.
+// `charcoal stream` subscribes to post and repost creates, keeps the ones
+// that quote or repost one of the protected user's posts, and feeds them to
+// the amplification pipeline.
+//
+// Message parsing is always compiled so it can be tested offline. The
+// WebSocket connection itself needs the `jetstream` feature.
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use crate::bluesky::amplification::{tid_timestamp, AmplificationNotification};
+
+/// Public Jetstream instance run by Bluesky.
+pub const DEFAULT_JETSTREAM_URL: &str = "wss://jetstream2.us-east.bsky.network/subscribe";
+
+/// Collections the subscription asks for — quotes are posts, reposts have
+/// their own collection.
+const WANTED_COLLECTIONS: [&str; 2] = ["app.bsky.feed.post", "app.bsky.feed.repost"];
+
+/// One Jetstream event. Only commit events carry records; identity and
+/// account events are skipped.
+#[derive(Debug, Deserialize)]
+pub struct JetstreamMessage {
+    pub did: String,
+    /// Server time of the event in unix microseconds — also the resume cursor
+    pub time_us: i64,
+    pub kind: String,
+    #[serde(default)]
+    pub commit: Option<JetstreamCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JetstreamCommit {
+    /// "create", "update", or "delete"
+    pub operation: String,
+    pub collection: String,
+    pub rkey: String,
+    /// The record itself (absent on deletes)
+    #[serde(default)]
+    pub record: Option<serde_json::Value>,
+}
+
+/// The subscription URL for `base`, resuming from `cursor` when given.
+pub fn subscribe_url(base: &str, cursor: Option<i64>) -> String {
+    let mut url = base.to_string();
+    let mut sep = if url.contains('?') { '&' } else { '?' };
+    for collection in WANTED_COLLECTIONS {
+        url.push(sep);
+        url.push_str("wantedCollections=");
+        url.push_str(collection);
+        sep = '&';
+    }
+    if let Some(cursor) = cursor {
+        url.push_str(&format!("&cursor={cursor}"));
+    }
+    url
+}
+
+/// Parse one WebSocket text frame. Malformed frames return None.
+pub fn parse_message(text: &str) -> Option<JetstreamMessage> {
+    serde_json::from_str(text).ok()
+}
+
+/// The amplification event in a message, if it quotes or reposts one of the
+/// `watched` post URIs. The protected user amplifying their own post
+/// doesn't count.
+pub fn amplification_event(
+    message: &JetstreamMessage,
+    watched: &HashSet<String>,
+    protected_did: &str,
+) -> Option<AmplificationNotification> {
+    if message.kind != "commit" || message.did == protected_did {
+        return None;
+    }
+    let commit = message.commit.as_ref()?;
+    if commit.operation != "create" {
+        return None;
+    }
+    let (event_type, original_uri) = amplified_post(&commit.collection, commit.record.as_ref()?)?;
+    if !watched.contains(&original_uri) {
+        return None;
+    }
+
+    Some(AmplificationNotification {
+        event_type: event_type.to_string(),
+        amplifier_did: message.did.clone(),
+        amplifier_handle: message.did.clone(),
+        original_post_uri: Some(original_uri),
+        amplifier_post_uri: format!("at://{}/{}/{}", message.did, commit.collection, commit.rkey),
+        indexed_at: String::new(),
+        occurred_at: tid_timestamp(&commit.rkey),
+    })
+}
+
+/// The event type and subject URI of a record that quotes or reposts
+/// another post. Quotes embed the post either alone or alongside media.
+fn amplified_post(collection: &str, record: &serde_json::Value) -> Option<(&'static str, String)> {
+    let uri = match collection {
+        "app.bsky.feed.repost" => return Some(("repost", string_at(record, "/subject/uri")?)),
+        "app.bsky.feed.post" => {
+            let embed = record.get("embed")?;
+            match embed.get("$type")?.as_str()? {
+                "app.bsky.embed.record" => string_at(embed, "/record/uri")?,
+                "app.bsky.embed.recordWithMedia" => string_at(embed, "/record/record/uri")?,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(("quote", uri))
+}
+
+fn string_at(value: &serde_json::Value, pointer: &str) -> Option<String> {
+    value.pointer(pointer)?.as_str().map(str::to_string)
+}
+
+#[cfg(feature = "jetstream")]
+pub use connection::stream_events;
+
+#[cfg(feature = "jetstream")]
+mod connection {
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use tokio::sync::mpsc;
+    use tokio_tungstenite::tungstenite::Message;
+    use tracing::{info, warn};
+
+    use super::{amplification_event, parse_message, subscribe_url};
+    use crate::bluesky::amplification::AmplificationNotification;
+
+    /// Longest wait between reconnect attempts.
+    const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+    /// Stream amplification events of the `watched` posts into `tx` until
+    /// the receiver is dropped.
+    ///
+    /// Dropped connections are retried with exponential backoff and resume
+    /// from the last event seen, so a brief outage loses nothing (Jetstream
+    /// replays from the cursor). Replayed events can repeat; callers dedup.
+    pub async fn stream_events(
+        url: String,
+        watched: HashSet<String>,
+        protected_did: String,
+        tx: mpsc::Sender<AmplificationNotification>,
+    ) {
+        let mut cursor: Option<i64> = None;
+        let mut backoff = Duration::from_secs(1);
+
+        loop {
+            let endpoint = subscribe_url(&url, cursor);
+            match tokio_tungstenite::connect_async(endpoint.as_str()).await {
+                Ok((mut socket, _)) => {
+                    info!(url = url.as_str(), "Connected to Jetstream");
+                    backoff = Duration::from_secs(1);
+
+                    while let Some(frame) = socket.next().await {
+                        let text = match frame {
+                            Ok(Message::Text(text)) => text,
+                            Ok(Message::Close(_)) => break,
+                            Ok(_) => continue,
+                            Err(e) => {
+                                warn!(error = %e, "Jetstream connection error");
+                                break;
+                            }
+                        };
+                        let Some(message) = parse_message(text.as_str()) else {
+                            continue;
+                        };
+                        cursor = Some(message.time_us);
+                        if let Some(event) = amplification_event(&message, &watched, &protected_did)
+                        {
+                            if tx.send(event).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!(error = %e, url = url.as_str(), "Failed to connect to Jetstream");
+                }
+            }
+
+            if tx.is_closed() {
+                return;
+            }
+            warn!(retry_in = ?backoff, "Jetstream disconnected, reconnecting");
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// Labeler verdicts via com.atproto.label.queryLabels.
+//
+// Bluesky labelers (the official moderation service among them) already flag
+// accounts for spam, impersonation, intolerance and the like. An account
+// carrying one of those labels from a labeler the user trusts gets its threat
+// score multiplied by `LABEL_THREAT_WEIGHT`, and the labels are kept with its
+// behavioral signals so reports can show why.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::debug;
+
+use super::client::PublicAtpClient;
+
+/// DID of the official Bluesky moderation service — the default trusted
+/// labeler.
+pub const DEFAULT_LABELER_DID: &str = "did:plc:ar7c4by46qjdydhdevvrndac";
+
+/// Label values that count against an account. Content-warning labels
+/// (adult content, graphic media) say nothing about hostility and are left
+/// out.
+pub const NEGATIVE_LABELS: &[&str] = &[
+    "!takedown",
+    "!hide",
+    "spam",
+    "impersonation",
+    "intolerant",
+    "threat",
+    "rude",
+    "engagement-farming",
+];
+
+/// Threat score multiplier for an account with at least one negative label
+/// from a trusted labeler. Applied alongside the graph distance weight, after
+/// the benign gate.
+pub const LABEL_THREAT_WEIGHT: f64 = 1.5;
+
+/// Labels returned per queryLabels request (the API maximum).
+const QUERY_LIMIT: &str = "250";
+
+/// One label applied to an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountLabel {
+    /// DID of the labeler that applied it
+    pub src: String,
+    /// The label value, e.g. "spam"
+    pub val: String,
+    /// Whether this retracts an earlier label with the same `src` and `val`
+    pub neg: bool,
+    /// When the label was created (RFC 3339)
+    pub cts: String,
+    /// When the label stops applying (RFC 3339), if it expires
+    pub exp: Option<String>,
+}
+
+/// Parse the raw JSON response from `com.atproto.label.queryLabels`.
+///
+/// Labels missing `src` or `val` are skipped.
+pub fn parse_query_labels_response(json: &serde_json::Value) -> Vec<AccountLabel> {
+    let Some(labels) = json["labels"].as_array() else {
+        return Vec::new();
+    };
+
+    labels
+        .iter()
+        .filter_map(|label| {
+            let src = label["src"].as_str().filter(|s| !s.is_empty())?;
+            let val = label["val"].as_str().filter(|v| !v.is_empty())?;
+            Some(AccountLabel {
+                src: src.to_string(),
+                val: val.to_string(),
+                neg: label["neg"].as_bool().unwrap_or(false),
+                cts: label["cts"].as_str().unwrap_or_default().to_string(),
+                exp: label["exp"].as_str().map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+/// The negative label values currently applied by any of `trusted`, sorted
+/// and deduplicated.
+///
+/// Labels are replayed in creation order so a later negation retracts an
+/// earlier label; expired labels (`exp` before `now`) don't count.
+pub fn negative_labels(
+    labels: &[AccountLabel],
+    trusted: &[String],
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let mut ordered: Vec<&AccountLabel> = labels
+        .iter()
+        .filter(|l| trusted.contains(&l.src) && NEGATIVE_LABELS.contains(&l.val.as_str()))
+        .collect();
+    ordered.sort_by(|a, b| a.cts.cmp(&b.cts));
+
+    let mut active: BTreeMap<(&str, &str), bool> = BTreeMap::new();
+    for label in ordered {
+        let expired = label
+            .exp
+            .as_deref()
+            .and_then(|exp| DateTime::parse_from_rfc3339(exp).ok())
+            .is_some_and(|exp| exp <= now);
+        let live = !label.neg && !expired;
+        active.insert((label.src.as_str(), label.val.as_str()), live);
+    }
+
+    active
+        .into_iter()
+        .filter(|(_, live)| *live)
+        .map(|((_, val), _)| val.to_string())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Threat weight for an account carrying `negative` labels: 1.0 (no
+/// change) when there are none, `LABEL_THREAT_WEIGHT` otherwise.
+pub fn label_weight(negative: &[String]) -> f64 {
+    if negative.is_empty() {
+        1.0
+    } else {
+        LABEL_THREAT_WEIGHT
+    }
+}
+
+/// Fetch the labels `labelers` have applied to the account `did`.
+///
+/// Only account-level labels are returned (the subject URI is the bare DID),
+/// not labels on individual posts. With no labelers there's nothing to ask
+/// and no request is made.
+pub async fn fetch_account_labels(
+    client: &PublicAtpClient,
+    did: &str,
+    labelers: &[String],
+) -> Result<Vec<AccountLabel>> {
+    if labelers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut params: Vec<(&str, &str)> = vec![("uriPatterns", did), ("limit", QUERY_LIMIT)];
+    params.extend(labelers.iter().map(|src| ("sources", src.as_str())));
+
+    let json: serde_json::Value = client
+        .xrpc_get("com.atproto.label.queryLabels", &params)
+        .await?;
+    let labels = parse_query_labels_response(&json);
+    debug!(did = did, labels = labels.len(), "Fetched account labels");
+    Ok(labels)
+}
@@ -4,10 +4,18 @@
 // the AT Protocol API surface. All endpoints are public (read-only).
 
 pub mod amplification;
+pub mod cache;
 pub mod client;
 pub mod followers;
+pub mod handle;
+pub mod jetstream;
+pub mod labels;
 pub mod likes;
+pub mod pagination;
 pub mod posts;
 pub mod profiles;
+pub mod quotes;
 pub mod relationships;
 pub mod replies;
+
+pub use handle::{is_did, is_valid_handle, parse_actor, parse_actor_args, read_actor_list, Actor};
@@ -0,0 +1,70 @@
+// Cursor pagination with one page of prefetch.
+//
+// AT Protocol listings are cursor-based: page N+1 can't be requested until
+// page N's response names its cursor, so pages can't be fetched in
+// parallel. What can overlap is the work on each page — as soon as a page
+// arrives, the request for the next one goes out, and the page is processed
+// while that request is in flight.
+
+use anyhow::Result;
+use std::future::Future;
+use std::task::Poll;
+
+/// One page of a cursor-paginated listing.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor for the next page; `None` on the last page
+    pub cursor: Option<String>,
+}
+
+/// Walk a listing until `target` items are collected or it runs out.
+///
+/// `fetch` requests the page after a cursor (`None` for the first page).
+/// `consume` processes a page and returns the running total of items
+/// collected — which may be lower than the raw item count when the caller
+/// filters. The next page is requested before `consume` runs on the current
+/// one, but only when the current page can't reach `target` on its own, so
+/// no request is ever made for a page that won't be needed.
+pub async fn prefetch_pages<T, F, Fut, C>(target: usize, mut fetch: F, mut consume: C) -> Result<()>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<Page<T>>>,
+    C: FnMut(Vec<T>) -> usize,
+{
+    if target == 0 {
+        return Ok(());
+    }
+
+    let mut collected = 0;
+    let mut page = fetch(None).await?;
+    loop {
+        let cursor = page.cursor.take().filter(|_| !page.items.is_empty());
+
+        match cursor {
+            // Even if every item counts, this page falls short — start the
+            // next request first, then process the page while it's in flight
+            Some(cursor) if collected + page.items.len() < target => {
+                let mut next = Box::pin(fetch(Some(cursor)));
+                let early = futures::poll!(next.as_mut());
+                collected = consume(page.items);
+                page = match early {
+                    Poll::Ready(result) => result?,
+                    Poll::Pending => next.await?,
+                };
+            }
+            // This page may be enough — only ask for more if it wasn't
+            Some(cursor) => {
+                collected = consume(page.items);
+                if collected >= target {
+                    return Ok(());
+                }
+                page = fetch(Some(cursor)).await?;
+            }
+            None => {
+                consume(page.items);
+                return Ok(());
+            }
+        }
+    }
+}
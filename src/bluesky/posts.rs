@@ -3,14 +3,19 @@
 // Fetches a user's recent posts from Bluesky. Used both for building the
 // protected user's topic fingerprint (Step 0) and for analyzing target
 // accounts' posting history (toxicity scoring).
+//
+// The fingerprint can also be built from a local JSON export instead of the
+// live feed (`charcoal fingerprint --from-file`); see `load_posts_file`.
 
 use anyhow::{Context, Result};
+use atrium_api::app::bsky::feed::defs::FeedViewPost;
 use atrium_api::app::bsky::feed::{get_author_feed, get_posts};
 use atrium_api::types::TryFromUnknown;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
 
 use super::client::PublicAtpClient;
+use super::pagination::{prefetch_pages, Page};
 
 /// A simplified post — just the fields Charcoal needs for analysis.
 #[derive(Debug, Clone)]
@@ -23,6 +28,72 @@ pub struct Post {
     pub quote_count: i64,
     /// Whether this post is a quote-post (embeds another post).
     pub is_quote: bool,
+    /// Languages declared on the post record (BCP-47 tags, may be empty)
+    pub langs: Vec<String>,
+}
+
+/// One post as written in a local export file: `text` is required, `uri`
+/// and `created_at` are optional.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExportedPost {
+    text: String,
+    #[serde(default)]
+    uri: String,
+    #[serde(default)]
+    created_at: Option<String>,
+}
+
+impl From<ExportedPost> for Post {
+    fn from(exported: ExportedPost) -> Self {
+        Post {
+            uri: exported.uri,
+            text: exported.text,
+            created_at: exported.created_at,
+            like_count: 0,
+            repost_count: 0,
+            quote_count: 0,
+            is_quote: false,
+            langs: Vec::new(),
+        }
+    }
+}
+
+/// Parse a posts export: a JSON array of `{"text", "uri", "created_at"}`
+/// objects. Engagement counts aren't part of an export and come back as 0.
+///
+/// Errors name the problem — not an array, a post missing `text`, an
+/// unexpected field — with serde's line and column.
+pub fn parse_posts_export(json: &str) -> Result<Vec<Post>> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).context("Posts export is not valid JSON")?;
+    let Some(items) = value.as_array() else {
+        anyhow::bail!(
+            "Posts export must be a JSON array of {{\"text\", \"uri\", \"created_at\"}} objects"
+        );
+    };
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            ExportedPost::deserialize(item)
+                .map(Post::from)
+                .with_context(|| format!("Post {} in the export is malformed", i + 1))
+        })
+        .collect()
+}
+
+/// Read a posts export file (see `parse_posts_export`). An export with no
+/// posts is an error, since there's nothing to build a fingerprint from.
+pub fn load_posts_file(path: &std::path::Path) -> Result<Vec<Post>> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read posts export {}", path.display()))?;
+    let posts = parse_posts_export(&json)
+        .with_context(|| format!("Invalid posts export {}", path.display()))?;
+    if posts.is_empty() {
+        anyhow::bail!("Posts export {} contains no posts", path.display());
+    }
+    Ok(posts)
 }
 
 /// A reply post with its parent URI for context pair formation.
@@ -33,6 +104,21 @@ pub struct ReplyPost {
     pub parent_uri: String,
 }
 
+/// The language tags declared on a post record, as plain strings.
+fn record_langs(record: &atrium_api::app::bsky::feed::post::Record) -> Vec<String> {
+    record
+        .data
+        .langs
+        .iter()
+        .flatten()
+        .filter_map(|lang| {
+            serde_json::to_value(lang)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+        })
+        .collect()
+}
+
 /// Partitioned post sample from an account's feed.
 ///
 /// Separates posts into originals, replies, and quotes so different
@@ -56,6 +142,41 @@ pub struct PostSample {
     pub total_posts: usize,
 }
 
+impl PostSample {
+    /// Timestamp of the newest post in the sample, normalized to RFC 3339
+    /// UTC so stored values compare correctly as text. `None` if no post
+    /// carries a parseable timestamp.
+    pub fn last_post_at(&self) -> Option<String> {
+        self.originals
+            .iter()
+            .chain(self.replies.iter().map(|r| &r.post))
+            .chain(self.quotes.iter())
+            .filter_map(|p| p.created_at.as_deref())
+            .filter_map(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .max()
+            .map(|t| {
+                t.with_timezone(&chrono::Utc)
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            })
+    }
+
+    /// Add replies fetched separately (see `fetch_recent_replies`), skipping
+    /// any the sample already holds. `total_posts` grows with them, but the
+    /// reply and quote ratios keep describing the feed sample: replies
+    /// fetched on purpose would make every account look reply-heavy.
+    pub fn with_extra_replies(mut self, extra: Vec<ReplyPost>) -> Self {
+        let mut seen: std::collections::HashSet<String> =
+            self.replies.iter().map(|r| r.post.uri.clone()).collect();
+        for reply in extra {
+            if seen.insert(reply.post.uri.clone()) {
+                self.replies.push(reply);
+                self.total_posts += 1;
+            }
+        }
+        self
+    }
+}
+
 /// Quality of a topic fingerprint based on data availability.
 ///
 /// When an account is reply-heavy, fingerprinting from originals alone
@@ -98,6 +219,13 @@ impl FingerprintQuality {
     }
 }
 
+/// Posts requested per author-feed page unless configured otherwise — the
+/// API maximum.
+pub const DEFAULT_FEED_PAGE_SIZE: usize = 100;
+
+/// Largest page the author-feed API returns.
+pub const MAX_FEED_PAGE_SIZE: usize = 100;
+
 /// Fetch recent posts for a given account, handling pagination automatically.
 ///
 /// `max_posts` controls how many posts to collect (the API returns up to 100 per
@@ -107,18 +235,34 @@ pub async fn fetch_recent_posts(
     client: &PublicAtpClient,
     handle: &str,
     max_posts: usize,
+) -> crate::error::Result<Vec<Post>> {
+    Ok(fetch_recent_posts_paged(client, handle, max_posts, DEFAULT_FEED_PAGE_SIZE).await?)
+}
+
+/// [`fetch_recent_posts`] with an explicit page size (clamped to 1..=100).
+///
+/// The next page is requested while the current one is being cleaned (see
+/// [`prefetch_pages`]), and exactly `max_posts` posts come back when the
+/// feed has that many.
+pub async fn fetch_recent_posts_paged(
+    client: &PublicAtpClient,
+    handle: &str,
+    max_posts: usize,
+    page_size: usize,
 ) -> Result<Vec<Post>> {
     let mut posts = Vec::new();
-    let mut cursor: Option<String> = None;
 
-    // How many to request per page (API max is 100).
-    let page_size = max_posts.min(100).to_string();
+    let page_size = page_size
+        .clamp(1, MAX_FEED_PAGE_SIZE)
+        .min(max_posts.max(1))
+        .to_string();
+    let page_size = page_size.as_str();
 
-    loop {
+    let fetch = |cursor: Option<String>| async move {
         let mut params: Vec<(&str, &str)> = vec![
             ("actor", handle),
             ("filter", "posts_no_replies"),
-            ("limit", &page_size),
+            ("limit", page_size),
         ];
         if let Some(ref c) = cursor {
             params.push(("cursor", c));
@@ -128,8 +272,19 @@ pub async fn fetch_recent_posts(
             .xrpc_get("app.bsky.feed.getAuthorFeed", &params)
             .await
             .with_context(|| format!("Failed to fetch feed for @{}", handle))?;
+        Ok::<_, anyhow::Error>(Page {
+            items: output.data.feed,
+            cursor: output.data.cursor,
+        })
+    };
+
+    let consume = |feed: Vec<FeedViewPost>| {
+        let page_posts = feed.len();
+        for feed_item in &feed {
+            if posts.len() >= max_posts {
+                break;
+            }
 
-        for feed_item in &output.feed {
             // Skip reposts — we only want posts authored by this account.
             // Reposts show up with a `reason` of ReasonRepost.
             if feed_item.reason.is_some() {
@@ -141,10 +296,10 @@ pub async fn fetch_recent_posts(
             // Decode the record to get the post text.
             // The record field is an untyped IPLD value — we deserialize it
             // into the typed post::Record to access the text.
-            let text = atrium_api::app::bsky::feed::post::Record::try_from_unknown(
+            let (text, langs) = atrium_api::app::bsky::feed::post::Record::try_from_unknown(
                 post_view.record.clone(),
             )
-            .map(|record| record.data.text.clone())
+            .map(|record| (record.data.text.clone(), record_langs(&record)))
             .unwrap_or_default();
 
             // Skip empty posts and very short posts (likely just links/images).
@@ -175,30 +330,20 @@ pub async fn fetch_recent_posts(
                 repost_count: post_view.repost_count.unwrap_or(0),
                 quote_count: post_view.quote_count.unwrap_or(0),
                 is_quote,
+                langs,
             });
-
-            if posts.len() >= max_posts {
-                break;
-            }
         }
 
         debug!(
-            page_posts = output.feed.len(),
+            page_posts,
             total_collected = posts.len(),
             "Fetched page of posts for @{}",
             handle
         );
+        posts.len()
+    };
 
-        // Stop if we have enough posts or there are no more pages
-        if posts.len() >= max_posts {
-            break;
-        }
-
-        cursor = output.data.cursor.clone();
-        if cursor.is_none() || output.feed.is_empty() {
-            break;
-        }
-    }
+    prefetch_pages(max_posts, fetch, consume).await?;
 
     info!(
         count = posts.len(),
@@ -209,6 +354,64 @@ pub async fn fetch_recent_posts(
     Ok(posts)
 }
 
+/// Decode a feed item into a `Post` authored by the feed's account, with
+/// the parent post's URI when it's a reply. `None` for reposts, undecodable
+/// records and posts too short to analyze.
+fn authored_post(feed_item: &FeedViewPost) -> Option<(Post, Option<String>)> {
+    // Skip reposts — we only want posts authored by this account.
+    if feed_item.reason.is_some() {
+        return None;
+    }
+
+    let post_view = &feed_item.post;
+
+    // Decode the record to get the post text and reply reference.
+    let record =
+        atrium_api::app::bsky::feed::post::Record::try_from_unknown(post_view.record.clone())
+            .ok()?;
+
+    let text = record.data.text.clone();
+
+    // Skip empty posts and very short posts (likely just links/images).
+    if text.chars().count() < 15 {
+        return None;
+    }
+
+    // Detect quote-posts by checking the embed type.
+    let is_quote = post_view.embed.as_ref().is_some_and(|embed| {
+        use atrium_api::types::Union;
+        matches!(
+            embed,
+            Union::Refs(
+                atrium_api::app::bsky::feed::defs::PostViewEmbedRefs::AppBskyEmbedRecordView(_)
+                    | atrium_api::app::bsky::feed::defs::PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(_)
+            )
+        )
+    });
+
+    let post = Post {
+        uri: post_view.uri.clone(),
+        text,
+        created_at: Some(post_view.indexed_at.as_ref().to_string()),
+        like_count: post_view.like_count.unwrap_or(0),
+        repost_count: post_view.repost_count.unwrap_or(0),
+        quote_count: post_view.quote_count.unwrap_or(0),
+        is_quote,
+        langs: record_langs(&record),
+    };
+
+    // Edge case: feed says it's a reply but there's no parent URI in the
+    // record. Treat it as an original.
+    let parent_uri = feed_item
+        .reply
+        .as_ref()
+        .and(record.data.reply.as_ref())
+        .map(|r| r.parent.uri.clone())
+        .filter(|uri| !uri.is_empty());
+
+    Some((post, parent_uri))
+}
+
 /// Fetch recent posts with replies included, partitioned into a PostSample.
 ///
 /// Uses the `posts_with_replies` filter to get both original posts and replies
@@ -247,73 +450,18 @@ pub async fn fetch_posts_with_replies(
             .with_context(|| format!("Failed to fetch feed for @{}", handle))?;
 
         for feed_item in &output.feed {
-            // Skip reposts — we only want posts authored by this account.
-            if feed_item.reason.is_some() {
+            let Some((post, parent_uri)) = authored_post(feed_item) else {
                 continue;
-            }
-
-            let post_view = &feed_item.post;
-
-            // Decode the record to get the post text and reply reference.
-            let record = match atrium_api::app::bsky::feed::post::Record::try_from_unknown(
-                post_view.record.clone(),
-            ) {
-                Ok(r) => r,
-                Err(_) => continue,
-            };
-
-            let text = record.data.text.clone();
-
-            // Skip empty posts and very short posts (likely just links/images).
-            if text.chars().count() < 15 {
-                continue;
-            }
-
-            // Detect quote-posts by checking the embed type.
-            let is_quote = post_view.embed.as_ref().is_some_and(|embed| {
-                use atrium_api::types::Union;
-                matches!(
-                    embed,
-                    Union::Refs(
-                        atrium_api::app::bsky::feed::defs::PostViewEmbedRefs::AppBskyEmbedRecordView(_)
-                            | atrium_api::app::bsky::feed::defs::PostViewEmbedRefs::AppBskyEmbedRecordWithMediaView(_)
-                    )
-                )
-            });
-
-            let post = Post {
-                uri: post_view.uri.clone(),
-                text,
-                created_at: Some(post_view.indexed_at.as_ref().to_string()),
-                like_count: post_view.like_count.unwrap_or(0),
-                repost_count: post_view.repost_count.unwrap_or(0),
-                quote_count: post_view.quote_count.unwrap_or(0),
-                is_quote,
             };
 
             total_collected += 1;
 
             // Classify: reply takes priority over quote (reply context is more
             // important for NLI pair scoring than the quote relationship).
-            if feed_item.reply.is_some() {
-                let parent_uri = record
-                    .data
-                    .reply
-                    .as_ref()
-                    .map(|r| r.parent.uri.clone())
-                    .unwrap_or_default();
-
-                if parent_uri.is_empty() {
-                    // Edge case: feed says it's a reply but no parent URI in record.
-                    // Treat as original.
-                    originals.push(post);
-                } else {
-                    replies.push(ReplyPost { post, parent_uri });
-                }
-            } else if is_quote {
-                quotes.push(post);
-            } else {
-                originals.push(post);
+            match parent_uri {
+                Some(parent_uri) => replies.push(ReplyPost { post, parent_uri }),
+                None if post.is_quote && feed_item.reply.is_none() => quotes.push(post),
+                None => originals.push(post),
             }
 
             if total_collected >= max_posts {
@@ -369,6 +517,73 @@ pub async fn fetch_posts_with_replies(
     })
 }
 
+/// Replies fetched per account when `CHARCOAL_SCORE_REPLIES` is on.
+pub const REPLY_SAMPLE_SIZE: usize = 50;
+
+/// Feed pages `fetch_recent_replies` reads before settling for fewer
+/// replies, so an account that rarely replies doesn't page back for ever.
+const MAX_REPLY_PAGES: usize = 3;
+
+/// Fetch an account's recent replies, newest first, up to `max_replies`.
+///
+/// `fetch_posts_with_replies` samples replies only in proportion to how
+/// often the account replies, so an account that harasses in a minority of
+/// its posts can show few of them. The author feed has no replies-only
+/// filter, so this pages through `posts_with_replies` and keeps just the
+/// replies, giving up after `MAX_REPLY_PAGES` pages.
+pub async fn fetch_recent_replies(
+    client: &PublicAtpClient,
+    handle: &str,
+    max_replies: usize,
+) -> Result<Vec<ReplyPost>> {
+    let mut replies = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    for _ in 0..MAX_REPLY_PAGES {
+        if replies.len() >= max_replies {
+            break;
+        }
+        let mut params: Vec<(&str, &str)> = vec![
+            ("actor", handle),
+            ("filter", "posts_with_replies"),
+            ("limit", "100"),
+        ];
+        if let Some(ref c) = cursor {
+            params.push(("cursor", c));
+        }
+
+        let output: get_author_feed::Output = client
+            .xrpc_get("app.bsky.feed.getAuthorFeed", &params)
+            .await
+            .with_context(|| format!("Failed to fetch replies for @{}", handle))?;
+
+        let wanted = max_replies - replies.len();
+        replies.extend(
+            output
+                .feed
+                .iter()
+                .filter_map(authored_post)
+                .filter_map(|(post, parent)| {
+                    parent.map(|parent_uri| ReplyPost { post, parent_uri })
+                })
+                .take(wanted),
+        );
+
+        cursor = output.data.cursor.clone();
+        if cursor.is_none() || output.feed.is_empty() {
+            break;
+        }
+    }
+
+    info!(
+        count = replies.len(),
+        handle = handle,
+        "Collected replies for analysis"
+    );
+
+    Ok(replies)
+}
+
 /// Fetch a single post's text by its AT URI.
 ///
 /// Used to retrieve quote-post text for amplification events. The Constellation
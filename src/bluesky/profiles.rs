@@ -2,7 +2,8 @@
 //
 // Used to resolve DIDs from Constellation backlinks into human-readable
 // handles. The `app.bsky.actor.getProfiles` endpoint accepts up to 25
-// actors per request.
+// actors per request. Single-profile lookups (follower counts) go through
+// `app.bsky.actor.getProfile`.
 
 use anyhow::Result;
 use std::collections::HashMap;
@@ -31,7 +32,7 @@ pub async fn resolve_dids_to_handles(
         }
 
         match client
-            .xrpc_get::<atrium_api::app::bsky::actor::get_profiles::Output>(
+            .xrpc_get_cached::<atrium_api::app::bsky::actor::get_profiles::Output>(
                 "app.bsky.actor.getProfiles",
                 &query_params,
             )
@@ -58,3 +59,12 @@ pub async fn resolve_dids_to_handles(
 
     Ok(result)
 }
+
+/// Look up how many followers an account has. `None` when the profile
+/// doesn't report a count.
+pub async fn fetch_follower_count(client: &PublicAtpClient, actor: &str) -> Result<Option<u64>> {
+    let json: serde_json::Value = client
+        .xrpc_get_cached("app.bsky.actor.getProfile", &[("actor", actor)])
+        .await?;
+    Ok(json["followersCount"].as_u64())
+}
@@ -0,0 +1,130 @@
+//! Quote and repost detection via the public AT Protocol API.
+//!
+//! Fallback for when Constellation is unreachable. Uses
+//! `app.bsky.feed.getQuotes` and `app.bsky.feed.getRepostedBy` to rebuild
+//! the same amplification events from the AppView. Unlike Constellation,
+//! the AppView hides accounts that block the protected user, and it doesn't
+//! return repost records, so repost events have no `amplifier_post_uri` or
+//! timestamp.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::bluesky::amplification::{
+    uri_timestamp, AmplificationNotification, AmplificationSource,
+};
+use crate::bluesky::client::PublicAtpClient;
+
+/// Most quotes or reposts fetched per post — one page, the same as a
+/// Constellation backlink query.
+pub const MAX_PER_POST: u32 = 100;
+
+/// The account behind a quote or repost.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuoteActor {
+    pub did: String,
+    pub handle: String,
+}
+
+/// A quote-post from the getQuotes response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuotePost {
+    pub uri: String,
+    pub author: QuoteActor,
+    #[serde(rename = "indexedAt", default)]
+    pub indexed_at: String,
+}
+
+/// Response from `app.bsky.feed.getQuotes`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuotesResponse {
+    pub posts: Vec<QuotePost>,
+    pub cursor: Option<String>,
+}
+
+/// Response from `app.bsky.feed.getRepostedBy`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RepostedByResponse {
+    #[serde(rename = "repostedBy")]
+    pub reposted_by: Vec<QuoteActor>,
+    pub cursor: Option<String>,
+}
+
+/// Quote events for `post_uri` from a getQuotes response.
+pub fn quote_events(post_uri: &str, resp: &QuotesResponse) -> Vec<AmplificationNotification> {
+    resp.posts
+        .iter()
+        .map(|post| AmplificationNotification {
+            event_type: "quote".to_string(),
+            amplifier_did: post.author.did.clone(),
+            amplifier_handle: post.author.handle.clone(),
+            original_post_uri: Some(post_uri.to_string()),
+            amplifier_post_uri: post.uri.clone(),
+            indexed_at: post.indexed_at.clone(),
+            occurred_at: uri_timestamp(&post.uri),
+        })
+        .collect()
+}
+
+/// Repost events for `post_uri` from a getRepostedBy response.
+pub fn repost_events(post_uri: &str, resp: &RepostedByResponse) -> Vec<AmplificationNotification> {
+    resp.reposted_by
+        .iter()
+        .map(|actor| AmplificationNotification {
+            event_type: "repost".to_string(),
+            amplifier_did: actor.did.clone(),
+            amplifier_handle: actor.handle.clone(),
+            original_post_uri: Some(post_uri.to_string()),
+            amplifier_post_uri: String::new(),
+            indexed_at: String::new(),
+            occurred_at: None,
+        })
+        .collect()
+}
+
+/// Amplification events from the public AppView.
+pub struct AppviewSource<'a> {
+    client: &'a PublicAtpClient,
+}
+
+impl<'a> AppviewSource<'a> {
+    pub fn new(client: &'a PublicAtpClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl AmplificationSource for AppviewSource<'_> {
+    fn name(&self) -> &'static str {
+        "appview"
+    }
+
+    async fn post_amplifications(&self, post_uri: &str) -> Result<Vec<AmplificationNotification>> {
+        let limit = MAX_PER_POST.to_string();
+        let params = [("uri", post_uri), ("limit", limit.as_str())];
+
+        let quotes: QuotesResponse = self
+            .client
+            .xrpc_get("app.bsky.feed.getQuotes", &params)
+            .await
+            .context("Failed to fetch quotes from the AppView")?;
+        let reposts: RepostedByResponse = self
+            .client
+            .xrpc_get("app.bsky.feed.getRepostedBy", &params)
+            .await
+            .context("Failed to fetch reposts from the AppView")?;
+
+        let mut events = quote_events(post_uri, &quotes);
+        events.extend(repost_events(post_uri, &reposts));
+
+        debug!(
+            post_uri = post_uri,
+            event_count = events.len(),
+            "Fetched amplification events via AppView"
+        );
+
+        Ok(events)
+    }
+}
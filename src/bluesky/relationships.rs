@@ -51,6 +51,22 @@ impl GraphDistance {
             GraphDistance::Stranger => 1.2,
         }
     }
+
+    /// Whether the other account follows the protected user.
+    pub fn follows_you(&self) -> bool {
+        matches!(
+            self,
+            GraphDistance::MutualFollow | GraphDistance::InboundFollow
+        )
+    }
+
+    /// Whether the protected user follows the other account.
+    pub fn followed_by_you(&self) -> bool {
+        matches!(
+            self,
+            GraphDistance::MutualFollow | GraphDistance::OutboundFollow
+        )
+    }
 }
 
 impl fmt::Display for GraphDistance {
@@ -143,3 +159,20 @@ pub async fn classify_relationships(
 
     Ok(all_results)
 }
+
+/// Classify a single account's graph distance to the protected user.
+///
+/// Unlike `classify_relationships`, a failed lookup is an error rather than
+/// a `Stranger` — callers scoring one account can treat the relationship as
+/// unknown instead of assuming the worst.
+pub async fn classify_relationship(
+    client: &PublicAtpClient,
+    protected_did: &str,
+    target_did: &str,
+) -> Result<Option<GraphDistance>> {
+    let params = [("actor", protected_did), ("others", target_did)];
+    let json = client
+        .xrpc_get::<serde_json::Value>("app.bsky.graph.getRelationships", &params)
+        .await?;
+    Ok(parse_relationships_response(&json)?.remove(target_did))
+}
@@ -5,10 +5,22 @@
 // format used by the notification pipeline, so they can be merged seamlessly.
 
 use anyhow::{Context, Result};
+use async_trait::async_trait;
 use serde::Deserialize;
 use tracing::{debug, warn};
 
-use crate::bluesky::amplification::AmplificationNotification;
+use crate::bluesky::amplification::{
+    tid_timestamp, AmplificationNotification, AmplificationSource,
+};
+use crate::error::Error;
+
+/// Constellation source path for quote-posts, which embed the original via
+/// `embed.record.uri`.
+pub const QUOTES_SOURCE: &str = "app.bsky.feed.post:embed.record.uri";
+
+/// Constellation source path for reposts, which reference the original via
+/// `subject.uri`.
+pub const REPOSTS_SOURCE: &str = "app.bsky.feed.repost:subject.uri";
 
 /// Constellation source path for like backlinks.
 pub const LIKES_SOURCE: &str = "app.bsky.feed.like:subject.uri";
@@ -37,9 +49,8 @@ pub struct ConstellationClient {
 
 impl ConstellationClient {
     /// Create a new Constellation client pointing at the given base URL.
-    pub fn new(base_url: &str) -> Result<Self> {
-        let client = reqwest::Client::builder()
-            .user_agent("charcoal/0.1 (threat-detection; @chaosgreml.in)")
+    pub fn new(base_url: &str) -> crate::error::Result<Self> {
+        let client = crate::http::client_builder()
             .build()
             .context("Failed to build HTTP client")?;
 
@@ -58,7 +69,7 @@ impl ConstellationClient {
         subject: &str,
         source: &str,
         limit: u32,
-    ) -> Result<BacklinksResponse> {
+    ) -> crate::error::Result<BacklinksResponse> {
         let url = format!("{}/xrpc/blue.microcosm.links.getBacklinks", self.base_url);
 
         let response = self
@@ -76,90 +87,74 @@ impl ConstellationClient {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            anyhow::bail!("Constellation API returned {}: {}", status, body);
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                return Err(Error::RateLimited {
+                    service: "Constellation".to_string(),
+                    message: body,
+                });
+            }
+            return Err(anyhow::anyhow!("Constellation API returned {}: {}", status, body).into());
         }
 
-        response
+        Ok(response
             .json::<BacklinksResponse>()
             .await
-            .context("Failed to parse Constellation response")
+            .context("Failed to parse Constellation response")?)
     }
 
-    /// Find all amplification events (quotes + reposts) for a set of post URIs.
+    /// Follow quote-of-quote chains below a set of quote-posts.
     ///
-    /// Queries Constellation for both quote-posts and reposts of each URI,
-    /// deduplicates by `amplifier_post_uri`, and returns events in the same
-    /// format as the notification pipeline.
-    pub async fn find_amplification_events(
+    /// `seed_edges` are the direct quotes already known, as
+    /// `(quoted_post_uri, quote_post_uri, quoter_did)`. Each quote-post is
+    /// queried for quotes of its own, level by level, down to `max_depth`
+    /// levels below the seeds. Returns the seeds plus every edge discovered,
+    /// ready for `scoring::behavioral::detect_quote_cascades`.
+    ///
+    /// Each level costs one Constellation request per post on the previous
+    /// level, so `max_depth` should stay small.
+    pub async fn find_quote_chains(
         &self,
-        post_uris: &[String],
-    ) -> Vec<AmplificationNotification> {
-        let mut events = Vec::new();
-        let mut seen_uris = std::collections::HashSet::new();
-
-        for uri in post_uris {
-            // Query for quote-posts referencing this URI
-            // Source format: collection:json_path — quotes embed the original via embed.record.uri
-            match self
-                .get_backlinks(uri, "app.bsky.feed.post:embed.record.uri", 100)
-                .await
-            {
-                Ok(resp) => {
-                    for record in &resp.records {
-                        let amp_uri =
-                            format!("at://{}/{}/{}", record.did, record.collection, record.rkey);
-                        if seen_uris.insert(amp_uri.clone()) {
-                            events.push(AmplificationNotification {
-                                event_type: "quote".to_string(),
-                                amplifier_did: record.did.clone(),
-                                amplifier_handle: record.did.clone(),
-                                original_post_uri: Some(uri.clone()),
-                                amplifier_post_uri: amp_uri,
-                                indexed_at: String::new(),
-                            });
+        seed_edges: Vec<(String, String, String)>,
+        max_depth: usize,
+    ) -> Vec<(String, String, String)> {
+        let mut seen_uris: std::collections::HashSet<String> =
+            seed_edges.iter().map(|(_, uri, _)| uri.clone()).collect();
+        let mut frontier: Vec<String> = seed_edges.iter().map(|(_, uri, _)| uri.clone()).collect();
+        let mut edges = seed_edges;
+
+        for _ in 0..max_depth {
+            let mut next = Vec::new();
+            for uri in &frontier {
+                match self.get_backlinks(uri, QUOTES_SOURCE, 100).await {
+                    Ok(resp) => {
+                        for record in &resp.records {
+                            let quote_uri = format!(
+                                "at://{}/{}/{}",
+                                record.did, record.collection, record.rkey
+                            );
+                            if seen_uris.insert(quote_uri.clone()) {
+                                edges.push((uri.clone(), quote_uri.clone(), record.did.clone()));
+                                next.push(quote_uri);
+                            }
                         }
                     }
-                }
-                Err(e) => {
-                    warn!(uri = uri, error = %e, "Failed to query Constellation for quotes");
-                }
-            }
-
-            // Query for reposts referencing this URI
-            // Source format: collection:json_path — reposts reference the original via subject.uri
-            match self
-                .get_backlinks(uri, "app.bsky.feed.repost:subject.uri", 100)
-                .await
-            {
-                Ok(resp) => {
-                    for record in &resp.records {
-                        let amp_uri =
-                            format!("at://{}/{}/{}", record.did, record.collection, record.rkey);
-                        if seen_uris.insert(amp_uri.clone()) {
-                            events.push(AmplificationNotification {
-                                event_type: "repost".to_string(),
-                                amplifier_did: record.did.clone(),
-                                amplifier_handle: record.did.clone(),
-                                original_post_uri: Some(uri.clone()),
-                                amplifier_post_uri: amp_uri,
-                                indexed_at: String::new(),
-                            });
-                        }
+                    Err(e) => {
+                        warn!(uri = uri, error = %e, "Failed to query Constellation for quote chain");
                     }
                 }
-                Err(e) => {
-                    warn!(uri = uri, error = %e, "Failed to query Constellation for reposts");
-                }
             }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
         }
 
         debug!(
-            total_events = events.len(),
-            post_count = post_uris.len(),
-            "Constellation backlink query complete"
+            edge_count = edges.len(),
+            "Constellation quote chain query complete"
         );
 
-        events
+        edges
     }
 
     /// Find accounts that liked the given post URIs via Constellation backlinks.
@@ -184,6 +179,7 @@ impl ConstellationClient {
                                 original_post_uri: Some(uri.clone()),
                                 amplifier_post_uri: String::new(),
                                 indexed_at: String::new(),
+                                occurred_at: tid_timestamp(&record.rkey),
                             });
                         }
                     }
@@ -203,3 +199,50 @@ impl ConstellationClient {
         results
     }
 }
+
+/// Events for the records in a backlinks response to `post_uri`.
+fn backlink_events(
+    post_uri: &str,
+    event_type: &str,
+    resp: &BacklinksResponse,
+) -> Vec<AmplificationNotification> {
+    resp.records
+        .iter()
+        .map(|record| AmplificationNotification {
+            event_type: event_type.to_string(),
+            amplifier_did: record.did.clone(),
+            amplifier_handle: record.did.clone(),
+            original_post_uri: Some(post_uri.to_string()),
+            amplifier_post_uri: format!(
+                "at://{}/{}/{}",
+                record.did, record.collection, record.rkey
+            ),
+            indexed_at: String::new(),
+            occurred_at: tid_timestamp(&record.rkey),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl AmplificationSource for ConstellationClient {
+    fn name(&self) -> &'static str {
+        "constellation"
+    }
+
+    /// Quote-posts and reposts of `post_uri` from the backlink index.
+    /// Handles are left as DIDs for the caller to resolve.
+    async fn post_amplifications(&self, post_uri: &str) -> Result<Vec<AmplificationNotification>> {
+        let quotes = self
+            .get_backlinks(post_uri, QUOTES_SOURCE, 100)
+            .await
+            .context("Failed to query Constellation for quotes")?;
+        let reposts = self
+            .get_backlinks(post_uri, REPOSTS_SOURCE, 100)
+            .await
+            .context("Failed to query Constellation for reposts")?;
+
+        let mut events = backlink_events(post_uri, "quote", &quotes);
+        events.extend(backlink_events(post_uri, "repost", &reposts));
+        Ok(events)
+    }
+}
@@ -5,6 +5,7 @@
 // including those from blocked/muted accounts and has 1+ years of indexed data.
 //
 // Constellation is the primary amplification detection source — it replaced
-// notification polling (which required authentication).
+// notification polling (which required authentication). When it can't be
+// reached, scans fall back to the public AppView (see `bluesky::quotes`).
 
 pub mod client;
@@ -0,0 +1,367 @@
+// Copy one user's data between database backends — backs `charcoal migrate`.
+//
+// Safe to re-run after an interruption. Fingerprint, embedding, scores, scan
+// state and whitelist entries are skipped when the destination already holds
+// an equal copy, and amplification events (which have no natural upsert key)
+// are resumed from a checkpoint stored in the destination's scan_state, with
+// a content-level duplicate check covering the gap between an insert and its
+// checkpoint write.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+
+use super::models::{AccountScore, AmplificationEvent};
+use super::timestamps::parse_timestamp;
+use super::Database;
+
+/// Destination scan_state key holding the highest source event ID copied.
+pub const EVENTS_CHECKPOINT_KEY: &str = "migrate_events_checkpoint";
+
+/// What a migration run copied versus found already present.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MigrationSummary {
+    /// Post count of the fingerprint copied this run
+    pub fingerprint_copied: Option<u32>,
+    /// Source had a fingerprint the destination already matched
+    pub fingerprint_unchanged: bool,
+    /// Dimension of the embedding copied this run
+    pub embedding_copied: Option<usize>,
+    pub scores_copied: usize,
+    pub scores_unchanged: usize,
+    pub events_copied: usize,
+    /// Events skipped via the checkpoint or because the destination had them
+    pub events_skipped: usize,
+    pub scan_state_copied: usize,
+    pub whitelist_copied: usize,
+}
+
+/// Copy everything stored for `user_did` from `source` to `dest`.
+///
+/// Both databases must already have the user registered.
+pub async fn migrate_user(
+    source: &dyn Database,
+    dest: &dyn Database,
+    user_did: &str,
+) -> Result<MigrationSummary> {
+    let mut summary = MigrationSummary::default();
+
+    // 1. Fingerprint + embedding
+    if let Some((json, count, _)) = source.get_fingerprint(user_did).await? {
+        let existing = dest.get_fingerprint(user_did).await?;
+        if existing.is_some_and(|(j, c, _)| j == json && c == count) {
+            summary.fingerprint_unchanged = true;
+        } else {
+            dest.save_fingerprint(user_did, &json, count).await?;
+            summary.fingerprint_copied = Some(count);
+        }
+
+        if let Some(embedding) = source.get_embedding(user_did).await? {
+            if dest.get_embedding(user_did).await?.as_ref() != Some(&embedding) {
+                dest.save_embedding(user_did, &embedding).await?;
+                summary.embedding_copied = Some(embedding.len());
+            }
+        }
+    }
+
+    // 2. Account scores — every row, including accounts that were never
+    // given a threat score, with their stored tiers. Each upsert also
+    // appends to the score history, so rows the destination already holds
+    // are skipped
+    let existing: HashMap<String, AccountScore> = dest
+        .get_all_account_scores(user_did)
+        .await?
+        .into_iter()
+        .map(|s| (s.did.clone(), s))
+        .collect();
+    for score in source.get_all_account_scores(user_did).await? {
+        if existing
+            .get(&score.did)
+            .is_some_and(|d| scores_match(d, &score))
+        {
+            summary.scores_unchanged += 1;
+            continue;
+        }
+        dest.upsert_account_score(user_did, &score, None).await?;
+        summary.scores_copied += 1;
+    }
+
+    // 3. Amplification events — oldest first so the checkpoint only moves
+    // forward. Use i32::MAX as the limit rather than u32::MAX to avoid an
+    // overflow when the Postgres backend casts the value to i32.
+    let checkpoint: i64 = dest
+        .get_scan_state(user_did, EVENTS_CHECKPOINT_KEY)
+        .await?
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+
+    let mut present: HashSet<EventKey> = dest
+        .get_recent_events(user_did, i32::MAX as u32)
+        .await?
+        .iter()
+        .map(EventKey::of)
+        .collect();
+
+    let mut events = source.get_recent_events(user_did, i32::MAX as u32).await?;
+    events.sort_by_key(|e| e.id);
+
+    for event in &events {
+        if event.id <= checkpoint || !present.insert(EventKey::of(event)) {
+            summary.events_skipped += 1;
+            continue;
+        }
+        // Preserve the original detected_at so pile-on detection still works
+        dest.insert_amplification_event_raw(user_did, event).await?;
+        dest.set_scan_state(user_did, EVENTS_CHECKPOINT_KEY, &event.id.to_string())
+            .await?;
+        summary.events_copied += 1;
+    }
+
+    // 4. All scan state keys (not a hardcoded subset) so cursors, timestamps,
+    // and any future keys transfer automatically
+    let dest_state: HashMap<String, String> = dest
+        .get_all_scan_state(user_did)
+        .await?
+        .into_iter()
+        .collect();
+    for (key, val) in source.get_all_scan_state(user_did).await? {
+        // A source that was itself a migration target carries its own
+        // checkpoint, which means nothing here
+        if key == EVENTS_CHECKPOINT_KEY || dest_state.get(&key) == Some(&val) {
+            continue;
+        }
+        dest.set_scan_state(user_did, &key, &val).await?;
+        summary.scan_state_copied += 1;
+    }
+
+    // 5. Whitelist — accounts already listed in the destination are left as-is
+    let listed: HashSet<String> = dest
+        .get_whitelist(user_did)
+        .await?
+        .into_iter()
+        .map(|entry| entry.did)
+        .collect();
+    for entry in source.get_whitelist(user_did).await? {
+        if listed.contains(&entry.did) {
+            continue;
+        }
+        dest.add_to_whitelist(user_did, &entry.did, &entry.handle)
+            .await?;
+        summary.whitelist_copied += 1;
+    }
+
+    Ok(summary)
+}
+
+/// Score equality. `scored_at` is copied along with the rest of the row, so
+/// it's compared too, as an instant since each backend formats timestamps
+/// its own way.
+fn scores_match(a: &AccountScore, b: &AccountScore) -> bool {
+    let strip = |s: &AccountScore| {
+        let mut value = serde_json::to_value(s).unwrap_or_default();
+        if let Some(obj) = value.as_object_mut() {
+            obj.remove("scored_at");
+        }
+        value
+    };
+    let same_time = match (parse_timestamp(&a.scored_at), parse_timestamp(&b.scored_at)) {
+        (Some(x), Some(y)) => x == y,
+        _ => a.scored_at == b.scored_at,
+    };
+    strip(a) == strip(b) && same_time
+}
+
+/// Content identity of an event. The numeric ID differs per backend, and
+/// timestamps are normalized since each backend formats them its own way.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct EventKey {
+    event_type: String,
+    amplifier_did: String,
+    original_post_uri: String,
+    amplifier_post_uri: Option<String>,
+    detected_at: Option<i64>,
+}
+
+impl EventKey {
+    fn of(event: &AmplificationEvent) -> Self {
+        Self {
+            event_type: event.event_type.clone(),
+            amplifier_did: event.amplifier_did.clone(),
+            original_post_uri: event.original_post_uri.clone(),
+            amplifier_post_uri: event.amplifier_post_uri.clone(),
+            detected_at: parse_timestamp(&event.detected_at).map(|t| t.timestamp()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::db::schema::create_tables;
+    use crate::db::sqlite::SqliteDatabase;
+    use rusqlite::Connection;
+
+    const TEST_USER: &str = "did:plc:testuser000000000000";
+
+    async fn test_db() -> SqliteDatabase {
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+        let db = SqliteDatabase::new(conn);
+        db.upsert_user(TEST_USER, "test.bsky.social").await.unwrap();
+        db
+    }
+
+    fn score(did: &str, threat: f64) -> AccountScore {
+        AccountScore {
+            did: did.to_string(),
+            handle: format!("{}.bsky.social", did.trim_start_matches("did:plc:")),
+            toxicity_score: Some(0.6),
+            topic_overlap: Some(0.3),
+            threat_score: Some(threat),
+            threat_tier: Some("Elevated".to_string()),
+            posts_analyzed: 20,
+            ..Default::default()
+        }
+    }
+
+    fn event(id: i64, amplifier: &str) -> AmplificationEvent {
+        AmplificationEvent {
+            id,
+            event_type: "quote".to_string(),
+            amplifier_did: amplifier.to_string(),
+            amplifier_handle: "amp.bsky.social".to_string(),
+            original_post_uri: "at://did:plc:testuser000000000000/app.bsky.feed.post/1".to_string(),
+            amplifier_post_uri: Some(format!("at://{amplifier}/app.bsky.feed.post/{id}")),
+            amplifier_text: Some("look at this".to_string()),
+            detected_at: format!("2026-03-0{id} 12:00:00"),
+            followers_fetched: false,
+            followers_scored: false,
+            original_post_text: None,
+            context_score: None,
+        }
+    }
+
+    async fn seeded_source() -> SqliteDatabase {
+        let src = test_db().await;
+        src.save_fingerprint(TEST_USER, r#"{"clusters": []}"#, 50)
+            .await
+            .unwrap();
+        src.save_embedding(TEST_USER, &[0.1, 0.2, 0.3])
+            .await
+            .unwrap();
+        src.upsert_account_score(TEST_USER, &score("did:plc:a", 40.0), None)
+            .await
+            .unwrap();
+        src.upsert_account_score(TEST_USER, &score("did:plc:b", 20.0), None)
+            .await
+            .unwrap();
+        for (id, amp) in [(1, "did:plc:a"), (2, "did:plc:b"), (3, "did:plc:a")] {
+            src.insert_amplification_event_raw(TEST_USER, &event(id, amp))
+                .await
+                .unwrap();
+        }
+        src.set_scan_state(TEST_USER, "notifications_cursor", "abc")
+            .await
+            .unwrap();
+        src
+    }
+
+    /// Everything `migrate_user` writes, in a comparable form.
+    async fn snapshot(db: &SqliteDatabase) -> String {
+        let fingerprint = db.get_fingerprint(TEST_USER).await.unwrap().map(|f| f.0);
+        let embedding = db.get_embedding(TEST_USER).await.unwrap();
+        let scores = db.get_all_account_scores(TEST_USER).await.unwrap();
+        let events = db.get_recent_events(TEST_USER, 100).await.unwrap();
+        let mut state = db.get_all_scan_state(TEST_USER).await.unwrap();
+        state.sort();
+        serde_json::json!([fingerprint, embedding, scores, events, state]).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_migrate_twice_is_idempotent() {
+        let src = seeded_source().await;
+        let dest = test_db().await;
+
+        let first = migrate_user(&src, &dest, TEST_USER).await.unwrap();
+        assert_eq!(first.fingerprint_copied, Some(50));
+        assert_eq!(first.embedding_copied, Some(3));
+        assert_eq!(first.scores_copied, 2);
+        assert_eq!(first.events_copied, 3);
+        assert_eq!(first.scan_state_copied, 1);
+        let after_first = snapshot(&dest).await;
+
+        let second = migrate_user(&src, &dest, TEST_USER).await.unwrap();
+        assert!(second.fingerprint_unchanged);
+        assert_eq!(second.embedding_copied, None);
+        assert_eq!(second.scores_copied, 0);
+        assert_eq!(second.scores_unchanged, 2);
+        assert_eq!(second.events_copied, 0);
+        assert_eq!(second.events_skipped, 3);
+        assert_eq!(second.scan_state_copied, 0);
+
+        assert_eq!(snapshot(&dest).await, after_first);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_resumes_without_duplicating_events() {
+        let src = seeded_source().await;
+        let dest = test_db().await;
+
+        // Simulate a run that inserted the first event but died before
+        // writing its checkpoint
+        let first_event = &src.get_recent_events(TEST_USER, 100).await.unwrap()[2];
+        dest.insert_amplification_event_raw(TEST_USER, first_event)
+            .await
+            .unwrap();
+
+        let summary = migrate_user(&src, &dest, TEST_USER).await.unwrap();
+        assert_eq!(summary.events_copied, 2);
+        assert_eq!(summary.events_skipped, 1);
+        assert_eq!(
+            dest.get_recent_events(TEST_USER, 100).await.unwrap().len(),
+            3
+        );
+        assert!(dest
+            .get_scan_state(TEST_USER, EVENTS_CHECKPOINT_KEY)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_changed_score_is_recopied() {
+        let src = seeded_source().await;
+        let dest = test_db().await;
+        migrate_user(&src, &dest, TEST_USER).await.unwrap();
+
+        src.upsert_account_score(TEST_USER, &score("did:plc:a", 55.0), None)
+            .await
+            .unwrap();
+        let summary = migrate_user(&src, &dest, TEST_USER).await.unwrap();
+        assert_eq!(summary.scores_copied, 1);
+        assert_eq!(summary.scores_unchanged, 1);
+    }
+
+    #[tokio::test]
+    async fn test_unscored_accounts_are_copied_with_their_scored_at() {
+        let src = seeded_source().await;
+        let mut unscored = score("did:plc:c", 0.0);
+        unscored.threat_score = None;
+        unscored.threat_tier = None;
+        unscored.scored_at = "2026-02-01 08:00:00".to_string();
+        src.upsert_account_score(TEST_USER, &unscored, None)
+            .await
+            .unwrap();
+        let dest = test_db().await;
+
+        let summary = migrate_user(&src, &dest, TEST_USER).await.unwrap();
+        assert_eq!(summary.scores_copied, 3);
+        let copied = dest
+            .get_account_by_did(TEST_USER, "did:plc:c")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(copied.threat_score, None);
+        assert_eq!(copied.scored_at, "2026-02-01 08:00:00");
+    }
+}
@@ -6,7 +6,9 @@
 // The database file lives wherever CHARCOAL_DB_PATH points (defaults to
 // ./charcoal.db) for SQLite. PostgreSQL uses DATABASE_URL.
 
+pub mod migrate;
 pub mod models;
+pub mod pool;
 #[cfg(feature = "postgres")]
 pub mod postgres;
 #[cfg(feature = "sqlite")]
@@ -15,11 +17,14 @@ pub mod queries;
 pub mod schema;
 #[cfg(feature = "sqlite")]
 pub mod sqlite;
+pub mod timestamps;
 pub mod traits;
 
+pub use pool::PgPoolConfig;
 pub use traits::Database;
 
 use anyhow::Result;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[cfg(feature = "sqlite")]
@@ -29,6 +34,40 @@ use rusqlite::Connection;
 #[cfg(feature = "sqlite")]
 use std::path::Path;
 
+/// Collapse repeated DIDs in a batch of scores the way successive per-score
+/// upserts would: the later score wins unless it may not replace the earlier
+/// one (see `AccountScore::may_replace`), and a missing last-active time or
+/// origin amplifier keeps the earlier one. First-seen order is kept.
+pub(crate) fn merge_repeated_dids(scores: &[models::AccountScore]) -> Vec<models::AccountScore> {
+    let mut latest: Vec<models::AccountScore> = Vec::with_capacity(scores.len());
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for score in scores {
+        match index.get(score.did.as_str()) {
+            Some(&i) if !score.may_replace(&latest[i]) => {}
+            Some(&i) => {
+                let last_active_at = score
+                    .last_active_at
+                    .clone()
+                    .or_else(|| latest[i].last_active_at.take());
+                let origin_amplifier_did = score
+                    .origin_amplifier_did
+                    .clone()
+                    .or_else(|| latest[i].origin_amplifier_did.take());
+                latest[i] = models::AccountScore {
+                    last_active_at,
+                    origin_amplifier_did,
+                    ..score.clone()
+                };
+            }
+            None => {
+                index.insert(score.did.as_str(), latest.len());
+                latest.push(score.clone());
+            }
+        }
+    }
+    latest
+}
+
 /// Open (or create) the SQLite database and run migrations.
 ///
 /// This is the main entry point — called by `charcoal init` and by any
@@ -93,9 +132,13 @@ pub fn initialize_sqlite(db_path: &str) -> Result<Arc<dyn Database>> {
     Ok(Arc::new(sqlite::SqliteDatabase::new(conn)))
 }
 
-/// Connect to PostgreSQL and return it as a trait object.
+/// Connect to PostgreSQL with a pool sized by `pool` and return it as a
+/// trait object.
 #[cfg(feature = "postgres")]
-pub async fn connect_postgres(database_url: &str) -> Result<Arc<dyn Database>> {
-    let db = postgres::PgDatabase::connect(database_url).await?;
+pub async fn connect_postgres(
+    database_url: &str,
+    pool: &PgPoolConfig,
+) -> Result<Arc<dyn Database>> {
+    let db = postgres::PgDatabase::connect(database_url, pool).await?;
     Ok(Arc::new(db))
 }
@@ -7,7 +7,7 @@
 use serde::{Deserialize, Serialize};
 
 /// A scored account in the threat list.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AccountScore {
     pub did: String,
     pub handle: String,
@@ -29,6 +29,60 @@ pub struct AccountScore {
     pub fingerprint_quality: Option<String>,
     /// Confidence level of this scoring result
     pub scoring_confidence: Option<String>,
+    /// How topic_overlap was computed ("keyword" or "embedding"; "unknown"
+    /// for rows scored before this was tracked)
+    pub overlap_method: Option<String>,
+    /// When the account last posted, as of scoring (RFC 3339, UTC). `None`
+    /// for rows scored before this was tracked.
+    pub last_active_at: Option<String>,
+    /// DID of the amplifier whose follower list this account was found in,
+    /// when the amplification pipeline scored it. `None` for accounts found
+    /// other ways (sweeps, `score`) and rows scored before this was tracked.
+    pub origin_amplifier_did: Option<String>,
+    /// The latest scan's own threat score when `threat_score` is smoothed
+    /// across scans (see `CHARCOAL_SCORE_EMA_ALPHA`). `None` on fresh
+    /// scores, where `threat_score` is already raw, and on rows stored
+    /// before raw scores were kept.
+    pub raw_threat_score: Option<f64>,
+    /// How many posts topic_overlap was computed from, set only when that
+    /// was below the minimum for a trustworthy overlap
+    /// (`CHARCOAL_MIN_OVERLAP_POSTS`). `None` when the overlap had enough
+    /// posts and on rows scored before this was tracked.
+    pub overlap_post_count: Option<u32>,
+    /// How many of `posts_analyzed` were replies. `None` on rows scored
+    /// before this was tracked.
+    pub replies_analyzed: Option<u32>,
+    /// How much evidence the threat score rests on, from 0.0 to 1.0, based
+    /// on how many posts were analyzed, the overlap method, and how much the
+    /// posts' toxicity varied (see `scoring::evidence`). Unlike
+    /// `scoring_confidence` it says nothing about pipeline depth. `None` for
+    /// accounts that weren't scored and on rows scored before this was tracked.
+    pub evidence_strength: Option<f64>,
+    /// The account is on the user's whitelist, so this is a placeholder
+    /// Low score rather than the result of any analysis. Stored in its own
+    /// column; removing the account from the whitelist deletes the row.
+    #[serde(default)]
+    pub whitelisted: bool,
+    /// Highest per-category toxicity across the posts analyzed. Only set on
+    /// freshly built profiles — not stored in the database, so it's `None`
+    /// on scores read back from storage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_toxicity_attributes: Option<crate::toxicity::traits::ToxicityAttributes>,
+}
+
+impl AccountScore {
+    /// Whether this score is a threat score without a measured toxicity —
+    /// an overlap-only scan, or an account skipped by `--min-overlap`.
+    pub fn is_unmeasured(&self) -> bool {
+        self.toxicity_score.is_none() && self.threat_score.is_some() && !self.whitelisted
+    }
+
+    /// Whether this score may replace `stored` for the same account. An
+    /// unmeasured score never replaces one whose toxicity was measured; the
+    /// database upserts apply the same rule.
+    pub fn may_replace(&self, stored: &AccountScore) -> bool {
+        !self.is_unmeasured() || stored.toxicity_score.is_none()
+    }
 }
 
 /// Confidence level of a scoring result based on data volume.
@@ -70,6 +124,24 @@ pub struct ToxicPost {
     pub text: String,
     pub toxicity: f64,
     pub uri: String,
+    /// Per-category scores for this post. Stored alongside the post in the
+    /// evidence JSON; `None` on rows written before categories were kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<crate::toxicity::traits::ToxicityAttributes>,
+    /// When the post was made (RFC 3339, as reported by Bluesky). `None` on
+    /// rows written before timestamps were kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// The hard-block pattern this post matched (see `scoring::keywords`),
+    /// if it was kept as evidence for a hard-block override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hardblock_pattern: Option<String>,
+    /// The toxicity category that set this post's effective score (e.g.
+    /// "identity_attack"; see `toxicity::traits::dominant_attribute`).
+    /// `None` when the top-line score did, or on rows written before it was
+    /// kept.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flagged_for: Option<String>,
 }
 
 /// An amplification event — someone quoted or reposted the protected user.
@@ -126,6 +198,73 @@ pub struct UserRow {
     pub last_login_at: Option<String>,
 }
 
+/// An account the user has whitelisted — skipped by scoring pipelines.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WhitelistEntry {
+    pub did: String,
+    /// Handle when the account was added (display only)
+    pub handle: String,
+    pub added_at: String,
+}
+
+/// One completed pipeline run, recorded for `charcoal status`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanRun {
+    pub id: i64,
+    /// Which pipeline ran: "scan", "stream", "sweep", or "sweep-topic"
+    pub command: String,
+    pub started_at: String,
+    pub finished_at: String,
+    pub events_detected: i64,
+    pub accounts_scored: i64,
+}
+
+/// One past score for an account, from the append-only score history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreSnapshot {
+    pub threat_score: Option<f64>,
+    pub toxicity_score: Option<f64>,
+    /// UTC "YYYY-MM-DD HH:MM:SS"
+    pub scored_at: String,
+}
+
+/// Threat rollup of one amplifier's scored followers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmplifierRollup {
+    pub amplifier_did: String,
+    pub amplifier_handle: String,
+    /// Amplification events recorded for this amplifier
+    pub event_count: i64,
+    /// Followers with a threat score
+    pub followers_scored: i64,
+    pub avg_threat: f64,
+    pub max_threat: f64,
+    /// Sum of follower threat scores — the ranking key, so a large hostile
+    /// following outranks a single bad actor
+    pub total_threat: f64,
+}
+
+/// Which page of a user's ranked threats to fetch, and how to filter them.
+#[derive(Debug, Clone, Default)]
+pub struct ThreatPageQuery {
+    pub limit: u32,
+    pub offset: u32,
+    /// Lowest threat score to include; unscored accounts never are
+    pub min_score: f64,
+    /// Only accounts in this tier
+    pub tier: Option<ThreatTier>,
+    /// Only handles containing this text, ignoring case
+    pub handle: Option<String>,
+}
+
+/// One page of ranked threats.
+#[derive(Debug, Clone)]
+pub struct ThreatPage {
+    pub items: Vec<AccountScore>,
+    /// Accounts matching the query across all pages
+    pub total: u64,
+}
+
 /// Accuracy metrics comparing predicted tiers to user labels.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccuracyMetrics {
@@ -136,7 +275,122 @@ pub struct AccuracyMetrics {
     pub accuracy: f64,
 }
 
-/// Threat tier thresholds — these are configurable constants.
+/// Lower score bounds for the Watch, Elevated and High tiers.
+///
+/// The defaults are tuned for the multiplicative scoring formula where
+/// overlap amplifies toxicity. A score of 35+ requires meaningful toxicity
+/// combined with topic proximity — the core threat signal. Users who want
+/// stricter or looser tiers override them through `Config`
+/// (CHARCOAL_TIER_WATCH, CHARCOAL_TIER_ELEVATED, CHARCOAL_TIER_HIGH).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TierThresholds {
+    pub watch: f64,
+    pub elevated: f64,
+    pub high: f64,
+}
+
+impl TierThresholds {
+    /// The built-in boundaries: Watch at 8, Elevated at 15, High at 35.
+    pub const DEFAULT: Self = Self {
+        watch: 8.0,
+        elevated: 15.0,
+        high: 35.0,
+    };
+
+    /// Thresholds from explicit bounds, or `None` unless they are finite
+    /// and strictly increasing (0 < watch < elevated < high).
+    pub fn new(watch: f64, elevated: f64, high: f64) -> Option<Self> {
+        let valid = [watch, elevated, high].iter().all(|t| t.is_finite())
+            && 0.0 < watch
+            && watch < elevated
+            && elevated < high;
+        valid.then_some(Self {
+            watch,
+            elevated,
+            high,
+        })
+    }
+
+    /// The score every account at or below `tier` stays under — the next
+    /// tier's threshold. `None` for High, which has no ceiling.
+    pub fn ceiling(&self, tier: ThreatTier) -> Option<f64> {
+        match tier {
+            ThreatTier::Low => Some(self.watch),
+            ThreatTier::Watch => Some(self.elevated),
+            ThreatTier::Elevated => Some(self.high),
+            ThreatTier::High => None,
+        }
+    }
+
+    /// The lowest score that lands in `tier` — its threshold, or 0 for Low.
+    pub fn floor(&self, tier: ThreatTier) -> f64 {
+        match tier {
+            ThreatTier::Low => 0.0,
+            ThreatTier::Watch => self.watch,
+            ThreatTier::Elevated => self.elevated,
+            ThreatTier::High => self.high,
+        }
+    }
+}
+
+impl Default for TierThresholds {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How a rescored account's threat score is smoothed against the one already
+/// stored (CHARCOAL_SCORE_EMA_ALPHA, see `Config::score_smoothing`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreSmoothing {
+    /// Weight of the new scan: the stored score becomes
+    /// `alpha * new + (1 - alpha) * previous`.
+    pub alpha: f64,
+    /// Tiers the smoothed score is re-tiered with. A hard-blocked account
+    /// never smooths below `thresholds.high`.
+    pub thresholds: TierThresholds,
+}
+
+impl ScoreSmoothing {
+    /// `score` as it should be stored over an existing threat score of
+    /// `previous`: blended, re-tiered from the blend, and with the new score
+    /// kept as `raw_threat_score`. Unchanged when there is nothing to blend —
+    /// no previous score, no new threat score, or a whitelisted placeholder.
+    pub fn apply(&self, score: &AccountScore, previous: Option<f64>) -> AccountScore {
+        let (Some(new), Some(previous)) = (score.threat_score, previous) else {
+            return score.clone();
+        };
+        if score.whitelisted {
+            return score.clone();
+        }
+
+        let blended = self.alpha * new + (1.0 - self.alpha) * previous;
+        // Same override as scoring: a hard-block match is High however the
+        // history averages out
+        let hardblocked = score
+            .top_toxic_posts
+            .iter()
+            .any(|p| p.hardblock_pattern.is_some());
+        let blended = if hardblocked {
+            blended.max(self.thresholds.high)
+        } else {
+            blended
+        };
+
+        AccountScore {
+            threat_score: Some(blended),
+            threat_tier: Some(
+                ThreatTier::from_score_with_thresholds(blended, &self.thresholds)
+                    .as_str()
+                    .to_string(),
+            ),
+            raw_threat_score: Some(score.raw_threat_score.unwrap_or(new)),
+            ..score.clone()
+        }
+    }
+}
+
+/// Threat tiers, from least to most concerning.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ThreatTier {
     Low,
@@ -146,17 +400,19 @@ pub enum ThreatTier {
 }
 
 impl ThreatTier {
-    /// Determine the tier from a threat score (0-100).
-    ///
-    /// Thresholds are tuned for the multiplicative scoring formula where
-    /// overlap amplifies toxicity. A score of 35+ requires meaningful
-    /// toxicity combined with topic proximity — the core threat signal.
-    /// Low-toxicity accounts stay low regardless of topic overlap.
+    /// Determine the tier from a threat score (0-100) using the default
+    /// thresholds (see [`TierThresholds::DEFAULT`]).
     pub fn from_score(score: f64) -> Self {
+        Self::from_score_with_thresholds(score, &TierThresholds::DEFAULT)
+    }
+
+    /// Determine the tier from a threat score (0-100) using the given
+    /// thresholds.
+    pub fn from_score_with_thresholds(score: f64, thresholds: &TierThresholds) -> Self {
         match score {
-            s if s >= 35.0 => ThreatTier::High,
-            s if s >= 15.0 => ThreatTier::Elevated,
-            s if s >= 8.0 => ThreatTier::Watch,
+            s if s >= thresholds.high => ThreatTier::High,
+            s if s >= thresholds.elevated => ThreatTier::Elevated,
+            s if s >= thresholds.watch => ThreatTier::Watch,
             _ => ThreatTier::Low,
         }
     }
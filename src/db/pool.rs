@@ -0,0 +1,53 @@
+// PostgreSQL connection pool sizing.
+//
+// Kept outside the `postgres` feature so Config can describe the pool
+// whether or not the backend is compiled in.
+
+use std::time::Duration;
+
+/// Scans, sweeps and `score` run 8 accounts at a time unless told otherwise.
+pub const DEFAULT_SCAN_CONCURRENCY: usize = 8;
+
+/// Connections kept beyond the scan concurrency, for the queries a scan
+/// makes outside its scoring tasks (score writer flushes, scan state).
+pub const POOL_HEADROOM: u32 = 2;
+
+/// Smallest pool opened by default — the sqlx default, enough for the web
+/// dashboard's request handlers alongside a background scan.
+pub const MIN_DEFAULT_MAX_CONNECTIONS: u32 = 10;
+
+/// Default seconds to wait for a free connection before a query fails.
+pub const DEFAULT_ACQUIRE_TIMEOUT_SECS: u64 = 30;
+
+/// How the PostgreSQL connection pool is sized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgPoolConfig {
+    /// Most connections open at once
+    pub max_connections: u32,
+    /// Connections kept open even when idle
+    pub min_connections: u32,
+    /// How long a query waits for a free connection before failing
+    pub acquire_timeout: Duration,
+}
+
+impl PgPoolConfig {
+    /// The default pool for a command scoring `concurrency` accounts at once:
+    /// room for every scoring task plus `POOL_HEADROOM`, and never smaller
+    /// than `MIN_DEFAULT_MAX_CONNECTIONS`.
+    pub fn for_concurrency(concurrency: usize) -> Self {
+        let needed = u32::try_from(concurrency)
+            .unwrap_or(u32::MAX)
+            .saturating_add(POOL_HEADROOM);
+        Self {
+            max_connections: needed.max(MIN_DEFAULT_MAX_CONNECTIONS),
+            min_connections: 0,
+            acquire_timeout: Duration::from_secs(DEFAULT_ACQUIRE_TIMEOUT_SECS),
+        }
+    }
+}
+
+impl Default for PgPoolConfig {
+    fn default() -> Self {
+        Self::for_concurrency(DEFAULT_SCAN_CONCURRENCY)
+    }
+}
@@ -11,16 +11,20 @@
 // - $1/$2 parameter syntax (handled by sqlx)
 // - GENERATED ALWAYS AS IDENTITY for auto-increment
 
+use std::collections::{HashMap, HashSet};
+
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use sqlx_core::pool::Pool;
 use sqlx_core::row::Row;
-use sqlx_postgres::Postgres;
+use sqlx_postgres::{PgPoolOptions, Postgres};
 
 use super::models::{
-    AccountScore, AccuracyMetrics, AmplificationEvent, InferredPair, ThreatTier, ToxicPost,
-    UserLabel, UserRow,
+    AccountScore, AccuracyMetrics, AmplificationEvent, AmplifierRollup, InferredPair, ScanRun,
+    ScoreSmoothing, ScoreSnapshot, ThreatPage, ThreatPageQuery, ThreatTier, TierThresholds,
+    ToxicPost, UserLabel, UserRow, WhitelistEntry,
 };
+use super::pool::PgPoolConfig;
 use super::traits::Database;
 
 /// Type alias for the PostgreSQL connection pool.
@@ -31,9 +35,14 @@ pub struct PgDatabase {
 }
 
 impl PgDatabase {
-    /// Connect to PostgreSQL and run migrations.
-    pub async fn connect(database_url: &str) -> Result<Self> {
-        let pool = PgPool::connect(database_url)
+    /// Connect to PostgreSQL with a pool sized by `pool_config`, and run
+    /// migrations.
+    pub async fn connect(database_url: &str, pool_config: &PgPoolConfig) -> Result<Self> {
+        let pool = PgPoolOptions::new()
+            .max_connections(pool_config.max_connections)
+            .min_connections(pool_config.min_connections)
+            .acquire_timeout(pool_config.acquire_timeout)
+            .connect(database_url)
             .await
             .with_context(|| format!("Failed to connect to PostgreSQL at {database_url}"))?;
 
@@ -127,6 +136,62 @@ impl PgDatabase {
                     8,
                     include_str!("../../migrations/postgres/0008_fingerprint_scoring.sql"),
                 ),
+                (
+                    9,
+                    include_str!("../../migrations/postgres/0009_overlap_method.sql"),
+                ),
+                (
+                    10,
+                    include_str!("../../migrations/postgres/0010_did_cache.sql"),
+                ),
+                (
+                    11,
+                    include_str!("../../migrations/postgres/0011_follower_snapshots.sql"),
+                ),
+                (
+                    12,
+                    include_str!("../../migrations/postgres/0012_amplifier_followers.sql"),
+                ),
+                (
+                    13,
+                    include_str!("../../migrations/postgres/0013_last_active_at.sql"),
+                ),
+                (
+                    14,
+                    include_str!("../../migrations/postgres/0014_whitelist.sql"),
+                ),
+                (
+                    15,
+                    include_str!("../../migrations/postgres/0015_scan_runs.sql"),
+                ),
+                (
+                    16,
+                    include_str!("../../migrations/postgres/0016_origin_amplifier.sql"),
+                ),
+                (
+                    17,
+                    include_str!("../../migrations/postgres/0017_account_score_history.sql"),
+                ),
+                (
+                    18,
+                    include_str!("../../migrations/postgres/0018_raw_threat_score.sql"),
+                ),
+                (
+                    19,
+                    include_str!("../../migrations/postgres/0019_overlap_post_count.sql"),
+                ),
+                (
+                    20,
+                    include_str!("../../migrations/postgres/0020_replies_analyzed.sql"),
+                ),
+                (
+                    21,
+                    include_str!("../../migrations/postgres/0021_evidence_search.sql"),
+                ),
+                (
+                    22,
+                    include_str!("../../migrations/postgres/0022_evidence_strength.sql"),
+                ),
             ];
 
             for (version, sql) in migrations {
@@ -174,9 +239,133 @@ impl PgDatabase {
 
         Ok(())
     }
+
+    /// Ranked threats, optionally limited to accounts active within the
+    /// last `active_within_days` days.
+    async fn ranked_threats(
+        &self,
+        user_did: &str,
+        min_score: f64,
+        active_within_days: Option<u32>,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>> {
+        let active_within_days = active_within_days
+            .map(i32::try_from)
+            .transpose()
+            .context("active_within_days exceeds i32 range")?;
+        let rows = sqlx_core::query::query(
+            "SELECT did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
+                    posts_analyzed, top_toxic_posts,
+                    to_char(scored_at, 'YYYY-MM-DD HH24:MI:SS') as scored_at,
+                    behavioral_signals, context_score,
+                    fingerprint_quality, scoring_confidence, graph_distance, overlap_method,
+                    to_char(last_active_at AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"') as last_active_at,
+                    origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength,
+                    whitelisted
+             FROM account_scores
+             WHERE user_did = $1 AND threat_score >= $2
+               AND ($3::int IS NULL
+                    OR last_active_at >= NOW() - make_interval(days => $3::int))
+             ORDER BY threat_score DESC",
+        )
+        .bind(user_did)
+        .bind(min_score)
+        .bind(active_within_days)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(|row| ranked_row(row, thresholds)).collect())
+    }
+}
+
+/// An `AccountScore` from a row of the ranked-threat column list (see
+/// `PgDatabase::ranked_threats`), with its tier recomputed using `thresholds`.
+fn ranked_row(row: &sqlx_postgres::PgRow, thresholds: &TierThresholds) -> AccountScore {
+    let top_posts_json: serde_json::Value = row.get(7);
+    let top_toxic_posts: Vec<ToxicPost> =
+        serde_json::from_value(top_posts_json).unwrap_or_default();
+
+    // Recalculate tier from stored score so threshold changes
+    // take effect without rescanning.
+    let threat_score: Option<f64> = row.get(4);
+    let threat_tier =
+        threat_score.map(|s| ThreatTier::from_score_with_thresholds(s, thresholds).to_string());
+
+    let behavioral_signals: Option<serde_json::Value> = row.get(9);
+
+    AccountScore {
+        did: row.get(0),
+        handle: row.get(1),
+        toxicity_score: row.get(2),
+        topic_overlap: row.get(3),
+        threat_score,
+        threat_tier,
+        posts_analyzed: row.get::<i32, _>(6) as u32,
+        top_toxic_posts,
+        scored_at: row.get(8),
+        behavioral_signals: behavioral_signals.map(|v| v.to_string()),
+        context_score: row.get(10),
+        graph_distance: row.get(13),
+        fingerprint_quality: row.get(11),
+        scoring_confidence: row.get(12),
+        overlap_method: row.get(14),
+        last_active_at: row.get(15),
+        origin_amplifier_did: row.get(16),
+        raw_threat_score: row.get(17),
+        overlap_post_count: row.get::<Option<i32>, _>(18).map(|n| n as u32),
+        replies_analyzed: row.get::<Option<i32>, _>(19).map(|n| n as u32),
+        evidence_strength: row.get(20),
+        whitelisted: row.get(21),
+        peak_toxicity_attributes: None,
+    }
 }
 
 #[async_trait]
+/// Column list read by `stored_row`.
+const STORED_SCORE_COLUMNS: &str =
+    "did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
+     posts_analyzed, top_toxic_posts,
+     to_char(scored_at, 'YYYY-MM-DD HH24:MI:SS') as scored_at,
+     behavioral_signals, context_score,
+     fingerprint_quality, scoring_confidence, graph_distance, overlap_method,
+     to_char(last_active_at AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"') as last_active_at,
+     origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength,
+     whitelisted";
+
+/// An `AccountScore` from a row of `STORED_SCORE_COLUMNS`, keeping the tier
+/// set at scoring time (with the thresholds configured then).
+fn stored_row(row: &sqlx_postgres::PgRow) -> AccountScore {
+    let top_posts_json: serde_json::Value = row.get(7);
+    let top_toxic_posts: Vec<ToxicPost> =
+        serde_json::from_value(top_posts_json).unwrap_or_default();
+    let behavioral_signals: Option<serde_json::Value> = row.get(9);
+    AccountScore {
+        did: row.get(0),
+        handle: row.get(1),
+        toxicity_score: row.get(2),
+        topic_overlap: row.get(3),
+        threat_score: row.get(4),
+        threat_tier: row.get(5),
+        posts_analyzed: row.get::<i32, _>(6) as u32,
+        top_toxic_posts,
+        scored_at: row.get(8),
+        behavioral_signals: behavioral_signals.map(|v| v.to_string()),
+        context_score: row.get(10),
+        graph_distance: row.get(13),
+        fingerprint_quality: row.get(11),
+        scoring_confidence: row.get(12),
+        overlap_method: row.get(14),
+        last_active_at: row.get(15),
+        origin_amplifier_did: row.get(16),
+        raw_threat_score: row.get(17),
+        overlap_post_count: row.get::<Option<i32>, _>(18).map(|n| n as u32),
+        replies_analyzed: row.get::<Option<i32>, _>(19).map(|n| n as u32),
+        evidence_strength: row.get(20),
+        whitelisted: row.get(21),
+        peak_toxicity_attributes: None,
+    }
+}
+
 impl Database for PgDatabase {
     async fn table_count(&self) -> Result<i64> {
         let row = sqlx_core::query::query(
@@ -311,19 +500,50 @@ impl Database for PgDatabase {
         }
     }
 
-    async fn upsert_account_score(&self, user_did: &str, score: &AccountScore) -> Result<()> {
+    async fn upsert_account_score(
+        &self,
+        user_did: &str,
+        score: &AccountScore,
+        smoothing: Option<ScoreSmoothing>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        let smoothed;
+        let new_score = score;
+        let score = match smoothing {
+            Some(smoothing) => {
+                // A whitelisted placeholder's zero is not a score to blend with
+                let previous: Option<f64> = sqlx_core::query::query(
+                    "SELECT threat_score FROM account_scores
+                     WHERE user_did = $1 AND did = $2 AND NOT whitelisted
+                     FOR UPDATE",
+                )
+                .bind(user_did)
+                .bind(&score.did)
+                .fetch_optional(&mut *tx)
+                .await?
+                .and_then(|r| r.get(0));
+                smoothed = smoothing.apply(score, previous);
+                &smoothed
+            }
+            None => score,
+        };
+
         let top_posts_json = serde_json::to_value(&score.top_toxic_posts)?;
         let behavioral_json: Option<serde_json::Value> = score
             .behavioral_signals
             .as_ref()
             .and_then(|s| serde_json::from_str(s).ok());
 
-        sqlx_core::query::query(
+        let written = sqlx_core::query::query(
             "INSERT INTO account_scores
                 (user_did, did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
                  posts_analyzed, top_toxic_posts, scored_at, behavioral_signals, context_score, graph_distance,
-                 fingerprint_quality, scoring_confidence)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW(), $10, $11, $12, $13, $14)
+                 fingerprint_quality, scoring_confidence, overlap_method, last_active_at,
+                 origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed,
+                 evidence_strength, whitelisted)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9,
+                     COALESCE(NULLIF($23, '')::timestamptz, NOW()), $10, $11, $12, $13, $14,
+                     $15, $16::timestamptz, $17, COALESCE($18, $6), $19, $20, $21, $22)
              ON CONFLICT(user_did, did) DO UPDATE SET
                 handle = $3,
                 toxicity_score = $4,
@@ -332,12 +552,24 @@ impl Database for PgDatabase {
                 threat_tier = $7,
                 posts_analyzed = $8,
                 top_toxic_posts = $9,
-                scored_at = NOW(),
+                scored_at = COALESCE(NULLIF($23, '')::timestamptz, NOW()),
                 behavioral_signals = $10,
                 context_score = $11,
                 graph_distance = $12,
                 fingerprint_quality = $13,
-                scoring_confidence = $14",
+                scoring_confidence = $14,
+                overlap_method = $15,
+                last_active_at = COALESCE($16::timestamptz, account_scores.last_active_at),
+                origin_amplifier_did = COALESCE($17, account_scores.origin_amplifier_did),
+                raw_threat_score = COALESCE($18, $6),
+                overlap_post_count = $19,
+                replies_analyzed = $20,
+                evidence_strength = $21,
+                whitelisted = $22
+             WHERE EXCLUDED.toxicity_score IS NOT NULL
+                OR EXCLUDED.threat_score IS NULL
+                OR EXCLUDED.whitelisted
+                OR account_scores.toxicity_score IS NULL",
         )
         .bind(user_did)
         .bind(&score.did)
@@ -353,8 +585,243 @@ impl Database for PgDatabase {
         .bind(&score.graph_distance)
         .bind(&score.fingerprint_quality)
         .bind(&score.scoring_confidence)
-        .execute(&self.pool)
+        .bind(&score.overlap_method)
+        .bind(&score.last_active_at)
+        .bind(&score.origin_amplifier_did)
+        .bind(score.raw_threat_score)
+        .bind(score.overlap_post_count.map(|n| n as i32))
+        .bind(score.replies_analyzed.map(|n| n as i32))
+        .bind(score.evidence_strength)
+        .bind(score.whitelisted)
+        .bind(&score.scored_at)
+        .execute(&mut *tx)
+        .await?;
+        // An unmeasured score left a measured one in place: nothing to record
+        if written.rows_affected() == 0 {
+            tx.commit().await?;
+            return Ok(());
+        }
+        sqlx_core::query::query(
+            "INSERT INTO account_score_history (user_did, did, threat_score, toxicity_score, scored_at)
+             VALUES ($1, $2, $3, $4, COALESCE(NULLIF($5, '')::timestamptz, NOW()))",
+        )
+        .bind(user_did)
+        .bind(&new_score.did)
+        .bind(new_score.threat_score)
+        .bind(new_score.toxicity_score)
+        .bind(&new_score.scored_at)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn upsert_account_scores(
+        &self,
+        user_did: &str,
+        scores: &[AccountScore],
+        smoothing: Option<ScoreSmoothing>,
+    ) -> Result<()> {
+        // One multi-row INSERT can't touch the same row twice, so repeated
+        // DIDs are merged first. With smoothing, a repeated DID is blended
+        // once, with its later score.
+        let mut latest = super::merge_repeated_dids(scores);
+
+        let mut tx = self.pool.begin().await?;
+        if let Some(smoothing) = smoothing {
+            let dids: Vec<&str> = latest.iter().map(|s| s.did.as_str()).collect();
+            // A whitelisted placeholder's zero is not a score to blend with
+            let previous: HashMap<String, f64> = sqlx_core::query::query(
+                "SELECT did, threat_score FROM account_scores
+                 WHERE user_did = $1 AND did = ANY($2) AND NOT whitelisted
+                   AND threat_score IS NOT NULL
+                 FOR UPDATE",
+            )
+            .bind(user_did)
+            .bind(dids)
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|r| (r.get(0), r.get(1)))
+            .collect();
+            latest = latest
+                .iter()
+                .map(|score| smoothing.apply(score, previous.get(&score.did).copied()))
+                .collect();
+        }
+
+        // One statement per table whatever the batch size: each column goes
+        // up as a single array parameter and UNNEST turns them back into rows
+        let mut dids = Vec::with_capacity(latest.len());
+        let mut handles = Vec::with_capacity(latest.len());
+        let mut toxicity_scores = Vec::with_capacity(latest.len());
+        let mut topic_overlaps = Vec::with_capacity(latest.len());
+        let mut threat_scores = Vec::with_capacity(latest.len());
+        let mut threat_tiers = Vec::with_capacity(latest.len());
+        let mut posts_analyzed = Vec::with_capacity(latest.len());
+        let mut top_toxic_posts = Vec::with_capacity(latest.len());
+        let mut behavioral_signals = Vec::with_capacity(latest.len());
+        let mut context_scores = Vec::with_capacity(latest.len());
+        let mut graph_distances = Vec::with_capacity(latest.len());
+        let mut fingerprint_qualities = Vec::with_capacity(latest.len());
+        let mut scoring_confidences = Vec::with_capacity(latest.len());
+        let mut overlap_methods = Vec::with_capacity(latest.len());
+        let mut last_active_ats = Vec::with_capacity(latest.len());
+        let mut origin_amplifier_dids = Vec::with_capacity(latest.len());
+        let mut raw_threat_scores = Vec::with_capacity(latest.len());
+        let mut overlap_post_counts = Vec::with_capacity(latest.len());
+        let mut replies_analyzed = Vec::with_capacity(latest.len());
+        let mut evidence_strengths = Vec::with_capacity(latest.len());
+        let mut whitelisted = Vec::with_capacity(latest.len());
+        let mut scored_ats = Vec::with_capacity(latest.len());
+        for score in latest.iter().cloned() {
+            dids.push(score.did);
+            handles.push(score.handle);
+            toxicity_scores.push(score.toxicity_score);
+            topic_overlaps.push(score.topic_overlap);
+            threat_scores.push(score.threat_score);
+            threat_tiers.push(score.threat_tier);
+            posts_analyzed.push(score.posts_analyzed as i32);
+            top_toxic_posts.push(serde_json::to_string(&score.top_toxic_posts)?);
+            // Signals that aren't valid JSON are stored as NULL, not rejected
+            behavioral_signals.push(
+                score
+                    .behavioral_signals
+                    .filter(|s| serde_json::from_str::<serde_json::Value>(s).is_ok()),
+            );
+            context_scores.push(score.context_score);
+            graph_distances.push(score.graph_distance);
+            fingerprint_qualities.push(score.fingerprint_quality);
+            scoring_confidences.push(score.scoring_confidence);
+            overlap_methods.push(score.overlap_method);
+            last_active_ats.push(score.last_active_at);
+            origin_amplifier_dids.push(score.origin_amplifier_did);
+            raw_threat_scores.push(score.raw_threat_score.or(score.threat_score));
+            overlap_post_counts.push(score.overlap_post_count.map(|n| n as i32));
+            replies_analyzed.push(score.replies_analyzed.map(|n| n as i32));
+            evidence_strengths.push(score.evidence_strength);
+            whitelisted.push(score.whitelisted);
+            scored_ats.push(score.scored_at);
+        }
+
+        let written = sqlx_core::query::query(
+            "INSERT INTO account_scores
+                (user_did, did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
+                 posts_analyzed, top_toxic_posts, scored_at, behavioral_signals, context_score,
+                 graph_distance, fingerprint_quality, scoring_confidence, overlap_method,
+                 last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count,
+                 replies_analyzed, evidence_strength, whitelisted)
+             SELECT $1, t.did, t.handle, t.toxicity_score, t.topic_overlap, t.threat_score,
+                    t.threat_tier, t.posts_analyzed, t.top_toxic_posts::jsonb,
+                    COALESCE(NULLIF(t.scored_at, '')::timestamptz, NOW()),
+                    t.behavioral_signals::jsonb, t.context_score, t.graph_distance,
+                    t.fingerprint_quality, t.scoring_confidence, t.overlap_method,
+                    t.last_active_at::timestamptz, t.origin_amplifier_did, t.raw_threat_score,
+                    t.overlap_post_count, t.replies_analyzed, t.evidence_strength,
+                    t.whitelisted
+             FROM UNNEST(
+                 $2::text[], $3::text[], $4::float8[], $5::float8[], $6::float8[], $7::text[],
+                 $8::int4[], $9::text[], $10::text[], $11::float8[], $12::text[], $13::text[],
+                 $14::text[], $15::text[], $16::text[], $17::text[], $18::float8[],
+                 $19::int4[], $20::int4[], $21::float8[], $22::bool[], $23::text[]
+             ) AS t(did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
+                    posts_analyzed, top_toxic_posts, behavioral_signals, context_score,
+                    graph_distance, fingerprint_quality, scoring_confidence, overlap_method,
+                    last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count,
+                    replies_analyzed, evidence_strength, whitelisted, scored_at)
+             ON CONFLICT(user_did, did) DO UPDATE SET
+                handle = EXCLUDED.handle,
+                toxicity_score = EXCLUDED.toxicity_score,
+                topic_overlap = EXCLUDED.topic_overlap,
+                threat_score = EXCLUDED.threat_score,
+                threat_tier = EXCLUDED.threat_tier,
+                posts_analyzed = EXCLUDED.posts_analyzed,
+                top_toxic_posts = EXCLUDED.top_toxic_posts,
+                scored_at = EXCLUDED.scored_at,
+                behavioral_signals = EXCLUDED.behavioral_signals,
+                context_score = EXCLUDED.context_score,
+                graph_distance = EXCLUDED.graph_distance,
+                fingerprint_quality = EXCLUDED.fingerprint_quality,
+                scoring_confidence = EXCLUDED.scoring_confidence,
+                overlap_method = EXCLUDED.overlap_method,
+                last_active_at = COALESCE(EXCLUDED.last_active_at, account_scores.last_active_at),
+                origin_amplifier_did = COALESCE(EXCLUDED.origin_amplifier_did, account_scores.origin_amplifier_did),
+                raw_threat_score = EXCLUDED.raw_threat_score,
+                overlap_post_count = EXCLUDED.overlap_post_count,
+                replies_analyzed = EXCLUDED.replies_analyzed,
+                evidence_strength = EXCLUDED.evidence_strength,
+                whitelisted = EXCLUDED.whitelisted
+             WHERE EXCLUDED.toxicity_score IS NOT NULL
+                OR EXCLUDED.threat_score IS NULL
+                OR EXCLUDED.whitelisted
+                OR account_scores.toxicity_score IS NULL
+             RETURNING did",
+        )
+        .bind(user_did)
+        .bind(dids)
+        .bind(handles)
+        .bind(toxicity_scores)
+        .bind(topic_overlaps)
+        .bind(threat_scores)
+        .bind(threat_tiers)
+        .bind(posts_analyzed)
+        .bind(top_toxic_posts)
+        .bind(behavioral_signals)
+        .bind(context_scores)
+        .bind(graph_distances)
+        .bind(fingerprint_qualities)
+        .bind(scoring_confidences)
+        .bind(overlap_methods)
+        .bind(last_active_ats)
+        .bind(origin_amplifier_dids)
+        .bind(raw_threat_scores)
+        .bind(overlap_post_counts)
+        .bind(replies_analyzed)
+        .bind(evidence_strengths)
+        .bind(whitelisted)
+        .bind(scored_ats)
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|r| r.get(0))
+        .collect::<HashSet<String>>();
+
+        // History keeps every score of the rows written, repeated DIDs
+        // included, except an unmeasured score the merge dropped
+        let merged: HashMap<&str, &AccountScore> =
+            latest.iter().map(|s| (s.did.as_str(), s)).collect();
+        let recorded: Vec<&AccountScore> = scores
+            .iter()
+            .filter(|s| {
+                written.contains(&s.did)
+                    && merged.get(s.did.as_str()).is_some_and(|m| s.may_replace(m))
+            })
+            .collect();
+        sqlx_core::query::query(
+            "INSERT INTO account_score_history (user_did, did, threat_score, toxicity_score, scored_at)
+             SELECT $1, t.did, t.threat_score, t.toxicity_score,
+                    COALESCE(NULLIF(t.scored_at, '')::timestamptz, NOW())
+             FROM UNNEST($2::text[], $3::float8[], $4::float8[], $5::text[])
+                 AS t(did, threat_score, toxicity_score, scored_at)",
+        )
+        .bind(user_did)
+        .bind(recorded.iter().map(|s| s.did.clone()).collect::<Vec<_>>())
+        .bind(recorded.iter().map(|s| s.threat_score).collect::<Vec<_>>())
+        .bind(
+            recorded
+                .iter()
+                .map(|s| s.toxicity_score)
+                .collect::<Vec<_>>(),
+        )
+        .bind(
+            recorded
+                .iter()
+                .map(|s| s.scored_at.clone())
+                .collect::<Vec<_>>(),
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
         Ok(())
     }
 
@@ -362,53 +829,77 @@ impl Database for PgDatabase {
         &self,
         user_did: &str,
         min_score: f64,
+        thresholds: &TierThresholds,
     ) -> Result<Vec<AccountScore>> {
-        let rows = sqlx_core::query::query(
+        self.ranked_threats(user_did, min_score, None, thresholds)
+            .await
+    }
+
+    async fn get_ranked_threats_active_within(
+        &self,
+        user_did: &str,
+        min_score: f64,
+        active_within_days: u32,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>> {
+        self.ranked_threats(user_did, min_score, Some(active_within_days), thresholds)
+            .await
+    }
+
+    async fn get_threats_paged(
+        &self,
+        user_did: &str,
+        query: &ThreatPageQuery,
+        thresholds: &TierThresholds,
+    ) -> Result<ThreatPage> {
+        let floor = query.tier.map(|t| thresholds.floor(t));
+        let ceiling = query.tier.and_then(|t| thresholds.ceiling(t));
+        let handle = query.handle.as_deref();
+        let filter = "WHERE user_did = $1 AND threat_score >= $2
+               AND ($3::float8 IS NULL OR threat_score >= $3)
+               AND ($4::float8 IS NULL OR threat_score < $4)
+               AND ($5::text IS NULL OR strpos(lower(handle), lower($5)) > 0)";
+
+        let total: i64 = sqlx_core::query::query(&format!(
+            "SELECT COUNT(*)::bigint FROM account_scores {filter}"
+        ))
+        .bind(user_did)
+        .bind(query.min_score)
+        .bind(floor)
+        .bind(ceiling)
+        .bind(handle)
+        .fetch_one(&self.pool)
+        .await?
+        .get(0);
+
+        let rows = sqlx_core::query::query(&format!(
             "SELECT did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
                     posts_analyzed, top_toxic_posts,
                     to_char(scored_at, 'YYYY-MM-DD HH24:MI:SS') as scored_at,
                     behavioral_signals, context_score,
-                    fingerprint_quality, scoring_confidence, graph_distance
+                    fingerprint_quality, scoring_confidence, graph_distance, overlap_method,
+                    to_char(last_active_at AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"') as last_active_at,
+                    origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength,
+                    whitelisted
              FROM account_scores
-             WHERE user_did = $1 AND threat_score >= $2
-             ORDER BY threat_score DESC",
-        )
+             {filter}
+             ORDER BY threat_score DESC, did
+             LIMIT $6 OFFSET $7"
+        ))
         .bind(user_did)
-        .bind(min_score)
+        .bind(query.min_score)
+        .bind(floor)
+        .bind(ceiling)
+        .bind(handle)
+        .bind(i64::from(query.limit))
+        .bind(i64::from(query.offset))
         .fetch_all(&self.pool)
         .await?;
 
-        let mut accounts = Vec::new();
-        for row in rows {
-            let top_posts_json: serde_json::Value = row.get(7);
-            let top_toxic_posts: Vec<ToxicPost> =
-                serde_json::from_value(top_posts_json).unwrap_or_default();
-
-            // Recalculate tier from stored score so threshold changes
-            // take effect without rescanning.
-            let threat_score: Option<f64> = row.get(4);
-            let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
-
-            let behavioral_signals: Option<serde_json::Value> = row.get(9);
-
-            accounts.push(AccountScore {
-                did: row.get(0),
-                handle: row.get(1),
-                toxicity_score: row.get(2),
-                topic_overlap: row.get(3),
-                threat_score,
-                threat_tier,
-                posts_analyzed: row.get::<i32, _>(6) as u32,
-                top_toxic_posts,
-                scored_at: row.get(8),
-                behavioral_signals: behavioral_signals.map(|v| v.to_string()),
-                context_score: row.get(10),
-                graph_distance: row.get(13),
-                fingerprint_quality: row.get(11),
-                scoring_confidence: row.get(12),
-            });
-        }
-        Ok(accounts)
+        Ok(ThreatPage {
+            items: rows.iter().map(|row| ranked_row(row, thresholds)).collect(),
+            total: total as u64,
+        })
     }
 
     async fn is_score_stale(&self, user_did: &str, did: &str, max_age_days: i64) -> Result<bool> {
@@ -430,6 +921,28 @@ impl Database for PgDatabase {
         }
     }
 
+    async fn prune_scores(
+        &self,
+        user_did: &str,
+        older_than_days: u32,
+        max_tier: ThreatTier,
+        thresholds: &TierThresholds,
+    ) -> Result<u64> {
+        // Autovacuum reclaims the space — no explicit VACUUM needed here
+        let result = sqlx_core::query::query(
+            "DELETE FROM account_scores
+             WHERE user_did = $1
+               AND scored_at < NOW() - make_interval(days => $2)
+               AND ($3::float8 IS NULL OR threat_score IS NULL OR threat_score < $3)",
+        )
+        .bind(user_did)
+        .bind(i32::try_from(older_than_days).context("older_than_days exceeds i32 range")?)
+        .bind(thresholds.ceiling(max_tier))
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
     async fn insert_amplification_event(
         &self,
         user_did: &str,
@@ -507,6 +1020,49 @@ impl Database for PgDatabase {
         Ok(events)
     }
 
+    async fn get_recent_events_since(
+        &self,
+        user_did: &str,
+        limit: u32,
+        since_days: u32,
+    ) -> Result<Vec<AmplificationEvent>> {
+        let rows = sqlx_core::query::query(
+            "SELECT id, event_type, amplifier_did, amplifier_handle, original_post_uri,
+                    amplifier_post_uri, amplifier_text,
+                    to_char(detected_at, 'YYYY-MM-DD HH24:MI:SS') as detected_at,
+                    followers_fetched, followers_scored,
+                    original_post_text, context_score
+             FROM amplification_events
+             WHERE user_did = $1 AND detected_at >= NOW() - make_interval(days => $3)
+             ORDER BY detected_at DESC
+             LIMIT $2",
+        )
+        .bind(user_did)
+        .bind(limit.min(i32::MAX as u32) as i32)
+        .bind(i32::try_from(since_days).context("since_days exceeds i32 range")?)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            events.push(AmplificationEvent {
+                id: row.get(0),
+                event_type: row.get(1),
+                amplifier_did: row.get(2),
+                amplifier_handle: row.get(3),
+                original_post_uri: row.get(4),
+                amplifier_post_uri: row.get(5),
+                amplifier_text: row.get(6),
+                detected_at: row.get(7),
+                followers_fetched: row.get(8),
+                followers_scored: row.get(9),
+                original_post_text: row.get(10),
+                context_score: row.get(11),
+            });
+        }
+        Ok(events)
+    }
+
     async fn get_events_for_pile_on(
         &self,
         user_did: &str,
@@ -603,6 +1159,30 @@ impl Database for PgDatabase {
             .collect())
     }
 
+    async fn mark_event_followers_scored(&self, event_id: i64) -> Result<()> {
+        sqlx_core::query::query(
+            "UPDATE amplification_events
+             SET followers_fetched = TRUE, followers_scored = TRUE
+             WHERE id = $1",
+        )
+        .bind(event_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_followers_scored_post_uris(&self, user_did: &str) -> Result<Vec<String>> {
+        let rows = sqlx_core::query::query(
+            "SELECT DISTINCT amplifier_post_uri
+             FROM amplification_events
+             WHERE user_did = $1 AND followers_scored AND amplifier_post_uri IS NOT NULL",
+        )
+        .bind(user_did)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.iter().map(|r| r.get::<String, _>(0)).collect())
+    }
+
     async fn insert_amplification_event_raw(
         &self,
         user_did: &str,
@@ -658,7 +1238,10 @@ impl Database for PgDatabase {
                     posts_analyzed, top_toxic_posts,
                     to_char(scored_at, 'YYYY-MM-DD HH24:MI:SS') as scored_at,
                     behavioral_signals, context_score,
-                    fingerprint_quality, scoring_confidence, graph_distance
+                    fingerprint_quality, scoring_confidence, graph_distance, overlap_method,
+                    to_char(last_active_at AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"') as last_active_at,
+                    origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength,
+                    whitelisted
              FROM account_scores
              WHERE user_did = $1 AND lower(handle) = lower($2)
              LIMIT 1",
@@ -673,7 +1256,8 @@ impl Database for PgDatabase {
             let top_toxic_posts: Vec<ToxicPost> =
                 serde_json::from_value(top_posts_json).unwrap_or_default();
             let threat_score: Option<f64> = r.get(4);
-            let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
+            // The tier set at scoring time, with the thresholds configured then
+            let threat_tier: Option<String> = r.get(5);
             let behavioral_signals: Option<serde_json::Value> = r.get(9);
             AccountScore {
                 did: r.get(0),
@@ -690,50 +1274,143 @@ impl Database for PgDatabase {
                 graph_distance: r.get(13),
                 fingerprint_quality: r.get(11),
                 scoring_confidence: r.get(12),
+                overlap_method: r.get(14),
+                last_active_at: r.get(15),
+                origin_amplifier_did: r.get(16),
+                raw_threat_score: r.get(17),
+                overlap_post_count: r.get::<Option<i32>, _>(18).map(|n| n as u32),
+                replies_analyzed: r.get::<Option<i32>, _>(19).map(|n| n as u32),
+                evidence_strength: r.get(20),
+                whitelisted: r.get(21),
+                peak_toxicity_attributes: None,
             }
         }))
     }
 
+    async fn get_account_history(
+        &self,
+        user_did: &str,
+        did: &str,
+        limit: u32,
+    ) -> Result<Vec<ScoreSnapshot>> {
+        let rows = sqlx_core::query::query(
+            "SELECT threat_score, toxicity_score,
+                    to_char(scored_at AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS')
+             FROM (
+                 SELECT id, threat_score, toxicity_score, scored_at
+                 FROM account_score_history
+                 WHERE user_did = $1 AND did = $2
+                 ORDER BY scored_at DESC, id DESC
+                 LIMIT $3
+             ) recent
+             ORDER BY scored_at, id",
+        )
+        .bind(user_did)
+        .bind(did)
+        .bind(limit.min(i32::MAX as u32) as i32)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(|r| ScoreSnapshot {
+                threat_score: r.get(0),
+                toxicity_score: r.get(1),
+                scored_at: r.get(2),
+            })
+            .collect())
+    }
+
     async fn get_account_by_did(&self, user_did: &str, did: &str) -> Result<Option<AccountScore>> {
-        let row = sqlx_core::query::query(
+        let row = sqlx_core::query::query(&format!(
+            "SELECT {STORED_SCORE_COLUMNS}
+             FROM account_scores
+             WHERE user_did = $1 AND did = $2
+             LIMIT 1"
+        ))
+        .bind(user_did)
+        .bind(did)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(stored_row))
+    }
+
+    async fn get_all_account_scores(&self, user_did: &str) -> Result<Vec<AccountScore>> {
+        let rows = sqlx_core::query::query(&format!(
+            "SELECT {STORED_SCORE_COLUMNS}
+             FROM account_scores
+             WHERE user_did = $1
+             ORDER BY did"
+        ))
+        .bind(user_did)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(stored_row).collect())
+    }
+
+    async fn search_evidence(
+        &self,
+        user_did: &str,
+        query: &str,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>> {
+        let rows = sqlx_core::query::query(
             "SELECT did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
                     posts_analyzed, top_toxic_posts,
                     to_char(scored_at, 'YYYY-MM-DD HH24:MI:SS') as scored_at,
                     behavioral_signals, context_score,
-                    fingerprint_quality, scoring_confidence, graph_distance
+                    fingerprint_quality, scoring_confidence, graph_distance, overlap_method,
+                    to_char(last_active_at AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"') as last_active_at,
+                    origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength,
+                    whitelisted
              FROM account_scores
-             WHERE user_did = $1 AND did = $2
-             LIMIT 1",
+             WHERE user_did = $1 AND evidence_tsv @@ plainto_tsquery('english', $2)
+             ORDER BY threat_score DESC NULLS LAST",
         )
         .bind(user_did)
-        .bind(did)
-        .fetch_optional(&self.pool)
+        .bind(query)
+        .fetch_all(&self.pool)
         .await?;
 
-        Ok(row.map(|r| {
-            let top_posts_json: serde_json::Value = r.get(7);
+        let mut accounts = Vec::new();
+        for row in rows {
+            let top_posts_json: serde_json::Value = row.get(7);
             let top_toxic_posts: Vec<ToxicPost> =
                 serde_json::from_value(top_posts_json).unwrap_or_default();
-            let threat_score: Option<f64> = r.get(4);
-            let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
-            let behavioral_signals: Option<serde_json::Value> = r.get(9);
-            AccountScore {
-                did: r.get(0),
-                handle: r.get(1),
-                toxicity_score: r.get(2),
-                topic_overlap: r.get(3),
+            let threat_score: Option<f64> = row.get(4);
+            let threat_tier = threat_score
+                .map(|s| ThreatTier::from_score_with_thresholds(s, thresholds).to_string());
+            let behavioral_signals: Option<serde_json::Value> = row.get(9);
+
+            accounts.push(AccountScore {
+                did: row.get(0),
+                handle: row.get(1),
+                toxicity_score: row.get(2),
+                topic_overlap: row.get(3),
                 threat_score,
                 threat_tier,
-                posts_analyzed: r.get::<i32, _>(6) as u32,
+                posts_analyzed: row.get::<i32, _>(6) as u32,
                 top_toxic_posts,
-                scored_at: r.get(8),
+                scored_at: row.get(8),
                 behavioral_signals: behavioral_signals.map(|v| v.to_string()),
-                context_score: r.get(10),
-                graph_distance: r.get(13),
-                fingerprint_quality: r.get(11),
-                scoring_confidence: r.get(12),
-            }
-        }))
+                context_score: row.get(10),
+                graph_distance: row.get(13),
+                fingerprint_quality: row.get(11),
+                scoring_confidence: row.get(12),
+                overlap_method: row.get(14),
+                last_active_at: row.get(15),
+                origin_amplifier_did: row.get(16),
+                raw_threat_score: row.get(17),
+                overlap_post_count: row.get::<Option<i32>, _>(18).map(|n| n as u32),
+                replies_analyzed: row.get::<Option<i32>, _>(19).map(|n| n as u32),
+                evidence_strength: row.get(20),
+                whitelisted: row.get(21),
+                peak_toxicity_attributes: None,
+            });
+        }
+        Ok(accounts)
     }
 
     async fn upsert_user_label(
@@ -789,7 +1466,10 @@ impl Database for PgDatabase {
                     a.posts_analyzed, a.top_toxic_posts,
                     to_char(a.scored_at, 'YYYY-MM-DD HH24:MI:SS') as scored_at,
                     a.behavioral_signals, a.context_score,
-                    a.fingerprint_quality, a.scoring_confidence, a.graph_distance
+                    a.fingerprint_quality, a.scoring_confidence, a.graph_distance, a.overlap_method,
+                    to_char(a.last_active_at AT TIME ZONE 'UTC', 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"') as last_active_at,
+                    a.origin_amplifier_did, a.raw_threat_score, a.overlap_post_count, a.replies_analyzed,
+                    a.evidence_strength, a.whitelisted
              FROM account_scores a
              LEFT JOIN user_labels ul ON a.user_did = ul.user_did AND a.did = ul.target_did
              WHERE a.user_did = $1 AND ul.target_did IS NULL AND a.threat_score IS NOT NULL
@@ -807,7 +1487,8 @@ impl Database for PgDatabase {
             let top_toxic_posts: Vec<ToxicPost> =
                 serde_json::from_value(top_posts_json).unwrap_or_default();
             let threat_score: Option<f64> = row.get(4);
-            let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
+            // The tier set at scoring time, with the thresholds configured then
+            let threat_tier: Option<String> = row.get(5);
             let behavioral_signals: Option<serde_json::Value> = row.get(9);
 
             accounts.push(AccountScore {
@@ -825,6 +1506,15 @@ impl Database for PgDatabase {
                 graph_distance: row.get(13),
                 fingerprint_quality: row.get(11),
                 scoring_confidence: row.get(12),
+                overlap_method: row.get(14),
+                last_active_at: row.get(15),
+                origin_amplifier_did: row.get(16),
+                raw_threat_score: row.get(17),
+                overlap_post_count: row.get::<Option<i32>, _>(18).map(|n| n as u32),
+                replies_analyzed: row.get::<Option<i32>, _>(19).map(|n| n as u32),
+                evidence_strength: row.get(20),
+                whitelisted: row.get(21),
+                peak_toxicity_attributes: None,
             });
         }
         Ok(accounts)
@@ -998,6 +1688,28 @@ impl Database for PgDatabase {
         Ok(row.get::<i64, _>(0))
     }
 
+    async fn count_accounts(&self, user_did: &str, min_score: f64) -> Result<i64> {
+        let row = sqlx_core::query::query(
+            "SELECT COUNT(*)::bigint FROM account_scores
+             WHERE user_did = $1 AND threat_score >= $2",
+        )
+        .bind(user_did)
+        .bind(min_score)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row.get::<i64, _>(0))
+    }
+
+    async fn count_events(&self, user_did: &str) -> Result<i64> {
+        let row = sqlx_core::query::query(
+            "SELECT COUNT(*)::bigint FROM amplification_events WHERE user_did = $1",
+        )
+        .bind(user_did)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row.get::<i64, _>(0))
+    }
+
     async fn has_fingerprint(&self, user_did: &str) -> Result<bool> {
         let row = sqlx_core::query::query(
             "SELECT COUNT(*) > 0 FROM topic_fingerprint WHERE user_did = $1",
@@ -1037,6 +1749,26 @@ impl Database for PgDatabase {
             .bind(user_did)
             .execute(&mut *tx)
             .await?;
+        sqlx_core::query::query("DELETE FROM follower_snapshots WHERE user_did = $1")
+            .bind(user_did)
+            .execute(&mut *tx)
+            .await?;
+        sqlx_core::query::query("DELETE FROM amplifier_followers WHERE user_did = $1")
+            .bind(user_did)
+            .execute(&mut *tx)
+            .await?;
+        sqlx_core::query::query("DELETE FROM whitelist WHERE user_did = $1")
+            .bind(user_did)
+            .execute(&mut *tx)
+            .await?;
+        sqlx_core::query::query("DELETE FROM scan_runs WHERE user_did = $1")
+            .bind(user_did)
+            .execute(&mut *tx)
+            .await?;
+        sqlx_core::query::query("DELETE FROM account_score_history WHERE user_did = $1")
+            .bind(user_did)
+            .execute(&mut *tx)
+            .await?;
         sqlx_core::query::query("DELETE FROM users WHERE did = $1")
             .bind(user_did)
             .execute(&mut *tx)
@@ -1061,4 +1793,334 @@ impl Database for PgDatabase {
         let dids = rows.iter().map(|row| row.get::<String, _>("did")).collect();
         Ok(dids)
     }
+
+    async fn get_unresolved_handle_dids(&self, user_did: &str) -> Result<Vec<String>> {
+        let rows = sqlx_core::query::query(
+            "SELECT did FROM account_scores
+             WHERE user_did = $1 AND handle LIKE 'did:%'
+             UNION
+             SELECT amplifier_did FROM amplification_events
+             WHERE user_did = $1 AND amplifier_handle LIKE 'did:%'
+             ORDER BY 1",
+        )
+        .bind(user_did)
+        .fetch_all(&self.pool)
+        .await?;
+        let dids = rows.iter().map(|row| row.get::<String, _>(0)).collect();
+        Ok(dids)
+    }
+
+    async fn update_resolved_handles(
+        &self,
+        user_did: &str,
+        resolved: &[(String, String)],
+    ) -> Result<u64> {
+        let mut tx = self.pool.begin().await?;
+        let mut updated = 0u64;
+        for (did, handle) in resolved {
+            updated += sqlx_core::query::query(
+                "UPDATE account_scores SET handle = $3
+                 WHERE user_did = $1 AND did = $2 AND handle = did",
+            )
+            .bind(user_did)
+            .bind(did)
+            .bind(handle)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            updated += sqlx_core::query::query(
+                "UPDATE amplification_events SET amplifier_handle = $3
+                 WHERE user_did = $1 AND amplifier_did = $2 AND amplifier_handle = amplifier_did",
+            )
+            .bind(user_did)
+            .bind(did)
+            .bind(handle)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        }
+        tx.commit().await?;
+        Ok(updated)
+    }
+
+    async fn get_known_dids(&self, user_did: &str) -> Result<Vec<String>> {
+        let rows = sqlx_core::query::query(
+            "SELECT did FROM account_scores WHERE user_did = $1
+             UNION
+             SELECT amplifier_did FROM amplification_events WHERE user_did = $1
+             ORDER BY 1",
+        )
+        .bind(user_did)
+        .fetch_all(&self.pool)
+        .await?;
+        let dids = rows.iter().map(|row| row.get::<String, _>(0)).collect();
+        Ok(dids)
+    }
+
+    async fn get_cached_handles(&self, dids: &[String]) -> Result<HashMap<String, String>> {
+        let rows = sqlx_core::query::query("SELECT did, handle FROM did_cache WHERE did = ANY($1)")
+            .bind(dids)
+            .fetch_all(&self.pool)
+            .await?;
+        let cached = rows
+            .iter()
+            .map(|row| (row.get::<String, _>(0), row.get::<String, _>(1)))
+            .collect();
+        Ok(cached)
+    }
+
+    async fn get_fresh_cached_handles(
+        &self,
+        dids: &[String],
+        max_age_days: u32,
+    ) -> Result<HashMap<String, String>> {
+        let rows = sqlx_core::query::query(
+            "SELECT did, handle FROM did_cache
+             WHERE did = ANY($1) AND resolved_at >= NOW() - make_interval(days => $2)",
+        )
+        .bind(dids)
+        .bind(i32::try_from(max_age_days).context("max_age_days exceeds i32 range")?)
+        .fetch_all(&self.pool)
+        .await?;
+        let cached = rows
+            .iter()
+            .map(|row| (row.get::<String, _>(0), row.get::<String, _>(1)))
+            .collect();
+        Ok(cached)
+    }
+
+    async fn cache_handles(&self, entries: &[(String, String)]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for (did, handle) in entries {
+            sqlx_core::query::query(
+                "INSERT INTO did_cache (did, handle) VALUES ($1, $2)
+                 ON CONFLICT(did) DO UPDATE SET handle = $2, resolved_at = NOW()",
+            )
+            .bind(did)
+            .bind(handle)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_follower_snapshot(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+    ) -> Result<Option<Vec<String>>> {
+        let row = sqlx_core::query::query(
+            "SELECT follower_hashes FROM follower_snapshots
+             WHERE user_did = $1 AND amplifier_did = $2",
+        )
+        .bind(user_did)
+        .bind(amplifier_did)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|r| r.get::<Vec<String>, _>(0)))
+    }
+
+    async fn save_follower_snapshot(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+        follower_hashes: &[String],
+    ) -> Result<()> {
+        sqlx_core::query::query(
+            "INSERT INTO follower_snapshots (user_did, amplifier_did, follower_hashes)
+             VALUES ($1, $2, $3)
+             ON CONFLICT(user_did, amplifier_did) DO UPDATE SET
+                follower_hashes = $3, taken_at = NOW()",
+        )
+        .bind(user_did)
+        .bind(amplifier_did)
+        .bind(follower_hashes)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn record_amplifier_followers(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+        follower_dids: &[String],
+    ) -> Result<()> {
+        sqlx_core::query::query(
+            "INSERT INTO amplifier_followers (user_did, amplifier_did, follower_did)
+             SELECT $1, $2, UNNEST($3::text[])
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(user_did)
+        .bind(amplifier_did)
+        .bind(follower_dids)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn get_amplifier_rollups(&self, user_did: &str) -> Result<Vec<AmplifierRollup>> {
+        let rows = sqlx_core::query::query(
+            "SELECT e.amplifier_did, e.amplifier_handle, e.event_count,
+                    COUNT(s.threat_score),
+                    COALESCE(AVG(s.threat_score), 0.0)::float8,
+                    COALESCE(MAX(s.threat_score), 0.0)::float8,
+                    COALESCE(SUM(s.threat_score), 0.0)::float8 AS total
+             FROM (
+                 SELECT a.amplifier_did,
+                        COALESCE(
+                            (SELECT h.amplifier_handle FROM amplification_events h
+                             WHERE h.user_did = $1 AND h.amplifier_did = a.amplifier_did
+                               AND h.amplifier_handle NOT LIKE 'did:%'
+                             ORDER BY h.detected_at DESC, h.id DESC
+                             LIMIT 1),
+                            a.amplifier_did
+                        ) AS amplifier_handle,
+                        COUNT(*) AS event_count
+                 FROM amplification_events a
+                 WHERE a.user_did = $1
+                 GROUP BY a.amplifier_did
+             ) e
+             LEFT JOIN amplifier_followers f
+                 ON f.user_did = $1 AND f.amplifier_did = e.amplifier_did
+             LEFT JOIN account_scores s
+                 ON s.user_did = $1 AND s.did = f.follower_did
+             GROUP BY e.amplifier_did, e.amplifier_handle, e.event_count
+             ORDER BY total DESC, e.amplifier_did",
+        )
+        .bind(user_did)
+        .fetch_all(&self.pool)
+        .await?;
+        let rollups = rows
+            .iter()
+            .map(|row| AmplifierRollup {
+                amplifier_did: row.get(0),
+                amplifier_handle: row.get(1),
+                event_count: row.get(2),
+                followers_scored: row.get(3),
+                avg_threat: row.get(4),
+                max_threat: row.get(5),
+                total_threat: row.get(6),
+            })
+            .collect();
+        Ok(rollups)
+    }
+
+    async fn add_to_whitelist(&self, user_did: &str, did: &str, handle: &str) -> Result<()> {
+        sqlx_core::query::query(
+            "INSERT INTO whitelist (user_did, did, handle) VALUES ($1, $2, $3)
+             ON CONFLICT(user_did, did) DO UPDATE SET handle = $3",
+        )
+        .bind(user_did)
+        .bind(did)
+        .bind(handle)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_from_whitelist(&self, user_did: &str, did: &str) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let result =
+            sqlx_core::query::query("DELETE FROM whitelist WHERE user_did = $1 AND did = $2")
+                .bind(user_did)
+                .bind(did)
+                .execute(&mut *tx)
+                .await?;
+        // The placeholder score goes too, so the next scan rescores the account
+        sqlx_core::query::query(
+            "DELETE FROM account_scores WHERE user_did = $1 AND did = $2 AND whitelisted",
+        )
+        .bind(user_did)
+        .bind(did)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn is_whitelisted(&self, user_did: &str, did: &str) -> Result<bool> {
+        let row = sqlx_core::query::query(
+            "SELECT EXISTS(SELECT 1 FROM whitelist WHERE user_did = $1 AND did = $2)",
+        )
+        .bind(user_did)
+        .bind(did)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row.get::<bool, _>(0))
+    }
+
+    async fn get_whitelist(&self, user_did: &str) -> Result<Vec<WhitelistEntry>> {
+        let rows = sqlx_core::query::query(
+            "SELECT did, handle, to_char(added_at, 'YYYY-MM-DD HH24:MI:SS') as added_at
+             FROM whitelist
+             WHERE user_did = $1
+             ORDER BY whitelist.added_at DESC, did",
+        )
+        .bind(user_did)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(|r| WhitelistEntry {
+                did: r.get(0),
+                handle: r.get(1),
+                added_at: r.get(2),
+            })
+            .collect())
+    }
+
+    async fn record_scan_run(
+        &self,
+        user_did: &str,
+        command: &str,
+        started_at: &str,
+        events_detected: i64,
+        accounts_scored: i64,
+    ) -> Result<i64> {
+        let row = sqlx_core::query::query(
+            "INSERT INTO scan_runs (user_did, command, started_at, events_detected, accounts_scored)
+             VALUES ($1, $2, $3::timestamp AT TIME ZONE 'UTC', $4, $5)
+             RETURNING id",
+        )
+        .bind(user_did)
+        .bind(command)
+        .bind(started_at)
+        .bind(events_detected)
+        .bind(accounts_scored)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(row.get::<i64, _>(0))
+    }
+
+    async fn get_scan_runs(&self, user_did: &str, limit: u32) -> Result<Vec<ScanRun>> {
+        let rows = sqlx_core::query::query(
+            "SELECT id, command,
+                    to_char(started_at AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS'),
+                    to_char(finished_at AT TIME ZONE 'UTC', 'YYYY-MM-DD HH24:MI:SS'),
+                    events_detected, accounts_scored
+             FROM scan_runs
+             WHERE user_did = $1
+             ORDER BY scan_runs.finished_at DESC, id DESC
+             LIMIT $2",
+        )
+        .bind(user_did)
+        .bind(limit.min(i32::MAX as u32) as i32)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(|r| ScanRun {
+                id: r.get(0),
+                command: r.get(1),
+                started_at: r.get(2),
+                finished_at: r.get(3),
+                events_detected: r.get(4),
+                accounts_scored: r.get(5),
+            })
+            .collect())
+    }
 }
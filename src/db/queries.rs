@@ -7,12 +7,15 @@
 // protected user. This enables multi-user support where each user's threat
 // data is isolated.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use rusqlite::{params, Connection};
 
 use super::models::{
-    AccountScore, AccuracyMetrics, AmplificationEvent, InferredPair, ThreatTier, ToxicPost,
-    UserLabel, UserRow,
+    AccountScore, AccuracyMetrics, AmplificationEvent, AmplifierRollup, InferredPair, ScanRun,
+    ScoreSmoothing, ScoreSnapshot, ThreatPage, ThreatPageQuery, ThreatTier, TierThresholds,
+    ToxicPost, UserLabel, UserRow, WhitelistEntry,
 };
 
 // --- Users ---
@@ -144,12 +147,60 @@ pub fn get_embedding(conn: &Connection, user_did: &str) -> Result<Option<Vec<f64
 
 // --- Account scores ---
 
-/// Save or update an account's scores for a specific user.
-pub fn upsert_account_score(conn: &Connection, user_did: &str, score: &AccountScore) -> Result<()> {
+/// Save or update an account's scores for a specific user, and append a
+/// snapshot to the account's score history.
+///
+/// With `smoothing` set, a rescored account's stored threat score is blended
+/// with its previous one (see `ScoreSmoothing::apply`). History snapshots
+/// always record the new score. Both are stamped with the score's own
+/// `scored_at` when it has one (a restored import), or the current time.
+///
+/// A score with a threat score but no toxicity score (overlap-only, or
+/// skipped by `--min-overlap`) never replaces one whose toxicity was
+/// measured: the stored row, its scored_at and its history are left alone.
+pub fn upsert_account_score(
+    conn: &Connection,
+    user_did: &str,
+    score: &AccountScore,
+    smoothing: Option<ScoreSmoothing>,
+) -> Result<()> {
+    if write_account_score(conn, user_did, score, smoothing)? {
+        record_score_history(conn, user_did, score)?;
+    }
+    Ok(())
+}
+
+/// The account_scores half of `upsert_account_score`: the row itself and
+/// its evidence index, without a history snapshot. Returns whether the row
+/// was written.
+fn write_account_score(
+    conn: &Connection,
+    user_did: &str,
+    score: &AccountScore,
+    smoothing: Option<ScoreSmoothing>,
+) -> Result<bool> {
+    let smoothed;
+    let score = match smoothing {
+        Some(smoothing) => {
+            // A whitelisted placeholder's zero is not a score to blend with
+            let previous: Option<f64> = conn
+                .query_row(
+                    "SELECT threat_score FROM account_scores
+                     WHERE user_did = ?1 AND did = ?2 AND whitelisted = 0",
+                    params![user_did, score.did],
+                    |row| row.get(0),
+                )
+                .optional()?
+                .flatten();
+            smoothed = smoothing.apply(score, previous);
+            &smoothed
+        }
+        None => score,
+    };
     let top_posts_json = serde_json::to_string(&score.top_toxic_posts)?;
-    conn.execute(
-        "INSERT INTO account_scores (user_did, did, handle, toxicity_score, topic_overlap, threat_score, threat_tier, posts_analyzed, top_toxic_posts, scored_at, behavioral_signals, context_score, graph_distance, fingerprint_quality, scoring_confidence)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, datetime('now'), ?10, ?11, ?12, ?13, ?14)
+    let written = conn.execute(
+        "INSERT INTO account_scores (user_did, did, handle, toxicity_score, topic_overlap, threat_score, threat_tier, posts_analyzed, top_toxic_posts, scored_at, behavioral_signals, context_score, graph_distance, fingerprint_quality, scoring_confidence, overlap_method, last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength, whitelisted)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, COALESCE(NULLIF(?23, ''), datetime('now')), ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, COALESCE(?18, ?6), ?19, ?20, ?21, ?22)
          ON CONFLICT(user_did, did) DO UPDATE SET
             handle = ?3,
             toxicity_score = ?4,
@@ -158,12 +209,24 @@ pub fn upsert_account_score(conn: &Connection, user_did: &str, score: &AccountSc
             threat_tier = ?7,
             posts_analyzed = ?8,
             top_toxic_posts = ?9,
-            scored_at = datetime('now'),
+            scored_at = COALESCE(NULLIF(?23, ''), datetime('now')),
             behavioral_signals = ?10,
             context_score = ?11,
             graph_distance = ?12,
             fingerprint_quality = ?13,
-            scoring_confidence = ?14",
+            scoring_confidence = ?14,
+            overlap_method = ?15,
+            last_active_at = COALESCE(?16, last_active_at),
+            origin_amplifier_did = COALESCE(?17, origin_amplifier_did),
+            raw_threat_score = COALESCE(?18, ?6),
+            overlap_post_count = ?19,
+            replies_analyzed = ?20,
+            evidence_strength = ?21,
+            whitelisted = ?22
+         WHERE excluded.toxicity_score IS NOT NULL
+            OR excluded.threat_score IS NULL
+            OR excluded.whitelisted
+            OR account_scores.toxicity_score IS NULL",
         params![
             user_did,
             score.did,
@@ -179,50 +242,136 @@ pub fn upsert_account_score(conn: &Connection, user_did: &str, score: &AccountSc
             score.graph_distance,
             score.fingerprint_quality,
             score.scoring_confidence,
+            score.overlap_method,
+            score.last_active_at,
+            score.origin_amplifier_did,
+            score.raw_threat_score,
+            score.overlap_post_count,
+            score.replies_analyzed,
+            score.evidence_strength,
+            score.whitelisted,
+            score.scored_at,
         ],
     )?;
+    if written == 0 {
+        return Ok(false);
+    }
+    index_evidence(conn, user_did, score)?;
+    Ok(true)
+}
+
+/// Append the new score (before any smoothing) to the account's history.
+fn record_score_history(conn: &Connection, user_did: &str, score: &AccountScore) -> Result<()> {
+    conn.execute(
+        "INSERT INTO account_score_history (user_did, did, threat_score, toxicity_score, scored_at)
+         VALUES (?1, ?2, ?3, ?4, COALESCE(NULLIF(?5, ''), datetime('now')))",
+        params![
+            user_did,
+            score.did,
+            score.threat_score,
+            score.toxicity_score,
+            score.scored_at
+        ],
+    )?;
+    Ok(())
+}
+
+/// Replace an account's rows in the evidence full-text index with one per
+/// post in its current `top_toxic_posts`.
+fn index_evidence(conn: &Connection, user_did: &str, score: &AccountScore) -> Result<()> {
+    conn.execute(
+        "DELETE FROM evidence_fts WHERE user_did = ?1 AND did = ?2",
+        params![user_did, score.did],
+    )?;
+    let mut stmt =
+        conn.prepare_cached("INSERT INTO evidence_fts (text, user_did, did) VALUES (?1, ?2, ?3)")?;
+    for post in &score.top_toxic_posts {
+        stmt.execute(params![post.text, user_did, score.did])?;
+    }
+    Ok(())
+}
+
+/// Save or update many account scores in one transaction. A DID that
+/// appears more than once is written once, with its later score (see
+/// `merge_repeated_dids`), so smoothing blends it once like the Postgres
+/// backend does. History keeps every score for the rows that were written.
+pub fn upsert_account_scores(
+    conn: &Connection,
+    user_did: &str,
+    scores: &[AccountScore],
+    smoothing: Option<ScoreSmoothing>,
+) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    let merged = super::merge_repeated_dids(scores);
+    let mut written = HashMap::new();
+    for score in &merged {
+        if write_account_score(&tx, user_did, score, smoothing)? {
+            written.insert(score.did.as_str(), score);
+        }
+    }
+    // A repeated DID's unmeasured score that the merge dropped isn't history
+    for score in scores {
+        if written
+            .get(score.did.as_str())
+            .is_some_and(|w| score.may_replace(w))
+        {
+            record_score_history(&tx, user_did, score)?;
+        }
+    }
+    tx.commit()?;
     Ok(())
 }
 
 /// Get all scored accounts for a specific user, ranked by threat score descending.
+/// Tiers are recomputed from each stored score with `thresholds`.
 pub fn get_ranked_threats(
     conn: &Connection,
     user_did: &str,
     min_score: f64,
+    thresholds: &TierThresholds,
+) -> Result<Vec<AccountScore>> {
+    ranked_threats(conn, user_did, min_score, None, thresholds)
+}
+
+/// Like `get_ranked_threats`, but only accounts that posted within the last
+/// `active_within_days` days. Accounts with no recorded activity are left out.
+pub fn get_ranked_threats_active_within(
+    conn: &Connection,
+    user_did: &str,
+    min_score: f64,
+    active_within_days: u32,
+    thresholds: &TierThresholds,
+) -> Result<Vec<AccountScore>> {
+    ranked_threats(
+        conn,
+        user_did,
+        min_score,
+        Some(active_within_days),
+        thresholds,
+    )
+}
+
+fn ranked_threats(
+    conn: &Connection,
+    user_did: &str,
+    min_score: f64,
+    active_within_days: Option<u32>,
+    thresholds: &TierThresholds,
 ) -> Result<Vec<AccountScore>> {
     let mut stmt = conn.prepare(
         "SELECT did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
                 posts_analyzed, top_toxic_posts, scored_at, behavioral_signals,
-                graph_distance, fingerprint_quality, scoring_confidence, context_score
+                graph_distance, fingerprint_quality, scoring_confidence, context_score,
+                overlap_method, last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength, whitelisted
          FROM account_scores
          WHERE user_did = ?1 AND threat_score >= ?2
+           AND (?3 IS NULL OR datetime(last_active_at) >= datetime('now', ?3))
          ORDER BY threat_score DESC",
     )?;
 
-    let rows = stmt.query_map(params![user_did, min_score], |row| {
-        let top_posts_json: String = row.get(7)?;
-        let top_toxic_posts: Vec<ToxicPost> =
-            serde_json::from_str(&top_posts_json).unwrap_or_default();
-        // Recalculate tier from stored score so threshold changes
-        // take effect without rescanning.
-        let threat_score: Option<f64> = row.get(4)?;
-        let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
-        Ok(AccountScore {
-            did: row.get(0)?,
-            handle: row.get(1)?,
-            toxicity_score: row.get(2)?,
-            topic_overlap: row.get(3)?,
-            threat_score,
-            threat_tier,
-            posts_analyzed: row.get(6)?,
-            top_toxic_posts,
-            scored_at: row.get(8)?,
-            behavioral_signals: row.get(9)?,
-            context_score: row.get(13)?,
-            graph_distance: row.get(10)?,
-            fingerprint_quality: row.get(11)?,
-            scoring_confidence: row.get(12)?,
-        })
+    let active_window = active_within_days.map(|days| format!("-{days} days"));
+    let rows = stmt.query_map(params![user_did, min_score, active_window], |row| {
+        ranked_row(row, thresholds)
     })?;
 
     let mut accounts = Vec::new();
@@ -232,6 +381,113 @@ pub fn get_ranked_threats(
     Ok(accounts)
 }
 
+/// An `AccountScore` from a row of the ranked-threat column list (see
+/// `ranked_threats`), with its tier recomputed using `thresholds`.
+fn ranked_row(
+    row: &rusqlite::Row<'_>,
+    thresholds: &TierThresholds,
+) -> rusqlite::Result<AccountScore> {
+    let top_posts_json: String = row.get(7)?;
+    let top_toxic_posts: Vec<ToxicPost> = serde_json::from_str(&top_posts_json).unwrap_or_default();
+    // Recalculate tier from stored score so threshold changes
+    // take effect without rescanning.
+    let threat_score: Option<f64> = row.get(4)?;
+    let threat_tier =
+        threat_score.map(|s| ThreatTier::from_score_with_thresholds(s, thresholds).to_string());
+    Ok(AccountScore {
+        did: row.get(0)?,
+        handle: row.get(1)?,
+        toxicity_score: row.get(2)?,
+        topic_overlap: row.get(3)?,
+        threat_score,
+        threat_tier,
+        posts_analyzed: row.get(6)?,
+        top_toxic_posts,
+        scored_at: row.get(8)?,
+        behavioral_signals: row.get(9)?,
+        context_score: row.get(13)?,
+        graph_distance: row.get(10)?,
+        fingerprint_quality: row.get(11)?,
+        scoring_confidence: row.get(12)?,
+        overlap_method: row.get(14)?,
+        last_active_at: row.get(15)?,
+        origin_amplifier_did: row.get(16)?,
+        raw_threat_score: row.get(17)?,
+        overlap_post_count: row.get(18)?,
+        replies_analyzed: row.get(19)?,
+        evidence_strength: row.get(20)?,
+        whitelisted: row.get(21)?,
+        peak_toxicity_attributes: None,
+    })
+}
+
+/// One page of a user's scored accounts, ranked by threat score descending
+/// (ties broken by DID so pages don't shift), plus how many accounts match
+/// across all pages. `query.tier` selects the score range that tier covers
+/// under `thresholds`; `query.handle` is a case-insensitive substring match.
+pub fn get_threats_paged(
+    conn: &Connection,
+    user_did: &str,
+    query: &ThreatPageQuery,
+    thresholds: &TierThresholds,
+) -> Result<ThreatPage> {
+    let floor = query.tier.map(|t| thresholds.floor(t));
+    let ceiling = query.tier.and_then(|t| thresholds.ceiling(t));
+    let handle_pattern = query.handle.as_deref().map(like_pattern);
+    let filter = "WHERE user_did = ?1 AND threat_score >= ?2
+           AND (?3 IS NULL OR threat_score >= ?3)
+           AND (?4 IS NULL OR threat_score < ?4)
+           AND (?5 IS NULL OR handle LIKE ?5 ESCAPE '\\')";
+
+    let total: i64 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM account_scores {filter}"),
+        params![user_did, query.min_score, floor, ceiling, handle_pattern],
+        |row| row.get(0),
+    )?;
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
+                posts_analyzed, top_toxic_posts, scored_at, behavioral_signals,
+                graph_distance, fingerprint_quality, scoring_confidence, context_score,
+                overlap_method, last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength, whitelisted
+         FROM account_scores
+         {filter}
+         ORDER BY threat_score DESC, did
+         LIMIT ?6 OFFSET ?7"
+    ))?;
+    let rows = stmt.query_map(
+        params![
+            user_did,
+            query.min_score,
+            floor,
+            ceiling,
+            handle_pattern,
+            query.limit,
+            query.offset
+        ],
+        |row| ranked_row(row, thresholds),
+    )?;
+
+    let mut items = Vec::new();
+    for row in rows {
+        items.push(row?);
+    }
+    Ok(ThreatPage {
+        items,
+        total: total as u64,
+    })
+}
+
+/// A LIKE pattern matching `text` anywhere, with `%`, `_` and `\` in it
+/// taken literally.
+fn like_pattern(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
 /// Check if an account's score is stale (older than the given number of days) for a specific user.
 pub fn is_score_stale(
     conn: &Connection,
@@ -259,6 +515,36 @@ pub fn is_score_stale(
     }
 }
 
+/// Delete a user's scores older than `older_than_days` and at or below
+/// `max_tier`. Unscored rows (NULL threat_score) are always eligible.
+/// Returns the number of rows deleted.
+pub fn prune_scores(
+    conn: &Connection,
+    user_did: &str,
+    older_than_days: u32,
+    max_tier: ThreatTier,
+    thresholds: &TierThresholds,
+) -> Result<u64> {
+    let deleted = conn.execute(
+        "DELETE FROM account_scores
+         WHERE user_did = ?1
+           AND datetime(scored_at) < datetime('now', ?2)
+           AND (?3 IS NULL OR threat_score IS NULL OR threat_score < ?3)",
+        params![
+            user_did,
+            format!("-{older_than_days} days"),
+            thresholds.ceiling(max_tier),
+        ],
+    )?;
+    conn.execute(
+        "DELETE FROM evidence_fts
+         WHERE user_did = ?1
+           AND did NOT IN (SELECT did FROM account_scores WHERE user_did = ?1)",
+        params![user_did],
+    )?;
+    Ok(deleted as u64)
+}
+
 // --- Amplification events ---
 
 /// Insert an amplification event with an explicit detected_at timestamp for a specific user.
@@ -323,6 +609,30 @@ pub fn insert_amplification_event(
     Ok(conn.last_insert_rowid())
 }
 
+/// Mark an event's amplifier followers as fetched and scored.
+pub fn mark_event_followers_scored(conn: &Connection, event_id: i64) -> Result<()> {
+    conn.execute(
+        "UPDATE amplification_events
+         SET followers_fetched = 1, followers_scored = 1
+         WHERE id = ?1",
+        params![event_id],
+    )?;
+    Ok(())
+}
+
+/// Amplifier post URIs of a user's events whose followers have been scored.
+pub fn get_followers_scored_post_uris(conn: &Connection, user_did: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT amplifier_post_uri
+         FROM amplification_events
+         WHERE user_did = ?1 AND followers_scored = 1 AND amplifier_post_uri IS NOT NULL",
+    )?;
+    let uris = stmt
+        .query_map(params![user_did], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(uris)
+}
+
 /// Get recent amplification events for a specific user.
 pub fn get_recent_events(
     conn: &Connection,
@@ -339,22 +649,37 @@ pub fn get_recent_events(
          LIMIT ?2",
     )?;
 
-    let rows = stmt.query_map(params![user_did, limit], |row| {
-        Ok(AmplificationEvent {
-            id: row.get(0)?,
-            event_type: row.get(1)?,
-            amplifier_did: row.get(2)?,
-            amplifier_handle: row.get(3)?,
-            original_post_uri: row.get(4)?,
-            amplifier_post_uri: row.get(5)?,
-            amplifier_text: row.get(6)?,
-            detected_at: row.get(7)?,
-            followers_fetched: row.get::<_, i32>(8)? != 0,
-            followers_scored: row.get::<_, i32>(9)? != 0,
-            original_post_text: row.get(10)?,
-            context_score: row.get(11)?,
-        })
-    })?;
+    let rows = stmt.query_map(params![user_did, limit], event_from_row)?;
+
+    let mut events = Vec::new();
+    for row in rows {
+        events.push(row?);
+    }
+    Ok(events)
+}
+
+/// Like `get_recent_events`, but only events detected in the last
+/// `since_days` days.
+pub fn get_recent_events_since(
+    conn: &Connection,
+    user_did: &str,
+    limit: u32,
+    since_days: u32,
+) -> Result<Vec<AmplificationEvent>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, event_type, amplifier_did, amplifier_handle, original_post_uri,
+                amplifier_post_uri, amplifier_text, detected_at, followers_fetched, followers_scored,
+                original_post_text, context_score
+         FROM amplification_events
+         WHERE user_did = ?1 AND datetime(detected_at) >= datetime('now', ?3)
+         ORDER BY detected_at DESC
+         LIMIT ?2",
+    )?;
+
+    let rows = stmt.query_map(
+        params![user_did, limit, format!("-{since_days} days")],
+        event_from_row,
+    )?;
 
     let mut events = Vec::new();
     for row in rows {
@@ -363,6 +688,24 @@ pub fn get_recent_events(
     Ok(events)
 }
 
+/// Map a row selected with the column order used by `get_recent_events`.
+fn event_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<AmplificationEvent> {
+    Ok(AmplificationEvent {
+        id: row.get(0)?,
+        event_type: row.get(1)?,
+        amplifier_did: row.get(2)?,
+        amplifier_handle: row.get(3)?,
+        original_post_uri: row.get(4)?,
+        amplifier_post_uri: row.get(5)?,
+        amplifier_text: row.get(6)?,
+        detected_at: row.get(7)?,
+        followers_fetched: row.get::<_, i32>(8)? != 0,
+        followers_scored: row.get::<_, i32>(9)? != 0,
+        original_post_text: row.get(10)?,
+        context_score: row.get(11)?,
+    })
+}
+
 /// Get amplification events for pile-on detection for a specific user.
 /// Returns (amplifier_did, original_post_uri, detected_at) tuples.
 pub fn get_events_for_pile_on(
@@ -465,7 +808,8 @@ pub fn get_account_by_handle(
     let mut stmt = conn.prepare(
         "SELECT did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
                 posts_analyzed, top_toxic_posts, scored_at, behavioral_signals,
-                fingerprint_quality, scoring_confidence, context_score, graph_distance
+                fingerprint_quality, scoring_confidence, context_score, graph_distance,
+                overlap_method, last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength, whitelisted
          FROM account_scores
          WHERE user_did = ?1 AND lower(handle) = lower(?2)
          LIMIT 1",
@@ -476,7 +820,8 @@ pub fn get_account_by_handle(
             let top_toxic_posts: Vec<ToxicPost> =
                 serde_json::from_str(&top_posts_json).unwrap_or_default();
             let threat_score: Option<f64> = row.get(4)?;
-            let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
+            // The tier set at scoring time, with the thresholds configured then
+            let threat_tier: Option<String> = row.get(5)?;
             Ok(AccountScore {
                 did: row.get(0)?,
                 handle: row.get(1)?,
@@ -492,52 +837,200 @@ pub fn get_account_by_handle(
                 graph_distance: row.get(13)?,
                 fingerprint_quality: row.get(10)?,
                 scoring_confidence: row.get(11)?,
+                overlap_method: row.get(14)?,
+                last_active_at: row.get(15)?,
+                origin_amplifier_did: row.get(16)?,
+                raw_threat_score: row.get(17)?,
+                overlap_post_count: row.get(18)?,
+                replies_analyzed: row.get(19)?,
+                evidence_strength: row.get(20)?,
+                whitelisted: row.get(21)?,
+                peak_toxicity_attributes: None,
             })
         })
         .optional()?;
     Ok(result)
 }
 
+/// Column list read by `stored_row`.
+const STORED_SCORE_COLUMNS: &str =
+    "did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
+     posts_analyzed, top_toxic_posts, scored_at, behavioral_signals,
+     fingerprint_quality, scoring_confidence, context_score, graph_distance,
+     overlap_method, last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength, whitelisted";
+
+/// An `AccountScore` from a row of `STORED_SCORE_COLUMNS`, keeping the tier
+/// set at scoring time (with the thresholds configured then).
+fn stored_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<AccountScore> {
+    let top_posts_json: String = row.get(7)?;
+    let top_toxic_posts: Vec<ToxicPost> = serde_json::from_str(&top_posts_json).unwrap_or_default();
+    Ok(AccountScore {
+        did: row.get(0)?,
+        handle: row.get(1)?,
+        toxicity_score: row.get(2)?,
+        topic_overlap: row.get(3)?,
+        threat_score: row.get(4)?,
+        threat_tier: row.get(5)?,
+        posts_analyzed: row.get(6)?,
+        top_toxic_posts,
+        scored_at: row.get(8)?,
+        behavioral_signals: row.get(9)?,
+        context_score: row.get(12)?,
+        graph_distance: row.get(13)?,
+        fingerprint_quality: row.get(10)?,
+        scoring_confidence: row.get(11)?,
+        overlap_method: row.get(14)?,
+        last_active_at: row.get(15)?,
+        origin_amplifier_did: row.get(16)?,
+        raw_threat_score: row.get(17)?,
+        overlap_post_count: row.get(18)?,
+        replies_analyzed: row.get(19)?,
+        evidence_strength: row.get(20)?,
+        whitelisted: row.get(21)?,
+        peak_toxicity_attributes: None,
+    })
+}
+
 /// Get a single account score by DID for a specific user.
 pub fn get_account_by_did(
     conn: &Connection,
     user_did: &str,
     did: &str,
 ) -> Result<Option<AccountScore>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {STORED_SCORE_COLUMNS}
+         FROM account_scores
+         WHERE user_did = ?1 AND did = ?2
+         LIMIT 1"
+    ))?;
+    let result = stmt
+        .query_row(params![user_did, did], stored_row)
+        .optional()?;
+    Ok(result)
+}
+
+/// Every account score stored for a user, unscored rows included, as
+/// stored: no score floor and no tier recomputation. Ordered by DID.
+pub fn get_all_account_scores(conn: &Connection, user_did: &str) -> Result<Vec<AccountScore>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {STORED_SCORE_COLUMNS}
+         FROM account_scores
+         WHERE user_did = ?1
+         ORDER BY did"
+    ))?;
+    let scores = stmt
+        .query_map(params![user_did], stored_row)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(scores)
+}
+
+/// A user's scored accounts whose toxic post evidence matches `query`,
+/// highest threat score first. Every word in the query must appear in one
+/// of an account's evidence posts (stemmed, so "threaten" finds
+/// "threatening"); punctuation and FTS5 operators are treated as text.
+/// Tiers are recomputed from each stored score with `thresholds`.
+pub fn search_evidence(
+    conn: &Connection,
+    user_did: &str,
+    query: &str,
+    thresholds: &TierThresholds,
+) -> Result<Vec<AccountScore>> {
+    let Some(match_expr) = fts_match_expression(query) else {
+        return Ok(Vec::new());
+    };
     let mut stmt = conn.prepare(
         "SELECT did, handle, toxicity_score, topic_overlap, threat_score, threat_tier,
                 posts_analyzed, top_toxic_posts, scored_at, behavioral_signals,
-                fingerprint_quality, scoring_confidence, context_score, graph_distance
+                fingerprint_quality, scoring_confidence, context_score, graph_distance,
+                overlap_method, last_active_at, origin_amplifier_did, raw_threat_score, overlap_post_count, replies_analyzed, evidence_strength, whitelisted
          FROM account_scores
-         WHERE user_did = ?1 AND did = ?2
-         LIMIT 1",
+         WHERE user_did = ?1
+           AND did IN (
+               SELECT did FROM evidence_fts
+               WHERE evidence_fts MATCH ?2 AND user_did = ?1
+           )
+         ORDER BY threat_score DESC",
     )?;
-    let result = stmt
-        .query_row(params![user_did, did], |row| {
-            let top_posts_json: String = row.get(7)?;
-            let top_toxic_posts: Vec<ToxicPost> =
-                serde_json::from_str(&top_posts_json).unwrap_or_default();
-            let threat_score: Option<f64> = row.get(4)?;
-            let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
-            Ok(AccountScore {
-                did: row.get(0)?,
-                handle: row.get(1)?,
-                toxicity_score: row.get(2)?,
-                topic_overlap: row.get(3)?,
-                threat_score,
-                threat_tier,
-                posts_analyzed: row.get(6)?,
-                top_toxic_posts,
-                scored_at: row.get(8)?,
-                behavioral_signals: row.get(9)?,
-                context_score: row.get(12)?,
-                graph_distance: row.get(13)?,
-                fingerprint_quality: row.get(10)?,
-                scoring_confidence: row.get(11)?,
-            })
+    let rows = stmt.query_map(params![user_did, match_expr], |row| {
+        let top_posts_json: String = row.get(7)?;
+        let top_toxic_posts: Vec<ToxicPost> =
+            serde_json::from_str(&top_posts_json).unwrap_or_default();
+        let threat_score: Option<f64> = row.get(4)?;
+        let threat_tier =
+            threat_score.map(|s| ThreatTier::from_score_with_thresholds(s, thresholds).to_string());
+        Ok(AccountScore {
+            did: row.get(0)?,
+            handle: row.get(1)?,
+            toxicity_score: row.get(2)?,
+            topic_overlap: row.get(3)?,
+            threat_score,
+            threat_tier,
+            posts_analyzed: row.get(6)?,
+            top_toxic_posts,
+            scored_at: row.get(8)?,
+            behavioral_signals: row.get(9)?,
+            context_score: row.get(12)?,
+            graph_distance: row.get(13)?,
+            fingerprint_quality: row.get(10)?,
+            scoring_confidence: row.get(11)?,
+            overlap_method: row.get(14)?,
+            last_active_at: row.get(15)?,
+            origin_amplifier_did: row.get(16)?,
+            raw_threat_score: row.get(17)?,
+            overlap_post_count: row.get(18)?,
+            replies_analyzed: row.get(19)?,
+            evidence_strength: row.get(20)?,
+            whitelisted: row.get(21)?,
+            peak_toxicity_attributes: None,
         })
-        .optional()?;
-    Ok(result)
+    })?;
+    let mut accounts = Vec::new();
+    for row in rows {
+        accounts.push(row?);
+    }
+    Ok(accounts)
+}
+
+/// Turn a plain search phrase into an FTS5 MATCH expression: each word
+/// quoted as a string, so the words are ANDed together like Postgres's
+/// plainto_tsquery. Words with no letters or digits can't match anything
+/// and are dropped. `None` for a query with no words left.
+fn fts_match_expression(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// An account's last `limit` score snapshots, oldest first.
+pub fn get_account_history(
+    conn: &Connection,
+    user_did: &str,
+    did: &str,
+    limit: u32,
+) -> Result<Vec<ScoreSnapshot>> {
+    let mut stmt = conn.prepare(
+        "SELECT threat_score, toxicity_score, scored_at FROM (
+             SELECT id, threat_score, toxicity_score, scored_at
+             FROM account_score_history
+             WHERE user_did = ?1 AND did = ?2
+             ORDER BY scored_at DESC, id DESC
+             LIMIT ?3
+         )
+         ORDER BY scored_at, id",
+    )?;
+    let snapshots = stmt
+        .query_map(params![user_did, did, limit], |row| {
+            Ok(ScoreSnapshot {
+                threat_score: row.get(0)?,
+                toxicity_score: row.get(1)?,
+                scored_at: row.get(2)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(snapshots)
 }
 
 // --- User labels ---
@@ -594,7 +1087,9 @@ pub fn get_unlabeled_accounts(
     let mut stmt = conn.prepare(
         "SELECT a.did, a.handle, a.toxicity_score, a.topic_overlap, a.threat_score, a.threat_tier,
                 a.posts_analyzed, a.top_toxic_posts, a.scored_at, a.behavioral_signals,
-                a.context_score, a.fingerprint_quality, a.scoring_confidence
+                a.context_score, a.fingerprint_quality, a.scoring_confidence, a.overlap_method,
+                a.last_active_at, a.origin_amplifier_did, a.raw_threat_score, a.overlap_post_count, a.replies_analyzed,
+                a.evidence_strength, a.whitelisted
          FROM account_scores a
          LEFT JOIN user_labels ul ON a.user_did = ul.user_did AND a.did = ul.target_did
          WHERE a.user_did = ?1 AND ul.target_did IS NULL AND a.threat_score IS NOT NULL
@@ -607,7 +1102,8 @@ pub fn get_unlabeled_accounts(
         let top_toxic_posts: Vec<ToxicPost> =
             serde_json::from_str(&top_posts_json).unwrap_or_default();
         let threat_score: Option<f64> = row.get(4)?;
-        let threat_tier = threat_score.map(|s| ThreatTier::from_score(s).to_string());
+        // The tier set at scoring time, with the thresholds configured then
+        let threat_tier: Option<String> = row.get(5)?;
         Ok(AccountScore {
             did: row.get(0)?,
             handle: row.get(1)?,
@@ -623,6 +1119,15 @@ pub fn get_unlabeled_accounts(
             graph_distance: None,
             fingerprint_quality: row.get(11)?,
             scoring_confidence: row.get(12)?,
+            overlap_method: row.get(13)?,
+            last_active_at: row.get(14)?,
+            origin_amplifier_did: row.get(15)?,
+            raw_threat_score: row.get(16)?,
+            overlap_post_count: row.get(17)?,
+            replies_analyzed: row.get(18)?,
+            evidence_strength: row.get(19)?,
+            whitelisted: row.get(20)?,
+            peak_toxicity_attributes: None,
         })
     })?;
 
@@ -805,6 +1310,26 @@ pub fn get_scored_account_count(conn: &Connection, user_did: &str) -> Result<i64
     Ok(count)
 }
 
+/// Count accounts whose threat score is at least `min_score`.
+pub fn count_accounts(conn: &Connection, user_did: &str, min_score: f64) -> Result<i64> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM account_scores WHERE user_did = ?1 AND threat_score >= ?2",
+        params![user_did, min_score],
+        |row| row.get(0),
+    )?;
+    Ok(count)
+}
+
+/// Count amplification events for a user.
+pub fn count_events(conn: &Connection, user_did: &str) -> Result<i64> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM amplification_events WHERE user_did = ?1",
+        params![user_did],
+        |row| row.get(0),
+    )?;
+    Ok(count)
+}
+
 /// Check if a topic fingerprint exists for a user.
 pub fn has_fingerprint(conn: &Connection, user_did: &str) -> Result<bool> {
     let count: i64 = conn.query_row(
@@ -833,6 +1358,10 @@ pub fn delete_user_data(conn: &Connection, user_did: &str) -> Result<()> {
         "DELETE FROM account_scores WHERE user_did = ?1",
         params![user_did],
     )?;
+    conn.execute(
+        "DELETE FROM evidence_fts WHERE user_did = ?1",
+        params![user_did],
+    )?;
     conn.execute(
         "DELETE FROM scan_state WHERE user_did = ?1",
         params![user_did],
@@ -841,6 +1370,26 @@ pub fn delete_user_data(conn: &Connection, user_did: &str) -> Result<()> {
         "DELETE FROM topic_fingerprint WHERE user_did = ?1",
         params![user_did],
     )?;
+    conn.execute(
+        "DELETE FROM follower_snapshots WHERE user_did = ?1",
+        params![user_did],
+    )?;
+    conn.execute(
+        "DELETE FROM amplifier_followers WHERE user_did = ?1",
+        params![user_did],
+    )?;
+    conn.execute(
+        "DELETE FROM whitelist WHERE user_did = ?1",
+        params![user_did],
+    )?;
+    conn.execute(
+        "DELETE FROM scan_runs WHERE user_did = ?1",
+        params![user_did],
+    )?;
+    conn.execute(
+        "DELETE FROM account_score_history WHERE user_did = ?1",
+        params![user_did],
+    )?;
     conn.execute("DELETE FROM users WHERE did = ?1", params![user_did])?;
     Ok(())
 }
@@ -863,6 +1412,336 @@ pub fn get_all_scored_dids(conn: &Connection, user_did: &str) -> Result<Vec<Stri
     Ok(dids)
 }
 
+/// Distinct DIDs whose stored handle is still the raw DID.
+pub fn get_unresolved_handle_dids(conn: &Connection, user_did: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT did FROM account_scores
+         WHERE user_did = ?1 AND handle LIKE 'did:%'
+         UNION
+         SELECT amplifier_did FROM amplification_events
+         WHERE user_did = ?1 AND amplifier_handle LIKE 'did:%'
+         ORDER BY 1",
+    )?;
+    let dids = stmt
+        .query_map(params![user_did], |row| row.get(0))?
+        .collect::<std::result::Result<Vec<String>, _>>()?;
+    Ok(dids)
+}
+
+/// Replace raw-DID handles with resolved ones. Only rows still holding the
+/// DID are touched, so a handle set by a newer scan is never overwritten.
+/// All updates run in one transaction; returns the number of rows updated.
+pub fn update_resolved_handles(
+    conn: &Connection,
+    user_did: &str,
+    resolved: &[(String, String)],
+) -> Result<u64> {
+    let tx = conn.unchecked_transaction()?;
+    let mut updated = 0u64;
+    for (did, handle) in resolved {
+        updated += tx.execute(
+            "UPDATE account_scores SET handle = ?3
+             WHERE user_did = ?1 AND did = ?2 AND handle = did",
+            params![user_did, did, handle],
+        )? as u64;
+        updated += tx.execute(
+            "UPDATE amplification_events SET amplifier_handle = ?3
+             WHERE user_did = ?1 AND amplifier_did = ?2 AND amplifier_handle = amplifier_did",
+            params![user_did, did, handle],
+        )? as u64;
+    }
+    tx.commit()?;
+    Ok(updated)
+}
+
+/// Distinct DIDs stored for a user: scored accounts plus amplifiers.
+pub fn get_known_dids(conn: &Connection, user_did: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT did FROM account_scores WHERE user_did = ?1
+         UNION
+         SELECT amplifier_did FROM amplification_events WHERE user_did = ?1
+         ORDER BY 1",
+    )?;
+    let dids = stmt
+        .query_map(params![user_did], |row| row.get(0))?
+        .collect::<std::result::Result<Vec<String>, _>>()?;
+    Ok(dids)
+}
+
+/// Cached handles for the given DIDs; uncached DIDs are left out.
+pub fn get_cached_handles(
+    conn: &Connection,
+    dids: &[String],
+) -> Result<std::collections::HashMap<String, String>> {
+    let mut stmt = conn.prepare("SELECT handle FROM did_cache WHERE did = ?1")?;
+    let mut cached = std::collections::HashMap::new();
+    for did in dids {
+        if let Some(handle) = stmt
+            .query_row(params![did], |row| row.get::<_, String>(0))
+            .optional()?
+        {
+            cached.insert(did.clone(), handle);
+        }
+    }
+    Ok(cached)
+}
+
+/// Cached handles resolved within the last `max_age_days`; uncached and
+/// stale DIDs are left out.
+pub fn get_fresh_cached_handles(
+    conn: &Connection,
+    dids: &[String],
+    max_age_days: u32,
+) -> Result<std::collections::HashMap<String, String>> {
+    let mut stmt = conn.prepare(
+        "SELECT handle FROM did_cache
+         WHERE did = ?1 AND datetime(resolved_at) >= datetime('now', ?2)",
+    )?;
+    let window = format!("-{max_age_days} days");
+    let mut cached = std::collections::HashMap::new();
+    for did in dids {
+        if let Some(handle) = stmt
+            .query_row(params![did, window], |row| row.get::<_, String>(0))
+            .optional()?
+        {
+            cached.insert(did.clone(), handle);
+        }
+    }
+    Ok(cached)
+}
+
+/// Insert or refresh DID → handle cache entries in one transaction.
+pub fn cache_handles(conn: &Connection, entries: &[(String, String)]) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    for (did, handle) in entries {
+        tx.execute(
+            "INSERT INTO did_cache (did, handle) VALUES (?1, ?2)
+             ON CONFLICT(did) DO UPDATE SET handle = ?2, resolved_at = datetime('now')",
+            params![did, handle],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// The hashed follower DIDs last recorded for an amplifier.
+pub fn get_follower_snapshot(
+    conn: &Connection,
+    user_did: &str,
+    amplifier_did: &str,
+) -> Result<Option<Vec<String>>> {
+    let json: Option<String> = conn
+        .query_row(
+            "SELECT follower_hashes FROM follower_snapshots
+             WHERE user_did = ?1 AND amplifier_did = ?2",
+            params![user_did, amplifier_did],
+            |row| row.get(0),
+        )
+        .optional()?;
+    match json {
+        Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+        None => Ok(None),
+    }
+}
+
+/// Replace an amplifier's follower snapshot — only the latest is kept.
+pub fn save_follower_snapshot(
+    conn: &Connection,
+    user_did: &str,
+    amplifier_did: &str,
+    follower_hashes: &[String],
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO follower_snapshots (user_did, amplifier_did, follower_hashes)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(user_did, amplifier_did) DO UPDATE SET
+            follower_hashes = ?3, taken_at = datetime('now')",
+        params![
+            user_did,
+            amplifier_did,
+            serde_json::to_string(follower_hashes)?
+        ],
+    )?;
+    Ok(())
+}
+
+/// Link followers to the amplifier they were found through. Existing links
+/// are left alone; all inserts run in one transaction.
+pub fn record_amplifier_followers(
+    conn: &Connection,
+    user_did: &str,
+    amplifier_did: &str,
+    follower_dids: &[String],
+) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    for follower in follower_dids {
+        tx.execute(
+            "INSERT OR IGNORE INTO amplifier_followers (user_did, amplifier_did, follower_did)
+             VALUES (?1, ?2, ?3)",
+            params![user_did, amplifier_did, follower],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Per-amplifier threat rollups. Amplifiers with no scored followers are
+/// included with zero counts so the list covers every amplifier.
+pub fn get_amplifier_rollups(conn: &Connection, user_did: &str) -> Result<Vec<AmplifierRollup>> {
+    let mut stmt = conn.prepare(
+        "SELECT e.amplifier_did, e.amplifier_handle, e.event_count,
+                COUNT(s.threat_score),
+                COALESCE(AVG(s.threat_score), 0.0),
+                COALESCE(MAX(s.threat_score), 0.0),
+                COALESCE(SUM(s.threat_score), 0.0) AS total
+         FROM (
+             SELECT a.amplifier_did,
+                    COALESCE(
+                        (SELECT h.amplifier_handle FROM amplification_events h
+                         WHERE h.user_did = ?1 AND h.amplifier_did = a.amplifier_did
+                           AND h.amplifier_handle NOT LIKE 'did:%'
+                         ORDER BY h.detected_at DESC, h.id DESC
+                         LIMIT 1),
+                        a.amplifier_did
+                    ) AS amplifier_handle,
+                    COUNT(*) AS event_count
+             FROM amplification_events a
+             WHERE a.user_did = ?1
+             GROUP BY a.amplifier_did
+         ) e
+         LEFT JOIN amplifier_followers f
+             ON f.user_did = ?1 AND f.amplifier_did = e.amplifier_did
+         LEFT JOIN account_scores s
+             ON s.user_did = ?1 AND s.did = f.follower_did
+         GROUP BY e.amplifier_did, e.amplifier_handle, e.event_count
+         ORDER BY total DESC, e.amplifier_did",
+    )?;
+    let rollups = stmt
+        .query_map(params![user_did], |row| {
+            Ok(AmplifierRollup {
+                amplifier_did: row.get(0)?,
+                amplifier_handle: row.get(1)?,
+                event_count: row.get(2)?,
+                followers_scored: row.get(3)?,
+                avg_threat: row.get(4)?,
+                max_threat: row.get(5)?,
+                total_threat: row.get(6)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(rollups)
+}
+
+// --- Whitelist ---
+
+/// Whitelist an account for a user. Re-adding refreshes the stored handle
+/// but keeps the original `added_at`.
+pub fn add_to_whitelist(conn: &Connection, user_did: &str, did: &str, handle: &str) -> Result<()> {
+    conn.execute(
+        "INSERT INTO whitelist (user_did, did, handle) VALUES (?1, ?2, ?3)
+         ON CONFLICT(user_did, did) DO UPDATE SET handle = ?3",
+        params![user_did, did, handle],
+    )?;
+    Ok(())
+}
+
+/// Remove an account from a user's whitelist. Returns whether it was listed.
+///
+/// The account's whitelisted placeholder score is deleted with it, so the
+/// next scan scores the account instead of treating the placeholder as fresh.
+pub fn remove_from_whitelist(conn: &Connection, user_did: &str, did: &str) -> Result<bool> {
+    let tx = conn.unchecked_transaction()?;
+    let removed = tx.execute(
+        "DELETE FROM whitelist WHERE user_did = ?1 AND did = ?2",
+        params![user_did, did],
+    )?;
+    tx.execute(
+        "DELETE FROM account_scores WHERE user_did = ?1 AND did = ?2 AND whitelisted = 1",
+        params![user_did, did],
+    )?;
+    tx.commit()?;
+    Ok(removed > 0)
+}
+
+/// Whether an account is on a user's whitelist.
+pub fn is_whitelisted(conn: &Connection, user_did: &str, did: &str) -> Result<bool> {
+    let listed: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM whitelist WHERE user_did = ?1 AND did = ?2",
+        params![user_did, did],
+        |row| row.get(0),
+    )?;
+    Ok(listed)
+}
+
+/// Every whitelisted account for a user, most recently added first.
+pub fn get_whitelist(conn: &Connection, user_did: &str) -> Result<Vec<WhitelistEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT did, handle, added_at FROM whitelist
+         WHERE user_did = ?1
+         ORDER BY added_at DESC, did",
+    )?;
+    let entries = stmt
+        .query_map(params![user_did], |row| {
+            Ok(WhitelistEntry {
+                did: row.get(0)?,
+                handle: row.get(1)?,
+                added_at: row.get(2)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(entries)
+}
+
+// --- Scan runs ---
+
+/// Record a finished pipeline run. `started_at` is UTC "YYYY-MM-DD HH:MM:SS";
+/// `finished_at` is stamped now. Returns the new row id.
+pub fn record_scan_run(
+    conn: &Connection,
+    user_did: &str,
+    command: &str,
+    started_at: &str,
+    events_detected: i64,
+    accounts_scored: i64,
+) -> Result<i64> {
+    conn.execute(
+        "INSERT INTO scan_runs (user_did, command, started_at, events_detected, accounts_scored)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            user_did,
+            command,
+            started_at,
+            events_detected,
+            accounts_scored
+        ],
+    )?;
+    Ok(conn.last_insert_rowid())
+}
+
+/// The user's most recent pipeline runs, newest first.
+pub fn get_scan_runs(conn: &Connection, user_did: &str, limit: u32) -> Result<Vec<ScanRun>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, command, started_at, finished_at, events_detected, accounts_scored
+         FROM scan_runs
+         WHERE user_did = ?1
+         ORDER BY finished_at DESC, id DESC
+         LIMIT ?2",
+    )?;
+    let runs = stmt
+        .query_map(params![user_did, limit], |row| {
+            Ok(ScanRun {
+                id: row.get(0)?,
+                command: row.get(1)?,
+                started_at: row.get(2)?,
+                finished_at: row.get(3)?,
+                events_detected: row.get(4)?,
+                accounts_scored: row.get(5)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(runs)
+}
+
 // rusqlite's optional() helper — converts "no rows" into None
 use rusqlite::OptionalExtension;
 
@@ -976,22 +1855,174 @@ mod tests {
             threat_score: Some(65.0),
             threat_tier: Some("Elevated".to_string()),
             posts_analyzed: 20,
-            top_toxic_posts: vec![],
-            scored_at: String::new(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
         };
-        upsert_account_score(&conn, TEST_USER, &score).unwrap();
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
 
-        let ranked = get_ranked_threats(&conn, TEST_USER, 0.0).unwrap();
+        let ranked = get_ranked_threats(&conn, TEST_USER, 0.0, &TierThresholds::DEFAULT).unwrap();
         assert_eq!(ranked.len(), 1);
         assert_eq!(ranked[0].handle, "test.bsky.social");
         assert_eq!(ranked[0].threat_score, Some(65.0));
     }
 
+    #[test]
+    fn test_ranked_threats_retier_with_thresholds() {
+        let conn = test_db();
+
+        let score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.4),
+            topic_overlap: Some(0.3),
+            threat_score: Some(20.0),
+            threat_tier: Some("Elevated".to_string()),
+            posts_analyzed: 20,
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+
+        let default = get_ranked_threats(&conn, TEST_USER, 0.0, &TierThresholds::DEFAULT).unwrap();
+        assert_eq!(default[0].threat_tier.as_deref(), Some("Elevated"));
+
+        // Stricter thresholds take effect without rescoring
+        let strict = TierThresholds::new(4.0, 10.0, 18.0).unwrap();
+        let ranked = get_ranked_threats(&conn, TEST_USER, 0.0, &strict).unwrap();
+        assert_eq!(ranked[0].threat_tier.as_deref(), Some("High"));
+    }
+
+    #[test]
+    fn test_single_lookups_keep_the_stored_tier() {
+        let conn = test_db();
+        // Scored under strict thresholds, where 20 is already High
+        let score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.4),
+            topic_overlap: Some(0.3),
+            threat_score: Some(20.0),
+            threat_tier: Some("High".to_string()),
+            posts_analyzed: 20,
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+
+        let by_did = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        let by_handle = get_account_by_handle(&conn, TEST_USER, "test.bsky.social")
+            .unwrap()
+            .unwrap();
+        let unlabeled = get_unlabeled_accounts(&conn, TEST_USER, 10).unwrap();
+        assert_eq!(by_did.threat_tier.as_deref(), Some("High"));
+        assert_eq!(by_handle.threat_tier.as_deref(), Some("High"));
+        assert_eq!(unlabeled[0].threat_tier.as_deref(), Some("High"));
+    }
+
+    #[test]
+    fn test_overlap_method_roundtrip() {
+        let conn = test_db();
+
+        let score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.8),
+            topic_overlap: Some(0.3),
+            threat_score: Some(65.0),
+            threat_tier: Some("Elevated".to_string()),
+            posts_analyzed: 20,
+            overlap_method: Some("embedding".to_string()),
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+
+        let ranked = get_ranked_threats(&conn, TEST_USER, 0.0, &TierThresholds::DEFAULT).unwrap();
+        assert_eq!(ranked[0].overlap_method.as_deref(), Some("embedding"));
+        let by_did = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        assert_eq!(by_did.overlap_method.as_deref(), Some("embedding"));
+    }
+
+    #[test]
+    fn test_overlap_post_count_roundtrip() {
+        let conn = test_db();
+        let low = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.1),
+            topic_overlap: Some(0.4),
+            threat_score: Some(12.0),
+            threat_tier: Some("Low".to_string()),
+            posts_analyzed: 3,
+            overlap_method: Some("keyword".to_string()),
+            overlap_post_count: Some(3),
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &low, None).unwrap();
+        let stored = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.overlap_post_count, Some(3));
+
+        // A rescore with enough posts clears the low-confidence marker
+        let full = AccountScore {
+            overlap_post_count: None,
+            replies_analyzed: None,
+            evidence_strength: None,
+            ..low
+        };
+        upsert_account_score(&conn, TEST_USER, &full, None).unwrap();
+        let ranked = get_ranked_threats(&conn, TEST_USER, 0.0, &TierThresholds::DEFAULT).unwrap();
+        assert_eq!(ranked[0].overlap_post_count, None);
+    }
+
+    #[test]
+    fn test_replies_analyzed_roundtrip() {
+        let conn = test_db();
+        let score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.3),
+            topic_overlap: Some(0.4),
+            threat_score: Some(12.0),
+            threat_tier: Some("Low".to_string()),
+            posts_analyzed: 80,
+            replies_analyzed: Some(55),
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        let stored = get_account_by_handle(&conn, TEST_USER, "test.bsky.social")
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.posts_analyzed, 80);
+        assert_eq!(stored.replies_analyzed, Some(55));
+        let unlabeled = get_unlabeled_accounts(&conn, TEST_USER, 10).unwrap();
+        assert_eq!(unlabeled[0].replies_analyzed, Some(55));
+    }
+
+    #[test]
+    fn test_evidence_strength_roundtrip() {
+        let conn = test_db();
+        let score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.3),
+            topic_overlap: Some(0.4),
+            threat_score: Some(40.0),
+            threat_tier: Some("High".to_string()),
+            posts_analyzed: 4,
+            evidence_strength: Some(0.25),
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        let stored = get_account_by_handle(&conn, TEST_USER, "test.bsky.social")
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.evidence_strength, Some(0.25));
+        let ranked = get_ranked_threats(&conn, TEST_USER, 0.0, &TierThresholds::DEFAULT).unwrap();
+        assert_eq!(ranked[0].evidence_strength, Some(0.25));
+    }
+
     #[test]
     fn test_save_embedding_fails_without_fingerprint_row() {
         let conn = test_db();
@@ -1085,6 +2116,84 @@ mod tests {
         assert_eq!(events[0].amplifier_handle, "troll.bsky.social");
     }
 
+    #[test]
+    fn test_mark_event_followers_scored() {
+        let conn = test_db();
+
+        let insert = |uri: &str| {
+            insert_amplification_event(
+                &conn,
+                TEST_USER,
+                "quote",
+                "did:plc:xyz",
+                "troll.bsky.social",
+                "at://did:plc:me/app.bsky.feed.post/abc",
+                Some(uri),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+        let done = insert("at://did:plc:xyz/app.bsky.feed.post/done");
+        insert("at://did:plc:xyz/app.bsky.feed.post/todo");
+        assert!(get_followers_scored_post_uris(&conn, TEST_USER)
+            .unwrap()
+            .is_empty());
+
+        mark_event_followers_scored(&conn, done).unwrap();
+
+        assert_eq!(
+            get_followers_scored_post_uris(&conn, TEST_USER).unwrap(),
+            vec!["at://did:plc:xyz/app.bsky.feed.post/done".to_string()]
+        );
+        let events = get_recent_events(&conn, TEST_USER, 10).unwrap();
+        let marked = events.iter().find(|e| e.id == done).unwrap();
+        assert!(marked.followers_fetched && marked.followers_scored);
+        assert_eq!(events.iter().filter(|e| !e.followers_scored).count(), 1);
+        assert!(get_followers_scored_post_uris(&conn, "did:plc:other")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_recent_events_since_window() {
+        let conn = test_db();
+
+        for (did, handle) in [
+            ("did:plc:new", "new.bsky.social"),
+            ("did:plc:old", "old.bsky.social"),
+        ] {
+            insert_amplification_event(
+                &conn,
+                TEST_USER,
+                "quote",
+                did,
+                handle,
+                "at://did:plc:me/app.bsky.feed.post/abc",
+                None,
+                Some("text"),
+                None,
+                None,
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "UPDATE amplification_events SET detected_at = datetime('now', '-90 days')
+             WHERE amplifier_did = 'did:plc:old'",
+            [],
+        )
+        .unwrap();
+
+        let recent = get_recent_events_since(&conn, TEST_USER, 10, 30).unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].amplifier_handle, "new.bsky.social");
+
+        let wide = get_recent_events_since(&conn, TEST_USER, 10, 365).unwrap();
+        assert_eq!(wide.len(), 2);
+        assert_eq!(get_recent_events(&conn, TEST_USER, 10).unwrap().len(), 2);
+    }
+
     #[test]
     fn test_account_by_handle() {
         let conn = test_db();
@@ -1097,15 +2206,9 @@ mod tests {
             threat_score: Some(30.0),
             threat_tier: Some("Watch".to_string()),
             posts_analyzed: 10,
-            top_toxic_posts: vec![],
-            scored_at: String::new(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
         };
-        upsert_account_score(&conn, TEST_USER, &score).unwrap();
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
 
         // Case-insensitive lookup
         let found = get_account_by_handle(&conn, TEST_USER, "test.bsky.social")
@@ -1119,26 +2222,308 @@ mod tests {
     }
 
     #[test]
-    fn test_account_by_did() {
+    fn test_resolve_raw_did_handles() {
         let conn = test_db();
 
         let score = AccountScore {
-            did: "did:plc:abc".to_string(),
-            handle: "test.bsky.social".to_string(),
+            did: "did:plc:raw".to_string(),
+            handle: "did:plc:raw".to_string(),
             toxicity_score: Some(0.5),
             topic_overlap: Some(0.2),
             threat_score: Some(30.0),
             threat_tier: Some("Watch".to_string()),
             posts_analyzed: 10,
-            top_toxic_posts: vec![],
-            scored_at: String::new(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        let named = AccountScore {
+            did: "did:plc:named".to_string(),
+            handle: "named.bsky.social".to_string(),
+            ..score.clone()
         };
-        upsert_account_score(&conn, TEST_USER, &score).unwrap();
+        upsert_account_score(&conn, TEST_USER, &named, None).unwrap();
+        insert_amplification_event(
+            &conn,
+            TEST_USER,
+            "repost",
+            "did:plc:amp",
+            "did:plc:amp",
+            "at://did:plc:me/app.bsky.feed.post/abc",
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let pending = get_unresolved_handle_dids(&conn, TEST_USER).unwrap();
+        assert_eq!(pending, vec!["did:plc:amp", "did:plc:raw"]);
+        assert!(get_unresolved_handle_dids(&conn, "did:plc:other")
+            .unwrap()
+            .is_empty());
+
+        let updated = update_resolved_handles(
+            &conn,
+            TEST_USER,
+            &[
+                ("did:plc:raw".to_string(), "raw.bsky.social".to_string()),
+                ("did:plc:amp".to_string(), "amp.bsky.social".to_string()),
+                // Already has a handle — must not be overwritten
+                (
+                    "did:plc:named".to_string(),
+                    "renamed.bsky.social".to_string(),
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(updated, 2);
+
+        let raw = get_account_by_did(&conn, TEST_USER, "did:plc:raw")
+            .unwrap()
+            .unwrap();
+        assert_eq!(raw.handle, "raw.bsky.social");
+        let named = get_account_by_did(&conn, TEST_USER, "did:plc:named")
+            .unwrap()
+            .unwrap();
+        assert_eq!(named.handle, "named.bsky.social");
+        let events = get_recent_events(&conn, TEST_USER, 10).unwrap();
+        assert_eq!(events[0].amplifier_handle, "amp.bsky.social");
+        assert!(get_unresolved_handle_dids(&conn, TEST_USER)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_did_cache_roundtrip_and_known_dids() {
+        let conn = test_db();
+        insert_amplification_event(
+            &conn,
+            TEST_USER,
+            "quote",
+            "did:plc:amp",
+            "amp.bsky.social",
+            "at://did:plc:me/app.bsky.feed.post/abc",
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO account_scores (user_did, did, handle, posts_analyzed)
+             VALUES (?1, 'did:plc:scored', 'scored.bsky.social', 10),
+                    (?1, 'did:plc:amp', 'amp.bsky.social', 10)",
+            params![TEST_USER],
+        )
+        .unwrap();
+
+        // Union across both tables, deduplicated
+        assert_eq!(
+            get_known_dids(&conn, TEST_USER).unwrap(),
+            vec!["did:plc:amp", "did:plc:scored"]
+        );
+
+        let dids = vec!["did:plc:amp".to_string(), "did:plc:scored".to_string()];
+        assert!(get_cached_handles(&conn, &dids).unwrap().is_empty());
+
+        cache_handles(
+            &conn,
+            &[("did:plc:amp".to_string(), "old.bsky.social".to_string())],
+        )
+        .unwrap();
+        cache_handles(
+            &conn,
+            &[("did:plc:amp".to_string(), "new.bsky.social".to_string())],
+        )
+        .unwrap();
+
+        let cached = get_cached_handles(&conn, &dids).unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached["did:plc:amp"], "new.bsky.social");
+    }
+
+    #[test]
+    fn test_fresh_cached_handles_skip_stale_entries() {
+        let conn = test_db();
+        cache_handles(
+            &conn,
+            &[
+                ("did:plc:fresh".to_string(), "fresh.bsky.social".to_string()),
+                ("did:plc:stale".to_string(), "stale.bsky.social".to_string()),
+            ],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE did_cache SET resolved_at = datetime('now', '-45 days')
+             WHERE did = 'did:plc:stale'",
+            [],
+        )
+        .unwrap();
+
+        let dids = vec!["did:plc:fresh".to_string(), "did:plc:stale".to_string()];
+        let fresh = get_fresh_cached_handles(&conn, &dids, 30).unwrap();
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh["did:plc:fresh"], "fresh.bsky.social");
+        assert_eq!(get_fresh_cached_handles(&conn, &dids, 60).unwrap().len(), 2);
+
+        // Re-caching refreshes the timestamp
+        cache_handles(
+            &conn,
+            &[("did:plc:stale".to_string(), "stale.bsky.social".to_string())],
+        )
+        .unwrap();
+        assert_eq!(get_fresh_cached_handles(&conn, &dids, 30).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_follower_snapshot_keeps_latest_only() {
+        let conn = test_db();
+        assert!(get_follower_snapshot(&conn, TEST_USER, "did:plc:amp")
+            .unwrap()
+            .is_none());
+
+        save_follower_snapshot(&conn, TEST_USER, "did:plc:amp", &["aa".to_string()]).unwrap();
+        save_follower_snapshot(
+            &conn,
+            TEST_USER,
+            "did:plc:amp",
+            &["bb".to_string(), "cc".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_follower_snapshot(&conn, TEST_USER, "did:plc:amp").unwrap(),
+            Some(vec!["bb".to_string(), "cc".to_string()])
+        );
+        let rows: i64 = conn
+            .query_row("SELECT COUNT(*) FROM follower_snapshots", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(rows, 1);
+
+        delete_user_data(&conn, TEST_USER).unwrap();
+        assert!(get_follower_snapshot(&conn, TEST_USER, "did:plc:amp")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_amplifier_rollups_rank_by_total_threat() {
+        let conn = test_db();
+        for (amp, handle) in [
+            ("did:plc:amp1", "one.bsky.social"),
+            ("did:plc:amp2", "two.bsky.social"),
+        ] {
+            insert_amplification_event(
+                &conn,
+                TEST_USER,
+                "quote",
+                amp,
+                handle,
+                "at://post",
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        }
+        for (did, threat) in [
+            ("did:plc:f1", 10.0),
+            ("did:plc:f2", 30.0),
+            ("did:plc:f3", 50.0),
+        ] {
+            let score = AccountScore {
+                did: did.to_string(),
+                handle: format!("{did}.test"),
+                toxicity_score: Some(0.5),
+                topic_overlap: Some(0.2),
+                threat_score: Some(threat),
+                posts_analyzed: 10,
+                ..Default::default()
+            };
+            upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        }
+        // amp1: one follower at 50; amp2: two followers at 10 + 30, plus one
+        // that was never scored. Re-recording a follower is a no-op.
+        record_amplifier_followers(
+            &conn,
+            TEST_USER,
+            "did:plc:amp1",
+            &["did:plc:f3".to_string()],
+        )
+        .unwrap();
+        let amp2_followers = [
+            "did:plc:f1".to_string(),
+            "did:plc:f2".to_string(),
+            "did:plc:unscored".to_string(),
+        ];
+        record_amplifier_followers(&conn, TEST_USER, "did:plc:amp2", &amp2_followers).unwrap();
+        record_amplifier_followers(&conn, TEST_USER, "did:plc:amp2", &amp2_followers).unwrap();
+
+        let rollups = get_amplifier_rollups(&conn, TEST_USER).unwrap();
+        assert_eq!(rollups.len(), 2);
+        assert_eq!(rollups[0].amplifier_handle, "one.bsky.social");
+        assert!((rollups[0].total_threat - 50.0).abs() < 1e-9);
+        assert_eq!(rollups[1].amplifier_did, "did:plc:amp2");
+        assert_eq!(rollups[1].followers_scored, 2);
+        assert!((rollups[1].avg_threat - 20.0).abs() < 1e-9);
+        assert!((rollups[1].max_threat - 30.0).abs() < 1e-9);
+
+        // Other users see nothing
+        assert!(get_amplifier_rollups(&conn, "did:plc:other")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_amplifier_rollups_prefer_resolved_handle() {
+        let conn = test_db();
+        // A real handle recorded before and after an unresolved DID row —
+        // neither order should surface the raw DID
+        for handle in [
+            "amp.bsky.social",
+            "did:plc:amp",
+            "amp.bsky.social",
+            "did:plc:amp",
+        ] {
+            insert_amplification_event(
+                &conn,
+                TEST_USER,
+                "repost",
+                "did:plc:amp",
+                handle,
+                "at://post",
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        }
+
+        let rollups = get_amplifier_rollups(&conn, TEST_USER).unwrap();
+        assert_eq!(rollups.len(), 1);
+        assert_eq!(rollups[0].amplifier_handle, "amp.bsky.social");
+        assert_eq!(rollups[0].event_count, 4);
+    }
+
+    #[test]
+    fn test_account_by_did() {
+        let conn = test_db();
+
+        let score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.5),
+            topic_overlap: Some(0.2),
+            threat_score: Some(30.0),
+            threat_tier: Some("Watch".to_string()),
+            posts_analyzed: 10,
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
 
         let found = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
             .unwrap()
@@ -1150,6 +2535,559 @@ mod tests {
         assert!(not_found.is_none());
     }
 
+    #[test]
+    fn test_ranked_threats_active_within() {
+        let conn = test_db();
+        let now = chrono::Utc::now();
+        let ago = |days: i64| {
+            (now - chrono::Duration::days(days)).to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        };
+        for (did, last_active) in [
+            ("did:plc:live", Some(ago(2))),
+            ("did:plc:dormant", Some(ago(400))),
+            ("did:plc:unknown", None),
+        ] {
+            let score = AccountScore {
+                did: did.to_string(),
+                handle: format!("{did}.test"),
+                toxicity_score: Some(0.5),
+                topic_overlap: Some(0.2),
+                threat_score: Some(40.0),
+                posts_analyzed: 10,
+                last_active_at: last_active,
+                ..Default::default()
+            };
+            upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        }
+
+        assert_eq!(
+            get_ranked_threats(&conn, TEST_USER, 0.0, &TierThresholds::DEFAULT)
+                .unwrap()
+                .len(),
+            3
+        );
+
+        let active =
+            get_ranked_threats_active_within(&conn, TEST_USER, 0.0, 30, &TierThresholds::DEFAULT)
+                .unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].did, "did:plc:live");
+        assert_eq!(active[0].last_active_at, Some(ago(2)));
+
+        let year =
+            get_ranked_threats_active_within(&conn, TEST_USER, 0.0, 500, &TierThresholds::DEFAULT)
+                .unwrap();
+        assert_eq!(year.len(), 2);
+    }
+
+    #[test]
+    fn test_upsert_keeps_last_active_when_rescore_has_none() {
+        let conn = test_db();
+        let mut score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.5),
+            topic_overlap: Some(0.2),
+            threat_score: Some(30.0),
+            posts_analyzed: 10,
+            last_active_at: Some("2026-01-02T03:04:05Z".to_string()),
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        score.last_active_at = None;
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+
+        let found = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            found.last_active_at.as_deref(),
+            Some("2026-01-02T03:04:05Z")
+        );
+    }
+
+    #[test]
+    fn test_upsert_appends_score_history() {
+        let conn = test_db();
+        let mut score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.2),
+            topic_overlap: Some(0.2),
+            threat_score: Some(10.0),
+            posts_analyzed: 10,
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        score.threat_score = Some(25.0);
+        score.toxicity_score = Some(0.6);
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+
+        // The same DID twice: one current score, two history rows, oldest first
+        let history = get_account_history(&conn, TEST_USER, "did:plc:abc", 10).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].threat_score, Some(10.0));
+        assert_eq!(history[1].threat_score, Some(25.0));
+        assert_eq!(history[1].toxicity_score, Some(0.6));
+
+        // The limit keeps the most recent snapshots
+        score.threat_score = Some(40.0);
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        let recent = get_account_history(&conn, TEST_USER, "did:plc:abc", 2).unwrap();
+        let threats: Vec<Option<f64>> = recent.iter().map(|s| s.threat_score).collect();
+        assert_eq!(threats, vec![Some(25.0), Some(40.0)]);
+
+        assert!(
+            get_account_history(&conn, "did:plc:other", "did:plc:abc", 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_upsert_keeps_origin_amplifier_when_rescore_has_none() {
+        let conn = test_db();
+        let mut score = AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.5),
+            topic_overlap: Some(0.2),
+            threat_score: Some(30.0),
+            posts_analyzed: 10,
+            origin_amplifier_did: Some("did:plc:amp".to_string()),
+            ..Default::default()
+        };
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+
+        // A sweep rescoring the same account doesn't know the amplifier
+        score.origin_amplifier_did = None;
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        let ranked = get_ranked_threats(&conn, TEST_USER, 0.0, &TierThresholds::DEFAULT).unwrap();
+        assert_eq!(
+            ranked[0].origin_amplifier_did.as_deref(),
+            Some("did:plc:amp")
+        );
+
+        // A later amplifier replaces it
+        score.origin_amplifier_did = Some("did:plc:newer".to_string());
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
+        let found = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.origin_amplifier_did.as_deref(), Some("did:plc:newer"));
+    }
+
+    /// A minimal score for did:plc:abc with the given threat score.
+    fn ema_score(threat: f64) -> AccountScore {
+        AccountScore {
+            did: "did:plc:abc".to_string(),
+            handle: "test.bsky.social".to_string(),
+            toxicity_score: Some(0.5),
+            topic_overlap: Some(0.2),
+            threat_score: Some(threat),
+            posts_analyzed: 10,
+            ..Default::default()
+        }
+    }
+
+    fn stored_scores(conn: &Connection) -> (Option<f64>, Option<f64>) {
+        let found = get_account_by_did(conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        (found.threat_score, found.raw_threat_score)
+    }
+
+    fn smoothing(alpha: f64) -> Option<ScoreSmoothing> {
+        Some(ScoreSmoothing {
+            alpha,
+            thresholds: TierThresholds::DEFAULT,
+        })
+    }
+
+    /// The tier column as written, not recomputed on read.
+    fn stored_tier(conn: &Connection) -> String {
+        conn.query_row(
+            "SELECT threat_tier FROM account_scores WHERE did = 'did:plc:abc'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ema_alpha_one_is_a_pure_overwrite() {
+        let conn = test_db();
+        upsert_account_score(&conn, TEST_USER, &ema_score(10.0), smoothing(1.0)).unwrap();
+        upsert_account_score(&conn, TEST_USER, &ema_score(40.0), smoothing(1.0)).unwrap();
+        assert_eq!(stored_scores(&conn), (Some(40.0), Some(40.0)));
+    }
+
+    #[test]
+    fn test_ema_tier_follows_the_smoothed_score() {
+        let conn = test_db();
+        upsert_account_score(&conn, TEST_USER, &ema_score(10.0), smoothing(0.5)).unwrap();
+        // The new scan alone says High; the blend of 25 is Elevated
+        let high = AccountScore {
+            threat_tier: Some("High".to_string()),
+            ..ema_score(40.0)
+        };
+        upsert_account_score(&conn, TEST_USER, &high, smoothing(0.5)).unwrap();
+        assert_eq!(stored_scores(&conn), (Some(25.0), Some(40.0)));
+        assert_eq!(stored_tier(&conn), "Elevated");
+    }
+
+    #[test]
+    fn test_ema_keeps_the_hardblock_floor() {
+        let conn = test_db();
+        upsert_account_score(&conn, TEST_USER, &ema_score(0.0), smoothing(0.5)).unwrap();
+        let hardblocked = AccountScore {
+            threat_tier: Some("High".to_string()),
+            top_toxic_posts: vec![ToxicPost {
+                text: "blocked phrase".to_string(),
+                toxicity: 0.9,
+                uri: "at://did:plc:abc/app.bsky.feed.post/1".to_string(),
+                attributes: None,
+                created_at: None,
+                hardblock_pattern: Some("blocked".to_string()),
+                flagged_for: None,
+            }],
+            ..ema_score(35.0)
+        };
+        upsert_account_score(&conn, TEST_USER, &hardblocked, smoothing(0.5)).unwrap();
+        // Blending alone would give 17.5
+        assert_eq!(stored_scores(&conn), (Some(35.0), Some(35.0)));
+        assert_eq!(stored_tier(&conn), "High");
+    }
+
+    #[test]
+    fn test_unmeasured_score_leaves_a_measured_one_alone() {
+        let conn = test_db();
+        upsert_account_score(&conn, TEST_USER, &ema_score(30.0), None).unwrap();
+        conn.execute(
+            "UPDATE account_scores SET scored_at = '2020-01-01 00:00:00' WHERE did = 'did:plc:abc'",
+            [],
+        )
+        .unwrap();
+
+        // Overlap-only: a threat score and no toxicity
+        let overlap_only = AccountScore {
+            toxicity_score: None,
+            ..ema_score(12.0)
+        };
+        upsert_account_score(&conn, TEST_USER, &overlap_only, None).unwrap();
+        upsert_account_scores(&conn, TEST_USER, &[overlap_only.clone()], None).unwrap();
+
+        let found = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.threat_score, Some(30.0));
+        assert_eq!(found.toxicity_score, Some(0.5));
+        assert_eq!(found.scored_at, "2020-01-01 00:00:00");
+        let history: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM account_score_history WHERE did = 'did:plc:abc'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(history, 1);
+
+        // A measured score still replaces it, and an unmeasured one replaces
+        // another unmeasured one
+        let other = AccountScore {
+            did: "did:plc:other".to_string(),
+            ..overlap_only.clone()
+        };
+        upsert_account_score(&conn, TEST_USER, &other, None).unwrap();
+        upsert_account_score(
+            &conn,
+            TEST_USER,
+            &AccountScore {
+                threat_score: Some(20.0),
+                ..other
+            },
+            None,
+        )
+        .unwrap();
+        let other = get_account_by_did(&conn, TEST_USER, "did:plc:other")
+            .unwrap()
+            .unwrap();
+        assert_eq!(other.threat_score, Some(20.0));
+    }
+
+    #[test]
+    fn test_min_overlap_skip_keeps_the_stored_scored_at() {
+        let conn = test_db();
+        upsert_account_score(&conn, TEST_USER, &ema_score(30.0), None).unwrap();
+        conn.execute(
+            "UPDATE account_scores SET scored_at = '2020-01-01 00:00:00' WHERE did = 'did:plc:abc'",
+            [],
+        )
+        .unwrap();
+
+        // What build_profile records below --min-overlap
+        let skipped = AccountScore {
+            toxicity_score: None,
+            threat_tier: Some("Low".to_string()),
+            ..ema_score(0.0)
+        };
+        upsert_account_score(&conn, TEST_USER, &skipped, None).unwrap();
+
+        let found = get_account_by_did(&conn, TEST_USER, "did:plc:abc")
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.scored_at, "2020-01-01 00:00:00");
+        assert_eq!(found.threat_score, Some(30.0));
+    }
+
+    #[test]
+    fn test_ema_batch_blends_a_repeated_did_once() {
+        let conn = test_db();
+        upsert_account_score(&conn, TEST_USER, &ema_score(10.0), smoothing(0.5)).unwrap();
+        upsert_account_scores(
+            &conn,
+            TEST_USER,
+            &[ema_score(40.0), ema_score(20.0)],
+            smoothing(0.5),
+        )
+        .unwrap();
+        // Only the later score is blended: (20 + 10) / 2, not ((40 + 10) / 2 + 20) / 2
+        assert_eq!(stored_scores(&conn), (Some(15.0), Some(20.0)));
+        let history: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM account_score_history WHERE did = 'did:plc:abc'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(history, 3);
+    }
+
+    #[test]
+    fn test_ema_alpha_half_halves_the_delta() {
+        let conn = test_db();
+        // The first score has nothing to blend with
+        upsert_account_score(&conn, TEST_USER, &ema_score(10.0), smoothing(0.5)).unwrap();
+        assert_eq!(stored_scores(&conn), (Some(10.0), Some(10.0)));
+
+        upsert_account_score(&conn, TEST_USER, &ema_score(40.0), smoothing(0.5)).unwrap();
+        assert_eq!(stored_scores(&conn), (Some(25.0), Some(40.0)));
+
+        // Batched writes blend the same way, against the smoothed value
+        upsert_account_scores(&conn, TEST_USER, &[ema_score(5.0)], smoothing(0.5)).unwrap();
+        assert_eq!(stored_scores(&conn), (Some(15.0), Some(5.0)));
+
+        // Without smoothing the raw score overwrites again
+        upsert_account_score(&conn, TEST_USER, &ema_score(8.0), None).unwrap();
+        assert_eq!(stored_scores(&conn), (Some(8.0), Some(8.0)));
+    }
+
+    /// Store a score for `did`, then backdate its scored_at by `age_days`.
+    fn insert_aged_score(conn: &Connection, did: &str, threat: Option<f64>, age_days: u32) {
+        let score = AccountScore {
+            did: did.to_string(),
+            handle: format!("{did}.test"),
+            toxicity_score: threat.map(|_| 0.1),
+            topic_overlap: threat.map(|_| 0.1),
+            threat_score: threat,
+            posts_analyzed: 10,
+            ..Default::default()
+        };
+        upsert_account_score(conn, TEST_USER, &score, None).unwrap();
+        conn.execute(
+            "UPDATE account_scores SET scored_at = datetime('now', ?1) WHERE did = ?2",
+            params![format!("-{age_days} days"), did],
+        )
+        .unwrap();
+    }
+
+    fn remaining_dids(conn: &Connection) -> Vec<String> {
+        let mut stmt = conn
+            .prepare("SELECT did FROM account_scores WHERE user_did = ?1 ORDER BY did")
+            .unwrap();
+        stmt.query_map(params![TEST_USER], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_prune_scores_date_filter() {
+        let conn = test_db();
+        insert_aged_score(&conn, "did:plc:fresh", Some(2.0), 10);
+        insert_aged_score(&conn, "did:plc:old", Some(2.0), 120);
+
+        // Every tier is eligible, so only age decides
+        let removed = prune_scores(
+            &conn,
+            TEST_USER,
+            90,
+            ThreatTier::High,
+            &TierThresholds::DEFAULT,
+        )
+        .unwrap();
+        assert_eq!(removed, 1);
+        assert_eq!(remaining_dids(&conn), vec!["did:plc:fresh"]);
+    }
+
+    #[test]
+    fn test_prune_scores_tier_filter() {
+        let conn = test_db();
+        // All older than the cutoff, so only the tier decides
+        insert_aged_score(&conn, "did:plc:high", Some(50.0), 120);
+        insert_aged_score(&conn, "did:plc:low", Some(3.0), 120);
+        insert_aged_score(&conn, "did:plc:unscored", None, 120);
+        insert_aged_score(&conn, "did:plc:watch", Some(10.0), 120);
+
+        let removed = prune_scores(
+            &conn,
+            TEST_USER,
+            90,
+            ThreatTier::Low,
+            &TierThresholds::DEFAULT,
+        )
+        .unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(remaining_dids(&conn), vec!["did:plc:high", "did:plc:watch"]);
+
+        let removed = prune_scores(
+            &conn,
+            TEST_USER,
+            90,
+            ThreatTier::Watch,
+            &TierThresholds::DEFAULT,
+        )
+        .unwrap();
+        assert_eq!(removed, 1);
+        assert_eq!(remaining_dids(&conn), vec!["did:plc:high"]);
+    }
+
+    fn insert_evidence(conn: &Connection, did: &str, threat_score: f64, texts: &[&str]) {
+        let score = AccountScore {
+            did: did.to_string(),
+            handle: format!("{}.bsky.social", did.trim_start_matches("did:plc:")),
+            toxicity_score: Some(0.5),
+            topic_overlap: Some(0.3),
+            threat_score: Some(threat_score),
+            posts_analyzed: 20,
+            top_toxic_posts: texts
+                .iter()
+                .enumerate()
+                .map(|(i, text)| ToxicPost {
+                    text: text.to_string(),
+                    toxicity: 0.8,
+                    uri: format!("at://{did}/app.bsky.feed.post/{i}"),
+                    attributes: None,
+                    created_at: None,
+                    hardblock_pattern: None,
+                    flagged_for: None,
+                })
+                .collect(),
+            ..Default::default()
+        };
+        upsert_account_score(conn, TEST_USER, &score, None).unwrap();
+    }
+
+    fn search_dids(conn: &Connection, query: &str) -> Vec<String> {
+        search_evidence(conn, TEST_USER, query, &TierThresholds::DEFAULT)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.did)
+            .collect()
+    }
+
+    #[test]
+    fn test_search_evidence_matches_stemmed_words_by_threat() {
+        let conn = test_db();
+        insert_evidence(&conn, "did:plc:low", 8.0, &["they keep threatening people"]);
+        insert_evidence(
+            &conn,
+            "did:plc:high",
+            40.0,
+            &["nothing here", "I will threaten you"],
+        );
+        insert_evidence(&conn, "did:plc:other", 30.0, &["a different complaint"]);
+
+        assert_eq!(
+            search_dids(&conn, "threaten"),
+            vec!["did:plc:high", "did:plc:low"]
+        );
+        // Every word has to match
+        assert_eq!(search_dids(&conn, "threaten people"), vec!["did:plc:low"]);
+        let found =
+            search_evidence(&conn, TEST_USER, "complaint", &TierThresholds::DEFAULT).unwrap();
+        assert_eq!(found[0].threat_tier.as_deref(), Some("Elevated"));
+        // Empty queries and FTS5 syntax don't error
+        assert!(search_dids(&conn, "   ").is_empty());
+        assert!(search_dids(&conn, "\"threaten OR *").is_empty());
+        assert!(search_evidence(
+            &conn,
+            "did:plc:someoneelse",
+            "threaten",
+            &TierThresholds::DEFAULT
+        )
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn test_search_evidence_follows_rescores_and_prunes() {
+        let conn = test_db();
+        insert_evidence(&conn, "did:plc:acct", 2.0, &["old hostile words"]);
+        insert_evidence(&conn, "did:plc:acct", 2.0, &["new hostile words"]);
+        assert!(search_dids(&conn, "old").is_empty());
+        assert_eq!(search_dids(&conn, "new"), vec!["did:plc:acct"]);
+
+        conn.execute(
+            "UPDATE account_scores SET scored_at = datetime('now', '-120 days')",
+            [],
+        )
+        .unwrap();
+        let removed = prune_scores(
+            &conn,
+            TEST_USER,
+            90,
+            ThreatTier::Low,
+            &TierThresholds::DEFAULT,
+        )
+        .unwrap();
+        assert_eq!(removed, 1);
+        assert!(search_dids(&conn, "hostile").is_empty());
+        let indexed: i64 = conn
+            .query_row("SELECT COUNT(*) FROM evidence_fts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(indexed, 0);
+    }
+
+    #[test]
+    fn test_scan_runs_newest_first_with_limit() {
+        let conn = test_db();
+        for (command, finished_at) in [
+            ("scan", "2026-01-01 10:00:00"),
+            ("sweep", "2026-01-03 10:00:00"),
+            ("scan", "2026-01-02 10:00:00"),
+        ] {
+            let id =
+                record_scan_run(&conn, TEST_USER, command, "2026-01-01 09:00:00", 4, 7).unwrap();
+            conn.execute(
+                "UPDATE scan_runs SET finished_at = ?1 WHERE id = ?2",
+                params![finished_at, id],
+            )
+            .unwrap();
+        }
+        record_scan_run(&conn, "did:plc:other", "scan", "2026-01-05 09:00:00", 1, 1).unwrap();
+
+        let runs = get_scan_runs(&conn, TEST_USER, 2).unwrap();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].command, "sweep");
+        assert_eq!(runs[0].finished_at, "2026-01-03 10:00:00");
+        assert_eq!(runs[1].finished_at, "2026-01-02 10:00:00");
+        assert_eq!(runs[1].started_at, "2026-01-01 09:00:00");
+        assert_eq!((runs[1].events_detected, runs[1].accounts_scored), (4, 7));
+
+        assert_eq!(get_scan_runs(&conn, TEST_USER, 10).unwrap().len(), 3);
+    }
+
     #[test]
     fn test_is_score_stale() {
         let conn = test_db();
@@ -1165,15 +3103,9 @@ mod tests {
             threat_score: Some(30.0),
             threat_tier: Some("Watch".to_string()),
             posts_analyzed: 10,
-            top_toxic_posts: vec![],
-            scored_at: String::new(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
         };
-        upsert_account_score(&conn, TEST_USER, &score).unwrap();
+        upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
 
         // Just scored — should not be stale
         assert!(!is_score_stale(&conn, TEST_USER, "did:plc:abc", 7).unwrap());
@@ -1231,15 +3163,10 @@ mod tests {
                 threat_score: Some(30.0),
                 threat_tier: Some("Watch".to_string()),
                 posts_analyzed: 10,
-                top_toxic_posts: vec![],
-                scored_at: String::new(),
                 behavioral_signals: Some(format!(r#"{{"avg_engagement":{eng}}}"#)),
-                context_score: None,
-                graph_distance: None,
-                fingerprint_quality: None,
-                scoring_confidence: None,
+                ..Default::default()
             };
-            upsert_account_score(&conn, TEST_USER, &score).unwrap();
+            upsert_account_score(&conn, TEST_USER, &score, None).unwrap();
         }
 
         let median = get_median_engagement(&conn, TEST_USER).unwrap();
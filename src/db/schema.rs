@@ -286,6 +286,180 @@ pub fn create_tables(conn: &Connection) -> Result<()> {
         )
     })?;
 
+    // Migration v9: record how topic_overlap was computed (keyword or embedding).
+    // Rows scored before this existed get 'unknown'.
+    run_migration(conn, 9, |c| {
+        c.execute_batch(
+            "ALTER TABLE account_scores ADD COLUMN overlap_method TEXT DEFAULT 'unknown';",
+        )
+    })?;
+
+    // Migration v10: DID → handle cache, shared across users (a DID resolves
+    // the same way no matter who is asking). Filled by `warm-cache` and
+    // `resolve-handles` so later runs can skip the network.
+    run_migration(conn, 10, |c| {
+        c.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS did_cache (
+                did TEXT PRIMARY KEY,
+                handle TEXT NOT NULL,
+                resolved_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            ",
+        )
+    })?;
+
+    // Migration v11: latest follower snapshot per amplifier, as a JSON array
+    // of hashed follower DIDs. Diffed across scans to spot accounts that
+    // followed an amplifier since the last one. One row per amplifier keeps
+    // storage bounded.
+    run_migration(conn, 11, |c| {
+        c.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS follower_snapshots (
+                user_did TEXT NOT NULL,
+                amplifier_did TEXT NOT NULL,
+                follower_hashes TEXT NOT NULL,
+                taken_at TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (user_did, amplifier_did)
+            );
+            ",
+        )
+    })?;
+
+    // Migration v12: which amplifier each scored follower was found through,
+    // so threat can be rolled up per amplifier. A follower reached through
+    // several amplifiers gets one row per amplifier.
+    run_migration(conn, 12, |c| {
+        c.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS amplifier_followers (
+                user_did TEXT NOT NULL,
+                amplifier_did TEXT NOT NULL,
+                follower_did TEXT NOT NULL,
+                PRIMARY KEY (user_did, amplifier_did, follower_did)
+            );
+            ",
+        )
+    })?;
+
+    // Migration v13: when each scored account last posted, so reports can
+    // set dormant accounts aside. Stored as RFC 3339 UTC text.
+    run_migration(conn, 13, |c| {
+        c.execute_batch("ALTER TABLE account_scores ADD COLUMN last_active_at TEXT;")
+    })?;
+
+    // Migration v14: accounts the user trusts, which pipelines skip instead of
+    // scoring. The handle is kept for display in `charcoal whitelist list`;
+    // the placeholder scores recorded for them are flagged `whitelisted`.
+    run_migration(conn, 14, |c| {
+        c.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS whitelist (
+                user_did TEXT NOT NULL,
+                did TEXT NOT NULL,
+                handle TEXT NOT NULL,
+                added_at TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (user_did, did)
+            );
+            ALTER TABLE account_scores ADD COLUMN whitelisted INTEGER NOT NULL DEFAULT 0;
+            ",
+        )
+    })?;
+
+    // Migration v15: one row per finished scan or sweep, so exposure can be
+    // tracked over time. Timestamps are UTC "YYYY-MM-DD HH:MM:SS" text.
+    run_migration(conn, 15, |c| {
+        c.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS scan_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                user_did TEXT NOT NULL,
+                command TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                finished_at TEXT NOT NULL DEFAULT (datetime('now')),
+                events_detected INTEGER NOT NULL DEFAULT 0,
+                accounts_scored INTEGER NOT NULL DEFAULT 0
+            );
+            CREATE INDEX IF NOT EXISTS idx_scan_runs_user_finished
+                ON scan_runs(user_did, finished_at);
+            ",
+        )
+    })?;
+
+    // Migration v16: the amplifier whose follower list led to each scored
+    // account, so reports can group accounts by amplifier. NULL for accounts
+    // found other ways.
+    run_migration(conn, 16, |c| {
+        c.execute_batch("ALTER TABLE account_scores ADD COLUMN origin_amplifier_did TEXT;")
+    })?;
+
+    // Migration v17: an append-only row per score written, so an account's
+    // threat can be followed over time. account_scores keeps only the latest.
+    run_migration(conn, 17, |c| {
+        c.execute_batch(
+            "
+            CREATE TABLE IF NOT EXISTS account_score_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                user_did TEXT NOT NULL,
+                did TEXT NOT NULL,
+                threat_score REAL,
+                toxicity_score REAL,
+                scored_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            CREATE INDEX IF NOT EXISTS idx_account_score_history_account
+                ON account_score_history(user_did, did, scored_at);
+            ",
+        )
+    })?;
+
+    // Migration v18: the unsmoothed score behind threat_score. With EMA
+    // smoothing on, threat_score blends scans together and this keeps the
+    // latest scan's own score. NULL for rows scored before it existed.
+    run_migration(conn, 18, |c| {
+        c.execute_batch("ALTER TABLE account_scores ADD COLUMN raw_threat_score REAL;")
+    })?;
+
+    // Migration v19: how few posts a low-confidence topic overlap rests on.
+    // NULL when the overlap had enough posts, and for older rows.
+    run_migration(conn, 19, |c| {
+        c.execute_batch("ALTER TABLE account_scores ADD COLUMN overlap_post_count INTEGER;")
+    })?;
+
+    // Migration v20: how many of posts_analyzed were replies. NULL for rows
+    // scored before it was tracked.
+    run_migration(conn, 20, |c| {
+        c.execute_batch("ALTER TABLE account_scores ADD COLUMN replies_analyzed INTEGER;")
+    })?;
+
+    // Migration v21: full-text index over toxic post evidence, one row per
+    // post in top_toxic_posts. upsert_account_score keeps it in step with
+    // account_scores; existing evidence is indexed here.
+    run_migration(conn, 21, |c| {
+        c.execute_batch(
+            "
+            CREATE VIRTUAL TABLE IF NOT EXISTS evidence_fts USING fts5(
+                text,
+                user_did UNINDEXED,
+                did UNINDEXED,
+                tokenize = 'porter unicode61'
+            );
+
+            INSERT INTO evidence_fts (text, user_did, did)
+            SELECT json_extract(p.value, '$.text'), a.user_did, a.did
+            FROM account_scores a, json_each(a.top_toxic_posts) p
+            WHERE json_valid(a.top_toxic_posts)
+              AND json_extract(p.value, '$.text') IS NOT NULL;
+            ",
+        )
+    })?;
+
+    // Migration v22: how much evidence the threat score rests on (0.0-1.0,
+    // see scoring::evidence). NULL for rows scored before it was tracked.
+    run_migration(conn, 22, |c| {
+        c.execute_batch("ALTER TABLE account_scores ADD COLUMN evidence_strength REAL;")
+    })?;
+
     Ok(())
 }
 
@@ -341,8 +515,11 @@ mod tests {
         let count = table_count(&conn).unwrap();
         // schema_version, topic_fingerprint, account_scores,
         // amplification_events, scan_state, users, user_labels,
-        // inferred_pairs = 8 tables
-        assert_eq!(count, 8i64);
+        // inferred_pairs, did_cache, follower_snapshots,
+        // amplifier_followers, whitelist, scan_runs,
+        // account_score_history, plus evidence_fts and its five FTS5
+        // shadow tables = 20 tables
+        assert_eq!(count, 20i64);
     }
 
     #[test]
@@ -414,7 +591,13 @@ mod tests {
             .unwrap()
             .map(|r| r.unwrap())
             .collect();
-        assert_eq!(versions, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            versions,
+            vec![
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+                24
+            ]
+        );
     }
 
     #[test]
@@ -515,8 +698,11 @@ mod tests {
         let count = table_count(&conn).unwrap();
         // schema_version, topic_fingerprint, account_scores,
         // amplification_events, scan_state, users, user_labels,
-        // inferred_pairs = 8 tables
-        assert_eq!(count, 8i64);
+        // inferred_pairs, did_cache, follower_snapshots,
+        // amplifier_followers, whitelist, scan_runs,
+        // account_score_history, plus evidence_fts and its five FTS5
+        // shadow tables = 20 tables
+        assert_eq!(count, 20i64);
 
         // Verify schema_version includes v4
         let versions: Vec<i64> = conn
@@ -526,6 +712,35 @@ mod tests {
             .unwrap()
             .map(|r| r.unwrap())
             .collect();
-        assert_eq!(versions, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            versions,
+            vec![
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+                24
+            ]
+        );
+    }
+
+    #[test]
+    fn test_migration_v9_defaults_overlap_method_to_unknown() {
+        let conn = Connection::open_in_memory().unwrap();
+        create_tables(&conn).unwrap();
+
+        // A row written without the column, as every pre-v9 row was
+        conn.execute(
+            "INSERT INTO account_scores (user_did, did, handle, posts_analyzed)
+             VALUES ('did:plc:abc123', 'did:plc:target1', 'target.bsky.social', 10)",
+            [],
+        )
+        .unwrap();
+
+        let method: Option<String> = conn
+            .query_row(
+                "SELECT overlap_method FROM account_scores WHERE did = 'did:plc:target1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(method.as_deref(), Some("unknown"));
     }
 }
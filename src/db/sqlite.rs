@@ -8,13 +8,17 @@
 // The free functions in queries.rs remain unchanged so existing tests
 // continue to work against Connection directly.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use async_trait::async_trait;
 use rusqlite::Connection;
 use tokio::sync::Mutex;
 
 use super::models::{
-    AccountScore, AccuracyMetrics, AmplificationEvent, InferredPair, UserLabel, UserRow,
+    AccountScore, AccuracyMetrics, AmplificationEvent, AmplifierRollup, InferredPair, ScanRun,
+    ScoreSmoothing, ScoreSnapshot, ThreatPage, ThreatPageQuery, ThreatTier, TierThresholds,
+    UserLabel, UserRow, WhitelistEntry,
 };
 use super::traits::Database;
 
@@ -89,18 +93,61 @@ impl Database for SqliteDatabase {
         super::queries::get_embedding(&conn, user_did)
     }
 
-    async fn upsert_account_score(&self, user_did: &str, score: &AccountScore) -> Result<()> {
+    async fn upsert_account_score(
+        &self,
+        user_did: &str,
+        score: &AccountScore,
+        smoothing: Option<ScoreSmoothing>,
+    ) -> Result<()> {
         let conn = self.conn.lock().await;
-        super::queries::upsert_account_score(&conn, user_did, score)
+        super::queries::upsert_account_score(&conn, user_did, score, smoothing)
+    }
+
+    async fn upsert_account_scores(
+        &self,
+        user_did: &str,
+        scores: &[AccountScore],
+        smoothing: Option<ScoreSmoothing>,
+    ) -> Result<()> {
+        let conn = self.conn.lock().await;
+        super::queries::upsert_account_scores(&conn, user_did, scores, smoothing)
     }
 
     async fn get_ranked_threats(
         &self,
         user_did: &str,
         min_score: f64,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_ranked_threats(&conn, user_did, min_score, thresholds)
+    }
+
+    async fn get_ranked_threats_active_within(
+        &self,
+        user_did: &str,
+        min_score: f64,
+        active_within_days: u32,
+        thresholds: &TierThresholds,
     ) -> Result<Vec<AccountScore>> {
         let conn = self.conn.lock().await;
-        super::queries::get_ranked_threats(&conn, user_did, min_score)
+        super::queries::get_ranked_threats_active_within(
+            &conn,
+            user_did,
+            min_score,
+            active_within_days,
+            thresholds,
+        )
+    }
+
+    async fn get_threats_paged(
+        &self,
+        user_did: &str,
+        query: &ThreatPageQuery,
+        thresholds: &TierThresholds,
+    ) -> Result<ThreatPage> {
+        let conn = self.conn.lock().await;
+        super::queries::get_threats_paged(&conn, user_did, query, thresholds)
     }
 
     async fn is_score_stale(&self, user_did: &str, did: &str, max_age_days: i64) -> Result<bool> {
@@ -108,6 +155,23 @@ impl Database for SqliteDatabase {
         super::queries::is_score_stale(&conn, user_did, did, max_age_days)
     }
 
+    async fn prune_scores(
+        &self,
+        user_did: &str,
+        older_than_days: u32,
+        max_tier: ThreatTier,
+        thresholds: &TierThresholds,
+    ) -> Result<u64> {
+        let conn = self.conn.lock().await;
+        let deleted =
+            super::queries::prune_scores(&conn, user_did, older_than_days, max_tier, thresholds)?;
+        if deleted > 0 {
+            // Deleted rows leave free pages behind; VACUUM shrinks the file
+            conn.execute_batch("VACUUM")?;
+        }
+        Ok(deleted)
+    }
+
     async fn insert_amplification_event(
         &self,
         user_did: &str,
@@ -144,6 +208,16 @@ impl Database for SqliteDatabase {
         super::queries::get_recent_events(&conn, user_did, limit)
     }
 
+    async fn get_recent_events_since(
+        &self,
+        user_did: &str,
+        limit: u32,
+        since_days: u32,
+    ) -> Result<Vec<AmplificationEvent>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_recent_events_since(&conn, user_did, limit, since_days)
+    }
+
     async fn get_events_for_pile_on(
         &self,
         user_did: &str,
@@ -166,6 +240,16 @@ impl Database for SqliteDatabase {
         super::queries::get_median_engagement(&conn, user_did)
     }
 
+    async fn mark_event_followers_scored(&self, event_id: i64) -> Result<()> {
+        let conn = self.conn.lock().await;
+        super::queries::mark_event_followers_scored(&conn, event_id)
+    }
+
+    async fn get_followers_scored_post_uris(&self, user_did: &str) -> Result<Vec<String>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_followers_scored_post_uris(&conn, user_did)
+    }
+
     async fn insert_amplification_event_raw(
         &self,
         user_did: &str,
@@ -189,6 +273,31 @@ impl Database for SqliteDatabase {
         super::queries::get_account_by_did(&conn, user_did, did)
     }
 
+    async fn get_all_account_scores(&self, user_did: &str) -> Result<Vec<AccountScore>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_all_account_scores(&conn, user_did)
+    }
+
+    async fn search_evidence(
+        &self,
+        user_did: &str,
+        query: &str,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>> {
+        let conn = self.conn.lock().await;
+        super::queries::search_evidence(&conn, user_did, query, thresholds)
+    }
+
+    async fn get_account_history(
+        &self,
+        user_did: &str,
+        did: &str,
+        limit: u32,
+    ) -> Result<Vec<ScoreSnapshot>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_account_history(&conn, user_did, did, limit)
+    }
+
     async fn upsert_user_label(
         &self,
         user_did: &str,
@@ -268,6 +377,16 @@ impl Database for SqliteDatabase {
         super::queries::get_scored_account_count(&conn, user_did)
     }
 
+    async fn count_accounts(&self, user_did: &str, min_score: f64) -> Result<i64> {
+        let conn = self.conn.lock().await;
+        super::queries::count_accounts(&conn, user_did, min_score)
+    }
+
+    async fn count_events(&self, user_did: &str) -> Result<i64> {
+        let conn = self.conn.lock().await;
+        super::queries::count_events(&conn, user_did)
+    }
+
     async fn has_fingerprint(&self, user_did: &str) -> Result<bool> {
         let conn = self.conn.lock().await;
         super::queries::has_fingerprint(&conn, user_did)
@@ -287,6 +406,122 @@ impl Database for SqliteDatabase {
         let conn = self.conn.lock().await;
         super::queries::get_all_scored_dids(&conn, user_did)
     }
+
+    async fn get_unresolved_handle_dids(&self, user_did: &str) -> Result<Vec<String>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_unresolved_handle_dids(&conn, user_did)
+    }
+
+    async fn update_resolved_handles(
+        &self,
+        user_did: &str,
+        resolved: &[(String, String)],
+    ) -> Result<u64> {
+        let conn = self.conn.lock().await;
+        super::queries::update_resolved_handles(&conn, user_did, resolved)
+    }
+
+    async fn get_known_dids(&self, user_did: &str) -> Result<Vec<String>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_known_dids(&conn, user_did)
+    }
+
+    async fn get_cached_handles(&self, dids: &[String]) -> Result<HashMap<String, String>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_cached_handles(&conn, dids)
+    }
+
+    async fn get_fresh_cached_handles(
+        &self,
+        dids: &[String],
+        max_age_days: u32,
+    ) -> Result<HashMap<String, String>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_fresh_cached_handles(&conn, dids, max_age_days)
+    }
+
+    async fn cache_handles(&self, entries: &[(String, String)]) -> Result<()> {
+        let conn = self.conn.lock().await;
+        super::queries::cache_handles(&conn, entries)
+    }
+
+    async fn get_follower_snapshot(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+    ) -> Result<Option<Vec<String>>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_follower_snapshot(&conn, user_did, amplifier_did)
+    }
+
+    async fn save_follower_snapshot(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+        follower_hashes: &[String],
+    ) -> Result<()> {
+        let conn = self.conn.lock().await;
+        super::queries::save_follower_snapshot(&conn, user_did, amplifier_did, follower_hashes)
+    }
+
+    async fn record_amplifier_followers(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+        follower_dids: &[String],
+    ) -> Result<()> {
+        let conn = self.conn.lock().await;
+        super::queries::record_amplifier_followers(&conn, user_did, amplifier_did, follower_dids)
+    }
+
+    async fn get_amplifier_rollups(&self, user_did: &str) -> Result<Vec<AmplifierRollup>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_amplifier_rollups(&conn, user_did)
+    }
+
+    async fn add_to_whitelist(&self, user_did: &str, did: &str, handle: &str) -> Result<()> {
+        let conn = self.conn.lock().await;
+        super::queries::add_to_whitelist(&conn, user_did, did, handle)
+    }
+
+    async fn remove_from_whitelist(&self, user_did: &str, did: &str) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        super::queries::remove_from_whitelist(&conn, user_did, did)
+    }
+
+    async fn is_whitelisted(&self, user_did: &str, did: &str) -> Result<bool> {
+        let conn = self.conn.lock().await;
+        super::queries::is_whitelisted(&conn, user_did, did)
+    }
+
+    async fn get_whitelist(&self, user_did: &str) -> Result<Vec<WhitelistEntry>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_whitelist(&conn, user_did)
+    }
+
+    async fn record_scan_run(
+        &self,
+        user_did: &str,
+        command: &str,
+        started_at: &str,
+        events_detected: i64,
+        accounts_scored: i64,
+    ) -> Result<i64> {
+        let conn = self.conn.lock().await;
+        super::queries::record_scan_run(
+            &conn,
+            user_did,
+            command,
+            started_at,
+            events_detected,
+            accounts_scored,
+        )
+    }
+
+    async fn get_scan_runs(&self, user_did: &str, limit: u32) -> Result<Vec<ScanRun>> {
+        let conn = self.conn.lock().await;
+        super::queries::get_scan_runs(&conn, user_did, limit)
+    }
 }
 
 #[cfg(test)]
@@ -353,18 +588,21 @@ mod tests {
             threat_score: Some(65.0),
             threat_tier: Some("Elevated".to_string()),
             posts_analyzed: 20,
-            top_toxic_posts: vec![],
-            scored_at: String::new(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
         };
-        db.upsert_account_score(TEST_USER, &score).await.unwrap();
-        let ranked = db.get_ranked_threats(TEST_USER, 0.0).await.unwrap();
+        db.upsert_account_score(TEST_USER, &score, None)
+            .await
+            .unwrap();
+        let ranked = db
+            .get_ranked_threats(TEST_USER, 0.0, &TierThresholds::DEFAULT)
+            .await
+            .unwrap();
         assert_eq!(ranked.len(), 1);
         assert_eq!(ranked[0].handle, "test.bsky.social");
+
+        assert_eq!(db.count_accounts(TEST_USER, 0.0).await.unwrap(), 1);
+        assert_eq!(db.count_accounts(TEST_USER, 70.0).await.unwrap(), 0);
+        assert_eq!(db.count_accounts("did:plc:other", 0.0).await.unwrap(), 0);
     }
 
     #[tokio::test]
@@ -388,13 +626,15 @@ mod tests {
         let events = db.get_recent_events(TEST_USER, 10).await.unwrap();
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].event_type, "quote");
+        assert_eq!(db.count_events(TEST_USER).await.unwrap(), 1);
+        assert_eq!(db.count_events("did:plc:other").await.unwrap(), 0);
     }
 
     #[tokio::test]
     async fn test_trait_table_count() {
         let db = test_db().await;
         let count = db.table_count().await.unwrap();
-        assert_eq!(count, 8);
+        assert_eq!(count, 20);
     }
 
     #[tokio::test]
@@ -424,15 +664,12 @@ mod tests {
             threat_score: Some(20.0),
             threat_tier: Some("Elevated".to_string()),
             posts_analyzed: 10,
-            top_toxic_posts: vec![],
             scored_at: "2024-01-01".to_string(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
         };
-        db.upsert_account_score(TEST_USER, &score).await.unwrap();
+        db.upsert_account_score(TEST_USER, &score, None)
+            .await
+            .unwrap();
         // Exact match
         let found = db
             .get_account_by_handle(TEST_USER, "test.bsky.social")
@@ -465,15 +702,12 @@ mod tests {
             threat_score: Some(5.0),
             threat_tier: Some("Low".to_string()),
             posts_analyzed: 5,
-            top_toxic_posts: vec![],
             scored_at: "2024-01-01".to_string(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
         };
-        db.upsert_account_score(TEST_USER, &score).await.unwrap();
+        db.upsert_account_score(TEST_USER, &score, None)
+            .await
+            .unwrap();
         let found = db
             .get_account_by_did(TEST_USER, "did:plc:findme")
             .await
@@ -498,4 +732,159 @@ mod tests {
             .await
             .unwrap();
     }
+    fn batch_score(did: &str, threat: f64, last_active_at: Option<&str>) -> AccountScore {
+        AccountScore {
+            did: did.to_string(),
+            handle: format!("{}.bsky.social", &did[8..]),
+            toxicity_score: Some(threat / 100.0),
+            topic_overlap: Some(0.3),
+            threat_score: Some(threat),
+            threat_tier: Some(crate::db::models::ThreatTier::from_score(threat).to_string()),
+            posts_analyzed: 20,
+            last_active_at: last_active_at.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    /// Everything about a stored score except the write timestamp.
+    async fn stored_state(db: &SqliteDatabase) -> Vec<String> {
+        let mut rows: Vec<String> = db
+            .get_ranked_threats(TEST_USER, 0.0, &TierThresholds::DEFAULT)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|s| AccountScore {
+                scored_at: String::new(),
+                ..s
+            })
+            .map(|s| format!("{s:?}"))
+            .collect();
+        rows.sort();
+        rows
+    }
+
+    #[tokio::test]
+    async fn test_batched_and_per_row_score_writes_match() {
+        // Includes a rescore of the same DID and a rescore without a
+        // last-active time, which must keep the earlier one
+        let scores = vec![
+            batch_score("did:plc:aaa", 40.0, Some("2026-01-01T00:00:00Z")),
+            batch_score("did:plc:bbb", 12.0, None),
+            batch_score("did:plc:ccc", 70.0, None),
+            batch_score("did:plc:aaa", 20.0, None),
+            batch_score("did:plc:ddd", 5.0, Some("2026-02-01T00:00:00Z")),
+        ];
+
+        let per_row = test_db().await;
+        for score in &scores {
+            per_row
+                .upsert_account_score(TEST_USER, score, None)
+                .await
+                .unwrap();
+        }
+
+        let batched = test_db().await;
+        let mut writer =
+            crate::pipeline::score_writer::ScoreWriter::new(&batched, TEST_USER, 2, None);
+        for score in scores {
+            writer.push(score).await.unwrap();
+        }
+        assert_eq!(writer.pending(), 1, "last odd score waits for finish");
+        assert_eq!(writer.finish().await.unwrap(), 5);
+
+        let expected = stored_state(&per_row).await;
+        assert_eq!(expected.len(), 4);
+        assert_eq!(stored_state(&batched).await, expected);
+
+        let aaa = batched
+            .get_account_by_did(TEST_USER, "did:plc:aaa")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(aaa.threat_score, Some(20.0));
+        assert_eq!(aaa.last_active_at.as_deref(), Some("2026-01-01T00:00:00Z"));
+    }
+
+    #[tokio::test]
+    async fn test_whitelist_add_list_remove() {
+        let db = test_db().await;
+        assert!(!db
+            .is_whitelisted(TEST_USER, "did:plc:friend")
+            .await
+            .unwrap());
+
+        db.add_to_whitelist(TEST_USER, "did:plc:friend", "old.bsky.social")
+            .await
+            .unwrap();
+        // Re-adding refreshes the handle instead of failing
+        db.add_to_whitelist(TEST_USER, "did:plc:friend", "friend.bsky.social")
+            .await
+            .unwrap();
+        assert!(db
+            .is_whitelisted(TEST_USER, "did:plc:friend")
+            .await
+            .unwrap());
+        assert!(!db
+            .is_whitelisted("did:plc:other", "did:plc:friend")
+            .await
+            .unwrap());
+
+        let entries = db.get_whitelist(TEST_USER).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].did, "did:plc:friend");
+        assert_eq!(entries[0].handle, "friend.bsky.social");
+
+        assert!(db
+            .remove_from_whitelist(TEST_USER, "did:plc:friend")
+            .await
+            .unwrap());
+        assert!(!db
+            .remove_from_whitelist(TEST_USER, "did:plc:friend")
+            .await
+            .unwrap());
+        assert!(db.get_whitelist(TEST_USER).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_whitelist_remove_drops_placeholder_score() {
+        let db = test_db().await;
+        db.add_to_whitelist(TEST_USER, "did:plc:friend", "friend.bsky.social")
+            .await
+            .unwrap();
+        let placeholder =
+            crate::scoring::profile::whitelisted_score("did:plc:friend", "friend.bsky.social");
+        db.upsert_account_score(TEST_USER, &placeholder, None)
+            .await
+            .unwrap();
+        let stored = db
+            .get_account_by_did(TEST_USER, "did:plc:friend")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.whitelisted);
+        assert!(stored.scoring_confidence.is_none());
+
+        // A real score for another account survives the removal
+        db.upsert_account_score(TEST_USER, &batch_score("did:plc:other", 40.0, None), None)
+            .await
+            .unwrap();
+
+        db.remove_from_whitelist(TEST_USER, "did:plc:friend")
+            .await
+            .unwrap();
+        assert!(db
+            .get_account_by_did(TEST_USER, "did:plc:friend")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(db
+            .is_score_stale(TEST_USER, "did:plc:friend", 7)
+            .await
+            .unwrap());
+        assert!(db
+            .get_account_by_did(TEST_USER, "did:plc:other")
+            .await
+            .unwrap()
+            .is_some());
+    }
 }
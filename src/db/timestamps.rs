@@ -0,0 +1,78 @@
+// Timestamp parsing for values read back from the database.
+//
+// SQLite stores `datetime('now')` text ("2026-02-08 14:03:11"), the Postgres
+// backend formats TIMESTAMPTZ columns the same way via to_char, and a few
+// fields carry RFC 3339 strings from the AT Protocol. Parse all of them in
+// one place so age calculations agree across backends.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+/// Parse a stored timestamp as UTC. Accepts RFC 3339, `YYYY-MM-DD HH:MM:SS`
+/// (with optional fractional seconds), and a bare `YYYY-MM-DD` date.
+/// Returns `None` for anything else.
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    for fmt in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(naive.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|naive| naive.and_utc())
+}
+
+/// Whole days between a stored timestamp and `now`. Negative if the
+/// timestamp is in the future; `None` if it can't be parsed.
+pub fn age_in_days(s: &str, now: DateTime<Utc>) -> Option<i64> {
+    parse_timestamp(s).map(|ts| now.signed_duration_since(ts).num_days())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        parse_timestamp("2026-03-01 12:00:00").unwrap()
+    }
+
+    #[test]
+    fn test_parses_sqlite_datetime() {
+        let ts = parse_timestamp("2026-02-08 14:03:11").unwrap();
+        assert_eq!(ts.to_rfc3339(), "2026-02-08T14:03:11+00:00");
+    }
+
+    #[test]
+    fn test_parses_rfc3339_and_date_only() {
+        let rfc = parse_timestamp("2026-02-08T14:03:11.500Z").unwrap();
+        assert_eq!(
+            rfc.format("%Y-%m-%d %H:%M:%S").to_string(),
+            "2026-02-08 14:03:11"
+        );
+
+        let offset = parse_timestamp("2026-02-08T16:03:11+02:00").unwrap();
+        assert_eq!(offset, parse_timestamp("2026-02-08 14:03:11").unwrap());
+
+        let date = parse_timestamp("2026-02-08").unwrap();
+        assert_eq!(date.format("%H:%M:%S").to_string(), "00:00:00");
+    }
+
+    #[test]
+    fn test_rejects_garbage() {
+        assert!(parse_timestamp("").is_none());
+        assert!(parse_timestamp("yesterday").is_none());
+        assert!(parse_timestamp("2026-13-40").is_none());
+    }
+
+    #[test]
+    fn test_age_in_days() {
+        assert_eq!(age_in_days("2026-03-01 00:00:00", now()), Some(0));
+        assert_eq!(age_in_days("2026-01-01 12:00:00", now()), Some(59));
+        assert_eq!(age_in_days("2026-03-05 12:00:00", now()), Some(-4));
+        assert_eq!(age_in_days("not a date", now()), None);
+    }
+}
@@ -8,11 +8,15 @@
 // from direct Connection usage to `Arc<dyn Database>` is a straightforward
 // mechanical replacement in callers.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use async_trait::async_trait;
 
 use super::models::{
-    AccountScore, AccuracyMetrics, AmplificationEvent, InferredPair, UserLabel, UserRow,
+    AccountScore, AccuracyMetrics, AmplificationEvent, AmplifierRollup, InferredPair, ScanRun,
+    ScoreSmoothing, ScoreSnapshot, ThreatPage, ThreatPageQuery, ThreatTier, TierThresholds,
+    UserLabel, UserRow, WhitelistEntry,
 };
 
 #[async_trait]
@@ -63,16 +67,82 @@ pub trait Database: Send + Sync {
 
     // --- Account scores ---
 
-    /// Save or update an account's scores for a specific user.
-    async fn upsert_account_score(&self, user_did: &str, score: &AccountScore) -> Result<()>;
+    /// Save or update an account's scores for a specific user. Every call
+    /// also appends a snapshot to the account's score history.
+    ///
+    /// With `smoothing` set, an account that already has a threat score
+    /// stores the blend of old and new instead, re-tiered from the blend,
+    /// and keeps the new score as `raw_threat_score` (see
+    /// `ScoreSmoothing::apply`). `None` overwrites.
+    ///
+    /// An unmeasured score (see `AccountScore::is_unmeasured`) never
+    /// replaces a stored score whose toxicity was measured; the call then
+    /// changes nothing, history included.
+    async fn upsert_account_score(
+        &self,
+        user_did: &str,
+        score: &AccountScore,
+        smoothing: Option<ScoreSmoothing>,
+    ) -> Result<()>;
+
+    /// Save or update several account scores at once. Lets pipelines write
+    /// in batches instead of taking a write per account. A DID repeated in
+    /// the batch is stored once, from its later score, and smoothed once;
+    /// otherwise the result matches calling `upsert_account_score` for each
+    /// in order.
+    async fn upsert_account_scores(
+        &self,
+        user_did: &str,
+        scores: &[AccountScore],
+        smoothing: Option<ScoreSmoothing>,
+    ) -> Result<()>;
 
     /// Get all scored accounts above a minimum score for a user, ranked by threat score descending.
-    async fn get_ranked_threats(&self, user_did: &str, min_score: f64)
-        -> Result<Vec<AccountScore>>;
+    /// Each account's tier is recomputed from its stored score with `thresholds`.
+    async fn get_ranked_threats(
+        &self,
+        user_did: &str,
+        min_score: f64,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>>;
+
+    /// Like `get_ranked_threats`, limited to accounts whose last post is within
+    /// the last `active_within_days` days. Accounts with no recorded activity
+    /// are excluded.
+    async fn get_ranked_threats_active_within(
+        &self,
+        user_did: &str,
+        min_score: f64,
+        active_within_days: u32,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>>;
+
+    /// One page of a user's ranked threats matching `query`, plus the total
+    /// across all pages. Tiers are recomputed with `thresholds`, and
+    /// `query.tier` filters on the score range that tier covers under them.
+    async fn get_threats_paged(
+        &self,
+        user_did: &str,
+        query: &ThreatPageQuery,
+        thresholds: &TierThresholds,
+    ) -> Result<ThreatPage>;
 
     /// Check if an account's score is stale for a user (older than the given number of days).
     async fn is_score_stale(&self, user_did: &str, did: &str, max_age_days: i64) -> Result<bool>;
 
+    /// Delete a user's account scores that are both older than `older_than_days`
+    /// and at or below `max_tier` (tiered with `thresholds`). Accounts without a
+    /// threat score (insufficient data, unsupported language) count as below
+    /// every tier. Returns the number of rows deleted. SQLite also reclaims
+    /// the freed space with VACUUM when anything was deleted.
+    async fn prune_scores(
+        &self,
+        user_did: &str,
+        older_than_days: u32,
+        max_tier: ThreatTier,
+        thresholds: &TierThresholds,
+    ) -> Result<u64>;
+
     // --- Amplification events ---
 
     /// Record a new amplification event for a user and return its ID.
@@ -97,6 +167,15 @@ pub trait Database: Send + Sync {
         limit: u32,
     ) -> Result<Vec<AmplificationEvent>>;
 
+    /// Get recent amplification events detected within the last `since_days`
+    /// days, ordered by detection time descending.
+    async fn get_recent_events_since(
+        &self,
+        user_did: &str,
+        limit: u32,
+        since_days: u32,
+    ) -> Result<Vec<AmplificationEvent>>;
+
     /// Get amplification events for pile-on detection for a specific user.
     /// Returns (amplifier_did, original_post_uri, detected_at) tuples.
     async fn get_events_for_pile_on(&self, user_did: &str)
@@ -109,6 +188,14 @@ pub trait Database: Send + Sync {
         amplifier_did: &str,
     ) -> Result<Vec<AmplificationEvent>>;
 
+    /// Mark an event's amplifier followers as fetched and scored, so a resumed
+    /// scan (`charcoal scan --resume`) can skip it.
+    async fn mark_event_followers_scored(&self, event_id: i64) -> Result<()>;
+
+    /// The amplifier post URIs of a user's events whose followers have already
+    /// been scored.
+    async fn get_followers_scored_post_uris(&self, user_did: &str) -> Result<Vec<String>>;
+
     /// Insert an amplification event for a user, preserving its original detected_at timestamp.
     /// Used only by the migrate command so historical events keep their real timestamps
     /// instead of all being stamped with NOW().
@@ -135,6 +222,27 @@ pub trait Database: Send + Sync {
     /// Get a single account score by DID, scoped to a user.
     async fn get_account_by_did(&self, user_did: &str, did: &str) -> Result<Option<AccountScore>>;
 
+    /// Every account score stored for a user, unscored rows included,
+    /// exactly as stored (no score floor, no tier recomputation).
+    async fn get_all_account_scores(&self, user_did: &str) -> Result<Vec<AccountScore>>;
+
+    /// A user's scored accounts whose toxic post evidence matches `query`,
+    /// highest threat score first. Tiers are recomputed with `thresholds`.
+    async fn search_evidence(
+        &self,
+        user_did: &str,
+        query: &str,
+        thresholds: &TierThresholds,
+    ) -> Result<Vec<AccountScore>>;
+
+    /// An account's last `limit` score snapshots, oldest first.
+    async fn get_account_history(
+        &self,
+        user_did: &str,
+        did: &str,
+        limit: u32,
+    ) -> Result<Vec<ScoreSnapshot>>;
+
     // --- User labels (ground truth for accuracy measurement) ---
 
     /// Create or update a user-provided label for a target account.
@@ -190,6 +298,13 @@ pub trait Database: Send + Sync {
     /// Count scored accounts for a user.
     async fn get_scored_account_count(&self, user_did: &str) -> Result<i64>;
 
+    /// Count accounts with a threat score at or above `min_score` — the
+    /// size `get_ranked_threats` would return, without loading the rows.
+    async fn count_accounts(&self, user_did: &str, min_score: f64) -> Result<i64>;
+
+    /// Count all amplification events recorded for a user.
+    async fn count_events(&self, user_did: &str) -> Result<i64>;
+
     /// Check if a topic fingerprint exists for a user.
     async fn has_fingerprint(&self, user_did: &str) -> Result<bool>;
 
@@ -201,4 +316,104 @@ pub trait Database: Send + Sync {
 
     /// Get all DIDs that have been scored for a user (for deduplication during discovery).
     async fn get_all_scored_dids(&self, user_did: &str) -> Result<Vec<String>>;
+
+    // --- Handle backfill ---
+
+    /// Distinct DIDs whose stored handle (in account_scores or
+    /// amplification_events) is still the raw DID.
+    async fn get_unresolved_handle_dids(&self, user_did: &str) -> Result<Vec<String>>;
+
+    /// Replace raw-DID handles with resolved ones across account_scores and
+    /// amplification_events. Takes (did, handle) pairs; returns rows updated.
+    async fn update_resolved_handles(
+        &self,
+        user_did: &str,
+        resolved: &[(String, String)],
+    ) -> Result<u64>;
+
+    // --- DID → handle cache ---
+
+    /// Distinct DIDs stored for a user across account_scores and
+    /// amplification_events (scored accounts and amplifiers).
+    async fn get_known_dids(&self, user_did: &str) -> Result<Vec<String>>;
+
+    /// Cached handles for the given DIDs. DIDs with no cache entry are
+    /// absent from the map.
+    async fn get_cached_handles(&self, dids: &[String]) -> Result<HashMap<String, String>>;
+
+    /// Like `get_cached_handles`, but entries resolved more than
+    /// `max_age_days` ago count as missing.
+    async fn get_fresh_cached_handles(
+        &self,
+        dids: &[String],
+        max_age_days: u32,
+    ) -> Result<HashMap<String, String>>;
+
+    /// Insert or refresh (did, handle) cache entries.
+    async fn cache_handles(&self, entries: &[(String, String)]) -> Result<()>;
+
+    // --- Follower snapshots ---
+
+    /// The hashed follower DIDs last recorded for an amplifier, if any.
+    async fn get_follower_snapshot(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+    ) -> Result<Option<Vec<String>>>;
+
+    /// Replace an amplifier's follower snapshot.
+    async fn save_follower_snapshot(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+        follower_hashes: &[String],
+    ) -> Result<()>;
+
+    // --- Per-amplifier rollups ---
+
+    /// Record that these followers were found through an amplifier.
+    /// Already-recorded pairs are ignored.
+    async fn record_amplifier_followers(
+        &self,
+        user_did: &str,
+        amplifier_did: &str,
+        follower_dids: &[String],
+    ) -> Result<()>;
+
+    /// Every amplifier in amplification_events with a threat rollup of its
+    /// scored followers, highest total threat first.
+    async fn get_amplifier_rollups(&self, user_did: &str) -> Result<Vec<AmplifierRollup>>;
+
+    // --- Whitelist ---
+
+    /// Whitelist an account so pipelines skip it. Re-adding an account
+    /// refreshes its stored handle.
+    async fn add_to_whitelist(&self, user_did: &str, did: &str, handle: &str) -> Result<()>;
+
+    /// Remove an account from the whitelist, along with its whitelisted
+    /// placeholder score so the next scan rescores it. Returns whether it
+    /// was listed.
+    async fn remove_from_whitelist(&self, user_did: &str, did: &str) -> Result<bool>;
+
+    /// Whether an account is on the user's whitelist.
+    async fn is_whitelisted(&self, user_did: &str, did: &str) -> Result<bool>;
+
+    /// Every whitelisted account for a user, most recently added first.
+    async fn get_whitelist(&self, user_did: &str) -> Result<Vec<WhitelistEntry>>;
+
+    // --- Scan runs ---
+
+    /// Record a finished scan or sweep. `started_at` is UTC
+    /// "YYYY-MM-DD HH:MM:SS"; the finish time is stamped by the database.
+    async fn record_scan_run(
+        &self,
+        user_did: &str,
+        command: &str,
+        started_at: &str,
+        events_detected: i64,
+        accounts_scored: i64,
+    ) -> Result<i64>;
+
+    /// The user's most recent pipeline runs, newest first.
+    async fn get_scan_runs(&self, user_did: &str, limit: u32) -> Result<Vec<ScanRun>>;
 }
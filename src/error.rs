@@ -0,0 +1,80 @@
+// Typed errors for library consumers.
+//
+// Charcoal's internals use anyhow, which is fine for the CLI but leaves code
+// built on the library unable to tell a rate limit from a missing model. The
+// public entry points (`scoring::profile::build_profile`,
+// `bluesky::posts::fetch_recent_posts`, `ConstellationClient`, the ONNX model
+// loaders, `Config`) return this `Error` instead.
+//
+// The variants are raised where the failure is first understood — a 429 in
+// the HTTP clients, a missing file in the model loaders — and travel through
+// anyhow code unchanged: converting an `anyhow::Error` back into an `Error`
+// finds the first typed error in its chain before falling back to `Other`.
+
+use std::path::PathBuf;
+
+/// Result alias for charcoal's public API.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// What went wrong, in terms a caller can act on.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An upstream service (the Bluesky AppView, Constellation) answered 429.
+    /// Retry after a pause.
+    #[error("{service} rate limited the request: {message}")]
+    RateLimited { service: String, message: String },
+
+    /// The account, handle, or record asked for doesn't exist.
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    /// A local ONNX model or tokenizer file is missing. Run
+    /// `charcoal download-model`.
+    #[error("Model file not found: {}\nRun `charcoal download-model` to download it.", path.display())]
+    ModelMissing { path: PathBuf },
+
+    /// Configuration is missing or invalid.
+    #[error("{0}")]
+    Config(String),
+
+    /// The database failed.
+    #[error("database error: {0:#}")]
+    Db(anyhow::Error),
+
+    /// Anything else.
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(error: anyhow::Error) -> Self {
+        if error.chain().any(|cause| cause.is::<Error>()) {
+            // Context added on the way up is dropped; the typed error is
+            // what callers match on
+            return match error.downcast::<Error>() {
+                Ok(typed) => typed,
+                Err(error) => Error::Other(error),
+            };
+        }
+        if is_database_error(&error) {
+            return Error::Db(error);
+        }
+        Error::Other(error)
+    }
+}
+
+/// Whether a database driver error is anywhere in `error`'s chain.
+fn is_database_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        #[cfg(feature = "sqlite")]
+        if cause.is::<rusqlite::Error>() {
+            return true;
+        }
+        #[cfg(feature = "postgres")]
+        if cause.is::<sqlx_core::error::Error>() {
+            return true;
+        }
+        let _ = cause;
+        false
+    })
+}
@@ -0,0 +1,50 @@
+// Shared HTTP client setup.
+//
+// Every outbound request — public AppView, Constellation, Perspective,
+// Zentropi, model downloads — goes through a client built here, so they all
+// identify themselves the same way. A descriptive user-agent lets the
+// operators of the services Charcoal leans on (Constellation in particular
+// runs on donated hardware) see whose traffic it is and rate it sensibly.
+//
+// The user-agent is process-wide: `Config::load` records the configured
+// value (CHARCOAL_USER_AGENT) before any client is built.
+
+use std::sync::OnceLock;
+
+/// User-agent sent when CHARCOAL_USER_AGENT isn't set.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "charcoal/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/musicjunkieg/charcoal)"
+);
+
+static USER_AGENT: OnceLock<String> = OnceLock::new();
+
+/// Record the user-agent for every client built afterwards. The first call
+/// wins; later calls (e.g. a second `Config::load`) are ignored.
+pub fn set_user_agent(user_agent: &str) {
+    let _ = USER_AGENT.set(user_agent.to_string());
+}
+
+/// The user-agent clients are built with.
+pub fn user_agent() -> &'static str {
+    USER_AGENT
+        .get()
+        .map(String::as_str)
+        .unwrap_or(DEFAULT_USER_AGENT)
+}
+
+/// A reqwest builder with the shared user-agent applied. Callers add their
+/// own timeouts and the like.
+pub fn client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder().user_agent(user_agent())
+}
+
+/// A default client with the shared user-agent — the drop-in replacement
+/// for `reqwest::Client::new()`, and like it, panics only if the TLS
+/// backend can't be initialized.
+pub fn client() -> reqwest::Client {
+    client_builder()
+        .build()
+        .expect("Failed to build HTTP client")
+}
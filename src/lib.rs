@@ -3,11 +3,14 @@
 // This is the library root. Each module corresponds to a major subsystem
 // of the threat detection pipeline.
 
+pub mod bench;
 pub mod bluesky;
 pub mod config;
 pub mod constellation;
 pub mod db;
 pub mod discovery;
+pub mod error;
+pub mod http;
 pub mod output;
 pub mod pipeline;
 pub mod scoring;
@@ -0,0 +1,81 @@
+// CSV export of scored accounts.
+//
+// One row per account with the headline scores, plus the behavioral signals
+// most useful for triage flattened into their own columns, so the file opens
+// straight into a spreadsheet. Fields are quoted per RFC 4180 only when they
+// need it (commas, quotes, or line breaks).
+
+use anyhow::Result;
+use std::fmt::Write;
+
+use crate::db::models::AccountScore;
+use crate::scoring::behavioral::BehavioralSignals;
+
+/// Column names, in the order every row is written.
+pub const CSV_COLUMNS: [&str; 11] = [
+    "did",
+    "handle",
+    "toxicity_score",
+    "topic_overlap",
+    "threat_score",
+    "threat_tier",
+    "posts_analyzed",
+    "scored_at",
+    "quote_ratio",
+    "reply_ratio",
+    "pile_on",
+];
+
+/// Write `scores` to a CSV file at `path`, header row first.
+///
+/// Missing scores and accounts without (parseable) behavioral signals leave
+/// those cells blank. Returns the path the file was written to.
+pub fn write_account_scores(scores: &[AccountScore], path: &str) -> Result<String> {
+    let mut csv = String::new();
+    writeln!(csv, "{}", CSV_COLUMNS.join(","))?;
+
+    for score in scores {
+        let signals = score
+            .behavioral_signals
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<BehavioralSignals>(s).ok());
+
+        let row = [
+            escape_field(&score.did),
+            escape_field(&score.handle),
+            optional(score.toxicity_score),
+            optional(score.topic_overlap),
+            optional(score.threat_score),
+            escape_field(score.threat_tier.as_deref().unwrap_or("")),
+            score.posts_analyzed.to_string(),
+            escape_field(&score.scored_at),
+            optional(signals.as_ref().map(|s| s.quote_ratio)),
+            optional(signals.as_ref().map(|s| s.reply_ratio)),
+            optional(signals.as_ref().map(|s| s.pile_on)),
+        ];
+        writeln!(csv, "{}", row.join(","))?;
+    }
+
+    // Ensure parent directory exists, then write
+    if let Some(parent) = std::path::Path::new(path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, csv)?;
+
+    Ok(path.to_string())
+}
+
+/// Quote a field if it contains a comma, quote, or line break, doubling any
+/// embedded quotes. Other fields are written as-is.
+pub fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// A value's display form, or an empty cell for `None`.
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
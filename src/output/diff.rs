@@ -0,0 +1,188 @@
+// Report diffs — what changed between two runs of `charcoal report`.
+//
+// Every report saves a small snapshot of its ranked accounts (DID, handle,
+// score, tier) in its output directory. `report --diff` loads the previous
+// snapshot before overwriting it and compares the two: accounts that are new
+// to the list, accounts that dropped off it, accounts whose tier changed,
+// and accounts whose score moved by at least `MIN_SCORE_CHANGE` within the
+// same tier. `diff_reports` is pure so it can be tested on synthesized
+// snapshots; rendering lives with the markdown and terminal output.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::db::models::AccountScore;
+
+/// File each report saves its snapshot to, in the report's output directory,
+/// for the next `--diff`.
+pub const SNAPSHOT_FILE: &str = "charcoal-report.snapshot.json";
+
+/// Smallest score movement, in points, reported for an account whose tier
+/// didn't change. Smaller moves are rescoring noise.
+pub const MIN_SCORE_CHANGE: f64 = 5.0;
+
+/// One account as a report listed it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotAccount {
+    pub did: String,
+    pub handle: String,
+    pub threat_score: Option<f64>,
+    pub threat_tier: Option<String>,
+}
+
+/// The ranked accounts of one report, saved for comparing against the next.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportSnapshot {
+    /// When the report was generated (RFC 3339).
+    pub generated_at: String,
+    pub accounts: Vec<SnapshotAccount>,
+}
+
+impl ReportSnapshot {
+    /// A snapshot of `accounts` as of now.
+    pub fn from_accounts(accounts: &[AccountScore]) -> Self {
+        Self {
+            generated_at: Utc::now().to_rfc3339(),
+            accounts: accounts
+                .iter()
+                .map(|a| SnapshotAccount {
+                    did: a.did.clone(),
+                    handle: a.handle.clone(),
+                    threat_score: a.threat_score,
+                    threat_tier: a.threat_tier.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Read the snapshot saved at `path`, or `None` if no report has saved
+    /// one there yet.
+    pub fn load(path: &str) -> Result<Option<Self>> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {path}")),
+        };
+        serde_json::from_str(&text)
+            .map(Some)
+            .with_context(|| format!("Failed to parse report snapshot {path}"))
+    }
+
+    /// Write the snapshot to `path`, replacing any earlier one.
+    pub fn save(&self, path: &str) -> Result<()> {
+        if let Some(parent) = std::path::Path::new(path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// An account listed in both reports whose score or tier moved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreChange {
+    /// The account as the newer report lists it.
+    pub account: SnapshotAccount,
+    pub old_score: Option<f64>,
+    pub old_tier: Option<String>,
+}
+
+impl ScoreChange {
+    /// New score minus old, with a missing score counted as 0.
+    pub fn delta(&self) -> f64 {
+        self.account.threat_score.unwrap_or(0.0) - self.old_score.unwrap_or(0.0)
+    }
+}
+
+/// What changed between two reports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportDiff {
+    /// When the older report was generated.
+    pub since: String,
+    /// Accounts only in the newer report, highest score first.
+    pub added: Vec<SnapshotAccount>,
+    /// Accounts only in the older report, highest (old) score first.
+    pub removed: Vec<SnapshotAccount>,
+    /// Accounts whose tier changed, largest move first.
+    pub tier_changes: Vec<ScoreChange>,
+    /// Accounts in the same tier whose score moved by at least
+    /// `MIN_SCORE_CHANGE`, largest move first.
+    pub score_changes: Vec<ScoreChange>,
+}
+
+impl ReportDiff {
+    /// Whether nothing worth reporting changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.tier_changes.is_empty()
+            && self.score_changes.is_empty()
+    }
+
+    /// Accounts that are High now and weren't in the older report: new to
+    /// the list, or moved up into High.
+    pub fn new_high(&self) -> Vec<&SnapshotAccount> {
+        self.added
+            .iter()
+            .chain(self.tier_changes.iter().map(|c| &c.account))
+            .filter(|a| a.threat_tier.as_deref() == Some("High"))
+            .collect()
+    }
+}
+
+/// Compare two report snapshots. Accounts are matched by DID, so a handle
+/// change isn't reported as one account leaving and another arriving.
+pub fn diff_reports(old: &ReportSnapshot, new: &ReportSnapshot) -> ReportDiff {
+    let old_by_did: HashMap<&str, &SnapshotAccount> =
+        old.accounts.iter().map(|a| (a.did.as_str(), a)).collect();
+    let new_dids: std::collections::HashSet<&str> =
+        new.accounts.iter().map(|a| a.did.as_str()).collect();
+
+    let mut added = Vec::new();
+    let mut tier_changes = Vec::new();
+    let mut score_changes = Vec::new();
+    for account in &new.accounts {
+        let Some(before) = old_by_did.get(account.did.as_str()) else {
+            added.push(account.clone());
+            continue;
+        };
+        let change = ScoreChange {
+            account: account.clone(),
+            old_score: before.threat_score,
+            old_tier: before.threat_tier.clone(),
+        };
+        if account.threat_tier != before.threat_tier {
+            tier_changes.push(change);
+        } else if change.delta().abs() >= MIN_SCORE_CHANGE {
+            score_changes.push(change);
+        }
+    }
+
+    let mut removed: Vec<SnapshotAccount> = old
+        .accounts
+        .iter()
+        .filter(|a| !new_dids.contains(a.did.as_str()))
+        .cloned()
+        .collect();
+
+    let by_score = |a: &SnapshotAccount, b: &SnapshotAccount| {
+        b.threat_score
+            .unwrap_or(0.0)
+            .total_cmp(&a.threat_score.unwrap_or(0.0))
+    };
+    let by_move = |a: &ScoreChange, b: &ScoreChange| b.delta().abs().total_cmp(&a.delta().abs());
+    added.sort_by(by_score);
+    removed.sort_by(by_score);
+    tier_changes.sort_by(by_move);
+    score_changes.sort_by(by_move);
+
+    ReportDiff {
+        since: old.generated_at.clone(),
+        added,
+        removed,
+        tier_changes,
+        score_changes,
+    }
+}
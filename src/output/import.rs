@@ -0,0 +1,282 @@
+// Import of previously exported scores — the reverse of csv.rs and json.rs.
+//
+// Reads a CSV export or a JSON report back into `AccountScore`s so a backup
+// can be restored into a fresh database. Each record is validated on its
+// own: a malformed one is skipped with a reason rather than failing the
+// whole file, so one bad row doesn't block a restore. The file as a whole is
+// rejected only when it isn't an export at all (no recognizable header, or
+// no `accounts` array).
+//
+// A CSV export carries only the headline scores and three behavioral
+// signals, so accounts restored from one have no evidence posts; a JSON
+// report restores everything it holds.
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+use crate::db::models::AccountScore;
+use crate::output::csv::CSV_COLUMNS;
+use crate::scoring::behavioral::BehavioralSignals;
+
+/// A record that couldn't be imported, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedRecord {
+    /// Where the record was: "line 4" in a CSV, "account 3" in a report.
+    pub location: String,
+    pub reason: String,
+}
+
+/// Scores read from an export, plus the records left out.
+#[derive(Debug, Clone, Default)]
+pub struct ImportedScores {
+    pub scores: Vec<AccountScore>,
+    pub skipped: Vec<SkippedRecord>,
+}
+
+impl ImportedScores {
+    fn push(&mut self, location: String, record: std::result::Result<AccountScore, String>) {
+        match record.and_then(validate) {
+            Ok(score) => self.scores.push(score),
+            Err(reason) => self.skipped.push(SkippedRecord { location, reason }),
+        }
+    }
+}
+
+/// Read the scores in a CSV export (see `output::csv`).
+///
+/// Columns are matched by header name, so a file with columns reordered or
+/// dropped still imports as long as `did` and `handle` are there.
+pub fn parse_csv_scores(text: &str) -> Result<ImportedScores> {
+    let mut records = parse_records(text).into_iter();
+    let Some((_, header)) = records.next() else {
+        bail!("CSV file is empty");
+    };
+    let header: Vec<&str> = header.iter().map(|h| h.trim()).collect();
+    for required in ["did", "handle"] {
+        if !header.contains(&required) {
+            bail!(
+                "CSV header has no `{required}` column — expected the columns of a \
+                 charcoal export: {}",
+                CSV_COLUMNS.join(",")
+            );
+        }
+    }
+
+    let mut imported = ImportedScores::default();
+    for (line, fields) in records {
+        // A trailing blank line, or one left between rows
+        if fields.iter().all(|f| f.trim().is_empty()) {
+            continue;
+        }
+        let record = if fields.len() != header.len() {
+            Err(format!(
+                "expected {} fields, found {}",
+                header.len(),
+                fields.len()
+            ))
+        } else {
+            let row = CsvRow {
+                header: &header,
+                fields: &fields,
+            };
+            csv_row_score(&row)
+        };
+        imported.push(format!("line {line}"), record);
+    }
+    Ok(imported)
+}
+
+/// Read the accounts in a JSON report (see `output::json`).
+pub fn parse_json_report_scores(text: &str) -> Result<ImportedScores> {
+    let report: Value = serde_json::from_str(text).context("File is not valid JSON")?;
+    let Some(accounts) = report.get("accounts").and_then(Value::as_array) else {
+        bail!("JSON file has no `accounts` array — expected a charcoal JSON report");
+    };
+
+    let mut imported = ImportedScores::default();
+    for (i, account) in accounts.iter().enumerate() {
+        imported.push(format!("account {}", i + 1), json_account_score(account));
+    }
+    Ok(imported)
+}
+
+/// One CSV data row, looked up by column name.
+struct CsvRow<'a> {
+    header: &'a [&'a str],
+    fields: &'a [String],
+}
+
+impl CsvRow<'_> {
+    /// The trimmed cell under `column`, or `None` when the column is absent
+    /// or the cell is blank.
+    fn get(&self, column: &str) -> Option<&str> {
+        let i = self.header.iter().position(|h| *h == column)?;
+        Some(self.fields[i].trim()).filter(|v| !v.is_empty())
+    }
+
+    fn parse<T: std::str::FromStr>(&self, column: &str) -> std::result::Result<Option<T>, String> {
+        self.get(column)
+            .map(|v| {
+                v.parse()
+                    .map_err(|_| format!("{column} `{v}` is not valid"))
+            })
+            .transpose()
+    }
+}
+
+fn csv_row_score(row: &CsvRow<'_>) -> std::result::Result<AccountScore, String> {
+    let quote_ratio = row.parse::<f64>("quote_ratio")?;
+    let reply_ratio = row.parse::<f64>("reply_ratio")?;
+    let pile_on = row.parse::<bool>("pile_on")?;
+    let behavioral_signals = if quote_ratio.is_some() || reply_ratio.is_some() || pile_on.is_some()
+    {
+        for ratio in [quote_ratio, reply_ratio].into_iter().flatten() {
+            in_range("behavioral ratio", ratio, 0.0, 1.0)?;
+        }
+        let signals = BehavioralSignals {
+            quote_ratio: quote_ratio.unwrap_or(0.0),
+            reply_ratio: reply_ratio.unwrap_or(0.0),
+            pile_on: pile_on.unwrap_or(false),
+            ..BehavioralSignals::default()
+        };
+        Some(serde_json::to_string(&signals).map_err(|e| e.to_string())?)
+    } else {
+        None
+    };
+
+    Ok(AccountScore {
+        did: row.get("did").unwrap_or_default().to_string(),
+        handle: row.get("handle").unwrap_or_default().to_string(),
+        toxicity_score: row.parse("toxicity_score")?,
+        topic_overlap: row.parse("topic_overlap")?,
+        threat_score: row.parse("threat_score")?,
+        threat_tier: row.get("threat_tier").map(str::to_string),
+        posts_analyzed: row.parse("posts_analyzed")?.unwrap_or(0),
+        top_toxic_posts: vec![],
+        scored_at: row.get("scored_at").unwrap_or_default().to_string(),
+        behavioral_signals,
+        context_score: None,
+        graph_distance: None,
+        fingerprint_quality: None,
+        scoring_confidence: None,
+        overlap_method: None,
+        last_active_at: None,
+        origin_amplifier_did: None,
+        raw_threat_score: None,
+        overlap_post_count: None,
+        replies_analyzed: None,
+        evidence_strength: None,
+        whitelisted: false,
+        peak_toxicity_attributes: None,
+    })
+}
+
+fn json_account_score(account: &Value) -> std::result::Result<AccountScore, String> {
+    let mut account = account.clone();
+    let Some(object) = account.as_object_mut() else {
+        return Err("not a JSON object".to_string());
+    };
+    // Reports nest behavioral signals as an object; the model stores them as
+    // a JSON string.
+    if let Some(signals) = object.get_mut("behavioral_signals") {
+        if !signals.is_null() && !signals.is_string() {
+            *signals = Value::String(signals.to_string());
+        }
+    }
+    serde_json::from_value(account).map_err(|e| e.to_string())
+}
+
+/// Check the values an import can't trust: a DID and handle, scores in
+/// range, a tier charcoal knows, and a readable `scored_at`. The timestamp
+/// is kept, in the database's format, so restored scores age from when they
+/// were scored rather than from the import.
+fn validate(mut score: AccountScore) -> std::result::Result<AccountScore, String> {
+    if !score.did.starts_with("did:") {
+        return Err(format!("`{}` is not a DID", score.did));
+    }
+    if score.handle.is_empty() {
+        return Err("handle is empty".to_string());
+    }
+    if let Some(threat) = score.threat_score {
+        in_range("threat_score", threat, 0.0, 100.0)?;
+    }
+    if let Some(toxicity) = score.toxicity_score {
+        in_range("toxicity_score", toxicity, 0.0, 1.0)?;
+    }
+    if let Some(overlap) = score.topic_overlap {
+        // Embedding similarity can be negative
+        in_range("topic_overlap", overlap, -1.0, 1.0)?;
+    }
+    if let Some(tier) = score.threat_tier.as_deref() {
+        if !["High", "Elevated", "Watch", "Low"].contains(&tier) {
+            return Err(format!("threat_tier `{tier}` is not a known tier"));
+        }
+    }
+    score.scored_at = stored_timestamp(&score.scored_at)?;
+    Ok(score)
+}
+
+/// `value` as a `YYYY-MM-DD HH:MM:SS` UTC timestamp, accepting that format
+/// (what exports contain) or RFC 3339. Empty stays empty, which the
+/// database stamps with the import time.
+fn stored_timestamp(value: &str) -> std::result::Result<String, String> {
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(String::new());
+    }
+    chrono::NaiveDateTime::parse_from_str(value, FORMAT)
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(value).map(|t| t.naive_utc()))
+        .map(|t| t.format(FORMAT).to_string())
+        .map_err(|_| format!("scored_at `{value}` is not a timestamp"))
+}
+
+fn in_range(name: &str, value: f64, min: f64, max: f64) -> std::result::Result<(), String> {
+    if value.is_finite() && (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(format!("{name} {value} is outside {min}–{max}"))
+    }
+}
+
+/// Split CSV text into records of fields per RFC 4180, each with the line
+/// it starts on (1-based). Quoted fields may hold commas, doubled quotes,
+/// and line breaks; a CR before a record's LF is dropped.
+fn parse_records(text: &str) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                line += 1;
+                record.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut record)));
+                record_line = line;
+            }
+            ('\n', true) => {
+                line += 1;
+                field.push(c);
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((record_line, record));
+    }
+    records
+}
@@ -0,0 +1,73 @@
+// JSON report generation.
+//
+// Produces a single machine-readable document with the ranked accounts, the
+// protected user's topic fingerprint, and recent amplification events, for
+// piping into other tools. The document carries a top-level schema_version
+// so consumers can detect shape changes; bump JSON_REPORT_SCHEMA_VERSION
+// whenever a field is renamed, removed, or changes meaning.
+
+use anyhow::Result;
+use chrono::Utc;
+use serde_json::{json, Value};
+
+use crate::db::models::{AccountScore, AmplificationEvent};
+use crate::topics::fingerprint::TopicFingerprint;
+
+/// Shape version of the JSON report document.
+pub const JSON_REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Build the JSON report document.
+///
+/// Each account's `behavioral_signals` is parsed back into an object (it's
+/// stored as a JSON string); unparseable signals become `null`. Empty inputs
+/// produce empty arrays, and a missing fingerprint is `null`.
+pub fn build_json_report(
+    threats: &[AccountScore],
+    fingerprint: Option<&TopicFingerprint>,
+    events: &[AmplificationEvent],
+) -> Result<Value> {
+    let accounts = threats
+        .iter()
+        .map(account_to_json)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "schema_version": JSON_REPORT_SCHEMA_VERSION,
+        "generated_at": Utc::now().to_rfc3339(),
+        "accounts": accounts,
+        "fingerprint": fingerprint,
+        "amplification_events": events,
+    }))
+}
+
+/// Generate a JSON threat report and write it to a file.
+///
+/// Returns the path the report was written to.
+pub fn generate_json_report(
+    threats: &[AccountScore],
+    fingerprint: Option<&TopicFingerprint>,
+    events: &[AmplificationEvent],
+    output_path: &str,
+) -> Result<String> {
+    let report = build_json_report(threats, fingerprint, events)?;
+
+    // Ensure parent directory exists, then write
+    if let Some(parent) = std::path::Path::new(output_path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(output_path, serde_json::to_string_pretty(&report)?)?;
+
+    Ok(output_path.to_string())
+}
+
+/// One account as JSON, with behavioral signals as a nested object.
+fn account_to_json(account: &AccountScore) -> Result<Value> {
+    let mut value = serde_json::to_value(account)?;
+    let behavioral = account
+        .behavioral_signals
+        .as_deref()
+        .and_then(|s| serde_json::from_str::<Value>(s).ok())
+        .unwrap_or(Value::Null);
+    value["behavioral_signals"] = behavioral;
+    Ok(value)
+}
@@ -1,24 +1,43 @@
 // Markdown report generation.
 //
 // Produces a .md file with the full threat report, including ranked accounts,
-// evidence (toxic posts), and the protected user's topic fingerprint.
+// evidence (toxic posts, grouped by category when per-post categories are
+// available), the protected user's topic fingerprint, and optionally the
+// accounts grouped by the amplifier whose followers they were found among,
+// and what changed since the previous report (see `output::diff`).
 // This format is easy to review, share, and version-control.
 
 use anyhow::Result;
 use chrono::Utc;
 use std::fmt::Write;
 
-use crate::db::models::{AccountScore, AmplificationEvent};
+use crate::config::EvidenceOrder;
+use crate::db::models::{AccountScore, AmplificationEvent, ThreatTier, ToxicPost};
+use crate::output::diff::{ReportDiff, ScoreChange, SnapshotAccount};
 use crate::scoring::behavioral::BehavioralSignals;
+use crate::scoring::recency;
 use crate::topics::fingerprint::TopicFingerprint;
+use crate::topics::overlap::overlap_method_note;
+use crate::toxicity::traits::ToxicityAttributes;
 
 /// Generate a markdown threat report and write it to a file.
 ///
+/// `events_since_days` is the recency window the caller used to select
+/// `events` (`None` for all time); it's shown in the report headers, as is
+/// the `evidence_order` accounts were scored with. With `group_by_amplifier`
+/// an "Accounts by Amplifier" section comes before the flat ranked table.
+/// `changes` adds a "Changes since last report" section after the summary.
+///
 /// Returns the path the report was written to.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_report(
     accounts: &[AccountScore],
     fingerprint: Option<&TopicFingerprint>,
     events: &[AmplificationEvent],
+    events_since_days: Option<u32>,
+    evidence_order: EvidenceOrder,
+    group_by_amplifier: bool,
+    changes: Option<&ReportDiff>,
     output_path: &str,
 ) -> Result<String> {
     let mut md = String::new();
@@ -29,6 +48,25 @@ pub fn generate_report(
     writeln!(md)?;
     writeln!(md, "Generated: {timestamp}")?;
     writeln!(md)?;
+    writeln!(md, "Evidence: {}", evidence_order.label())?;
+    if events_since_days.is_some() {
+        writeln!(md)?;
+        writeln!(
+            md,
+            "Amplification events: {}",
+            super::events_window_label(events_since_days)
+        )?;
+    }
+    let signals: Vec<BehavioralSignals> = accounts
+        .iter()
+        .filter_map(|a| a.behavioral_signals.as_deref())
+        .filter_map(|json| serde_json::from_str(json).ok())
+        .collect();
+    if let Some(window) = recency::report_window_label(&signals) {
+        writeln!(md)?;
+        writeln!(md, "Post window: {window}")?;
+    }
+    writeln!(md)?;
 
     // Summary statistics
     let total = accounts.len();
@@ -57,6 +95,10 @@ pub fn generate_report(
     writeln!(md, "| **Total** | **{total}** |")?;
     writeln!(md)?;
 
+    if let Some(changes) = changes {
+        write_changes(&mut md, changes)?;
+    }
+
     // Topic fingerprint (if available)
     if let Some(fp) = fingerprint {
         writeln!(md, "## Protected User Topic Fingerprint")?;
@@ -77,6 +119,10 @@ pub fn generate_report(
         writeln!(md)?;
     }
 
+    if group_by_amplifier {
+        write_amplifier_groups(&mut md, accounts, events)?;
+    }
+
     // Ranked threat list
     writeln!(md, "## Ranked Accounts")?;
     writeln!(md)?;
@@ -97,7 +143,7 @@ pub fn generate_report(
             i + 1,
             account.handle,
             account.threat_score.unwrap_or(0.0),
-            account.threat_tier.as_deref().unwrap_or("?"),
+            super::tier_with_evidence(account),
             distance,
             account.toxicity_score.unwrap_or(0.0),
             account.topic_overlap.unwrap_or(0.0),
@@ -113,7 +159,14 @@ pub fn generate_report(
         .collect();
 
     if !quotes.is_empty() {
-        writeln!(md, "## Amplification Events")?;
+        match events_since_days {
+            Some(_) => writeln!(
+                md,
+                "## Amplification Events ({})",
+                super::events_window_label(events_since_days)
+            )?,
+            None => writeln!(md, "## Amplification Events")?,
+        }
         writeln!(md)?;
         writeln!(md, "Quote posts that triggered analysis:")?;
         writeln!(md)?;
@@ -134,10 +187,16 @@ pub fn generate_report(
         writeln!(md)?;
     }
 
-    // Detailed evidence for elevated+ accounts (Elevated tier starts at 15.0)
+    // Detailed evidence for Elevated+ accounts, by their recorded tier so
+    // configured tier boundaries apply
     let high_priority: Vec<&AccountScore> = accounts
         .iter()
-        .filter(|a| a.threat_score.is_some_and(|s| s >= 15.0))
+        .filter(|a| {
+            matches!(
+                a.threat_tier.as_deref(),
+                Some(tier) if tier == ThreatTier::Elevated.as_str() || tier == ThreatTier::High.as_str()
+            )
+        })
         .collect();
 
     if !high_priority.is_empty() {
@@ -151,7 +210,7 @@ pub fn generate_report(
                 md,
                 "- **Score:** {:.1} ({})",
                 account.threat_score.unwrap_or(0.0),
-                account.threat_tier.as_deref().unwrap_or("?")
+                super::tier_with_evidence(account)
             )?;
             writeln!(
                 md,
@@ -160,8 +219,12 @@ pub fn generate_report(
             )?;
             writeln!(
                 md,
-                "- **Topic overlap:** {:.2}",
-                account.topic_overlap.unwrap_or(0.0)
+                "- **Topic overlap:** {:.2} ({})",
+                account.topic_overlap.unwrap_or(0.0),
+                overlap_method_note(
+                    account.overlap_method.as_deref(),
+                    account.overlap_post_count
+                )
             )?;
 
             if let Some(signals_json) = &account.behavioral_signals {
@@ -183,19 +246,44 @@ pub fn generate_report(
                         "  - Pile-on: {} | Benign gate: {} | Behavioral boost: {:.2}x",
                         pile_on, gate, signals.behavioral_boost
                     )?;
+                    if signals.stranger_quote {
+                        writeln!(md, "  - Quoted you with no follow either way")?;
+                    }
+                    if !signals.moderation_labels.is_empty() {
+                        writeln!(
+                            md,
+                            "  - Labeled by a trusted labeler: {}",
+                            signals.moderation_labels.join(", ")
+                        )?;
+                    }
+                    if let Some(window) = recency::post_window_note(&signals) {
+                        writeln!(md, "  - Post window: {window}")?;
+                    }
                 }
             }
             writeln!(md)?;
 
-            if !account.top_toxic_posts.is_empty() {
+            let all_categorized = account
+                .top_toxic_posts
+                .iter()
+                .all(|p| p.attributes.is_some());
+
+            if !account.top_toxic_posts.is_empty() && all_categorized {
+                write_grouped_evidence(&mut md, &account.top_toxic_posts)?;
+            } else if !account.top_toxic_posts.is_empty() {
+                // Rows scored before per-post categories were stored
                 writeln!(md, "**Most toxic posts:**")?;
                 writeln!(md)?;
                 for (j, post) in account.top_toxic_posts.iter().enumerate() {
+                    let flagged = super::flagged_for_label(post)
+                        .map(|label| format!(", {label}"))
+                        .unwrap_or_default();
                     writeln!(
                         md,
-                        "{}. \\[tox: {:.2}\\] {}",
+                        "{}. \\[tox: {:.2}{}\\] {}",
                         j + 1,
                         post.toxicity,
+                        flagged,
                         post.text.replace('\n', " ")
                     )?;
                 }
@@ -219,10 +307,261 @@ pub fn generate_report(
     Ok(output_path.to_string())
 }
 
+/// Write the "Accounts by Amplifier" section: one ranked table per
+/// amplifier, largest group first. Ranks match the flat ranked table.
+/// Write the "Changes since last report" section.
+fn write_changes(md: &mut String, diff: &ReportDiff) -> Result<()> {
+    writeln!(md, "## Changes since last report")?;
+    writeln!(md)?;
+    writeln!(
+        md,
+        "Compared with the report from {}.",
+        report_time(&diff.since)
+    )?;
+    writeln!(md)?;
+
+    if diff.is_empty() {
+        writeln!(md, "No accounts were added, removed, or changed tier.")?;
+        writeln!(md)?;
+        return Ok(());
+    }
+
+    let new_high = diff.new_high();
+    if !new_high.is_empty() {
+        let handles: Vec<String> = new_high.iter().map(|a| format!("@{}", a.handle)).collect();
+        writeln!(md, "**New High-tier accounts:** {}", handles.join(", "))?;
+        writeln!(md)?;
+    }
+
+    let write_accounts = |md: &mut String, title: &str, accounts: &[SnapshotAccount]| {
+        if accounts.is_empty() {
+            return Ok(());
+        }
+        writeln!(md, "### {title} ({})", accounts.len())?;
+        writeln!(md)?;
+        writeln!(md, "| Handle | Score | Tier |")?;
+        writeln!(md, "|--------|-------|------|")?;
+        for account in accounts {
+            writeln!(
+                md,
+                "| @{} | {} | {} |",
+                account.handle,
+                score_cell(account.threat_score),
+                account.threat_tier.as_deref().unwrap_or("—")
+            )?;
+        }
+        writeln!(md)
+    };
+    let write_moves = |md: &mut String, title: &str, changes: &[ScoreChange]| {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        writeln!(md, "### {title} ({})", changes.len())?;
+        writeln!(md)?;
+        writeln!(md, "| Handle | Before | After | Change |")?;
+        writeln!(md, "|--------|--------|-------|--------|")?;
+        for change in changes {
+            writeln!(
+                md,
+                "| @{} | {} ({}) | {} ({}) | {:+.1} |",
+                change.account.handle,
+                change.old_tier.as_deref().unwrap_or("—"),
+                score_cell(change.old_score),
+                change.account.threat_tier.as_deref().unwrap_or("—"),
+                score_cell(change.account.threat_score),
+                change.delta()
+            )?;
+        }
+        writeln!(md)
+    };
+
+    write_accounts(md, "New accounts", &diff.added)?;
+    write_accounts(md, "No longer listed", &diff.removed)?;
+    write_moves(md, "Tier changes", &diff.tier_changes)?;
+    write_moves(md, "Score changes", &diff.score_changes)?;
+    Ok(())
+}
+
+/// A snapshot's RFC 3339 timestamp as "2026-02-08 12:00 UTC", or as stored
+/// if it doesn't parse.
+fn report_time(timestamp: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|t| {
+            t.with_timezone(&Utc)
+                .format("%Y-%m-%d %H:%M UTC")
+                .to_string()
+        })
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+fn score_cell(score: Option<f64>) -> String {
+    score.map_or_else(|| "—".to_string(), |s| format!("{s:.1}"))
+}
+
+fn write_amplifier_groups(
+    md: &mut String,
+    accounts: &[AccountScore],
+    events: &[AmplificationEvent],
+) -> Result<()> {
+    // (amplifier DID, [(rank, account)]) in first-seen order
+    let mut groups: Vec<(&str, Vec<(usize, &AccountScore)>)> = Vec::new();
+    let mut ungrouped = 0;
+    for (i, account) in accounts.iter().enumerate() {
+        let Some(amplifier) = account.origin_amplifier_did.as_deref() else {
+            ungrouped += 1;
+            continue;
+        };
+        match groups.iter_mut().find(|(did, _)| *did == amplifier) {
+            Some((_, members)) => members.push((i + 1, account)),
+            None => groups.push((amplifier, vec![(i + 1, account)])),
+        }
+    }
+    // Stable sort keeps the higher-ranked group first among equal sizes
+    groups.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+
+    writeln!(md, "## Accounts by Amplifier")?;
+    writeln!(md)?;
+    if groups.is_empty() {
+        writeln!(md, "No scored accounts were found through an amplifier.")?;
+        writeln!(md)?;
+        return Ok(());
+    }
+    writeln!(
+        md,
+        "Scored accounts grouped by the amplifier whose followers they were found among."
+    )?;
+    writeln!(md)?;
+
+    for (amplifier_did, members) in &groups {
+        let amplifier_events: Vec<&AmplificationEvent> = events
+            .iter()
+            .filter(|e| e.amplifier_did == *amplifier_did)
+            .collect();
+        let name = amplifier_events
+            .first()
+            .map(|e| format!("@{}", e.amplifier_handle))
+            .unwrap_or_else(|| amplifier_did.to_string());
+        let mut kinds: Vec<&str> = amplifier_events
+            .iter()
+            .map(|e| e.event_type.as_str())
+            .collect();
+        kinds.sort_unstable();
+        kinds.dedup();
+
+        let noun = if members.len() == 1 {
+            "account"
+        } else {
+            "accounts"
+        };
+        if kinds.is_empty() {
+            writeln!(md, "### {name} — {} {noun}", members.len())?;
+        } else {
+            writeln!(
+                md,
+                "### {name} — {} {noun} ({})",
+                members.len(),
+                kinds.join(", ")
+            )?;
+        }
+        writeln!(md)?;
+        writeln!(md, "| Rank | Handle | Score | Tier | Toxicity | Overlap |")?;
+        writeln!(md, "|------|--------|-------|------|----------|---------|")?;
+        for (rank, account) in members {
+            writeln!(
+                md,
+                "| {} | @{} | {:.1} | {} | {:.2} | {:.2} |",
+                rank,
+                account.handle,
+                account.threat_score.unwrap_or(0.0),
+                account.threat_tier.as_deref().unwrap_or("?"),
+                account.toxicity_score.unwrap_or(0.0),
+                account.topic_overlap.unwrap_or(0.0),
+            )?;
+        }
+        writeln!(md)?;
+    }
+
+    if ungrouped > 0 {
+        writeln!(
+            md,
+            "{ungrouped} other account(s) weren't found through an amplifier \
+             (sweeps, `charcoal score`) — see Ranked Accounts."
+        )?;
+        writeln!(md)?;
+    }
+    Ok(())
+}
+
+/// Evidence groups in report order — the most serious first.
+const EVIDENCE_GROUPS: [&str; 4] = ["Threats", "Identity attacks", "Insults", "General toxicity"];
+
+/// A category score must reach this before a post is filed under it rather
+/// than under "General toxicity".
+const EVIDENCE_CATEGORY_FLOOR: f64 = 0.3;
+
+/// Max characters of post text shown in an evidence table cell.
+const EVIDENCE_PREVIEW_CHARS: usize = 200;
+
+/// Which evidence group a post belongs in, based on its strongest
+/// threat / identity-attack / insult score.
+pub fn evidence_category(attributes: &ToxicityAttributes) -> &'static str {
+    let candidates = [
+        (EVIDENCE_GROUPS[0], attributes.threat),
+        (EVIDENCE_GROUPS[1], attributes.identity_attack),
+        (EVIDENCE_GROUPS[2], attributes.insult),
+    ];
+    candidates
+        .into_iter()
+        .filter_map(|(group, score)| score.map(|s| (group, s)))
+        .filter(|(_, s)| *s >= EVIDENCE_CATEGORY_FLOOR)
+        .fold(
+            None,
+            |best: Option<(&'static str, f64)>, (group, s)| match best {
+                Some((_, b)) if b >= s => best,
+                _ => Some((group, s)),
+            },
+        )
+        .map(|(group, _)| group)
+        .unwrap_or(EVIDENCE_GROUPS[3])
+}
+
+/// Write evidence posts as one table per category, skipping empty groups.
+fn write_grouped_evidence(md: &mut String, posts: &[ToxicPost]) -> Result<()> {
+    for group in EVIDENCE_GROUPS {
+        let in_group: Vec<&ToxicPost> = posts
+            .iter()
+            .filter(|p| {
+                p.attributes
+                    .as_ref()
+                    .is_some_and(|a| evidence_category(a) == group)
+            })
+            .collect();
+        if in_group.is_empty() {
+            continue;
+        }
+
+        writeln!(md, "**{group}:**")?;
+        writeln!(md)?;
+        writeln!(md, "| Toxicity | Post |")?;
+        writeln!(md, "|----------|------|")?;
+        for post in in_group {
+            let preview = super::truncate_chars(&post.text, EVIDENCE_PREVIEW_CHARS);
+            let safe_text = preview.replace('|', "\\|").replace('\n', " ");
+            match super::flagged_for_label(post) {
+                Some(label) => {
+                    writeln!(md, "| {:.2} | {} _({label})_ |", post.toxicity, safe_text)?
+                }
+                None => writeln!(md, "| {:.2} | {} |", post.toxicity, safe_text)?,
+            }
+        }
+        writeln!(md)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::models::ToxicPost;
 
     #[test]
     fn test_generate_report() {
@@ -239,13 +578,14 @@ mod tests {
                     text: "This is a toxic post example".to_string(),
                     toxicity: 0.92,
                     uri: "at://did:plc:abc/post/123".to_string(),
+                    attributes: None,
+                    created_at: None,
+                    hardblock_pattern: None,
+                    flagged_for: None,
                 }],
                 scored_at: "2026-02-08".to_string(),
-                behavioral_signals: None,
-                context_score: None,
-                graph_distance: None,
-                fingerprint_quality: None,
-                scoring_confidence: None,
+                overlap_method: Some("keyword".to_string()),
+                ..Default::default()
             },
             AccountScore {
                 did: "did:plc:def".to_string(),
@@ -255,28 +595,272 @@ mod tests {
                 threat_score: Some(24.0),
                 threat_tier: Some("Low".to_string()),
                 posts_analyzed: 15,
-                top_toxic_posts: vec![],
                 scored_at: "2026-02-08".to_string(),
-                behavioral_signals: None,
-                context_score: None,
-                graph_distance: None,
-                fingerprint_quality: None,
-                scoring_confidence: None,
+                ..Default::default()
             },
         ];
 
         let tmp_path = "/tmp/charcoal_test_report.md";
-        let result = generate_report(&accounts, None, &[], tmp_path);
+        let result = generate_report(
+            &accounts,
+            None,
+            &[],
+            None,
+            EvidenceOrder::Toxicity,
+            false,
+            None,
+            tmp_path,
+        );
         assert!(result.is_ok());
 
         let content = std::fs::read_to_string(tmp_path).unwrap();
         assert!(content.contains("# Charcoal Threat Report"));
         assert!(content.contains("hostile.bsky.social"));
         assert!(content.contains("Elevated"));
-        assert!(content.contains("Evidence"));
+        assert!(content.contains("Evidence: most toxic posts first"));
         assert!(content.contains("toxic post example"));
+        assert!(content.contains("**Topic overlap:** 0.30 (keyword)"));
 
         // Clean up
         let _ = std::fs::remove_file(tmp_path);
     }
+
+    fn attrs(threat: f64, identity_attack: f64, insult: f64) -> ToxicityAttributes {
+        ToxicityAttributes {
+            severe_toxicity: None,
+            identity_attack: Some(identity_attack),
+            insult: Some(insult),
+            profanity: None,
+            threat: Some(threat),
+        }
+    }
+
+    fn evidence_account(posts: Vec<ToxicPost>) -> AccountScore {
+        AccountScore {
+            did: "did:plc:grp".to_string(),
+            handle: "grouped.bsky.social".to_string(),
+            toxicity_score: Some(0.7),
+            topic_overlap: Some(0.4),
+            threat_score: Some(40.0),
+            threat_tier: Some("Elevated".to_string()),
+            posts_analyzed: 30,
+            top_toxic_posts: posts,
+            scored_at: "2026-02-08".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_evidence_category_picks_strongest() {
+        assert_eq!(evidence_category(&attrs(0.8, 0.4, 0.6)), "Threats");
+        assert_eq!(evidence_category(&attrs(0.1, 0.9, 0.6)), "Identity attacks");
+        assert_eq!(evidence_category(&attrs(0.1, 0.2, 0.5)), "Insults");
+        // Nothing clears the floor
+        assert_eq!(evidence_category(&attrs(0.1, 0.2, 0.2)), "General toxicity");
+        assert_eq!(
+            evidence_category(&ToxicityAttributes::default()),
+            "General toxicity"
+        );
+    }
+
+    #[test]
+    fn test_report_groups_evidence_by_category() {
+        let accounts = vec![evidence_account(vec![
+            ToxicPost {
+                text: "I know where you live | watch out".to_string(),
+                toxicity: 0.95,
+                uri: "at://did:plc:grp/post/1".to_string(),
+                attributes: Some(attrs(0.9, 0.1, 0.4)),
+                created_at: None,
+                hardblock_pattern: None,
+                flagged_for: Some("threat".to_string()),
+            },
+            ToxicPost {
+                text: "you absolute clown".to_string(),
+                toxicity: 0.7,
+                uri: "at://did:plc:grp/post/2".to_string(),
+                attributes: Some(attrs(0.0, 0.1, 0.8)),
+                created_at: None,
+                hardblock_pattern: None,
+                flagged_for: None,
+            },
+        ])];
+
+        let tmp_path = "/tmp/charcoal_test_report_grouped.md";
+        generate_report(
+            &accounts,
+            None,
+            &[],
+            None,
+            EvidenceOrder::Toxicity,
+            false,
+            None,
+            tmp_path,
+        )
+        .unwrap();
+        let content = std::fs::read_to_string(tmp_path).unwrap();
+        let _ = std::fs::remove_file(tmp_path);
+
+        assert!(content.contains("**Threats:**"));
+        assert!(content.contains("**Insults:**"));
+        assert!(!content.contains("**Identity attacks:**"));
+        assert!(!content.contains("Most toxic posts"));
+        // Pipes inside post text are escaped so the table stays intact
+        assert!(content.contains("I know where you live \\| watch out"));
+        // The category that set a post's score is named beside it
+        assert!(content.contains("watch out _(flagged for threat)_ |"));
+        assert!(!content.contains("clown _("));
+        // Threats come before insults
+        let threats = content.find("**Threats:**").unwrap();
+        let insults = content.find("**Insults:**").unwrap();
+        assert!(threats < insults);
+    }
+
+    #[test]
+    fn test_report_falls_back_to_flat_evidence_without_attributes() {
+        let accounts = vec![evidence_account(vec![ToxicPost {
+            text: "legacy evidence row".to_string(),
+            toxicity: 0.8,
+            uri: "at://did:plc:grp/post/3".to_string(),
+            attributes: None,
+            created_at: None,
+            hardblock_pattern: None,
+            flagged_for: None,
+        }])];
+
+        let tmp_path = "/tmp/charcoal_test_report_flat.md";
+        generate_report(
+            &accounts,
+            None,
+            &[],
+            None,
+            EvidenceOrder::Toxicity,
+            false,
+            None,
+            tmp_path,
+        )
+        .unwrap();
+        let content = std::fs::read_to_string(tmp_path).unwrap();
+        let _ = std::fs::remove_file(tmp_path);
+
+        assert!(content.contains("**Most toxic posts:**"));
+        assert!(content.contains("legacy evidence row"));
+        assert!(!content.contains("**Threats:**"));
+    }
+
+    #[test]
+    fn test_report_groups_accounts_by_amplifier() {
+        let account = |did: &str, threat: f64, origin: Option<&str>| AccountScore {
+            did: did.to_string(),
+            handle: format!("{}.bsky.social", did.trim_start_matches("did:plc:")),
+            threat_score: Some(threat),
+            origin_amplifier_did: origin.map(str::to_string),
+            ..evidence_account(vec![])
+        };
+        let accounts = vec![
+            account("did:plc:solo", 50.0, Some("did:plc:other")),
+            account("did:plc:first", 40.0, Some("did:plc:troll")),
+            account("did:plc:second", 30.0, Some("did:plc:troll")),
+            account("did:plc:swept", 20.0, None),
+        ];
+        let events = vec![AmplificationEvent {
+            id: 1,
+            event_type: "quote".to_string(),
+            amplifier_did: "did:plc:troll".to_string(),
+            amplifier_handle: "troll.bsky.social".to_string(),
+            original_post_uri: "at://did:plc:me/app.bsky.feed.post/1".to_string(),
+            amplifier_post_uri: None,
+            amplifier_text: None,
+            detected_at: "2026-02-08 12:00:00".to_string(),
+            followers_fetched: true,
+            followers_scored: true,
+            original_post_text: None,
+            context_score: None,
+        }];
+
+        let tmp_path = "/tmp/charcoal_test_report_by_amplifier.md";
+        generate_report(
+            &accounts,
+            None,
+            &events,
+            None,
+            EvidenceOrder::Toxicity,
+            true,
+            None,
+            tmp_path,
+        )
+        .unwrap();
+        let content = std::fs::read_to_string(tmp_path).unwrap();
+        let _ = std::fs::remove_file(tmp_path);
+
+        // Largest group first, named by handle when an event has it
+        let troll = content
+            .find("### @troll.bsky.social — 2 accounts (quote)")
+            .unwrap();
+        let other = content.find("### did:plc:other — 1 account").unwrap();
+        assert!(troll < other);
+        // Ranks match the flat table, which still follows the grouping
+        assert!(content.contains("| 2 | @first.bsky.social | 40.0 |"));
+        assert!(content.contains("1 other account(s) weren't found through an amplifier"));
+        assert!(content.find("## Ranked Accounts").unwrap() > other);
+
+        // Off by default
+        generate_report(
+            &accounts,
+            None,
+            &events,
+            None,
+            EvidenceOrder::Toxicity,
+            false,
+            None,
+            tmp_path,
+        )
+        .unwrap();
+        let content = std::fs::read_to_string(tmp_path).unwrap();
+        let _ = std::fs::remove_file(tmp_path);
+        assert!(!content.contains("## Accounts by Amplifier"));
+    }
+
+    #[test]
+    fn test_report_lists_changes_since_last_report() {
+        let accounts = vec![evidence_account(vec![])];
+        let old = crate::output::diff::ReportSnapshot {
+            generated_at: "2026-02-01T09:30:00+00:00".to_string(),
+            accounts: vec![SnapshotAccount {
+                did: "did:plc:gone".to_string(),
+                handle: "gone.bsky.social".to_string(),
+                threat_score: Some(12.0),
+                threat_tier: Some("Watch".to_string()),
+            }],
+        };
+        let new = crate::output::diff::ReportSnapshot::from_accounts(&accounts);
+        let changes = crate::output::diff::diff_reports(&old, &new);
+
+        let tmp_path = "/tmp/charcoal_test_report_changes.md";
+        generate_report(
+            &accounts,
+            None,
+            &[],
+            None,
+            EvidenceOrder::Toxicity,
+            false,
+            Some(&changes),
+            tmp_path,
+        )
+        .unwrap();
+        let content = std::fs::read_to_string(tmp_path).unwrap();
+        let _ = std::fs::remove_file(tmp_path);
+
+        assert!(content.contains("## Changes since last report"));
+        assert!(content.contains("Compared with the report from 2026-02-01 09:30 UTC."));
+        assert!(content.contains("### New accounts (1)"));
+        assert!(content.contains("| @grouped.bsky.social | 40.0 | Elevated |"));
+        assert!(content.contains("### No longer listed (1)"));
+        assert!(content.contains("| @gone.bsky.social | 12.0 | Watch |"));
+        // Right after the summary
+        assert!(
+            content.find("## Summary").unwrap() < content.find("## Changes").unwrap()
+                && content.find("## Changes").unwrap() < content.find("## Ranked").unwrap()
+        );
+    }
 }
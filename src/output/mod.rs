@@ -1,7 +1,76 @@
-// Output formatting — terminal display and report generation.
+// Output formatting — terminal display, report generation (markdown, JSON
+// and CSV, into one output directory) and diffs between reports, CSV and
+// mute-list export, re-import of exported scores, command status reporting,
+// and webhook alerts.
 
+pub mod csv;
+pub mod diff;
+pub mod import;
+pub mod json;
 pub mod markdown;
+pub mod mutelist;
+pub mod reporter;
 pub mod terminal;
+pub mod webhook;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+
+use crate::db::models::{AccountScore, AmplificationEvent, ThreatTier, TierThresholds, ToxicPost};
+use crate::db::timestamps::parse_timestamp;
+
+/// Directory report files go in unless `--output-dir` says otherwise.
+pub const DEFAULT_OUTPUT_DIR: &str = "output";
+
+/// File name of every report before its extension (and timestamp).
+const REPORT_STEM: &str = "charcoal-report";
+
+/// Where one run of `charcoal report` writes its files.
+///
+/// Every format shares the directory and, when timestamped, the same
+/// timestamp, so the markdown, JSON and CSV of one run sort together. The
+/// writers create the directory if it's missing. The `--diff` snapshot is
+/// never timestamped: the next run has to find it by name.
+#[derive(Debug, Clone)]
+pub struct ReportFiles {
+    dir: PathBuf,
+    stamp: Option<String>,
+}
+
+impl ReportFiles {
+    /// Files in `dir`, named with the current time when `timestamped`.
+    pub fn new(dir: impl Into<PathBuf>, timestamped: bool) -> Self {
+        Self::at(dir, timestamped.then(Utc::now))
+    }
+
+    /// Files in `dir`, named with `time` when one is given.
+    pub fn at(dir: impl Into<PathBuf>, time: Option<DateTime<Utc>>) -> Self {
+        Self {
+            dir: dir.into(),
+            stamp: time.map(|t| t.format("%Y%m%d-%H%M%S").to_string()),
+        }
+    }
+
+    /// Path of the report file with `extension`, e.g.
+    /// `output/charcoal-report.md` or `output/charcoal-report-20261016-093000.md`.
+    pub fn path(&self, extension: &str) -> String {
+        let name = match &self.stamp {
+            Some(stamp) => format!("{REPORT_STEM}-{stamp}.{extension}"),
+            None => format!("{REPORT_STEM}.{extension}"),
+        };
+        self.dir.join(name).to_string_lossy().into_owned()
+    }
+
+    /// Path of the snapshot `report --diff` compares against.
+    pub fn snapshot_path(&self) -> String {
+        self.dir
+            .join(diff::SNAPSHOT_FILE)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
 
 /// Truncate a string to at most `max_chars` characters, appending "..." if truncated.
 ///
@@ -16,3 +85,111 @@ pub fn truncate_chars(text: &str, max_chars: usize) -> String {
         format!("{truncated}...")
     }
 }
+
+/// Why an evidence post was flagged, e.g. "flagged for identity attack", when
+/// a toxicity category rather than the top-line score set its score.
+pub fn flagged_for_label(post: &ToxicPost) -> Option<String> {
+    post.flagged_for
+        .as_deref()
+        .map(|category| format!("flagged for {}", category.replace('_', " ")))
+}
+
+/// How much evidence an account's score rests on, e.g. "evidence: weak — 4
+/// posts". `None` for rows without a stored evidence strength.
+pub fn evidence_note(score: &AccountScore) -> Option<String> {
+    score.evidence_strength.map(|strength| {
+        format!(
+            "evidence: {} — {} posts",
+            crate::scoring::evidence::evidence_label(strength),
+            score.posts_analyzed
+        )
+    })
+}
+
+/// An account's tier with its `evidence_note`, e.g. "High (evidence: weak —
+/// 4 posts)".
+pub fn tier_with_evidence(score: &AccountScore) -> String {
+    let tier = score.threat_tier.as_deref().unwrap_or("?");
+    match evidence_note(score) {
+        Some(note) => format!("{tier} ({note})"),
+        None => tier.to_string(),
+    }
+}
+
+/// Human-readable label for an events recency window ("last 30 days", or
+/// "all time" when unbounded).
+pub fn events_window_label(since_days: Option<u32>) -> String {
+    match since_days {
+        None => "all time".to_string(),
+        Some(1) => "last 1 day".to_string(),
+        Some(days) => format!("last {days} days"),
+    }
+}
+
+/// Show one handle per DID across a report's accounts and events.
+///
+/// Handle resolution can lag, so the same DID may be stored as `did:...` in
+/// one row and under a real handle in another — or under an old handle after
+/// a rename. Handles are display metadata: this rewrites every row to the
+/// most recently recorded real handle for its DID. DIDs with no real handle
+/// anywhere keep what they have.
+pub fn coalesce_handles(accounts: &mut [AccountScore], events: &mut [AmplificationEvent]) {
+    // Latest (timestamp, handle) seen per DID. Unparseable timestamps sort
+    // before everything, so any dated handle beats them.
+    let mut latest: HashMap<String, (Option<chrono::DateTime<chrono::Utc>>, String)> =
+        HashMap::new();
+    let candidates = accounts
+        .iter()
+        .map(|a| (&a.did, &a.handle, &a.scored_at))
+        .chain(
+            events
+                .iter()
+                .map(|e| (&e.amplifier_did, &e.amplifier_handle, &e.detected_at)),
+        );
+    for (did, handle, at) in candidates {
+        if handle.starts_with("did:") {
+            continue;
+        }
+        let at = parse_timestamp(at);
+        match latest.get(did.as_str()) {
+            Some((best, _)) if *best >= at => {}
+            _ => {
+                latest.insert(did.clone(), (at, handle.clone()));
+            }
+        }
+    }
+
+    for account in accounts.iter_mut() {
+        if let Some((_, handle)) = latest.get(&account.did) {
+            account.handle = handle.clone();
+        }
+    }
+    for event in events.iter_mut() {
+        if let Some((_, handle)) = latest.get(&event.amplifier_did) {
+            event.amplifier_handle = handle.clone();
+        }
+    }
+}
+
+/// Replace each account's (smoothed) threat score with its raw score from
+/// the latest scan, where one is stored, then re-tier with `thresholds`,
+/// drop accounts below `min_score`, and re-rank highest first.
+pub fn use_raw_scores(
+    accounts: &mut Vec<AccountScore>,
+    min_score: f64,
+    thresholds: &TierThresholds,
+) {
+    for account in accounts.iter_mut() {
+        if let Some(raw) = account.raw_threat_score {
+            account.threat_score = Some(raw);
+            account.threat_tier =
+                Some(ThreatTier::from_score_with_thresholds(raw, thresholds).to_string());
+        }
+    }
+    accounts.retain(|a| a.threat_score.is_some_and(|s| s >= min_score));
+    accounts.sort_by(|a, b| {
+        b.threat_score
+            .unwrap_or(0.0)
+            .total_cmp(&a.threat_score.unwrap_or(0.0))
+    });
+}
@@ -0,0 +1,83 @@
+// Mute-list export of flagged accounts.
+//
+// Charcoal is read-only — it never mutes or blocks anyone itself. Instead it
+// writes a file the user imports by hand: `app.bsky.graph.listitem` records
+// ready to add to a moderation list, or a plain array of DIDs for tools that
+// take one.
+
+use anyhow::Result;
+use chrono::Utc;
+use serde_json::{json, Value};
+
+use crate::db::models::AccountScore;
+
+/// Lexicon type of a list membership record.
+pub const LISTITEM_TYPE: &str = "app.bsky.graph.listitem";
+
+/// Shape of the exported mute list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MutelistFormat {
+    /// An array of `app.bsky.graph.listitem` records
+    ListItems,
+    /// An array of DID strings
+    Dids,
+}
+
+/// Build the mute-list document for `scores`, one entry per DID in the
+/// order given.
+///
+/// List items point at `list_uri` when one is given; without it the `list`
+/// field is left out and has to be filled in at import time, once the
+/// moderation list exists.
+pub fn build_mutelist(
+    scores: &[AccountScore],
+    format: MutelistFormat,
+    list_uri: Option<&str>,
+) -> Value {
+    let mut seen = std::collections::HashSet::new();
+    let dids = scores
+        .iter()
+        .map(|s| s.did.as_str())
+        .filter(|did| seen.insert(*did));
+
+    match format {
+        MutelistFormat::Dids => Value::from(dids.collect::<Vec<_>>()),
+        MutelistFormat::ListItems => {
+            let created_at = Utc::now().to_rfc3339();
+            let items = dids
+                .map(|did| {
+                    let mut item = json!({
+                        "$type": LISTITEM_TYPE,
+                        "subject": did,
+                        "createdAt": created_at,
+                    });
+                    if let Some(uri) = list_uri {
+                        item["list"] = Value::from(uri);
+                    }
+                    item
+                })
+                .collect();
+            Value::Array(items)
+        }
+    }
+}
+
+/// Write a mute list for `scores` to `path`.
+///
+/// Returns the path the file was written to.
+pub fn write_mutelist(
+    scores: &[AccountScore],
+    format: MutelistFormat,
+    list_uri: Option<&str>,
+    path: &str,
+) -> Result<String> {
+    let document = build_mutelist(scores, format, list_uri);
+
+    // Ensure parent directory exists, then write
+    if let Some(parent) = std::path::Path::new(path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(&document)?)?;
+
+    Ok(path.to_string())
+}
@@ -0,0 +1,167 @@
+// Status reporting for command handlers.
+//
+// Commands describe what they're doing ("scan started", "event detected")
+// through a Reporter instead of printing directly. By default that's the
+// familiar human-readable text on stdout; with `--json` each status line
+// becomes a newline-delimited JSON object on stderr, so stdout carries
+// only a command's real output.
+
+use std::fmt::Display;
+use std::io::Write;
+
+use colored::Colorize;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde_json::{json, Map, Value};
+
+/// Where command status goes — human text on stdout, or NDJSON on stderr.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Reporter {
+    json: bool,
+}
+
+impl Reporter {
+    pub fn new(json: bool) -> Self {
+        Self { json }
+    }
+
+    /// Plain-text status on stdout — the CLI's default.
+    pub fn human() -> Self {
+        Self::new(false)
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
+
+    /// Report a status line. Humans see `message`; JSON consumers see
+    /// `event` with `fields` merged into the object.
+    pub fn status(&self, event: &str, message: impl Display, fields: Value) {
+        if self.json {
+            emit(&json_line(event, fields));
+        } else {
+            println!("{message}");
+        }
+    }
+
+    /// Report a command's actual output on stdout. Humans see `message`;
+    /// JSON consumers get `event` with `fields` as one line of JSON, so
+    /// stdout stays newline-delimited JSON too.
+    pub fn result(&self, event: &str, message: impl Display, fields: Value) {
+        if self.json {
+            println!("{}", json_line(event, fields));
+        } else {
+            println!("{message}");
+        }
+    }
+
+    /// Report a recoverable problem ("warning" event in JSON mode).
+    pub fn warn(&self, message: impl Display) {
+        if self.json {
+            emit(&json_line(
+                "warning",
+                json!({ "message": message.to_string() }),
+            ));
+        } else {
+            println!("  {} {}", "Warning:".yellow(), message);
+        }
+    }
+
+    /// Report an error ("error" event in JSON mode). Human mode leaves the
+    /// error to whoever returns it, so this only writes in JSON mode.
+    pub fn error(&self, err: &anyhow::Error) {
+        if self.json {
+            emit(&json_line(
+                "error",
+                json!({ "message": format!("{err:#}") }),
+            ));
+        }
+    }
+
+    /// A progress bar drawn with `template`, hidden in JSON mode so it
+    /// can't interleave with the event stream on stderr.
+    pub fn progress_bar(&self, len: u64, template: &str) -> ProgressBar {
+        if self.json {
+            return ProgressBar::hidden();
+        }
+        let pb = ProgressBar::new(len);
+        pb.set_style(ProgressStyle::default_bar().template(template).unwrap());
+        pb
+    }
+}
+
+/// Render one status event as a single line of JSON: `event` and an RFC
+/// 3339 `timestamp`, followed by `fields` (an object; anything else is
+/// stored under `"value"`).
+pub fn json_line(event: &str, fields: Value) -> String {
+    let mut object = Map::new();
+    object.insert("event".to_string(), Value::from(event));
+    object.insert(
+        "timestamp".to_string(),
+        Value::from(chrono::Utc::now().to_rfc3339()),
+    );
+    match fields {
+        Value::Object(map) => object.extend(map),
+        Value::Null => {}
+        other => {
+            object.insert("value".to_string(), other);
+        }
+    }
+    Value::Object(object).to_string()
+}
+
+fn emit(line: &str) {
+    // A closed stderr isn't worth failing a scan over
+    let _ = writeln!(std::io::stderr().lock(), "{line}");
+}
+
+/// A tracing layer that writes each log event as a `"log"` status line, so
+/// `--json` keeps stderr to one object per line.
+pub struct JsonLogLayer;
+
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for JsonLogLayer {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+        let mut fields = Map::new();
+        fields.insert(
+            "level".to_string(),
+            Value::from(metadata.level().as_str().to_lowercase()),
+        );
+        fields.insert("target".to_string(), Value::from(metadata.target()));
+        event.record(&mut FieldVisitor(&mut fields));
+        emit(&json_line("log", Value::Object(fields)));
+    }
+}
+
+/// Collects an event's fields, keeping numbers and booleans as JSON values.
+struct FieldVisitor<'a>(&'a mut Map<String, Value>);
+
+impl tracing::field::Visit for FieldVisitor<'_> {
+    fn record_f64(&mut self, field: &tracing::field::Field, value: f64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &tracing::field::Field, value: i64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &tracing::field::Field, value: u64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &tracing::field::Field, value: bool) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), Value::from(format!("{value:?}")));
+    }
+}
@@ -5,8 +5,12 @@
 
 use colored::Colorize;
 
-use crate::db::models::{AccountScore, AmplificationEvent};
+use crate::db::models::{
+    AccountScore, AmplificationEvent, AmplifierRollup, ScoreSnapshot, ThreatTier, TierThresholds,
+};
+use crate::output::diff::{ReportDiff, ScoreChange};
 use crate::scoring::behavioral::BehavioralSignals;
+use crate::topics::overlap::{overlap_method_note, OverlapBreakdown, OverlapMethod};
 
 /// Display a ranked threat list in the terminal.
 pub fn display_threat_list(accounts: &[AccountScore]) {
@@ -78,15 +82,19 @@ pub fn display_threat_list(accounts: &[AccountScore]) {
     }
 }
 
-/// Display a single account's detailed score.
-pub fn display_account_detail(score: &AccountScore) {
+/// Display a single account's detailed score. `overlap`, when given, adds
+/// an "Overlap breakdown" section tracing the topic overlap to clusters.
+pub fn display_account_detail(score: &AccountScore, overlap: Option<&OverlapBreakdown>) {
     println!(
         "\n{}",
         format!("=== Score for @{} ===", score.handle).bold()
     );
 
     if let Some(tier) = &score.threat_tier {
-        println!("  Threat tier: {}", colorize_tier(tier));
+        match super::evidence_note(score) {
+            Some(note) => println!("  Threat tier: {} ({note})", colorize_tier(tier)),
+            None => println!("  Threat tier: {}", colorize_tier(tier)),
+        }
     }
 
     if let Some(threat) = score.threat_score {
@@ -96,12 +104,36 @@ pub fn display_account_detail(score: &AccountScore) {
         println!("  Toxicity: {:.2}", tox);
     }
     if let Some(overlap) = score.topic_overlap {
-        println!("  Topic overlap: {:.2}", overlap);
+        println!(
+            "  Topic overlap: {:.2} ({})",
+            overlap,
+            overlap_method_note(score.overlap_method.as_deref(), score.overlap_post_count)
+        );
     }
     if let Some(ref distance) = score.graph_distance {
         println!("  Graph distance: {}", distance);
     }
-    println!("  Posts analyzed: {}", score.posts_analyzed);
+    match score.replies_analyzed {
+        Some(replies) => println!(
+            "  Posts analyzed: {} ({} replies)",
+            score.posts_analyzed, replies
+        ),
+        None => println!("  Posts analyzed: {}", score.posts_analyzed),
+    }
+    if let Some(ref last_active) = score.last_active_at {
+        match crate::db::timestamps::age_in_days(last_active, chrono::Utc::now()) {
+            Some(days) => println!(
+                "  Last active: {} ({} days ago)",
+                &last_active[..last_active.len().min(10)],
+                days.max(0)
+            ),
+            None => println!("  Last active: {}", last_active),
+        }
+    }
+
+    if let Some(breakdown) = overlap {
+        display_overlap_breakdown(breakdown);
+    }
 
     if let Some(signals_json) = &score.behavioral_signals {
         if let Ok(signals) = serde_json::from_str::<BehavioralSignals>(signals_json) {
@@ -124,6 +156,49 @@ pub fn display_account_detail(score: &AccountScore) {
                 "    Pile-on: {}  |  Benign gate: {}  |  Boost: {:.2}x",
                 pile_on_str, gate_str, signals.behavioral_boost
             );
+            if signals.recently_followed_amplifier {
+                println!(
+                    "    {}",
+                    "Followed an amplifier since the previous scan".yellow()
+                );
+            }
+            if signals.stranger_quote {
+                println!("    {}", "Quoted you with no follow either way".yellow());
+            }
+            if !signals.moderation_labels.is_empty() {
+                println!(
+                    "    {} {}",
+                    "Labeled by a trusted labeler:".red(),
+                    signals.moderation_labels.join(", ")
+                );
+            }
+            if signals.posts_skipped_language > 0 {
+                println!(
+                    "    Skipped (unsupported language): {} posts",
+                    signals.posts_skipped_language
+                );
+            }
+            if let Some(window) = crate::scoring::recency::post_window_note(&signals) {
+                println!("    Post window: {}", window);
+            }
+        }
+    }
+
+    if let Some(ref peaks) = score.peak_toxicity_attributes {
+        let labeled = peaks.labeled();
+        if !labeled.is_empty() {
+            println!("\n  Peak toxicity by category:");
+            for (name, value) in labeled {
+                let bar = render_bar(value, ATTRIBUTE_BAR_WIDTH);
+                let colored_bar = if value >= 0.7 {
+                    bar.red()
+                } else if value >= 0.4 {
+                    bar.yellow()
+                } else {
+                    bar.dimmed()
+                };
+                println!("    {:<16} {} {:.2}", name, colored_bar, value);
+            }
         }
     }
 
@@ -134,18 +209,78 @@ pub fn display_account_detail(score: &AccountScore) {
         );
         for (i, post) in score.top_toxic_posts.iter().enumerate() {
             let preview = super::truncate_chars(&post.text, 120);
+            let flagged = super::flagged_for_label(post)
+                .map(|label| format!(", {label}"))
+                .unwrap_or_default();
             println!(
-                "    {}. [tox: {:.2}] {}",
+                "    {}. [tox: {:.2}{}] {}",
                 i + 1,
                 post.toxicity,
+                flagged,
                 preview.dimmed()
             );
         }
     }
 }
 
+/// Keywords shown per matched cluster in the overlap breakdown.
+const BREAKDOWN_KEYWORDS: usize = 5;
+
+/// Protected clusters named on the "strongest overlapping topics" line.
+const STRONGEST_TOPICS: usize = 3;
+
+/// The "Overlap breakdown" section of `display_account_detail`.
+fn display_overlap_breakdown(breakdown: &OverlapBreakdown) {
+    println!("\n  Overlap breakdown ({}):", breakdown.method.as_str());
+    if breakdown.method == OverlapMethod::Embedding {
+        println!(
+            "    Keyword overlap for comparison: {:.2}",
+            breakdown.keyword_overlap
+        );
+    }
+    if breakdown.clusters.is_empty() {
+        println!(
+            "    {}",
+            "No keywords shared with any of your topic clusters".dimmed()
+        );
+        return;
+    }
+    let mut strongest: Vec<&(String, f64)> = breakdown
+        .topic_contributions
+        .iter()
+        .filter(|(_, contribution)| *contribution > 0.0)
+        .collect();
+    strongest.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let total: f64 = strongest.iter().map(|(_, c)| c).sum();
+    if total > 0.0 {
+        let topics: Vec<String> = strongest
+            .iter()
+            .take(STRONGEST_TOPICS)
+            .map(|(label, c)| format!("{label} ({:.0}%)", c / total * 100.0))
+            .collect();
+        println!("    Strongest overlapping topics: {}", topics.join(", "));
+    }
+    for cluster in &breakdown.clusters {
+        let keywords: Vec<&str> = cluster
+            .shared_keywords
+            .iter()
+            .take(BREAKDOWN_KEYWORDS)
+            .map(String::as_str)
+            .collect();
+        println!(
+            "    {:<24} weight {:.2}  contributes {:.3}  {}",
+            cluster.label,
+            cluster.weight,
+            cluster.contribution,
+            keywords.join(", ").dimmed()
+        );
+    }
+}
+
 /// Display recent amplification events with quote text when available.
-pub fn display_amplification_events(events: &[AmplificationEvent]) {
+/// `since_days` is the recency window the events were selected with, shown
+/// in the header (`None` for all time).
+pub fn display_amplification_events(events: &[AmplificationEvent], since_days: Option<u32>) {
     let quotes: Vec<&AmplificationEvent> = events
         .iter()
         .filter(|e| e.event_type == "quote" && e.amplifier_text.is_some())
@@ -157,7 +292,15 @@ pub fn display_amplification_events(events: &[AmplificationEvent]) {
 
     println!(
         "\n{}",
-        format!("=== Quote Context ({} quotes with text) ===", quotes.len()).bold()
+        match since_days {
+            Some(_) => format!(
+                "=== Quote Context ({} quotes with text, {}) ===",
+                quotes.len(),
+                super::events_window_label(since_days)
+            ),
+            None => format!("=== Quote Context ({} quotes with text) ===", quotes.len()),
+        }
+        .bold()
     );
     println!();
 
@@ -169,6 +312,267 @@ pub fn display_amplification_events(events: &[AmplificationEvent]) {
     println!();
 }
 
+/// Display what changed since the previous report (`report --diff`).
+pub fn display_report_changes(diff: &ReportDiff) {
+    println!("\n{}", "=== Changes Since Last Report ===".bold());
+    println!();
+
+    if diff.is_empty() {
+        println!("  No accounts were added, removed, or changed tier.");
+        println!();
+        return;
+    }
+
+    let new_high = diff.new_high();
+    if !new_high.is_empty() {
+        let handles: Vec<String> = new_high.iter().map(|a| format!("@{}", a.handle)).collect();
+        println!("  {} {}", "New High:".red().bold(), handles.join(", "));
+    }
+    for account in &diff.added {
+        println!(
+            "  {} @{:<30} {:>6}  {}",
+            "+".green(),
+            account.handle,
+            score_text(account.threat_score),
+            account.threat_tier.as_deref().unwrap_or("—")
+        );
+    }
+    for account in &diff.removed {
+        println!(
+            "  {} @{:<30} {:>6}  {}",
+            "-".dimmed(),
+            account.handle,
+            score_text(account.threat_score),
+            account.threat_tier.as_deref().unwrap_or("—").dimmed()
+        );
+    }
+    let print_move = |change: &ScoreChange| {
+        println!(
+            "  {} @{:<30} {} → {}  ({:+.1})",
+            "~".yellow(),
+            change.account.handle,
+            change.old_tier.as_deref().unwrap_or("—"),
+            change.account.threat_tier.as_deref().unwrap_or("—"),
+            change.delta()
+        );
+    };
+    diff.tier_changes.iter().for_each(print_move);
+    diff.score_changes.iter().for_each(print_move);
+    println!();
+}
+
+fn score_text(score: Option<f64>) -> String {
+    score.map_or_else(|| "—".to_string(), |s| format!("{s:.1}"))
+}
+
+/// Display amplifiers ranked by the combined threat of their scored followers.
+/// Average and worst follower scores are tiered with `thresholds`.
+pub fn display_amplifier_rollups(rollups: &[AmplifierRollup], thresholds: &TierThresholds) {
+    if rollups.is_empty() {
+        println!("No amplification events yet. Run `charcoal scan` first.");
+        return;
+    }
+
+    println!(
+        "\n{}",
+        format!("=== Amplifiers ({} accounts) ===", rollups.len()).bold()
+    );
+    println!();
+
+    println!(
+        "  {:>4}  {:<32} {:>6}  {:>8}  {:>7}  {:<10}  {:>6}  {:<10}",
+        "Rank".dimmed(),
+        "Amplifier".dimmed(),
+        "Events".dimmed(),
+        "Scored".dimmed(),
+        "Total".dimmed(),
+        "Avg tier".dimmed(),
+        "Max".dimmed(),
+        "Max tier".dimmed(),
+    );
+    println!("  {}", "-".repeat(98).dimmed());
+
+    for (i, rollup) in rollups.iter().enumerate() {
+        // Amplifiers whose followers haven't been scored have no tier yet
+        let tier = |score: f64| {
+            if rollup.followers_scored == 0 {
+                colorize_tier("—")
+            } else {
+                colorize_tier(
+                    &ThreatTier::from_score_with_thresholds(score, thresholds).to_string(),
+                )
+            }
+        };
+
+        println!(
+            "  {:>4}. @{:<30} {:>6}  {:>8}  {:>7.1}  {:<10}  {:>6.1}  {:<10}",
+            i + 1,
+            rollup.amplifier_handle,
+            rollup.event_count,
+            rollup.followers_scored,
+            rollup.total_threat,
+            tier(rollup.avg_threat),
+            rollup.max_threat,
+            tier(rollup.max_threat),
+        );
+    }
+    println!();
+}
+
+/// Display the accounts `charcoal search` found for `query`, each with the
+/// evidence posts that mention a word from the query.
+pub fn display_evidence_matches(accounts: &[AccountScore], query: &str) {
+    if accounts.is_empty() {
+        println!("No scored accounts have evidence matching \"{query}\".");
+        return;
+    }
+
+    println!(
+        "\n{}",
+        format!(
+            "=== Evidence matching \"{query}\" ({} accounts) ===",
+            accounts.len()
+        )
+        .bold()
+    );
+
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    for account in accounts {
+        println!(
+            "\n  @{:<30} {:>6.1}  {}",
+            account.handle,
+            account.threat_score.unwrap_or(0.0),
+            colorize_tier(account.threat_tier.as_deref().unwrap_or("?")),
+        );
+
+        // The index matches stemmed words, so a hit can have no literal
+        // mention; fall back to the account's most toxic post.
+        let mut matching: Vec<_> = account
+            .top_toxic_posts
+            .iter()
+            .filter(|post| {
+                let text = post.text.to_lowercase();
+                words.iter().any(|w| text.contains(w.as_str()))
+            })
+            .collect();
+        if matching.is_empty() {
+            matching.extend(account.top_toxic_posts.first());
+        }
+        for post in matching {
+            println!(
+                "    [tox: {:.2}] {}",
+                post.toxicity,
+                super::truncate_chars(&post.text, 120).dimmed()
+            );
+        }
+    }
+    println!();
+}
+
+/// Render one account as a single plain-text line for `report --oneline`:
+/// handle, score, tier, toxicity, overlap — tab-separated, no colors.
+///
+/// Every line has exactly five fields. Numbers use a fixed precision, missing
+/// values are written as "-", and spaces in tier names become underscores so
+/// both `cut -f` and whitespace-splitting `awk` see the same columns.
+pub fn format_oneline(account: &AccountScore) -> String {
+    let fixed = |value: Option<f64>, precision: usize| {
+        value.map_or_else(|| "-".to_string(), |v| format!("{v:.precision$}"))
+    };
+    let tier = account
+        .threat_tier
+        .as_deref()
+        .map_or_else(|| "-".to_string(), |t| t.replace(' ', "_"));
+
+    format!(
+        "{}\t{}\t{}\t{}\t{}",
+        account.handle,
+        fixed(account.threat_score, 1),
+        tier,
+        fixed(account.toxicity_score, 2),
+        fixed(account.topic_overlap, 2),
+    )
+}
+
+/// Render a whole threat list in the `--oneline` format, highest score
+/// first. Unscored accounts sort last.
+pub fn format_oneline_list(accounts: &[AccountScore]) -> String {
+    let mut sorted: Vec<&AccountScore> = accounts.iter().collect();
+    sorted.sort_by(|a, b| {
+        let key = |s: &AccountScore| s.threat_score.unwrap_or(f64::NEG_INFINITY);
+        key(b).total_cmp(&key(a))
+    });
+    sorted
+        .into_iter()
+        .map(|a| format_oneline(a) + "\n")
+        .collect()
+}
+
+/// Print a threat list one account per line — see `format_oneline`.
+pub fn display_oneline(accounts: &[AccountScore]) {
+    print!("{}", format_oneline_list(accounts));
+}
+
+/// Width in characters of the per-category toxicity bars.
+const ATTRIBUTE_BAR_WIDTH: usize = 10;
+
+/// Render a 0.0–1.0 value as a fixed-width bar of block characters, padded
+/// with spaces so bars line up in a column ("████      " for 0.4 at width 10).
+/// Values outside the range are clamped.
+pub fn render_bar(value: f64, width: usize) -> String {
+    let filled = (value.clamp(0.0, 1.0) * width as f64).round() as usize;
+    format!("{}{}", "█".repeat(filled), " ".repeat(width - filled))
+}
+
+/// Score snapshots `charcoal score` shows under an account's detail.
+pub const SCORE_HISTORY_SHOWN: u32 = 10;
+
+/// Display an account's past threat scores, oldest first, as a sparkline
+/// plus one line per snapshot. Prints nothing for an empty history.
+pub fn display_score_history(history: &[ScoreSnapshot]) {
+    if history.is_empty() {
+        return;
+    }
+    let scores: Vec<f64> = history
+        .iter()
+        .map(|s| s.threat_score.unwrap_or(0.0))
+        .collect();
+    println!(
+        "\n  Score history (last {}): {}",
+        history.len(),
+        sparkline(&scores, 100.0)
+    );
+    for snapshot in history {
+        let toxicity = snapshot
+            .toxicity_score
+            .map(|t| format!("  tox {t:.2}"))
+            .unwrap_or_default();
+        println!(
+            "    {}  {:>5.1}{}",
+            &snapshot.scored_at[..snapshot.scored_at.len().min(10)],
+            snapshot.threat_score.unwrap_or(0.0),
+            toxicity.dimmed()
+        );
+    }
+}
+
+/// One block character per value, from "▁" at 0 to "█" at `max`. Values
+/// outside 0..=max are clamped.
+pub fn sparkline(values: &[f64], max: f64) -> String {
+    const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    values
+        .iter()
+        .map(|v| {
+            let fraction = if max > 0.0 {
+                (v / max).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            LEVELS[(fraction * (LEVELS.len() - 1) as f64).round() as usize]
+        })
+        .collect()
+}
+
 /// Colorize a threat tier string.
 fn colorize_tier(tier: &str) -> colored::ColoredString {
     match tier {
@@ -0,0 +1,62 @@
+// Webhook alerts for accounts newly reaching the High tier.
+//
+// With CHARCOAL_ALERT_WEBHOOK set, the scoring pipelines post one JSON
+// message per account that crosses into High (see `ScoreWriter`). The
+// message goes out as both `text` (Slack incoming webhooks) and `content`
+// (Discord), alongside the account's details for any other receiver.
+// Alerts are best-effort: callers log a failed post and carry on.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::db::models::{AccountScore, ThreatTier};
+
+/// How long a webhook has to answer before the alert is given up on.
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// True when `score` is High tier and `prior` — the account's stored score
+/// before this one, if any — wasn't, so an account that stays High doesn't
+/// alert on every rescan.
+pub fn is_new_high_tier(score: &AccountScore, prior: Option<&AccountScore>) -> bool {
+    let is_high = |s: &AccountScore| s.threat_tier.as_deref() == Some(ThreatTier::High.as_str());
+    is_high(score) && !prior.is_some_and(is_high)
+}
+
+/// The JSON body posted for `account`.
+pub fn alert_payload(account: &AccountScore) -> serde_json::Value {
+    let message = format!(
+        "New High-tier account: @{} (threat score {:.1}) — https://bsky.app/profile/{}",
+        account.handle,
+        account.threat_score.unwrap_or(0.0),
+        account.did
+    );
+    serde_json::json!({
+        "text": message,
+        "content": message,
+        "did": account.did,
+        "handle": account.handle,
+        "threat_score": account.threat_score,
+        "threat_tier": account.threat_tier,
+        "toxicity_score": account.toxicity_score,
+        "topic_overlap": account.topic_overlap,
+    })
+}
+
+/// Post an alert for `account` to the webhook at `url`. Errors on a failed
+/// request or a non-success status.
+pub async fn post_alert(url: &str, account: &AccountScore) -> Result<()> {
+    let client = crate::http::client_builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .context("Failed to build webhook client")?;
+    client
+        .post(url)
+        .json(&alert_payload(account))
+        .send()
+        .await
+        .context("Alert webhook request failed")?
+        .error_for_status()
+        .context("Alert webhook rejected the alert")?;
+    Ok(())
+}
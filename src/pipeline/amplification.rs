@@ -11,31 +11,338 @@
 use anyhow::Result;
 use futures::stream::{self, StreamExt};
 use futures::FutureExt;
-use indicatif::{ProgressBar, ProgressStyle};
+use serde_json::json;
 use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 use tracing::{info, warn};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 use crate::bluesky::amplification::AmplificationNotification;
 use crate::bluesky::client::PublicAtpClient;
 use crate::bluesky::followers;
 use crate::bluesky::posts;
+use crate::bluesky::profiles;
 use crate::bluesky::relationships::GraphDistance;
+use crate::config::{Config, IgnoreList};
+use crate::db::models::ScoreSmoothing;
+use crate::db::pool::DEFAULT_SCAN_CONCURRENCY;
 use crate::db::Database;
+use crate::output::reporter::Reporter;
+use crate::pipeline::score_writer::ScoreWriter;
+use crate::scoring::behavioral;
 use crate::scoring::nli::NliScorer;
-use crate::scoring::profile;
+use crate::scoring::profile::{self, ScoringOptions};
 use crate::scoring::threat::ThreatWeights;
 use crate::topics::embeddings::SentenceEmbedder;
 use crate::topics::fingerprint::TopicFingerprint;
+use crate::topics::overlap::PreparedFingerprint;
 use crate::toxicity::traits::ToxicityScorer;
 
+/// Prefix for the per-amplifier follower offset keys in `scan_state`.
+/// The full key is `follower_offset:<amplifier DID>`.
+pub const FOLLOWER_OFFSET_KEY_PREFIX: &str = "follower_offset:";
+
+/// Forget every stored follower offset so the next scan starts at the top of
+/// each amplifier's follower list again. Returns how many offsets were cleared.
+///
+/// Offsets are cleared by writing an empty value rather than deleting the
+/// row — an empty offset already means "start from the beginning".
+pub async fn reset_follower_offsets(db: &Arc<dyn Database>, user_did: &str) -> Result<usize> {
+    let mut cleared = 0;
+    for (key, value) in db.get_all_scan_state(user_did).await? {
+        if key.starts_with(FOLLOWER_OFFSET_KEY_PREFIX) && !value.is_empty() {
+            db.set_scan_state(user_did, &key, "").await?;
+            cleared += 1;
+        }
+    }
+    Ok(cleared)
+}
+
+/// Followers fetched for one amplifier event, plus where the amplifier's
+/// rotating offset should move once they've been scored.
+struct FollowerSlice {
+    followers: Vec<followers::Follower>,
+    /// `Some` only when rotating; an empty cursor wraps the next scan back
+    /// to the top of the list.
+    next_cursor: Option<String>,
+}
+
+/// Fetch the next slice of an amplifier's followers.
+///
+/// With `rotate` on, each scan picks up where the previous one stopped for
+/// this amplifier, and wraps back to the top once the end of the list is
+/// reached. For an amplifier with 10k followers and a cap of 50, successive
+/// scans eventually cover everyone instead of re-checking the same 50.
+///
+/// The stored offset isn't moved here: the caller saves `next_cursor` with
+/// `save_follower_offset` once the slice is scored, so an interrupted scan
+/// fetches the same slice again.
+///
+/// The tradeoff: Bluesky returns followers newest-first, so while we're
+/// working through an older slice, brand-new followers won't be looked at
+/// until the rotation wraps around. That's breadth over freshness — the right
+/// call for large amplifiers, where the first page is a tiny sample anyway.
+async fn fetch_follower_slice(
+    client: &PublicAtpClient,
+    db: &Arc<dyn Database>,
+    user_did: &str,
+    event: &AmplificationNotification,
+    max_followers: usize,
+    rotate: bool,
+    reporter: &Reporter,
+) -> Result<FollowerSlice> {
+    if !rotate {
+        let followers =
+            followers::fetch_followers(client, &event.amplifier_handle, max_followers).await?;
+        return Ok(FollowerSlice {
+            followers,
+            next_cursor: None,
+        });
+    }
+
+    let start = db
+        .get_scan_state(user_did, &follower_offset_key(&event.amplifier_did))
+        .await?
+        .filter(|c| !c.is_empty());
+
+    let mut window = followers::fetch_followers_window(
+        client,
+        &event.amplifier_handle,
+        start.as_deref(),
+        max_followers,
+    )
+    .await?;
+
+    // A stored cursor can go stale (or land exactly at the end of the list).
+    // Rather than scoring nobody this run, wrap to the top right away.
+    if window.followers.is_empty() && start.is_some() {
+        window =
+            followers::fetch_followers_window(client, &event.amplifier_handle, None, max_followers)
+                .await?;
+    }
+
+    if start.is_some() {
+        reporter.status(
+            "follower_offset_resumed",
+            "  Continuing from saved follower offset",
+            json!({ "amplifier_handle": event.amplifier_handle }),
+        );
+    }
+
+    Ok(FollowerSlice {
+        followers: window.followers,
+        next_cursor: Some(window.next_cursor.unwrap_or_default()),
+    })
+}
+
+fn follower_offset_key(amplifier_did: &str) -> String {
+    format!("{FOLLOWER_OFFSET_KEY_PREFIX}{amplifier_did}")
+}
+
+/// Move an amplifier's rotating follower offset to `next_cursor`.
+async fn save_follower_offset(
+    db: &Arc<dyn Database>,
+    user_did: &str,
+    amplifier_did: &str,
+    next_cursor: &str,
+) -> Result<()> {
+    db.set_scan_state(user_did, &follower_offset_key(amplifier_did), next_cursor)
+        .await
+}
+
+/// Follower-analysis events still to be scored per amplifier in one scan.
+///
+/// Every event from the same amplifier reads the same stored offset, so the
+/// offset may only move once the last of them is scored — once per
+/// amplifier per scan. An event that's skipped or fails keeps its
+/// amplifier's offset where it was.
+#[derive(Debug, Default)]
+pub struct PendingSlices {
+    remaining: HashMap<String, usize>,
+}
+
+impl PendingSlices {
+    /// Count one pending event per entry in `amplifier_dids`.
+    pub fn new<'a>(amplifier_dids: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut remaining = HashMap::new();
+        for did in amplifier_dids {
+            *remaining.entry(did.to_string()).or_insert(0) += 1;
+        }
+        Self { remaining }
+    }
+
+    /// Record that one of `amplifier_did`'s events has been scored. Returns
+    /// true when that was its last pending event, i.e. when its offset
+    /// should be saved now; never true twice for the same amplifier.
+    pub fn settle(&mut self, amplifier_did: &str) -> bool {
+        let Some(count) = self.remaining.get_mut(amplifier_did) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.remaining.remove(amplifier_did);
+            return true;
+        }
+        false
+    }
+}
+
+/// Compare this fetch of an amplifier's followers against the stored
+/// snapshot, then store the merged snapshot. Returns the DIDs of followers
+/// that appeared since the last scan.
+///
+/// Snapshot failures are logged and treated as "no new followers" — the
+/// signal is an extra, not a reason to abandon the scan.
+async fn diff_follower_snapshot(
+    db: &Arc<dyn Database>,
+    user_did: &str,
+    amplifier_did: &str,
+    follower_list: &[followers::Follower],
+) -> HashSet<String> {
+    let previous = match db.get_follower_snapshot(user_did, amplifier_did).await {
+        Ok(previous) => previous.unwrap_or_default(),
+        Err(e) => {
+            warn!(amplifier = amplifier_did, error = %e, "Failed to load follower snapshot");
+            return HashSet::new();
+        }
+    };
+
+    let dids: Vec<&str> = follower_list.iter().map(|f| f.did.as_str()).collect();
+    let known: HashSet<String> = previous.iter().cloned().collect();
+    let new_followers = behavioral::detect_new_followers(&dids, &known);
+
+    let merged = behavioral::merge_follower_snapshot(&dids, &previous);
+    if let Err(e) = db
+        .save_follower_snapshot(user_did, amplifier_did, &merged)
+        .await
+    {
+        warn!(amplifier = amplifier_did, error = %e, "Failed to save follower snapshot");
+    }
+
+    if !new_followers.is_empty() {
+        info!(
+            amplifier = amplifier_did,
+            count = new_followers.len(),
+            "New followers since last scan"
+        );
+    }
+    new_followers
+}
+
+/// True when an account is the protected user themselves.
+///
+/// Matches on DID first — it survives handle changes and case differences.
+/// The case-insensitive handle comparison only catches records that carry
+/// a handle placeholder instead of a real DID.
+pub fn is_protected_account(
+    did: &str,
+    handle: &str,
+    protected_did: &str,
+    protected_handle: &str,
+) -> bool {
+    did == protected_did || handle.eq_ignore_ascii_case(protected_handle)
+}
+
+/// True when an amplifier has more followers than `skip_above` allows, so
+/// follower analysis should be skipped. An unknown count, or no threshold,
+/// never skips.
+///
+/// A mass account's followers mostly follow it for reasons unrelated to the
+/// protected user, so scoring a slice of them costs rate limit and model
+/// time for little signal.
+pub fn is_mass_amplifier(follower_count: Option<u64>, skip_above: Option<u64>) -> bool {
+    matches!((follower_count, skip_above), (Some(count), Some(limit)) if count > limit)
+}
+
+/// Per-scan settings for `run`, built once from `Config` and the command's
+/// flags. Start from `ScanOptions::from_config` and override what the
+/// command sets.
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    /// Score the followers of quote/reply amplifiers, not only record events.
+    pub analyze_followers: bool,
+    /// Cap on followers fetched per amplifier.
+    pub max_followers_per_amplifier: usize,
+    /// Skip follower analysis for amplifiers with more followers than this
+    /// (see `is_mass_amplifier`).
+    pub skip_followers_above: Option<u64>,
+    /// Work through each amplifier's follower list across scans instead of
+    /// always taking the newest slice (see `fetch_follower_slice`).
+    pub rotate_follower_offset: bool,
+    /// Skip events whose followers an earlier scan already scored.
+    pub resume: bool,
+    /// Accounts scored in parallel.
+    pub concurrency: usize,
+    /// Amplifiers whose events are dropped before anything is recorded.
+    pub ignored_amplifiers: IgnoreList,
+    /// Where NLI audit entries are logged; `None` logs nothing.
+    pub data_dir: Option<PathBuf>,
+    /// Scores written per batch (see `ScoreWriter`).
+    pub score_batch_size: usize,
+    /// How rescored accounts are smoothed against their stored score.
+    pub score_smoothing: Option<ScoreSmoothing>,
+    /// Where accounts newly reaching High are posted (see `output::webhook`).
+    pub alert_webhook: Option<String>,
+    /// What the run is recorded as in scan_runs. Only "scan" runs update
+    /// `last_scan_at`; `charcoal stream` records its batches as "stream".
+    pub run_kind: &'static str,
+}
+
+impl ScanOptions {
+    /// The CLI's scan defaults — events recorded but followers not analyzed,
+    /// 50 followers per amplifier with rotating offsets,
+    /// `DEFAULT_SCAN_CONCURRENCY` workers — with the
+    /// ignore list, data directory, batching, smoothing and webhook taken
+    /// from `config`.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            analyze_followers: false,
+            max_followers_per_amplifier: 50,
+            skip_followers_above: None,
+            rotate_follower_offset: true,
+            resume: false,
+            concurrency: DEFAULT_SCAN_CONCURRENCY,
+            ignored_amplifiers: config.ignored_amplifiers.clone(),
+            data_dir: Some(config.data_dir().to_path_buf()),
+            score_batch_size: config.score_batch_size,
+            score_smoothing: config.score_smoothing(),
+            alert_webhook: config.alert_webhook.clone(),
+            run_kind: "scan",
+        }
+    }
+}
+
 /// Run the amplification detection pipeline.
 ///
 /// Processes pre-fetched amplification events (from Constellation backlinks),
 /// fetches amplifier followers, and scores them. Returns the number of events
 /// processed and accounts scored.
+///
+/// Each event is marked once its follower batch has been scored and written.
+/// With `options.resume` on, events whose amplifier post was already marked by an
+/// earlier (possibly interrupted) scan skip follower analysis. A rotating
+/// follower offset only moves after its amplifier's events are marked (see
+/// `PendingSlices`), so the events that weren't get the same follower slice
+/// the interrupted scan was working on.
+///
+/// Events from `options.ignored_amplifiers` are dropped up front: they're
+/// neither recorded nor counted, and their followers aren't scored.
+/// Amplifiers with more than `options.skip_followers_above` followers (see
+/// `is_mass_amplifier`) have their events recorded but their followers left
+/// alone.
+///
+/// Progress goes through `reporter`, which emits an `event_detected` status
+/// for every recorded event.
+///
+/// Accounts newly scored into the High tier are posted to
+/// `options.alert_webhook` when set (see `output::webhook`).
+///
+/// Amplifiers are scored, and their followers analyzed, only with
+/// `options.analyze_followers`; otherwise events are just recorded.
+///
+/// A finished run is recorded in scan_runs as `options.run_kind`.
 #[allow(clippy::too_many_arguments)]
 pub async fn run(
     client: &PublicAtpClient,
@@ -44,10 +351,9 @@ pub async fn run(
     user_did: &str,
     protected_fingerprint: &TopicFingerprint,
     weights: &ThreatWeights,
+    scoring_options: &ScoringOptions,
     protected_handle: &str,
-    analyze_followers: bool,
-    max_followers_per_amplifier: usize,
-    concurrency: usize,
+    options: &ScanOptions,
     embedder: Option<&SentenceEmbedder>,
     protected_embedding: Option<&[f64]>,
     events: Vec<AmplificationNotification>,
@@ -56,27 +362,103 @@ pub async fn run(
     original_text_cache: &std::collections::HashMap<String, String>,
     nli_scorer: Option<&NliScorer>,
     protected_posts_with_embeddings: Option<&[(String, Vec<f64>)]>,
-    data_dir: Option<&std::path::Path>,
     graph_distances: &HashMap<String, GraphDistance>,
+    reporter: &Reporter,
 ) -> Result<(usize, usize)> {
+    let started_at = super::run_timestamp();
+    let ScanOptions {
+        analyze_followers,
+        max_followers_per_amplifier,
+        skip_followers_above,
+        rotate_follower_offset,
+        resume,
+        concurrency,
+        ref ignored_amplifiers,
+        ref data_dir,
+        score_batch_size,
+        score_smoothing,
+        ref alert_webhook,
+        run_kind,
+    } = *options;
+    let data_dir = data_dir.as_deref();
+    let alert_webhook = alert_webhook.as_deref();
+
+    // Ignored amplifiers are dropped before anything is recorded or scored
+    let total_events = events.len();
+    let events: Vec<AmplificationNotification> = events
+        .into_iter()
+        .filter(|e| !ignored_amplifiers.matches(&e.amplifier_did, &e.amplifier_handle))
+        .collect();
+    if events.len() < total_events {
+        info!(
+            ignored = total_events - events.len(),
+            "Dropped events from ignored amplifiers"
+        );
+    }
     info!(
         total_events = events.len(),
         "Processing amplification events"
     );
 
+    // The protected side of every overlap comparison is the same — compute
+    // its keyword weights once for the whole scan.
+    let prepared_fingerprint = &PreparedFingerprint::new(protected_fingerprint);
+
+    // Whitelisted accounts are trusted: their events are still recorded, but
+    // no inference runs on their posts and they're never scored
+    let whitelist: HashSet<String> = db
+        .get_whitelist(user_did)
+        .await?
+        .into_iter()
+        .map(|entry| entry.did)
+        .collect();
+
+    // Amplifiers are scored with their direct pairs and a relationship
+    // lookup; followers get a fast NLI-free pass first (see Phase 2 below)
+    let amplifier_ctx = profile::ScoringContext {
+        client,
+        scorer,
+        protected_fingerprint: prepared_fingerprint,
+        weights,
+        options: scoring_options,
+        embedder,
+        protected_embedding,
+        median_engagement,
+        pile_on_dids,
+        whitelist: &whitelist,
+        nli_scorer,
+        protected_posts_with_embeddings: None, // No inferred pairs — using direct pairs
+        protected_did: Some(user_did),
+        data_dir,
+        min_posts: profile::DEFAULT_MIN_POSTS,
+    };
+
+    let already_scored: HashSet<String> = if resume {
+        db.get_followers_scored_post_uris(user_did)
+            .await?
+            .into_iter()
+            .collect()
+    } else {
+        HashSet::new()
+    };
+
     // Record the scan timestamp
-    db.set_scan_state(
-        user_did,
-        "last_scan_at",
-        &chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
-    )
-    .await?;
+    if run_kind == "scan" {
+        db.set_scan_state(
+            user_did,
+            "last_scan_at",
+            &chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        )
+        .await?;
+    }
 
     // Store each event in the database, fetching quote text when available.
     // Look up original post text from the cache for all event types.
+    let mut event_ids = Vec::with_capacity(events.len());
     for event in &events {
         let mut amplifier_text: Option<String> = None;
         let mut quote_toxicity: Option<f64> = None;
+        let trusted = whitelist.contains(&event.amplifier_did);
 
         // Look up the original (protected user's) post text from the cache
         // (resolved before scoring so the ensemble scorer can use it as context)
@@ -90,12 +472,14 @@ pub async fn run(
         if (event.event_type == "quote" || event.event_type == "reply") && analyze_followers {
             match posts::fetch_post_text(client, &event.amplifier_post_uri).await {
                 Ok(Some(text)) => {
-                    match scorer.score_with_context(&text, original_post_text).await {
-                        Ok(result) => {
-                            quote_toxicity = Some(result.toxicity);
-                        }
-                        Err(e) => {
-                            warn!(error = %e, "Failed to score amplifier text");
+                    if !trusted {
+                        match scorer.score_with_context(&text, original_post_text).await {
+                            Ok(result) => {
+                                quote_toxicity = Some(result.toxicity);
+                            }
+                            Err(e) => {
+                                warn!(error = %e, "Failed to score amplifier text");
+                            }
                         }
                     }
                     amplifier_text = Some(text);
@@ -113,7 +497,11 @@ pub async fn run(
         }
 
         // Score the interaction pair via NLI when both texts are available
-        let context_score = match (nli_scorer, amplifier_text.as_deref(), original_post_text) {
+        let context_score = match (
+            nli_scorer.filter(|_| !trusted),
+            amplifier_text.as_deref(),
+            original_post_text,
+        ) {
             (Some(nli), Some(amp_text), Some(orig_text)) => {
                 match nli.score_pair(orig_text, amp_text).await {
                     Ok((score, hypothesis_scores)) => {
@@ -149,18 +537,20 @@ pub async fn run(
             _ => None,
         };
 
-        db.insert_amplification_event(
-            user_did,
-            &event.event_type,
-            &event.amplifier_did,
-            &event.amplifier_handle,
-            event.original_post_uri.as_deref().unwrap_or("unknown"),
-            Some(&event.amplifier_post_uri),
-            amplifier_text.as_deref(),
-            original_post_text,
-            context_score,
-        )
-        .await?;
+        let event_id = db
+            .insert_amplification_event(
+                user_did,
+                &event.event_type,
+                &event.amplifier_did,
+                &event.amplifier_handle,
+                event.original_post_uri.as_deref().unwrap_or("unknown"),
+                Some(&event.amplifier_post_uri),
+                amplifier_text.as_deref(),
+                original_post_text,
+                context_score,
+            )
+            .await?;
+        event_ids.push(event_id);
 
         let event_label = match event.event_type.as_str() {
             "quote" => "Quote",
@@ -169,7 +559,7 @@ pub async fn run(
             "reply" => "Reply",
             other => other,
         };
-        println!(
+        let mut message = format!(
             "  {} by @{} ({})",
             event_label, event.amplifier_handle, event.indexed_at,
         );
@@ -178,8 +568,20 @@ pub async fn run(
             let tox_str = quote_toxicity
                 .map(|t| format!(" [tox: {:.2}]", t))
                 .unwrap_or_default();
-            println!("    \"{}\"{}", preview, tox_str);
+            message.push_str(&format!("\n    \"{}\"{}", preview, tox_str));
         }
+        reporter.status(
+            "event_detected",
+            message,
+            json!({
+                "event_type": event.event_type,
+                "amplifier_did": event.amplifier_did,
+                "amplifier_handle": event.amplifier_handle,
+                "post_uri": event.amplifier_post_uri,
+                "indexed_at": event.indexed_at,
+                "toxicity": quote_toxicity,
+            }),
+        );
     }
 
     // Phase B: Score amplifiers via build_profile() with direct NLI pairs.
@@ -187,8 +589,11 @@ pub async fn run(
     // Collect unique amplifier DIDs and their text pairs from stored events,
     // then run full profile builds. This gives each amplifier a threat tier
     // informed by their actual interactions with the protected user.
-    let mut accounts_scored = 0;
-    {
+    // Without --analyze there is no scorer to build profiles with, so only
+    // the events themselves are recorded.
+    let mut writer = ScoreWriter::new(db.as_ref(), user_did, score_batch_size, score_smoothing)
+        .with_alert_webhook(alert_webhook);
+    if analyze_followers {
         let mut amplifier_handles: std::collections::HashMap<String, String> =
             std::collections::HashMap::new();
 
@@ -200,10 +605,16 @@ pub async fn run(
 
         let amplifier_count = amplifier_handles.len();
         if amplifier_count > 0 {
-            println!("\nScoring {} amplifiers…", amplifier_count);
+            reporter.status(
+                "amplifiers_scoring",
+                format!("\nScoring {} amplifiers…", amplifier_count),
+                json!({ "count": amplifier_count }),
+            );
 
             for (did, handle) in &amplifier_handles {
-                if handle == protected_handle {
+                if is_protected_account(did, handle, user_did, protected_handle)
+                    || whitelist.contains(did)
+                {
                     continue;
                 }
                 if !db.is_score_stale(user_did, did, 7).await.unwrap_or(true) {
@@ -230,39 +641,41 @@ pub async fn run(
                 }
 
                 match profile::build_profile(
-                    client,
-                    scorer,
+                    &amplifier_ctx,
                     handle,
                     did,
-                    protected_fingerprint,
-                    weights,
-                    embedder,
-                    protected_embedding,
-                    median_engagement,
-                    pile_on_dids,
-                    nli_scorer,
-                    None, // No inferred pairs — using direct pairs
                     Some(&pairs),
-                    data_dir,
                     graph_distances.get(did).copied(),
                 )
                 .await
                 {
                     Ok(score) => {
-                        db.upsert_account_score(user_did, &score).await?;
-                        accounts_scored += 1;
-                        println!(
-                            "  @{}: {} (context: {})",
-                            handle,
-                            score.threat_tier.as_deref().unwrap_or("?"),
-                            score
-                                .context_score
-                                .map(|s| format!("{:.2}", s))
-                                .unwrap_or_else(|| "n/a".to_string())
+                        reporter.status(
+                            "amplifier_scored",
+                            format!(
+                                "  @{}: {} (context: {})",
+                                handle,
+                                score.threat_tier.as_deref().unwrap_or("?"),
+                                score
+                                    .context_score
+                                    .map(|s| format!("{:.2}", s))
+                                    .unwrap_or_else(|| "n/a".to_string())
+                            ),
+                            json!({
+                                "did": did,
+                                "handle": handle,
+                                "threat_score": score.threat_score,
+                                "threat_tier": score.threat_tier,
+                                "context_score": score.context_score,
+                            }),
                         );
+                        writer.push(score).await?;
                     }
                     Err(e) => {
                         warn!(handle = handle.as_str(), error = %e, "Failed to score amplifier");
+                        reporter.error(
+                            &anyhow::Error::from(e).context(format!("failed to score @{handle}")),
+                        );
                     }
                 }
             }
@@ -277,7 +690,8 @@ pub async fn run(
     if analyze_followers && !events.is_empty() {
         let scorable_events: Vec<_> = events
             .iter()
-            .filter(|e| e.event_type == "quote" || e.event_type == "reply")
+            .zip(event_ids.iter().copied())
+            .filter(|(e, _)| e.event_type == "quote" || e.event_type == "reply")
             .collect();
         let skipped_count = events.len() - scorable_events.len();
 
@@ -286,9 +700,13 @@ pub async fn run(
                 skipped = skipped_count,
                 "Skipping follower analysis for reposts/likes"
             );
-            println!(
-                "  Skipping {} reposts/likes (follower analysis is quote/reply-only)",
-                skipped_count
+            reporter.status(
+                "follower_analysis_skipped",
+                format!(
+                    "  Skipping {} reposts/likes (follower analysis is quote/reply-only)",
+                    skipped_count
+                ),
+                json!({ "skipped": skipped_count }),
             );
         }
 
@@ -296,25 +714,122 @@ pub async fn run(
             info!("No quote/reply events to analyze");
         }
 
-        for event in &scorable_events {
-            println!("\nFetching followers of @{}...", event.amplifier_handle);
+        let mut pending_slices = PendingSlices::new(
+            scorable_events
+                .iter()
+                .filter(|(e, _)| !already_scored.contains(&e.amplifier_post_uri))
+                .map(|(e, _)| e.amplifier_did.as_str()),
+        );
+
+        let mut resumed = 0;
+        for &(event, event_id) in &scorable_events {
+            if already_scored.contains(&event.amplifier_post_uri) {
+                resumed += 1;
+                continue;
+            }
+
+            if skip_followers_above.is_some() {
+                match profiles::fetch_follower_count(client, &event.amplifier_did).await {
+                    Ok(count) if is_mass_amplifier(count, skip_followers_above) => {
+                        let count = count.unwrap_or_default();
+                        info!(
+                            amplifier = event.amplifier_handle.as_str(),
+                            followers = count,
+                            "Skipping follower analysis for mass amplifier"
+                        );
+                        reporter.status(
+                            "followers_skipped_mass_amplifier",
+                            format!(
+                                "\nSkipping followers of @{}: {count} followers is above \
+                                 --skip-amplifier-followers-above (event still recorded)",
+                                event.amplifier_handle
+                            ),
+                            json!({
+                                "amplifier_handle": event.amplifier_handle,
+                                "followers": count,
+                                "threshold": skip_followers_above,
+                            }),
+                        );
+                        continue;
+                    }
+                    Ok(_) => {}
+                    // Without a count, analyze as usual rather than guess
+                    Err(e) => warn!(
+                        amplifier = event.amplifier_handle.as_str(),
+                        error = %e,
+                        "Could not look up amplifier follower count"
+                    ),
+                }
+            }
+
+            reporter.status(
+                "followers_fetching",
+                format!("\nFetching followers of @{}...", event.amplifier_handle),
+                json!({ "amplifier_handle": event.amplifier_handle }),
+            );
 
-            match followers::fetch_followers(
+            match fetch_follower_slice(
                 client,
-                &event.amplifier_handle,
+                db,
+                user_did,
+                event,
                 max_followers_per_amplifier,
+                rotate_follower_offset,
+                reporter,
             )
             .await
             {
-                Ok(follower_list) => {
+                Ok(slice) => {
+                    let follower_list = slice.followers;
+                    let new_followers =
+                        diff_follower_snapshot(db, user_did, &event.amplifier_did, &follower_list)
+                            .await;
+                    if !new_followers.is_empty() {
+                        reporter.status(
+                            "new_followers",
+                            format!(
+                                "  {} new followers since the last scan",
+                                new_followers.len()
+                            ),
+                            json!({
+                                "amplifier_handle": event.amplifier_handle,
+                                "count": new_followers.len(),
+                            }),
+                        );
+                    }
+
+                    // Write out everything scored so far first, so the staleness
+                    // check below sees it and doesn't rescore those accounts
+                    writer.flush().await?;
+
                     // Phase 1: Filter — find followers with stale scores (DB reads on main task)
-                    // Also exclude the protected user from their own threat report
-                    let mut stale_followers = Vec::new();
-                    for f in follower_list
+                    // Also exclude the protected user from their own threat report,
+                    // and whitelisted accounts. New followers are always rescored so
+                    // they carry the flag.
+                    let candidates: Vec<&followers::Follower> = follower_list
                         .iter()
-                        .filter(|f| f.handle != protected_handle)
+                        .filter(|f| {
+                            !is_protected_account(&f.did, &f.handle, user_did, protected_handle)
+                                && !whitelist.contains(&f.did)
+                        })
+                        .collect();
+
+                    // Link every candidate to this amplifier for `charcoal amplifiers`,
+                    // including fresh ones that won't be rescored this time
+                    let candidate_dids: Vec<String> =
+                        candidates.iter().map(|f| f.did.clone()).collect();
+                    if let Err(e) = db
+                        .record_amplifier_followers(user_did, &event.amplifier_did, &candidate_dids)
+                        .await
                     {
-                        if db.is_score_stale(user_did, &f.did, 7).await.unwrap_or(true) {
+                        warn!(error = %e, "Failed to record amplifier followers");
+                    }
+
+                    let mut stale_followers = Vec::new();
+                    for f in candidates {
+                        if new_followers.contains(&f.did)
+                            || db.is_score_stale(user_did, &f.did, 7).await.unwrap_or(true)
+                        {
                             // Clone to produce an owned Vec<Follower> — required for
                             // the async move closure in the scoring stream to be
                             // 'static-compatible when called from tokio::spawn.
@@ -322,62 +837,78 @@ pub async fn run(
                         }
                     }
 
-                    println!(
-                        "  Found {} followers, {} need scoring ({} concurrent)...",
-                        follower_list.len(),
-                        stale_followers.len(),
-                        concurrency,
+                    reporter.status(
+                        "followers_found",
+                        format!(
+                            "  Found {} followers, {} need scoring ({} concurrent)...",
+                            follower_list.len(),
+                            stale_followers.len(),
+                            concurrency,
+                        ),
+                        json!({
+                            "amplifier_handle": event.amplifier_handle,
+                            "followers": follower_list.len(),
+                            "to_score": stale_followers.len(),
+                        }),
                     );
 
                     if stale_followers.is_empty() {
+                        db.mark_event_followers_scored(event_id).await?;
+                        if let Some(next) = slice.next_cursor.as_deref() {
+                            if pending_slices.settle(&event.amplifier_did) {
+                                save_follower_offset(db, user_did, &event.amplifier_did, next)
+                                    .await?;
+                            }
+                        }
                         continue;
                     }
 
-                    let pb = ProgressBar::new(stale_followers.len() as u64);
-                    pb.set_style(
-                        ProgressStyle::default_bar()
-                            .template("  Scoring [{bar:30}] {pos}/{len} ({eta})")
-                            .unwrap(),
+                    let pb = reporter.progress_bar(
+                        stale_followers.len() as u64,
+                        "  Scoring [{bar:30}] {pos}/{len} ({eta})",
                     );
 
                     // Phase 2: Two-pass scoring in parallel
-                    // Pass 1: score without NLI (fast). If raw_score >= 8.0 (Watch threshold),
-                    // pass 2 re-scores with NLI inferred pairs. Falls back to pass 1 on panic.
-                    let nli_ref = nli_scorer;
-                    let ppwe_ref = protected_posts_with_embeddings;
+                    // Pass 1: score without NLI (fast). If raw_score reaches the Watch
+                    // threshold, pass 2 re-scores with NLI inferred pairs. Falls back to
+                    // pass 1 on panic.
+                    let pass1_ctx = profile::ScoringContext {
+                        nli_scorer: None,                      // No NLI in pass 1
+                        protected_posts_with_embeddings: None, // No protected post embeddings
+                        protected_did: None, // No relationship lookup for followers
+                        data_dir: None,      // No audit logging in pass 1
+                        ..amplifier_ctx
+                    };
+                    let pass2_ctx = profile::ScoringContext {
+                        protected_posts_with_embeddings, // Inferred pairs
+                        protected_did: None,             // No relationship lookup
+                        ..amplifier_ctx
+                    };
 
                     let mut stream = stream::iter(stale_followers.into_iter().map(|follower| {
                         let handle_for_panic = follower.handle.clone();
                         async move {
                             // Pass 1: score without NLI (fast)
                             let result = AssertUnwindSafe(profile::build_profile(
-                                client,
-                                scorer,
+                                &pass1_ctx,
                                 &follower.handle,
                                 &follower.did,
-                                protected_fingerprint,
-                                weights,
-                                embedder,
-                                protected_embedding,
-                                median_engagement,
-                                pile_on_dids,
-                                None, // No NLI in pass 1
-                                None, // No protected post embeddings
                                 None, // No direct pairs
-                                None, // No audit logging in pass 1
                                 None, // No graph distance for followers
                             ))
                             .catch_unwind()
                             .await
                             .unwrap_or_else(|_| {
-                                Err(anyhow::anyhow!("Panic while scoring @{}", handle_for_panic))
+                                Err(anyhow::anyhow!("Panic while scoring @{}", handle_for_panic)
+                                    .into())
                             });
 
                             match result {
                                 Ok(ref score)
-                                    if score.threat_score.unwrap_or(0.0) >= 8.0
-                                        && nli_ref.is_some()
-                                        && ppwe_ref.is_some() =>
+                                    if score.threat_score.unwrap_or(0.0)
+                                        >= scoring_options.tier_thresholds.watch
+                                        && pass2_ctx.nli_scorer.is_some()
+                                        && pass2_ctx.protected_posts_with_embeddings.is_some() =>
                                 {
                                     // Pass 2: above Watch threshold — re-score with NLI
                                     info!(
@@ -387,21 +918,11 @@ pub async fn run(
                                         "Follower above Watch threshold, running NLI"
                                     );
                                     AssertUnwindSafe(profile::build_profile(
-                                        client,
-                                        scorer,
+                                        &pass2_ctx,
                                         &follower.handle,
                                         &follower.did,
-                                        protected_fingerprint,
-                                        weights,
-                                        embedder,
-                                        protected_embedding,
-                                        median_engagement,
-                                        pile_on_dids,
-                                        nli_ref,  // NLI enabled
-                                        ppwe_ref, // Inferred pairs
-                                        None,     // No direct pairs
-                                        data_dir, // Audit logging
-                                        None,     // No graph distance for followers
+                                        None, // No direct pairs
+                                        None, // No graph distance for followers
                                     ))
                                     .catch_unwind()
                                     .await
@@ -413,12 +934,17 @@ pub async fn run(
                     }))
                     .buffer_unordered(concurrency);
 
-                    // Phase 3: Write results to DB incrementally as they arrive
+                    // Phase 3: Write results to DB in batches as they arrive
                     while let Some(result) = stream.next().await {
                         match result {
-                            Ok(score) => {
-                                db.upsert_account_score(user_did, &score).await?;
-                                accounts_scored += 1;
+                            Ok(mut score) => {
+                                if new_followers.contains(&score.did) {
+                                    behavioral::mark_recently_followed(&mut score);
+                                }
+                                // Record which amplifier led here, for reports
+                                // grouped by amplifier
+                                score.origin_amplifier_did = Some(event.amplifier_did.clone());
+                                writer.push(score).await?;
                             }
                             Err(e) => {
                                 warn!(error = %e, "Failed to score follower, skipping");
@@ -428,6 +954,17 @@ pub async fn run(
                     }
 
                     pb.finish_and_clear();
+
+                    // Only mark the event and move the offset once its scores
+                    // are stored, so an interrupted scan never skips followers
+                    // it didn't save and a resumed one refetches the same slice
+                    writer.flush().await?;
+                    db.mark_event_followers_scored(event_id).await?;
+                    if let Some(next) = slice.next_cursor.as_deref() {
+                        if pending_slices.settle(&event.amplifier_did) {
+                            save_follower_offset(db, user_did, &event.amplifier_did, next).await?;
+                        }
+                    }
                 }
                 Err(e) => {
                     warn!(
@@ -435,10 +972,35 @@ pub async fn run(
                         error = %e,
                         "Failed to fetch followers, skipping"
                     );
+                    reporter.error(&e.context(format!(
+                        "failed to fetch followers of @{}",
+                        event.amplifier_handle
+                    )));
                 }
             }
         }
+
+        if resumed > 0 {
+            reporter.status(
+                "scan_resumed",
+                format!(
+                    "\n  Resumed: skipped {} events whose followers were already scored",
+                    resumed
+                ),
+                json!({ "skipped_events": resumed }),
+            );
+        }
     }
 
+    let accounts_scored = writer.finish().await?;
+    super::record_run(
+        db,
+        user_did,
+        run_kind,
+        &started_at,
+        events.len(),
+        accounts_scored,
+    )
+    .await;
     Ok((events.len(), accounts_scored))
 }
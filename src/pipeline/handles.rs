@@ -0,0 +1,242 @@
+// Handle backfill — replace raw DIDs stored in the handle columns.
+//
+// Bulk imports and Constellation-only scans record accounts before their
+// handles are known, leaving `did:...` in account_scores.handle and
+// amplification_events.amplifier_handle. This one-shot cleanup resolves
+// those DIDs through getProfiles and rewrites the rows so reports are
+// readable. DIDs that don't resolve (deleted or suspended accounts) are
+// left as-is and counted.
+//
+// Every resolution is also written to the shared did_cache table, and the
+// backfill checks that cache before going to the network. `warm_did_cache`
+// fills it up front for every DID a user has stored, so later runs can
+// work offline. Scans resolve through `resolve_dids_cached`, which trusts
+// cache entries for `HANDLE_CACHE_MAX_AGE_DAYS` before asking again.
+
+use anyhow::Result;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info};
+
+use crate::bluesky::client::PublicAtpClient;
+use crate::bluesky::profiles;
+use crate::db::Database;
+use crate::toxicity::rate_limiter::RateLimiter;
+
+/// getProfiles accepts at most 25 actors per request.
+const RESOLVE_BATCH_SIZE: usize = 25;
+
+/// Batches per second — well under the public AppView's request budget.
+const RESOLVE_BATCHES_PER_SECOND: f64 = 4.0;
+
+/// How long scans trust a cached handle before resolving the DID again.
+/// Handles change rarely; a month keeps renames from lingering forever.
+pub const HANDLE_CACHE_MAX_AGE_DAYS: u32 = 30;
+
+/// Outcome of a handle backfill run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HandleBackfill {
+    /// Distinct DIDs found with a raw-DID handle
+    pub candidates: usize,
+    /// DIDs that resolved to a handle
+    pub resolved: usize,
+    /// DIDs the AppView didn't return (deleted, suspended, or invalid)
+    pub unresolvable: usize,
+    /// Rows rewritten across both tables
+    pub rows_updated: u64,
+}
+
+/// Outcome of a did_cache warmup run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheWarmup {
+    /// Distinct DIDs stored for the user
+    pub known: usize,
+    /// DIDs that already had a cache entry
+    pub already_cached: usize,
+    /// DIDs resolved over the network and cached this run
+    pub resolved: usize,
+    /// DIDs the AppView didn't return (deleted, suspended, or invalid)
+    pub unresolvable: usize,
+}
+
+/// True when a stored handle is really an unresolved DID.
+pub fn is_raw_did_handle(handle: &str) -> bool {
+    handle.starts_with("did:")
+}
+
+/// Resolve every raw-DID handle for a user and update the stored rows.
+///
+/// Batches are rate-limited and each batch is written as soon as it
+/// resolves, so an interrupted run keeps the progress it made.
+pub async fn backfill_handles(
+    client: &PublicAtpClient,
+    db: &dyn Database,
+    user_did: &str,
+) -> Result<HandleBackfill> {
+    let dids = db.get_unresolved_handle_dids(user_did).await?;
+    let mut outcome = HandleBackfill {
+        candidates: dids.len(),
+        ..Default::default()
+    };
+
+    if dids.is_empty() {
+        return Ok(outcome);
+    }
+
+    // Cached DIDs need no network round-trip
+    let cached = db.get_cached_handles(&dids).await?;
+    let dids: Vec<String> = dids
+        .into_iter()
+        .filter(|did| !cached.contains_key(did))
+        .collect();
+    let from_cache: Vec<(String, String)> = cached.into_iter().collect();
+    if !from_cache.is_empty() {
+        outcome.resolved += from_cache.len();
+        outcome.rows_updated += db.update_resolved_handles(user_did, &from_cache).await?;
+    }
+
+    info!(
+        cached = from_cache.len(),
+        count = dids.len(),
+        "Resolving raw-DID handles"
+    );
+    let limiter = RateLimiter::new(RESOLVE_BATCHES_PER_SECOND);
+
+    for chunk in dids.chunks(RESOLVE_BATCH_SIZE) {
+        limiter.acquire().await;
+        let resolved = resolve_and_cache(client, db, chunk).await?;
+
+        outcome.resolved += resolved.len();
+        outcome.unresolvable += chunk.len() - resolved.len();
+        if !resolved.is_empty() {
+            outcome.rows_updated += db.update_resolved_handles(user_did, &resolved).await?;
+        }
+    }
+
+    info!(
+        resolved = outcome.resolved,
+        unresolvable = outcome.unresolvable,
+        rows = outcome.rows_updated,
+        "Handle backfill complete"
+    );
+
+    Ok(outcome)
+}
+
+/// Resolve every DID stored for a user that isn't cached yet and populate
+/// the did_cache, so later report and backfill runs need no network.
+///
+/// Rate-limited like `backfill_handles`, and each batch is cached as soon as
+/// it resolves, so an interrupted run keeps its progress.
+pub async fn warm_did_cache(
+    client: &PublicAtpClient,
+    db: &dyn Database,
+    user_did: &str,
+) -> Result<CacheWarmup> {
+    let known = db.get_known_dids(user_did).await?;
+    let cached = db.get_cached_handles(&known).await?;
+    let pending: Vec<String> = known
+        .iter()
+        .filter(|did| !cached.contains_key(*did))
+        .cloned()
+        .collect();
+
+    let mut outcome = CacheWarmup {
+        known: known.len(),
+        already_cached: cached.len(),
+        ..Default::default()
+    };
+
+    if pending.is_empty() {
+        return Ok(outcome);
+    }
+
+    info!(count = pending.len(), "Warming DID cache");
+    let limiter = RateLimiter::new(RESOLVE_BATCHES_PER_SECOND);
+
+    let pb = ProgressBar::new(pending.len() as u64);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("  Resolving [{bar:30}] {pos}/{len} ({eta})")
+            .unwrap(),
+    );
+
+    for chunk in pending.chunks(RESOLVE_BATCH_SIZE) {
+        limiter.acquire().await;
+        let resolved = resolve_and_cache(client, db, chunk).await?;
+        outcome.resolved += resolved.len();
+        outcome.unresolvable += chunk.len() - resolved.len();
+        pb.inc(chunk.len() as u64);
+    }
+    pb.finish_and_clear();
+
+    info!(
+        resolved = outcome.resolved,
+        unresolvable = outcome.unresolvable,
+        "DID cache warmup complete"
+    );
+
+    Ok(outcome)
+}
+
+/// Resolve DIDs to handles through the did_cache. Entries resolved within
+/// `HANDLE_CACHE_MAX_AGE_DAYS` are served from the database; only unknown
+/// or stale DIDs go to the network, and what resolves is cached.
+///
+/// As with `profiles::resolve_dids_to_handles`, DIDs that don't resolve
+/// are left out of the map.
+pub async fn resolve_dids_cached(
+    client: &PublicAtpClient,
+    db: &dyn Database,
+    dids: &[String],
+) -> Result<HashMap<String, String>> {
+    let mut handles = db
+        .get_fresh_cached_handles(dids, HANDLE_CACHE_MAX_AGE_DAYS)
+        .await?;
+    let cached = handles.len();
+
+    let mut seen = HashSet::new();
+    let pending: Vec<String> = dids
+        .iter()
+        .filter(|did| !handles.contains_key(*did) && seen.insert(did.as_str()))
+        .cloned()
+        .collect();
+    if !pending.is_empty() {
+        handles.extend(resolve_and_cache(client, db, &pending).await?);
+    }
+
+    debug!(
+        cached,
+        fetched = pending.len(),
+        resolved = handles.len(),
+        "Resolved DIDs through the handle cache"
+    );
+    Ok(handles)
+}
+
+/// Resolve one batch of DIDs and cache the usable results. Returns the
+/// (did, handle) pairs that resolved.
+async fn resolve_and_cache(
+    client: &PublicAtpClient,
+    db: &dyn Database,
+    dids: &[String],
+) -> Result<Vec<(String, String)>> {
+    let handles = profiles::resolve_dids_to_handles(client, dids).await?;
+
+    // A profile can come back with its handle unset (handle.invalid);
+    // writing that would be no more readable than the DID.
+    let resolved: Vec<(String, String)> = dids
+        .iter()
+        .filter_map(|did| {
+            handles
+                .get(did)
+                .filter(|h| !is_raw_did_handle(h) && h.as_str() != "handle.invalid")
+                .map(|h| (did.clone(), h.clone()))
+        })
+        .collect();
+
+    if !resolved.is_empty() {
+        db.cache_handles(&resolved).await?;
+    }
+    Ok(resolved)
+}
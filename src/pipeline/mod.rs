@@ -1,4 +1,40 @@
 // Pipeline — the main threat detection workflows.
 
 pub mod amplification;
+pub mod handles;
+pub mod new_threats;
+pub mod score_writer;
 pub mod sweep;
+
+use std::sync::Arc;
+
+use crate::db::Database;
+
+/// Current UTC time in the "YYYY-MM-DD HH:MM:SS" form scan_runs stores.
+pub(crate) fn run_timestamp() -> String {
+    chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Record a finished pipeline run for `charcoal status`. The run's results
+/// are already saved, so a failure here is logged rather than returned.
+pub(crate) async fn record_run(
+    db: &Arc<dyn Database>,
+    user_did: &str,
+    command: &str,
+    started_at: &str,
+    events_detected: usize,
+    accounts_scored: usize,
+) {
+    if let Err(e) = db
+        .record_scan_run(
+            user_did,
+            command,
+            started_at,
+            events_detected as i64,
+            accounts_scored as i64,
+        )
+        .await
+    {
+        tracing::warn!(command, error = %e, "Failed to record scan run");
+    }
+}
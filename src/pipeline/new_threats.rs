@@ -0,0 +1,54 @@
+// New-threat detection — which accounts crossed into High during a run.
+//
+// A scan can't tell "this account is High" from "this account just became
+// High" on its own, so callers snapshot the High-tier DIDs before scoring
+// and diff the stored scores against that snapshot afterwards. Accounts
+// that were already High, or that dropped out of High, aren't reported.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::db::models::{AccountScore, ThreatTier, TierThresholds};
+use crate::db::Database;
+
+/// DIDs of every account currently stored at High tier for this user.
+pub async fn high_tier_dids(
+    db: &dyn Database,
+    user_did: &str,
+    thresholds: &TierThresholds,
+) -> Result<HashSet<String>> {
+    let scores = db
+        .get_ranked_threats(user_did, thresholds.high, thresholds)
+        .await?;
+    Ok(scores.into_iter().map(|s| s.did).collect())
+}
+
+/// Accounts in `after` whose tier is High and weren't in `before`, highest
+/// score first.
+pub fn newly_high(before: &HashSet<String>, after: Vec<AccountScore>) -> Vec<AccountScore> {
+    let mut new: Vec<AccountScore> = after
+        .into_iter()
+        .filter(|s| s.threat_tier.as_deref() == Some(ThreatTier::High.as_str()))
+        .filter(|s| !before.contains(&s.did))
+        .collect();
+    new.sort_by(|a, b| {
+        b.threat_score
+            .unwrap_or(0.0)
+            .total_cmp(&a.threat_score.unwrap_or(0.0))
+    });
+    new
+}
+
+/// Diff the stored High-tier accounts against a snapshot taken before the run.
+pub async fn find_newly_high(
+    db: &dyn Database,
+    user_did: &str,
+    before: &HashSet<String>,
+    thresholds: &TierThresholds,
+) -> Result<Vec<AccountScore>> {
+    let after = db
+        .get_ranked_threats(user_did, thresholds.high, thresholds)
+        .await?;
+    Ok(newly_high(before, after))
+}
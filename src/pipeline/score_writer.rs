@@ -0,0 +1,166 @@
+// Batched score writes for the scoring pipelines.
+//
+// Pipelines score accounts concurrently but used to persist each result with
+// its own `upsert_account_score`, so every account took the SQLite connection
+// mutex and committed its own transaction. ScoreWriter collects finished
+// scores and writes them with `Database::upsert_account_scores` every
+// `batch_size` accounts instead. Callers must `finish` it to write the
+// remainder — a writer dropped with scores still pending logs how many were
+// lost. A batch size of 1 writes every score as it arrives.
+//
+// With an alert webhook set, the stored score of each account queued as
+// High is noted as it's queued. Once the batch is written, the row that was
+// actually stored — smoothed, or left alone by the upsert's guard — is read
+// back, and accounts it shows newly in High are posted to the webhook.
+
+use anyhow::Result;
+use tracing::{info, warn};
+
+use crate::db::models::{AccountScore, ScoreSmoothing, ThreatTier};
+use crate::db::Database;
+use crate::output::webhook;
+
+/// Default number of scores written per batch.
+pub const DEFAULT_SCORE_BATCH_SIZE: usize = 50;
+
+/// Buffers completed scores and writes them in batches.
+pub struct ScoreWriter<'a> {
+    db: &'a dyn Database,
+    user_did: &'a str,
+    batch_size: usize,
+    smoothing: Option<ScoreSmoothing>,
+    alert_webhook: Option<&'a str>,
+    pending: Vec<AccountScore>,
+    /// DIDs queued as High this batch, with their stored score before it.
+    alert_candidates: Vec<(String, Option<AccountScore>)>,
+    written: usize,
+}
+
+impl<'a> ScoreWriter<'a> {
+    /// A writer that flushes every `batch_size` scores (0 is treated as 1),
+    /// smoothing threat scores with `smoothing` when set (see
+    /// `Database::upsert_account_score`).
+    pub fn new(
+        db: &'a dyn Database,
+        user_did: &'a str,
+        batch_size: usize,
+        smoothing: Option<ScoreSmoothing>,
+    ) -> Self {
+        let batch_size = batch_size.max(1);
+        Self {
+            db,
+            user_did,
+            batch_size,
+            smoothing,
+            alert_webhook: None,
+            pending: Vec::with_capacity(batch_size),
+            alert_candidates: Vec::new(),
+            written: 0,
+        }
+    }
+
+    /// Post accounts newly reaching the High tier to `url` (see
+    /// `output::webhook`). `None` sends no alerts.
+    pub fn with_alert_webhook(mut self, url: Option<&'a str>) -> Self {
+        self.alert_webhook = url;
+        self
+    }
+
+    /// Queue a score, writing the batch once it is full.
+    pub async fn push(&mut self, score: AccountScore) -> Result<()> {
+        if self.alert_webhook.is_some() {
+            self.queue_alert(&score).await;
+        }
+        self.pending.push(score);
+        if self.pending.len() >= self.batch_size {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Write every queued score now.
+    pub async fn flush(&mut self) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        self.db
+            .upsert_account_scores(self.user_did, &self.pending, self.smoothing)
+            .await?;
+        self.written += self.pending.len();
+        self.pending.clear();
+        self.send_alerts().await;
+        Ok(())
+    }
+
+    /// Note the stored score of an account queued as High, so `send_alerts`
+    /// can tell whether the write moved it into High. Only the first queueing
+    /// in a batch counts, so an account queued twice only alerts once.
+    async fn queue_alert(&mut self, score: &AccountScore) {
+        if score.threat_tier.as_deref() != Some(ThreatTier::High.as_str())
+            || self
+                .alert_candidates
+                .iter()
+                .any(|(did, _)| *did == score.did)
+        {
+            return;
+        }
+        let prior = match self.db.get_account_by_did(self.user_did, &score.did).await {
+            Ok(prior) => prior,
+            Err(e) => {
+                warn!(did = %score.did, error = %e, "Couldn't look up prior score for alert");
+                None
+            }
+        };
+        self.alert_candidates.push((score.did.clone(), prior));
+    }
+
+    /// Post alerts for candidates whose stored score is now newly High. A
+    /// failed lookup or post is logged and dropped — alerts never fail a
+    /// scan.
+    async fn send_alerts(&mut self) {
+        let Some(url) = self.alert_webhook else {
+            return;
+        };
+        for (did, prior) in std::mem::take(&mut self.alert_candidates) {
+            let account = match self.db.get_account_by_did(self.user_did, &did).await {
+                Ok(Some(account)) => account,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!(did = %did, error = %e, "Couldn't look up stored score for alert");
+                    continue;
+                }
+            };
+            if !webhook::is_new_high_tier(&account, prior.as_ref()) {
+                continue;
+            }
+            match webhook::post_alert(url, &account).await {
+                Ok(()) => info!(handle = %account.handle, "Sent High-tier alert"),
+                Err(e) => {
+                    warn!(handle = %account.handle, error = %e, "Failed to send High-tier alert")
+                }
+            }
+        }
+    }
+
+    /// Write the remainder and return how many scores were written in total.
+    pub async fn finish(mut self) -> Result<usize> {
+        self.flush().await?;
+        Ok(self.written)
+    }
+
+    /// Scores queued but not yet written.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+impl Drop for ScoreWriter<'_> {
+    fn drop(&mut self) {
+        if !self.pending.is_empty() {
+            warn!(
+                count = self.pending.len(),
+                "Score writer dropped before finishing — unwritten scores were lost"
+            );
+        }
+    }
+}
@@ -12,25 +12,118 @@
 use anyhow::Result;
 use futures::stream::{self, StreamExt};
 use futures::FutureExt;
-use indicatif::{ProgressBar, ProgressStyle};
+use serde_json::json;
 use std::collections::HashSet;
 use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{info, warn};
 
 use crate::bluesky::client::PublicAtpClient;
 use crate::bluesky::followers;
+use crate::db::models::ScoreSmoothing;
 use crate::db::Database;
-use crate::scoring::profile;
+use crate::output::reporter::Reporter;
+use crate::pipeline::score_writer::ScoreWriter;
+use crate::scoring::profile::{self, ScoringOptions};
 use crate::scoring::threat::ThreatWeights;
 use crate::topics::embeddings::SentenceEmbedder;
 use crate::topics::fingerprint::TopicFingerprint;
+use crate::topics::overlap::PreparedFingerprint;
 use crate::toxicity::traits::ToxicityScorer;
 
+pub use crate::bluesky::client::{PUBLIC_API_REQUESTS_PER_WINDOW, PUBLIC_API_WINDOW};
+
+/// Followers returned per getFollowers page.
+const FOLLOWER_PAGE_SIZE: usize = 100;
+
+/// Requests to score one account when it reaches stage 2: the stage-1 and
+/// stage-2 feed fetches plus up to two getPosts calls for reply parents.
+/// Accounts that exit at stage 1 use fewer, so this is an upper bound.
+pub const REQUESTS_PER_SCORED_ACCOUNT: usize = 4;
+
+/// What a graph sweep would cost, from `estimate_cost`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SweepEstimate {
+    /// First-degree followers the sweep would walk
+    pub first_degree: usize,
+    /// Second-degree accounts the walk could collect — an upper bound, as
+    /// duplicates and already-scored accounts are dropped later
+    pub projected_pool: usize,
+    /// getFollowers pages for both levels of the walk
+    pub follower_requests: usize,
+    /// Requests to score every account in the projected pool
+    pub scoring_requests: usize,
+}
+
+impl SweepEstimate {
+    pub fn total_requests(&self) -> usize {
+        self.follower_requests + self.scoring_requests
+    }
+
+    /// Rate-limit windows the requests span (at least one).
+    pub fn rate_windows(&self) -> usize {
+        self.total_requests()
+            .div_ceil(PUBLIC_API_REQUESTS_PER_WINDOW)
+            .max(1)
+    }
+
+    /// Shortest time the requests can take without exceeding the public
+    /// AppView's rate limit.
+    pub fn min_duration(&self) -> Duration {
+        PUBLIC_API_WINDOW
+            .mul_f64(self.total_requests() as f64 / PUBLIC_API_REQUESTS_PER_WINDOW as f64)
+    }
+}
+
+/// Estimate the requests a graph sweep makes over `first_degree` followers,
+/// fetching up to `max_second_degree_per` of each one's followers.
+pub fn estimate_cost(first_degree: usize, max_second_degree_per: usize) -> SweepEstimate {
+    let pages_per_follower = max_second_degree_per.div_ceil(FOLLOWER_PAGE_SIZE);
+    let projected_pool = first_degree * max_second_degree_per;
+    SweepEstimate {
+        first_degree,
+        projected_pool,
+        follower_requests: first_degree.div_ceil(FOLLOWER_PAGE_SIZE).max(1)
+            + first_degree * pages_per_follower,
+        scoring_requests: projected_pool * REQUESTS_PER_SCORED_ACCOUNT,
+    }
+}
+
+fn report_fetching_followers(reporter: &Reporter, max_first_degree: usize) {
+    reporter.status(
+        "followers_fetching",
+        format!("Fetching your followers (up to {max_first_degree})..."),
+        json!({ "max_first_degree": max_first_degree }),
+    );
+}
+
+/// Fetch the protected user's followers the way `run` does, then estimate
+/// the rest of the sweep without fetching second-degree followers or
+/// scoring anything.
+pub async fn dry_run(
+    client: &PublicAtpClient,
+    protected_handle: &str,
+    max_first_degree: usize,
+    max_second_degree_per: usize,
+    reporter: &Reporter,
+) -> Result<SweepEstimate> {
+    report_fetching_followers(reporter, max_first_degree);
+    let first_degree =
+        followers::fetch_followers(client, protected_handle, max_first_degree).await?;
+    info!(count = first_degree.len(), "First-degree followers fetched");
+    Ok(estimate_cost(first_degree.len(), max_second_degree_per))
+}
+
 /// Run the background sweep pipeline.
 ///
 /// Scans followers-of-followers of the protected user, filtered by topic
 /// overlap. Returns the number of second-degree accounts found and scored.
+///
+/// Accounts with fewer than `min_posts` posts are recorded as
+/// "Insufficient Data" without running any inference. The run is recorded
+/// in scan_runs as "sweep". Accounts newly scored into the High tier are
+/// posted to `alert_webhook` when set.
 #[allow(clippy::too_many_arguments)]
 pub async fn run(
     client: &PublicAtpClient,
@@ -40,6 +133,7 @@ pub async fn run(
     protected_handle: &str,
     protected_fingerprint: &TopicFingerprint,
     weights: &ThreatWeights,
+    scoring_options: &ScoringOptions,
     max_first_degree: usize,
     max_second_degree_per: usize,
     concurrency: usize,
@@ -48,18 +142,32 @@ pub async fn run(
     median_engagement: f64,
     pile_on_dids: &std::collections::HashSet<String>,
     data_dir: Option<&std::path::Path>,
+    min_posts: usize,
+    score_batch_size: usize,
+    score_smoothing: Option<ScoreSmoothing>,
+    alert_webhook: Option<&str>,
+    reporter: &Reporter,
 ) -> Result<(usize, usize)> {
+    let started_at = super::run_timestamp();
+
     // Step 1: Fetch the protected user's followers
-    println!("Fetching your followers (up to {max_first_degree})...");
+    report_fetching_followers(reporter, max_first_degree);
     let first_degree =
         followers::fetch_followers(client, protected_handle, max_first_degree).await?;
     info!(count = first_degree.len(), "First-degree followers fetched");
 
     // Step 2: Fetch second-degree followers (followers of your followers)
-    println!(
-        "Scanning second-degree network ({} followers, up to {} each)...",
-        first_degree.len(),
-        max_second_degree_per,
+    reporter.status(
+        "second_degree_scan_started",
+        format!(
+            "Scanning second-degree network ({} followers, up to {} each)...",
+            first_degree.len(),
+            max_second_degree_per,
+        ),
+        json!({
+            "first_degree": first_degree.len(),
+            "max_second_degree_per": max_second_degree_per,
+        }),
     );
 
     let mut seen: HashSet<String> = HashSet::new();
@@ -71,11 +179,9 @@ pub async fn run(
 
     let mut second_degree_pool = Vec::new();
 
-    let pb = ProgressBar::new(first_degree.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("  Network [{bar:30}] {pos}/{len} ({eta})")
-            .unwrap(),
+    let pb = reporter.progress_bar(
+        first_degree.len() as u64,
+        "  Network [{bar:30}] {pos}/{len} ({eta})",
     );
 
     for follower in &first_degree {
@@ -99,74 +205,115 @@ pub async fn run(
     }
     pb.finish_and_clear();
 
-    println!(
-        "  Found {} unique second-degree accounts",
-        second_degree_pool.len(),
+    reporter.status(
+        "second_degree_found",
+        format!(
+            "  Found {} unique second-degree accounts",
+            second_degree_pool.len(),
+        ),
+        json!({ "count": second_degree_pool.len() }),
     );
 
-    // Step 3: Filter to accounts with stale or missing scores
+    // Step 3: Filter to accounts with stale or missing scores, leaving out
+    // whitelisted accounts entirely
+    let whitelist: HashSet<String> = db
+        .get_whitelist(user_did)
+        .await?
+        .into_iter()
+        .map(|entry| entry.did)
+        .collect();
     let mut stale = Vec::new();
     for f in &second_degree_pool {
+        if whitelist.contains(&f.did) {
+            continue;
+        }
         if db.is_score_stale(user_did, &f.did, 7).await.unwrap_or(true) {
             stale.push(f);
         }
     }
 
     if stale.is_empty() {
-        println!("  All second-degree accounts have recent scores.");
+        reporter.status(
+            "scores_fresh",
+            "  All second-degree accounts have recent scores.",
+            json!({ "count": second_degree_pool.len() }),
+        );
+        super::record_run(
+            db,
+            user_did,
+            "sweep",
+            &started_at,
+            second_degree_pool.len(),
+            0,
+        )
+        .await;
         return Ok((second_degree_pool.len(), 0));
     }
 
-    println!(
-        "  {} need scoring ({} concurrent)...",
-        stale.len(),
-        concurrency,
+    reporter.status(
+        "scoring_started",
+        format!(
+            "  {} need scoring ({} concurrent)...",
+            stale.len(),
+            concurrency,
+        ),
+        json!({ "count": stale.len(), "concurrency": concurrency }),
     );
 
+    // The protected side of every overlap comparison is the same — compute
+    // its keyword weights once for the whole batch.
+    let prepared_fingerprint = &PreparedFingerprint::new(protected_fingerprint);
+    let ctx = &profile::ScoringContext {
+        client,
+        scorer,
+        protected_fingerprint: prepared_fingerprint,
+        weights,
+        options: scoring_options,
+        embedder,
+        protected_embedding,
+        median_engagement,
+        pile_on_dids,
+        whitelist: &whitelist,
+        nli_scorer: None, // NLI scorer not used for sweep scoring
+        protected_posts_with_embeddings: None,
+        protected_did: None, // No relationship lookup for sweep
+        data_dir,
+        min_posts,
+    };
+
     // Step 4: Score in parallel (same pattern as amplification pipeline)
-    let pb = ProgressBar::new(stale.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("  Scoring [{bar:30}] {pos}/{len} ({eta})")
-            .unwrap(),
+    let pb = reporter.progress_bar(
+        stale.len() as u64,
+        "  Scoring [{bar:30}] {pos}/{len} ({eta})",
     );
 
     let mut stream = stream::iter(stale.into_iter().map(|follower| {
         let handle_for_panic = follower.handle.clone();
         async move {
+            // No direct pairs or graph distance for sweep
             AssertUnwindSafe(profile::build_profile(
-                client,
-                scorer,
+                ctx,
                 &follower.handle,
                 &follower.did,
-                protected_fingerprint,
-                weights,
-                embedder,
-                protected_embedding,
-                median_engagement,
-                pile_on_dids,
-                None, // NLI scorer not used for sweep scoring
-                None, // No protected post embeddings for sweep
-                None, // No direct pairs for sweep
-                data_dir,
-                None, // No graph distance for sweep
+                None,
+                None,
             ))
             .catch_unwind()
             .await
-            .unwrap_or_else(|_| Err(anyhow::anyhow!("Panic while scoring @{}", handle_for_panic)))
+            .unwrap_or_else(|_| {
+                Err(anyhow::anyhow!("Panic while scoring @{}", handle_for_panic).into())
+            })
         }
     }))
     .buffer_unordered(concurrency);
 
-    // Step 5: Write results to DB incrementally — each score is persisted
-    // as it arrives so a crash doesn't lose everything scored so far
-    let mut accounts_scored = 0;
+    // Step 5: Write results to DB incrementally, a batch at a time, so a
+    // crash loses at most one batch of what was scored so far
+    let mut writer = ScoreWriter::new(db.as_ref(), user_did, score_batch_size, score_smoothing)
+        .with_alert_webhook(alert_webhook);
     while let Some(result) = stream.next().await {
         match result {
-            Ok(score) => {
-                db.upsert_account_score(user_did, &score).await?;
-                accounts_scored += 1;
-            }
+            Ok(score) => writer.push(score).await?,
             Err(e) => {
                 warn!(error = %e, "Failed to score account, skipping");
             }
@@ -174,6 +321,16 @@ pub async fn run(
         pb.inc(1);
     }
     pb.finish_and_clear();
+    let accounts_scored = writer.finish().await?;
+    super::record_run(
+        db,
+        user_did,
+        "sweep",
+        &started_at,
+        second_degree_pool.len(),
+        accounts_scored,
+    )
+    .await;
 
     Ok((second_degree_pool.len(), accounts_scored))
 }
@@ -182,7 +339,10 @@ pub async fn run(
 ///
 /// Instead of walking the follower graph, searches for posts matching the
 /// protected user's topic fingerprint via searchPosts. Deduplicates against
-/// already-scored accounts and scores new discoveries.
+/// already-scored accounts and scores new discoveries. Accounts below
+/// `min_posts` are recorded as "Insufficient Data" rather than scored. The
+/// run is recorded in scan_runs as "sweep-topic". Accounts newly scored
+/// into the High tier are posted to `alert_webhook` when set.
 #[allow(clippy::too_many_arguments)]
 pub async fn run_topic_first(
     client: &PublicAtpClient,
@@ -191,6 +351,7 @@ pub async fn run_topic_first(
     user_did: &str,
     protected_fingerprint: &TopicFingerprint,
     weights: &ThreatWeights,
+    scoring_options: &ScoringOptions,
     concurrency: usize,
     embedder: Option<&SentenceEmbedder>,
     protected_embedding: Option<&[f64]>,
@@ -199,19 +360,42 @@ pub async fn run_topic_first(
     data_dir: Option<&std::path::Path>,
     keywords_per_cycle: usize,
     results_per_keyword: usize,
+    min_posts: usize,
+    score_batch_size: usize,
+    score_smoothing: Option<ScoreSmoothing>,
+    alert_webhook: Option<&str>,
+    reporter: &Reporter,
 ) -> Result<(usize, usize)> {
+    let started_at = super::run_timestamp();
+
     // Step 1: Get already-scored DIDs for deduplication
-    let scored_dids: HashSet<String> = db
+    let mut scored_dids: HashSet<String> = db
         .get_all_scored_dids(user_did)
         .await?
         .into_iter()
         .collect();
 
-    println!(
-        "  {} accounts already scored, searching for new discoveries...",
-        scored_dids.len()
+    reporter.status(
+        "topic_search_started",
+        format!(
+            "  {} accounts already scored, searching for new discoveries...",
+            scored_dids.len()
+        ),
+        json!({ "already_scored": scored_dids.len() }),
     );
 
+    // Searching the protected user's own keywords readily turns up their own
+    // posts — exclude them by DID so they never score themselves. Whitelisted
+    // accounts are excluded the same way.
+    let whitelist: HashSet<String> = db
+        .get_whitelist(user_did)
+        .await?
+        .into_iter()
+        .map(|entry| entry.did)
+        .collect();
+    scored_dids.insert(user_did.to_string());
+    scored_dids.extend(whitelist.iter().cloned());
+
     // Step 2: Discover new accounts via topic search
     let new_dids = crate::discovery::topic_search::discover_by_topic(
         client,
@@ -222,34 +406,62 @@ pub async fn run_topic_first(
     )
     .await?;
 
-    println!("  Found {} new accounts to score", new_dids.len());
+    reporter.status(
+        "topic_search_found",
+        format!("  Found {} new accounts to score", new_dids.len()),
+        json!({ "count": new_dids.len() }),
+    );
 
     if new_dids.is_empty() {
+        super::record_run(db, user_did, "sweep-topic", &started_at, 0, 0).await;
         return Ok((0, 0));
     }
 
-    // Step 3: Resolve DIDs to handles via getProfiles (batch, 25 per call)
+    // Step 3: Resolve DIDs to handles, via getProfiles for any not cached
     let did_handle_map =
-        crate::bluesky::profiles::resolve_dids_to_handles(client, &new_dids).await?;
+        super::handles::resolve_dids_cached(client, db.as_ref(), &new_dids).await?;
 
     let did_handle_pairs: Vec<(String, String)> = did_handle_map.into_iter().collect();
 
-    println!(
-        "  Resolved {}/{} DIDs to handles",
-        did_handle_pairs.len(),
-        new_dids.len()
+    reporter.status(
+        "handles_resolved",
+        format!(
+            "  Resolved {}/{} DIDs to handles",
+            did_handle_pairs.len(),
+            new_dids.len()
+        ),
+        json!({ "resolved": did_handle_pairs.len(), "total": new_dids.len() }),
     );
 
     if did_handle_pairs.is_empty() {
         return Ok((new_dids.len(), 0));
     }
 
+    // The protected side of every overlap comparison is the same — compute
+    // its keyword weights once for the whole batch.
+    let prepared_fingerprint = &PreparedFingerprint::new(protected_fingerprint);
+    let ctx = &profile::ScoringContext {
+        client,
+        scorer,
+        protected_fingerprint: prepared_fingerprint,
+        weights,
+        options: scoring_options,
+        embedder,
+        protected_embedding,
+        median_engagement,
+        pile_on_dids,
+        whitelist: &whitelist,
+        nli_scorer: None, // No NLI for discovery sweep
+        protected_posts_with_embeddings: None,
+        protected_did: None, // No relationship lookup for discovery
+        data_dir,
+        min_posts,
+    };
+
     // Step 4: Score accounts in parallel (same pattern as existing sweep)
-    let pb = ProgressBar::new(did_handle_pairs.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("  Scoring [{bar:30}] {pos}/{len} ({eta})")
-            .unwrap(),
+    let pb = reporter.progress_bar(
+        did_handle_pairs.len() as u64,
+        "  Scoring [{bar:30}] {pos}/{len} ({eta})",
     );
 
     let discovered = did_handle_pairs.len();
@@ -257,37 +469,22 @@ pub async fn run_topic_first(
     let mut stream = stream::iter(did_handle_pairs.into_iter().map(|(did, handle)| {
         let handle_for_panic = handle.clone();
         async move {
-            AssertUnwindSafe(profile::build_profile(
-                client,
-                scorer,
-                &handle,
-                &did,
-                protected_fingerprint,
-                weights,
-                embedder,
-                protected_embedding,
-                median_engagement,
-                pile_on_dids,
-                None, // No NLI for discovery sweep
-                None, // No protected post embeddings
-                None, // No direct pairs
-                data_dir,
-                None, // No graph distance for discovery
-            ))
-            .catch_unwind()
-            .await
-            .unwrap_or_else(|_| Err(anyhow::anyhow!("Panic while scoring @{}", handle_for_panic)))
+            // No direct pairs or graph distance for discovery
+            AssertUnwindSafe(profile::build_profile(ctx, &handle, &did, None, None))
+                .catch_unwind()
+                .await
+                .unwrap_or_else(|_| {
+                    Err(anyhow::anyhow!("Panic while scoring @{}", handle_for_panic).into())
+                })
         }
     }))
     .buffer_unordered(concurrency);
 
-    let mut accounts_scored = 0;
+    let mut writer = ScoreWriter::new(db.as_ref(), user_did, score_batch_size, score_smoothing)
+        .with_alert_webhook(alert_webhook);
     while let Some(result) = stream.next().await {
         match result {
-            Ok(score) => {
-                db.upsert_account_score(user_did, &score).await?;
-                accounts_scored += 1;
-            }
+            Ok(score) => writer.push(score).await?,
             Err(e) => {
                 warn!(error = %e, "Failed to score discovered account, skipping");
             }
@@ -295,6 +492,16 @@ pub async fn run_topic_first(
         pb.inc(1);
     }
     pb.finish_and_clear();
+    let accounts_scored = writer.finish().await?;
+    super::record_run(
+        db,
+        user_did,
+        "sweep-topic",
+        &started_at,
+        discovered,
+        accounts_scored,
+    )
+    .await;
 
     Ok((discovered, accounts_scored))
 }
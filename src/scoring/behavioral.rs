@@ -1,15 +1,23 @@
 // Behavioral signals — post-pattern analysis for scoring adjustment.
 //
 // Computes behavioral signals (quote ratio, reply ratio, engagement,
-// pile-on participation) and uses them as a gate + multiplier hybrid:
+// pile-on participation, strangers quoting the protected user) and uses
+// them as a gate + multiplier hybrid:
 // - Benign gate: caps score at 12.0 for clearly non-threatening accounts
 // - Hostile multiplier: boosts score by 1.0-1.5x for hostile patterns
+//
+// Also detects quote cascades ("ratio-ing") — quote trees where the quotes
+// themselves get quoted — so the accounts that set them off can be boosted.
+//
+// And diffs per-amplifier follower snapshots across scans to flag accounts
+// that followed an amplifier since the previous scan.
 
 use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
 use crate::bluesky::posts::Post;
+use crate::db::models::AccountScore;
 
 /// Behavioral signals computed from an account's posting patterns.
 ///
@@ -28,6 +36,44 @@ pub struct BehavioralSignals {
     pub benign_gate: bool,
     /// The computed behavioral boost multiplier (1.0 = neutral)
     pub behavioral_boost: f64,
+    /// Posts left out of toxicity scoring because they weren't in a
+    /// language the model supports
+    #[serde(default)]
+    pub posts_skipped_language: usize,
+    /// Whether this account followed an amplifier since the previous scan
+    #[serde(default)]
+    pub recently_followed_amplifier: bool,
+    /// Negative labels a trusted labeler has applied to this account (see
+    /// `bluesky::labels`)
+    #[serde(default)]
+    pub moderation_labels: Vec<String>,
+    /// The post-age window scoring was limited to, in days
+    /// (`--max-post-age-days`). `None` when every fetched post counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_window_days: Option<u32>,
+    /// Posts left out for being older than `post_window_days`
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub posts_outside_window: usize,
+    /// Multiplier for sparse activity inside the post window (see
+    /// `scoring::recency::recency_weight`). `None` without a window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recency_weight: Option<f64>,
+    /// Whether this account follows the protected user. `None` when the
+    /// relationship wasn't looked up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follows_you: Option<bool>,
+    /// Whether the protected user follows this account. `None` when the
+    /// relationship wasn't looked up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub followed_by_you: Option<bool>,
+    /// Whether this account quoted or replied to the protected user with
+    /// no follow in either direction
+    #[serde(default)]
+    pub stranger_quote: bool,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
 }
 
 impl Default for BehavioralSignals {
@@ -39,6 +85,15 @@ impl Default for BehavioralSignals {
             pile_on: false,
             benign_gate: false,
             behavioral_boost: 1.0,
+            posts_skipped_language: 0,
+            recently_followed_amplifier: false,
+            moderation_labels: Vec::new(),
+            post_window_days: None,
+            posts_outside_window: 0,
+            recency_weight: None,
+            follows_you: None,
+            followed_by_you: None,
+            stranger_quote: false,
         }
     }
 }
@@ -85,20 +140,34 @@ pub fn compute_reply_ratio(reply_count: usize, total_posts: usize) -> f64 {
     reply_count as f64 / total_posts as f64
 }
 
+/// Ceiling on the behavioral boost, however many hostile patterns match.
+pub const MAX_BEHAVIORAL_BOOST: f64 = 1.5;
+
 /// Compute the behavioral boost multiplier from posting patterns.
 ///
-/// Range: 1.0 (neutral) to 1.5 (maximum hostile pattern).
+/// Range: 1.0 (neutral) to `MAX_BEHAVIORAL_BOOST` (maximum hostile pattern).
 /// - quote_ratio * 0.20: accounts that mostly quote-dunk get up to +0.20
 /// - reply_ratio * 0.15: reply-heavy accounts get up to +0.15
 /// - pile_on: +0.15 if the account participated in a detected pile-on
-pub fn compute_behavioral_boost(quote_ratio: f64, reply_ratio: f64, pile_on: bool) -> f64 {
+/// - stranger_quote: +0.10 if the account quoted or replied to the protected
+///   user with no follow either way — no existing relationship to read the
+///   interaction charitably
+pub fn compute_behavioral_boost(
+    quote_ratio: f64,
+    reply_ratio: f64,
+    pile_on: bool,
+    stranger_quote: bool,
+) -> f64 {
     let mut boost = 1.0;
     boost += quote_ratio * 0.20;
     boost += reply_ratio * 0.15;
     if pile_on {
         boost += 0.15;
     }
-    boost
+    if stranger_quote {
+        boost += 0.10;
+    }
+    boost.min(MAX_BEHAVIORAL_BOOST)
 }
 
 /// Benign gate thresholds
@@ -138,6 +207,7 @@ pub fn apply_behavioral_modifier(
     quote_ratio: f64,
     reply_ratio: f64,
     pile_on: bool,
+    stranger_quote: bool,
     avg_engagement: f64,
     median_engagement: f64,
 ) -> (f64, bool) {
@@ -152,7 +222,7 @@ pub fn apply_behavioral_modifier(
     if benign {
         (raw_score.min(BENIGN_GATE_CAP), true)
     } else {
-        let boost = compute_behavioral_boost(quote_ratio, reply_ratio, pile_on);
+        let boost = compute_behavioral_boost(quote_ratio, reply_ratio, pile_on, stranger_quote);
         let score = (raw_score * boost).clamp(0.0, 100.0);
         (score, false)
     }
@@ -168,11 +238,13 @@ pub fn apply_behavioral_modifier(
 /// The third element lets callers avoid double-applying context: when the gate
 /// bypass already consumed the context signal, the context multiplier should
 /// not be applied again on top.
+#[allow(clippy::too_many_arguments)]
 pub fn apply_behavioral_modifier_contextual(
     raw_score: f64,
     quote_ratio: f64,
     reply_ratio: f64,
     pile_on: bool,
+    stranger_quote: bool,
     avg_engagement: f64,
     median_engagement: f64,
     context_score: Option<f64>,
@@ -181,7 +253,7 @@ pub fn apply_behavioral_modifier_contextual(
 
     if context_overrides_gate {
         // Skip benign gate check, but still apply hostile multiplier
-        let boost = compute_behavioral_boost(quote_ratio, reply_ratio, pile_on);
+        let boost = compute_behavioral_boost(quote_ratio, reply_ratio, pile_on, stranger_quote);
         ((raw_score * boost).clamp(0.0, 100.0), false, true)
     } else {
         let (score, benign_gate) = apply_behavioral_modifier(
@@ -189,6 +261,7 @@ pub fn apply_behavioral_modifier_contextual(
             quote_ratio,
             reply_ratio,
             pile_on,
+            stranger_quote,
             avg_engagement,
             median_engagement,
         );
@@ -278,3 +351,199 @@ pub fn detect_pile_on_participants(events: &[(&str, &str, &str)]) -> HashSet<Str
 
     result
 }
+
+// ============================================================
+// Quote cascades ("ratio-ing")
+// ============================================================
+
+/// Minimum chain depth for a quote tree to count as a cascade. Depth 1 is
+/// people quoting the post directly; depth 2 means someone's quote was itself
+/// quoted — the audience of a quoter joining in.
+const CASCADE_MIN_DEPTH: usize = 2;
+
+/// Minimum number of quote-posts in the tree before it counts as a cascade.
+/// A single quote-of-a-quote is normal conversation, not a ratio.
+const CASCADE_MIN_QUOTES: usize = 5;
+
+/// Participants at or above this depth (closest to the root) are treated as
+/// the accounts driving a cascade. Deeper participants are the audience
+/// that piled in after them.
+pub const CASCADE_ROOT_DEPTH: usize = 1;
+
+/// The shape of the quote tree hanging off a single root post.
+#[derive(Debug, Clone)]
+pub struct QuoteCascade {
+    /// The post at the top of the tree (the protected user's post)
+    pub root_uri: String,
+    /// Longest chain of quotes below the root (1 = direct quotes only)
+    pub depth: usize,
+    /// Total quote-posts anywhere in the tree
+    pub total_quotes: usize,
+    /// Most direct quotes received by any single post in the tree
+    pub max_branching: usize,
+    /// Shallowest depth at which each participant DID appears
+    pub participant_depths: HashMap<String, usize>,
+}
+
+impl QuoteCascade {
+    /// Whether this tree looks like a ratio cascade rather than ordinary
+    /// quoting: quotes are being quoted in turn, and there are enough of them
+    /// to be more than a side conversation.
+    pub fn is_cascade(&self) -> bool {
+        self.depth >= CASCADE_MIN_DEPTH && self.total_quotes >= CASCADE_MIN_QUOTES
+    }
+}
+
+/// Build quote trees from an edge list and measure their shape.
+///
+/// Each edge is `(quoted_post_uri, quote_post_uri, quoter_did)` — "the post
+/// at `quote_post_uri`, written by `quoter_did`, quotes `quoted_post_uri`".
+/// Roots are posts that are quoted but don't quote anything in the edge list
+/// themselves. Returns one `QuoteCascade` per root, whether or not it meets
+/// the cascade threshold — callers check `is_cascade()`.
+///
+/// Walks each tree breadth-first so a participant's recorded depth is the
+/// shallowest one they appear at. A visited set guards against malformed
+/// input that loops back on itself.
+pub fn detect_quote_cascades(edges: &[(&str, &str, &str)]) -> Vec<QuoteCascade> {
+    let mut children: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+    let mut quoted_something: HashSet<&str> = HashSet::new();
+    for &(parent, child, did) in edges {
+        children.entry(parent).or_default().push((child, did));
+        quoted_something.insert(child);
+    }
+
+    let mut roots: Vec<&str> = children
+        .keys()
+        .copied()
+        .filter(|uri| !quoted_something.contains(uri))
+        .collect();
+    // Deterministic output order regardless of HashMap iteration
+    roots.sort_unstable();
+
+    let mut cascades = Vec::with_capacity(roots.len());
+    for root in roots {
+        let mut cascade = QuoteCascade {
+            root_uri: root.to_string(),
+            depth: 0,
+            total_quotes: 0,
+            max_branching: 0,
+            participant_depths: HashMap::new(),
+        };
+
+        let mut visited: HashSet<&str> = HashSet::new();
+        visited.insert(root);
+        let mut frontier = vec![root];
+        let mut level = 0;
+
+        while !frontier.is_empty() {
+            level += 1;
+            let mut next = Vec::new();
+            for uri in frontier {
+                let Some(kids) = children.get(uri) else {
+                    continue;
+                };
+                cascade.max_branching = cascade.max_branching.max(kids.len());
+                for &(child, did) in kids {
+                    if !visited.insert(child) {
+                        continue;
+                    }
+                    cascade.total_quotes += 1;
+                    cascade.depth = level;
+                    cascade
+                        .participant_depths
+                        .entry(did.to_string())
+                        .or_insert(level);
+                    next.push(child);
+                }
+            }
+            frontier = next;
+        }
+
+        cascades.push(cascade);
+    }
+
+    cascades
+}
+
+/// DIDs of accounts near the root of any detected cascade.
+///
+/// These are the accounts whose quotes set the cascade off. Callers fold
+/// them into the same coordinated-attack signal as pile-on participants, so
+/// they pick up the pile-on boost in `compute_behavioral_boost`.
+pub fn cascade_root_participants(cascades: &[QuoteCascade]) -> HashSet<String> {
+    cascades
+        .iter()
+        .filter(|c| c.is_cascade())
+        .flat_map(|c| c.participant_depths.iter())
+        .filter(|(_, &depth)| depth <= CASCADE_ROOT_DEPTH)
+        .map(|(did, _)| did.clone())
+        .collect()
+}
+
+// ============================================================
+// Follower snapshots ("recently followed an amplifier")
+// ============================================================
+
+/// Most follower hashes kept in one amplifier's snapshot. Follower slices
+/// rotate through large lists across scans, so snapshots accumulate; this
+/// keeps a single row from growing without bound.
+pub const MAX_SNAPSHOT_FOLLOWERS: usize = 5000;
+
+/// Stable 64-bit FNV-1a hash of a follower DID, as 16 hex chars. Snapshots
+/// store these instead of DIDs — compact, and the same on every platform
+/// and Rust version, unlike `DefaultHasher`.
+pub fn hash_follower_did(did: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in did.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// Followers that appeared since the previous snapshot.
+///
+/// `followers` must be newest-first, as the follow graph returns them. Only
+/// the run of unknown followers *above* the first already-snapshotted one
+/// counts as new: below that point an unknown follower may just be an old
+/// one the previous scan's slice never reached. With no previous snapshot,
+/// or no overlap with it, nothing can be called new.
+pub fn detect_new_followers(followers: &[&str], previous: &HashSet<String>) -> HashSet<String> {
+    let mut new = HashSet::new();
+    for did in followers {
+        if previous.contains(&hash_follower_did(did)) {
+            return new;
+        }
+        new.insert(did.to_string());
+    }
+    // Never reached a known follower — no anchor to compare against
+    HashSet::new()
+}
+
+/// The snapshot to store after a scan: the current slice's hashes first,
+/// then the previous snapshot's, deduplicated and capped at
+/// `MAX_SNAPSHOT_FOLLOWERS`.
+pub fn merge_follower_snapshot(followers: &[&str], previous: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    followers
+        .iter()
+        .map(|did| hash_follower_did(did))
+        .chain(previous.iter().cloned())
+        .filter(|h| seen.insert(h.clone()))
+        .take(MAX_SNAPSHOT_FOLLOWERS)
+        .collect()
+}
+
+/// Set `recently_followed_amplifier` on a score's stored behavioral
+/// signals. Scores without signals (insufficient data, early exits) get
+/// default signals carrying just the flag.
+pub fn mark_recently_followed(score: &mut AccountScore) {
+    let mut signals = score
+        .behavioral_signals
+        .as_deref()
+        .and_then(|json| serde_json::from_str::<BehavioralSignals>(json).ok())
+        .unwrap_or_default();
+    signals.recently_followed_amplifier = true;
+    score.behavioral_signals = serde_json::to_string(&signals).ok();
+}
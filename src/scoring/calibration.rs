@@ -0,0 +1,236 @@
+// Per-backend toxicity calibration.
+//
+// ONNX and Perspective produce differently-distributed toxicity scores, so
+// the same tier thresholds carve their accounts up differently. Calibration
+// maps each backend's toxicity onto a shared reference distribution before
+// the threat formula sees it, so "High" means roughly the same thing
+// whichever backend produced the score.
+//
+// Methodology: `charcoal calibrate` takes the toxicity scores of every
+// full-pipeline account in the database (Stage 1 early exits are skipped —
+// they're recorded as 0.0 and would pin the median) and fits a linear map
+// that sends the backend's median and 90th percentile onto REFERENCE_MEDIAN
+// and REFERENCE_P90. The result is a multiplier and offset per backend,
+// stored as plain JSON next to the database so it can be read, diffed, or
+// hand-edited. Calibration is off unless CHARCOAL_CALIBRATION is enabled;
+// stored toxicity scores stay raw, only threat scores use the mapping.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::{Config, ScorerBackend};
+use crate::db::models::AccountScore;
+use crate::scoring::threat::ThreatWeights;
+
+/// Median toxicity the tier thresholds were tuned against.
+pub const REFERENCE_MEDIAN: f64 = 0.05;
+
+/// 90th-percentile toxicity the tier thresholds were tuned against.
+pub const REFERENCE_P90: f64 = 0.40;
+
+/// Fewest scores `fit` will calibrate from. Percentiles of a handful of
+/// accounts say more about those accounts than about the backend.
+pub const MIN_CALIBRATION_SAMPLES: usize = 50;
+
+/// Bounds on the fitted multiplier, so a skewed database can't produce a
+/// mapping that flattens or explodes every score.
+const MULTIPLIER_RANGE: (f64, f64) = (0.25, 4.0);
+
+/// File name of the calibration store inside the data directory.
+pub const CALIBRATION_FILE: &str = "calibration.json";
+
+/// A linear toxicity mapping for one scorer backend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Calibration {
+    pub multiplier: f64,
+    pub offset: f64,
+    /// Scores the fit was computed from
+    pub sample_size: usize,
+    /// The backend's median toxicity at fit time
+    pub observed_median: f64,
+    /// The backend's 90th-percentile toxicity at fit time
+    pub observed_p90: f64,
+    /// RFC 3339 time of the fit
+    pub computed_at: String,
+}
+
+impl Calibration {
+    /// Map a raw toxicity score onto the reference scale (clamped to 0–1).
+    pub fn apply(&self, toxicity: f64) -> f64 {
+        (toxicity * self.multiplier + self.offset).clamp(0.0, 1.0)
+    }
+}
+
+/// Every backend's calibration, as stored in `calibration.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationStore {
+    pub backends: BTreeMap<String, Calibration>,
+}
+
+impl CalibrationStore {
+    /// Read the store, or an empty one when the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("Invalid {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Where the calibration store lives for this config.
+pub fn calibration_path(config: &Config) -> PathBuf {
+    config.data_dir().join(CALIBRATION_FILE)
+}
+
+/// The toxicity scores a calibration should be fitted from: full-pipeline
+/// accounts only. Early exits and unscored accounts would skew the fit.
+pub fn calibration_samples(scores: &[AccountScore]) -> Vec<f64> {
+    scores
+        .iter()
+        .filter(|s| s.scoring_confidence.as_deref() != Some("low"))
+        .filter_map(|s| s.toxicity_score)
+        .collect()
+}
+
+/// Fit a calibration that maps the samples' median and 90th percentile
+/// onto the reference anchors.
+pub fn fit(samples: &[f64]) -> Result<Calibration> {
+    if samples.len() < MIN_CALIBRATION_SAMPLES {
+        anyhow::bail!(
+            "Need at least {MIN_CALIBRATION_SAMPLES} fully scored accounts to calibrate, found {}",
+            samples.len()
+        );
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let median = percentile(&sorted, 0.5);
+    let p90 = percentile(&sorted, 0.9);
+
+    if p90 - median < 0.01 {
+        anyhow::bail!(
+            "Toxicity scores are too tightly clustered to calibrate \
+             (median {median:.3}, p90 {p90:.3})"
+        );
+    }
+
+    let multiplier = ((REFERENCE_P90 - REFERENCE_MEDIAN) / (p90 - median))
+        .clamp(MULTIPLIER_RANGE.0, MULTIPLIER_RANGE.1);
+    let offset = REFERENCE_MEDIAN - multiplier * median;
+
+    Ok(Calibration {
+        multiplier,
+        offset,
+        sample_size: samples.len(),
+        observed_median: median,
+        observed_p90: p90,
+        computed_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+/// Nearest-rank percentile of an already-sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = ((sorted.len() as f64 * p).ceil() as usize).clamp(1, sorted.len());
+    sorted[rank - 1]
+}
+
+/// Threat weights for scoring with `backend`: the defaults plus that
+/// backend's stored calibration when CHARCOAL_CALIBRATION is on. The rest
+/// of the configured scoring behavior is `profile::ScoringOptions::from_config`.
+///
+/// A missing or unreadable calibration logs a warning and scores
+/// uncalibrated rather than failing the scan.
+pub fn threat_weights(config: &Config, backend: &ScorerBackend) -> ThreatWeights {
+    let mut weights = ThreatWeights::default();
+    if !config.calibration_enabled {
+        return weights;
+    }
+
+    let path = calibration_path(config);
+    match CalibrationStore::load(&path) {
+        Ok(mut store) => match store.backends.remove(backend.as_str()) {
+            Some(calibration) => weights.toxicity_calibration = Some(calibration),
+            None => warn!(
+                backend = backend.as_str(),
+                "Calibration enabled but none stored for this backend — run `charcoal calibrate`"
+            ),
+        },
+        Err(e) => warn!(error = %e, "Could not load calibration, scoring uncalibrated"),
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_maps_anchors_onto_reference() {
+        // Uniform 0.00..=0.99 — median 0.49, p90 0.89
+        let samples: Vec<f64> = (0..100).map(|i| i as f64 / 100.0).collect();
+        let cal = fit(&samples).unwrap();
+
+        assert_eq!(cal.sample_size, 100);
+        assert!((cal.apply(cal.observed_median) - REFERENCE_MEDIAN).abs() < 1e-9);
+        assert!((cal.apply(cal.observed_p90) - REFERENCE_P90).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fit_rejects_small_or_flat_samples() {
+        assert!(fit(&[0.1; 10]).is_err());
+        assert!(fit(&[0.2; 100]).is_err());
+    }
+
+    #[test]
+    fn test_apply_clamps_to_unit_range() {
+        let cal = Calibration {
+            multiplier: 2.0,
+            offset: -0.1,
+            sample_size: 0,
+            observed_median: 0.0,
+            observed_p90: 0.0,
+            computed_at: String::new(),
+        };
+        assert_eq!(cal.apply(0.0), 0.0);
+        assert_eq!(cal.apply(0.9), 1.0);
+        assert!((cal.apply(0.3) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_store_roundtrip() {
+        let dir = std::env::temp_dir().join("charcoal-calibration-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}-{CALIBRATION_FILE}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        assert!(CalibrationStore::load(&path).unwrap().backends.is_empty());
+
+        let samples: Vec<f64> = (0..60).map(|i| i as f64 / 60.0).collect();
+        let mut store = CalibrationStore::default();
+        store
+            .backends
+            .insert("perspective".to_string(), fit(&samples).unwrap());
+        store.save(&path).unwrap();
+
+        assert_eq!(CalibrationStore::load(&path).unwrap(), store);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let config = Config::test_defaults();
+        let weights = threat_weights(&config, &ScorerBackend::Onnx);
+        assert!(weights.toxicity_calibration.is_none());
+    }
+}
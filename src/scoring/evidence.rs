@@ -0,0 +1,77 @@
+// Evidence strength — how much evidence a threat score rests on.
+//
+// A High score from 50 posts means more than one from 4. This is separate
+// from `scoring_confidence`, which records how deep the pipeline went
+// (stage-1 early exit or a full run). `compute_evidence_strength` turns what
+// a score was built from into a number between 0 and 1:
+//
+//   - Volume: strength grows with the square root of posts analyzed and
+//     tops out at `FULL_EVIDENCE_POSTS`, so the first few posts count most.
+//   - Overlap method: keyword (TF-IDF) overlap is a rougher measure than
+//     embedding similarity, so it costs `KEYWORD_OVERLAP_FACTOR`.
+//   - Consistency: posts whose toxicity varies widely make the average a
+//     shakier summary. The factor is 1 minus the standard deviation of the
+//     per-post toxicity scores, which can't go below 0.5 for scores in [0, 1].
+//
+// The three multiply. `evidence_label` buckets the result for display.
+
+use crate::topics::overlap::OverlapMethod;
+
+/// Posts analyzed at which volume stops limiting evidence strength (the
+/// full stage-2 sample).
+pub const FULL_EVIDENCE_POSTS: usize = 50;
+
+/// Evidence strength multiplier for scores whose topic overlap came from
+/// TF-IDF keywords rather than embeddings.
+pub const KEYWORD_OVERLAP_FACTOR: f64 = 0.85;
+
+/// Strength below which the evidence is labelled "weak".
+pub const WEAK_EVIDENCE_BELOW: f64 = 0.4;
+
+/// Strength below which the evidence is labelled "moderate" (and at or
+/// above which it's "strong").
+pub const MODERATE_EVIDENCE_BELOW: f64 = 0.7;
+
+/// How much evidence a score rests on, from 0.0 (none) to 1.0.
+///
+/// `post_toxicities` are the per-post toxicity scores, each in [0, 1]; pass
+/// an empty slice when toxicity wasn't scored (overlap-only scans) and only
+/// volume and method count.
+pub fn compute_evidence_strength(
+    posts_analyzed: usize,
+    overlap_method: OverlapMethod,
+    post_toxicities: &[f64],
+) -> f64 {
+    let volume =
+        (posts_analyzed.min(FULL_EVIDENCE_POSTS) as f64 / FULL_EVIDENCE_POSTS as f64).sqrt();
+    let method = match overlap_method {
+        OverlapMethod::Embedding => 1.0,
+        OverlapMethod::Keyword => KEYWORD_OVERLAP_FACTOR,
+    };
+    let consistency = 1.0 - std_dev(post_toxicities);
+
+    (volume * method * consistency).clamp(0.0, 1.0)
+}
+
+/// "weak", "moderate", or "strong" for a strength from
+/// `compute_evidence_strength`.
+pub fn evidence_label(strength: f64) -> &'static str {
+    if strength < WEAK_EVIDENCE_BELOW {
+        "weak"
+    } else if strength < MODERATE_EVIDENCE_BELOW {
+        "moderate"
+    } else {
+        "strong"
+    }
+}
+
+/// Population standard deviation; 0.0 for fewer than two values.
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    variance.sqrt()
+}
@@ -0,0 +1,163 @@
+// Hard-block keyword overrides.
+//
+// Some content — slurs, explicit threats — is serious enough that an account
+// posting it belongs in the High tier no matter how far its topics are from
+// the protected user's. The threat formula can't express that: low topic
+// overlap gates the score, and the toxicity model may under-rate short or
+// coded phrasing. Hard-block patterns bypass both.
+//
+// Patterns are regexes read from the file named by CHARCOAL_HARDBLOCK_PATTERNS,
+// one per line; blank lines and lines starting with `#` are ignored. Every
+// pattern matches case-insensitively and only on whole words, so "ass" does
+// not fire on "classic". No patterns ship with Charcoal — the list is the
+// user's to write.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use regex_lite::Regex;
+
+use crate::db::models::ToxicPost;
+
+/// A compiled list of hard-block patterns.
+#[derive(Debug, Clone, Default)]
+pub struct HardblockPatterns {
+    /// (pattern as written, compiled whole-word case-insensitive regex)
+    patterns: Vec<(String, Regex)>,
+}
+
+impl HardblockPatterns {
+    /// Compile patterns from the text of a pattern file. Fails on the first
+    /// invalid regex, naming its line.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut patterns = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let pattern = line.trim();
+            if pattern.is_empty() || pattern.starts_with('#') {
+                continue;
+            }
+            let regex = Regex::new(&format!(r"(?i)\b(?:{pattern})\b"))
+                .with_context(|| format!("Invalid hard-block pattern on line {}", i + 1))?;
+            patterns.push((pattern.to_string(), regex));
+        }
+        Ok(Self { patterns })
+    }
+
+    /// Read and compile a pattern file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read hard-block patterns {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Failed to load {}", path.display()))
+    }
+
+    /// The first pattern (as written) that matches `text`, if any.
+    pub fn first_match(&self, text: &str) -> Option<&str> {
+        self.patterns
+            .iter()
+            .find(|(_, regex)| regex.is_match(text))
+            .map(|(pattern, _)| pattern.as_str())
+    }
+
+    /// Whether any pattern matches `text`.
+    pub fn contains_hardblock(&self, text: &str) -> bool {
+        self.first_match(text).is_some()
+    }
+
+    /// Number of patterns loaded.
+    pub fn len(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Whether no patterns are loaded.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
+
+/// Evidence for a hard-blocked account: the matching posts first (each
+/// carrying its `hardblock_pattern`), then the usual evidence minus any post
+/// already shown, up to `limit` posts in all.
+pub fn merge_hardblock_evidence(
+    hits: Vec<ToxicPost>,
+    evidence: Vec<ToxicPost>,
+    limit: usize,
+) -> Vec<ToxicPost> {
+    let mut merged = hits;
+    merged.truncate(limit);
+    for post in evidence {
+        if merged.len() >= limit {
+            break;
+        }
+        if !merged.iter().any(|m| m.uri == post.uri) {
+            merged.push(post);
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(uri: &str, hardblock_pattern: Option<&str>) -> ToxicPost {
+        ToxicPost {
+            text: String::new(),
+            toxicity: 0.5,
+            uri: uri.to_string(),
+            attributes: None,
+            created_at: None,
+            hardblock_pattern: hardblock_pattern.map(str::to_string),
+            flagged_for: None,
+        }
+    }
+
+    #[test]
+    fn test_match_is_case_insensitive() {
+        let patterns = HardblockPatterns::parse("ass\nkill (yo)?u").unwrap();
+        assert!(patterns.contains_hardblock("what an ASS"));
+        assert!(patterns.contains_hardblock("I will Kill You"));
+        assert_eq!(patterns.first_match("KILL U"), Some("kill (yo)?u"));
+    }
+
+    #[test]
+    fn test_match_respects_word_boundaries() {
+        let patterns = HardblockPatterns::parse("ass").unwrap();
+        assert!(!patterns.contains_hardblock("a classic album"));
+        assert!(!patterns.contains_hardblock("passing through"));
+        assert!(!patterns.contains_hardblock("assessment"));
+        assert!(patterns.contains_hardblock("you ass."));
+        assert!(patterns.contains_hardblock("ass"));
+    }
+
+    #[test]
+    fn test_parse_skips_comments_and_blank_lines() {
+        let patterns = HardblockPatterns::parse("# slurs\n\n  foo  \n#bar\n").unwrap();
+        assert_eq!(patterns.len(), 1);
+        assert!(patterns.contains_hardblock("FOO"));
+        assert!(!patterns.contains_hardblock("bar"));
+
+        assert!(HardblockPatterns::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_regex() {
+        let err = HardblockPatterns::parse("fine\n(unclosed").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+    }
+
+    #[test]
+    fn test_merge_puts_hits_first_without_duplicates() {
+        let merged = merge_hardblock_evidence(
+            vec![post("at://hit", Some("foo"))],
+            vec![
+                post("at://a", None),
+                post("at://hit", None),
+                post("at://b", None),
+            ],
+            3,
+        );
+        let uris: Vec<&str> = merged.iter().map(|p| p.uri.as_str()).collect();
+        assert_eq!(uris, vec!["at://hit", "at://a", "at://b"]);
+        assert_eq!(merged[0].hardblock_pattern.as_deref(), Some("foo"));
+    }
+}
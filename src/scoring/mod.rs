@@ -1,8 +1,12 @@
 // Threat scoring — combines toxicity and topic overlap into a ranked score.
 
 pub mod behavioral;
+pub mod calibration;
 pub mod context;
+pub mod evidence;
+pub mod keywords;
 pub mod nli;
 pub mod nli_audit;
 pub mod profile;
+pub mod recency;
 pub mod threat;
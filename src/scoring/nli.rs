@@ -103,33 +103,34 @@ pub struct NliScorer {
 
 impl NliScorer {
     /// Load the NLI model and tokenizer from the nli-deberta-v3-xsmall subdirectory.
-    pub fn load(model_dir: &Path) -> Result<Self> {
+    pub fn load(model_dir: &Path) -> crate::error::Result<Self> {
         let nli_dir = crate::toxicity::download::nli_model_dir(model_dir);
         let model_path = nli_dir.join("model_quantized.onnx");
         let tokenizer_path = nli_dir.join("tokenizer.json");
 
         if !model_path.exists() {
-            anyhow::bail!(
-                "NLI model not found: {}\nRun `charcoal download-model` to download it.",
-                model_path.display()
-            );
+            return Err(crate::error::Error::ModelMissing { path: model_path });
         }
         if !tokenizer_path.exists() {
-            anyhow::bail!(
-                "NLI tokenizer not found: {}\nRun `charcoal download-model` to download it.",
-                tokenizer_path.display()
-            );
+            return Err(crate::error::Error::ModelMissing {
+                path: tokenizer_path,
+            });
         }
 
-        let session = Session::builder()
-            .context("Failed to create NLI ONNX session builder")?
-            .commit_from_file(&model_path)
-            .with_context(|| {
-                format!(
-                    "Failed to load NLI ONNX model from {}",
-                    model_path.display()
-                )
-            })?;
+        crate::toxicity::download::verify_sidecar(&model_path)?;
+        crate::toxicity::download::verify_sidecar(&tokenizer_path)?;
+
+        let session = crate::toxicity::onnx::load_with_retry(|| {
+            Session::builder()
+                .context("Failed to create NLI ONNX session builder")?
+                .commit_from_file(&model_path)
+                .with_context(|| {
+                    format!(
+                        "Failed to load NLI ONNX model from {}",
+                        model_path.display()
+                    )
+                })
+        })?;
 
         let tokenizer = Tokenizer::from_file(&tokenizer_path)
             .map_err(|e| anyhow::anyhow!("Failed to load NLI tokenizer: {}", e))?;
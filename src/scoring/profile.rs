@@ -5,25 +5,266 @@
 // 2. Runs toxicity scoring on those posts
 // 3. Builds their topic fingerprint
 // 4. Computes topic overlap with the protected user
-// 5. Calculates the combined threat score
+// 5. Calculates the combined threat score, weighted by graph distance and
+//    any negative labels from trusted labelers
 // 6. Returns a complete AccountScore ready for storage
+//
+// Stage 1 takes a keyword (TF-IDF) overlap reading before step 2, so
+// `--min-overlap` can skip toxicity scoring for off-topic accounts.
 
 use anyhow::Result;
 use tracing::{info, warn};
 
 use crate::bluesky::client::PublicAtpClient;
+use crate::bluesky::labels::{self, DEFAULT_LABELER_DID};
 use crate::bluesky::posts::{self, FingerprintQuality, Post};
-use crate::bluesky::relationships::GraphDistance;
-use crate::db::models::{AccountScore, ToxicPost};
+use crate::bluesky::relationships::{self, GraphDistance};
+use crate::config::{Config, EvidenceOrder};
+use crate::db::models::{AccountScore, TierThresholds, ToxicPost};
 use crate::scoring::behavioral;
+use crate::scoring::evidence;
+use crate::scoring::keywords::{self, HardblockPatterns};
 use crate::scoring::nli::NliScorer;
+use crate::scoring::recency;
 use crate::scoring::threat::{self, ThreatWeights};
 use crate::topics::embeddings::{self, SentenceEmbedder};
-use crate::topics::fingerprint::TopicFingerprint;
-use crate::topics::overlap;
+use crate::topics::overlap::{
+    self, OverlapBreakdown, OverlapMethod, PreparedFingerprint, DEFAULT_MIN_OVERLAP_POSTS,
+};
 use crate::topics::tfidf::TfIdfExtractor;
 use crate::topics::traits::TopicExtractor;
-use crate::toxicity::traits::ToxicityScorer;
+use crate::toxicity::language;
+use crate::toxicity::traits::{
+    dominant_attribute, weighted_score, AttributeWeights, BinaryVerdict, ToxicityAttributes,
+    ToxicityScorer,
+};
+
+/// How many posts Stage 1 fetches for its quick check.
+const STAGE1_SAMPLE_SIZE: usize = 25;
+
+/// Default minimum post count before an account is worth scoring. Below this,
+/// one or two posts swing the toxicity rate and topic overlap wildly.
+pub const DEFAULT_MIN_POSTS: usize = 5;
+
+/// How an account gets scored, as opposed to the formula its score comes
+/// from (`ThreatWeights`). Part of the `ScoringContext` passed to
+/// `build_profile`.
+#[derive(Debug, Clone)]
+pub struct ScoringOptions {
+    /// Which posts are kept as evidence (default most toxic).
+    pub evidence_order: EvidenceOrder,
+    /// Score from topic overlap alone, without running the toxicity scorer
+    /// (default false). See `threat::compute_overlap_only_score`.
+    pub overlap_only: bool,
+    /// Score at full overlap when scoring from topic overlap alone (default
+    /// 30.0). Kept below the High tier: overlap alone says an account is in
+    /// the user's topic space, not that it's hostile.
+    pub overlap_only_weight: f64,
+    /// Fetch recent replies on top of the post sample and score them with
+    /// it (default false). See `bluesky::posts::fetch_recent_replies`.
+    pub score_replies: bool,
+    /// Only posts from this many days back are scored (default None, no
+    /// limit). Set per command by `--max-post-age-days`; see
+    /// `scoring::recency`.
+    pub max_post_age_days: Option<u32>,
+    /// Stage 1 keyword overlap below which an account skips toxicity
+    /// inference and is recorded Low with no toxicity score (default None,
+    /// off). Set per command by `--min-overlap`; see `below_min_overlap`.
+    pub min_overlap: Option<f64>,
+    /// Patterns that force an account to at least the High tier regardless
+    /// of the formula (default None). See `scoring::keywords`.
+    pub hardblock: Option<HardblockPatterns>,
+    /// Tier boundaries for the tier recorded with each score, and the floor
+    /// a hard-block match raises the score to (default `TierThresholds::DEFAULT`).
+    pub tier_thresholds: TierThresholds,
+    /// Labelers whose negative labels raise an account's score (default the
+    /// Bluesky moderation service). Empty skips the label lookup entirely.
+    /// See `bluesky::labels`.
+    pub trusted_labelers: Vec<String>,
+    /// How toxicity categories combine into each post's effective toxicity
+    /// (default `AttributeWeights::default()`). See
+    /// `toxicity::traits::weighted_score`.
+    pub attribute_weights: AttributeWeights,
+    /// Fewest posts for an embedding-based topic overlap (default
+    /// `DEFAULT_MIN_OVERLAP_POSTS`). See `topics::overlap::choose_overlap_method`.
+    pub min_overlap_posts: usize,
+}
+
+impl Default for ScoringOptions {
+    fn default() -> Self {
+        Self {
+            evidence_order: EvidenceOrder::default(),
+            overlap_only: false,
+            overlap_only_weight: 30.0,
+            score_replies: false,
+            max_post_age_days: None,
+            min_overlap: None,
+            hardblock: None,
+            tier_thresholds: TierThresholds::DEFAULT,
+            trusted_labelers: vec![DEFAULT_LABELER_DID.to_string()],
+            attribute_weights: AttributeWeights::default(),
+            min_overlap_posts: DEFAULT_MIN_OVERLAP_POSTS,
+        }
+    }
+}
+
+impl ScoringOptions {
+    /// The options set in `config`; overlap-only scoring, the post window
+    /// and `--min-overlap` default to off and are set per command.
+    ///
+    /// An unreadable hard-block file logs a warning and scores without
+    /// overrides rather than failing the scan.
+    pub fn from_config(config: &Config) -> Self {
+        let hardblock = config.hardblock_patterns.as_deref().and_then(|path| {
+            match HardblockPatterns::load(path) {
+                Ok(patterns) => Some(patterns),
+                Err(e) => {
+                    warn!(error = %e, "Could not load hard-block patterns, scoring without them");
+                    None
+                }
+            }
+        });
+        Self {
+            evidence_order: config.evidence_order,
+            score_replies: config.score_replies,
+            hardblock,
+            tier_thresholds: config.tier_thresholds(),
+            trusted_labelers: config.trusted_labelers.clone(),
+            attribute_weights: config.attribute_weights,
+            min_overlap_posts: config.min_overlap_posts,
+            ..Self::default()
+        }
+    }
+}
+
+/// Whether an account gets the stranger-quote behavioral boost: it quoted
+/// or replied to the protected user and follows neither way. A graph
+/// distance passed in by the caller already weights a stranger's final
+/// score (`GraphDistance::threat_weight`), so the boost only applies when
+/// the relationship was looked up here instead.
+pub fn is_stranger_quote(
+    quoted_you: bool,
+    relationship: Option<GraphDistance>,
+    graph_distance: Option<GraphDistance>,
+) -> bool {
+    quoted_you && graph_distance.is_none() && relationship == Some(GraphDistance::Stranger)
+}
+
+/// Whether an account has too few posts to produce a meaningful score.
+///
+/// The threshold is capped at the Stage 1 sample size — we never look at
+/// more than that many posts before deciding, so a higher threshold would
+/// skip every account.
+pub fn has_insufficient_posts(total_posts: usize, min_posts: usize) -> bool {
+    total_posts < min_posts.min(STAGE1_SAMPLE_SIZE)
+}
+
+/// Whether an account's Stage 1 keyword overlap is below `--min-overlap`,
+/// so toxicity inference can be skipped for it. Never true with no threshold
+/// set, or when the overlap is unknown (TF-IDF extraction failed).
+pub fn below_min_overlap(topic_overlap: Option<f64>, min_overlap: Option<f64>) -> bool {
+    match (topic_overlap, min_overlap) {
+        (Some(overlap), Some(min)) => overlap < min,
+        _ => false,
+    }
+}
+
+/// The placeholder score recorded for accounts skipped by the minimum-posts
+/// filter. No toxicity, overlap, or threat score is set, so the account
+/// can't be mistaken for a genuinely low-risk one.
+pub fn insufficient_data_score(
+    target_did: &str,
+    target_handle: &str,
+    posts_seen: usize,
+    graph_distance: Option<GraphDistance>,
+) -> AccountScore {
+    AccountScore {
+        did: target_did.to_string(),
+        handle: target_handle.to_string(),
+        toxicity_score: None,
+        topic_overlap: None,
+        threat_score: None,
+        threat_tier: Some("Insufficient Data".to_string()),
+        posts_analyzed: posts_seen as u32,
+        top_toxic_posts: vec![],
+        scored_at: String::new(),
+        behavioral_signals: None,
+        context_score: None,
+        // Preserve the graph distance even on insufficient-data accounts —
+        // it was computed by the caller and is independent of the post
+        // sample. Downstream consumers (sweep ranking, UI) want it.
+        graph_distance: graph_distance.map(|d| d.as_str().to_string()),
+        fingerprint_quality: None,
+        scoring_confidence: None,
+        overlap_method: None,
+        last_active_at: None,
+        origin_amplifier_did: None,
+        raw_threat_score: None,
+        overlap_post_count: None,
+        replies_analyzed: None,
+        evidence_strength: None,
+        whitelisted: false,
+        peak_toxicity_attributes: None,
+    }
+}
+
+/// The placeholder score recorded for accounts with no posts in a language
+/// the toxicity model supports. Like `insufficient_data_score`, nothing is
+/// scored — a toxicity rate computed from out-of-language posts is noise.
+pub fn unsupported_language_score(
+    target_did: &str,
+    target_handle: &str,
+    posts_seen: usize,
+    graph_distance: Option<GraphDistance>,
+) -> AccountScore {
+    AccountScore {
+        threat_tier: Some("Unsupported Language".to_string()),
+        ..insufficient_data_score(target_did, target_handle, posts_seen, graph_distance)
+    }
+}
+
+/// The score recorded for an account on the user's whitelist: Low tier with
+/// a zero threat score and `whitelisted` set, noting that the tier comes from
+/// the whitelist rather than any analysis. No posts are fetched and no
+/// toxicity inference runs.
+pub fn whitelisted_score(target_did: &str, target_handle: &str) -> AccountScore {
+    AccountScore {
+        threat_score: Some(0.0),
+        threat_tier: Some("Low".to_string()),
+        posts_analyzed: 0,
+        whitelisted: true,
+        ..insufficient_data_score(target_did, target_handle, 0, None)
+    }
+}
+
+/// Everything `build_profile` needs that stays the same across a batch of
+/// accounts: the clients and models, the protected user's side of every
+/// comparison, and how scoring is configured.
+#[derive(Clone, Copy)]
+pub struct ScoringContext<'a> {
+    pub client: &'a PublicAtpClient,
+    pub scorer: &'a dyn ToxicityScorer,
+    /// The protected user's fingerprint, prepared once per batch
+    pub protected_fingerprint: &'a PreparedFingerprint,
+    pub weights: &'a ThreatWeights,
+    pub options: &'a ScoringOptions,
+    pub embedder: Option<&'a SentenceEmbedder>,
+    pub protected_embedding: Option<&'a [f64]>,
+    pub median_engagement: f64,
+    pub pile_on_dids: &'a std::collections::HashSet<String>,
+    /// DIDs on the user's whitelist, recorded with `whitelisted_score`
+    /// instead of being scored
+    pub whitelist: &'a std::collections::HashSet<String>,
+    pub nli_scorer: Option<&'a NliScorer>,
+    pub protected_posts_with_embeddings: Option<&'a [(String, Vec<f64>)]>,
+    /// Looked up against for accounts that quoted or replied to the
+    /// protected user; see `build_profile`
+    pub protected_did: Option<&'a str>,
+    /// Where scored NLI pairs are cached, when caching is on
+    pub data_dir: Option<&'a std::path::Path>,
+    /// Fewest posts in the first sample for an account to be scored
+    pub min_posts: usize,
+}
 
 /// Build a complete threat profile for a single account.
 ///
@@ -31,69 +272,145 @@ use crate::toxicity::traits::ToxicityScorer;
 /// scores them for toxicity, extracts their topics, and computes the
 /// combined threat score against the protected user's fingerprint.
 ///
-/// When `embedder` and `protected_embedding` are provided, topic overlap
-/// is computed using sentence embeddings (semantic similarity). Otherwise,
-/// falls back to TF-IDF keyword cosine similarity.
-#[allow(clippy::too_many_arguments)]
+/// When `ctx.embedder` and `ctx.protected_embedding` are provided, topic
+/// overlap is computed using sentence embeddings (semantic similarity).
+/// Otherwise, falls back to TF-IDF keyword cosine similarity. The protected
+/// fingerprint is passed pre-prepared so batch callers compute its weights
+/// only once.
+///
+/// Accounts with fewer than `ctx.min_posts` posts in the first sample are
+/// not scored at all — they come back marked "Insufficient Data" instead of
+/// with a misleadingly low score. Pass `DEFAULT_MIN_POSTS` unless the caller
+/// has a reason to be stricter.
+///
+/// With `options.min_overlap` set, topic overlap is computed from the Stage 1
+/// sample before any toxicity inference, and an account below it is recorded
+/// as Low with `toxicity_score: None` — the scorer never runs for it. A
+/// hard-block match still gets the full pipeline.
+///
+/// An account with `direct_pairs` (it quoted or replied to the protected
+/// user) and no `graph_distance` has its relationship to `ctx.protected_did`
+/// looked up via `getRelationships`, so a stranger quoting the protected
+/// user gets the behavioral boost for it.
+///
+/// A DID in `ctx.whitelist` comes back as `whitelisted_score` before any
+/// posts are fetched.
+///
+/// Failures come back as a typed [`crate::error::Error`], so a rate-limited
+/// or missing account can be told apart from anything else.
 pub async fn build_profile(
-    client: &PublicAtpClient,
-    scorer: &dyn ToxicityScorer,
+    ctx: &ScoringContext<'_>,
     target_handle: &str,
     target_did: &str,
-    protected_fingerprint: &TopicFingerprint,
-    weights: &ThreatWeights,
-    embedder: Option<&SentenceEmbedder>,
-    protected_embedding: Option<&[f64]>,
-    median_engagement: f64,
-    pile_on_dids: &std::collections::HashSet<String>,
-    nli_scorer: Option<&NliScorer>,
-    protected_posts_with_embeddings: Option<&[(String, Vec<f64>)]>,
     direct_pairs: Option<&[(String, String)]>,
-    data_dir: Option<&std::path::Path>,
     graph_distance: Option<GraphDistance>,
-) -> Result<AccountScore> {
+) -> crate::error::Result<AccountScore> {
+    let (score, _) = build_profile_inner(
+        ctx,
+        target_handle,
+        target_did,
+        direct_pairs,
+        graph_distance,
+        false,
+    )
+    .await?;
+    Ok(score)
+}
+
+/// `build_profile`, plus an `OverlapBreakdown` explaining the topic overlap
+/// for display. `None` when no overlap was computed (insufficient data,
+/// unsupported language).
+///
+/// With embeddings loaded this also extracts the target's TF-IDF
+/// fingerprint for the keyword view, which `build_profile` skips — use it
+/// for single accounts, not whole scans.
+pub async fn build_profile_explained(
+    ctx: &ScoringContext<'_>,
+    target_handle: &str,
+    target_did: &str,
+    direct_pairs: Option<&[(String, String)]>,
+    graph_distance: Option<GraphDistance>,
+) -> crate::error::Result<(AccountScore, Option<OverlapBreakdown>)> {
+    Ok(build_profile_inner(
+        ctx,
+        target_handle,
+        target_did,
+        direct_pairs,
+        graph_distance,
+        true,
+    )
+    .await?)
+}
+
+async fn build_profile_inner(
+    ctx: &ScoringContext<'_>,
+    target_handle: &str,
+    target_did: &str,
+    direct_pairs: Option<&[(String, String)]>,
+    graph_distance: Option<GraphDistance>,
+    explain_overlap: bool,
+) -> Result<(AccountScore, Option<OverlapBreakdown>)> {
+    let ScoringContext {
+        client,
+        scorer,
+        protected_fingerprint,
+        weights,
+        options,
+        embedder,
+        protected_embedding,
+        median_engagement,
+        pile_on_dids,
+        whitelist,
+        nli_scorer,
+        protected_posts_with_embeddings,
+        protected_did,
+        data_dir,
+        min_posts,
+    } = *ctx;
+
+    // Whitelisted accounts are trusted: nothing is fetched or scored
+    if whitelist.contains(target_did) {
+        info!(
+            handle = target_handle,
+            "Whitelisted, recorded as Low without scoring"
+        );
+        return Ok((whitelisted_score(target_did, target_handle), None));
+    }
+
     // ── Stage 1: Quick check with 25 posts ──
     // Fetch a small sample and run ONNX + TF-IDF overlap.
     // If the account is clearly clean AND topically irrelevant, exit early.
     // This catches ~50-60% of sweep accounts with minimal cost.
-    let stage1_sample = posts::fetch_posts_with_replies(client, target_handle, 25).await?;
+    let stage1_sample =
+        posts::fetch_posts_with_replies(client, target_handle, STAGE1_SAMPLE_SIZE).await?;
+
+    // The feed is newest-first, so the Stage 1 sample holds the latest post
+    let last_active_at = stage1_sample.last_post_at();
 
-    if stage1_sample.total_posts < 5 {
+    // With a post window set, older posts are dropped before anything is
+    // scored — an account quiet for the whole window has insufficient data
+    let now = chrono::Utc::now();
+    let (stage1_sample, _) = recency::retain_recent(&stage1_sample, options.max_post_age_days, now);
+
+    if has_insufficient_posts(stage1_sample.total_posts, min_posts) {
         info!(
             handle = target_handle,
             post_count = stage1_sample.total_posts,
+            min_posts = min_posts,
             "Insufficient posts for reliable scoring"
         );
-        return Ok(AccountScore {
-            did: target_did.to_string(),
-            handle: target_handle.to_string(),
-            toxicity_score: None,
-            topic_overlap: None,
-            threat_score: None,
-            threat_tier: Some("Insufficient Data".to_string()),
-            posts_analyzed: stage1_sample.total_posts as u32,
-            top_toxic_posts: vec![],
-            scored_at: String::new(),
-            behavioral_signals: None,
-            context_score: None,
-            // Preserve the graph distance even on insufficient-data accounts —
-            // it was computed by the caller and is independent of the post
-            // sample. Downstream consumers (sweep ranking, UI) want it.
-            graph_distance: graph_distance.map(|d| d.as_str().to_string()),
-            fingerprint_quality: None,
-            scoring_confidence: None,
-        });
+        let score = AccountScore {
+            last_active_at,
+            ..insufficient_data_score(
+                target_did,
+                target_handle,
+                stage1_sample.total_posts,
+                graph_distance,
+            )
+        };
+        return Ok((score, None));
     }
 
-    // Quick ONNX scores for clean-pass check.
-    //
-    // Originals + quotes are scored solo against ONNX_CLEAN_THRESHOLD — those
-    // are first-person posts and ONNX in isolation is a reliable "obviously
-    // clean" filter for them. Reply texts in isolation are NOT reliable: a
-    // benign-looking "I agree" only becomes hostile in conversation context,
-    // and stage 1 has no parent texts available. Excluding replies from the
-    // early-exit decision means reply-context-dependent toxicity makes it to
-    // stage 2 where Zentropi can do pair classification with parent text.
     let stage1_texts: Vec<String> = stage1_sample
         .originals
         .iter()
@@ -101,24 +418,17 @@ pub async fn build_profile(
         .chain(stage1_sample.replies.iter().map(|r| r.post.text.clone()))
         .chain(stage1_sample.quotes.iter().map(|p| p.text.clone()))
         .collect();
-    let stage1_onnx = scorer.score_batch(&stage1_texts).await?;
-    let originals_count = stage1_sample.originals.len();
-    let quotes_offset = originals_count + stage1_sample.replies.len();
-    let stage1_clean_pass_scores: Vec<f64> = stage1_onnx
+    let (stage1_scorable, _) = language::retain_scorable(&stage1_sample);
+    let stage1_score_texts: Vec<String> = stage1_scorable
+        .originals
         .iter()
-        .enumerate()
-        .filter_map(|(i, r)| {
-            // Keep only originals (indices 0..originals_count) and quotes
-            // (indices quotes_offset..). Skip replies (the middle range).
-            if i < originals_count || i >= quotes_offset {
-                Some(r.toxicity)
-            } else {
-                None
-            }
-        })
+        .map(|p| p.text.clone())
+        .chain(stage1_scorable.replies.iter().map(|r| r.post.text.clone()))
+        .chain(stage1_scorable.quotes.iter().map(|p| p.text.clone()))
         .collect();
 
-    // Preliminary topic overlap via TF-IDF (cheap, always available)
+    // Preliminary topic overlap via TF-IDF (cheap, always available). It's
+    // computed before any toxicity inference so `--min-overlap` can skip it.
     let stage1_fp_texts: Vec<String> = if stage1_sample.originals.len() >= 15 {
         stage1_sample
             .originals
@@ -128,44 +438,127 @@ pub async fn build_profile(
     } else {
         stage1_texts.clone()
     };
-    let stage1_overlap: Option<f64> = {
+    let stage1_fingerprint = {
         let topic_extractor = TfIdfExtractor {
             top_n_keywords: 40,
             max_clusters: 7,
         };
-        match topic_extractor.extract(&stage1_fp_texts) {
-            Ok(fp) => Some(overlap::cosine_similarity(protected_fingerprint, &fp)),
-            // TF-IDF extraction failed (e.g. no usable tokens). Treat overlap as
-            // unknown rather than 0.0 — the prior `Err => 0.0` path inverted the
-            // intent in the comment and let extraction failures slip through the
-            // early-exit gate as if the account were topically irrelevant.
-            Err(_) => None,
-        }
+        // If TF-IDF extraction fails (e.g. no usable tokens), overlap is
+        // unknown rather than 0.0 — the prior `Err => 0.0` path inverted the
+        // intent in the comment and let extraction failures slip through the
+        // early-exit gate as if the account were topically irrelevant.
+        topic_extractor.extract(&stage1_fp_texts).ok()
     };
+    let stage1_overlap: Option<f64> = stage1_fingerprint
+        .as_ref()
+        .map(|fp| overlap::cosine_against_prepared(protected_fingerprint, fp));
+
+    // A hard-block match anywhere in the sample always goes to stage 2.
+    let stage1_hardblock = options
+        .hardblock
+        .as_ref()
+        .is_some_and(|hb| stage1_texts.iter().any(|t| hb.contains_hardblock(t)));
+
+    // Below `--min-overlap`, the account is irrelevant whatever its
+    // toxicity, so the scorer doesn't run at all (overlap-only scans never
+    // run it anyway)
+    let skip_toxicity = !options.overlap_only
+        && !stage1_hardblock
+        && below_min_overlap(stage1_overlap, options.min_overlap);
+
+    // Quick ONNX scores for clean-pass check.
+    //
+    // Originals + quotes are scored solo against ONNX_CLEAN_THRESHOLD — those
+    // are first-person posts and ONNX in isolation is a reliable "obviously
+    // clean" filter for them. Reply texts in isolation are NOT reliable: a
+    // benign-looking "I agree" only becomes hostile in conversation context,
+    // and stage 1 has no parent texts available. Excluding replies from the
+    // early-exit decision means reply-context-dependent toxicity makes it to
+    // stage 2 where Zentropi can do pair classification with parent text.
+    //
+    // Only posts in a language the model supports are scored; the rest would
+    // only add noise. Topic overlap above saw every post.
+    //
+    // Overlap-only scans never call the scorer (it's a NoopScorer there)
+    let stage1_onnx = if options.overlap_only || skip_toxicity {
+        Vec::new()
+    } else {
+        scorer.score_batch(&stage1_score_texts).await?
+    };
+    let originals_count = stage1_scorable.originals.len();
+    let quotes_offset = originals_count + stage1_scorable.replies.len();
+    let stage1_clean_pass_scores: Vec<f64> = stage1_onnx
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| {
+            // Keep only originals (indices 0..originals_count) and quotes
+            // (indices quotes_offset..). Skip replies (the middle range).
+            if i < originals_count || i >= quotes_offset {
+                Some(weighted_score(r, &options.attribute_weights))
+            } else {
+                None
+            }
+        })
+        .collect();
 
     // Early exit: all ONNX scores clean AND topic overlap below gate.
-    // When overlap is unknown (extraction failed), do not early-exit.
-    if should_early_exit_stage1(
-        &stage1_clean_pass_scores,
-        stage1_overlap,
-        weights.overlap_gate_threshold,
-    ) {
-        info!(
-            handle = target_handle,
-            posts = stage1_sample.total_posts,
-            overlap = format!("{:.3}", stage1_overlap.unwrap_or(0.0)),
-            "Stage 1 early exit: clean and topically irrelevant"
-        );
+    // When overlap is unknown (extraction failed), do not early-exit. An
+    // empty scorable set isn't "clean" — stage 2 decides how to mark it.
+    // Overlap-only scans exit on overlap alone: below the gate they score 0.
+    // Accounts below `--min-overlap` exit unscored for toxicity.
+    //
+    // A hard-block match anywhere in the sample always goes to stage 2.
+    let early_exit = skip_toxicity
+        || (!stage1_hardblock
+            && if options.overlap_only {
+                stage1_overlap.is_some_and(|o| o < weights.overlap_gate_threshold)
+            } else {
+                !stage1_score_texts.is_empty()
+                    && should_early_exit_stage1(
+                        &stage1_clean_pass_scores,
+                        stage1_overlap,
+                        weights.overlap_gate_threshold,
+                    )
+            });
+    if early_exit {
+        if skip_toxicity {
+            info!(
+                handle = target_handle,
+                posts = stage1_sample.total_posts,
+                overlap = format!("{:.3}", stage1_overlap.unwrap_or(0.0)),
+                "Stage 1 early exit: below minimum overlap, toxicity not scored"
+            );
+        } else {
+            info!(
+                handle = target_handle,
+                posts = stage1_sample.total_posts,
+                overlap = format!("{:.3}", stage1_overlap.unwrap_or(0.0)),
+                "Stage 1 early exit: clean and topically irrelevant"
+            );
+        }
 
         let fp_quality = FingerprintQuality::from_counts(
             stage1_sample.originals.len(),
             stage1_sample.replies.len() + stage1_sample.quotes.len(),
         );
 
-        return Ok(AccountScore {
+        let breakdown = stage1_fingerprint
+            .as_ref()
+            .filter(|_| explain_overlap)
+            .map(|fp| OverlapBreakdown {
+                method: OverlapMethod::Keyword,
+                keyword_overlap: stage1_overlap.unwrap_or(0.0),
+                clusters: overlap::breakdown_against_prepared(protected_fingerprint, fp),
+                topic_contributions: overlap::contributions_against_prepared(
+                    protected_fingerprint,
+                    fp,
+                ),
+            });
+        let score = AccountScore {
             did: target_did.to_string(),
             handle: target_handle.to_string(),
-            toxicity_score: Some(0.0),
+            // Unscored, not clean, when the scorer never ran
+            toxicity_score: (!options.overlap_only && !skip_toxicity).then_some(0.0),
             topic_overlap: stage1_overlap,
             threat_score: Some(0.0),
             threat_tier: Some("Low".to_string()),
@@ -179,13 +572,82 @@ pub async fn build_profile(
             graph_distance: graph_distance.map(|d| d.as_str().to_string()),
             fingerprint_quality: Some(fp_quality.as_str().to_string()),
             scoring_confidence: Some("low".to_string()),
-        });
+            overlap_method: stage1_overlap.map(|_| OverlapMethod::Keyword.as_str().to_string()),
+            last_active_at,
+            origin_amplifier_did: None,
+            raw_threat_score: None,
+            overlap_post_count: stage1_overlap.and_then(|_| {
+                overlap::low_confidence_post_count(stage1_fp_texts.len(), options.min_overlap_posts)
+            }),
+            replies_analyzed: Some(stage1_sample.replies.len() as u32),
+            evidence_strength: Some(evidence::compute_evidence_strength(
+                stage1_sample.total_posts,
+                OverlapMethod::Keyword,
+                &stage1_onnx.iter().map(|r| r.toxicity).collect::<Vec<_>>(),
+            )),
+            whitelisted: false,
+            peak_toxicity_attributes: non_empty_peak(stage1_onnx.iter().map(|r| &r.attributes)),
+        };
+        return Ok((score, breakdown));
     }
 
     // ── Stage 2: Full pipeline with 50 posts ──
     // Account wasn't clean enough for early exit — run the full analysis.
     let sample = posts::fetch_posts_with_replies(client, target_handle, 50).await?;
 
+    // With reply scoring on, the account's recent replies join the sample
+    // for toxicity, where the 70% reply weighting gives them their due.
+    // Fingerprinting and behavioral ratios stay with the feed sample. A
+    // failed reply fetch scores the feed sample alone.
+    let scored_sample = if options.score_replies && !options.overlap_only {
+        match posts::fetch_recent_replies(client, target_handle, posts::REPLY_SAMPLE_SIZE).await {
+            Ok(extra) => sample.clone().with_extra_replies(extra),
+            Err(e) => {
+                warn!(handle = target_handle, error = %e, "Reply fetch failed, scoring posts only");
+                sample.clone()
+            }
+        }
+    } else {
+        sample.clone()
+    };
+    let (sample, _) = recency::retain_recent(&sample, options.max_post_age_days, now);
+    let (scored_sample, posts_outside_window) =
+        recency::retain_recent(&scored_sample, options.max_post_age_days, now);
+
+    // Hard-block patterns are checked against every post in the sample,
+    // whatever its language.
+    let hardblock_hits: Vec<(&Post, &str)> = match &options.hardblock {
+        Some(hb) => scored_sample
+            .originals
+            .iter()
+            .chain(scored_sample.replies.iter().map(|r| &r.post))
+            .chain(scored_sample.quotes.iter())
+            .filter_map(|p| hb.first_match(&p.text).map(|pattern| (p, pattern)))
+            .collect(),
+        None => Vec::new(),
+    };
+
+    // Toxicity only sees posts in the model's languages. Fingerprinting and
+    // behavioral signals below keep using the full sample.
+    let (tox_sample, language_skipped) = language::retain_scorable(&scored_sample);
+    if tox_sample.total_posts == 0 && !options.overlap_only && hardblock_hits.is_empty() {
+        info!(
+            handle = target_handle,
+            post_count = scored_sample.total_posts,
+            "No posts in a scorable language"
+        );
+        let score = AccountScore {
+            last_active_at,
+            ..unsupported_language_score(
+                target_did,
+                target_handle,
+                scored_sample.total_posts,
+                graph_distance,
+            )
+        };
+        return Ok((score, None));
+    }
+
     // Step 2: Determine fingerprint quality and select posts for fingerprinting
     let fp_quality = FingerprintQuality::from_counts(
         sample.originals.len(),
@@ -206,53 +668,72 @@ pub async fn build_profile(
             .collect()
     };
 
-    // All posts go to toxicity scoring, with per-post context for replies.
+    // All scorable posts go to toxicity scoring, with per-post context for replies.
     // Originals and quotes are scored solo; replies are scored as a parent/reply
     // pair so the conversation-scoped Zentropi labeler can correctly evaluate
     // whether the reply is hostile toward the parent's author.
-    let all_post_texts: Vec<String> = sample
+    let all_post_texts: Vec<String> = tox_sample
         .originals
         .iter()
         .map(|p| p.text.clone())
-        .chain(sample.replies.iter().map(|r| r.post.text.clone()))
-        .chain(sample.quotes.iter().map(|p| p.text.clone()))
+        .chain(tox_sample.replies.iter().map(|r| r.post.text.clone()))
+        .chain(tox_sample.quotes.iter().map(|p| p.text.clone()))
         .collect();
 
-    let all_posts_flat: Vec<&Post> = sample
+    let all_posts_flat: Vec<&Post> = tox_sample
         .originals
         .iter()
-        .chain(sample.replies.iter().map(|r| &r.post))
-        .chain(sample.quotes.iter())
+        .chain(tox_sample.replies.iter().map(|r| &r.post))
+        .chain(tox_sample.quotes.iter())
         .collect();
 
-    let parent_uris: Vec<String> = sample
+    let parent_uris: Vec<String> = tox_sample
         .replies
         .iter()
         .map(|r| r.parent_uri.clone())
         .collect();
-    let parent_texts = posts::fetch_parent_posts(client, &parent_uris).await?;
+    // Parent texts only feed the classifier
+    let parent_texts = if options.overlap_only {
+        Default::default()
+    } else {
+        posts::fetch_parent_posts(client, &parent_uris).await?
+    };
 
     // contexts[i] aligns with all_post_texts[i]: parent text for replies, None otherwise.
     let mut contexts: Vec<Option<String>> = Vec::with_capacity(all_post_texts.len());
-    contexts.extend(std::iter::repeat_n(None, sample.originals.len()));
-    for r in &sample.replies {
+    contexts.extend(std::iter::repeat_n(None, tox_sample.originals.len()));
+    for r in &tox_sample.replies {
         contexts.push(parent_texts.get(&r.parent_uri).cloned());
     }
-    contexts.extend(std::iter::repeat_n(None, sample.quotes.len()));
+    contexts.extend(std::iter::repeat_n(None, tox_sample.quotes.len()));
 
     // Step 3: Two-stage classification — ONNX clean-pass + Zentropi binary verdict.
     // Each verdict carries the binary `is_toxic` flag plus the underlying ONNX
     // score (for evidence sorting and audit).
-    let verdicts = scorer
-        .classify_batch_with_contexts(&all_post_texts, &contexts)
-        .await?;
+    //
+    // Overlap-only scans get a neutral "not toxic, score 0" verdict per post
+    // instead, so the rest of the pipeline runs unchanged.
+    let verdicts = if options.overlap_only {
+        vec![
+            BinaryVerdict {
+                is_toxic: false,
+                onnx_score: 0.0,
+                onnx_attributes: ToxicityAttributes::default(),
+            };
+            all_post_texts.len()
+        ]
+    } else {
+        scorer
+            .classify_batch_with_contexts(&all_post_texts, &contexts, &options.attribute_weights)
+            .await?
+    };
 
     // Reply-weighted binary toxicity rate. Replies count 70% (where harassment
     // manifests), originals 30% (where stated views show). Quotes are bucketed
     // with originals — they are first-person commentary, not a reply pair.
-    let originals_len = sample.originals.len();
-    let replies_len = sample.replies.len();
-    let quotes_len = sample.quotes.len();
+    let originals_len = tox_sample.originals.len();
+    let replies_len = tox_sample.replies.len();
+    let quotes_len = tox_sample.quotes.len();
 
     let originals_verdicts = &verdicts[..originals_len];
     let replies_verdicts = &verdicts[originals_len..originals_len + replies_len];
@@ -269,64 +750,156 @@ pub async fn build_profile(
         total_originals,
     );
 
-    // Evidence: surface the worst-flagged posts (Zentropi-toxic, ranked by ONNX
-    // score). When no posts are flagged, surface the top-3 highest-ONNX posts as
+    // Evidence: surface the flagged posts (Zentropi-toxic), ranked by ONNX
+    // score or by recency per `options.evidence_order`. When no posts are flagged, surface the top-3 highest-ONNX posts as
     // a "watchlist" so users still see *something* explanatory.
-    let toxic_evidence: Vec<(&Post, f64)> = all_posts_flat
+    let toxic_evidence: Vec<(&Post, &BinaryVerdict)> = all_posts_flat
         .iter()
         .zip(verdicts.iter())
         .filter(|(_, v)| v.is_toxic)
-        .map(|(p, v)| (*p, v.onnx_score))
+        .map(|(p, v)| (*p, v))
         .collect();
 
-    let evidence_pool: Vec<(&Post, f64)> = if !toxic_evidence.is_empty() {
+    let evidence_pool_is_toxic = !toxic_evidence.is_empty();
+    let evidence_pool: Vec<(&Post, &BinaryVerdict)> = if evidence_pool_is_toxic {
         toxic_evidence
     } else {
         all_posts_flat
             .iter()
             .zip(verdicts.iter())
-            .map(|(p, v)| (*p, v.onnx_score))
+            .map(|(p, v)| (*p, v))
             .collect()
     };
 
-    let mut scored_posts = evidence_pool;
-    scored_posts.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-
-    let top_toxic_posts: Vec<ToxicPost> = scored_posts
+    // Recency only means something for flagged posts — the watchlist
+    // fallback always shows the highest-scoring posts.
+    let evidence_order = if evidence_pool_is_toxic {
+        options.evidence_order
+    } else {
+        EvidenceOrder::Toxicity
+    };
+    // Evidence carries each post's weighted score, so an identity attack
+    // outranks equally toxic swearing, and names the category behind it
+    let evidence_score = |verdict: &BinaryVerdict| {
+        let result = verdict.onnx_result();
+        (
+            weighted_score(&result, &options.attribute_weights),
+            dominant_attribute(&result, &options.attribute_weights).map(str::to_string),
+        )
+    };
+    let candidates: Vec<ToxicPost> = evidence_pool
         .iter()
-        .take(3)
-        .map(|(post, score)| ToxicPost {
-            text: post.text.clone(),
-            toxicity: *score,
-            uri: post.uri.clone(),
+        .map(|(post, verdict)| {
+            let (toxicity, flagged_for) = evidence_score(verdict);
+            ToxicPost {
+                text: post.text.clone(),
+                toxicity,
+                uri: post.uri.clone(),
+                attributes: (!verdict.onnx_attributes.is_empty())
+                    .then(|| verdict.onnx_attributes.clone()),
+                created_at: post.created_at.clone(),
+                hardblock_pattern: None,
+                flagged_for,
+            }
         })
         .collect();
+    // Neutral verdicts make no evidence
+    let top_toxic_posts = if options.overlap_only {
+        vec![]
+    } else {
+        select_evidence(candidates, evidence_order, EVIDENCE_POSTS)
+    };
+    // Hard-block matches lead the evidence, tagged with the pattern they hit
+    let top_toxic_posts = if hardblock_hits.is_empty() {
+        top_toxic_posts
+    } else {
+        let hits = hardblock_hits
+            .iter()
+            .map(|(post, pattern)| {
+                let verdict = all_posts_flat
+                    .iter()
+                    .position(|p| p.uri == post.uri)
+                    .map(|i| &verdicts[i]);
+                let (toxicity, flagged_for) = verdict.map(evidence_score).unwrap_or_default();
+                ToxicPost {
+                    text: post.text.clone(),
+                    toxicity,
+                    uri: post.uri.clone(),
+                    attributes: verdict
+                        .filter(|v| !v.onnx_attributes.is_empty())
+                        .map(|v| v.onnx_attributes.clone()),
+                    created_at: post.created_at.clone(),
+                    hardblock_pattern: Some(pattern.to_string()),
+                    flagged_for,
+                }
+            })
+            .collect();
+        keywords::merge_hardblock_evidence(hits, top_toxic_posts, EVIDENCE_POSTS)
+    };
 
     // Step 3: Compute topic overlap with the protected user.
     //
     // Prefer sentence embeddings when available — they capture semantic
     // similarity ("fatphobia" ≈ "obesity") that keyword matching misses.
-    // Fall back to TF-IDF keyword cosine when the embedding model isn't loaded.
-    let topic_overlap = if let (Some(emb), Some(protected_emb)) = (embedder, protected_embedding) {
-        // Embedding path: embed target's posts, average, compare
-        let target_embeddings = emb.embed_batch(&fingerprint_posts).await?;
-        let target_mean = embeddings::mean_embedding(&target_embeddings);
-        embeddings::cosine_similarity_embeddings(protected_emb, &target_mean)
-    } else {
-        // Fallback: TF-IDF keyword cosine similarity
-        let topic_extractor = TfIdfExtractor {
-            top_n_keywords: 40,
-            max_clusters: 7,
-        };
-        let target_fingerprint = topic_extractor.extract(&fingerprint_posts)?;
-        overlap::cosine_similarity(protected_fingerprint, &target_fingerprint)
+    // Fall back to TF-IDF keyword cosine when the embedding model isn't loaded,
+    // or when the account has too few posts for a stable mean embedding.
+    let topic_extractor = TfIdfExtractor {
+        top_n_keywords: 40,
+        max_clusters: 7,
     };
+    let chosen_method = overlap::choose_overlap_method(
+        fingerprint_posts.len(),
+        embedder.is_some() && protected_embedding.is_some(),
+        options.min_overlap_posts,
+    );
+    let (topic_overlap, overlap_method, target_fingerprint) =
+        if let (OverlapMethod::Embedding, Some(emb), Some(protected_emb)) =
+            (chosen_method, embedder, protected_embedding)
+        {
+            // Embedding path: embed target's posts, average, compare
+            let target_embeddings = emb.embed_batch(&fingerprint_posts).await?;
+            let target_mean = embeddings::mean_embedding(&target_embeddings, emb.dim());
+            // The keyword fingerprint only feeds the breakdown here
+            let target_fingerprint = if explain_overlap {
+                topic_extractor.extract(&fingerprint_posts).ok()
+            } else {
+                None
+            };
+            (
+                embeddings::cosine_similarity_embeddings(protected_emb, &target_mean),
+                OverlapMethod::Embedding,
+                target_fingerprint,
+            )
+        } else {
+            // Fallback: TF-IDF keyword cosine similarity
+            let target_fingerprint = topic_extractor.extract(&fingerprint_posts)?;
+            (
+                overlap::cosine_against_prepared(protected_fingerprint, &target_fingerprint),
+                OverlapMethod::Keyword,
+                Some(target_fingerprint),
+            )
+        };
+    let overlap_breakdown = target_fingerprint
+        .as_ref()
+        .filter(|_| explain_overlap)
+        .map(|fp| OverlapBreakdown {
+            method: overlap_method,
+            keyword_overlap: overlap::cosine_against_prepared(protected_fingerprint, fp),
+            clusters: overlap::breakdown_against_prepared(protected_fingerprint, fp),
+            topic_contributions: overlap::contributions_against_prepared(protected_fingerprint, fp),
+        });
 
     // Step 4b: Compute behavioral signals (from PostSample — no separate API call)
     let quote_ratio = sample.quote_ratio;
     let reply_ratio = sample.reply_ratio;
 
-    let avg_engagement = behavioral::compute_avg_engagement_refs(&all_posts_flat);
+    let full_sample_flat: Vec<&Post> = sample
+        .originals
+        .iter()
+        .chain(sample.replies.iter().map(|r| &r.post))
+        .chain(sample.quotes.iter())
+        .collect();
+    let avg_engagement = behavioral::compute_avg_engagement_refs(&full_sample_flat);
     let pile_on = pile_on_dids.contains(target_did);
 
     // Step 5: Compute context score via NLI
@@ -458,12 +1031,39 @@ pub async fn build_profile(
         None
     };
 
+    // Step 5b: Follow relationship. An account that quoted or replied to the
+    // protected user without a follow either way is a stranger quoting them.
+    // Callers that classified relationships in bulk pass the distance in;
+    // otherwise only accounts that interacted are looked up, so sweeps of
+    // followers don't spend a request each. A failed lookup leaves it unknown.
+    let quoted_you = direct_pairs.is_some_and(|pairs| !pairs.is_empty());
+    let relationship = match (graph_distance, protected_did) {
+        (Some(distance), _) => Some(distance),
+        (None, Some(protected)) if quoted_you => {
+            match relationships::classify_relationship(client, protected, target_did).await {
+                Ok(distance) => distance,
+                Err(e) => {
+                    warn!(handle = target_handle, error = %e, "Relationship lookup failed");
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    let stranger_quote = is_stranger_quote(quoted_you, relationship, graph_distance);
+
     // Step 6: Apply scoring formula in spec order:
     //   1. raw_score = tox * 70 * (1 + overlap * 1.5)
     //   2. score_with_behavioral = raw_score * behavioral_boost (via gate)
     //   3. context_multiplier = 1.0 + (context_score * 0.5)
     //   4. final_score = score_with_behavioral * context_multiplier
-    let (raw_score, _) = threat::compute_threat_score(avg_toxicity, topic_overlap, weights);
+    //
+    // Overlap-only scans replace step 1 with `compute_overlap_only_score`.
+    let (raw_score, _) = if options.overlap_only {
+        threat::compute_overlap_only_score(topic_overlap, options.overlap_only_weight, weights)
+    } else {
+        threat::compute_threat_score(avg_toxicity, topic_overlap, weights)
+    };
 
     let (score_with_behavioral, benign_gate, gate_was_bypassed) =
         behavioral::apply_behavioral_modifier_contextual(
@@ -471,6 +1071,7 @@ pub async fn build_profile(
             quote_ratio,
             reply_ratio,
             pile_on,
+            stranger_quote,
             avg_engagement,
             median_engagement,
             context_score,
@@ -485,16 +1086,64 @@ pub async fn build_profile(
         (None, _) => 1.0,
     };
 
-    // Step 7: Apply graph distance weight
-    // Strangers get amplified (1.2x), mutual follows get dampened (0.6x).
-    // Applied AFTER benign gate so it cannot bypass ally protections.
+    // Step 7: Apply graph distance and labeler weights
+    // Strangers get amplified (1.2x), mutual follows get dampened (0.6x), and
+    // a negative label from a trusted labeler amplifies (1.5x). Applied AFTER
+    // benign gate so they cannot bypass ally protections.
+    //
+    // A failed label lookup scores as if the account had no labels.
+    let moderation_labels = match labels::fetch_account_labels(
+        client,
+        target_did,
+        &options.trusted_labelers,
+    )
+    .await
+    {
+        Ok(account_labels) => labels::negative_labels(
+            &account_labels,
+            &options.trusted_labelers,
+            chrono::Utc::now(),
+        ),
+        Err(e) => {
+            warn!(handle = target_handle, error = %e, "Label lookup failed, scoring without labels");
+            Vec::new()
+        }
+    };
     let distance_weight = graph_distance.map(|d| d.threat_weight()).unwrap_or(1.0);
-    let final_score =
-        (score_with_behavioral * context_multiplier * distance_weight).clamp(0.0, 100.0);
+    let label_weight = labels::label_weight(&moderation_labels);
+    // Few posts inside the post window is thin evidence of a current pattern
+    let recency_weight = options
+        .max_post_age_days
+        .map(|_| recency::recency_weight(scored_sample.total_posts));
+    let final_score = (score_with_behavioral
+        * context_multiplier
+        * distance_weight
+        * label_weight
+        * recency_weight.unwrap_or(1.0))
+    .clamp(0.0, 100.0);
+    let final_score = if options.overlap_only {
+        final_score.min(threat::overlap_only_ceiling(&options.tier_thresholds))
+    } else {
+        final_score
+    };
 
-    let tier = crate::db::models::ThreatTier::from_score(final_score);
+    // Step 8: Hard-block override — a matching post puts the account in the
+    // High tier whatever the formula said, bypassing the overlap gate and
+    // every multiplier above.
+    let hardblocked = !hardblock_hits.is_empty();
+    let final_score = if hardblocked {
+        final_score.max(options.tier_thresholds.high)
+    } else {
+        final_score
+    };
 
-    let behavioral_boost = behavioral::compute_behavioral_boost(quote_ratio, reply_ratio, pile_on);
+    let tier = crate::db::models::ThreatTier::from_score_with_thresholds(
+        final_score,
+        &options.tier_thresholds,
+    );
+
+    let behavioral_boost =
+        behavioral::compute_behavioral_boost(quote_ratio, reply_ratio, pile_on, stranger_quote);
     let signals = behavioral::BehavioralSignals {
         quote_ratio,
         reply_ratio,
@@ -502,9 +1151,27 @@ pub async fn build_profile(
         pile_on,
         benign_gate,
         behavioral_boost,
+        posts_skipped_language: language_skipped,
+        // Set afterwards by the amplification pipeline, which knows the
+        // follower snapshots
+        recently_followed_amplifier: false,
+        moderation_labels,
+        post_window_days: options.max_post_age_days,
+        posts_outside_window,
+        recency_weight,
+        follows_you: relationship.map(|d| d.follows_you()),
+        followed_by_you: relationship.map(|d| d.followed_by_you()),
+        stranger_quote,
     };
     let signals_json = serde_json::to_string(&signals)?;
 
+    let post_toxicities: Vec<f64> = verdicts.iter().map(|v| v.onnx_score).collect();
+    let evidence_strength = evidence::compute_evidence_strength(
+        scored_sample.total_posts,
+        overlap_method,
+        &post_toxicities,
+    );
+
     info!(
         handle = target_handle,
         toxicity = format!("{:.2}", avg_toxicity),
@@ -513,22 +1180,28 @@ pub async fn build_profile(
         raw_score = format!("{:.1}", raw_score),
         threat = format!("{:.1}", final_score),
         tier = tier.as_str(),
+        evidence = format!("{:.2}", evidence_strength),
         quote_ratio = format!("{:.2}", quote_ratio),
         reply_ratio = format!("{:.2}", reply_ratio),
         benign_gate = benign_gate,
         behavioral_boost = format!("{:.2}", behavioral_boost),
-        posts = sample.total_posts,
+        stranger_quote = stranger_quote,
+        posts = scored_sample.total_posts,
+        replies = scored_sample.replies.len(),
+        skipped_language = language_skipped,
+        hardblocked = hardblocked,
+        labeled = label_weight > 1.0,
         "Scored account"
     );
 
-    Ok(AccountScore {
+    let score = AccountScore {
         did: target_did.to_string(),
         handle: target_handle.to_string(),
-        toxicity_score: Some(avg_toxicity),
+        toxicity_score: (!options.overlap_only).then_some(avg_toxicity),
         topic_overlap: Some(topic_overlap),
         threat_score: Some(final_score),
         threat_tier: Some(tier.to_string()),
-        posts_analyzed: sample.total_posts as u32,
+        posts_analyzed: scored_sample.total_posts as u32,
         top_toxic_posts,
         scored_at: String::new(),
         behavioral_signals: Some(signals_json),
@@ -550,7 +1223,29 @@ pub async fn build_profile(
             }
             .to_string(),
         ),
-    })
+        overlap_method: Some(overlap_method.as_str().to_string()),
+        last_active_at,
+        origin_amplifier_did: None,
+        raw_threat_score: None,
+        overlap_post_count: overlap::low_confidence_post_count(
+            fingerprint_posts.len(),
+            options.min_overlap_posts,
+        ),
+        replies_analyzed: Some(scored_sample.replies.len() as u32),
+        evidence_strength: Some(evidence_strength),
+        whitelisted: false,
+        peak_toxicity_attributes: non_empty_peak(verdicts.iter().map(|v| &v.onnx_attributes)),
+    };
+    Ok((score, overlap_breakdown))
+}
+
+/// Peak per-category toxicity, or `None` when the scorer gave no category
+/// breakdown at all (so the display doesn't show an empty section).
+fn non_empty_peak<'a>(
+    attributes: impl IntoIterator<Item = &'a ToxicityAttributes>,
+) -> Option<ToxicityAttributes> {
+    let peak = ToxicityAttributes::peak(attributes);
+    (!peak.is_empty()).then_some(peak)
 }
 
 /// Minimum number of replies to use reply-weighted toxicity.
@@ -601,6 +1296,33 @@ pub fn compute_reply_weighted_toxicity(
     reply_tox_rate * 0.7 + original_tox_rate * 0.3
 }
 
+/// Posts kept as evidence per account.
+pub const EVIDENCE_POSTS: usize = 3;
+
+/// Pick up to `limit` evidence posts in the given order.
+///
+/// `Toxicity` keeps the highest-scoring posts; `Recent` keeps the newest,
+/// with posts lacking a parseable timestamp last. Ties keep input order.
+pub fn select_evidence(
+    mut posts: Vec<ToxicPost>,
+    order: EvidenceOrder,
+    limit: usize,
+) -> Vec<ToxicPost> {
+    match order {
+        EvidenceOrder::Toxicity => posts.sort_by(|a, b| b.toxicity.total_cmp(&a.toxicity)),
+        EvidenceOrder::Recent => {
+            let timestamp = |p: &ToxicPost| {
+                p.created_at
+                    .as_deref()
+                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            };
+            posts.sort_by(|a, b| timestamp(b).cmp(&timestamp(a)));
+        }
+    }
+    posts.truncate(limit);
+    posts
+}
+
 // ============================================================
 // Adaptive sampling — stage decision functions
 // ============================================================
@@ -648,16 +1370,15 @@ pub fn should_early_exit_stage1(
     overlap < overlap_gate_threshold && onnx_scores.iter().all(|&s| s < ONNX_CLEAN_THRESHOLD)
 }
 
-/// Tier boundary proximity thresholds.
-const TIER_BOUNDARIES: [f64; 3] = [8.0, 15.0, 35.0]; // Watch, Elevated, High
+/// How close to a tier boundary counts as near it.
 const BOUNDARY_MARGIN: f64 = 5.0;
 
 /// Check if a Stage 2 score is near a tier boundary and needs Stage 3.
 ///
-/// Returns true if the score is within ±5 points of any tier boundary,
-/// meaning more data could change the tier classification.
-pub fn should_continue_to_stage3(score: f64) -> bool {
-    TIER_BOUNDARIES
+/// Returns true if the score is within ±5 points of any of the configured
+/// tier boundaries, meaning more data could change the tier classification.
+pub fn should_continue_to_stage3(score: f64, thresholds: &TierThresholds) -> bool {
+    [thresholds.watch, thresholds.elevated, thresholds.high]
         .iter()
         .any(|&boundary| (score - boundary).abs() <= BOUNDARY_MARGIN)
 }
@@ -0,0 +1,145 @@
+// Post-recency window — score an account on what it has posted lately.
+//
+// With `--max-post-age-days` set, `build_profile` drops posts older than the
+// window before anything is scored, so an account that was hostile years ago
+// and quiet since isn't judged on its old posts. An account with only a few
+// posts inside the window is down-weighted too: a handful of recent posts is
+// thin evidence of an ongoing pattern.
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::bluesky::posts::{Post, PostSample};
+use crate::scoring::behavioral::BehavioralSignals;
+
+/// Posts inside the window at which an account's score is no longer
+/// down-weighted for sparse activity.
+pub const SPARSE_ACTIVITY_POSTS: usize = 10;
+
+/// Lowest `recency_weight`, for an account with almost nothing recent.
+pub const MIN_RECENCY_WEIGHT: f64 = 0.5;
+
+/// Whether a post falls inside a window that starts at `cutoff`. Posts
+/// without a parseable timestamp are kept — there's no evidence they're old.
+pub fn is_within_window(post: &Post, cutoff: DateTime<Utc>) -> bool {
+    !post
+        .created_at
+        .as_deref()
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+        .is_some_and(|t| t.with_timezone(&Utc) < cutoff)
+}
+
+/// The posts in `sample` from the last `max_age_days` days (all of them when
+/// `None`), plus how many were left out. Reply and quote ratios are
+/// recomputed from the posts kept, so behavior is judged on the same window.
+pub fn retain_recent(
+    sample: &PostSample,
+    max_age_days: Option<u32>,
+    now: DateTime<Utc>,
+) -> (PostSample, usize) {
+    // A window reaching back past the earliest representable time keeps
+    // everything, like no window at all
+    let Some(cutoff) = max_age_days
+        .and_then(|days| TimeDelta::try_days(i64::from(days)))
+        .and_then(|window| now.checked_sub_signed(window))
+    else {
+        return (sample.clone(), 0);
+    };
+
+    let originals: Vec<Post> = sample
+        .originals
+        .iter()
+        .filter(|p| is_within_window(p, cutoff))
+        .cloned()
+        .collect();
+    let replies: Vec<_> = sample
+        .replies
+        .iter()
+        .filter(|r| is_within_window(&r.post, cutoff))
+        .cloned()
+        .collect();
+    let quotes: Vec<Post> = sample
+        .quotes
+        .iter()
+        .filter(|p| is_within_window(p, cutoff))
+        .cloned()
+        .collect();
+
+    let kept = originals.len() + replies.len() + quotes.len();
+    let seen = sample.originals.len() + sample.replies.len() + sample.quotes.len();
+    let ratio = |n: usize| {
+        if kept > 0 {
+            n as f64 / kept as f64
+        } else {
+            0.0
+        }
+    };
+
+    let recent = PostSample {
+        reply_ratio: ratio(replies.len()),
+        quote_ratio: ratio(quotes.len()),
+        originals,
+        replies,
+        quotes,
+        total_posts: kept,
+    };
+    (recent, seen - kept)
+}
+
+/// Score multiplier for an account with `recent_posts` posts inside the
+/// window: 1.0 from `SPARSE_ACTIVITY_POSTS` up, falling linearly to
+/// `MIN_RECENCY_WEIGHT` at none.
+pub fn recency_weight(recent_posts: usize) -> f64 {
+    let activity = (recent_posts as f64 / SPARSE_ACTIVITY_POSTS as f64).min(1.0);
+    MIN_RECENCY_WEIGHT + (1.0 - MIN_RECENCY_WEIGHT) * activity
+}
+
+/// How a post window reads in reports: "last 90 days", or "unlimited".
+pub fn window_label(max_age_days: Option<u32>) -> String {
+    match max_age_days {
+        Some(1) => "last 1 day".to_string(),
+        Some(days) => format!("last {days} days"),
+        None => "unlimited".to_string(),
+    }
+}
+
+/// Describe the post window an account was scored with, e.g. "last 90
+/// days, 12 older posts left out, score ×0.85 for sparse recent activity".
+/// `None` when the account was scored without a window.
+pub fn post_window_note(signals: &BehavioralSignals) -> Option<String> {
+    let days = signals.post_window_days?;
+    let mut note = window_label(Some(days));
+    if signals.posts_outside_window > 0 {
+        note.push_str(&format!(
+            ", {} older posts left out",
+            signals.posts_outside_window
+        ));
+    }
+    if let Some(weight) = signals.recency_weight.filter(|&w| w < 1.0) {
+        note.push_str(&format!(", score ×{weight:.2} for sparse recent activity"));
+    }
+    Some(note)
+}
+
+/// The post windows a report's accounts were scored with, for its header:
+/// "last 90 days", or a list when scans used different windows. `None` when
+/// no account was scored with a window.
+pub fn report_window_label<'a>(
+    signals: impl IntoIterator<Item = &'a BehavioralSignals>,
+) -> Option<String> {
+    let windows: std::collections::BTreeSet<Option<u32>> =
+        signals.into_iter().map(|s| s.post_window_days).collect();
+    if windows.iter().all(Option::is_none) {
+        return None;
+    }
+    if windows.len() == 1 {
+        return windows.into_iter().next().map(window_label);
+    }
+    // Bounded windows shortest first, then "unlimited"
+    let labels: Vec<String> = windows
+        .iter()
+        .flatten()
+        .map(|&days| window_label(Some(days)))
+        .chain(windows.contains(&None).then(|| window_label(None)))
+        .collect();
+    Some(format!("{} (varies by account)", labels.join(", ")))
+}
@@ -7,7 +7,8 @@
 // low-priority (they're hostile but unlikely to see your content). Toxicity
 // WITH topic overlap is the real danger.
 
-use crate::db::models::ThreatTier;
+use crate::db::models::{ThreatTier, TierThresholds};
+use crate::scoring::calibration::Calibration;
 
 /// Configurable weights for the threat score formula.
 ///
@@ -28,6 +29,9 @@ pub struct ThreatWeights {
     pub overlap_gate_threshold: f64,
     /// Maximum score when the gate is active (default 25.0)
     pub gate_max_score: f64,
+    /// Per-backend mapping applied to toxicity before the formula (default
+    /// None). See `scoring::calibration`.
+    pub toxicity_calibration: Option<Calibration>,
 }
 
 impl Default for ThreatWeights {
@@ -37,35 +41,117 @@ impl Default for ThreatWeights {
             overlap_multiplier: 1.5,
             overlap_gate_threshold: 0.15,
             gate_max_score: 25.0,
+            toxicity_calibration: None,
         }
     }
 }
 
-/// Compute the combined threat score from toxicity and topic overlap.
+/// How a threat score was built from its inputs, for display and tests.
 ///
-/// Returns a score from 0.0 to 100.0 and the corresponding threat tier.
-pub fn compute_threat_score(
+/// Ungated, `pre_clamp = toxicity_component + overlap_component`: the
+/// toxicity term alone, plus the extra that topic overlap multiplies on top.
+/// Gated, overlap contributes nothing and `pre_clamp` is the toxicity term
+/// capped at `gate_max_score`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreatBreakdown {
+    /// Toxicity (after calibration) times its weight — `toxicity_weight`,
+    /// or `gate_max_score` when gated
+    pub toxicity_component: f64,
+    /// Points added by topic overlap amplifying the toxicity term
+    pub overlap_component: f64,
+    /// True when overlap fell below the gate threshold
+    pub gated: bool,
+    /// Score before clamping to 0–100
+    pub pre_clamp: f64,
+    pub final_score: f64,
+    pub tier: ThreatTier,
+}
+
+/// Compute the combined threat score, keeping each step of the formula.
+pub fn compute_threat_score_breakdown(
     toxicity: f64,
     topic_overlap: f64,
     weights: &ThreatWeights,
-) -> (f64, ThreatTier) {
-    let score = if topic_overlap < weights.overlap_gate_threshold {
+) -> ThreatBreakdown {
+    let toxicity = match &weights.toxicity_calibration {
+        Some(calibration) => calibration.apply(toxicity),
+        None => toxicity,
+    };
+
+    let gated = topic_overlap < weights.overlap_gate_threshold;
+    let (toxicity_component, overlap_component, pre_clamp) = if gated {
         // Gate: hostile but irrelevant — cap the score
-        (toxicity * weights.gate_max_score).min(weights.gate_max_score)
+        let component = toxicity * weights.gate_max_score;
+        (component, 0.0, component.min(weights.gate_max_score))
     } else {
         // Multiplicative formula: overlap amplifies toxicity.
         // An ally (low tox, high overlap) stays low. A hostile account
         // in the same topic space gets amplified.
-        toxicity * weights.toxicity_weight * (1.0 + topic_overlap * weights.overlap_multiplier)
+        let component = toxicity * weights.toxicity_weight;
+        let amplification = component * topic_overlap * weights.overlap_multiplier;
+        (component, amplification, component + amplification)
     };
 
     // Clamp to 0-100 range
+    let final_score = pre_clamp.clamp(0.0, 100.0);
+
+    ThreatBreakdown {
+        toxicity_component,
+        overlap_component,
+        gated,
+        pre_clamp,
+        final_score,
+        tier: ThreatTier::from_score(final_score),
+    }
+}
+
+/// Compute the combined threat score from toxicity and topic overlap.
+///
+/// Returns a score from 0.0 to 100.0 and the corresponding threat tier on
+/// the default thresholds — `build_profile` tiers the final score against
+/// the configured ones. See `compute_threat_score_breakdown` for the
+/// intermediate values.
+pub fn compute_threat_score(
+    toxicity: f64,
+    topic_overlap: f64,
+    weights: &ThreatWeights,
+) -> (f64, ThreatTier) {
+    let breakdown = compute_threat_score_breakdown(toxicity, topic_overlap, weights);
+    (breakdown.final_score, breakdown.tier)
+}
+
+/// Compute a threat score from topic overlap alone, for scans that skip
+/// toxicity scoring.
+///
+/// Overlap below the gate threshold scores 0.0 — the same accounts the
+/// toxicity formula would cap as irrelevant. Above it the score grows
+/// linearly up to `overlap_only_weight` at full overlap (see
+/// `profile::ScoringOptions::overlap_only_weight`).
+pub fn compute_overlap_only_score(
+    topic_overlap: f64,
+    overlap_only_weight: f64,
+    weights: &ThreatWeights,
+) -> (f64, ThreatTier) {
+    let score = if topic_overlap < weights.overlap_gate_threshold {
+        0.0
+    } else {
+        topic_overlap * overlap_only_weight
+    };
+
     let score = score.clamp(0.0, 100.0);
     let tier = ThreatTier::from_score(score);
 
     (score, tier)
 }
 
+/// The highest final score an overlap-only account can reach. High is kept
+/// for accounts whose toxicity was measured, so overlap stays a tenth under
+/// the High threshold even after the behavioral, context, graph-distance and
+/// label multipliers.
+pub fn overlap_only_ceiling(thresholds: &TierThresholds) -> f64 {
+    (thresholds.high - 0.1).max(thresholds.elevated)
+}
+
 /// Compute the combined threat score with optional context multiplier.
 ///
 /// When a context_score is provided (from NLI pair scoring), it amplifies
@@ -98,19 +184,33 @@ mod tests {
     #[test]
     fn test_hostile_with_overlap() {
         let weights = ThreatWeights::default();
-        let (score, tier) = compute_threat_score(0.8, 0.25, &weights);
+        let b = compute_threat_score_breakdown(0.8, 0.25, &weights);
         // 0.8 * 70 * (1 + 0.25 * 1.5) = 56 * 1.375 = 77.0
-        assert!((score - 77.0).abs() < 0.1, "Expected ~77.0, got {score}");
-        assert_eq!(tier, ThreatTier::High);
+        assert!(!b.gated);
+        assert!((b.toxicity_component - 56.0).abs() < 1e-9);
+        assert!((b.overlap_component - 21.0).abs() < 1e-9);
+        assert!(
+            (b.final_score - 77.0).abs() < 0.1,
+            "Expected ~77.0, got {}",
+            b.final_score
+        );
+        assert_eq!(b.tier, ThreatTier::High);
     }
 
     #[test]
     fn test_hostile_without_overlap_is_gated() {
         let weights = ThreatWeights::default();
-        let (score, tier) = compute_threat_score(0.9, 0.02, &weights);
+        let b = compute_threat_score_breakdown(0.9, 0.02, &weights);
         // Gated (0.02 < 0.15): 0.9 * 25 = 22.5
-        assert!((score - 22.5).abs() < 0.1, "Expected ~22.5, got {score}");
-        assert_eq!(tier, ThreatTier::Elevated);
+        assert!(b.gated);
+        assert_eq!(b.overlap_component, 0.0);
+        assert!((b.toxicity_component - 22.5).abs() < 1e-9);
+        assert!(
+            (b.final_score - 22.5).abs() < 0.1,
+            "Expected ~22.5, got {}",
+            b.final_score
+        );
+        assert_eq!(b.tier, ThreatTier::Elevated);
     }
 
     #[test]
@@ -128,10 +228,34 @@ mod tests {
         // scores Elevated instead of High. The multiplicative formula
         // prevents overlap from independently driving high scores.
         let weights = ThreatWeights::default();
-        let (score, tier) = compute_threat_score(0.1, 0.8, &weights);
+        let b = compute_threat_score_breakdown(0.1, 0.8, &weights);
         // 0.1 * 70 * (1 + 0.8 * 1.5) = 7 * 2.2 = 15.4
-        assert!((score - 15.4).abs() < 0.1, "Expected ~15.4, got {score}");
-        assert_eq!(tier, ThreatTier::Elevated);
+        assert!((b.toxicity_component - 7.0).abs() < 1e-9);
+        assert!((b.overlap_component - 8.4).abs() < 1e-9);
+        assert!(
+            (b.final_score - 15.4).abs() < 0.1,
+            "Expected ~15.4, got {}",
+            b.final_score
+        );
+        assert_eq!(b.tier, ThreatTier::Elevated);
+    }
+
+    #[test]
+    fn test_calibration_applied_before_formula() {
+        let weights = ThreatWeights {
+            toxicity_calibration: Some(Calibration {
+                multiplier: 2.0,
+                offset: 0.0,
+                sample_size: 100,
+                observed_median: 0.0,
+                observed_p90: 0.0,
+                computed_at: String::new(),
+            }),
+            ..ThreatWeights::default()
+        };
+        let (calibrated, _) = compute_threat_score(0.2, 0.5, &weights);
+        let (raw, _) = compute_threat_score(0.4, 0.5, &ThreatWeights::default());
+        assert!((calibrated - raw).abs() < 1e-9);
     }
 
     #[test]
@@ -227,4 +351,25 @@ mod tests {
         );
         assert_eq!(tier, ThreatTier::Elevated);
     }
+
+    #[test]
+    fn configured_thresholds_retier_the_final_score() {
+        let thresholds = TierThresholds::new(5.0, 10.0, 20.0).unwrap();
+
+        // 0.2 * 70 * (1 + 0.5 * 1.5) = 24.5: Elevated by default, High here
+        let b = compute_threat_score_breakdown(0.2, 0.5, &ThreatWeights::default());
+        assert_eq!(b.tier, ThreatTier::Elevated);
+        assert_eq!(
+            ThreatTier::from_score_with_thresholds(b.final_score, &thresholds),
+            ThreatTier::High
+        );
+
+        // 0.2 * 30 = 6: Low by default, Watch here
+        let (score, tier) = compute_overlap_only_score(0.2, 30.0, &ThreatWeights::default());
+        assert_eq!(tier, ThreatTier::Low);
+        assert_eq!(
+            ThreatTier::from_score_with_thresholds(score, &thresholds),
+            ThreatTier::Watch
+        );
+    }
 }
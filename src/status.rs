@@ -1,24 +1,121 @@
-// System status display — shows DB stats, fingerprint age, last scan time.
+// System status display — shows DB stats, fingerprint age, last scan time,
+// and with --verbose, whether the models and stored embedding are ready.
 
 use anyhow::Result;
+use colored::Colorize;
+use serde_json::json;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use crate::config::Config;
 use crate::db::Database;
+use crate::output::reporter::Reporter;
+use crate::topics::embeddings::SentenceEmbedder;
+use crate::topics::fingerprint::{self, TopicFingerprint};
+use crate::toxicity::download;
 
-/// Display system status to the terminal.
+/// What `status --verbose` checks: the model files on disk and the stored
+/// protected-user embedding. Topic overlap only uses embeddings when the
+/// embedding model and a correctly sized stored embedding are both present;
+/// otherwise it silently falls back to TF-IDF keywords.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Readiness {
+    pub model_dir: PathBuf,
+    pub model_dir_exists: bool,
+    /// Each toxicity model file and its size, `None` when missing
+    pub toxicity_files: Vec<(PathBuf, Option<u64>)>,
+    /// Each embedding model file and its size, `None` when missing
+    pub embedding_files: Vec<(PathBuf, Option<u64>)>,
+    /// Dimension of the stored embedding, `None` when there isn't one
+    pub stored_embedding_dim: Option<usize>,
+    /// Dimension the embedding model produces, `None` when it couldn't be
+    /// loaded
+    pub model_embedding_dim: Option<usize>,
+}
+
+impl Readiness {
+    /// Inspect `model_dir` and compare the stored embedding's dimension
+    /// with the one the embedding model produces.
+    pub fn check(
+        model_dir: &Path,
+        stored_embedding_dim: Option<usize>,
+        model_embedding_dim: Option<usize>,
+    ) -> Self {
+        let sized = |files: [PathBuf; 2]| {
+            files
+                .into_iter()
+                .map(|f| {
+                    let size = std::fs::metadata(&f)
+                        .ok()
+                        .filter(|m| m.is_file())
+                        .map(|m| m.len());
+                    (f, size)
+                })
+                .collect()
+        };
+        Self {
+            model_dir: model_dir.to_path_buf(),
+            model_dir_exists: model_dir.is_dir(),
+            toxicity_files: sized(download::model_files(model_dir)),
+            embedding_files: sized(download::embedding_files(model_dir)),
+            stored_embedding_dim,
+            model_embedding_dim,
+        }
+    }
+
+    pub fn toxicity_model_ready(&self) -> bool {
+        self.toxicity_files.iter().all(|(_, size)| size.is_some())
+    }
+
+    pub fn embedding_model_ready(&self) -> bool {
+        self.embedding_files.iter().all(|(_, size)| size.is_some())
+    }
+
+    pub fn embedding_dim_matches(&self) -> bool {
+        self.stored_embedding_dim.is_some() && self.stored_embedding_dim == self.model_embedding_dim
+    }
+
+    /// Whether topic overlap will use embeddings rather than TF-IDF.
+    pub fn embeddings_ready(&self) -> bool {
+        self.embedding_model_ready() && self.embedding_dim_matches()
+    }
+}
+
+/// Display system status.
 ///
 /// `db_display` is the human-readable database identifier — either a file path
 /// (for SQLite) or a redacted connection URL (for PostgreSQL). The caller is
 /// responsible for redacting credentials before passing the URL.
-pub async fn show(db: &Arc<dyn Database>, user_did: &str, db_display: &str) -> Result<()> {
+///
+/// `config.fingerprint_max_age_days` is the staleness threshold for the
+/// topic fingerprint (0 disables the stale warning).
+///
+/// With `verbose`, a readiness checklist for the models in `config.model_dir`
+/// and the stored embedding follows the usual summary.
+///
+/// Each section goes through `reporter` as a result, so `--json` gets one
+/// object per section on stdout.
+pub async fn show(
+    db: &Arc<dyn Database>,
+    user_did: &str,
+    db_display: &str,
+    config: &Config,
+    verbose: bool,
+    reporter: &Reporter,
+) -> Result<()> {
+    let fingerprint_max_age_days = config.fingerprint_max_age_days;
+    let model_dir = config.model_dir.as_path();
     // Probe the database to detect initialization state. A table_count of 0
     // means the schema hasn't been applied yet. An error means the database
     // can't be reached at all. Both are treated as "not initialized".
     match db.table_count().await {
         Ok(n) if n > 0 => {}
         _ => {
-            println!("Database: not initialized");
-            println!("\nRun `charcoal init` to set up the database.");
+            reporter.result(
+                "status_database",
+                "Database: not initialized\n\nRun `charcoal init` to set up the database.",
+                json!({ "initialized": false }),
+            );
             return Ok(());
         }
     }
@@ -26,66 +123,256 @@ pub async fn show(db: &Arc<dyn Database>, user_did: &str, db_display: &str) -> R
     // For SQLite, show the file path and size. For PostgreSQL (URL), just show
     // the connection target — there's no local file to stat.
     if db_display.starts_with("postgres://") || db_display.starts_with("postgresql://") {
-        println!("Database: {db_display}");
+        reporter.result(
+            "status_database",
+            format!("Database: {db_display}"),
+            json!({ "initialized": true, "database": db_display }),
+        );
     } else {
-        let file_size = std::fs::metadata(db_display)
-            .map(|m| format_bytes(m.len()))
-            .unwrap_or_else(|_| "unknown".to_string());
-        println!("Database: {} ({})", db_display, file_size);
+        let file_size = std::fs::metadata(db_display).map(|m| m.len()).ok();
+        let size_display = file_size
+            .map(format_bytes)
+            .unwrap_or_else(|| "unknown".to_string());
+        reporter.result(
+            "status_database",
+            format!("Database: {db_display} ({size_display})"),
+            json!({ "initialized": true, "database": db_display, "size_bytes": file_size }),
+        );
     }
 
     // Fingerprint status
     match db.get_fingerprint(user_did).await? {
-        Some((_json, post_count, updated_at)) => {
-            println!(
-                "Fingerprint: built from {} posts (updated {})",
-                post_count, updated_at
+        Some((json, post_count, updated_at)) => {
+            let age = fingerprint::age_days(&updated_at);
+            let mut message = match age {
+                Some(age) => format!(
+                    "Fingerprint: built from {post_count} posts ({age} days old, updated {updated_at})"
+                ),
+                None => format!("Fingerprint: built from {post_count} posts (updated {updated_at})"),
+            };
+            let stale = age.is_some_and(|age| fingerprint::is_stale(age, fingerprint_max_age_days));
+            if stale {
+                message.push_str(&format!(
+                    "\n  STALE — older than {fingerprint_max_age_days} days; \
+                     run `charcoal fingerprint --refresh`"
+                ));
+            }
+            let degenerate = TopicFingerprint::from_json(&json)
+                .map(|fp| fp.is_degenerate())
+                .unwrap_or(false);
+            if degenerate {
+                message.push_str(
+                    "\n  No usable topic clusters — every account will score Low\
+                     \n  Run `charcoal fingerprint --refresh` once you have more posts",
+                );
+            }
+            reporter.result(
+                "status_fingerprint",
+                message,
+                json!({
+                    "built": true,
+                    "post_count": post_count,
+                    "updated_at": updated_at,
+                    "age_days": age,
+                    "stale": stale,
+                    "degenerate": degenerate,
+                }),
             );
         }
         None => {
-            println!("Fingerprint: not yet built");
-            println!("  Run `charcoal fingerprint` to build it");
+            reporter.result(
+                "status_fingerprint",
+                "Fingerprint: not yet built\n  Run `charcoal fingerprint` to build it",
+                json!({ "built": false }),
+            );
         }
     }
 
     // Scored accounts (Elevated tier starts at 15.0)
-    let all_scores = db.get_ranked_threats(user_did, 0.0).await?;
-    let elevated_count = all_scores
-        .iter()
-        .filter(|s| s.threat_score.is_some_and(|t| t >= 15.0))
-        .count();
-    println!(
-        "Scored accounts: {} total, {} elevated+",
-        all_scores.len(),
-        elevated_count
+    let total_count = db.count_accounts(user_did, 0.0).await?;
+    let elevated_count = db.count_accounts(user_did, 15.0).await?;
+    reporter.result(
+        "status_accounts",
+        format!("Scored accounts: {total_count} total, {elevated_count} elevated+"),
+        json!({ "total": total_count, "elevated_or_higher": elevated_count }),
     );
 
     // Recent events
     let events = db.get_recent_events(user_did, 5).await?;
     if events.is_empty() {
-        println!("Recent events: none detected yet");
-        println!("  Run `charcoal scan` to check for quotes/reposts");
+        reporter.result(
+            "status_events",
+            "Recent events: none detected yet\n  Run `charcoal scan` to check for quotes/reposts",
+            json!({ "total": 0, "recent": [] }),
+        );
     } else {
-        println!("Recent events: {} most recent:", events.len());
+        let event_count = db.count_events(user_did).await?;
+        let mut message = format!(
+            "Recent events: {event_count} total, {} most recent:",
+            events.len()
+        );
         for event in &events {
-            println!(
-                "  {} by @{} ({})",
+            message.push_str(&format!(
+                "\n  {} by @{} ({})",
                 event.event_type, event.amplifier_handle, event.detected_at
-            );
+            ));
         }
+        let recent: Vec<_> = events
+            .iter()
+            .map(|event| {
+                json!({
+                    "event_type": event.event_type,
+                    "amplifier_handle": event.amplifier_handle,
+                    "detected_at": event.detected_at,
+                })
+            })
+            .collect();
+        reporter.result(
+            "status_events",
+            message,
+            json!({ "total": event_count, "recent": recent }),
+        );
     }
 
     // Last scan time — check directly rather than gating on notifications_cursor,
     // because web/background scans set last_scan_at without a cursor.
-    if let Some(last_scan) = db.get_scan_state(user_did, "last_scan_at").await? {
-        println!("Last scan: {}", last_scan);
-    } else {
-        println!("Last scan: never");
+    let last_scan = db.get_scan_state(user_did, "last_scan_at").await?;
+    reporter.result(
+        "status_last_scan",
+        format!("Last scan: {}", last_scan.as_deref().unwrap_or("never")),
+        json!({ "last_scan_at": last_scan }),
+    );
+
+    // Run history — one row per finished scan or sweep
+    let runs = db.get_scan_runs(user_did, 5).await?;
+    if !runs.is_empty() {
+        let mut message = "Recent runs:".to_string();
+        for run in &runs {
+            message.push_str(&format!(
+                "\n  {} {} — {} events, {} accounts scored (started {})",
+                run.finished_at,
+                run.command,
+                run.events_detected,
+                run.accounts_scored,
+                run.started_at
+            ));
+        }
+        reporter.result("status_runs", message, json!({ "runs": runs }));
+    }
+
+    if verbose {
+        let stored_embedding_dim = db.get_embedding(user_did).await?.map(|e| e.len());
+        let model_embedding_dim = if download::embedding_files_present(model_dir) {
+            let embed_dir = download::embedding_model_dir(model_dir);
+            match SentenceEmbedder::load(&embed_dir, config.onnx_provider) {
+                Ok(embedder) => Some(embedder.dim()),
+                Err(e) => {
+                    tracing::debug!(error = %e, "Could not load the embedding model");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        report_readiness(
+            &Readiness::check(model_dir, stored_embedding_dim, model_embedding_dim),
+            reporter,
+        );
     }
 
     Ok(())
 }
 
+/// Report the readiness checklist with a green or red marker per item.
+fn report_readiness(readiness: &Readiness, reporter: &Reporter) {
+    let mark = |ok: bool| {
+        if ok {
+            "✓".green().to_string()
+        } else {
+            "✗".red().to_string()
+        }
+    };
+    let files = |files: &[(PathBuf, Option<u64>)]| {
+        files
+            .iter()
+            .map(|(path, size)| {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                match size {
+                    Some(bytes) => format!("{name} {}", format_bytes(*bytes)),
+                    None => format!("{name} missing"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut lines = vec![
+        "\nReadiness:".to_string(),
+        format!(
+            "  {} Model directory: {}",
+            mark(readiness.model_dir_exists),
+            readiness.model_dir.display()
+        ),
+        format!(
+            "  {} Toxicity model: {}",
+            mark(readiness.toxicity_model_ready()),
+            files(&readiness.toxicity_files)
+        ),
+        format!(
+            "  {} Embedding model: {}",
+            mark(readiness.embedding_model_ready()),
+            files(&readiness.embedding_files)
+        ),
+    ];
+    match readiness.stored_embedding_dim {
+        Some(dim) => {
+            lines.push(format!("  {} Stored embedding: present", mark(true)));
+            let expected = match readiness.model_embedding_dim {
+                Some(model_dim) => format!("model produces {model_dim}"),
+                None => "model not loaded".to_string(),
+            };
+            lines.push(format!(
+                "  {} Embedding dimension: {dim} ({expected})",
+                mark(readiness.embedding_dim_matches())
+            ));
+        }
+        None => lines.push(format!("  {} Stored embedding: none", mark(false))),
+    }
+
+    if readiness.embeddings_ready() {
+        lines.push("Topic overlap: sentence embeddings".to_string());
+    } else {
+        lines.push("Topic overlap: TF-IDF keywords (embeddings not ready)".to_string());
+        if !readiness.embedding_model_ready() {
+            lines.push("  Run `charcoal download-model` to fetch the embedding model".to_string());
+        }
+        if !readiness.embedding_dim_matches() {
+            lines.push(
+                "  Run `charcoal fingerprint --refresh` to store a fresh embedding".to_string(),
+            );
+        }
+    }
+    if !readiness.toxicity_model_ready() {
+        lines.push(
+            "  Run `charcoal download-model` before scanning with the ONNX scorer".to_string(),
+        );
+    }
+
+    reporter.result(
+        "status_readiness",
+        lines.join("\n"),
+        json!({
+            "model_dir": readiness.model_dir.display().to_string(),
+            "model_dir_exists": readiness.model_dir_exists,
+            "toxicity_model_ready": readiness.toxicity_model_ready(),
+            "embedding_model_ready": readiness.embedding_model_ready(),
+            "stored_embedding_dim": readiness.stored_embedding_dim,
+            "model_embedding_dim": readiness.model_embedding_dim,
+            "embeddings_ready": readiness.embeddings_ready(),
+        }),
+    );
+}
+
 fn format_bytes(bytes: u64) -> String {
     if bytes < 1024 {
         format!("{} B", bytes)
@@ -2,8 +2,8 @@
 //
 // Instead of comparing TF-IDF keyword lists (which fail when two people use
 // different words for the same topic — see docs/research-overlap-diagnosis.md),
-// this module embeds post text into 384-dimensional vectors using a sentence
-// transformer. Cosine similarity between mean embeddings captures semantic
+// this module embeds post text into dense vectors (384-dimensional for the
+// default model) using a sentence transformer. Cosine similarity between mean embeddings captures semantic
 // proximity: "fatphobia" and "obesity" land near each other even though they
 // share zero characters.
 //
@@ -19,55 +19,97 @@ use ort::value::Tensor;
 use tokenizers::Tokenizer;
 use tracing::debug;
 
-/// Embedding dimension for all-MiniLM-L6-v2.
+use crate::config::OnnxProvider;
+use crate::toxicity::onnx::{load_with_retry, pad_batch, session_builder};
+
+/// Embedding dimension for all-MiniLM-L6-v2, the model `download-model`
+/// fetches. A loaded model reports its own via `SentenceEmbedder::dim`.
 pub const EMBEDDING_DIM: usize = 384;
 
+/// Longest token sequence passed to the model — all-MiniLM-L6-v2 was
+/// trained on 256-token inputs, so anything longer only costs memory.
+pub const MAX_SEQ_LEN: usize = 256;
+
+/// Inputs every supported export must accept.
+const REQUIRED_INPUTS: [&str; 2] = ["input_ids", "attention_mask"];
+
+/// Optional segment-id input. Some MiniLM exports drop it and reject the
+/// session run if it's passed anyway.
+const TOKEN_TYPE_IDS: &str = "token_type_ids";
+
 /// Sentence embedder using a local ONNX model. Converts text into dense
-/// 384-dimensional vectors suitable for cosine similarity comparison.
+/// vectors suitable for cosine similarity comparison.
 ///
 /// Architecture mirrors OnnxToxicityScorer: Arc<Mutex<Session>> for thread
 /// safety, Arc<Tokenizer> for shared ownership across spawn_blocking.
 pub struct SentenceEmbedder {
     session: Arc<Mutex<Session>>,
     tokenizer: Arc<Tokenizer>,
+    /// Input names the model declares, read once at load time
+    input_names: Vec<String>,
+    /// Length of the vectors the model produces, read from its output shape
+    dim: usize,
 }
 
 impl SentenceEmbedder {
     /// Load the sentence embedding model and tokenizer from the given directory.
     ///
     /// Expects `model.onnx` and `tokenizer.json` in the directory.
-    /// Call `download_model()` first if they don't exist.
-    pub fn load(model_dir: &Path) -> Result<Self> {
+    /// Call `download_model()` first if they don't exist. The model runs on
+    /// `provider`, falling back to the CPU like the toxicity model.
+    pub fn load(model_dir: &Path, provider: OnnxProvider) -> crate::error::Result<Self> {
         let model_path = model_dir.join("model.onnx");
         let tokenizer_path = model_dir.join("tokenizer.json");
 
         if !model_path.exists() {
-            anyhow::bail!(
-                "Embedding model not found: {}\nRun `charcoal download-model` to download it.",
-                model_path.display()
-            );
+            return Err(crate::error::Error::ModelMissing { path: model_path });
         }
         if !tokenizer_path.exists() {
-            anyhow::bail!(
-                "Embedding tokenizer not found: {}\nRun `charcoal download-model` to download it.",
-                tokenizer_path.display()
-            );
+            return Err(crate::error::Error::ModelMissing {
+                path: tokenizer_path,
+            });
         }
 
-        let session = Session::builder()
-            .context("Failed to create ONNX session builder")?
-            .commit_from_file(&model_path)
-            .with_context(|| {
-                format!(
-                    "Failed to load embedding model from {}",
-                    model_path.display()
-                )
-            })?;
+        crate::toxicity::download::verify_sidecar(&model_path)?;
+        crate::toxicity::download::verify_sidecar(&tokenizer_path)?;
+
+        let session = load_with_retry(|| {
+            session_builder(provider)?
+                .commit_from_file(&model_path)
+                .with_context(|| {
+                    format!(
+                        "Failed to load embedding model from {}",
+                        model_path.display()
+                    )
+                })
+        })?;
 
         let tokenizer = Tokenizer::from_file(&tokenizer_path)
             .map_err(|e| anyhow::anyhow!("Failed to load embedding tokenizer: {}", e))?;
 
+        let input_names: Vec<String> = session
+            .inputs()
+            .iter()
+            .map(|input| input.name().to_string())
+            .collect();
+        check_input_names(&input_names)
+            .with_context(|| format!("Unsupported embedding model at {}", model_path.display()))?;
+
+        // last_hidden_state is [batch, seq_len, hidden]; batch and sequence
+        // are dynamic, the hidden size is fixed by the model.
+        let declared_dim = session
+            .outputs()
+            .first()
+            .and_then(|output| output.dtype().tensor_shape())
+            .and_then(|shape| hidden_dim(shape));
+        let dim = declared_dim.unwrap_or_else(|| {
+            debug!("Embedding model doesn't declare its output size, assuming {EMBEDDING_DIM}");
+            EMBEDDING_DIM
+        });
+
         debug!(
+            inputs = ?input_names,
+            dim,
             "Loaded sentence embedding model from {}",
             model_dir.display()
         );
@@ -75,10 +117,22 @@ impl SentenceEmbedder {
         Ok(Self {
             session: Arc::new(Mutex::new(session)),
             tokenizer: Arc::new(tokenizer),
+            input_names,
+            dim,
         })
     }
 
-    /// Embed a batch of texts into 384-dimensional vectors.
+    /// Input names declared by the loaded model.
+    pub fn input_names(&self) -> &[String] {
+        &self.input_names
+    }
+
+    /// Length of the vectors this model produces.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Embed a batch of texts into `dim()`-length vectors.
     ///
     /// Each text is tokenized, run through the BERT model, and mean-pooled
     /// (averaged across tokens, weighted by attention mask) to produce a
@@ -94,11 +148,62 @@ impl SentenceEmbedder {
         let session = Arc::clone(&self.session);
         let tokenizer = Arc::clone(&self.tokenizer);
         let texts = texts.to_vec();
+        let with_token_type_ids = accepts_token_type_ids(&self.input_names);
+        let dim = self.dim;
+
+        tokio::task::spawn_blocking(move || {
+            embed_sync(&session, &tokenizer, &texts, with_token_type_ids, dim)
+        })
+        .await
+        .context("spawn_blocking panicked")?
+    }
+}
 
-        tokio::task::spawn_blocking(move || embed_sync(&session, &tokenizer, &texts))
-            .await
-            .context("spawn_blocking panicked")?
+/// Check that a model declares the inputs the embedder feeds it.
+/// `token_type_ids` is optional; `input_ids` and `attention_mask` are not.
+pub fn check_input_names(input_names: &[String]) -> Result<()> {
+    let missing: Vec<&str> = REQUIRED_INPUTS
+        .iter()
+        .copied()
+        .filter(|required| !input_names.iter().any(|name| name == required))
+        .collect();
+    if !missing.is_empty() {
+        anyhow::bail!(
+            "model is missing required input(s) {} (declares: {})",
+            missing.join(", "),
+            input_names.join(", ")
+        );
     }
+    Ok(())
+}
+
+/// The hidden size from a `[batch, seq_len, hidden]` output shape, or
+/// `None` when it isn't a fixed positive size.
+pub fn hidden_dim(shape: &[i64]) -> Option<usize> {
+    match shape {
+        [_, _, hidden] if *hidden > 0 => usize::try_from(*hidden).ok(),
+        _ => None,
+    }
+}
+
+/// Check that a stored topic embedding was made by a model producing
+/// `model_dim`-length vectors. Comparing vectors of different lengths
+/// means nothing, so a mismatch needs the fingerprint rebuilt.
+pub fn check_stored_dim(stored: &[f64], model_dim: usize) -> Result<()> {
+    if stored.len() != model_dim {
+        anyhow::bail!(
+            "Stored topic embedding has {} dimensions but the embedding model produces {}. \
+             Was the model changed? Run `charcoal fingerprint --refresh` to rebuild it.",
+            stored.len(),
+            model_dim
+        );
+    }
+    Ok(())
+}
+
+/// Whether the model declares a `token_type_ids` input.
+pub fn accepts_token_type_ids(input_names: &[String]) -> bool {
+    input_names.iter().any(|name| name == TOKEN_TYPE_IDS)
 }
 
 /// Synchronous embedding — runs tokenization, inference, and mean pooling.
@@ -107,6 +212,8 @@ fn embed_sync(
     session: &Arc<Mutex<Session>>,
     tokenizer: &Arc<Tokenizer>,
     texts: &[String],
+    with_token_type_ids: bool,
+    dim: usize,
 ) -> Result<Vec<Vec<f64>>> {
     // Tokenize all texts
     let encodings: Vec<_> = texts
@@ -119,66 +226,69 @@ fn embed_sync(
         .collect::<Result<Vec<_>>>()?;
 
     let batch_size = encodings.len();
-    let max_len = encodings
+    let sequences: Vec<(&[u32], &[u32])> = encodings
         .iter()
-        .map(|e| e.get_ids().len())
-        .max()
-        .unwrap_or(0);
-
-    if max_len == 0 {
-        return Ok(vec![vec![0.0; EMBEDDING_DIM]; batch_size]);
-    }
+        .map(|e| (e.get_ids(), e.get_attention_mask()))
+        .collect();
 
-    // Build padded input tensors. BERT uses:
+    // Build padded input tensors, clipping long posts first. BERT uses:
     //   input_ids: token IDs (pad with 0)
     //   attention_mask: 1 for real tokens, 0 for padding
-    //   token_type_ids: all zeros for single-sentence input
-    let mut input_ids_flat: Vec<i64> = Vec::with_capacity(batch_size * max_len);
-    let mut attention_mask_flat: Vec<i64> = Vec::with_capacity(batch_size * max_len);
-    let mut token_type_ids_flat: Vec<i64> = Vec::with_capacity(batch_size * max_len);
-
-    for enc in &encodings {
-        let ids = enc.get_ids();
-        let mask = enc.get_attention_mask();
-        let seq_len = ids.len();
-
-        input_ids_flat.extend(ids.iter().map(|&id| id as i64));
-        attention_mask_flat.extend(mask.iter().map(|&m| m as i64));
-        token_type_ids_flat.extend(std::iter::repeat_n(0i64, seq_len));
-
-        // Pad to max_len (BERT pad token id = 0)
-        let pad_len = max_len - seq_len;
-        input_ids_flat.extend(std::iter::repeat_n(0i64, pad_len));
-        attention_mask_flat.extend(std::iter::repeat_n(0i64, pad_len));
-        token_type_ids_flat.extend(std::iter::repeat_n(0i64, pad_len));
+    //   token_type_ids: all zeros for single-sentence input (only passed
+    //   when the model declares it)
+    let batch = pad_batch(&sequences, MAX_SEQ_LEN, 0);
+    let max_len = batch.seq_len;
+    if max_len == 0 {
+        return Ok(vec![vec![0.0; dim]; batch_size]);
+    }
+    if batch.truncated > 0 {
+        debug!(
+            truncated = batch.truncated,
+            max_seq_len = MAX_SEQ_LEN,
+            "Truncated long texts before embedding"
+        );
     }
 
+    let input_ids_flat = batch.input_ids;
+    let attention_mask_flat = batch.attention_mask;
+    let token_type_ids_flat = vec![0i64; batch_size * max_len];
+
     let shape = [batch_size as i64, max_len as i64];
 
     let input_ids_tensor =
         Tensor::from_array((shape, input_ids_flat)).context("Failed to create input_ids tensor")?;
     let attention_mask_tensor = Tensor::from_array((shape, attention_mask_flat.clone()))
         .context("Failed to create attention_mask tensor")?;
-    let token_type_ids_tensor = Tensor::from_array((shape, token_type_ids_flat))
-        .context("Failed to create token_type_ids tensor")?;
 
-    // Run inference — output is last_hidden_state: [batch, seq_len, 384]
+    let mut inputs = ort::inputs! {
+        "input_ids" => input_ids_tensor,
+        "attention_mask" => attention_mask_tensor
+    };
+    if with_token_type_ids {
+        let token_type_ids_tensor = Tensor::from_array((shape, token_type_ids_flat))
+            .context("Failed to create token_type_ids tensor")?;
+        inputs.push((TOKEN_TYPE_IDS.into(), token_type_ids_tensor.into()));
+    }
+
+    // Run inference — output is last_hidden_state: [batch, seq_len, dim]
     let hidden_states = {
         let mut session = session
             .lock()
             .map_err(|e| anyhow::anyhow!("Session lock poisoned: {}", e))?;
 
         let outputs = session
-            .run(ort::inputs! {
-                "input_ids" => input_ids_tensor,
-                "attention_mask" => attention_mask_tensor,
-                "token_type_ids" => token_type_ids_tensor
-            })
+            .run(inputs)
             .context("Embedding ONNX inference failed")?;
 
-        let (_shape, data) = outputs[0]
+        let (shape, data) = outputs[0]
             .try_extract_tensor::<f32>()
             .context("Failed to extract embedding output tensor")?;
+        if hidden_dim(shape) != Some(dim) {
+            anyhow::bail!(
+                "Embedding model output has shape {:?}, expected [{batch_size}, {max_len}, {dim}]",
+                &shape[..]
+            );
+        }
 
         data.to_vec()
     };
@@ -189,15 +299,15 @@ fn embed_sync(
     let mut embeddings = Vec::with_capacity(batch_size);
 
     for i in 0..batch_size {
-        let mut sum = vec![0.0_f64; EMBEDDING_DIM];
+        let mut sum = vec![0.0_f64; dim];
         let mut mask_sum = 0.0_f64;
 
         for j in 0..max_len {
             let mask_val = attention_mask_flat[i * max_len + j] as f64;
             if mask_val > 0.0 {
                 mask_sum += mask_val;
-                let offset = (i * max_len + j) * EMBEDDING_DIM;
-                for k in 0..EMBEDDING_DIM {
+                let offset = (i * max_len + j) * dim;
+                for k in 0..dim {
                     sum[k] += hidden_states[offset + k] as f64 * mask_val;
                 }
             }
@@ -213,11 +323,7 @@ fn embed_sync(
         embeddings.push(sum);
     }
 
-    debug!(
-        batch_size = batch_size,
-        dim = EMBEDDING_DIM,
-        "Computed sentence embeddings"
-    );
+    debug!(batch_size = batch_size, dim, "Computed sentence embeddings");
 
     Ok(embeddings)
 }
@@ -227,19 +333,19 @@ fn embed_sync(
 /// Used to create a single "topic vector" for an account by averaging
 /// the embeddings of all their posts. This produces a stable centroid
 /// that represents the overall semantic space of what someone talks about.
-pub fn mean_embedding(embeddings: &[Vec<f64>]) -> Vec<f64> {
+/// `dim` is the loaded model's (`SentenceEmbedder::dim`), which every
+/// embedding it produced has.
+pub fn mean_embedding(embeddings: &[Vec<f64>], dim: usize) -> Vec<f64> {
     if embeddings.is_empty() {
-        return vec![0.0; EMBEDDING_DIM];
+        return vec![0.0; dim];
     }
 
     let n = embeddings.len() as f64;
-    let mut mean = vec![0.0_f64; EMBEDDING_DIM];
+    let mut mean = vec![0.0_f64; dim];
 
     for emb in embeddings {
-        for (i, &val) in emb.iter().enumerate() {
-            if i < EMBEDDING_DIM {
-                mean[i] += val;
-            }
+        for (m, &val) in mean.iter_mut().zip(emb) {
+            *m += val;
         }
     }
 
@@ -279,7 +385,7 @@ mod tests {
     #[test]
     fn test_mean_embedding_single() {
         let embeddings = vec![vec![1.0, 2.0, 3.0]];
-        let mean = mean_embedding(&embeddings);
+        let mean = mean_embedding(&embeddings, EMBEDDING_DIM);
         assert_eq!(mean.len(), EMBEDDING_DIM);
         assert!((mean[0] - 1.0).abs() < f64::EPSILON);
         assert!((mean[1] - 2.0).abs() < f64::EPSILON);
@@ -289,7 +395,7 @@ mod tests {
     #[test]
     fn test_mean_embedding_multiple() {
         let embeddings = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]];
-        let mean = mean_embedding(&embeddings);
+        let mean = mean_embedding(&embeddings, EMBEDDING_DIM);
         assert!((mean[0] - 0.5).abs() < f64::EPSILON);
         assert!((mean[1] - 0.5).abs() < f64::EPSILON);
         assert!((mean[2] - 0.0).abs() < f64::EPSILON);
@@ -298,7 +404,7 @@ mod tests {
     #[test]
     fn test_mean_embedding_empty() {
         let embeddings: Vec<Vec<f64>> = vec![];
-        let mean = mean_embedding(&embeddings);
+        let mean = mean_embedding(&embeddings, EMBEDDING_DIM);
         assert_eq!(mean.len(), EMBEDDING_DIM);
         assert!(mean.iter().all(|&v| v == 0.0));
     }
@@ -384,7 +490,7 @@ mod tests {
         // Averaging identical vectors should return the same vector
         let v = vec![0.5, -0.3, 0.8];
         let embeddings = vec![v.clone(), v.clone(), v.clone()];
-        let mean = mean_embedding(&embeddings);
+        let mean = mean_embedding(&embeddings, EMBEDDING_DIM);
         assert!((mean[0] - 0.5).abs() < 1e-10);
         assert!((mean[1] - -0.3).abs() < 1e-10);
         assert!((mean[2] - 0.8).abs() < 1e-10);
@@ -394,7 +500,7 @@ mod tests {
     fn test_mean_embedding_result_is_embedding_dim() {
         // Even short input vectors produce EMBEDDING_DIM-length output
         let embeddings = vec![vec![1.0, 2.0]];
-        let mean = mean_embedding(&embeddings);
+        let mean = mean_embedding(&embeddings, EMBEDDING_DIM);
         assert_eq!(mean.len(), EMBEDDING_DIM);
         // Elements beyond input length should be 0.0
         assert!((mean[0] - 1.0).abs() < f64::EPSILON);
@@ -402,6 +508,59 @@ mod tests {
         assert!((mean[2] - 0.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_mean_embedding_follows_model_dim() {
+        // A 128-dim model's vectors keep their length instead of being
+        // padded out to EMBEDDING_DIM
+        let embeddings = vec![vec![1.0; 128], vec![0.0; 128]];
+        let mean = mean_embedding(&embeddings, 128);
+        assert_eq!(mean.len(), 128);
+        assert!(mean.iter().all(|&v| (v - 0.5).abs() < f64::EPSILON));
+        assert_eq!(mean_embedding(&[], 128).len(), 128);
+    }
+
+    #[test]
+    fn test_stored_dim_mismatch_is_an_error() {
+        let stored = vec![0.1; 128];
+        assert!(check_stored_dim(&stored, 128).is_ok());
+        let err = check_stored_dim(&stored, EMBEDDING_DIM).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("128 dimensions"), "got: {msg}");
+        assert!(msg.contains("fingerprint --refresh"), "got: {msg}");
+    }
+
+    #[test]
+    fn test_hidden_dim_from_output_shape() {
+        assert_eq!(hidden_dim(&[-1, -1, 384]), Some(384));
+        assert_eq!(hidden_dim(&[2, 7, 128]), Some(128));
+        assert_eq!(hidden_dim(&[-1, -1, -1]), None);
+        assert_eq!(hidden_dim(&[-1, 384]), None);
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_token_type_ids_detected_when_declared() {
+        let bert = names(&["input_ids", "attention_mask", "token_type_ids"]);
+        assert!(check_input_names(&bert).is_ok());
+        assert!(accepts_token_type_ids(&bert));
+    }
+
+    #[test]
+    fn test_token_type_ids_skipped_when_absent() {
+        let slim = names(&["input_ids", "attention_mask"]);
+        assert!(check_input_names(&slim).is_ok());
+        assert!(!accepts_token_type_ids(&slim));
+    }
+
+    #[test]
+    fn test_missing_required_input_rejected() {
+        let err = check_input_names(&names(&["input_ids", "token_type_ids"])).unwrap_err();
+        assert!(err.to_string().contains("attention_mask"), "got: {err}");
+    }
+
     #[test]
     fn test_cosine_full_dimension_vectors() {
         // Test with actual EMBEDDING_DIM-sized vectors
@@ -4,8 +4,62 @@
 // keywords, and a weight indicating how prominent that topic is in the
 // person's posting history.
 
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::output::reporter::Reporter;
+
+/// Schema version written into newly built fingerprints. Bump it whenever
+/// the stored shape changes and teach `TopicFingerprint::upgrade` to migrate
+/// the previous version.
+///
+/// Version history:
+/// - 0: blobs written before the field existed. Clusters were not guaranteed
+///   to be sorted by weight.
+/// - 1: clusters sorted by weight, highest first.
+pub const FINGERPRINT_SCHEMA_VERSION: u32 = 1;
+
+/// Default age (days) after which a stored fingerprint is considered stale
+/// and commands recommend rebuilding it.
+pub const DEFAULT_MAX_AGE_DAYS: u32 = 60;
+
+/// A stored fingerprint's age in days, from the `updated_at` value returned
+/// by `Database::get_fingerprint`. `None` if the timestamp can't be parsed.
+pub fn age_days(updated_at: &str) -> Option<i64> {
+    crate::db::timestamps::age_in_days(updated_at, chrono::Utc::now())
+}
+
+/// Whether a fingerprint of `age_days` has outlived `max_age_days`.
+/// A `max_age_days` of 0 disables the check.
+pub fn is_stale(age_days: i64, max_age_days: u32) -> bool {
+    max_age_days > 0 && age_days > i64::from(max_age_days)
+}
+
+/// Default interval for the dashboard server's scheduled fingerprint refresh.
+pub const DEFAULT_REFRESH_DAYS: u32 = 7;
+
+/// Whether a fingerprint of `age_days` is due for a scheduled refresh every
+/// `refresh_days`. A `refresh_days` of 0 disables scheduled refreshes.
+pub fn is_refresh_due(age_days: i64, refresh_days: u32) -> bool {
+    refresh_days > 0 && age_days >= i64::from(refresh_days)
+}
+
+/// Warn through `reporter` if a fingerprint of `age_days` is stale. Returns
+/// whether the warning was shown.
+pub fn warn_if_stale(age_days: i64, max_age_days: u32, reporter: &Reporter) -> bool {
+    if !is_stale(age_days, max_age_days) {
+        return false;
+    }
+    reporter.warn(format!(
+        "your topic fingerprint is {age_days} days old (limit {max_age_days}). \
+         Interests drift — run `charcoal fingerprint --refresh` to keep overlap scores current."
+    ));
+    true
+}
 
 /// A complete topic fingerprint for an account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +68,10 @@ pub struct TopicFingerprint {
     pub clusters: Vec<TopicCluster>,
     /// Total number of posts analyzed to build this fingerprint
     pub post_count: u32,
+    /// Shape version of this fingerprint. Blobs stored before versioning
+    /// deserialize as 0 — run them through `upgrade` before use.
+    #[serde(default)]
+    pub schema_version: u32,
 }
 
 /// A single topic cluster — a group of related keywords with a label.
@@ -29,13 +87,57 @@ pub struct TopicCluster {
 }
 
 impl TopicFingerprint {
-    /// Display the fingerprint as a formatted bar chart in the terminal.
+    /// Parse a stored fingerprint blob and upgrade it to the current schema.
+    ///
+    /// Fails on blobs that aren't a fingerprint at all, and on blobs written
+    /// by a newer charcoal than this one, rather than guessing at them.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let fingerprint: Self =
+            serde_json::from_str(json).context("Stored topic fingerprint is not valid")?;
+        if fingerprint.schema_version > FINGERPRINT_SCHEMA_VERSION {
+            anyhow::bail!(
+                "Stored topic fingerprint has schema version {}, but this build only \
+                 understands up to {FINGERPRINT_SCHEMA_VERSION} — upgrade charcoal or \
+                 run `charcoal fingerprint --refresh`",
+                fingerprint.schema_version
+            );
+        }
+        Ok(fingerprint.upgrade())
+    }
+
+    /// Migrate an older fingerprint to `FINGERPRINT_SCHEMA_VERSION`.
+    /// Current-version fingerprints are returned unchanged.
+    pub fn upgrade(mut self) -> Self {
+        if self.schema_version < 1 {
+            // v0 → v1: callers rely on clusters being ranked by weight
+            self.clusters.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+        }
+        self.schema_version = self.schema_version.max(FINGERPRINT_SCHEMA_VERSION);
+        self
+    }
+
+    /// Report the fingerprint as a `fingerprint` result: a formatted bar
+    /// chart for humans (see `render`), the clusters themselves with `--json`.
+    pub fn display(&self, reporter: &Reporter) {
+        reporter.result(
+            "fingerprint",
+            self.render(),
+            json!({
+                "post_count": self.post_count,
+                "clusters": self.clusters,
+            }),
+        );
+    }
+
+    /// The fingerprint as a formatted bar chart for the terminal.
     ///
     /// This is the output Bryan sees when running `charcoal fingerprint` —
     /// it should be scannable and help him validate whether the system
     /// understands his topic profile correctly.
-    pub fn display(&self) {
-        println!(
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
             "\n{}",
             format!(
                 "=== Your Topic Fingerprint (based on {} recent posts) ===",
@@ -43,7 +145,6 @@ impl TopicFingerprint {
             )
             .bold()
         );
-        println!();
 
         let bar_width: usize = 20;
 
@@ -62,8 +163,9 @@ impl TopicFingerprint {
                 bar.bright_blue()
             };
 
-            println!(
-                "  {:>2}. {:<40} {} {:.2}",
+            let _ = writeln!(
+                out,
+                "\n  {:>2}. {:<40} {} {:.2}",
                 i + 1,
                 cluster.label.bold(),
                 colored_bar,
@@ -72,9 +174,9 @@ impl TopicFingerprint {
 
             // Show the keywords below the bar
             let keywords_str = cluster.keywords.join(", ");
-            println!("      Keywords: {}", keywords_str.dimmed());
-            println!();
+            let _ = writeln!(out, "      Keywords: {}", keywords_str.dimmed());
         }
+        out
     }
 
     /// Get the keyword weights as a flat map (keyword -> weight).
@@ -91,6 +193,32 @@ impl TopicFingerprint {
         }
         weights
     }
+
+    /// True when the fingerprint carries no usable topic signal — no clusters,
+    /// or only clusters with no keywords or zero weight.
+    ///
+    /// A degenerate fingerprint makes every overlap 0.0, so every account gets
+    /// gated to Low. Callers should warn and suggest rebuilding it.
+    pub fn is_degenerate(&self) -> bool {
+        self.clusters
+            .iter()
+            .all(|c| c.keywords.is_empty() || c.weight <= 0.0)
+    }
+
+    /// Warn through `reporter` if the fingerprint is degenerate. Returns
+    /// whether the warning was shown.
+    pub fn warn_if_degenerate(&self, reporter: &Reporter) -> bool {
+        if !self.is_degenerate() {
+            return false;
+        }
+        reporter.warn(format!(
+            "your topic fingerprint has no usable topic clusters (built from {} posts). \
+             Topic overlap will be 0.0 for every account, so all scores will read Low. \
+             Post more, then run `charcoal fingerprint --refresh` to rebuild it.",
+            self.post_count
+        ));
+        true
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +241,7 @@ mod tests {
                 },
             ],
             post_count: 100,
+            schema_version: FINGERPRINT_SCHEMA_VERSION,
         };
 
         let weights = fp.keyword_weights();
@@ -1,7 +1,9 @@
-// Topic extraction — TF-IDF fingerprinting, embeddings, and overlap scoring.
+// Topic extraction — TF-IDF fingerprinting (single words or n-gram phrases),
+// embeddings, and overlap scoring.
 
 pub mod embeddings;
 pub mod fingerprint;
+pub mod ngram;
 pub mod overlap;
 pub mod tfidf;
 pub mod traits;
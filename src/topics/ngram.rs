@@ -0,0 +1,221 @@
+// N-gram TF-IDF extraction — topic phrases instead of single words.
+//
+// Single-word TF-IDF splits phrases like "diet culture" or "weight stigma"
+// into words that land in clusters of their own. This extractor scores
+// contiguous 2- and 3-word phrases alongside single words, so a phrase that
+// recurs across posts becomes one keyword.
+//
+// Stop words (and junk tokens) are removed first and act as phrase
+// boundaries: "weight of stigma" yields "weight" and "stigma", never the
+// phrase "weight stigma", which keeps every phrase a run of words that
+// actually appears in a post.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use stop_words::{get, LANGUAGE};
+use tracing::info;
+
+use super::fingerprint::{TopicFingerprint, FINGERPRINT_SCHEMA_VERSION};
+use super::tfidf::{clean_post, cluster_keywords, extra_stop_words, is_meaningful_keyword};
+use super::traits::TopicExtractor;
+
+/// Posts a multi-word phrase must appear in to be a candidate keyword. Nearly
+/// every pair of adjacent words is a phrase somewhere, and one-off pairs
+/// would otherwise crowd the ranking on their high IDF alone.
+const MIN_PHRASE_POSTS: usize = 2;
+
+/// TF-IDF topic extractor over single words and phrases of up to
+/// `max_ngram` words.
+///
+/// With `max_ngram: 1` it scores single words only, like `TfIdfExtractor`
+/// (though with its own TF-IDF weighting, so the two don't produce identical
+/// fingerprints). Fingerprints compared for overlap should come from the same
+/// extractor settings — a phrase keyword only matches the same phrase.
+pub struct NgramExtractor {
+    /// How many top keywords (words and phrases) to extract before clustering
+    pub top_n_keywords: usize,
+    /// How many topic clusters to produce in the fingerprint
+    pub max_clusters: usize,
+    /// Longest phrase, in words, considered as a keyword (1 for single words)
+    pub max_ngram: usize,
+}
+
+impl Default for NgramExtractor {
+    fn default() -> Self {
+        Self {
+            top_n_keywords: 60,
+            max_clusters: 10,
+            max_ngram: 3,
+        }
+    }
+}
+
+impl TopicExtractor for NgramExtractor {
+    fn extract(&self, posts: &[String]) -> Result<TopicFingerprint> {
+        if posts.is_empty() {
+            anyhow::bail!("No posts to analyze — cannot build a topic fingerprint");
+        }
+
+        let cleaned: Vec<String> = posts.iter().map(|p| clean_post(p).to_lowercase()).collect();
+
+        let mut stop_words: HashSet<String> = get(LANGUAGE::English).into_iter().collect();
+        stop_words.extend(extra_stop_words().into_iter().map(String::from));
+
+        let documents: Vec<Vec<String>> = cleaned
+            .iter()
+            .map(|post| post_terms(post, &stop_words, self.max_ngram.max(1)))
+            .collect();
+
+        let ranked: Vec<(String, f32)> = rank_terms(&documents)
+            .into_iter()
+            .take(self.top_n_keywords)
+            .collect();
+
+        if ranked.is_empty() {
+            anyhow::bail!(
+                "N-gram TF-IDF produced no keywords from {} posts — posts may be too short or uniform",
+                posts.len()
+            );
+        }
+
+        info!(
+            keywords = ranked.len(),
+            phrases = ranked.iter().filter(|(term, _)| term.contains(' ')).count(),
+            top_keyword = &ranked[0].0,
+            top_score = ranked[0].1,
+            "Extracted n-gram TF-IDF keywords"
+        );
+
+        let clusters = cluster_keywords(&ranked, &cleaned, self.max_clusters);
+
+        Ok(TopicFingerprint {
+            clusters,
+            post_count: posts.len() as u32,
+            schema_version: FINGERPRINT_SCHEMA_VERSION,
+        })
+    }
+}
+
+/// Every candidate term in a cleaned, lowercased post, repeats included:
+/// each kept word, plus each run of 2 to `max_ngram` kept words that no
+/// stop word or junk token interrupts.
+fn post_terms(post: &str, stop_words: &HashSet<String>, max_ngram: usize) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut run: Vec<&str> = Vec::new();
+
+    let mut flush = |run: &mut Vec<&str>| {
+        for n in 1..=max_ngram.min(run.len()) {
+            terms.extend(run.windows(n).map(|words| words.join(" ")));
+        }
+        run.clear();
+    };
+
+    for word in post.split_whitespace() {
+        if stop_words.contains(word) || !is_meaningful_keyword(word) {
+            flush(&mut run);
+        } else {
+            run.push(word);
+        }
+    }
+    flush(&mut run);
+
+    terms
+}
+
+/// Score every term across `documents` (one per post) by TF-IDF, highest
+/// first. A term's score is its term frequency in each post, times its
+/// smoothed inverse document frequency, summed over posts. Phrases found in
+/// fewer than `MIN_PHRASE_POSTS` posts are dropped. Ties sort alphabetically
+/// so the ranking is deterministic.
+fn rank_terms(documents: &[Vec<String>]) -> Vec<(String, f32)> {
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for terms in documents {
+        let unique: HashSet<&str> = terms.iter().map(String::as_str).collect();
+        for term in unique {
+            *document_frequency.entry(term).or_default() += 1;
+        }
+    }
+
+    let n = documents.len() as f64;
+    let idf = |df: usize| ((1.0 + n) / (1.0 + df as f64)).ln() + 1.0;
+
+    let mut scores: HashMap<&str, f64> = HashMap::new();
+    for terms in documents {
+        if terms.is_empty() {
+            continue;
+        }
+        let len = terms.len() as f64;
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for term in terms {
+            *counts.entry(term.as_str()).or_default() += 1;
+        }
+        for (term, count) in counts {
+            *scores.entry(term).or_default() += count as f64 / len * idf(document_frequency[term]);
+        }
+    }
+
+    let mut ranked: Vec<(String, f32)> = scores
+        .into_iter()
+        .filter(|(term, _)| !term.contains(' ') || document_frequency[term] >= MIN_PHRASE_POSTS)
+        .map(|(term, score)| (term.to_string(), score as f32))
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stop_words() -> HashSet<String> {
+        ["and", "of", "the"].into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_post_terms_break_phrases_at_stop_words() {
+        let terms = post_terms("weight stigma and diet culture", &stop_words(), 2);
+        assert_eq!(
+            terms,
+            vec![
+                "weight",
+                "stigma",
+                "weight stigma",
+                "diet",
+                "culture",
+                "diet culture"
+            ]
+        );
+
+        // A stop word in between means no phrase across it
+        let terms = post_terms("weight of stigma", &stop_words(), 3);
+        assert_eq!(terms, vec!["weight", "stigma"]);
+    }
+
+    #[test]
+    fn test_post_terms_trigrams() {
+        let terms = post_terms("civil rights movement", &stop_words(), 3);
+        assert!(terms.contains(&"civil rights".to_string()));
+        assert!(terms.contains(&"rights movement".to_string()));
+        assert!(terms.contains(&"civil rights movement".to_string()));
+        assert_eq!(terms.len(), 6);
+    }
+
+    #[test]
+    fn test_one_off_phrases_are_not_ranked() {
+        let documents = vec![
+            post_terms("weight stigma hurts", &stop_words(), 2),
+            post_terms("weight stigma everywhere", &stop_words(), 2),
+        ];
+        let ranked = rank_terms(&documents);
+        let terms: Vec<&str> = ranked.iter().map(|(t, _)| t.as_str()).collect();
+        assert!(terms.contains(&"weight stigma"));
+        assert!(!terms.contains(&"stigma hurts"));
+        // Single words are kept even when they appear once
+        assert!(terms.contains(&"hurts"));
+    }
+}
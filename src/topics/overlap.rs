@@ -15,7 +15,74 @@
 
 use std::collections::HashMap;
 
-use super::fingerprint::TopicFingerprint;
+use super::fingerprint::{TopicCluster, TopicFingerprint};
+
+/// How an account's topic overlap was computed. Scores from different
+/// methods aren't directly comparable — a 0.3 keyword cosine and a 0.3
+/// embedding cosine mean different things — so the method is stored
+/// alongside the score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlapMethod {
+    /// TF-IDF keyword cosine against the protected fingerprint
+    Keyword,
+    /// Sentence-embedding cosine against the protected embedding
+    Embedding,
+}
+
+impl OverlapMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OverlapMethod::Keyword => "keyword",
+            OverlapMethod::Embedding => "embedding",
+        }
+    }
+}
+
+/// Display label for a stored overlap method. Rows scored before the
+/// method was tracked read back as "unknown".
+pub fn overlap_method_label(method: Option<&str>) -> &str {
+    method.unwrap_or("unknown")
+}
+
+/// Like `overlap_method_label`, plus a note when the overlap rests on too
+/// few posts to trust (`overlap_post_count` is set), e.g.
+/// "keyword, overlap based on only 3 posts".
+pub fn overlap_method_note(method: Option<&str>, post_count: Option<u32>) -> String {
+    let label = overlap_method_label(method);
+    match post_count {
+        Some(1) => format!("{label}, overlap based on only 1 post"),
+        Some(n) => format!("{label}, overlap based on only {n} posts"),
+        None => label.to_string(),
+    }
+}
+
+/// Default minimum number of posts for a trusted topic overlap. Below it, a
+/// mean embedding is dominated by one or two posts.
+pub const DEFAULT_MIN_OVERLAP_POSTS: usize = 5;
+
+/// Pick how to compute an account's topic overlap from `post_count`
+/// analyzable posts. Embeddings are used when loaded and the account has at
+/// least `min_posts` posts; otherwise TF-IDF keywords, which can't
+/// manufacture similarity from a couple of posts the way a noisy mean
+/// embedding can.
+pub fn choose_overlap_method(
+    post_count: usize,
+    embeddings_available: bool,
+    min_posts: usize,
+) -> OverlapMethod {
+    if embeddings_available && post_count >= min_posts {
+        OverlapMethod::Embedding
+    } else {
+        OverlapMethod::Keyword
+    }
+}
+
+/// The post count to record as `overlap_post_count`: `Some` only when it's
+/// below `min_posts`, marking the overlap low-confidence whatever method
+/// computed it.
+pub fn low_confidence_post_count(post_count: usize, min_posts: usize) -> Option<u32> {
+    (post_count < min_posts).then_some(post_count as u32)
+}
 
 /// Compute the cosine similarity between two fingerprints.
 ///
@@ -58,10 +125,202 @@ pub fn cosine_from_weights(
     }
 }
 
+/// A protected fingerprint with its keyword weights and norm computed once.
+///
+/// The protected side of every overlap comparison in a scan is the same, so
+/// rebuilding its weight map per scored account is wasted work. Prepare it
+/// once and compare with `cosine_against_prepared`.
+#[derive(Debug, Clone)]
+pub struct PreparedFingerprint {
+    weights: HashMap<String, f64>,
+    norm: f64,
+    /// Kept for `breakdown_against_prepared`, which reports per cluster
+    clusters: Vec<TopicCluster>,
+}
+
+impl PreparedFingerprint {
+    pub fn new(fingerprint: &TopicFingerprint) -> Self {
+        let weights = fingerprint.keyword_weights();
+        let norm = weights.values().map(|v| v * v).sum::<f64>().sqrt();
+        Self {
+            weights,
+            norm,
+            clusters: fingerprint.clusters.clone(),
+        }
+    }
+
+    /// The cached keyword weight map.
+    pub fn weights(&self) -> &HashMap<String, f64> {
+        &self.weights
+    }
+
+    /// The cached Euclidean norm of the weight vector.
+    pub fn norm(&self) -> f64 {
+        self.norm
+    }
+}
+
+impl From<&TopicFingerprint> for PreparedFingerprint {
+    fn from(fingerprint: &TopicFingerprint) -> Self {
+        Self::new(fingerprint)
+    }
+}
+
+/// Cosine similarity between a prepared fingerprint and another fingerprint.
+///
+/// Equivalent to `cosine_similarity(prepared_source, other)`, but only the
+/// `other` side's weights and norm are computed.
+pub fn cosine_against_prepared(prepared: &PreparedFingerprint, other: &TopicFingerprint) -> f64 {
+    let weights_b = other.keyword_weights();
+    if prepared.weights.is_empty() || weights_b.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f64 = weights_b
+        .iter()
+        .filter_map(|(key, &b)| prepared.weights.get(key).map(|&a| a * b))
+        .sum();
+    let mag_b: f64 = weights_b.values().map(|v| v * v).sum::<f64>().sqrt();
+
+    let denominator = prepared.norm * mag_b;
+    if denominator < f64::EPSILON {
+        0.0
+    } else {
+        (dot / denominator).clamp(0.0, 1.0)
+    }
+}
+
+/// One of the protected user's topic clusters and how much it contributed
+/// to a keyword overlap score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterMatch {
+    pub label: String,
+    /// The cluster's weight in the protected fingerprint
+    pub weight: f64,
+    /// Share of the keyword cosine from this cluster's keywords. Summed over
+    /// every cluster, contributions equal the (unclamped) cosine.
+    pub contribution: f64,
+    /// Keywords the target shares with this cluster, strongest first
+    pub shared_keywords: Vec<String>,
+}
+
+/// Why an account's topic overlap came out the way it did — shown by
+/// `charcoal score` so a surprising overlap (or gate) can be traced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlapBreakdown {
+    /// How the stored overlap was computed
+    pub method: OverlapMethod,
+    /// TF-IDF keyword cosine. Equals the stored overlap for `Keyword`; for
+    /// `Embedding` it's the keyword view of the same posts, for comparison.
+    pub keyword_overlap: f64,
+    /// Protected clusters sharing at least one keyword with the target,
+    /// largest contribution first
+    pub clusters: Vec<ClusterMatch>,
+    /// Every protected cluster's contribution to the keyword cosine, in
+    /// fingerprint order (see `cosine_contributions`)
+    pub topic_contributions: Vec<(String, f64)>,
+}
+
+/// Each of the protected user's clusters with its share of the keyword
+/// cosine against `other`, in fingerprint order. Clusters sharing no
+/// keywords with `other` contribute 0.0, and the contributions sum to
+/// `cosine_similarity(protected, other)`.
+///
+/// `cosine_similarity` is unchanged; this splits the same dot product by
+/// which protected cluster each keyword's weight came from.
+pub fn cosine_contributions(
+    protected: &TopicFingerprint,
+    other: &TopicFingerprint,
+) -> Vec<(String, f64)> {
+    contributions_against_prepared(&PreparedFingerprint::new(protected), other)
+}
+
+/// `cosine_contributions` against a prepared protected fingerprint.
+pub fn contributions_against_prepared(
+    prepared: &PreparedFingerprint,
+    other: &TopicFingerprint,
+) -> Vec<(String, f64)> {
+    let weights_b = other.keyword_weights();
+    let denominator = prepared.norm * norm_of(&weights_b);
+    prepared
+        .clusters
+        .iter()
+        .map(|cluster| {
+            let contribution = if denominator < f64::EPSILON {
+                0.0
+            } else {
+                shared_keyword_contributions(cluster, &weights_b, denominator)
+                    .iter()
+                    .map(|(_, c)| c)
+                    .sum()
+            };
+            (cluster.label.clone(), contribution)
+        })
+        .collect()
+}
+
+/// Split the keyword cosine between a prepared fingerprint and another
+/// fingerprint into per-cluster contributions (see `ClusterMatch`).
+pub fn breakdown_against_prepared(
+    prepared: &PreparedFingerprint,
+    other: &TopicFingerprint,
+) -> Vec<ClusterMatch> {
+    let weights_b = other.keyword_weights();
+    let denominator = prepared.norm * norm_of(&weights_b);
+    if denominator < f64::EPSILON {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<ClusterMatch> = prepared
+        .clusters
+        .iter()
+        .filter_map(|cluster| {
+            let mut shared = shared_keyword_contributions(cluster, &weights_b, denominator);
+            if shared.is_empty() {
+                return None;
+            }
+            shared.sort_by(|a, b| b.1.total_cmp(&a.1));
+            Some(ClusterMatch {
+                label: cluster.label.clone(),
+                weight: cluster.weight,
+                contribution: shared.iter().map(|(_, c)| c).sum(),
+                shared_keywords: shared.into_iter().map(|(kw, _)| kw.clone()).collect(),
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.contribution.total_cmp(&a.contribution));
+    matches
+}
+
+/// The keywords `cluster` shares with `weights_b`, each with its term of the
+/// cosine's dot product divided by `denominator` (the product of norms).
+fn shared_keyword_contributions<'a>(
+    cluster: &'a TopicCluster,
+    weights_b: &HashMap<String, f64>,
+    denominator: f64,
+) -> Vec<(&'a String, f64)> {
+    // Same per-keyword split as `TopicFingerprint::keyword_weights`
+    let per_keyword = cluster.weight / cluster.keywords.len().max(1) as f64;
+    cluster
+        .keywords
+        .iter()
+        .filter_map(|kw| {
+            weights_b
+                .get(kw)
+                .map(|&b| (kw, per_keyword * b / denominator))
+        })
+        .collect()
+}
+
+/// Euclidean norm of a keyword weight map.
+fn norm_of(weights: &HashMap<String, f64>) -> f64 {
+    weights.values().map(|v| v * v).sum::<f64>().sqrt()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::topics::fingerprint::{TopicCluster, TopicFingerprint};
+    use crate::topics::fingerprint::{TopicCluster, TopicFingerprint, FINGERPRINT_SCHEMA_VERSION};
 
     fn make_fp(keywords_and_weights: &[(&str, f64)]) -> TopicFingerprint {
         let clusters: Vec<TopicCluster> = keywords_and_weights
@@ -75,6 +334,7 @@ mod tests {
         TopicFingerprint {
             clusters,
             post_count: 100,
+            schema_version: FINGERPRINT_SCHEMA_VERSION,
         }
     }
 
@@ -131,4 +391,78 @@ mod tests {
             "Proportional weights should score ~1.0, got {score}"
         );
     }
+
+    #[test]
+    fn test_breakdown_sums_to_cosine() {
+        let protected = TopicFingerprint {
+            clusters: vec![
+                TopicCluster {
+                    label: "body politics".to_string(),
+                    keywords: vec!["fat".to_string(), "diet".to_string()],
+                    weight: 0.6,
+                },
+                TopicCluster {
+                    label: "queer".to_string(),
+                    keywords: vec!["queer".to_string()],
+                    weight: 0.3,
+                },
+                TopicCluster {
+                    label: "music".to_string(),
+                    keywords: vec!["vinyl".to_string()],
+                    weight: 0.1,
+                },
+            ],
+            post_count: 100,
+            schema_version: FINGERPRINT_SCHEMA_VERSION,
+        };
+        let target = make_fp(&[("diet", 0.5), ("queer", 0.2), ("gaming", 0.4)]);
+        let prepared = PreparedFingerprint::new(&protected);
+
+        let matches = breakdown_against_prepared(&prepared, &target);
+        let labels: Vec<&str> = matches.iter().map(|m| m.label.as_str()).collect();
+        assert_eq!(labels, vec!["body politics", "queer"]);
+        assert_eq!(matches[0].shared_keywords, vec!["diet".to_string()]);
+
+        let total: f64 = matches.iter().map(|m| m.contribution).sum();
+        let cosine = cosine_against_prepared(&prepared, &target);
+        assert!((total - cosine).abs() < 1e-9, "{total} vs {cosine}");
+    }
+
+    #[test]
+    fn test_breakdown_empty_without_shared_keywords() {
+        let prepared = PreparedFingerprint::new(&make_fp(&[("fat", 0.3)]));
+        assert!(breakdown_against_prepared(&prepared, &make_fp(&[("sports", 0.4)])).is_empty());
+        assert!(breakdown_against_prepared(&prepared, &make_fp(&[])).is_empty());
+    }
+
+    #[test]
+    fn test_contributions_sum_to_cosine() {
+        let protected = make_fp(&[("fat", 0.5), ("queer", 0.3), ("vinyl", 0.2)]);
+        let target = make_fp(&[("fat", 0.4), ("queer", 0.6), ("gaming", 0.4)]);
+
+        let contributions = cosine_contributions(&protected, &target);
+        let labels: Vec<&str> = contributions.iter().map(|(l, _)| l.as_str()).collect();
+        assert_eq!(labels, vec!["fat", "queer", "vinyl"]);
+
+        let total: f64 = contributions.iter().map(|(_, c)| c).sum();
+        let cosine = cosine_similarity(&protected, &target);
+        assert!((total - cosine).abs() < 1e-9, "{total} vs {cosine}");
+    }
+
+    #[test]
+    fn test_contributions_zero_for_non_overlapping_clusters() {
+        let protected = make_fp(&[("fat", 0.5), ("vinyl", 0.2)]);
+        let target = make_fp(&[("fat", 0.4), ("sports", 0.6)]);
+
+        let contributions = cosine_contributions(&protected, &target);
+        assert!(contributions[0].1 > 0.0);
+        assert_eq!(contributions[1], ("vinyl".to_string(), 0.0));
+
+        let disjoint = cosine_contributions(&protected, &make_fp(&[("sports", 0.6)]));
+        assert!(disjoint.iter().all(|(_, c)| *c == 0.0));
+        assert_eq!(disjoint.len(), 2);
+        assert!(cosine_contributions(&protected, &make_fp(&[]))
+            .iter()
+            .all(|(_, c)| *c == 0.0));
+    }
 }
@@ -24,7 +24,7 @@ static MENTION_PATTERN: LazyLock<regex_lite::Regex> =
 static WHITESPACE_PATTERN: LazyLock<regex_lite::Regex> =
     LazyLock::new(|| regex_lite::Regex::new(r"\s+").unwrap());
 
-use super::fingerprint::{TopicCluster, TopicFingerprint};
+use super::fingerprint::{TopicCluster, TopicFingerprint, FINGERPRINT_SCHEMA_VERSION};
 use super::traits::TopicExtractor;
 
 /// TF-IDF based topic extractor — the default for the MVP.
@@ -93,6 +93,7 @@ impl TopicExtractor for TfIdfExtractor {
         Ok(TopicFingerprint {
             clusters,
             post_count: posts.len() as u32,
+            schema_version: FINGERPRINT_SCHEMA_VERSION,
         })
     }
 }
@@ -102,7 +103,7 @@ impl TopicExtractor for TfIdfExtractor {
 /// Normalizes smart quotes, strips URLs/mentions/hashtags, lowercases,
 /// and removes non-alphabetic noise. This dramatically improves keyword
 /// quality on real social media text.
-fn clean_post(text: &str) -> String {
+pub(crate) fn clean_post(text: &str) -> String {
     let mut cleaned = text.to_string();
 
     // Normalize smart quotes and other unicode punctuation to ASCII
@@ -186,7 +187,7 @@ fn clean_post(text: &str) -> String {
 ///
 /// The standard English stop word list misses many common social media words
 /// and fragments that aren't meaningful for topic detection.
-fn extra_stop_words() -> Vec<&'static str> {
+pub(crate) fn extra_stop_words() -> Vec<&'static str> {
     vec![
         // Common social media / conversational words
         "just",
@@ -435,7 +436,7 @@ fn extra_stop_words() -> Vec<&'static str> {
 ///
 /// Filters out single characters, pure numbers, and other junk that
 /// survives stop word filtering.
-fn is_meaningful_keyword(word: &str) -> bool {
+pub(crate) fn is_meaningful_keyword(word: &str) -> bool {
     // Must be at least 3 characters
     if word.len() < 3 {
         return false;
@@ -455,8 +456,9 @@ fn is_meaningful_keyword(word: &str) -> bool {
 ///
 /// Strategy: for each pair of keywords, count how often they appear in the
 /// same post. Then greedily build clusters by starting with the highest-scored
-/// keyword and pulling in its most co-occurring neighbors.
-fn cluster_keywords(
+/// keyword and pulling in its most co-occurring neighbors. Multi-word keywords
+/// (see `topics::ngram`) count as appearing when the post has the whole phrase.
+pub(crate) fn cluster_keywords(
     ranked: &[(String, f32)],
     posts: &[String],
     max_clusters: usize,
@@ -472,7 +474,7 @@ fn cluster_keywords(
             keywords
                 .iter()
                 .enumerate()
-                .filter(|(_, kw)| words.contains(kw))
+                .filter(|(_, kw)| contains_term(&words, kw))
                 .map(|(i, _)| i)
                 .collect()
         })
@@ -561,6 +563,16 @@ fn cluster_keywords(
     clusters
 }
 
+/// Whether a post's words contain `term` — a single word, or a phrase as
+/// consecutive words.
+fn contains_term(words: &[&str], term: &str) -> bool {
+    if !term.contains(' ') {
+        return words.contains(&term);
+    }
+    let parts: Vec<&str> = term.split(' ').collect();
+    words.windows(parts.len()).any(|w| w == parts.as_slice())
+}
+
 /// Generate a human-readable label from a cluster's top keywords.
 fn generate_cluster_label(keywords: &[String]) -> String {
     let label_words: Vec<&str> = keywords.iter().take(3).map(|s| s.as_str()).collect();
@@ -624,6 +636,15 @@ mod tests {
         assert!(cleaned.contains("not"));
     }
 
+    #[test]
+    fn test_contains_term_matches_whole_phrases() {
+        let words = ["weight", "stigma", "and", "diet", "culture"];
+        assert!(contains_term(&words, "stigma"));
+        assert!(contains_term(&words, "diet culture"));
+        assert!(!contains_term(&words, "weight culture"));
+        assert!(!contains_term(&words, "stig"));
+    }
+
     #[test]
     fn test_is_meaningful_keyword() {
         assert!(is_meaningful_keyword("fat"));
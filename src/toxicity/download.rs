@@ -6,31 +6,100 @@
 //
 // Files are stored in a platform-appropriate directory
 // (~/.local/share/charcoal/models/ on Linux) so they persist across runs.
+// Every file is fetched at the revision set for it below and checked against
+// its Content-Length and its pinned SHA-256; the digest is kept in a
+// `.sha256` sidecar that the model loaders check (`verify_sidecar`) before
+// opening the file. `scripts/pin-models.sh` prints the `PinnedFile`
+// constants for the current upstream commits.
 
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use indicatif::{ProgressBar, ProgressStyle};
-use tracing::info;
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::output::reporter::Reporter;
+
+/// A file fetched from a HuggingFace repo. `sha256` is what its bytes must
+/// hash to; a download that doesn't match is deleted. A file without a
+/// pinned digest is trusted on first download (with a warning) and only
+/// checked against its sidecar from then on.
+pub struct PinnedFile {
+    pub repo: &'static str,
+    /// Git revision the file is resolved at. Only a commit SHA keeps
+    /// upstream pushes from changing what gets downloaded; a branch name
+    /// like `main` follows whatever the repo holds at download time.
+    pub revision: &'static str,
+    pub path: &'static str,
+    pub sha256: Option<&'static str>,
+}
 
-/// HuggingFace repo for the toxicity model.
-const TOXICITY_HF_URL: &str =
-    "https://huggingface.co/protectai/unbiased-toxic-roberta-onnx/resolve/main";
+impl PinnedFile {
+    pub fn url(&self) -> String {
+        format!(
+            "https://huggingface.co/{}/resolve/{}/{}",
+            self.repo, self.revision, self.path
+        )
+    }
+}
 
-/// HuggingFace repo for the sentence embedding model.
-const EMBEDDING_HF_URL: &str =
-    "https://huggingface.co/sentence-transformers/all-MiniLM-L6-v2/resolve/main";
+/// HuggingFace repo and revision for the toxicity model.
+const TOXICITY_REPO: &str = "protectai/unbiased-toxic-roberta-onnx";
+const TOXICITY_REVISION: &str = "main";
 
-/// HuggingFace repo for the NLI cross-encoder model (contextual hostility scoring).
-const NLI_HF_URL: &str = "https://huggingface.co/Xenova/nli-deberta-v3-xsmall/resolve/main";
+/// HuggingFace repo and revision for the sentence embedding model.
+const EMBEDDING_REPO: &str = "sentence-transformers/all-MiniLM-L6-v2";
+const EMBEDDING_REVISION: &str = "main";
+
+/// HuggingFace repo and revision for the NLI cross-encoder model
+/// (contextual hostility scoring).
+const NLI_REPO: &str = "Xenova/nli-deberta-v3-xsmall";
+const NLI_REVISION: &str = "main";
 
 /// Files for the toxicity model.
 const TOXICITY_MODEL_FILE: &str = "model_quantized.onnx";
 const TOXICITY_TOKENIZER_FILE: &str = "tokenizer.json";
 
-/// Files for the sentence embedding model (stored in a subdirectory).
-const EMBEDDING_MODEL_FILE: &str = "onnx/model.onnx";
-const EMBEDDING_TOKENIZER_FILE: &str = "tokenizer.json";
+/// Every file `download_model` fetches, in download order: tokenizer then
+/// model for the toxicity, embedding and NLI models. Regenerate the
+/// revisions and digests with `scripts/pin-models.sh`.
+pub const TOXICITY_TOKENIZER: PinnedFile = PinnedFile {
+    repo: TOXICITY_REPO,
+    revision: TOXICITY_REVISION,
+    path: TOXICITY_TOKENIZER_FILE,
+    sha256: None,
+};
+pub const TOXICITY_MODEL: PinnedFile = PinnedFile {
+    repo: TOXICITY_REPO,
+    revision: TOXICITY_REVISION,
+    path: TOXICITY_MODEL_FILE,
+    sha256: None,
+};
+pub const EMBEDDING_TOKENIZER: PinnedFile = PinnedFile {
+    repo: EMBEDDING_REPO,
+    revision: EMBEDDING_REVISION,
+    path: "tokenizer.json",
+    sha256: None,
+};
+pub const EMBEDDING_MODEL: PinnedFile = PinnedFile {
+    repo: EMBEDDING_REPO,
+    revision: EMBEDDING_REVISION,
+    path: "onnx/model.onnx",
+    sha256: None,
+};
+pub const NLI_TOKENIZER: PinnedFile = PinnedFile {
+    repo: NLI_REPO,
+    revision: NLI_REVISION,
+    path: "tokenizer.json",
+    sha256: None,
+};
+pub const NLI_MODEL: PinnedFile = PinnedFile {
+    repo: NLI_REPO,
+    revision: NLI_REVISION,
+    path: "onnx/model_quantized.onnx",
+    sha256: None,
+};
 
 /// Returns the default directory for storing model files.
 /// Uses the platform data directory: ~/.local/share/charcoal/models/ on Linux.
@@ -46,15 +115,31 @@ pub fn embedding_model_dir(base: &Path) -> PathBuf {
     base.join("all-MiniLM-L6-v2")
 }
 
+/// Paths of the files the toxicity model needs.
+pub fn model_files(dir: &Path) -> [PathBuf; 2] {
+    [
+        dir.join(TOXICITY_MODEL_FILE),
+        dir.join(TOXICITY_TOKENIZER_FILE),
+    ]
+}
+
+/// Paths of the files the sentence embedding model needs.
+pub fn embedding_files(dir: &Path) -> [PathBuf; 2] {
+    let embed_dir = embedding_model_dir(dir);
+    [
+        embed_dir.join("model.onnx"),
+        embed_dir.join("tokenizer.json"),
+    ]
+}
+
 /// Check whether both required toxicity model files exist.
 pub fn model_files_present(dir: &Path) -> bool {
-    dir.join(TOXICITY_MODEL_FILE).exists() && dir.join(TOXICITY_TOKENIZER_FILE).exists()
+    model_files(dir).iter().all(|f| f.exists())
 }
 
 /// Check whether both required embedding model files exist.
 pub fn embedding_files_present(dir: &Path) -> bool {
-    let embed_dir = embedding_model_dir(dir);
-    embed_dir.join("model.onnx").exists() && embed_dir.join("tokenizer.json").exists()
+    embedding_files(dir).iter().all(|f| f.exists())
 }
 
 /// Subdirectory within model_dir for the NLI cross-encoder model.
@@ -70,45 +155,44 @@ pub fn nli_files_present(dir: &Path) -> bool {
 
 /// Download all ONNX models (toxicity + embedding).
 ///
-/// Shows progress bars for large files. Skips files that already exist.
-/// Creates directories as needed.
-pub async fn download_model(dir: &Path) -> Result<()> {
+/// Reports each file through `reporter` and shows progress bars for large
+/// files outside `--json`. Skips files that already exist, unless their
+/// `.sha256` sidecar shows they've been corrupted, in which case they're
+/// fetched again. Creates directories as needed.
+pub async fn download_model(dir: &Path, reporter: &Reporter) -> Result<()> {
     std::fs::create_dir_all(dir)
         .with_context(|| format!("Failed to create model directory: {}", dir.display()))?;
 
     // --- Toxicity model (Detoxify unbiased-toxic-roberta) ---
-    println!("\nToxicity model (unbiased-toxic-roberta):");
-
-    let tokenizer_path = dir.join(TOXICITY_TOKENIZER_FILE);
-    if tokenizer_path.exists() {
-        info!("Toxicity tokenizer already exists, skipping");
-        println!("  {} (already exists)", TOXICITY_TOKENIZER_FILE);
-    } else {
-        println!("  Downloading {}...", TOXICITY_TOKENIZER_FILE);
-        download_file(
-            &format!("{}/{}", TOXICITY_HF_URL, TOXICITY_TOKENIZER_FILE),
-            &tokenizer_path,
-            false,
-        )
-        .await?;
-    }
-
-    let model_path = dir.join(TOXICITY_MODEL_FILE);
-    if model_path.exists() {
-        info!("Toxicity model already exists, skipping");
-        println!("  {} (already exists)", TOXICITY_MODEL_FILE);
-    } else {
-        println!("  Downloading {} (~126 MB)...", TOXICITY_MODEL_FILE);
-        download_file(
-            &format!("{}/{}", TOXICITY_HF_URL, TOXICITY_MODEL_FILE),
-            &model_path,
-            true,
-        )
-        .await?;
-    }
+    report_model(
+        reporter,
+        "toxicity",
+        "Toxicity model",
+        "unbiased-toxic-roberta",
+    );
+
+    fetch_model_file(
+        &TOXICITY_TOKENIZER,
+        &dir.join(TOXICITY_TOKENIZER_FILE),
+        None,
+        reporter,
+    )
+    .await?;
+    fetch_model_file(
+        &TOXICITY_MODEL,
+        &dir.join(TOXICITY_MODEL_FILE),
+        Some("~126 MB"),
+        reporter,
+    )
+    .await?;
 
     // --- Sentence embedding model (all-MiniLM-L6-v2) ---
-    println!("\nSentence embedding model (all-MiniLM-L6-v2):");
+    report_model(
+        reporter,
+        "embedding",
+        "Sentence embedding model",
+        "all-MiniLM-L6-v2",
+    );
 
     let embed_dir = embedding_model_dir(dir);
     std::fs::create_dir_all(&embed_dir).with_context(|| {
@@ -118,36 +202,28 @@ pub async fn download_model(dir: &Path) -> Result<()> {
         )
     })?;
 
-    let embed_tokenizer_path = embed_dir.join("tokenizer.json");
-    if embed_tokenizer_path.exists() {
-        info!("Embedding tokenizer already exists, skipping");
-        println!("  tokenizer.json (already exists)");
-    } else {
-        println!("  Downloading tokenizer.json...");
-        download_file(
-            &format!("{}/{}", EMBEDDING_HF_URL, EMBEDDING_TOKENIZER_FILE),
-            &embed_tokenizer_path,
-            false,
-        )
-        .await?;
-    }
-
-    let embed_model_path = embed_dir.join("model.onnx");
-    if embed_model_path.exists() {
-        info!("Embedding model already exists, skipping");
-        println!("  model.onnx (already exists)");
-    } else {
-        println!("  Downloading model.onnx (~90 MB)...");
-        download_file(
-            &format!("{}/{}", EMBEDDING_HF_URL, EMBEDDING_MODEL_FILE),
-            &embed_model_path,
-            true,
-        )
-        .await?;
-    }
+    fetch_model_file(
+        &EMBEDDING_TOKENIZER,
+        &embed_dir.join("tokenizer.json"),
+        None,
+        reporter,
+    )
+    .await?;
+    fetch_model_file(
+        &EMBEDDING_MODEL,
+        &embed_dir.join("model.onnx"),
+        Some("~90 MB"),
+        reporter,
+    )
+    .await?;
 
     // --- NLI cross-encoder model (DeBERTa-v3-xsmall for contextual scoring) ---
-    println!("\nNLI cross-encoder model (nli-deberta-v3-xsmall):");
+    report_model(
+        reporter,
+        "nli",
+        "NLI cross-encoder model",
+        "nli-deberta-v3-xsmall",
+    );
 
     let nli_dir = nli_model_dir(dir);
     std::fs::create_dir_all(&nli_dir).with_context(|| {
@@ -157,95 +233,365 @@ pub async fn download_model(dir: &Path) -> Result<()> {
         )
     })?;
 
-    let nli_tokenizer_path = nli_dir.join("tokenizer.json");
-    if nli_tokenizer_path.exists() {
-        info!("NLI tokenizer already exists, skipping");
-        println!("  tokenizer.json (already exists)");
-    } else {
-        println!("  Downloading tokenizer.json...");
-        download_file(
-            &format!("{}/tokenizer.json", NLI_HF_URL),
-            &nli_tokenizer_path,
-            false,
-        )
-        .await?;
-    }
+    fetch_model_file(
+        &NLI_TOKENIZER,
+        &nli_dir.join("tokenizer.json"),
+        None,
+        reporter,
+    )
+    .await?;
+    fetch_model_file(
+        &NLI_MODEL,
+        &nli_dir.join("model_quantized.onnx"),
+        Some("~87 MB"),
+        reporter,
+    )
+    .await?;
 
-    let nli_model_path = nli_dir.join("model_quantized.onnx");
-    if nli_model_path.exists() {
-        info!("NLI model already exists, skipping");
-        println!("  model_quantized.onnx (already exists)");
-    } else {
-        println!("  Downloading model_quantized.onnx (~87 MB)...");
-        download_file(
-            &format!("{}/onnx/model_quantized.onnx", NLI_HF_URL),
-            &nli_model_path,
-            true,
-        )
-        .await?;
+    Ok(())
+}
+
+/// Announce the model whose files are fetched next.
+fn report_model(reporter: &Reporter, model: &str, label: &str, name: &str) {
+    reporter.status(
+        "model_download_started",
+        format!("\n{label} ({name}):"),
+        json!({ "model": model, "name": name }),
+    );
+}
+
+/// Download `file` to `dest` unless an intact copy is already there. Large
+/// files (those with a `size_hint`) get a progress bar outside `--json`.
+async fn fetch_model_file(
+    file: &PinnedFile,
+    dest: &Path,
+    size_hint: Option<&str>,
+    reporter: &Reporter,
+) -> Result<()> {
+    let name = dest
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    if dest.exists() {
+        let intact = match file.sha256 {
+            // A pinned digest also catches a file from an older revision
+            Some(expected) => sha256_file(dest).and_then(|actual| {
+                if actual.eq_ignore_ascii_case(expected) {
+                    Ok(())
+                } else {
+                    anyhow::bail!("{} doesn't match its pinned SHA-256", dest.display())
+                }
+            }),
+            None => verify_sidecar(dest),
+        };
+        match intact {
+            Ok(()) => {
+                info!(file = %dest.display(), "Model file already exists, skipping");
+                reporter.status(
+                    "model_file_present",
+                    format!("  {name} (already exists)"),
+                    json!({ "file": name }),
+                );
+                return Ok(());
+            }
+            Err(e) => {
+                warn!(error = %e, "Existing model file is corrupted, downloading again");
+                reporter.status(
+                    "model_file_corrupted",
+                    format!("  {name} is corrupted, downloading it again"),
+                    json!({ "file": name }),
+                );
+                std::fs::remove_file(dest)
+                    .with_context(|| format!("Failed to remove {}", dest.display()))?;
+            }
+        }
     }
+    let resuming = partial_path(dest).exists();
+    let verb = if resuming { "Resuming" } else { "Downloading" };
+    let message = match size_hint {
+        Some(size) => format!("  {verb} {name} ({size})..."),
+        None => format!("  {verb} {name}..."),
+    };
+    reporter.status(
+        "model_file_downloading",
+        message,
+        json!({ "file": name, "resuming": resuming }),
+    );
+    if file.sha256.is_none() {
+        warn!(
+            file = file.path,
+            repo = file.repo,
+            "No pinned SHA-256 for this file, trusting the download"
+        );
+    }
+    let show_progress = size_hint.is_some() && !reporter.is_json();
+    download_file(&file.url(), dest, show_progress, file.sha256).await
+}
 
-    Ok(())
+/// How many times a single file's download is attempted before giving up.
+/// Each retry resumes from whatever the previous attempt saved.
+const DOWNLOAD_ATTEMPTS: u32 = 3;
+
+/// Where an in-progress download is kept: the final path with `.part`
+/// appended. It's renamed into place only once it's complete and verified,
+/// so an interrupted download never looks like a finished model file.
+pub fn partial_path(dest: &Path) -> PathBuf {
+    let mut name = dest.as_os_str().to_os_string();
+    name.push(".part");
+    PathBuf::from(name)
 }
 
 /// Download a single file from a URL to a local path.
 /// If `show_progress` is true, display a progress bar.
-async fn download_file(url: &str, dest: &Path, show_progress: bool) -> Result<()> {
-    let client = reqwest::Client::new();
-    let response = client
-        .get(url)
+///
+/// The body is streamed into a `.part` file (see `partial_path`). If the
+/// connection drops, the download is retried with a `Range` header to pick
+/// up where it stopped — including on a later `download-model` run, since
+/// the `.part` file is left behind. Servers that ignore the range get a
+/// fresh download.
+///
+/// The finished file must match its expected size and, when given,
+/// `expected_sha256`; a file that doesn't is deleted and the download
+/// fails. The digest is saved in a `.sha256` sidecar next to the file.
+async fn download_file(
+    url: &str,
+    dest: &Path,
+    show_progress: bool,
+    expected_sha256: Option<&str>,
+) -> Result<()> {
+    let part = partial_path(dest);
+    let pb = show_progress.then(|| {
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .template("    {spinner} {bytes}")
+                .expect("valid template"),
+        );
+        pb
+    });
+
+    let mut attempt = 1;
+    let total_size = loop {
+        match download_part(url, &part, pb.as_ref()).await {
+            Ok(done) => break done,
+            Err(e) if attempt < DOWNLOAD_ATTEMPTS => {
+                warn!(error = %e, attempt, "Download interrupted, resuming");
+                attempt += 1;
+            }
+            Err(e) => {
+                if let Some(pb) = pb {
+                    pb.abandon();
+                }
+                return Err(e.context(format!(
+                    "Download of {url} failed after {DOWNLOAD_ATTEMPTS} attempts. \
+                     Run `charcoal download-model` again to resume it."
+                )));
+            }
+        }
+    };
+
+    if let Some(pb) = pb {
+        pb.finish_and_clear();
+    }
+
+    let downloaded = std::fs::metadata(&part)
+        .with_context(|| format!("Failed to read {}", part.display()))?
+        .len();
+    if let Some(expected) = total_size {
+        if downloaded != expected {
+            std::fs::remove_file(&part)
+                .with_context(|| format!("Failed to remove {}", part.display()))?;
+            anyhow::bail!(
+                "Download of {url} has the wrong size ({downloaded} of {expected} bytes). \
+                 Run `charcoal download-model` again."
+            );
+        }
+    }
+
+    let digest = match expected_sha256 {
+        Some(expected) => {
+            verify_digest(&part, expected)?;
+            expected.to_ascii_lowercase()
+        }
+        None => sha256_file(&part)?,
+    };
+    std::fs::rename(&part, dest)
+        .with_context(|| format!("Failed to move download into {}", dest.display()))?;
+    write_sidecar(dest, &digest)?;
+
+    info!("Downloaded {} to {}", url, dest.display());
+    Ok(())
+}
+
+/// One attempt at fetching `url` into `part`, resuming from its current
+/// length. Returns the full size of the file (if the server said) once the
+/// body has been read to the end.
+async fn download_part(url: &str, part: &Path, pb: Option<&ProgressBar>) -> Result<Option<u64>> {
+    use reqwest::header::{CONTENT_RANGE, RANGE};
+    use reqwest::StatusCode;
+    use std::io::Write;
+
+    let existing = std::fs::metadata(part).map(|m| m.len()).unwrap_or(0);
+    let mut request = crate::http::client().get(url);
+    if existing > 0 {
+        request = request.header(RANGE, format!("bytes={existing}-"));
+    }
+    let mut response = request
         .send()
         .await
         .with_context(|| format!("Failed to download {}", url))?;
 
+    // The `.part` file is already complete (or bigger than the file now is):
+    // start over rather than guess
+    if existing > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+        std::fs::remove_file(part)
+            .with_context(|| format!("Failed to remove {}", part.display()))?;
+        anyhow::bail!("Server rejected resume of {url}, restarting");
+    }
     if !response.status().is_success() {
         anyhow::bail!("Download failed with status {}: {}", response.status(), url);
     }
 
-    let total_size = response.content_length();
+    // 206 means the server honoured the range; a plain 200 is the whole
+    // file again, so the partial download is discarded
+    let resumed = existing > 0 && response.status() == StatusCode::PARTIAL_CONTENT;
+    let offset = if resumed { existing } else { 0 };
+    let total_size = if resumed {
+        response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(content_range_total)
+            .or_else(|| response.content_length().map(|len| len + offset))
+    } else {
+        response.content_length()
+    };
+    if resumed {
+        info!(url, offset, "Resuming partial download");
+    }
 
-    // Set up progress bar if requested and we know the size
-    let pb = if show_progress {
-        let pb = if let Some(size) = total_size {
-            let pb = ProgressBar::new(size);
+    // Set up the progress bar once the size is known, carrying over what
+    // earlier attempts already fetched
+    if let Some(pb) = pb {
+        if let Some(size) = total_size {
+            pb.set_length(size);
             pb.set_style(
                 ProgressStyle::default_bar()
                     .template("    [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
                     .expect("valid template")
                     .progress_chars("=> "),
             );
-            pb
-        } else {
-            let pb = ProgressBar::new_spinner();
-            pb.set_style(
-                ProgressStyle::default_spinner()
-                    .template("    {spinner} {bytes}")
-                    .expect("valid template"),
-            );
-            pb
-        };
-        Some(pb)
-    } else {
-        None
-    };
+        }
+        pb.set_position(offset);
+        pb.reset_eta();
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(resumed)
+        .truncate(!resumed)
+        .open(part)
+        .with_context(|| format!("Failed to open {}", part.display()))?;
 
     // Stream the response body to disk
-    let bytes = response
-        .bytes()
+    while let Some(chunk) = response
+        .chunk()
         .await
-        .context("Failed to read response body")?;
-
-    if let Some(ref pb) = pb {
-        pb.set_position(bytes.len() as u64);
+        .context("Connection lost while downloading")?
+    {
+        file.write_all(&chunk)
+            .with_context(|| format!("Failed to write {}", part.display()))?;
+        if let Some(pb) = pb {
+            pb.inc(chunk.len() as u64);
+        }
     }
+    file.flush()
+        .with_context(|| format!("Failed to write {}", part.display()))?;
 
-    std::fs::write(dest, &bytes).with_context(|| format!("Failed to write {}", dest.display()))?;
+    Ok(total_size)
+}
 
-    if let Some(pb) = pb {
-        pb.finish_and_clear();
+/// The full file size from a `Content-Range: bytes <start>-<end>/<total>`
+/// header, or `None` if the total is missing or `*`.
+pub fn content_range_total(header: &str) -> Option<u64> {
+    header.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+/// Hex-encoded SHA-256 of a file's contents, read in chunks so a model file
+/// isn't held in memory.
+pub fn sha256_file(path: &Path) -> Result<String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let mut file =
+        std::fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
     }
+    Ok(hex::encode(hasher.finalize()))
+}
 
-    info!("Downloaded {} to {}", url, dest.display());
+/// Check `path` against an expected SHA-256, deleting it on a mismatch so
+/// the next `download-model` fetches it again.
+pub fn verify_digest(path: &Path, expected: &str) -> Result<()> {
+    let actual = sha256_file(path)?;
+    if actual.eq_ignore_ascii_case(expected.trim()) {
+        return Ok(());
+    }
+    std::fs::remove_file(path)
+        .with_context(|| format!("Failed to remove corrupted {}", path.display()))?;
+    anyhow::bail!(
+        "{} failed its integrity check (SHA-256 {actual}, expected {}) and was deleted. \
+         Run `charcoal download-model` to fetch it again.",
+        path.display(),
+        expected.trim()
+    )
+}
+
+/// Where the digest of a downloaded model file is kept: the file's path
+/// with `.sha256` appended, e.g. `model.onnx.sha256`.
+pub fn sidecar_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".sha256");
+    PathBuf::from(name)
+}
+
+fn write_sidecar(path: &Path, digest: &str) -> Result<()> {
+    let sidecar = sidecar_path(path);
+    std::fs::write(&sidecar, format!("{digest}\n"))
+        .with_context(|| format!("Failed to write {}", sidecar.display()))
+}
+
+/// Check a model file against its `.sha256` sidecar before loading it, so
+/// a file corrupted on disk fails with an actionable message instead of an
+/// obscure ONNX or tokenizer error. Files downloaded before sidecars
+/// existed have none and pass unchecked. Unlike `verify_digest`, a
+/// mismatch leaves the file in place; `download-model` replaces it.
+pub fn verify_sidecar(path: &Path) -> Result<()> {
+    let sidecar = sidecar_path(path);
+    let Ok(contents) = std::fs::read_to_string(&sidecar) else {
+        return Ok(());
+    };
+    // Same layout as `sha256sum` output: the digest comes first
+    let expected = contents.split_whitespace().next().unwrap_or_default();
+    let actual = sha256_file(path)?;
+    if !actual.eq_ignore_ascii_case(expected) {
+        anyhow::bail!(
+            "{} is corrupted (its SHA-256 no longer matches {}). \
+             Run `charcoal download-model` to download it again.",
+            path.display(),
+            sidecar.display()
+        );
+    }
     Ok(())
 }
 
@@ -295,4 +641,193 @@ mod tests {
         // Cleanup
         std::fs::remove_dir_all(&dir).unwrap();
     }
+
+    // sha256("abc"), the FIPS 180-2 test vector
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    fn fixture(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("charcoal-sha-test-{name}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("model.onnx");
+        std::fs::write(&path, b"abc").unwrap();
+        path
+    }
+
+    #[test]
+    fn test_sha256_file_matches_known_digest() {
+        let path = fixture("digest");
+        assert_eq!(sha256_file(&path).unwrap(), ABC_SHA256);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_sidecar_path_appends_extension() {
+        let path = PathBuf::from("/tmp/models/model.onnx");
+        assert_eq!(
+            sidecar_path(&path),
+            PathBuf::from("/tmp/models/model.onnx.sha256")
+        );
+    }
+
+    #[test]
+    fn test_verify_sidecar_accepts_match_and_missing_sidecar() {
+        let path = fixture("sidecar-ok");
+        assert!(verify_sidecar(&path).is_ok());
+
+        std::fs::write(sidecar_path(&path), format!("{ABC_SHA256}  model.onnx\n")).unwrap();
+        assert!(verify_sidecar(&path).is_ok());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_verify_sidecar_rejects_corrupted_file() {
+        let path = fixture("sidecar-bad");
+        write_sidecar(&path, ABC_SHA256).unwrap();
+        std::fs::write(&path, b"abd").unwrap();
+
+        let err = verify_sidecar(&path).unwrap_err().to_string();
+        assert!(err.contains("download-model"), "{err}");
+        assert!(path.exists(), "verify_sidecar must not delete the file");
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_verify_digest_deletes_on_mismatch() {
+        let path = fixture("digest-bad");
+        assert!(verify_digest(&path, ABC_SHA256).is_ok());
+        assert!(verify_digest(&path, &"0".repeat(64)).is_err());
+        assert!(!path.exists());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_pinned_file_url_uses_the_revision() {
+        let file = PinnedFile {
+            repo: "org/model",
+            revision: "0123abcd",
+            path: "onnx/model.onnx",
+            sha256: None,
+        };
+        assert_eq!(
+            file.url(),
+            "https://huggingface.co/org/model/resolve/0123abcd/onnx/model.onnx"
+        );
+    }
+
+    #[test]
+    fn test_partial_path_appends_extension() {
+        let path = PathBuf::from("/tmp/models/model.onnx");
+        assert_eq!(
+            partial_path(&path),
+            PathBuf::from("/tmp/models/model.onnx.part")
+        );
+    }
+
+    #[test]
+    fn test_content_range_total() {
+        assert_eq!(content_range_total("bytes 100-199/200"), Some(200));
+        assert_eq!(content_range_total("bytes 0-0/*"), None);
+        assert_eq!(content_range_total("garbage"), None);
+    }
+
+    /// Serve `body` on a local port, answering `Range: bytes=N-` requests
+    /// with a 206 unless `honour_range` is false. Returns the file URL.
+    async fn file_server(body: &'static [u8], honour_range: bool) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/model.onnx", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let head = String::from_utf8_lossy(&request).to_lowercase();
+                let start = head
+                    .lines()
+                    .find_map(|l| l.strip_prefix("range: bytes="))
+                    .and_then(|r| r.trim_end_matches('-').parse::<usize>().ok())
+                    .filter(|_| honour_range);
+                let response = match start {
+                    Some(start) => format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\n\
+                         Content-Range: bytes {start}-{}/{}\r\nConnection: close\r\n\r\n",
+                        body.len() - start,
+                        body.len() - 1,
+                        body.len()
+                    ),
+                    None => format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                        body.len()
+                    ),
+                };
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.write_all(&body[start.unwrap_or(0)..]).await.unwrap();
+            }
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_download_resumes_from_partial_file() {
+        let url = file_server(b"abc", true).await;
+        let dir = std::env::temp_dir().join("charcoal-resume-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let dest = dir.join("model.onnx");
+        std::fs::write(partial_path(&dest), b"a").unwrap();
+
+        download_file(&url, &dest, false, None).await.unwrap();
+
+        assert_eq!(std::fs::read(&dest).unwrap(), b"abc");
+        assert!(!partial_path(&dest).exists());
+        assert!(verify_sidecar(&dest).is_ok());
+        assert_eq!(
+            std::fs::read_to_string(sidecar_path(&dest)).unwrap().trim(),
+            ABC_SHA256
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_checks_the_pinned_digest() {
+        let url = file_server(b"abc", true).await;
+        let dir = std::env::temp_dir().join("charcoal-pinned-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let dest = dir.join("model.onnx");
+
+        let zeros = "0".repeat(64);
+        let err = download_file(&url, &dest, false, Some(&zeros))
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("integrity check"), "{err}");
+        assert!(!dest.exists());
+        assert!(!partial_path(&dest).exists());
+
+        download_file(&url, &dest, false, Some(ABC_SHA256))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&dest).unwrap(), b"abc");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_restarts_when_range_ignored() {
+        let url = file_server(b"abc", false).await;
+        let dir = std::env::temp_dir().join("charcoal-restart-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let dest = dir.join("model.onnx");
+        // A stale partial that would corrupt the file if appended to
+        std::fs::write(partial_path(&dest), b"xy").unwrap();
+
+        download_file(&url, &dest, false, None).await.unwrap();
+
+        assert_eq!(std::fs::read(&dest).unwrap(), b"abc");
+        assert!(!partial_path(&dest).exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -18,7 +18,9 @@ use std::sync::Arc;
 use tracing::{debug, warn};
 
 use super::format_parent_reply;
-use super::traits::{BinaryVerdict, ToxicityResult, ToxicityScorer};
+use super::traits::{
+    weighted_score, AttributeWeights, BinaryVerdict, ToxicityResult, ToxicityScorer,
+};
 use super::zentropi::ZentropiClient;
 
 /// ONNX score below this is genuinely safe — skip Zentropi entirely.
@@ -86,10 +88,16 @@ impl TwoStageToxicityScorer {
     /// Classify a single post. `context` is the parent post text for replies; pass
     /// `None` for originals. The pair-aware classification only runs when Zentropi
     /// is available — the labeler policy is conversation-scoped.
+    ///
+    /// The ONNX clean-pass and fallback thresholds apply to the primary's
+    /// `weighted_score` under `weights`, so a heavily weighted category can
+    /// send a post on to Zentropi (or flag it) when the top-line score alone
+    /// would not.
     pub async fn classify_post(
         &self,
         text: &str,
         context: Option<&str>,
+        weights: &AttributeWeights,
     ) -> Result<TwoStageVerdict> {
         // For replies, score the [Parent post] / [Reply] envelope so the ONNX
         // clean-pass filter can detect context-dependent toxicity. Without this,
@@ -107,10 +115,11 @@ impl TwoStageToxicityScorer {
         };
 
         let primary = self.primary.score_text(primary_input).await?;
+        let effective = weighted_score(&primary, weights);
         let onnx_score = primary.toxicity;
         let onnx_attributes = primary.attributes;
 
-        if onnx_score < ONNX_CLEAN_THRESHOLD {
+        if effective < ONNX_CLEAN_THRESHOLD {
             debug!(onnx_score, "Two-stage: ONNX cleared, skipping Zentropi");
             return Ok(TwoStageVerdict {
                 is_toxic: false,
@@ -151,7 +160,7 @@ impl TwoStageToxicityScorer {
                     Err(e) => {
                         warn!(error = %e, "Zentropi failed, falling back to ONNX threshold");
                         Ok(TwoStageVerdict {
-                            is_toxic: onnx_score >= ONNX_FALLBACK_BINARY_THRESHOLD,
+                            is_toxic: effective >= ONNX_FALLBACK_BINARY_THRESHOLD,
                             onnx_score,
                             onnx_attributes,
                             source: VerdictSource::OnnxFallback,
@@ -161,7 +170,7 @@ impl TwoStageToxicityScorer {
                 }
             }
             None => Ok(TwoStageVerdict {
-                is_toxic: onnx_score >= ONNX_FALLBACK_BINARY_THRESHOLD,
+                is_toxic: effective >= ONNX_FALLBACK_BINARY_THRESHOLD,
                 onnx_score,
                 onnx_attributes,
                 source: VerdictSource::OnnxFallback,
@@ -178,6 +187,7 @@ impl TwoStageToxicityScorer {
         &self,
         texts: &[String],
         contexts: &[Option<String>],
+        weights: &AttributeWeights,
     ) -> Result<Vec<TwoStageVerdict>> {
         if texts.len() != contexts.len() {
             anyhow::bail!(
@@ -198,7 +208,7 @@ impl TwoStageToxicityScorer {
 
         let mut indexed: Vec<(usize, Result<TwoStageVerdict>)> = stream::iter(owned)
             .map(|(i, text, ctx)| async move {
-                let verdict = self.classify_post(&text, ctx.as_deref()).await;
+                let verdict = self.classify_post(&text, ctx.as_deref(), weights).await;
                 (i, verdict)
             })
             .buffer_unordered(ZENTROPI_CONCURRENCY)
@@ -223,6 +233,12 @@ impl ToxicityScorer for TwoStageToxicityScorer {
         self.primary.score_text(text).await
     }
 
+    /// Forwarded so the primary's own batching (ONNX batch inference,
+    /// pipelined Perspective requests) is used instead of one call per text.
+    async fn score_batch(&self, texts: &[String]) -> Result<Vec<ToxicityResult>> {
+        self.primary.score_batch(texts).await
+    }
+
     async fn score_with_context(
         &self,
         text: &str,
@@ -237,8 +253,9 @@ impl ToxicityScorer for TwoStageToxicityScorer {
         &self,
         texts: &[String],
         contexts: &[Option<String>],
+        weights: &AttributeWeights,
     ) -> Result<Vec<BinaryVerdict>> {
-        let verdicts = self.classify_batch(texts, contexts).await?;
+        let verdicts = self.classify_batch(texts, contexts, weights).await?;
         Ok(verdicts
             .into_iter()
             .map(|v| BinaryVerdict {
@@ -0,0 +1,79 @@
+// Post-level language filtering for toxicity scoring.
+//
+// The ONNX toxicity model is English-trained. Feeding it Spanish or Japanese
+// posts produces scores that are noise, and that noise drags an account's
+// aggregate toxicity in unpredictable directions. Posts are filtered to the
+// model's supported languages before scoring.
+//
+// A post's language comes from the `langs` the author's client declared on
+// the record when present. Undeclared posts fall back to text detection, and
+// stay scorable when detection isn't confident — short posts are often
+// ambiguous, and dropping them would thin out English accounts too.
+
+use crate::bluesky::posts::{Post, PostSample};
+
+/// Languages the toxicity model was trained on (ISO 639-1 primary subtags).
+pub const SCORABLE_LANGUAGES: &[&str] = &["en"];
+
+/// ISO 639-3 codes `whatlang` reports for the scorable languages.
+const SCORABLE_DETECTED: &[&str] = &["eng"];
+
+/// Whether a declared BCP-47 tag ("en", "en-US") is a scorable language.
+fn is_scorable_tag(tag: &str) -> bool {
+    let primary = tag.split(['-', '_']).next().unwrap_or("").to_lowercase();
+    SCORABLE_LANGUAGES.contains(&primary.as_str())
+}
+
+/// Whether a post is in a language the toxicity model can score.
+///
+/// Declared languages win: the post is scorable if any declared tag is.
+/// Otherwise the text is detected, and only a confident non-English
+/// detection excludes it.
+pub fn is_scorable(post: &Post) -> bool {
+    if !post.langs.is_empty() {
+        return post.langs.iter().any(|tag| is_scorable_tag(tag));
+    }
+    match whatlang::detect(&post.text) {
+        Some(info) if info.is_reliable() => SCORABLE_DETECTED.contains(&info.lang().code()),
+        _ => true,
+    }
+}
+
+/// Split out the posts the toxicity model can score.
+///
+/// Returns a copy of `sample` holding only scorable posts (with
+/// `total_posts` set to the number kept; the ratios still describe the
+/// full feed) and how many posts were skipped for language.
+pub fn retain_scorable(sample: &PostSample) -> (PostSample, usize) {
+    let originals: Vec<Post> = sample
+        .originals
+        .iter()
+        .filter(|p| is_scorable(p))
+        .cloned()
+        .collect();
+    let replies: Vec<_> = sample
+        .replies
+        .iter()
+        .filter(|r| is_scorable(&r.post))
+        .cloned()
+        .collect();
+    let quotes: Vec<Post> = sample
+        .quotes
+        .iter()
+        .filter(|p| is_scorable(p))
+        .cloned()
+        .collect();
+
+    let kept = originals.len() + replies.len() + quotes.len();
+    let seen = sample.originals.len() + sample.replies.len() + sample.quotes.len();
+
+    let scorable = PostSample {
+        originals,
+        replies,
+        quotes,
+        reply_ratio: sample.reply_ratio,
+        quote_ratio: sample.quote_ratio,
+        total_posts: kept,
+    };
+    (scorable, seen - kept)
+}
@@ -6,6 +6,7 @@
 
 pub mod download;
 pub mod ensemble;
+pub mod language;
 pub mod onnx;
 pub mod perspective;
 pub mod rate_limiter;
@@ -1,6 +1,7 @@
 // Local ONNX toxicity scorer using Detoxify's unbiased-toxic-roberta model.
 //
-// This scorer runs entirely on the local CPU — no API calls, no rate limits,
+// This scorer runs entirely locally — on the CPU by default, or a GPU when
+// CHARCOAL_ONNX_PROVIDER selects one — with no API calls, no rate limits,
 // no network dependency. The model was specifically trained to reduce bias
 // around identity mentions, which is critical for Charcoal's use case (the
 // protected user posts about fat liberation, queer identity, DEI, etc.).
@@ -10,15 +11,22 @@
 
 use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use ort::execution_providers::{
+    CUDAExecutionProvider, CoreMLExecutionProvider, DirectMLExecutionProvider, ExecutionProvider,
+    ExecutionProviderDispatch,
+};
+use ort::session::builder::SessionBuilder;
 use ort::session::Session;
 use ort::value::Tensor;
 use tokenizers::Tokenizer;
-use tracing::debug;
+use tracing::{debug, info, warn};
 
 use super::traits::{ToxicityAttributes, ToxicityResult, ToxicityScorer};
+use crate::config::OnnxProvider;
 
 /// Labels output by unbiased-toxic-roberta, in the order the model returns them.
 /// These map to: toxicity, severe_toxicity, obscene, identity_attack, insult, threat, sexual_explicit
@@ -32,6 +40,185 @@ const LABEL_ORDER: [&str; 7] = [
     "sexual_explicit",
 ];
 
+/// Longest token sequence passed to the model. Detoxify was fine-tuned on
+/// short comments, so tokens past this add memory, not signal — and without
+/// a cap one pathological post sets the padded width for its whole batch.
+pub const MAX_SEQ_LEN: usize = 256;
+
+/// RoBERTa's padding token id.
+const PAD_TOKEN_ID: i64 = 1;
+
+/// A tokenized batch, right-padded to a common length and flattened into
+/// `[batch_size, seq_len]` tensors.
+#[derive(Debug)]
+pub struct PaddedBatch {
+    pub input_ids: Vec<i64>,
+    pub attention_mask: Vec<i64>,
+    /// Padded width of every row, never more than the batch's `max_seq_len`
+    pub seq_len: usize,
+    /// How many sequences were cut down to fit
+    pub truncated: usize,
+}
+
+/// Clip each (ids, attention mask) pair to `max_seq_len` tokens and pad the
+/// batch to its longest remaining row with `pad_id`.
+///
+/// A clipped sequence keeps its final token: tokenizers end every encoding
+/// with a special end-of-sequence token, and the model expects to see it.
+pub fn pad_batch(sequences: &[(&[u32], &[u32])], max_seq_len: usize, pad_id: i64) -> PaddedBatch {
+    let clip = |tokens: &[u32]| -> Vec<i64> {
+        if tokens.len() <= max_seq_len || max_seq_len == 0 {
+            return tokens.iter().take(max_seq_len).map(|&t| t as i64).collect();
+        }
+        tokens[..max_seq_len - 1]
+            .iter()
+            .chain(tokens.last())
+            .map(|&t| t as i64)
+            .collect()
+    };
+
+    let truncated = sequences
+        .iter()
+        .filter(|(ids, _)| ids.len() > max_seq_len)
+        .count();
+    let seq_len = sequences
+        .iter()
+        .map(|(ids, _)| ids.len().min(max_seq_len))
+        .max()
+        .unwrap_or(0);
+
+    let mut input_ids = Vec::with_capacity(sequences.len() * seq_len);
+    let mut attention_mask = Vec::with_capacity(sequences.len() * seq_len);
+    for (ids, mask) in sequences {
+        let ids = clip(ids);
+        let pad_len = seq_len - ids.len();
+        input_ids.extend(ids);
+        input_ids.extend(std::iter::repeat_n(pad_id, pad_len));
+        attention_mask.extend(clip(mask));
+        attention_mask.extend(std::iter::repeat_n(0i64, pad_len));
+    }
+
+    PaddedBatch {
+        input_ids,
+        attention_mask,
+        seq_len,
+        truncated,
+    }
+}
+
+/// A session builder with `provider` registered as its execution provider.
+///
+/// Shared by every local model so they all run on the same hardware. When
+/// the provider isn't compiled in (see `OnnxProvider::cargo_feature`), or
+/// its runtime is missing or fails to initialize, this warns and returns a
+/// plain CPU builder instead — a misconfigured GPU shouldn't stop scoring.
+pub fn session_builder(provider: OnnxProvider) -> Result<SessionBuilder> {
+    let builder = Session::builder().context("Failed to create ONNX session builder")?;
+    let (dispatch, available): (ExecutionProviderDispatch, _) = match provider {
+        OnnxProvider::Cpu => return Ok(builder),
+        OnnxProvider::Cuda => {
+            let ep = CUDAExecutionProvider::default();
+            (ep.clone().build(), ep.is_available())
+        }
+        OnnxProvider::CoreMl => {
+            let ep = CoreMLExecutionProvider::default();
+            (ep.clone().build(), ep.is_available())
+        }
+        OnnxProvider::DirectMl => {
+            let ep = DirectMLExecutionProvider::default();
+            (ep.clone().build(), ep.is_available())
+        }
+    };
+
+    let registered = match available {
+        Ok(true) => builder
+            .with_execution_providers([dispatch.error_on_failure()])
+            .map_err(|e| e.to_string()),
+        Ok(false) => Err(match provider.cargo_feature() {
+            Some(feature) if !feature_enabled(provider) => {
+                format!("this build lacks it — rebuild with `--features {feature}`")
+            }
+            _ => "not available in this ONNX Runtime".to_string(),
+        }),
+        Err(e) => Err(e.to_string()),
+    };
+    match registered {
+        Ok(builder) => {
+            info!(
+                provider = provider.name(),
+                "Running ONNX models on {}",
+                provider.name()
+            );
+            Ok(builder)
+        }
+        Err(reason) => {
+            warn!(
+                provider = provider.name(),
+                "ONNX execution provider unavailable ({reason}), falling back to CPU"
+            );
+            Session::builder().context("Failed to create ONNX session builder")
+        }
+    }
+}
+
+/// Whether charcoal was built with the cargo feature for `provider`.
+fn feature_enabled(provider: OnnxProvider) -> bool {
+    match provider {
+        OnnxProvider::Cpu => true,
+        OnnxProvider::Cuda => cfg!(feature = "cuda"),
+        OnnxProvider::CoreMl => cfg!(feature = "coreml"),
+        OnnxProvider::DirectMl => cfg!(feature = "directml"),
+    }
+}
+
+/// How many times loading a model is attempted when it keeps failing with a
+/// transient error (see `is_transient_load_error`).
+pub const SESSION_LOAD_ATTEMPTS: u32 = 3;
+
+/// Pause between model load attempts.
+pub const SESSION_LOAD_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// ONNX Runtime failures that clear up on their own: allocation failures
+/// while a model loads under memory pressure, usually on a cold start.
+const TRANSIENT_LOAD_ERRORS: &[&str] = &[
+    "bad_alloc",
+    "bad allocation",
+    "failed to allocate",
+    "out of memory",
+    "cannot allocate memory",
+    "resource temporarily unavailable",
+];
+
+/// Run `load` — building an ONNX session from a model file — up to
+/// `SESSION_LOAD_ATTEMPTS` times, pausing between tries, while it fails
+/// with a transient error. Anything else, like a corrupt model, fails on the
+/// first attempt.
+///
+/// Separate from the HTTP clients' retries: model loading is synchronous
+/// and happens once at startup, so this blocks the thread for the pause.
+pub fn load_with_retry<T>(mut load: impl FnMut() -> Result<T>) -> Result<T> {
+    let mut attempt = 1;
+    loop {
+        match load() {
+            Err(e) if attempt < SESSION_LOAD_ATTEMPTS && is_transient_load_error(&e) => {
+                warn!(attempt, error = %format!("{e:#}"), "ONNX model load failed, retrying");
+                std::thread::sleep(SESSION_LOAD_RETRY_DELAY);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Whether a model load failure looks like a passing resource shortage
+/// rather than a problem with the model itself.
+pub fn is_transient_load_error(err: &anyhow::Error) -> bool {
+    let message = format!("{err:#}").to_lowercase();
+    TRANSIENT_LOAD_ERRORS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
 /// Local ONNX-based toxicity scorer. Holds the model session and tokenizer
 /// behind Arc<Mutex> so inference can be offloaded to spawn_blocking without
 /// blocking the async runtime.
@@ -50,28 +237,31 @@ impl OnnxToxicityScorer {
     /// Load the ONNX model and tokenizer from the given directory.
     ///
     /// Expects `model_quantized.onnx` and `tokenizer.json` to exist in `model_dir`.
-    /// Call `download::download_model()` first if they don't.
-    pub fn load(model_dir: &Path) -> Result<Self> {
+    /// Call `download::download_model()` first if they don't — a missing file
+    /// is an `Error::ModelMissing`. The model runs on `provider`, falling back
+    /// to the CPU (see `session_builder`), and a transient failure while it
+    /// loads is retried (see `load_with_retry`).
+    pub fn load(model_dir: &Path, provider: OnnxProvider) -> crate::error::Result<Self> {
         let model_path = model_dir.join("model_quantized.onnx");
         let tokenizer_path = model_dir.join("tokenizer.json");
 
         if !model_path.exists() {
-            anyhow::bail!(
-                "Model file not found: {}\nRun `charcoal download-model` to download it.",
-                model_path.display()
-            );
+            return Err(crate::error::Error::ModelMissing { path: model_path });
         }
         if !tokenizer_path.exists() {
-            anyhow::bail!(
-                "Tokenizer file not found: {}\nRun `charcoal download-model` to download it.",
-                tokenizer_path.display()
-            );
+            return Err(crate::error::Error::ModelMissing {
+                path: tokenizer_path,
+            });
         }
 
-        let session = Session::builder()
-            .context("Failed to create ONNX session builder")?
-            .commit_from_file(&model_path)
-            .with_context(|| format!("Failed to load ONNX model from {}", model_path.display()))?;
+        super::download::verify_sidecar(&model_path)?;
+        super::download::verify_sidecar(&tokenizer_path)?;
+
+        let session = load_with_retry(|| {
+            session_builder(provider)?
+                .commit_from_file(&model_path)
+                .with_context(|| format!("Failed to load ONNX model from {}", model_path.display()))
+        })?;
 
         let tokenizer = Tokenizer::from_file(&tokenizer_path)
             .map_err(|e| anyhow::anyhow!("Failed to load tokenizer: {}", e))?;
@@ -110,7 +300,7 @@ impl ToxicityScorer for OnnxToxicityScorer {
         // Offload all CPU-bound work (tokenization + inference) to a blocking
         // thread so the async runtime stays responsive for other tasks.
         tokio::task::spawn_blocking(move || {
-            // Tokenize all texts, finding the max sequence length for padding
+            // Tokenize all texts
             let encodings: Vec<_> = texts
                 .iter()
                 .map(|t| {
@@ -121,42 +311,27 @@ impl ToxicityScorer for OnnxToxicityScorer {
                 .collect::<Result<Vec<_>>>()?;
 
             let batch_size = encodings.len();
-            let max_len = encodings
+            let sequences: Vec<(&[u32], &[u32])> = encodings
                 .iter()
-                .map(|e| e.get_ids().len())
-                .max()
-                .unwrap_or(0);
-
-            // Build flat input tensors with right-padding to max_len.
-            // Shape: [batch_size, max_len]
-            let mut input_ids_flat: Vec<i64> = Vec::with_capacity(batch_size * max_len);
-            let mut attention_mask_flat: Vec<i64> = Vec::with_capacity(batch_size * max_len);
-
-            for enc in &encodings {
-                let ids = enc.get_ids();
-                let mask = enc.get_attention_mask();
-                let seq_len = ids.len();
-
-                // Copy actual tokens
-                for &id in ids {
-                    input_ids_flat.push(id as i64);
-                }
-                for &m in mask {
-                    attention_mask_flat.push(m as i64);
-                }
-
-                // Pad to max_len (pad_id = 1 for RoBERTa)
-                for _ in seq_len..max_len {
-                    input_ids_flat.push(1); // RoBERTa pad token id
-                    attention_mask_flat.push(0);
-                }
+                .map(|e| (e.get_ids(), e.get_attention_mask()))
+                .collect();
+
+            // Clip long posts, then right-pad to the longest remaining row.
+            // Shape: [batch_size, seq_len]
+            let batch = pad_batch(&sequences, MAX_SEQ_LEN, PAD_TOKEN_ID);
+            if batch.truncated > 0 {
+                debug!(
+                    truncated = batch.truncated,
+                    max_seq_len = MAX_SEQ_LEN,
+                    "Truncated long texts before toxicity scoring"
+                );
             }
 
-            let shape = [batch_size as i64, max_len as i64];
+            let shape = [batch_size as i64, batch.seq_len as i64];
 
-            let input_ids_tensor = Tensor::from_array((shape, input_ids_flat))
+            let input_ids_tensor = Tensor::from_array((shape, batch.input_ids))
                 .context("Failed to create input_ids tensor")?;
-            let attention_mask_tensor = Tensor::from_array((shape, attention_mask_flat))
+            let attention_mask_tensor = Tensor::from_array((shape, batch.attention_mask))
                 .context("Failed to create attention_mask tensor")?;
 
             let logits_data = {
@@ -239,6 +414,45 @@ fn map_scores_to_result(scores: &[f64]) -> ToxicityResult {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pad_batch_truncates_long_sequences() {
+        // A 10,000-token post alongside a short one
+        let long_ids: Vec<u32> = (0..10_000).collect();
+        let long_mask = vec![1u32; 10_000];
+        let short_ids = vec![0u32, 42, 2];
+        let short_mask = vec![1u32; 3];
+
+        let batch = pad_batch(
+            &[(&long_ids, &long_mask), (&short_ids, &short_mask)],
+            MAX_SEQ_LEN,
+            PAD_TOKEN_ID,
+        );
+
+        assert_eq!(batch.seq_len, MAX_SEQ_LEN);
+        assert_eq!(batch.truncated, 1);
+        // Memory is bounded by the cap, not the input length
+        assert_eq!(batch.input_ids.len(), 2 * MAX_SEQ_LEN);
+        assert!(batch.input_ids.capacity() <= 2 * MAX_SEQ_LEN);
+        // The end-of-sequence token survives truncation
+        assert_eq!(batch.input_ids[MAX_SEQ_LEN - 1], 9_999);
+        // The short row is padded and masked out
+        assert_eq!(
+            &batch.input_ids[MAX_SEQ_LEN..MAX_SEQ_LEN + 4],
+            &[0, 42, 2, 1]
+        );
+        assert_eq!(batch.attention_mask[MAX_SEQ_LEN + 3], 0);
+    }
+
+    #[test]
+    fn test_pad_batch_leaves_short_batches_alone() {
+        let ids = vec![0u32, 5, 2];
+        let mask = vec![1u32; 3];
+        let batch = pad_batch(&[(&ids, &mask)], MAX_SEQ_LEN, PAD_TOKEN_ID);
+        assert_eq!(batch.seq_len, 3);
+        assert_eq!(batch.truncated, 0);
+        assert_eq!(batch.input_ids, vec![0, 5, 2]);
+    }
+
     #[test]
     fn test_sigmoid_zero() {
         let result = sigmoid(0.0);
@@ -6,33 +6,91 @@
 // trait so it can be swapped out when that happens.
 //
 // API docs: https://developers.perspectiveapi.com/s/about-the-api-methods
+//
+// Batches are pipelined: up to `concurrency` requests are in flight at once,
+// but every request still waits its turn at the shared rate limiter, so a
+// batch never exceeds the QPS limit — it only stops one slow response from
+// holding up the next request's slot.
+
+use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use super::rate_limiter::RateLimiter;
 use super::traits::{ToxicityAttributes, ToxicityResult, ToxicityScorer};
 
+/// Perspective API endpoint (the `comments:analyze` method's base URL).
+pub const DEFAULT_PERSPECTIVE_URL: &str = "https://commentanalyzer.googleapis.com";
+
+/// Perspective's documented default quota: 1 query per second.
+pub const PERSPECTIVE_QPS: f64 = 1.0;
+
+/// Default number of Perspective requests in flight during a batch.
+pub const DEFAULT_PERSPECTIVE_CONCURRENCY: usize = 4;
+
 /// Perspective API toxicity scorer.
 pub struct PerspectiveScorer {
     client: Client,
     api_key: String,
+    base_url: String,
     rate_limiter: RateLimiter,
+    concurrency: usize,
 }
 
 impl PerspectiveScorer {
     /// Create a new Perspective API scorer with the given API key.
     pub fn new(api_key: String) -> Self {
         Self {
-            client: Client::new(),
+            client: crate::http::client(),
             api_key,
-            // Perspective free tier: 1 query per second
-            rate_limiter: RateLimiter::new(1.0),
+            base_url: DEFAULT_PERSPECTIVE_URL.to_string(),
+            rate_limiter: RateLimiter::new(PERSPECTIVE_QPS),
+            concurrency: DEFAULT_PERSPECTIVE_CONCURRENCY,
         }
     }
+
+    /// Like `new`, but the rate limiter's recent request history is kept in
+    /// `state_path` so consecutive processes share one quota window.
+    pub fn with_rate_state(api_key: String, state_path: PathBuf) -> Self {
+        Self {
+            rate_limiter: RateLimiter::with_state_file(PERSPECTIVE_QPS, state_path),
+            ..Self::new(api_key)
+        }
+    }
+
+    /// Send requests to `base_url` instead of the public Perspective API.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Allow up to `concurrency` requests in flight during `score_batch`
+    /// (0 is treated as 1). The QPS limit applies either way.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Score every text, pipelined through the rate limiter. Results are in
+    /// input order, and a failed request fails only its own entry.
+    pub async fn score_each(&self, texts: &[String]) -> Vec<Result<ToxicityResult>> {
+        // Owned strings keep the stream's futures free of borrowed-input
+        // lifetimes, which async-trait callers can't satisfy
+        let owned: Vec<(usize, String)> = texts.iter().cloned().enumerate().collect();
+        let mut indexed: Vec<(usize, Result<ToxicityResult>)> = stream::iter(owned)
+            .map(|(i, text)| async move { (i, self.score_text(&text).await) })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        indexed.sort_by_key(|(i, _)| *i);
+        indexed.into_iter().map(|(_, result)| result).collect()
+    }
 }
 
 #[async_trait]
@@ -42,8 +100,8 @@ impl ToxicityScorer for PerspectiveScorer {
         self.rate_limiter.acquire().await;
 
         let url = format!(
-            "https://commentanalyzer.googleapis.com/v1alpha1/comments:analyze?key={}",
-            self.api_key
+            "{}/v1alpha1/comments:analyze?key={}",
+            self.base_url, self.api_key
         );
 
         let request = PerspectiveRequest {
@@ -106,6 +164,38 @@ impl ToxicityScorer for PerspectiveScorer {
             },
         })
     }
+
+    /// Pipelined batch scoring (see `score_each`). If any text fails, the
+    /// whole batch errors, naming every failed index and the first cause.
+    async fn score_batch(&self, texts: &[String]) -> Result<Vec<ToxicityResult>> {
+        let results = self.score_each(texts).await;
+
+        let failed: Vec<usize> = results
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.is_err())
+            .map(|(i, _)| i)
+            .collect();
+        if failed.is_empty() {
+            return results.into_iter().collect();
+        }
+
+        warn!(
+            failed = failed.len(),
+            total = texts.len(),
+            "Perspective batch had failures"
+        );
+        let first_error = results
+            .into_iter()
+            .find_map(|r| r.err())
+            .expect("at least one failure");
+        Err(first_error.context(format!(
+            "Perspective failed to score {} of {} texts (indices {:?})",
+            failed.len(),
+            texts.len(),
+            failed
+        )))
+    }
 }
 
 /// Extract a specific attribute's summary score from the API response.
@@ -4,10 +4,35 @@
 // limiter enforces that limit to avoid getting throttled. It uses a simple
 // token-bucket approach: one token is added per second, and each request
 // consumes one token. If no tokens are available, we sleep until one is.
+//
+// A limiter can optionally persist its recent request history to a small
+// JSON file, so back-to-back `charcoal score` invocations in a shell loop
+// don't each start with a fresh allowance and burst past the quota. The file
+// is best-effort: a missing or corrupt one just starts an empty window.
+//
+// APIs that report their own budget (the Bluesky AppView's
+// `ratelimit-remaining` / `ratelimit-reset` headers) can feed it in with
+// `update_from_headers`. The limiter then spreads the remaining requests
+// over the time left once the budget runs low, and waits for the reset when
+// it's spent, instead of trusting its fixed rate alone.
 
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use tokio::time::{Duration, Instant};
+use tracing::warn;
+
+/// How far back persisted request timestamps are kept. Perspective quotas
+/// are enforced per minute, so older history can't affect the next request.
+pub const HISTORY_WINDOW: Duration = Duration::from_secs(60);
+
+/// Reported remaining requests at or below which the limiter starts pacing
+/// requests to last until the reset.
+pub const LOW_BUDGET_REMAINING: u64 = 100;
 
 /// A simple rate limiter that enforces a maximum request rate.
 #[derive(Clone)]
@@ -20,41 +45,203 @@ struct RateLimiterInner {
     interval: Duration,
     /// When the last request was allowed through
     last_request: Option<Instant>,
+    /// Wall-clock times (unix millis) of requests within `HISTORY_WINDOW`
+    history: VecDeque<u64>,
+    /// Where `history` is loaded from and saved to, if persisted
+    state_path: Option<PathBuf>,
+    /// The server-reported budget, from `update_from_headers`
+    budget: Option<Budget>,
+}
+
+/// Requests the server says are left, and when its window resets.
+struct Budget {
+    remaining: u64,
+    reset_at: Instant,
+}
+
+/// On-disk form of a limiter's recent history.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PersistedState {
+    requests_ms: Vec<u64>,
 }
 
 impl RateLimiter {
     /// Create a new rate limiter that allows `requests_per_second` requests per second.
     pub fn new(requests_per_second: f64) -> Self {
+        Self::build(requests_per_second, None)
+    }
+
+    /// Create a rate limiter whose recent request history survives restarts.
+    ///
+    /// History is loaded from `state_path` now (stale entries pruned) and
+    /// written back when the last clone of the limiter is dropped.
+    pub fn with_state_file(requests_per_second: f64, state_path: PathBuf) -> Self {
+        Self::build(requests_per_second, Some(state_path))
+    }
+
+    fn build(requests_per_second: f64, state_path: Option<PathBuf>) -> Self {
         let interval = Duration::from_secs_f64(1.0 / requests_per_second);
+        let now_ms = unix_millis();
+        let history = state_path
+            .as_deref()
+            .map(|path| prune(load_history(path), now_ms))
+            .unwrap_or_default();
+
+        // Seed the monotonic clock from the newest persisted request so the
+        // first call in this process still waits out the interval
+        let last_request = history
+            .back()
+            .and_then(|&ms| Instant::now().checked_sub(Duration::from_millis(now_ms - ms)));
+
         Self {
             inner: Arc::new(Mutex::new(RateLimiterInner {
                 interval,
-                last_request: None,
+                last_request,
+                history,
+                state_path,
+                budget: None,
             })),
         }
     }
 
+    /// Record the budget a server reported: `remaining` requests left in its
+    /// window, which resets at `reset_epoch` (unix seconds). A reset time
+    /// already past is ignored.
+    pub async fn update_from_headers(&self, remaining: u64, reset_epoch: u64) {
+        let now_ms = unix_millis();
+        let reset_ms = reset_epoch.saturating_mul(1000);
+        let mut inner = self.inner.lock().await;
+        inner.budget = (reset_ms > now_ms).then(|| Budget {
+            remaining,
+            reset_at: Instant::now() + Duration::from_millis(reset_ms - now_ms),
+        });
+    }
+
     /// Wait until a request is allowed, then return.
     ///
     /// If we're within the rate limit, this returns immediately.
     /// If we need to wait, it sleeps for the appropriate duration.
+    ///
+    /// With a server-reported budget, a spent budget waits for the reset and
+    /// a low one (see `LOW_BUDGET_REMAINING`) widens the interval to
+    /// `budget_interval`.
     pub async fn acquire(&self) {
         let mut inner = self.inner.lock().await;
         let now = Instant::now();
 
-        if let Some(last) = inner.last_request {
-            let elapsed = now.duration_since(last);
-            if elapsed < inner.interval {
-                let sleep_time = inner.interval - elapsed;
-                // Drop the lock before sleeping so other tasks aren't blocked
-                drop(inner);
-                tokio::time::sleep(sleep_time).await;
-                // Re-acquire after sleeping
-                inner = self.inner.lock().await;
+        if inner.budget.as_ref().is_some_and(|b| b.reset_at <= now) {
+            inner.budget = None;
+        }
+        let sleep_time = match &inner.budget {
+            Some(budget) if budget.remaining == 0 => Some(budget.reset_at - now),
+            budget => {
+                let interval = budget
+                    .as_ref()
+                    .map(|b| budget_interval(b.remaining, b.reset_at - now))
+                    .map_or(inner.interval, |paced| paced.max(inner.interval));
+                inner
+                    .last_request
+                    .map(|last| now.duration_since(last))
+                    .filter(|&elapsed| elapsed < interval)
+                    .map(|elapsed| interval - elapsed)
             }
+        };
+        if let Some(sleep_time) = sleep_time {
+            // Drop the lock before sleeping so other tasks aren't blocked
+            drop(inner);
+            tokio::time::sleep(sleep_time).await;
+            // Re-acquire after sleeping
+            inner = self.inner.lock().await;
         }
 
+        // Count this request against the budget until the server reports again
+        if let Some(budget) = inner.budget.as_mut() {
+            budget.remaining = budget.remaining.saturating_sub(1);
+        }
         inner.last_request = Some(Instant::now());
+        if inner.state_path.is_some() {
+            let now_ms = unix_millis();
+            inner.history.push_back(now_ms);
+            let history = std::mem::take(&mut inner.history);
+            inner.history = prune(history, now_ms);
+        }
+    }
+}
+
+impl Drop for RateLimiterInner {
+    fn drop(&mut self) {
+        if let Some(path) = &self.state_path {
+            save_history(
+                path,
+                &prune(std::mem::take(&mut self.history), unix_millis()),
+            );
+        }
+    }
+}
+
+/// Minimum time between requests with `remaining` left and `until_reset`
+/// to go: zero while the budget is comfortable, otherwise the time left
+/// shared evenly among the remaining requests.
+pub fn budget_interval(remaining: u64, until_reset: Duration) -> Duration {
+    if remaining > LOW_BUDGET_REMAINING {
+        Duration::ZERO
+    } else {
+        until_reset / remaining.max(1) as u32
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Keep only timestamps inside `HISTORY_WINDOW`, oldest first. Timestamps
+/// from the future (clock changes, hand-edited files) are dropped too.
+fn prune(history: impl IntoIterator<Item = u64>, now_ms: u64) -> VecDeque<u64> {
+    let cutoff = now_ms.saturating_sub(HISTORY_WINDOW.as_millis() as u64);
+    let mut kept: Vec<u64> = history
+        .into_iter()
+        .filter(|&ms| ms >= cutoff && ms <= now_ms)
+        .collect();
+    kept.sort_unstable();
+    kept.into()
+}
+
+/// Read persisted history. Any failure other than a missing file is logged
+/// and treated as an empty window.
+fn load_history(path: &Path) -> Vec<u64> {
+    let raw = match std::fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return vec![],
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Could not read rate limiter state, starting fresh");
+            return vec![];
+        }
+    };
+    match serde_json::from_str::<PersistedState>(&raw) {
+        Ok(state) => state.requests_ms,
+        Err(e) => {
+            warn!(path = %path.display(), error = %e, "Corrupt rate limiter state, starting fresh");
+            vec![]
+        }
+    }
+}
+
+/// Write history via a temp file + rename so a crash mid-write can't leave
+/// a truncated file behind. Failures are logged, never fatal.
+fn save_history(path: &Path, history: &VecDeque<u64>) {
+    let state = PersistedState {
+        requests_ms: history.iter().copied().collect(),
+    };
+    let tmp = path.with_extension("json.tmp");
+    let result = serde_json::to_vec(&state)
+        .map_err(std::io::Error::other)
+        .and_then(|bytes| std::fs::write(&tmp, bytes))
+        .and_then(|()| std::fs::rename(&tmp, path));
+    if let Err(e) = result {
+        warn!(path = %path.display(), error = %e, "Could not save rate limiter state");
     }
 }
 
@@ -86,4 +273,117 @@ mod tests {
             elapsed
         );
     }
+
+    fn state_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join("charcoal-rate-limiter-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{name}-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[tokio::test]
+    async fn test_fresh_limiter_respects_previous_process() {
+        let path = state_path("restart");
+
+        // First "process": one request, then the limiter goes away
+        {
+            let limiter = RateLimiter::with_state_file(2.0, path.clone());
+            limiter.acquire().await;
+        }
+        assert!(path.exists(), "state should be saved on drop");
+
+        // Second "process" must still wait out the interval
+        let limiter = RateLimiter::with_state_file(2.0, path.clone());
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(
+            start.elapsed() >= Duration::from_millis(400),
+            "Expected ~500ms delay after restart, got {:?}",
+            start.elapsed()
+        );
+
+        drop(limiter);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_state_file_starts_fresh() {
+        let path = state_path("corrupt");
+        std::fs::write(&path, "{not json").unwrap();
+
+        let limiter = RateLimiter::with_state_file(1.0, path.clone());
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        // Dropping rewrites the file as valid state
+        drop(limiter);
+        let saved: PersistedState =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.requests_ms.len(), 1);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_budget_interval_paces_low_budgets() {
+        let minute = Duration::from_secs(60);
+        assert_eq!(budget_interval(2500, minute), Duration::ZERO);
+        assert_eq!(budget_interval(LOW_BUDGET_REMAINING, minute), minute / 100);
+        assert_eq!(budget_interval(3, minute), Duration::from_secs(20));
+    }
+
+    #[tokio::test]
+    async fn test_near_exhausted_budget_slows_requests() {
+        let limiter = RateLimiter::new(100.0); // 10ms interval on its own
+        let reset_epoch = unix_millis() / 1000 + 3;
+        // Two requests left in a window that resets in 2-3s
+        limiter.update_from_headers(2, reset_epoch).await;
+
+        limiter.acquire().await;
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(
+            start.elapsed() >= Duration::from_millis(900),
+            "Expected the last request to be paced toward the reset, got {:?}",
+            start.elapsed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_budget_waits_for_reset() {
+        let limiter = RateLimiter::new(100.0);
+        let reset_epoch = unix_millis() / 1000 + 2;
+        limiter.update_from_headers(0, reset_epoch).await;
+
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(
+            start.elapsed() >= Duration::from_millis(900),
+            "Expected to wait for the reset, got {:?}",
+            start.elapsed()
+        );
+
+        // Past the reset the fixed rate applies again
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_past_reset_is_ignored() {
+        let limiter = RateLimiter::new(100.0);
+        limiter.update_from_headers(0, 1).await;
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_prune_drops_stale_and_future_entries() {
+        let now = 1_000_000;
+        let window = HISTORY_WINDOW.as_millis() as u64;
+        let pruned = prune(vec![now + 5, now - 10, now - window - 1, now - 20], now);
+        assert_eq!(pruned, VecDeque::from(vec![now - 20, now - 10]));
+    }
 }
@@ -6,6 +6,7 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 /// The result of scoring a single piece of text for toxicity.
 #[derive(Debug, Clone)]
@@ -18,7 +19,7 @@ pub struct ToxicityResult {
 
 /// Detailed toxicity attribute scores (all 0.0 to 1.0).
 /// Not all providers will populate every field.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ToxicityAttributes {
     pub severe_toxicity: Option<f64>,
     pub identity_attack: Option<f64>,
@@ -27,6 +28,165 @@ pub struct ToxicityAttributes {
     pub threat: Option<f64>,
 }
 
+impl ToxicityAttributes {
+    /// The highest score seen for each category across many posts.
+    ///
+    /// An account's average hides the nature of its worst moments — one
+    /// identity attack among fifty benign posts barely moves the mean but is
+    /// exactly what the user needs to see. A category stays `None` only if
+    /// no post had a score for it.
+    pub fn peak<'a>(all: impl IntoIterator<Item = &'a ToxicityAttributes>) -> Self {
+        fn max_opt(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+            match (a, b) {
+                (Some(x), Some(y)) => Some(x.max(y)),
+                (x, None) => x,
+                (None, y) => y,
+            }
+        }
+
+        all.into_iter().fold(Self::default(), |acc, a| Self {
+            severe_toxicity: max_opt(acc.severe_toxicity, a.severe_toxicity),
+            identity_attack: max_opt(acc.identity_attack, a.identity_attack),
+            insult: max_opt(acc.insult, a.insult),
+            profanity: max_opt(acc.profanity, a.profanity),
+            threat: max_opt(acc.threat, a.threat),
+        })
+    }
+
+    /// The populated categories as `(name, score)` pairs, in a fixed order.
+    pub fn labeled(&self) -> Vec<(&'static str, f64)> {
+        [
+            ("severe_toxicity", self.severe_toxicity),
+            ("identity_attack", self.identity_attack),
+            ("insult", self.insult),
+            ("profanity", self.profanity),
+            ("threat", self.threat),
+        ]
+        .into_iter()
+        .filter_map(|(name, score)| score.map(|s| (name, s)))
+        .collect()
+    }
+
+    /// True when no category has a score.
+    pub fn is_empty(&self) -> bool {
+        self.labeled().is_empty()
+    }
+}
+
+/// How much each score counts toward a post's effective toxicity.
+///
+/// Charcoal's users care far more about identity attacks and threats than
+/// about swearing, so by default those two count half again as much as the
+/// top-line score while profanity counts half. See `weighted_score`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeWeights {
+    /// Weight of the top-line toxicity score
+    pub toxicity: f64,
+    pub severe_toxicity: f64,
+    pub identity_attack: f64,
+    pub insult: f64,
+    pub profanity: f64,
+    pub threat: f64,
+}
+
+impl Default for AttributeWeights {
+    fn default() -> Self {
+        Self {
+            toxicity: 1.0,
+            severe_toxicity: 1.0,
+            identity_attack: 1.5,
+            insult: 1.0,
+            profanity: 0.5,
+            threat: 1.5,
+        }
+    }
+}
+
+impl AttributeWeights {
+    /// Parse `name=weight` pairs separated by commas, e.g.
+    /// `"identity_attack=2,profanity=0"`. Names are `toxicity` or a category
+    /// name from `ToxicityAttributes::labeled`; unnamed weights keep their
+    /// defaults. Negative or non-numeric weights are an error.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut weights = Self::default();
+        for pair in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Expected name=weight, got {pair:?}"))?;
+            let value: f64 = value
+                .trim()
+                .parse()
+                .ok()
+                .filter(|v: &f64| v.is_finite() && *v >= 0.0)
+                .ok_or_else(|| anyhow::anyhow!("Invalid weight for {}: {value:?}", name.trim()))?;
+            let slot = match name.trim() {
+                "toxicity" => &mut weights.toxicity,
+                "severe_toxicity" => &mut weights.severe_toxicity,
+                "identity_attack" => &mut weights.identity_attack,
+                "insult" => &mut weights.insult,
+                "profanity" => &mut weights.profanity,
+                "threat" => &mut weights.threat,
+                other => anyhow::bail!("Unknown toxicity attribute {other:?}"),
+            };
+            *slot = value;
+        }
+        Ok(weights)
+    }
+
+    /// The weight of a category named as in `ToxicityAttributes::labeled`.
+    fn for_category(&self, name: &str) -> f64 {
+        match name {
+            "severe_toxicity" => self.severe_toxicity,
+            "identity_attack" => self.identity_attack,
+            "insult" => self.insult,
+            "profanity" => self.profanity,
+            "threat" => self.threat,
+            _ => 0.0,
+        }
+    }
+}
+
+/// A post's effective toxicity: the largest of its weighted scores — the
+/// top-line score and each category the scorer reported — capped at 1.0.
+///
+/// Taking the largest rather than an average means one strong signal is
+/// never diluted by the categories a post doesn't touch: an identity attack
+/// with no profanity still scores as an identity attack. A result with no
+/// category breakdown scores `toxicity × weights.toxicity`.
+pub fn weighted_score(result: &ToxicityResult, weights: &AttributeWeights) -> f64 {
+    result
+        .attributes
+        .labeled()
+        .into_iter()
+        .map(|(name, score)| score * weights.for_category(name))
+        .fold(result.toxicity * weights.toxicity, f64::max)
+        .clamp(0.0, 1.0)
+}
+
+/// The category that sets a post's effective toxicity, named as in
+/// `ToxicityAttributes::labeled`. `None` when the top-line score outweighs
+/// every category, or there is no breakdown.
+pub fn dominant_attribute(
+    result: &ToxicityResult,
+    weights: &AttributeWeights,
+) -> Option<&'static str> {
+    let top_line = result.toxicity * weights.toxicity;
+    result
+        .attributes
+        .labeled()
+        .into_iter()
+        .map(|(name, score)| (name, score * weights.for_category(name)))
+        .filter(|(_, weighted)| *weighted > top_line)
+        .fold(
+            None,
+            |best: Option<(&'static str, f64)>, (name, w)| match best {
+                Some((_, b)) if b >= w => best,
+                _ => Some((name, w)),
+            },
+        )
+        .map(|(name, _)| name)
+}
+
 /// Binary toxicity verdict for a single post — drives the threat formula's
 /// toxicity rate. `onnx_score` is preserved for evidence sorting and audit logs.
 #[derive(Debug, Clone)]
@@ -40,10 +200,22 @@ pub struct BinaryVerdict {
     pub onnx_attributes: ToxicityAttributes,
 }
 
+impl BinaryVerdict {
+    /// The primary scorer's output, for `weighted_score` and
+    /// `dominant_attribute`.
+    pub fn onnx_result(&self) -> ToxicityResult {
+        ToxicityResult {
+            toxicity: self.onnx_score,
+            attributes: self.onnx_attributes.clone(),
+        }
+    }
+}
+
 /// Default binary threshold used when a scorer has no classifier of its own.
 const DEFAULT_BINARY_THRESHOLD: f64 = 0.50;
 
-/// No-op scorer used when toxicity scoring isn't needed (e.g. scan without --analyze).
+/// No-op scorer used when toxicity scoring isn't needed (e.g. scan without
+/// --analyze, or --overlap-only runs, which never call the scorer).
 /// Panics if actually called — ensures we don't silently produce fake scores.
 pub struct NoopScorer;
 
@@ -54,6 +226,64 @@ impl ToxicityScorer for NoopScorer {
     }
 }
 
+/// Deterministic scorer that returns the same toxicity for every input.
+///
+/// Meant for tests: it lets pipelines and `build_profile` run with
+/// predictable scores and no ONNX model on disk. Attribute breakdowns are
+/// left empty.
+///
+/// ```
+/// use charcoal::toxicity::ensemble::TwoStageToxicityScorer;
+/// use charcoal::toxicity::traits::{AttributeWeights, FixedScorer, ToxicityScorer};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// // Everything scores 0.05 — under the clean-pass threshold, so the
+/// // two-stage pipeline clears every post without a second stage.
+/// let scorer = TwoStageToxicityScorer::new(Box::new(FixedScorer { value: 0.05 }), None);
+/// let texts = vec!["first post".to_string(), "second post".to_string()];
+/// let verdicts = scorer
+///     .classify_batch_with_contexts(&texts, &[None, None], &AttributeWeights::default())
+///     .await
+///     .unwrap();
+/// assert!(verdicts.iter().all(|v| !v.is_toxic));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixedScorer {
+    pub value: f64,
+}
+
+#[async_trait]
+impl ToxicityScorer for FixedScorer {
+    async fn score_text(&self, _text: &str) -> Result<ToxicityResult> {
+        Ok(ToxicityResult {
+            toxicity: self.value,
+            attributes: ToxicityAttributes::default(),
+        })
+    }
+}
+
+/// Deterministic scorer that looks each text up in a map.
+///
+/// Texts not in the map get `default`. Useful when a test needs a mix of
+/// toxic and clean posts — e.g. one hostile reply among benign originals.
+#[derive(Debug, Clone, Default)]
+pub struct MapScorer {
+    pub scores: std::collections::HashMap<String, f64>,
+    pub default: f64,
+}
+
+#[async_trait]
+impl ToxicityScorer for MapScorer {
+    async fn score_text(&self, text: &str) -> Result<ToxicityResult> {
+        Ok(ToxicityResult {
+            toxicity: self.scores.get(text).copied().unwrap_or(self.default),
+            attributes: ToxicityAttributes::default(),
+        })
+    }
+}
+
 /// Trait for scoring text toxicity. Implementations must be async because
 /// most providers require HTTP API calls.
 #[async_trait]
@@ -88,13 +318,15 @@ pub trait ToxicityScorer: Send + Sync {
     /// post text for replies, or `None` for originals/quotes.
     ///
     /// The default implementation derives binary verdicts from the continuous
-    /// `score_with_context` output using `DEFAULT_BINARY_THRESHOLD` (0.50) — safe
-    /// for any continuous scorer but coarse. Implementations with native binary
+    /// `score_with_context` output, thresholding its `weighted_score` under
+    /// `weights` at `DEFAULT_BINARY_THRESHOLD` (0.50) — safe for any
+    /// continuous scorer but coarse. Implementations with native binary
     /// classification (e.g. `TwoStageToxicityScorer`) should override.
     async fn classify_batch_with_contexts(
         &self,
         texts: &[String],
         contexts: &[Option<String>],
+        weights: &AttributeWeights,
     ) -> Result<Vec<BinaryVerdict>> {
         if texts.len() != contexts.len() {
             anyhow::bail!(
@@ -107,7 +339,7 @@ pub trait ToxicityScorer: Send + Sync {
         for (text, ctx) in texts.iter().zip(contexts.iter()) {
             let r = self.score_with_context(text, ctx.as_deref()).await?;
             verdicts.push(BinaryVerdict {
-                is_toxic: r.toxicity >= DEFAULT_BINARY_THRESHOLD,
+                is_toxic: weighted_score(&r, weights) >= DEFAULT_BINARY_THRESHOLD,
                 onnx_score: r.toxicity,
                 onnx_attributes: r.attributes,
             });
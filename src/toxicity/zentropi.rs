@@ -90,7 +90,7 @@ impl ZentropiClient {
             anyhow::bail!("ZENTROPI_LABELER_ID is empty");
         }
 
-        let client = reqwest::Client::builder()
+        let client = crate::http::client_builder()
             .timeout(REQUEST_TIMEOUT)
             .build()
             .context("Failed to build reqwest client for Zentropi")?;
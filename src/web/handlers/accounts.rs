@@ -1,13 +1,13 @@
 // Account list and detail handlers.
 //
-// GET /api/accounts         — paginated, optional ?tier= filter and ?q= search
+// GET /api/accounts         — one page of accounts, ranked by threat score
 // GET /api/accounts/:handle — single account detail
 //
 // AT-URIs (at://did/collection/rkey) in top_toxic_posts are converted to
 // clickable Bluesky web URLs (https://bsky.app/profile/did/post/rkey).
 //
-// The ?q= search is a case-insensitive substring match done in Rust after
-// loading all accounts — the DB layer doesn't have a LIKE query for this.
+// Paging and filtering (?limit=, ?offset=, ?min_score=, ?tier=, ?q=) happen
+// in the database, so a page costs the same however many accounts are scored.
 
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
@@ -15,75 +15,87 @@ use axum::response::{IntoResponse, Response};
 use axum::{Extension, Json};
 use serde::Deserialize;
 
-use crate::db::models::AccountScore;
+use crate::db::models::{AccountScore, ThreatPageQuery, ThreatTier};
 use crate::web::{api_error, AppState, AuthUser};
 
+/// Page size when `?limit=` isn't given.
+pub const DEFAULT_PAGE_LIMIT: u32 = 50;
+
+/// Largest page `?limit=` can ask for.
+pub const MAX_PAGE_LIMIT: u32 = 200;
+
 #[derive(Deserialize, Default)]
 pub struct AccountsQuery {
-    /// Filter by tier: High | Elevated | Watch | Low
+    /// Accounts per page (default 50, max 200)
+    pub limit: Option<u32>,
+    /// Accounts to skip before this page (default 0)
+    pub offset: Option<u32>,
+    /// Only accounts scoring at least this much (default 0)
+    pub min_score: Option<f64>,
+    /// Filter by tier: High | Elevated | Watch | Low (case-insensitive)
     pub tier: Option<String>,
     /// Case-insensitive handle search
     pub q: Option<String>,
-    /// Page number (1-based)
-    pub page: Option<usize>,
-    /// Results per page (default 50, max 200)
-    pub per_page: Option<usize>,
 }
 
-/// GET /api/accounts — list accounts with optional tier filter and search.
+/// GET /api/accounts — one page of accounts with optional filters.
+///
+/// Returns `{items, total, has_more, limit, offset}`, where `total` counts
+/// every account matching the filters across all pages.
 pub async fn list_accounts(
     State(state): State<AppState>,
     Extension(auth): Extension<AuthUser>,
     Query(params): Query<AccountsQuery>,
 ) -> Response {
-    let mut accounts = match state.db.get_ranked_threats(&auth.effective_did, 0.0).await {
-        Ok(accounts) => accounts,
+    let tier = match params.tier.as_deref().map(parse_tier) {
+        None => None,
+        Some(Some(tier)) => Some(tier),
+        Some(None) => {
+            return api_error(
+                StatusCode::BAD_REQUEST,
+                "tier must be one of High, Elevated, Watch or Low",
+            )
+        }
+    };
+
+    let query = ThreatPageQuery {
+        limit: params
+            .limit
+            .unwrap_or(DEFAULT_PAGE_LIMIT)
+            .clamp(1, MAX_PAGE_LIMIT),
+        offset: params.offset.unwrap_or(0),
+        min_score: params.min_score.unwrap_or(0.0),
+        tier,
+        handle: params.q.filter(|q| !q.is_empty()),
+    };
+
+    let page = match state
+        .db
+        .get_threats_paged(&auth.effective_did, &query, &state.config.tier_thresholds())
+        .await
+    {
+        Ok(page) => page,
         Err(e) => {
             tracing::error!(error = %e, "DB error fetching accounts");
             return api_error(StatusCode::INTERNAL_SERVER_ERROR, "Database error");
         }
     };
 
-    // Tier filter
-    if let Some(ref tier) = params.tier {
-        let tier_upper = tier.to_uppercase();
-        let tier_str = match tier_upper.as_str() {
-            "HIGH" => "High",
-            "ELEVATED" => "Elevated",
-            "WATCH" => "Watch",
-            "LOW" => "Low",
-            _ => "",
-        };
-        if !tier_str.is_empty() {
-            accounts.retain(|a| a.threat_tier.as_deref() == Some(tier_str));
-        }
-    }
-
-    // Handle search — case-insensitive substring match
-    if let Some(ref q) = params.q {
-        let q_lower = q.to_lowercase();
-        accounts.retain(|a| a.handle.to_lowercase().contains(&q_lower));
-    }
-
-    let total = accounts.len();
-
-    // Pagination
-    let per_page = params.per_page.unwrap_or(50).min(200);
-    let page = params.page.unwrap_or(1).max(1);
-    let offset = (page - 1) * per_page;
-    let accounts: Vec<serde_json::Value> = accounts
+    let offset = query.offset as usize;
+    let has_more = ((offset + page.items.len()) as u64) < page.total;
+    let items: Vec<serde_json::Value> = page
+        .items
         .into_iter()
-        .skip(offset)
-        .take(per_page)
         .enumerate()
         .map(|(i, a)| account_to_json(a, offset + i + 1))
         .collect();
 
     Json(serde_json::json!({
-        "accounts": accounts,
-        "total": total,
-        "page": page,
-        "per_page": per_page,
+        "items": items,
+        "total": page.total,
+        "has_more": has_more,
+        "limit": query.limit,
+        "offset": query.offset,
     }))
     .into_response()
 }
@@ -132,9 +144,13 @@ pub async fn get_account(
                 "handle": handle,
                 "toxicity_score": null,
                 "topic_overlap": null,
+                "overlap_method": null,
+                "overlap_post_count": null,
                 "threat_score": null,
                 "threat_tier": null,
                 "posts_analyzed": 0,
+                "replies_analyzed": null,
+                "evidence_strength": null,
                 "top_toxic_posts": [],
                 "scored_at": null,
                 "behavioral_signals": null,
@@ -150,6 +166,17 @@ pub async fn get_account(
 
 // --- Helpers ---
 
+/// Parse a `?tier=` value, ignoring case.
+fn parse_tier(tier: &str) -> Option<ThreatTier> {
+    match tier.to_ascii_lowercase().as_str() {
+        "high" => Some(ThreatTier::High),
+        "elevated" => Some(ThreatTier::Elevated),
+        "watch" => Some(ThreatTier::Watch),
+        "low" => Some(ThreatTier::Low),
+        _ => None,
+    }
+}
+
 /// Convert an AccountScore to a JSON value, transforming AT-URIs to bsky.app links.
 fn account_to_json(mut account: AccountScore, rank: usize) -> serde_json::Value {
     // Convert AT-URIs in top_toxic_posts to bsky.app URLs.
@@ -169,9 +196,13 @@ fn account_to_json(mut account: AccountScore, rank: usize) -> serde_json::Value
         "handle": account.handle,
         "toxicity_score": account.toxicity_score,
         "topic_overlap": account.topic_overlap,
+        "overlap_method": account.overlap_method,
+        "overlap_post_count": account.overlap_post_count,
         "threat_score": account.threat_score,
         "threat_tier": account.threat_tier,
         "posts_analyzed": account.posts_analyzed,
+        "replies_analyzed": account.replies_analyzed,
+        "evidence_strength": account.evidence_strength,
         "top_toxic_posts": account.top_toxic_posts,
         "scored_at": account.scored_at,
         "behavioral_signals": behavioral,
@@ -11,6 +11,7 @@ use axum::Json;
 use serde::Deserialize;
 
 use crate::bluesky::client::PublicAtpClient;
+use crate::error::Error;
 use crate::web::scan_job;
 use crate::web::AppState;
 use crate::web::AuthUser;
@@ -107,8 +108,8 @@ pub async fn pre_seed_user(
     let did = match client.resolve_handle(&handle).await {
         Ok(did) => did,
         Err(e) => {
-            let msg = e.to_string();
-            if msg.contains("not found") || msg.contains("404") {
+            let msg = format!("{e:#}");
+            if matches!(Error::from(e), Error::NotFound(_)) {
                 return Err((
                     StatusCode::NOT_FOUND,
                     Json(serde_json::json!({"error": format!("Handle not found: {handle}")})),
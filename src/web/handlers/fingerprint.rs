@@ -2,12 +2,17 @@
 //
 // Returns the stored fingerprint JSON along with its metadata
 // (post count and last updated timestamp).
+//
+// POST /api/fingerprint/rebuild — rebuild it in the background. Returns 202
+// Accepted if the rebuild starts, 409 Conflict if one is already running or
+// a scan is. Callers poll GET /api/status to track progress.
 
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::{Extension, Json};
 
+use crate::web::scan_job::launch_fingerprint_rebuild;
 use crate::web::{api_error, AppState, AuthUser};
 
 /// GET /api/fingerprint — return the stored topic fingerprint.
@@ -45,3 +50,54 @@ pub async fn get_fingerprint(
         }
     }
 }
+
+/// POST /api/fingerprint/rebuild — start a background fingerprint rebuild.
+pub async fn rebuild_fingerprint(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthUser>,
+) -> Response {
+    let mut mgr = state.scan_manager.write().await;
+    if let Err(msg) = mgr.try_start_fingerprint_rebuild(&auth.did) {
+        return api_error(StatusCode::CONFLICT, &msg);
+    }
+    drop(mgr); // Release lock before the DB lookup
+
+    let handle = match state.db.get_user_handle(&auth.did).await {
+        Ok(Some(handle)) => handle,
+        Ok(None) => {
+            // Roll back the rebuild state since we can't proceed
+            state
+                .scan_manager
+                .write()
+                .await
+                .finish_fingerprint_rebuild(&auth.did, Some("User not found".to_string()));
+            return api_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "User not found — re-authenticate",
+            );
+        }
+        Err(e) => {
+            state
+                .scan_manager
+                .write()
+                .await
+                .finish_fingerprint_rebuild(&auth.did, Some("Database error".to_string()));
+            tracing::error!(error = %e, "DB error looking up user handle");
+            return api_error(StatusCode::INTERNAL_SERVER_ERROR, "Database error");
+        }
+    };
+
+    launch_fingerprint_rebuild(
+        state.config.clone(),
+        state.db.clone(),
+        state.scan_manager.clone(),
+        auth.did,
+        handle,
+    );
+
+    (
+        StatusCode::ACCEPTED,
+        Json(serde_json::json!({ "message": "Fingerprint rebuild started" })),
+    )
+        .into_response()
+}
@@ -119,6 +119,7 @@ pub async fn get_review_queue(
                         "handle": a.handle,
                         "toxicity_score": a.toxicity_score,
                         "topic_overlap": a.topic_overlap,
+                        "overlap_method": a.overlap_method,
                         "threat_score": a.threat_score,
                         "threat_tier": a.threat_tier,
                         "posts_analyzed": a.posts_analyzed,
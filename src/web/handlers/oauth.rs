@@ -122,12 +122,17 @@ pub async fn initiate(
     State(state): State<AppState>,
     Json(body): Json<InitiateRequest>,
 ) -> Response {
-    let handle = body.handle.trim().to_string();
-    if handle.is_empty() {
+    if body.handle.trim().is_empty() {
         return api_error(StatusCode::BAD_REQUEST, "handle is required");
     }
+    // Accept `@handle` or a pasted profile URL, and reject typos before
+    // any network call
+    let handle = match crate::bluesky::handle::normalize(&body.handle) {
+        Ok(handle) => handle,
+        Err(e) => return api_error(StatusCode::BAD_REQUEST, &e.to_string()),
+    };
 
-    let http_client = reqwest::Client::new();
+    let http_client = crate::http::client();
 
     // Step 1: Resolve handle to DID
     // Try HTTP well-known first, fall back to AppView resolveHandle API
@@ -396,7 +401,7 @@ pub async fn callback(
         private_signing_key_data: state.signing_key.clone(),
     };
 
-    let http_client = reqwest::Client::new();
+    let http_client = crate::http::client();
 
     // Exchange authorization code for tokens
     let token_response = match oauth_complete(
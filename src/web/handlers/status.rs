@@ -1,6 +1,7 @@
 // GET /api/status — returns scan status and threat tier counts.
 //
-// Combines the live ScanStatus (running, progress) with DB-derived
+// Combines the live ScanStatus (running, progress) and any on-demand
+// fingerprint rebuild's FingerprintStatus with DB-derived
 // tier counts so the dashboard can show "High: 12, Elevated: 34, ..."
 // without a separate round-trip.
 
@@ -18,21 +19,34 @@ pub async fn get_status(
     // Snapshot scan status fields and release the lock before awaiting the DB.
     // Holding the read guard across an async DB call would block writers (e.g.
     // the scan job updating progress) for the duration of the query.
-    let (scan_running, started_at, progress_message, last_error) = {
+    let (scan_running, started_at, progress_message, last_error, fingerprint_rebuild) = {
         let mgr = state.scan_manager.read().await;
+        let fingerprint_rebuild = mgr.get_fingerprint_status(&auth.effective_did).map(|s| {
+            serde_json::json!({
+                "running": s.running,
+                "started_at": s.started_at,
+                "progress_message": s.progress_message,
+                "last_error": s.last_error,
+            })
+        });
         match mgr.get_status(&auth.effective_did) {
             Some(s) => (
                 s.running,
                 s.started_at.clone(),
                 s.progress_message.clone(),
                 s.last_error.clone(),
+                fingerprint_rebuild,
             ),
-            None => (false, None, String::new(), None),
+            None => (false, None, String::new(), None, fingerprint_rebuild),
         }
     };
 
     // Compute tier counts from DB. threat_tier is stored as Option<String>.
-    let threats = match state.db.get_ranked_threats(&auth.effective_did, 0.0).await {
+    let threats = match state
+        .db
+        .get_ranked_threats(&auth.effective_did, 0.0, &state.config.tier_thresholds())
+        .await
+    {
         Ok(t) => t,
         Err(e) => {
             tracing::error!(error = %e, "DB error in get_status");
@@ -57,6 +71,7 @@ pub async fn get_status(
         "started_at": started_at,
         "progress_message": progress_message,
         "last_error": last_error,
+        "fingerprint_rebuild": fingerprint_rebuild,
         "tier_counts": {
             "high": high,
             "elevated": elevated,
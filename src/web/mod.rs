@@ -17,6 +17,8 @@ use axum::routing::{delete, get, post};
 use axum::Router;
 use include_dir::{include_dir, Dir};
 use tokio::sync::RwLock;
+use tower_http::compression::predicate::{NotForContentType, Predicate, SizeAbove};
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing::info;
@@ -30,7 +32,8 @@ pub mod scan_job;
 pub mod test_helpers;
 
 // Embed the SvelteKit build output at compile time.
-// web/build/ must exist before `cargo build --features web` runs.
+// web/build/ must exist before `cargo build --features web` runs —
+// build.rs fails the build if index.html is missing.
 // Run `cd web && npm ci && npm run build` first.
 static ASSETS: Dir<'static> = include_dir!("$CARGO_MANIFEST_DIR/web/build");
 
@@ -104,6 +107,12 @@ pub async fn run_server(
         signing_key,
     };
 
+    scan_job::spawn_fingerprint_refresher(
+        state.config.clone(),
+        state.db.clone(),
+        state.scan_manager.clone(),
+    );
+
     let app = build_router(state);
 
     let addr = format!("{bind}:{port}");
@@ -114,6 +123,10 @@ pub async fn run_server(
     Ok(())
 }
 
+/// Responses smaller than this are sent uncompressed — below roughly one
+/// packet the gzip header and CPU cost outweigh the bytes saved.
+pub const MIN_COMPRESS_BYTES: u16 = 1024;
+
 pub(crate) fn build_router(state: AppState) -> Router {
     // Authenticated API routes (require valid session cookie)
     let protected_api = Router::new()
@@ -128,6 +141,10 @@ pub(crate) fn build_router(state: AppState) -> Router {
             "/api/fingerprint",
             get(handlers::fingerprint::get_fingerprint),
         )
+        .route(
+            "/api/fingerprint/rebuild",
+            post(handlers::fingerprint::rebuild_fingerprint),
+        )
         .route("/api/scan", post(handlers::scan::trigger_scan))
         .route(
             "/api/accounts/{did}/label",
@@ -179,6 +196,17 @@ pub(crate) fn build_router(state: AppState) -> Router {
                 ])
                 .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION]),
         )
+        .layer(
+            // gzip/br for clients that send Accept-Encoding. Covers the JSON
+            // API and the embedded SPA assets alike; images are already
+            // compressed and SSE must stream unbuffered.
+            CompressionLayer::new().compress_when(
+                SizeAbove::new(MIN_COMPRESS_BYTES)
+                    .and(NotForContentType::GRPC)
+                    .and(NotForContentType::IMAGES)
+                    .and(NotForContentType::SSE),
+            ),
+        )
         .layer(TraceLayer::new_for_http())
         .with_state(state)
 }
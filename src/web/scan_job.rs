@@ -4,19 +4,30 @@
 // so startup stays fast and the scorer isn't held in memory while idle.
 //
 // Only one scan can run at a time; POST /api/scan returns 409 if one is already active.
+//
+// A background refresher also rebuilds each user's topic fingerprint on a
+// fixed interval (CHARCOAL_FINGERPRINT_REFRESH_DAYS) so overlap scoring
+// tracks drifting interests. It never runs alongside a scan.
+//
+// POST /api/fingerprint/rebuild starts the same rebuild on demand, tracked
+// like a scan so the dashboard can poll its progress.
 
 use std::collections::{HashMap, HashSet};
 use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures::FutureExt;
 use tracing::{error, info, warn};
 
 use crate::bluesky::client::PublicAtpClient;
-use crate::config::Config;
+use crate::config::{Config, IgnoreList, ScorerBackend};
 use crate::db::Database;
+use crate::output::reporter::Reporter;
+use crate::pipeline::amplification::ScanOptions;
 use crate::scoring::behavioral::detect_pile_on_participants;
-use crate::scoring::threat::ThreatWeights;
+use crate::scoring::calibration;
+use crate::scoring::profile::ScoringOptions;
 use crate::topics::fingerprint::TopicFingerprint;
 use crate::toxicity::download::{
     embedding_files_present, embedding_model_dir, model_files_present, nli_files_present,
@@ -27,6 +38,7 @@ use crate::toxicity::traits::ToxicityScorer;
 /// Manages per-user scan status with a global one-at-a-time gate.
 pub struct ScanManager {
     statuses: HashMap<String, ScanStatus>,
+    fingerprint_statuses: HashMap<String, FingerprintStatus>,
     fingerprint_building: HashSet<String>,
     any_running: bool,
 }
@@ -41,6 +53,7 @@ impl ScanManager {
     pub fn new() -> Self {
         Self {
             statuses: HashMap::new(),
+            fingerprint_statuses: HashMap::new(),
             fingerprint_building: HashSet::new(),
             any_running: false,
         }
@@ -51,6 +64,9 @@ impl ScanManager {
         if self.any_running {
             return Err("A scan is already running".to_string());
         }
+        if self.fingerprint_building.contains(user_did) {
+            return Err("Topic fingerprint is being rebuilt — try again shortly".to_string());
+        }
         self.any_running = true;
         self.statuses.insert(
             user_did.to_string(),
@@ -99,6 +115,60 @@ impl ScanManager {
     pub fn is_fingerprint_building(&self, user_did: &str) -> bool {
         self.fingerprint_building.contains(user_did)
     }
+
+    /// Atomically claim a scheduled fingerprint refresh. Refuses while any
+    /// scan is running (scans read the fingerprint and share the API
+    /// budget) or while a build for this user is already in flight.
+    pub fn try_start_fingerprint_refresh(&mut self, user_did: &str) -> bool {
+        if self.any_running || self.fingerprint_building.contains(user_did) {
+            return false;
+        }
+        self.fingerprint_building.insert(user_did.to_string());
+        true
+    }
+
+    /// Atomically claim an on-demand fingerprint rebuild. Refuses while a
+    /// scan is running or a build for this user is already in flight.
+    pub fn try_start_fingerprint_rebuild(&mut self, user_did: &str) -> Result<(), String> {
+        if self.fingerprint_building.contains(user_did) {
+            return Err("A fingerprint rebuild is already running".to_string());
+        }
+        if self.any_running {
+            return Err("A scan is running — rebuild the fingerprint when it finishes".to_string());
+        }
+        self.fingerprint_building.insert(user_did.to_string());
+        self.fingerprint_statuses.insert(
+            user_did.to_string(),
+            FingerprintStatus {
+                running: true,
+                started_at: Some(chrono::Utc::now().to_rfc3339()),
+                progress_message: "Starting fingerprint rebuild...".to_string(),
+                last_error: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Mark an on-demand rebuild finished, with its error if it failed.
+    pub fn finish_fingerprint_rebuild(&mut self, user_did: &str, error: Option<String>) {
+        self.fingerprint_building.remove(user_did);
+        if let Some(status) = self.fingerprint_statuses.get_mut(user_did) {
+            status.running = false;
+            status.progress_message = match error {
+                Some(_) => "Fingerprint rebuild failed — see server logs".to_string(),
+                None => "Fingerprint rebuilt".to_string(),
+            };
+            status.last_error = error;
+        }
+    }
+
+    pub fn get_fingerprint_status(&self, user_did: &str) -> Option<&FingerprintStatus> {
+        self.fingerprint_statuses.get(user_did)
+    }
+
+    pub fn get_fingerprint_status_mut(&mut self, user_did: &str) -> Option<&mut FingerprintStatus> {
+        self.fingerprint_statuses.get_mut(user_did)
+    }
 }
 
 /// Live status of the background scan, exposed via GET /api/status.
@@ -114,6 +184,20 @@ pub struct ScanStatus {
     pub last_error: Option<String>,
 }
 
+/// Live status of an on-demand fingerprint rebuild, exposed via
+/// GET /api/status.
+#[derive(Debug, Clone, Default)]
+pub struct FingerprintStatus {
+    /// True while the rebuild is in progress.
+    pub running: bool,
+    /// ISO 8601 timestamp of when the current/last rebuild started.
+    pub started_at: Option<String>,
+    /// Human-readable progress message updated as phases complete.
+    pub progress_message: String,
+    /// Error message from the last rebuild, if it failed.
+    pub last_error: Option<String>,
+}
+
 use tokio::sync::RwLock;
 
 /// Build a Zentropi client when both API key and labeler ID are configured.
@@ -181,6 +265,37 @@ pub fn launch_scan(
     });
 }
 
+/// Launch an on-demand fingerprint rebuild in a background tokio task.
+/// The caller must have claimed it with `try_start_fingerprint_rebuild`.
+/// Returns immediately. Callers poll `scan_manager` to track progress.
+pub fn launch_fingerprint_rebuild(
+    config: Arc<Config>,
+    db: Arc<dyn Database>,
+    scan_manager: Arc<RwLock<ScanManager>>,
+    user_did: String,
+    handle: String,
+) {
+    tokio::spawn(async move {
+        let result = AssertUnwindSafe(build_user_fingerprint_tracked(
+            &config,
+            db.as_ref(),
+            &user_did,
+            &handle,
+            Some(&scan_manager),
+        ))
+        .catch_unwind()
+        .await
+        .unwrap_or_else(|_| Err(anyhow::anyhow!("Fingerprint rebuild panicked")));
+        if let Err(ref e) = result {
+            error!(user_did = %user_did, error = %e, "Fingerprint rebuild failed");
+        }
+        scan_manager
+            .write()
+            .await
+            .finish_fingerprint_rebuild(&user_did, result.err().map(|e| e.to_string()));
+    });
+}
+
 /// Build a topic fingerprint and embeddings for a user.
 /// Fetches their recent posts, runs TF-IDF, and computes MiniLM embeddings.
 /// Used by both the scan pipeline (auto-fingerprint) and the admin pre-seed handler.
@@ -190,16 +305,49 @@ pub async fn build_user_fingerprint(
     user_did: &str,
     handle: &str,
 ) -> anyhow::Result<()> {
-    info!("No fingerprint found for {user_did}, building automatically");
+    build_user_fingerprint_tracked(config, db, user_did, handle, None).await
+}
+
+/// Update an on-demand rebuild's progress message, if it's being tracked.
+async fn set_fingerprint_progress(
+    scan_manager: Option<&RwLock<ScanManager>>,
+    user_did: &str,
+    message: &str,
+) {
+    if let Some(mgr) = scan_manager {
+        if let Some(s) = mgr.write().await.get_fingerprint_status_mut(user_did) {
+            s.progress_message = message.to_string();
+        }
+    }
+}
+
+/// `build_user_fingerprint`, reporting each phase to the user's
+/// `FingerprintStatus` when `scan_manager` is given.
+async fn build_user_fingerprint_tracked(
+    config: &Config,
+    db: &dyn Database,
+    user_did: &str,
+    handle: &str,
+    scan_manager: Option<&RwLock<ScanManager>>,
+) -> anyhow::Result<()> {
+    info!("Building topic fingerprint for {user_did}");
 
+    set_fingerprint_progress(scan_manager, user_did, "Fetching recent posts…").await;
     let client = PublicAtpClient::new(&config.public_api_url)?;
-    let fp_posts = crate::bluesky::posts::fetch_recent_posts(&client, handle, 500).await?;
+    let fp_posts = crate::bluesky::posts::fetch_recent_posts_paged(
+        &client,
+        handle,
+        500,
+        config.feed_page_size,
+    )
+    .await?;
     if fp_posts.is_empty() {
         anyhow::bail!(
             "No posts found — Charcoal needs posting history to build a topic fingerprint."
         );
     }
 
+    set_fingerprint_progress(scan_manager, user_did, "Extracting topics…").await;
     let post_texts: Vec<String> = fp_posts.iter().map(|p| p.text.clone()).collect();
     let extractor = crate::topics::tfidf::TfIdfExtractor::default();
     let fp = crate::topics::traits::TopicExtractor::extract(&extractor, &post_texts)?;
@@ -214,15 +362,18 @@ pub async fn build_user_fingerprint(
 
     // Compute and save sentence embedding if the embedding model is available
     let embed_dir = embedding_model_dir(&config.model_dir);
+    let onnx_provider = config.onnx_provider;
     if embedding_files_present(&config.model_dir) {
+        set_fingerprint_progress(scan_manager, user_did, "Computing sentence embedding…").await;
         match tokio::task::spawn_blocking(move || {
-            crate::topics::embeddings::SentenceEmbedder::load(&embed_dir)
+            crate::topics::embeddings::SentenceEmbedder::load(&embed_dir, onnx_provider)
         })
         .await
         {
             Ok(Ok(embedder)) => match embedder.embed_batch(&post_texts).await {
                 Ok(post_embeddings) => {
-                    let mean_emb = crate::topics::embeddings::mean_embedding(&post_embeddings);
+                    let mean_emb =
+                        crate::topics::embeddings::mean_embedding(&post_embeddings, embedder.dim());
                     if let Err(e) = db.save_embedding(user_did, &mean_emb).await {
                         warn!(error = %e, "Failed to save embedding during fingerprint build");
                     } else {
@@ -245,6 +396,97 @@ pub async fn build_user_fingerprint(
     Ok(())
 }
 
+/// How often the refresher checks for fingerprints that are due.
+const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Spawn the scheduled fingerprint refresher. Does nothing when
+/// `fingerprint_refresh_days` is 0.
+pub fn spawn_fingerprint_refresher(
+    config: Arc<Config>,
+    db: Arc<dyn Database>,
+    scan_manager: Arc<RwLock<ScanManager>>,
+) {
+    if config.fingerprint_refresh_days == 0 {
+        info!("Scheduled fingerprint refresh disabled");
+        return;
+    }
+    info!(
+        every_days = config.fingerprint_refresh_days,
+        "Scheduled fingerprint refresh enabled"
+    );
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(REFRESH_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let result = AssertUnwindSafe(refresh_due_fingerprints(
+                &config,
+                db.as_ref(),
+                &scan_manager,
+            ))
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("Fingerprint refresh panicked")));
+            if let Err(e) = result {
+                warn!(error = %e, "Scheduled fingerprint refresh pass failed");
+            }
+        }
+    });
+}
+
+/// Rebuild every user's fingerprint whose `updated_at` is at least
+/// `fingerprint_refresh_days` old. Users without a fingerprint are left to
+/// the scan's auto-build; users with a scan in progress are retried on the
+/// next pass. Returns how many fingerprints were refreshed.
+pub async fn refresh_due_fingerprints(
+    config: &Config,
+    db: &dyn Database,
+    scan_manager: &RwLock<ScanManager>,
+) -> anyhow::Result<usize> {
+    let mut refreshed = 0;
+
+    for user in db.list_users().await? {
+        let Some((_, _, updated_at)) = db.get_fingerprint(&user.did).await? else {
+            continue;
+        };
+        let Some(age) = crate::topics::fingerprint::age_days(&updated_at) else {
+            continue;
+        };
+        if !crate::topics::fingerprint::is_refresh_due(age, config.fingerprint_refresh_days) {
+            continue;
+        }
+
+        if !scan_manager
+            .write()
+            .await
+            .try_start_fingerprint_refresh(&user.did)
+        {
+            info!(user_did = %user.did, "Scan in progress, deferring fingerprint refresh");
+            continue;
+        }
+
+        info!(user_did = %user.did, age_days = age, "Refreshing topic fingerprint");
+        let result = build_user_fingerprint(config, db, &user.did, &user.handle).await;
+        scan_manager
+            .write()
+            .await
+            .finish_fingerprint_build(&user.did);
+
+        // A failed fetch leaves the old fingerprint in place; the next pass
+        // tries again
+        match result {
+            Ok(()) => refreshed += 1,
+            Err(e) => warn!(
+                user_did = %user.did,
+                error = %e,
+                "Fingerprint refresh failed, keeping the existing fingerprint"
+            ),
+        }
+    }
+
+    Ok(refreshed)
+}
+
 async fn run_scan(
     config: Arc<Config>,
     db: Arc<dyn Database>,
@@ -262,11 +504,14 @@ async fn run_scan(
 
     let primary_scorer: Box<dyn ToxicityScorer> = if model_files_present(&config.model_dir) {
         let model_dir = config.model_dir.clone();
+        let onnx_provider = config.onnx_provider;
         // OnnxToxicityScorer::load is synchronous blocking I/O — offload to avoid
         // stalling the async runtime while the model is read from disk.
-        let loaded = tokio::task::spawn_blocking(move || OnnxToxicityScorer::load(&model_dir))
-            .await
-            .map_err(|e| anyhow::anyhow!("spawn_blocking panicked loading ONNX model: {e}"))??;
+        let loaded = tokio::task::spawn_blocking(move || {
+            OnnxToxicityScorer::load(&model_dir, onnx_provider)
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("spawn_blocking panicked loading ONNX model: {e}"))??;
         Box::new(loaded)
     } else {
         anyhow::bail!("ONNX model files not found. Run `charcoal download-model` first.");
@@ -294,11 +539,12 @@ async fn run_scan(
     }
 
     let embed_dir = embedding_model_dir(&config.model_dir);
+    let onnx_provider = config.onnx_provider;
     let embedder = if embedding_files_present(&config.model_dir) {
         // SentenceEmbedder::load is synchronous blocking I/O — offload to avoid
         // stalling the async runtime while the model is read from disk.
         match tokio::task::spawn_blocking(move || {
-            crate::topics::embeddings::SentenceEmbedder::load(&embed_dir)
+            crate::topics::embeddings::SentenceEmbedder::load(&embed_dir, onnx_provider)
         })
         .await
         {
@@ -361,10 +607,11 @@ async fn run_scan(
         }
     }
 
-    let client = PublicAtpClient::new(&config.public_api_url)?;
+    let client = PublicAtpClient::new(&config.public_api_url)?
+        .with_max_in_flight(config.max_in_flight_requests);
 
     let fingerprint: TopicFingerprint = match db.get_fingerprint(user_did).await? {
-        Some((json, _, _)) => serde_json::from_str(&json)?,
+        Some((json, _, _)) => TopicFingerprint::from_json(&json)?,
         None => {
             // Auto-fingerprint: fetch posts, run TF-IDF, compute embeddings, save to DB.
             // build_user_fingerprint handles the full pipeline including embeddings.
@@ -383,11 +630,32 @@ async fn run_scan(
                 .get_fingerprint(user_did)
                 .await?
                 .expect("Fingerprint was just saved");
-            serde_json::from_str(&json)?
+            TopicFingerprint::from_json(&json)?
         }
     };
 
-    let protected_embedding = db.get_embedding(user_did).await?;
+    if fingerprint.is_degenerate() {
+        warn!(
+            user_did,
+            post_count = fingerprint.post_count,
+            "Topic fingerprint has no usable clusters — all overlap scores will be 0.0"
+        );
+    }
+
+    // An embedding built by a different model can't be compared against
+    // this one's vectors; score with TF-IDF until the fingerprint is rebuilt.
+    let protected_embedding = db.get_embedding(user_did).await?.filter(|stored| {
+        let Some(emb) = &embedder else {
+            return true;
+        };
+        match crate::topics::embeddings::check_stored_dim(stored, emb.dim()) {
+            Ok(()) => true,
+            Err(e) => {
+                warn!(error = %e, "Ignoring stored embedding, using TF-IDF fallback");
+                false
+            }
+        }
+    });
 
     // Build per-post embeddings for follower NLI inferred pair matching.
     // Each protected post gets its own embedding so followers' posts can be
@@ -438,7 +706,20 @@ async fn run_scan(
         .map(|p| (p.uri.clone(), p.text.clone()))
         .collect();
 
-    let mut events = constellation.find_amplification_events(&post_uris).await;
+    // Constellation first, the AppView for posts it can't answer for
+    let appview = crate::bluesky::quotes::AppviewSource::new(&client);
+    let mut events = match crate::bluesky::amplification::find_amplification_events(
+        &[&constellation, &appview],
+        &post_uris,
+    )
+    .await
+    {
+        Ok(found) => found.events,
+        Err(e) => {
+            warn!(error = %e, "No amplification source available, continuing without quotes and reposts");
+            Vec::new()
+        }
+    };
 
     // Also fetch likes via Constellation backlinks
     {
@@ -484,6 +765,7 @@ async fn run_scan(
                             original_post_uri: Some(post.uri.clone()),
                             amplifier_post_uri: uri.clone(),
                             indexed_at: String::new(),
+                            occurred_at: crate::bluesky::amplification::uri_timestamp(uri),
                         });
                     }
                 }
@@ -502,7 +784,8 @@ async fn run_scan(
         .collect();
     if !unresolved_dids.is_empty() {
         if let Ok(resolved) =
-            crate::bluesky::profiles::resolve_dids_to_handles(&client, &unresolved_dids).await
+            crate::pipeline::handles::resolve_dids_cached(&client, db.as_ref(), &unresolved_dids)
+                .await
         {
             for event in &mut events {
                 if let Some(handle) = resolved.get(&event.amplifier_did) {
@@ -512,16 +795,8 @@ async fn run_scan(
         }
     }
 
-    // Deduplicate: by amplifier_post_uri for quotes/replies, by (did, post_uri) for likes
-    let mut seen_uris = HashSet::new();
-    let mut seen_likes = HashSet::new();
-    events.retain(|e| {
-        if e.event_type == "like" {
-            seen_likes.insert((e.amplifier_did.clone(), e.original_post_uri.clone()))
-        } else {
-            seen_uris.insert(e.amplifier_post_uri.clone())
-        }
-    });
+    // One event per (amplifier, post, type), oldest first
+    let events = crate::bluesky::amplification::dedup_and_sort(events);
     let event_count = events.len();
 
     // Phase 5: behavioral context
@@ -558,7 +833,17 @@ async fn run_scan(
     );
 
     // Phase 6: run amplification pipeline
-    let weights = ThreatWeights::default();
+    // Web scans always score with ONNX (see primary_scorer above)
+    let weights = calibration::threat_weights(&config, &ScorerBackend::Onnx);
+    let scoring_options = ScoringOptions::from_config(&config);
+    let options = ScanOptions {
+        analyze_followers: true,
+        // `.charcoalignore` and the alert webhook belong to whoever runs the
+        // CLI, not to each dashboard user
+        ignored_amplifiers: IgnoreList::default(),
+        alert_webhook: None,
+        ..ScanOptions::from_config(&config)
+    };
     let result = crate::pipeline::amplification::run(
         &client,
         scorer.as_ref(),
@@ -566,10 +851,9 @@ async fn run_scan(
         user_did,
         &fingerprint,
         &weights,
+        &scoring_options,
         actor_handle,
-        true, // analyze_followers
-        50,   // max_followers_per_amplifier
-        8,    // concurrency
+        &options,
         embedder.as_ref(),
         protected_embedding.as_deref(),
         events,
@@ -578,8 +862,9 @@ async fn run_scan(
         &original_text_cache,
         nli_scorer.as_ref(),
         protected_posts_with_embeddings.as_deref(),
-        Some(config.data_dir()),
         &graph_distances,
+        // The server has no --json flag; progress stays plain text
+        &Reporter::human(),
     )
     .await;
 
@@ -5,10 +5,11 @@
 // without any network calls, database access, or filesystem side effects
 // (except report generation which writes to /tmp).
 
+use charcoal::config::EvidenceOrder;
 use charcoal::db::models::{AccountScore, AmplificationEvent, ThreatTier, ToxicPost};
 use charcoal::output::truncate_chars;
 use charcoal::scoring::threat::{compute_threat_score, ThreatWeights};
-use charcoal::topics::fingerprint::{TopicCluster, TopicFingerprint};
+use charcoal::topics::fingerprint::{TopicCluster, TopicFingerprint, FINGERPRINT_SCHEMA_VERSION};
 use charcoal::topics::overlap::{cosine_from_weights, cosine_similarity};
 use charcoal::topics::tfidf::TfIdfExtractor;
 use charcoal::topics::traits::TopicExtractor;
@@ -169,6 +170,7 @@ fn fingerprint_to_cosine_manual_pipeline() {
             },
         ],
         post_count: 100,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
 
     let fp_b = TopicFingerprint {
@@ -185,6 +187,7 @@ fn fingerprint_to_cosine_manual_pipeline() {
             },
         ],
         post_count: 50,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
 
     // Step 1: Verify keyword_weights produces expected maps
@@ -398,16 +401,16 @@ fn make_account(handle: &str, score: f64, tier: &str, toxicity: f64, overlap: f6
                 text: format!("Sample toxic post from {handle}"),
                 toxicity,
                 uri: format!("at://{handle}/post/1"),
+                attributes: None,
+                created_at: None,
+                hardblock_pattern: None,
+                flagged_for: None,
             }]
         } else {
             vec![]
         },
         scored_at: "2026-02-16".to_string(),
-        behavioral_signals: None,
-        context_score: None,
-        graph_distance: None,
-        fingerprint_quality: None,
-        scoring_confidence: None,
+        ..Default::default()
     }
 }
 
@@ -421,7 +424,16 @@ fn report_includes_all_tier_counts() {
     ];
 
     let tmp_path = "/tmp/charcoal_test_all_tiers.md";
-    let result = charcoal::output::markdown::generate_report(&accounts, None, &[], tmp_path);
+    let result = charcoal::output::markdown::generate_report(
+        &accounts,
+        None,
+        &[],
+        None,
+        EvidenceOrder::Toxicity,
+        false,
+        None,
+        tmp_path,
+    );
     assert!(result.is_ok());
 
     let content = std::fs::read_to_string(tmp_path).unwrap();
@@ -437,7 +449,16 @@ fn report_includes_all_tier_counts() {
 #[test]
 fn report_empty_accounts() {
     let tmp_path = "/tmp/charcoal_test_empty_accounts.md";
-    let result = charcoal::output::markdown::generate_report(&[], None, &[], tmp_path);
+    let result = charcoal::output::markdown::generate_report(
+        &[],
+        None,
+        &[],
+        None,
+        EvidenceOrder::Toxicity,
+        false,
+        None,
+        tmp_path,
+    );
     assert!(result.is_ok());
 
     let content = std::fs::read_to_string(tmp_path).unwrap();
@@ -464,10 +485,20 @@ fn report_includes_fingerprint_section() {
             },
         ],
         post_count: 50,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
 
     let tmp_path = "/tmp/charcoal_test_fp_section.md";
-    let result = charcoal::output::markdown::generate_report(&[], Some(&fp), &[], tmp_path);
+    let result = charcoal::output::markdown::generate_report(
+        &[],
+        Some(&fp),
+        &[],
+        None,
+        EvidenceOrder::Toxicity,
+        false,
+        None,
+        tmp_path,
+    );
     assert!(result.is_ok());
 
     let content = std::fs::read_to_string(tmp_path).unwrap();
@@ -514,7 +545,16 @@ fn report_quotes_but_not_reposts() {
     ];
 
     let tmp_path = "/tmp/charcoal_test_events_filter.md";
-    let result = charcoal::output::markdown::generate_report(&[], None, &events, tmp_path);
+    let result = charcoal::output::markdown::generate_report(
+        &[],
+        None,
+        &events,
+        None,
+        EvidenceOrder::Toxicity,
+        false,
+        None,
+        tmp_path,
+    );
     assert!(result.is_ok());
 
     let content = std::fs::read_to_string(tmp_path).unwrap();
@@ -527,6 +567,51 @@ fn report_quotes_but_not_reposts() {
     let _ = std::fs::remove_file(tmp_path);
 }
 
+#[test]
+fn report_shows_events_window_in_headers() {
+    let events = vec![AmplificationEvent {
+        id: 1,
+        event_type: "quote".to_string(),
+        amplifier_did: "did:plc:troll".to_string(),
+        amplifier_handle: "troll.bsky.social".to_string(),
+        original_post_uri: "at://post/1".to_string(),
+        amplifier_post_uri: Some("at://post/2".to_string()),
+        amplifier_text: Some("recent brigade".to_string()),
+        detected_at: "2026-02-15".to_string(),
+        followers_fetched: false,
+        followers_scored: false,
+        original_post_text: None,
+        context_score: None,
+    }];
+
+    let tmp_path = "/tmp/charcoal_test_events_window.md";
+    let result = charcoal::output::markdown::generate_report(
+        &[],
+        None,
+        &events,
+        Some(30),
+        EvidenceOrder::Toxicity,
+        false,
+        None,
+        tmp_path,
+    );
+    assert!(result.is_ok());
+
+    let content = std::fs::read_to_string(tmp_path).unwrap();
+    assert!(content.contains("Amplification events: last 30 days"));
+    assert!(content.contains("## Amplification Events (last 30 days)"));
+
+    let _ = std::fs::remove_file(tmp_path);
+}
+
+#[test]
+fn events_window_label_formats() {
+    use charcoal::output::events_window_label;
+    assert_eq!(events_window_label(None), "all time");
+    assert_eq!(events_window_label(Some(1)), "last 1 day");
+    assert_eq!(events_window_label(Some(30)), "last 30 days");
+}
+
 #[test]
 fn report_escapes_pipe_in_quote_text() {
     let events = vec![AmplificationEvent {
@@ -545,7 +630,16 @@ fn report_escapes_pipe_in_quote_text() {
     }];
 
     let tmp_path = "/tmp/charcoal_test_pipe_escape.md";
-    let result = charcoal::output::markdown::generate_report(&[], None, &events, tmp_path);
+    let result = charcoal::output::markdown::generate_report(
+        &[],
+        None,
+        &events,
+        None,
+        EvidenceOrder::Toxicity,
+        false,
+        None,
+        tmp_path,
+    );
     assert!(result.is_ok());
 
     let content = std::fs::read_to_string(tmp_path).unwrap();
@@ -557,6 +651,114 @@ fn report_escapes_pipe_in_quote_text() {
     let _ = std::fs::remove_file(tmp_path);
 }
 
+#[test]
+fn json_report_empty_inputs_are_valid_document() {
+    let tmp_path = "/tmp/charcoal_test_empty_report.json";
+    let result = charcoal::output::json::generate_json_report(&[], None, &[], tmp_path);
+    assert!(result.is_ok());
+
+    let content = std::fs::read_to_string(tmp_path).unwrap();
+    let doc: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(
+        doc["schema_version"],
+        charcoal::output::json::JSON_REPORT_SCHEMA_VERSION
+    );
+    assert_eq!(doc["accounts"], serde_json::json!([]));
+    assert_eq!(doc["amplification_events"], serde_json::json!([]));
+    assert!(doc["fingerprint"].is_null());
+
+    let _ = std::fs::remove_file(tmp_path);
+}
+
+#[test]
+fn report_files_share_one_output_dir() {
+    use charcoal::output::ReportFiles;
+    use chrono::TimeZone;
+
+    let dir = std::env::temp_dir().join(format!("charcoal-reports-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let time = chrono::Utc
+        .with_ymd_and_hms(2026, 10, 16, 9, 30, 0)
+        .unwrap();
+    let files = ReportFiles::at(dir.join("profile-a"), Some(time));
+    let accounts = vec![make_account("high.bsky.social", 65.0, "High", 0.85, 0.4)];
+
+    // The directory doesn't exist yet — each writer creates it
+    let md = charcoal::output::markdown::generate_report(
+        &accounts,
+        None,
+        &[],
+        None,
+        EvidenceOrder::Toxicity,
+        false,
+        None,
+        &files.path("md"),
+    )
+    .unwrap();
+    let json =
+        charcoal::output::json::generate_json_report(&accounts, None, &[], &files.path("json"))
+            .unwrap();
+    let csv = charcoal::output::csv::write_account_scores(&accounts, &files.path("csv")).unwrap();
+
+    let written: Vec<std::path::PathBuf> = [&md, &json, &csv]
+        .iter()
+        .map(std::path::PathBuf::from)
+        .collect();
+    assert!(written.iter().all(|p| p.is_file()));
+    assert!(written
+        .iter()
+        .all(|p| p.parent() == Some(dir.join("profile-a").as_path())));
+    assert!(md.ends_with("charcoal-report-20261016-093000.md"));
+    assert!(json.ends_with("charcoal-report-20261016-093000.json"));
+    assert!(csv.ends_with("charcoal-report-20261016-093000.csv"));
+    // The diff snapshot keeps its fixed name for the next run to find
+    assert!(files
+        .snapshot_path()
+        .ends_with("profile-a/charcoal-report.snapshot.json"));
+
+    let plain = ReportFiles::new(dir.join("profile-b"), false);
+    assert!(plain.path("md").ends_with("profile-b/charcoal-report.md"));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn json_report_nests_behavioral_signals_and_fingerprint() {
+    let mut with_signals = make_account("high.bsky.social", 65.0, "High", 0.85, 0.4);
+    with_signals.behavioral_signals = Some(r#"{"quote_ratio":0.6,"pile_on":true}"#.to_string());
+    let mut garbled = make_account("low.bsky.social", 3.0, "Low", 0.1, 0.02);
+    garbled.behavioral_signals = Some("{not json".to_string());
+
+    let fingerprint = TopicFingerprint {
+        clusters: vec![TopicCluster {
+            label: "Fat liberation".to_string(),
+            keywords: vec!["fatlib".to_string()],
+            weight: 0.7,
+        }],
+        post_count: 40,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
+    };
+
+    let doc = charcoal::output::json::build_json_report(
+        &[with_signals, garbled],
+        Some(&fingerprint),
+        &[],
+    )
+    .unwrap();
+
+    let accounts = doc["accounts"].as_array().unwrap();
+    assert_eq!(accounts.len(), 2);
+    assert_eq!(accounts[0]["handle"], "high.bsky.social");
+    // Parsed back into an object, not left as a string
+    assert_eq!(accounts[0]["behavioral_signals"]["quote_ratio"], 0.6);
+    assert_eq!(accounts[0]["behavioral_signals"]["pile_on"], true);
+    assert!(accounts[1]["behavioral_signals"].is_null());
+    assert_eq!(accounts[0]["top_toxic_posts"][0]["toxicity"], 0.85);
+
+    assert_eq!(doc["fingerprint"]["post_count"], 40);
+    assert_eq!(doc["fingerprint"]["clusters"][0]["label"], "Fat liberation");
+}
+
 // ============================================================
 // Chain: truncate_chars in report context
 // ============================================================
@@ -591,6 +793,51 @@ async fn noop_scorer_batch_also_errors() {
     assert!(result.is_err());
 }
 
+// ============================================================
+// FixedScorer / MapScorer — deterministic test scorers
+// ============================================================
+
+#[tokio::test]
+async fn fixed_scorer_returns_configured_value() {
+    use charcoal::toxicity::traits::{FixedScorer, ToxicityScorer};
+    let scorer = FixedScorer { value: 0.42 };
+    let texts = vec!["hello".to_string(), "world".to_string()];
+    let results = scorer.score_batch(&texts).await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.toxicity == 0.42));
+}
+
+#[tokio::test]
+async fn fixed_scorer_drives_binary_verdicts() {
+    use charcoal::toxicity::traits::{FixedScorer, ToxicityScorer};
+    let texts = vec!["a".to_string()];
+    let toxic = FixedScorer { value: 0.9 }
+        .classify_batch_with_contexts(&texts, &[None], &Default::default())
+        .await
+        .unwrap();
+    assert!(toxic[0].is_toxic);
+    let clean = FixedScorer { value: 0.1 }
+        .classify_batch_with_contexts(&texts, &[None], &Default::default())
+        .await
+        .unwrap();
+    assert!(!clean[0].is_toxic);
+}
+
+#[tokio::test]
+async fn map_scorer_looks_up_text_with_default() {
+    use charcoal::toxicity::traits::{MapScorer, ToxicityScorer};
+    let mut scorer = MapScorer {
+        default: 0.02,
+        ..Default::default()
+    };
+    scorer.scores.insert("you're disgusting".to_string(), 0.95);
+
+    let hostile = scorer.score_text("you're disgusting").await.unwrap();
+    let benign = scorer.score_text("lovely weather").await.unwrap();
+    assert_eq!(hostile.toxicity, 0.95);
+    assert_eq!(benign.toxicity, 0.02);
+}
+
 // ============================================================
 // Amplification event types: likes and replies
 // ============================================================
@@ -9,7 +9,8 @@
 #![cfg(feature = "postgres")]
 
 use anyhow::Result;
-use charcoal::db::models::AccountScore;
+use charcoal::db::models::{AccountScore, ScoreSmoothing, TierThresholds};
+use charcoal::db::PgPoolConfig;
 
 const TEST_USER: &str = "did:plc:pgtest_user000000000000";
 
@@ -42,11 +43,17 @@ async fn cleanup_test_data(url: &str) -> Result<()> {
 
     // Delete test-specific account scores (scoped by user_did)
     sqlx_core::query::query(
-        "DELETE FROM account_scores WHERE did = 'did:plc:pgtest1' AND user_did = 'did:plc:pgtest_user000000000000'",
+        "DELETE FROM account_scores WHERE (did IN ('did:plc:pgtest1', 'did:plc:pgema') OR did LIKE 'did:plc:pgbatch%') AND user_did = 'did:plc:pgtest_user000000000000'",
     )
     .execute(&pool)
     .await
     .map_err(|e| anyhow::anyhow!("cleanup: account_scores delete failed: {e}"))?;
+    sqlx_core::query::query(
+        "DELETE FROM account_score_history WHERE (did IN ('did:plc:pgtest1', 'did:plc:pgema') OR did LIKE 'did:plc:pgbatch%') AND user_did = 'did:plc:pgtest_user000000000000'",
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| anyhow::anyhow!("cleanup: account_score_history delete failed: {e}"))?;
 
     // Delete test-specific amplification events
     sqlx_core::query::query(
@@ -73,7 +80,9 @@ async fn test_pg_scan_state_roundtrip() {
         return;
     };
     cleanup_test_data(&url).await.unwrap();
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     db.set_scan_state(TEST_USER, "test_cursor", "abc123")
         .await
@@ -100,7 +109,9 @@ async fn test_pg_fingerprint_roundtrip() {
         return;
     };
     cleanup_test_data(&url).await.unwrap();
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     db.save_fingerprint(TEST_USER, r#"{"topics": ["test"]}"#, 42)
         .await
@@ -116,7 +127,9 @@ async fn test_pg_embedding_roundtrip() {
         return;
     };
     cleanup_test_data(&url).await.unwrap();
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     // Ensure fingerprint row exists
     db.save_fingerprint(TEST_USER, r#"{"clusters":[]}"#, 10)
@@ -139,7 +152,9 @@ async fn test_pg_account_score_upsert_and_rank() {
         return;
     };
     cleanup_test_data(&url).await.unwrap();
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     let score = AccountScore {
         did: "did:plc:pgtest1".to_string(),
@@ -149,27 +164,165 @@ async fn test_pg_account_score_upsert_and_rank() {
         threat_score: Some(52.5),
         threat_tier: Some("High".to_string()),
         posts_analyzed: 15,
-        top_toxic_posts: vec![],
-        scored_at: String::new(),
-        behavioral_signals: None,
-        context_score: None,
-        graph_distance: None,
-        fingerprint_quality: None,
-        scoring_confidence: None,
+        ..Default::default()
     };
-    db.upsert_account_score(TEST_USER, &score).await.unwrap();
+    db.upsert_account_score(TEST_USER, &score, None)
+        .await
+        .unwrap();
 
-    let ranked = db.get_ranked_threats(TEST_USER, 50.0).await.unwrap();
+    let ranked = db
+        .get_ranked_threats(TEST_USER, 50.0, &TierThresholds::DEFAULT)
+        .await
+        .unwrap();
     assert!(ranked.iter().any(|s| s.did == "did:plc:pgtest1"));
 }
 
+#[tokio::test]
+async fn test_pg_batched_score_upsert() {
+    let Some(url) = database_url() else {
+        return;
+    };
+    cleanup_test_data(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
+
+    let score = |did: &str, threat: f64, last_active_at: Option<&str>| AccountScore {
+        did: did.to_string(),
+        handle: "pgbatch.bsky.social".to_string(),
+        toxicity_score: Some(0.5),
+        topic_overlap: Some(0.4),
+        threat_score: Some(threat),
+        threat_tier: Some("Watch".to_string()),
+        posts_analyzed: 10,
+        last_active_at: last_active_at.map(str::to_string),
+        ..Default::default()
+    };
+
+    // The same DID twice in one batch: the later score wins, and its missing
+    // last-active time keeps the earlier one — as with per-row upserts
+    db.upsert_account_scores(
+        TEST_USER,
+        &[
+            score("did:plc:pgbatch1", 30.0, Some("2026-01-01T00:00:00Z")),
+            score("did:plc:pgbatch2", 18.0, None),
+            score("did:plc:pgbatch1", 21.0, None),
+        ],
+        None,
+    )
+    .await
+    .unwrap();
+
+    let one = db
+        .get_account_by_did(TEST_USER, "did:plc:pgbatch1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(one.threat_score, Some(21.0));
+    assert!(one.last_active_at.is_some());
+
+    // History keeps both scores for the repeated DID, in order
+    let history = db
+        .get_account_history(TEST_USER, "did:plc:pgbatch1", 10)
+        .await
+        .unwrap();
+    let threats: Vec<Option<f64>> = history.iter().map(|s| s.threat_score).collect();
+    assert_eq!(threats, vec![Some(30.0), Some(21.0)]);
+    assert!(db
+        .get_account_by_did(TEST_USER, "did:plc:pgbatch2")
+        .await
+        .unwrap()
+        .is_some());
+
+    // A batch far past the 65535 bind-parameter limit of a row-per-VALUES
+    // insert still goes in whole, and unparseable signals are stored as NULL
+    let bulk: Vec<AccountScore> = (0..4000)
+        .map(|i| AccountScore {
+            behavioral_signals: Some("{not json".to_string()),
+            ..score(&format!("did:plc:pgbatchbulk{i}"), 12.0, None)
+        })
+        .collect();
+    db.upsert_account_scores(TEST_USER, &bulk, None)
+        .await
+        .unwrap();
+    let last = db
+        .get_account_by_did(TEST_USER, "did:plc:pgbatchbulk3999")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(last.threat_score, Some(12.0));
+    assert!(last.behavioral_signals.is_none());
+}
+
+fn smoothing(alpha: f64) -> Option<ScoreSmoothing> {
+    Some(ScoreSmoothing {
+        alpha,
+        thresholds: TierThresholds::DEFAULT,
+    })
+}
+
+#[tokio::test]
+async fn test_pg_score_ema_smoothing() {
+    let Some(url) = database_url() else {
+        return;
+    };
+    cleanup_test_data(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
+
+    let score = |threat: f64| AccountScore {
+        did: "did:plc:pgema".to_string(),
+        handle: "pgema.bsky.social".to_string(),
+        toxicity_score: Some(0.5),
+        topic_overlap: Some(0.4),
+        threat_score: Some(threat),
+        posts_analyzed: 10,
+        ..Default::default()
+    };
+    let stored = || async {
+        let found = db
+            .get_account_by_did(TEST_USER, "did:plc:pgema")
+            .await
+            .unwrap()
+            .unwrap();
+        (found.threat_score, found.raw_threat_score)
+    };
+
+    db.upsert_account_score(TEST_USER, &score(10.0), smoothing(0.5))
+        .await
+        .unwrap();
+    db.upsert_account_score(TEST_USER, &score(40.0), smoothing(0.5))
+        .await
+        .unwrap();
+    assert_eq!(stored().await, (Some(25.0), Some(40.0)));
+
+    // The batched path blends the same way; alpha 1 is a plain overwrite
+    db.upsert_account_scores(TEST_USER, &[score(5.0)], smoothing(0.5))
+        .await
+        .unwrap();
+    assert_eq!(stored().await, (Some(15.0), Some(5.0)));
+    db.upsert_account_scores(TEST_USER, &[score(8.0)], smoothing(1.0))
+        .await
+        .unwrap();
+    assert_eq!(stored().await, (Some(8.0), Some(8.0)));
+
+    // A DID repeated in one batch is blended once, with its later score
+    db.upsert_account_scores(TEST_USER, &[score(40.0), score(20.0)], smoothing(0.5))
+        .await
+        .unwrap();
+    assert_eq!(stored().await, (Some(14.0), Some(20.0)));
+}
+
 #[tokio::test]
 async fn test_pg_amplification_event() {
     let Some(url) = database_url() else {
         return;
     };
     cleanup_test_data(&url).await.unwrap();
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     let id = db
         .insert_amplification_event(
@@ -196,7 +349,9 @@ async fn test_pg_table_count() {
     let Some(url) = database_url() else {
         return;
     };
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     let count = db.table_count().await.unwrap();
     assert!(count >= 6, "Expected at least 6 tables, got {count}");
@@ -207,7 +362,9 @@ async fn test_pg_is_score_stale_missing() {
     let Some(url) = database_url() else {
         return;
     };
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     assert!(db
         .is_score_stale(TEST_USER, "did:plc:nonexistent_pg", 7)
@@ -220,7 +377,9 @@ async fn test_pg_median_engagement_empty() {
     let Some(url) = database_url() else {
         return;
     };
-    let db = charcoal::db::connect_postgres(&url).await.unwrap();
+    let db = charcoal::db::connect_postgres(&url, &PgPoolConfig::default())
+        .await
+        .unwrap();
 
     // Should return 0.0 when no behavioral data exists
     let median = db.get_median_engagement(TEST_USER).await.unwrap();
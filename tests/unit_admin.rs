@@ -212,4 +212,49 @@ mod scan_manager_tests {
         mgr.finish_fingerprint_build("did:plc:abc");
         assert!(!mgr.is_fingerprint_building("did:plc:abc"));
     }
+
+    #[test]
+    fn test_fingerprint_refresh_waits_for_scan() {
+        let mut mgr = ScanManager::new();
+        mgr.try_start_scan("did:plc:abc").unwrap();
+        assert!(!mgr.try_start_fingerprint_refresh("did:plc:def"));
+
+        mgr.finish_scan("did:plc:abc");
+        assert!(mgr.try_start_fingerprint_refresh("did:plc:def"));
+        // Only one refresh per user at a time
+        assert!(!mgr.try_start_fingerprint_refresh("did:plc:def"));
+    }
+
+    #[test]
+    fn test_fingerprint_rebuild_rejects_second() {
+        let mut mgr = ScanManager::new();
+        mgr.try_start_fingerprint_rebuild("did:plc:abc").unwrap();
+        assert!(mgr.get_fingerprint_status("did:plc:abc").unwrap().running);
+        assert!(mgr.try_start_fingerprint_rebuild("did:plc:abc").is_err());
+        // Scans wait for it like any other fingerprint build
+        assert!(mgr.try_start_scan("did:plc:abc").is_err());
+
+        mgr.finish_fingerprint_rebuild("did:plc:abc", Some("No posts found".to_string()));
+        let status = mgr.get_fingerprint_status("did:plc:abc").unwrap();
+        assert!(!status.running);
+        assert_eq!(status.last_error.as_deref(), Some("No posts found"));
+        assert!(mgr.try_start_fingerprint_rebuild("did:plc:abc").is_ok());
+    }
+
+    #[test]
+    fn test_fingerprint_rebuild_waits_for_scan() {
+        let mut mgr = ScanManager::new();
+        mgr.try_start_scan("did:plc:abc").unwrap();
+        assert!(mgr.try_start_fingerprint_rebuild("did:plc:abc").is_err());
+        assert!(mgr.get_fingerprint_status("did:plc:abc").is_none());
+    }
+
+    #[test]
+    fn test_scan_waits_for_fingerprint_refresh() {
+        let mut mgr = ScanManager::new();
+        assert!(mgr.try_start_fingerprint_refresh("did:plc:abc"));
+        assert!(mgr.try_start_scan("did:plc:abc").is_err());
+        // Other users aren't blocked
+        assert!(mgr.try_start_scan("did:plc:def").is_ok());
+    }
 }
@@ -0,0 +1,72 @@
+// tests/unit_amplification.rs
+//
+// Tests for the amplification pipeline's protected-user exclusion,
+// mass-amplifier follower skip and follower offset bookkeeping.
+
+use charcoal::pipeline::amplification::{is_mass_amplifier, is_protected_account, PendingSlices};
+
+const PROTECTED_DID: &str = "did:plc:protected123";
+
+#[test]
+fn protected_user_matched_by_did_after_rename() {
+    // The follower list reports the new handle; the DID is unchanged
+    assert!(is_protected_account(
+        PROTECTED_DID,
+        "new-name.bsky.social",
+        PROTECTED_DID,
+        "old-name.bsky.social",
+    ));
+}
+
+#[test]
+fn protected_user_matched_with_mismatched_case_handle() {
+    assert!(is_protected_account(
+        "alice.bsky.social",
+        "Alice.Bsky.Social",
+        PROTECTED_DID,
+        "alice.bsky.social",
+    ));
+}
+
+#[test]
+fn other_accounts_not_excluded() {
+    assert!(!is_protected_account(
+        "did:plc:someoneelse",
+        "alice2.bsky.social",
+        PROTECTED_DID,
+        "alice.bsky.social",
+    ));
+}
+
+#[test]
+fn mass_amplifiers_are_skipped_above_the_threshold() {
+    assert!(is_mass_amplifier(Some(200_000), Some(10_000)));
+    // The threshold itself is still analyzed
+    assert!(!is_mass_amplifier(Some(10_000), Some(10_000)));
+    assert!(!is_mass_amplifier(Some(500), Some(10_000)));
+}
+
+#[test]
+fn mass_amplifier_skip_needs_a_threshold_and_a_count() {
+    assert!(!is_mass_amplifier(Some(200_000), None));
+    assert!(!is_mass_amplifier(None, Some(10_000)));
+}
+
+#[test]
+fn follower_offset_moves_after_the_amplifiers_last_event() {
+    let mut pending = PendingSlices::new(["did:plc:a", "did:plc:b", "did:plc:a"]);
+
+    assert!(!pending.settle("did:plc:a"));
+    assert!(pending.settle("did:plc:b"));
+    assert!(pending.settle("did:plc:a"));
+}
+
+#[test]
+fn follower_offset_moves_once_per_scan() {
+    let mut pending = PendingSlices::new(["did:plc:a"]);
+
+    assert!(pending.settle("did:plc:a"));
+    assert!(!pending.settle("did:plc:a"));
+    // Amplifiers with no pending events never move
+    assert!(!pending.settle("did:plc:unknown"));
+}
@@ -1,8 +1,12 @@
+use std::collections::HashSet;
+
 use charcoal::db::models::ThreatTier;
 use charcoal::scoring::behavioral::{
-    apply_behavioral_modifier, apply_behavioral_modifier_contextual, compute_behavioral_boost,
-    compute_quote_ratio, compute_reply_ratio, detect_pile_on_participants, is_behaviorally_benign,
-    BehavioralSignals,
+    apply_behavioral_modifier, apply_behavioral_modifier_contextual, cascade_root_participants,
+    compute_behavioral_boost, compute_quote_ratio, compute_reply_ratio, detect_new_followers,
+    detect_pile_on_participants, detect_quote_cascades, hash_follower_did, is_behaviorally_benign,
+    mark_recently_followed, merge_follower_snapshot, BehavioralSignals, MAX_BEHAVIORAL_BOOST,
+    MAX_SNAPSHOT_FOLLOWERS,
 };
 use charcoal::scoring::threat::{compute_threat_score, ThreatWeights};
 
@@ -26,52 +30,143 @@ fn behavioral_signals_json_roundtrip() {
         pile_on: true,
         benign_gate: false,
         behavioral_boost: 1.22,
+        posts_skipped_language: 4,
+        recently_followed_amplifier: false,
+        moderation_labels: vec!["spam".to_string()],
+        post_window_days: None,
+        posts_outside_window: 0,
+        recency_weight: None,
+        follows_you: Some(false),
+        followed_by_you: Some(false),
+        stranger_quote: true,
     };
     let json = serde_json::to_string(&signals).unwrap();
     let deserialized: BehavioralSignals = serde_json::from_str(&json).unwrap();
     assert!((deserialized.quote_ratio - 0.35).abs() < f64::EPSILON);
     assert!(deserialized.pile_on);
     assert!((deserialized.behavioral_boost - 1.22).abs() < f64::EPSILON);
+    assert_eq!(deserialized.posts_skipped_language, 4);
+    assert_eq!(deserialized.moderation_labels, vec!["spam".to_string()]);
+    assert_eq!(deserialized.follows_you, Some(false));
+    assert_eq!(deserialized.followed_by_you, Some(false));
+    assert!(deserialized.stranger_quote);
+}
+
+#[test]
+fn behavioral_signals_without_language_field_deserialize() {
+    // Rows scored before language filtering have no posts_skipped_language
+    let json = r#"{"quote_ratio":0.1,"reply_ratio":0.2,"avg_engagement":3.0,"pile_on":false,"benign_gate":false,"behavioral_boost":1.0}"#;
+    let signals: BehavioralSignals = serde_json::from_str(json).unwrap();
+    assert_eq!(signals.posts_skipped_language, 0);
+    // ...and none of the follow relationship either
+    assert_eq!(signals.follows_you, None);
+    assert_eq!(signals.followed_by_you, None);
+    assert!(!signals.stranger_quote);
 }
 
 // --- Behavioral boost tests ---
 
 #[test]
 fn boost_all_zeros_is_one() {
-    let boost = compute_behavioral_boost(0.0, 0.0, false);
+    let boost = compute_behavioral_boost(0.0, 0.0, false, false);
     assert!((boost - 1.0).abs() < f64::EPSILON);
 }
 
 #[test]
 fn boost_max_is_1_5() {
-    let boost = compute_behavioral_boost(1.0, 1.0, true);
+    let boost = compute_behavioral_boost(1.0, 1.0, true, false);
     assert!((boost - 1.5).abs() < 1e-10);
 }
 
 #[test]
 fn boost_quote_only() {
-    let boost = compute_behavioral_boost(0.5, 0.0, false);
+    let boost = compute_behavioral_boost(0.5, 0.0, false, false);
     assert!((boost - 1.1).abs() < f64::EPSILON);
 }
 
 #[test]
 fn boost_reply_only() {
-    let boost = compute_behavioral_boost(0.0, 0.8, false);
+    let boost = compute_behavioral_boost(0.0, 0.8, false, false);
     assert!((boost - 1.12).abs() < f64::EPSILON);
 }
 
 #[test]
 fn boost_pile_on_only() {
-    let boost = compute_behavioral_boost(0.0, 0.0, true);
+    let boost = compute_behavioral_boost(0.0, 0.0, true, false);
     assert!((boost - 1.15).abs() < f64::EPSILON);
 }
 
 #[test]
 fn boost_typical_hostile() {
-    let boost = compute_behavioral_boost(0.4, 0.3, false);
+    let boost = compute_behavioral_boost(0.4, 0.3, false, false);
     assert!((boost - 1.125).abs() < 0.001);
 }
 
+#[test]
+fn boost_stranger_quote_only() {
+    let boost = compute_behavioral_boost(0.0, 0.0, false, true);
+    assert!((boost - 1.10).abs() < f64::EPSILON);
+}
+
+#[test]
+fn boost_stranger_quote_stays_within_max() {
+    // Every pattern at once would be 1.6 — capped
+    let boost = compute_behavioral_boost(1.0, 1.0, true, true);
+    assert!((boost - MAX_BEHAVIORAL_BOOST).abs() < 1e-10);
+}
+
+#[test]
+fn stranger_quote_outscores_mutual_with_same_posts() {
+    // Same posting pattern; only the relationship differs
+    let (stranger, _) = apply_behavioral_modifier(30.0, 0.40, 0.30, false, true, 2.0, 10.0);
+    let (mutual, _) = apply_behavioral_modifier(30.0, 0.40, 0.30, false, false, 2.0, 10.0);
+    assert!((stranger - mutual * 1.225 / 1.125).abs() < 0.01);
+    assert!(stranger > mutual);
+}
+
+#[test]
+fn stranger_status_is_weighted_once() {
+    use charcoal::bluesky::relationships::GraphDistance;
+    use charcoal::scoring::profile::is_stranger_quote;
+
+    let stranger = Some(GraphDistance::Stranger);
+
+    // Distance passed in: the 1.2x distance weight alone, no +0.10 boost
+    let boost =
+        compute_behavioral_boost(0.0, 0.0, false, is_stranger_quote(true, stranger, stranger));
+    let combined = boost * GraphDistance::Stranger.threat_weight();
+    assert!((combined - 1.2).abs() < 1e-10, "{combined}");
+
+    // Looked up during scoring: no distance weight, so the boost stands in
+    let boost = compute_behavioral_boost(0.0, 0.0, false, is_stranger_quote(true, stranger, None));
+    assert!((boost - 1.10).abs() < 1e-10, "{boost}");
+
+    // No quote or reply, no boost
+    assert!(!is_stranger_quote(false, stranger, None));
+}
+
+#[test]
+fn stranger_quote_does_not_lift_benign_gate() {
+    // The benign gate still caps an otherwise benign stranger
+    let (score, benign) = apply_behavioral_modifier(50.0, 0.05, 0.10, false, true, 15.0, 10.0);
+    assert!(benign);
+    assert!((score - 12.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn graph_distance_follow_directions() {
+    use charcoal::bluesky::relationships::GraphDistance;
+
+    assert!(GraphDistance::MutualFollow.follows_you());
+    assert!(GraphDistance::MutualFollow.followed_by_you());
+    assert!(GraphDistance::InboundFollow.follows_you());
+    assert!(!GraphDistance::InboundFollow.followed_by_you());
+    assert!(!GraphDistance::OutboundFollow.follows_you());
+    assert!(GraphDistance::OutboundFollow.followed_by_you());
+    assert!(!GraphDistance::Stranger.follows_you());
+    assert!(!GraphDistance::Stranger.followed_by_you());
+}
+
 // --- Benign gate tests ---
 
 #[test]
@@ -109,21 +204,21 @@ fn benign_gate_exact_thresholds() {
 
 #[test]
 fn modifier_benign_caps_at_12() {
-    let (score, benign) = apply_behavioral_modifier(50.0, 0.05, 0.10, false, 15.0, 10.0);
+    let (score, benign) = apply_behavioral_modifier(50.0, 0.05, 0.10, false, false, 15.0, 10.0);
     assert!(benign);
     assert!((score - 12.0).abs() < f64::EPSILON);
 }
 
 #[test]
 fn modifier_benign_passes_through_low_score() {
-    let (score, benign) = apply_behavioral_modifier(5.0, 0.05, 0.10, false, 15.0, 10.0);
+    let (score, benign) = apply_behavioral_modifier(5.0, 0.05, 0.10, false, false, 15.0, 10.0);
     assert!(benign);
     assert!((score - 5.0).abs() < f64::EPSILON);
 }
 
 #[test]
 fn modifier_hostile_applies_boost() {
-    let (score, benign) = apply_behavioral_modifier(50.0, 0.80, 0.10, false, 15.0, 10.0);
+    let (score, benign) = apply_behavioral_modifier(50.0, 0.80, 0.10, false, false, 15.0, 10.0);
     assert!(!benign);
     // boost = 1.0 + 0.80*0.20 + 0.10*0.15 = 1.175; 50.0 * 1.175 = 58.75
     assert!((score - 58.75).abs() < 0.1);
@@ -131,14 +226,14 @@ fn modifier_hostile_applies_boost() {
 
 #[test]
 fn modifier_no_behavioral_data_is_neutral() {
-    let (score, benign) = apply_behavioral_modifier(50.0, 0.0, 0.0, false, 0.0, 10.0);
+    let (score, benign) = apply_behavioral_modifier(50.0, 0.0, 0.0, false, false, 0.0, 10.0);
     assert!(!benign);
     assert!((score - 50.0).abs() < f64::EPSILON);
 }
 
 #[test]
 fn modifier_clamped_to_100() {
-    let (score, _) = apply_behavioral_modifier(90.0, 1.0, 1.0, true, 0.0, 10.0);
+    let (score, _) = apply_behavioral_modifier(90.0, 1.0, 1.0, true, false, 0.0, 10.0);
     assert!((score - 100.0).abs() < f64::EPSILON);
 }
 
@@ -290,7 +385,8 @@ fn persona_the_quote_dunker() {
 
     // With behavioral boost: quote_ratio=0.80, reply_ratio=0.30, no pile-on
     // boost = 1.0 + 0.80*0.20 + 0.30*0.15 = 1.0 + 0.16 + 0.045 = 1.205
-    let (final_score, benign) = apply_behavioral_modifier(raw_score, 0.80, 0.30, false, 20.0, 10.0);
+    let (final_score, benign) =
+        apply_behavioral_modifier(raw_score, 0.80, 0.30, false, false, 20.0, 10.0);
     assert!(!benign);
     // 16.8 * 1.205 = 20.244
     assert!(final_score > raw_score, "Boost should increase score");
@@ -310,7 +406,8 @@ fn persona_the_supportive_ally() {
     assert!((raw_score - 14.35).abs() < 0.1);
 
     // Benign: quote=0.05 (<0.15), reply=0.10 (<0.30), no pile-on, engagement 25 > median 10
-    let (final_score, benign) = apply_behavioral_modifier(raw_score, 0.05, 0.10, false, 25.0, 10.0);
+    let (final_score, benign) =
+        apply_behavioral_modifier(raw_score, 0.05, 0.10, false, false, 25.0, 10.0);
     assert!(benign, "Ally should trigger benign gate");
     assert!(
         (final_score - 12.0).abs() < f64::EPSILON,
@@ -333,7 +430,8 @@ fn persona_the_pile_on_participant() {
 
     // With pile-on: quote=0.30, reply=0.20, pile_on=true
     // boost = 1.0 + 0.30*0.20 + 0.20*0.15 + 0.15 = 1.0 + 0.06 + 0.03 + 0.15 = 1.24
-    let (final_score, benign) = apply_behavioral_modifier(raw_score, 0.30, 0.20, true, 8.0, 10.0);
+    let (final_score, benign) =
+        apply_behavioral_modifier(raw_score, 0.30, 0.20, true, false, 8.0, 10.0);
     assert!(!benign);
     // 21.35 * 1.24 = 26.474
     assert!((final_score - 26.474).abs() < 0.1);
@@ -355,7 +453,8 @@ fn persona_the_lurker_reposter() {
     // Low engagement (2.0 < median 10.0) blocks benign gate
     // quote=0.05, reply=0.15, no pile-on
     // boost = 1.0 + 0.05*0.20 + 0.15*0.15 = 1.0 + 0.01 + 0.0225 = 1.0325
-    let (final_score, benign) = apply_behavioral_modifier(raw_score, 0.05, 0.15, false, 2.0, 10.0);
+    let (final_score, benign) =
+        apply_behavioral_modifier(raw_score, 0.05, 0.15, false, false, 2.0, 10.0);
     assert!(!benign, "Low engagement should block benign gate");
     // 25.375 * 1.0325 ≈ 26.2
     assert!((final_score - 26.2).abs() < 0.5);
@@ -373,7 +472,8 @@ fn persona_high_tox_benign_behavior() {
     assert_eq!(raw_tier, ThreatTier::High);
 
     // But benign behavior caps at 12.0
-    let (final_score, benign) = apply_behavioral_modifier(raw_score, 0.05, 0.10, false, 30.0, 10.0);
+    let (final_score, benign) =
+        apply_behavioral_modifier(raw_score, 0.05, 0.10, false, false, 30.0, 10.0);
     assert!(benign);
     assert!((final_score - 12.0).abs() < f64::EPSILON);
     let tier = ThreatTier::from_score(final_score);
@@ -390,6 +490,7 @@ fn benign_gate_bypassed_when_context_score_high() {
         0.05,      // quote_ratio (low — benign)
         0.10,      // reply_ratio (low — benign)
         false,     // pile_on (no)
+        false,     // stranger_quote (no)
         5.0,       // avg_engagement (above median)
         3.0,       // median_engagement
         Some(0.7), // context_score — HIGH, should bypass gate
@@ -415,6 +516,7 @@ fn benign_gate_still_applies_when_context_score_low() {
         0.05,
         0.10,
         false,
+        false,
         5.0,
         3.0,
         Some(0.3), // context_score below 0.5 threshold
@@ -433,7 +535,7 @@ fn benign_gate_still_applies_when_context_score_low() {
 #[test]
 fn benign_gate_applies_when_no_context_score() {
     let (score, benign_gate, gate_bypassed) =
-        apply_behavioral_modifier_contextual(30.0, 0.05, 0.10, false, 5.0, 3.0, None);
+        apply_behavioral_modifier_contextual(30.0, 0.05, 0.10, false, false, 5.0, 3.0, None);
     assert_eq!(score, 12.0);
     assert!(benign_gate);
     assert!(!gate_bypassed);
@@ -442,9 +544,10 @@ fn benign_gate_applies_when_no_context_score() {
 #[test]
 fn contextual_modifier_matches_original_when_no_context() {
     // Without context score, contextual version should produce same result
-    let (score_orig, gate_orig) = apply_behavioral_modifier(30.0, 0.05, 0.10, false, 5.0, 3.0);
+    let (score_orig, gate_orig) =
+        apply_behavioral_modifier(30.0, 0.05, 0.10, false, false, 5.0, 3.0);
     let (score_ctx, gate_ctx, gate_bypassed) =
-        apply_behavioral_modifier_contextual(30.0, 0.05, 0.10, false, 5.0, 3.0, None);
+        apply_behavioral_modifier_contextual(30.0, 0.05, 0.10, false, false, 5.0, 3.0, None);
     assert!((score_orig - score_ctx).abs() < f64::EPSILON);
     assert_eq!(gate_orig, gate_ctx);
     assert!(!gate_bypassed);
@@ -458,6 +561,7 @@ fn contextual_modifier_hostile_account_still_boosted() {
         0.25,      // quote_ratio (high — not benign)
         0.35,      // reply_ratio (high — not benign)
         false,     // pile_on
+        false,     // stranger_quote (no)
         2.0,       // avg_engagement (below median)
         5.0,       // median_engagement
         Some(0.8), // context_score — high
@@ -470,3 +574,228 @@ fn contextual_modifier_hostile_account_still_boosted() {
         score
     );
 }
+
+// --- Quote cascade tests ---
+
+#[test]
+fn cascade_empty_edges() {
+    assert!(detect_quote_cascades(&[]).is_empty());
+}
+
+#[test]
+fn cascade_flat_quotes_are_not_a_cascade() {
+    // Six direct quotes, none of them quoted in turn — busy, but not a ratio
+    let edges: Vec<(&str, &str, &str)> = vec![
+        ("at://root", "at://q/1", "did:plc:a"),
+        ("at://root", "at://q/2", "did:plc:b"),
+        ("at://root", "at://q/3", "did:plc:c"),
+        ("at://root", "at://q/4", "did:plc:d"),
+        ("at://root", "at://q/5", "did:plc:e"),
+        ("at://root", "at://q/6", "did:plc:f"),
+    ];
+    let cascades = detect_quote_cascades(&edges);
+    assert_eq!(cascades.len(), 1);
+    assert_eq!(cascades[0].root_uri, "at://root");
+    assert_eq!(cascades[0].depth, 1);
+    assert_eq!(cascades[0].total_quotes, 6);
+    assert_eq!(cascades[0].max_branching, 6);
+    assert!(!cascades[0].is_cascade());
+}
+
+#[test]
+fn cascade_quote_of_quote_tree_detected() {
+    // a and b quote the root; their audiences quote them
+    let edges: Vec<(&str, &str, &str)> = vec![
+        ("at://root", "at://q/a", "did:plc:a"),
+        ("at://root", "at://q/b", "did:plc:b"),
+        ("at://q/a", "at://q/a1", "did:plc:c"),
+        ("at://q/a", "at://q/a2", "did:plc:d"),
+        ("at://q/a", "at://q/a3", "did:plc:e"),
+        ("at://q/a1", "at://q/a1x", "did:plc:f"),
+    ];
+    let cascades = detect_quote_cascades(&edges);
+    assert_eq!(cascades.len(), 1);
+    let c = &cascades[0];
+    assert_eq!(c.depth, 3);
+    assert_eq!(c.total_quotes, 6);
+    assert_eq!(c.max_branching, 3);
+    assert!(c.is_cascade());
+    assert_eq!(c.participant_depths["did:plc:a"], 1);
+    assert_eq!(c.participant_depths["did:plc:c"], 2);
+    assert_eq!(c.participant_depths["did:plc:f"], 3);
+}
+
+#[test]
+fn cascade_participant_keeps_shallowest_depth() {
+    // did:plc:a quotes the root and also quotes deeper in the tree
+    let edges: Vec<(&str, &str, &str)> = vec![
+        ("at://root", "at://q/a", "did:plc:a"),
+        ("at://q/a", "at://q/b", "did:plc:b"),
+        ("at://q/b", "at://q/a-again", "did:plc:a"),
+    ];
+    let cascades = detect_quote_cascades(&edges);
+    assert_eq!(cascades[0].participant_depths["did:plc:a"], 1);
+}
+
+#[test]
+fn cascade_separate_roots_measured_independently() {
+    let edges: Vec<(&str, &str, &str)> = vec![
+        ("at://root/1", "at://q/1", "did:plc:a"),
+        ("at://root/2", "at://q/2", "did:plc:b"),
+        ("at://q/2", "at://q/3", "did:plc:c"),
+    ];
+    let cascades = detect_quote_cascades(&edges);
+    assert_eq!(cascades.len(), 2);
+    assert_eq!(cascades[0].root_uri, "at://root/1");
+    assert_eq!(cascades[0].depth, 1);
+    assert_eq!(cascades[1].root_uri, "at://root/2");
+    assert_eq!(cascades[1].depth, 2);
+}
+
+#[test]
+fn cascade_root_participants_only_near_root_of_real_cascades() {
+    let edges: Vec<(&str, &str, &str)> = vec![
+        // A real cascade on root/1
+        ("at://root/1", "at://q/a", "did:plc:a"),
+        ("at://root/1", "at://q/b", "did:plc:b"),
+        ("at://q/a", "at://q/c", "did:plc:c"),
+        ("at://q/a", "at://q/d", "did:plc:d"),
+        ("at://q/b", "at://q/e", "did:plc:e"),
+        // A lone quote on root/2 — not a cascade
+        ("at://root/2", "at://q/z", "did:plc:z"),
+    ];
+    let cascades = detect_quote_cascades(&edges);
+    let drivers = cascade_root_participants(&cascades);
+
+    assert!(drivers.contains("did:plc:a"));
+    assert!(drivers.contains("did:plc:b"));
+    // Deeper participants are the audience, not the drivers
+    assert!(!drivers.contains("did:plc:c"));
+    // Quoters of a post that isn't cascading aren't boosted
+    assert!(!drivers.contains("did:plc:z"));
+}
+
+#[test]
+fn cascade_cycle_does_not_loop_forever() {
+    // Malformed input where a quote chain loops back. There's no root
+    // (every post quotes something), so nothing is reported.
+    let edges: Vec<(&str, &str, &str)> = vec![
+        ("at://q/a", "at://q/b", "did:plc:a"),
+        ("at://q/b", "at://q/a", "did:plc:b"),
+    ];
+    assert!(detect_quote_cascades(&edges).is_empty());
+}
+
+// --- Follower snapshot tests ---
+
+#[test]
+fn follower_hash_is_stable() {
+    // Stored snapshots depend on this never changing
+    assert_eq!(hash_follower_did(""), "cbf29ce484222325");
+    assert_eq!(
+        hash_follower_did("did:plc:a"),
+        hash_follower_did("did:plc:a")
+    );
+    assert_ne!(
+        hash_follower_did("did:plc:a"),
+        hash_follower_did("did:plc:b")
+    );
+    assert_eq!(hash_follower_did("did:plc:a").len(), 16);
+}
+
+fn snapshot_of(dids: &[&str]) -> HashSet<String> {
+    dids.iter().map(|d| hash_follower_did(d)).collect()
+}
+
+#[test]
+fn new_followers_are_those_above_first_known() {
+    let previous = snapshot_of(&["did:plc:old1", "did:plc:old2"]);
+    // Newest first: two new follows, then the previously-seen top follower
+    let current = [
+        "did:plc:new1",
+        "did:plc:new2",
+        "did:plc:old1",
+        "did:plc:unseen_old",
+    ];
+    let new = detect_new_followers(&current, &previous);
+    assert_eq!(new.len(), 2);
+    assert!(new.contains("did:plc:new1"));
+    assert!(new.contains("did:plc:new2"));
+    // Below the anchor, unknown just means the last slice never reached it
+    assert!(!new.contains("did:plc:unseen_old"));
+}
+
+#[test]
+fn no_new_followers_without_a_previous_snapshot() {
+    let current = ["did:plc:a", "did:plc:b"];
+    assert!(detect_new_followers(&current, &HashSet::new()).is_empty());
+}
+
+#[test]
+fn no_new_followers_when_slice_has_no_overlap() {
+    // A rotated slice deeper in the list shares nobody with the snapshot
+    let previous = snapshot_of(&["did:plc:top1", "did:plc:top2"]);
+    let current = ["did:plc:deep1", "did:plc:deep2"];
+    assert!(detect_new_followers(&current, &previous).is_empty());
+}
+
+#[test]
+fn merged_snapshot_dedupes_and_is_capped() {
+    let previous: Vec<String> = vec![
+        hash_follower_did("did:plc:a"),
+        hash_follower_did("did:plc:b"),
+    ];
+    let merged = merge_follower_snapshot(&["did:plc:c", "did:plc:a"], &previous);
+    assert_eq!(
+        merged,
+        vec![
+            hash_follower_did("did:plc:c"),
+            hash_follower_did("did:plc:a"),
+            hash_follower_did("did:plc:b"),
+        ]
+    );
+
+    let many: Vec<String> = (0..MAX_SNAPSHOT_FOLLOWERS + 100)
+        .map(|i| format!("did:plc:f{i}"))
+        .collect();
+    let refs: Vec<&str> = many.iter().map(String::as_str).collect();
+    assert_eq!(
+        merge_follower_snapshot(&refs, &[]).len(),
+        MAX_SNAPSHOT_FOLLOWERS
+    );
+}
+
+#[test]
+fn mark_recently_followed_sets_flag_and_keeps_signals() {
+    use charcoal::db::models::AccountScore;
+
+    let signals = BehavioralSignals {
+        quote_ratio: 0.4,
+        ..BehavioralSignals::default()
+    };
+    let mut score = AccountScore {
+        did: "did:plc:new".to_string(),
+        handle: "new.bsky.social".to_string(),
+        toxicity_score: Some(0.2),
+        topic_overlap: Some(0.3),
+        threat_score: Some(10.0),
+        threat_tier: Some("Watch".to_string()),
+        posts_analyzed: 20,
+        behavioral_signals: Some(serde_json::to_string(&signals).unwrap()),
+        ..Default::default()
+    };
+
+    mark_recently_followed(&mut score);
+    let marked: BehavioralSignals =
+        serde_json::from_str(score.behavioral_signals.as_deref().unwrap()).unwrap();
+    assert!(marked.recently_followed_amplifier);
+    assert!((marked.quote_ratio - 0.4).abs() < f64::EPSILON);
+
+    // Accounts scored without signals still get the flag
+    score.behavioral_signals = None;
+    mark_recently_followed(&mut score);
+    assert!(score
+        .behavioral_signals
+        .unwrap()
+        .contains("\"recently_followed_amplifier\":true"));
+}
@@ -0,0 +1,63 @@
+// Unit tests for the inference benchmark helpers.
+//
+// The real scorer and embedder need model files, so the timing loop is
+// exercised with FixedScorer; stats math is tested on fixed durations.
+
+use std::time::Duration;
+
+use charcoal::bench::{bench_scorer, percentile, synthetic_texts, BenchStats};
+use charcoal::toxicity::traits::FixedScorer;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn synthetic_texts_are_deterministic_and_varied() {
+    let a = synthetic_texts(12);
+    let b = synthetic_texts(12);
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 12);
+    assert!(a.iter().all(|t| !t.is_empty()));
+
+    let lengths: std::collections::HashSet<usize> = a.iter().map(|t| t.len()).collect();
+    assert!(lengths.len() > 1, "texts should vary in length");
+}
+
+#[test]
+fn percentile_nearest_rank() {
+    let sorted: Vec<Duration> = (1..=20).map(ms).collect();
+    assert_eq!(percentile(&sorted, 0.50), ms(10));
+    assert_eq!(percentile(&sorted, 0.95), ms(19));
+    assert_eq!(percentile(&sorted, 1.0), ms(20));
+    assert_eq!(percentile(&[], 0.5), Duration::ZERO);
+    assert_eq!(percentile(&[ms(7)], 0.95), ms(7));
+}
+
+#[test]
+fn stats_from_batches() {
+    let stats = BenchStats::from_batches(&[32, 32, 10], &[ms(30), ms(10), ms(20)], ms(500));
+    assert_eq!(stats.texts, 74);
+    assert_eq!(stats.batches, 3);
+    assert_eq!(stats.peak_batch_size, 32);
+    assert_eq!(stats.p50, ms(20));
+    assert_eq!(stats.p95, ms(30));
+    assert!((stats.texts_per_sec() - 148.0).abs() < 1e-9);
+}
+
+#[test]
+fn zero_duration_reports_zero_throughput() {
+    let stats = BenchStats::from_batches(&[], &[], Duration::ZERO);
+    assert_eq!(stats.texts_per_sec(), 0.0);
+    assert_eq!(stats.peak_batch_size, 0);
+}
+
+#[tokio::test]
+async fn bench_scorer_chunks_by_batch_size() {
+    let scorer = FixedScorer { value: 0.2 };
+    let texts = synthetic_texts(70);
+    let stats = bench_scorer(&scorer, &texts, 32).await.unwrap();
+    assert_eq!(stats.texts, 70);
+    assert_eq!(stats.batches, 3);
+    assert_eq!(stats.peak_batch_size, 32);
+}
@@ -0,0 +1,158 @@
+// Unit tests for the public API response cache.
+//
+// ResponseCache expiry and LRU eviction are tested directly; the client
+// tests point PublicAtpClient at a throwaway local server that counts the
+// requests it answers, so a cache hit shows up as a request never made.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use charcoal::bluesky::cache::{ResponseCache, DEFAULT_CACHE_TTL};
+use charcoal::bluesky::client::PublicAtpClient;
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const FOLLOWERS_BODY: &str = r#"{
+    "subject": {"did": "did:plc:amplifier", "handle": "amplifier.test"},
+    "followers": [{"did": "did:plc:follower", "handle": "follower.test"}]
+}"#;
+
+const PROFILES_BODY: &str = r#"{
+    "profiles": [{"did": "did:plc:follower", "handle": "follower.test"}]
+}"#;
+
+/// Serve `body` to every request on a local port. Returns the base URL and
+/// a counter of requests answered.
+async fn counting_server(body: &'static str) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+
+    let counter = Arc::clone(&hits);
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut head = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                head.extend_from_slice(&buf[..n]);
+            }
+            counter.fetch_add(1, Ordering::SeqCst);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    (base_url, hits)
+}
+
+// ============================================================
+// ResponseCache — expiry and eviction
+// ============================================================
+
+#[test]
+fn cache_returns_fresh_entries_for_identical_requests_only() {
+    let cache = ResponseCache::new(DEFAULT_CACHE_TTL, 10);
+    cache.insert("nsid", &[("actor", "a")], Arc::new(json!({"n": 1})));
+
+    assert_eq!(
+        *cache.get("nsid", &[("actor", "a")]).unwrap(),
+        json!({"n": 1})
+    );
+    assert!(cache.get("nsid", &[("actor", "b")]).is_none());
+    assert!(cache.get("other", &[("actor", "a")]).is_none());
+}
+
+#[test]
+fn cache_expires_entries_after_ttl() {
+    let cache = ResponseCache::new(Duration::ZERO, 10);
+    cache.insert("nsid", &[], Arc::new(json!(1)));
+
+    assert!(cache.get("nsid", &[]).is_none());
+    assert!(cache.is_empty(), "expired entry is dropped on lookup");
+}
+
+#[test]
+fn cache_evicts_least_recently_used_at_capacity() {
+    let cache = ResponseCache::new(DEFAULT_CACHE_TTL, 2);
+    cache.insert("nsid", &[("k", "a")], Arc::new(json!("a")));
+    cache.insert("nsid", &[("k", "b")], Arc::new(json!("b")));
+
+    // Reading "a" makes "b" the least recently used
+    assert!(cache.get("nsid", &[("k", "a")]).is_some());
+    cache.insert("nsid", &[("k", "c")], Arc::new(json!("c")));
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("nsid", &[("k", "a")]).is_some());
+    assert!(cache.get("nsid", &[("k", "b")]).is_none());
+    assert!(cache.get("nsid", &[("k", "c")]).is_some());
+}
+
+// ============================================================
+// PublicAtpClient::with_cache — network hits
+// ============================================================
+
+#[tokio::test]
+async fn repeated_follower_fetch_is_served_from_cache() {
+    let (base_url, hits) = counting_server(FOLLOWERS_BODY).await;
+    let client = PublicAtpClient::new(&base_url)
+        .unwrap()
+        .with_cache(DEFAULT_CACHE_TTL);
+
+    for _ in 0..2 {
+        let followers = charcoal::bluesky::followers::fetch_followers(&client, "amplifier.test", 1)
+            .await
+            .unwrap();
+        assert_eq!(followers.len(), 1);
+        assert_eq!(followers[0].handle, "follower.test");
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+    // A different request still goes to the network
+    charcoal::bluesky::followers::fetch_followers(&client, "other.test", 1)
+        .await
+        .unwrap();
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn repeated_profile_lookup_is_served_from_cache() {
+    let (base_url, hits) = counting_server(PROFILES_BODY).await;
+    let client = PublicAtpClient::new(&base_url)
+        .unwrap()
+        .with_cache(DEFAULT_CACHE_TTL);
+    let dids = vec!["did:plc:follower".to_string()];
+
+    for _ in 0..2 {
+        let handles = charcoal::bluesky::profiles::resolve_dids_to_handles(&client, &dids)
+            .await
+            .unwrap();
+        assert_eq!(handles["did:plc:follower"], "follower.test");
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn client_without_cache_always_hits_the_network() {
+    let (base_url, hits) = counting_server(PROFILES_BODY).await;
+    let client = PublicAtpClient::new(&base_url).unwrap();
+    let dids = vec!["did:plc:follower".to_string()];
+
+    for _ in 0..2 {
+        charcoal::bluesky::profiles::resolve_dids_to_handles(&client, &dids)
+            .await
+            .unwrap();
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    assert!(client.cache().is_none());
+}
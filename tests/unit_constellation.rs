@@ -1,7 +1,7 @@
 // Unit tests for the Constellation backlink client.
 //
 // Tests serde deserialization, AT-URI construction, event conversion,
-// and dedup logic — all without network access.
+// dedup logic and the AppView fallback — all without network access.
 
 use charcoal::constellation::client::{BacklinkRecord, BacklinksResponse};
 
@@ -86,6 +86,7 @@ fn dedup_by_amplifier_post_uri() {
             original_post_uri: Some("at://did:plc:me/app.bsky.feed.post/1".to_string()),
             amplifier_post_uri: "at://did:plc:aaa/app.bsky.feed.post/q1".to_string(),
             indexed_at: "2026-02-18T00:00:00Z".to_string(),
+            occurred_at: None,
         },
         AmplificationNotification {
             event_type: "repost".to_string(),
@@ -94,6 +95,7 @@ fn dedup_by_amplifier_post_uri() {
             original_post_uri: Some("at://did:plc:me/app.bsky.feed.post/1".to_string()),
             amplifier_post_uri: "at://did:plc:bbb/app.bsky.feed.repost/r1".to_string(),
             indexed_at: "2026-02-18T00:00:00Z".to_string(),
+            occurred_at: None,
         },
     ];
 
@@ -106,6 +108,7 @@ fn dedup_by_amplifier_post_uri() {
             original_post_uri: Some("at://did:plc:me/app.bsky.feed.post/1".to_string()),
             amplifier_post_uri: "at://did:plc:aaa/app.bsky.feed.post/q1".to_string(), // duplicate
             indexed_at: String::new(),
+            occurred_at: None,
         },
         AmplificationNotification {
             event_type: "quote".to_string(),
@@ -114,6 +117,7 @@ fn dedup_by_amplifier_post_uri() {
             original_post_uri: Some("at://did:plc:me/app.bsky.feed.post/1".to_string()),
             amplifier_post_uri: "at://did:plc:ccc/app.bsky.feed.post/q2".to_string(), // new
             indexed_at: String::new(),
+            occurred_at: None,
         },
     ];
 
@@ -132,3 +136,249 @@ fn dedup_by_amplifier_post_uri() {
     assert_eq!(merged.len(), 3); // 2 original + 1 new (duplicate dropped)
     assert_eq!(merged[2].amplifier_did, "did:plc:ccc");
 }
+
+// ============================================================
+// Event timestamps, dedup and ordering
+// ============================================================
+
+use charcoal::bluesky::amplification::{
+    dedup_and_sort, tid_timestamp, uri_timestamp, AmplificationNotification,
+};
+
+fn event(event_type: &str, did: &str, post: &str, rkey: &str) -> AmplificationNotification {
+    let amplifier_post_uri = format!("at://{did}/app.bsky.feed.post/{rkey}");
+    AmplificationNotification {
+        event_type: event_type.to_string(),
+        amplifier_did: did.to_string(),
+        amplifier_handle: did.to_string(),
+        original_post_uri: Some(format!("at://did:plc:me/app.bsky.feed.post/{post}")),
+        occurred_at: uri_timestamp(&amplifier_post_uri),
+        amplifier_post_uri,
+        indexed_at: String::new(),
+    }
+}
+
+#[test]
+fn tid_timestamp_decodes_record_keys() {
+    let ts = tid_timestamp("3mfyouuy62222").unwrap();
+    assert_eq!(ts.to_rfc3339(), "2026-03-01T12:00:00+00:00");
+
+    // Sub-second precision survives
+    let ts = tid_timestamp("3jzfcijpj2z2a").unwrap();
+    assert_eq!(ts.timestamp_micros(), 1_688_137_381_887_007);
+}
+
+#[test]
+fn tid_timestamp_rejects_non_tids() {
+    assert!(tid_timestamp("3k1xyz").is_none()); // too short
+    assert!(tid_timestamp("self").is_none());
+    assert!(tid_timestamp("3mfyouuy6222!").is_none()); // bad character
+    assert!(tid_timestamp("a222222222222").is_none()); // top bit set
+    assert!(tid_timestamp("zzzzzzzzzzzzz").is_none()); // over 64 bits
+    assert!(uri_timestamp("").is_none());
+}
+
+#[test]
+fn dedup_and_sort_orders_oldest_first() {
+    let events = vec![
+        event("quote", "did:plc:ccc", "1", "3mg5pspvo2222"), // Mar 3
+        event("repost", "did:plc:aaa", "1", "3mfyouuy62222"), // Mar 1
+        event("quote", "did:plc:bbb", "1", "not-a-tid"),
+        event("quote", "did:plc:ddd", "2", "3mg37dsgw2222"), // Mar 2
+    ];
+
+    let sorted = dedup_and_sort(events);
+    let dids: Vec<&str> = sorted.iter().map(|e| e.amplifier_did.as_str()).collect();
+    // Undated events go last
+    assert_eq!(
+        dids,
+        vec!["did:plc:aaa", "did:plc:ddd", "did:plc:ccc", "did:plc:bbb"]
+    );
+}
+
+#[test]
+fn dedup_and_sort_counts_repeat_amplification_once() {
+    let events = vec![
+        // Same person quoting the same post twice — keep the earlier quote
+        event("quote", "did:plc:aaa", "1", "3mg37dsgw2222"),
+        event("quote", "did:plc:aaa", "1", "3mfyouuy62222"),
+        // Different post, or a repost rather than a quote, is a separate event
+        event("quote", "did:plc:aaa", "2", "3mg5pspvo2222"),
+        event("repost", "did:plc:aaa", "1", "3mg5pspvo2222"),
+    ];
+
+    let kept = dedup_and_sort(events);
+    assert_eq!(kept.len(), 3);
+    assert!(kept[0].amplifier_post_uri.ends_with("3mfyouuy62222"));
+    assert_eq!(
+        kept.iter()
+            .filter(|e| e.event_type == "quote"
+                && e.original_post_uri.as_deref() == Some("at://did:plc:me/app.bsky.feed.post/1"))
+            .count(),
+        1
+    );
+}
+
+// ============================================================
+// Source fallback
+// ============================================================
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use charcoal::bluesky::amplification::{
+    find_amplification_events, AmplificationSource, SOURCE_FAILURE_LIMIT,
+};
+use charcoal::bluesky::quotes::{quote_events, repost_events, QuotesResponse, RepostedByResponse};
+
+/// A source that fails for posts in `failing` (or every post when `down`)
+/// and otherwise quotes each post once, counting the calls it gets.
+struct FakeSource {
+    name: &'static str,
+    down: bool,
+    failing: Vec<&'static str>,
+    calls: AtomicUsize,
+}
+
+impl FakeSource {
+    fn new(name: &'static str, down: bool, failing: Vec<&'static str>) -> Self {
+        Self {
+            name,
+            down,
+            failing,
+            calls: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AmplificationSource for FakeSource {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn post_amplifications(
+        &self,
+        post_uri: &str,
+    ) -> anyhow::Result<Vec<AmplificationNotification>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let post = post_uri.rsplit('/').next().unwrap();
+        if self.down || self.failing.contains(&post) {
+            anyhow::bail!("{} timed out", self.name);
+        }
+        Ok(vec![event(
+            "quote",
+            &format!("did:plc:{}", self.name),
+            post,
+            &format!("{}-{post}", self.name),
+        )])
+    }
+}
+
+fn post_uris(ids: &[&str]) -> Vec<String> {
+    ids.iter()
+        .map(|id| format!("at://did:plc:me/app.bsky.feed.post/{id}"))
+        .collect()
+}
+
+#[tokio::test]
+async fn fallback_answers_posts_the_primary_fails() {
+    let primary = FakeSource::new("constellation", false, vec!["2"]);
+    let fallback = FakeSource::new("appview", false, vec![]);
+
+    let found = find_amplification_events(&[&primary, &fallback], &post_uris(&["1", "2", "3"]))
+        .await
+        .unwrap();
+
+    let amplifiers: Vec<&str> = found
+        .events
+        .iter()
+        .map(|e| e.amplifier_did.as_str())
+        .collect();
+    assert_eq!(
+        amplifiers,
+        vec![
+            "did:plc:constellation",
+            "did:plc:appview",
+            "did:plc:constellation"
+        ]
+    );
+    assert_eq!(found.source_summary(), "appview: 1, constellation: 2");
+    assert_eq!(found.unanswered, 0);
+    assert_eq!(fallback.calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn failing_source_is_skipped_after_limit() {
+    let primary = FakeSource::new("constellation", true, vec![]);
+    let fallback = FakeSource::new("appview", false, vec![]);
+    let uris = post_uris(&["1", "2", "3", "4", "5", "6"]);
+
+    let found = find_amplification_events(&[&primary, &fallback], &uris)
+        .await
+        .unwrap();
+
+    assert_eq!(found.events.len(), 6);
+    assert_eq!(primary.calls.load(Ordering::SeqCst), SOURCE_FAILURE_LIMIT);
+}
+
+#[tokio::test]
+async fn every_source_down_is_an_error() {
+    let primary = FakeSource::new("constellation", true, vec![]);
+    let fallback = FakeSource::new("appview", true, vec![]);
+
+    let err = find_amplification_events(&[&primary, &fallback], &post_uris(&["1", "2"]))
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("constellation, appview"),
+        "got: {err}"
+    );
+
+    // No posts to ask about isn't an outage
+    let found = find_amplification_events(&[&primary, &fallback], &[])
+        .await
+        .unwrap();
+    assert!(found.events.is_empty());
+}
+
+#[test]
+fn appview_responses_become_events() {
+    let quotes: QuotesResponse = serde_json::from_str(
+        r#"{
+            "uri": "at://did:plc:me/app.bsky.feed.post/1",
+            "posts": [{
+                "uri": "at://did:plc:quoter/app.bsky.feed.post/3mfyouuy62222",
+                "cid": "bafy",
+                "author": {"did": "did:plc:quoter", "handle": "quoter.test"},
+                "record": {"text": "look at this"},
+                "indexedAt": "2026-03-01T12:00:01.000Z"
+            }]
+        }"#,
+    )
+    .unwrap();
+    let reposts: RepostedByResponse = serde_json::from_str(
+        r#"{
+            "uri": "at://did:plc:me/app.bsky.feed.post/1",
+            "repostedBy": [{"did": "did:plc:reposter", "handle": "reposter.test"}],
+            "cursor": "abc"
+        }"#,
+    )
+    .unwrap();
+    let post = "at://did:plc:me/app.bsky.feed.post/1";
+
+    let quote = &quote_events(post, &quotes)[0];
+    assert_eq!(quote.event_type, "quote");
+    assert_eq!(quote.amplifier_handle, "quoter.test");
+    assert_eq!(quote.original_post_uri.as_deref(), Some(post));
+    assert_eq!(
+        quote.occurred_at.unwrap().to_rfc3339(),
+        "2026-03-01T12:00:00+00:00"
+    );
+
+    let repost = &repost_events(post, &reposts)[0];
+    assert_eq!(repost.event_type, "repost");
+    assert_eq!(repost.amplifier_did, "did:plc:reposter");
+    // The AppView doesn't expose repost records
+    assert!(repost.amplifier_post_uri.is_empty());
+    assert!(repost.occurred_at.is_none());
+}
@@ -89,6 +89,7 @@ fn context_score_no_double_application() {
         0.05,  // low quote ratio (benign)
         0.10,  // low reply ratio (benign)
         false, // no pile-on
+        false, // not a stranger quote
         50.0,  // above median engagement
         10.0,  // median
         Some(0.8),
@@ -0,0 +1,153 @@
+// Unit tests for `report --diff` (output::diff).
+//
+// Builds report snapshots by hand and checks what diff_reports finds:
+// additions, removals, tier changes, score moves, and new High accounts.
+// Snapshots are also round-tripped through a file.
+
+use charcoal::output::diff::{diff_reports, ReportSnapshot, SnapshotAccount, MIN_SCORE_CHANGE};
+
+fn account(did: &str, handle: &str, score: f64, tier: &str) -> SnapshotAccount {
+    SnapshotAccount {
+        did: did.to_string(),
+        handle: handle.to_string(),
+        threat_score: Some(score),
+        threat_tier: Some(tier.to_string()),
+    }
+}
+
+fn snapshot(accounts: Vec<SnapshotAccount>) -> ReportSnapshot {
+    ReportSnapshot {
+        generated_at: "2026-10-09T12:00:00+00:00".to_string(),
+        accounts,
+    }
+}
+
+fn handles(accounts: &[&SnapshotAccount]) -> Vec<String> {
+    accounts.iter().map(|a| a.handle.clone()).collect()
+}
+
+// ============================================================
+// diff_reports
+// ============================================================
+
+#[test]
+fn diff_finds_additions_removals_and_tier_changes() {
+    let old = snapshot(vec![
+        account("did:plc:a", "stays.test", 40.0, "High"),
+        account("did:plc:b", "rises.test", 10.0, "Watch"),
+        account("did:plc:c", "gone.test", 20.0, "Elevated"),
+        account("did:plc:d", "gone-low.test", 3.0, "Low"),
+    ]);
+    let new = snapshot(vec![
+        account("did:plc:a", "stays.test", 41.0, "High"),
+        account("did:plc:b", "rises.test", 38.0, "High"),
+        account("did:plc:e", "newcomer.test", 16.0, "Elevated"),
+        account("did:plc:f", "new-high.test", 60.0, "High"),
+    ]);
+
+    let diff = diff_reports(&old, &new);
+
+    assert_eq!(diff.since, "2026-10-09T12:00:00+00:00");
+    // Highest score first
+    let added: Vec<&SnapshotAccount> = diff.added.iter().collect();
+    assert_eq!(handles(&added), ["new-high.test", "newcomer.test"]);
+    let removed: Vec<&SnapshotAccount> = diff.removed.iter().collect();
+    assert_eq!(handles(&removed), ["gone.test", "gone-low.test"]);
+
+    assert_eq!(diff.tier_changes.len(), 1);
+    let rise = &diff.tier_changes[0];
+    assert_eq!(rise.account.handle, "rises.test");
+    assert_eq!(rise.old_tier.as_deref(), Some("Watch"));
+    assert_eq!(rise.account.threat_tier.as_deref(), Some("High"));
+    assert!((rise.delta() - 28.0).abs() < 1e-9);
+
+    // A one-point wobble within a tier isn't a change
+    assert!(diff.score_changes.is_empty());
+    assert_eq!(handles(&diff.new_high()), ["new-high.test", "rises.test"]);
+    assert!(!diff.is_empty());
+}
+
+#[test]
+fn diff_reports_large_moves_within_a_tier() {
+    let old = snapshot(vec![
+        account("did:plc:a", "cooling.test", 80.0, "High"),
+        account("did:plc:b", "steady.test", 50.0, "High"),
+        account("did:plc:c", "heating.test", 36.0, "High"),
+    ]);
+    let new = snapshot(vec![
+        account("did:plc:a", "cooling.test", 60.0, "High"),
+        account(
+            "did:plc:b",
+            "steady.test",
+            50.0 + MIN_SCORE_CHANGE / 2.0,
+            "High",
+        ),
+        account("did:plc:c", "heating.test", 36.0 + MIN_SCORE_CHANGE, "High"),
+    ]);
+
+    let diff = diff_reports(&old, &new);
+
+    assert!(diff.tier_changes.is_empty());
+    let moved: Vec<&str> = diff
+        .score_changes
+        .iter()
+        .map(|c| c.account.handle.as_str())
+        .collect();
+    // Largest move first, drops included
+    assert_eq!(moved, ["cooling.test", "heating.test"]);
+    assert!((diff.score_changes[0].delta() + 20.0).abs() < 1e-9);
+    assert!(diff.new_high().is_empty());
+}
+
+#[test]
+fn diff_matches_accounts_by_did_across_handle_changes() {
+    let old = snapshot(vec![account(
+        "did:plc:a",
+        "old-name.test",
+        20.0,
+        "Elevated",
+    )]);
+    let new = snapshot(vec![account(
+        "did:plc:a",
+        "new-name.test",
+        21.0,
+        "Elevated",
+    )]);
+
+    let diff = diff_reports(&old, &new);
+
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn identical_reports_have_no_changes() {
+    let accounts = vec![
+        account("did:plc:a", "a.test", 40.0, "High"),
+        account("did:plc:b", "b.test", 10.0, "Watch"),
+    ];
+    let diff = diff_reports(&snapshot(accounts.clone()), &snapshot(accounts));
+    assert!(diff.is_empty());
+}
+
+// ============================================================
+// Snapshot files
+// ============================================================
+
+#[test]
+fn snapshot_round_trips_through_a_file() {
+    let path = std::env::temp_dir()
+        .join(format!("charcoal-snapshot-{}.json", std::process::id()))
+        .to_string_lossy()
+        .into_owned();
+    let _ = std::fs::remove_file(&path);
+
+    // Nothing saved yet is not an error
+    assert_eq!(ReportSnapshot::load(&path).unwrap(), None);
+
+    let saved = snapshot(vec![account("did:plc:a", "a.test", 40.0, "High")]);
+    saved.save(&path).unwrap();
+    let loaded = ReportSnapshot::load(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(loaded, Some(saved));
+}
@@ -5,7 +5,9 @@
 #[test]
 fn extract_search_keywords_from_fingerprint() {
     use charcoal::discovery::topic_search;
-    use charcoal::topics::fingerprint::{TopicCluster, TopicFingerprint};
+    use charcoal::topics::fingerprint::{
+        TopicCluster, TopicFingerprint, FINGERPRINT_SCHEMA_VERSION,
+    };
 
     let fingerprint = TopicFingerprint {
         clusters: vec![
@@ -34,6 +36,7 @@ fn extract_search_keywords_from_fingerprint() {
             },
         ],
         post_count: 50,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
 
     let search_terms = topic_search::extract_search_keywords(&fingerprint, 3);
@@ -48,7 +51,9 @@ fn extract_search_keywords_from_fingerprint() {
 #[test]
 fn extract_search_keywords_respects_limit() {
     use charcoal::discovery::topic_search;
-    use charcoal::topics::fingerprint::{TopicCluster, TopicFingerprint};
+    use charcoal::topics::fingerprint::{
+        TopicCluster, TopicFingerprint, FINGERPRINT_SCHEMA_VERSION,
+    };
 
     let fingerprint = TopicFingerprint {
         clusters: vec![
@@ -69,6 +74,7 @@ fn extract_search_keywords_respects_limit() {
             },
         ],
         post_count: 30,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
 
     let terms = topic_search::extract_search_keywords(&fingerprint, 2);
@@ -125,7 +131,9 @@ fn expand_empty_list() {
 #[test]
 fn extract_keywords_skips_short_terms() {
     use charcoal::discovery::topic_search;
-    use charcoal::topics::fingerprint::{TopicCluster, TopicFingerprint};
+    use charcoal::topics::fingerprint::{
+        TopicCluster, TopicFingerprint, FINGERPRINT_SCHEMA_VERSION,
+    };
 
     let fingerprint = TopicFingerprint {
         clusters: vec![TopicCluster {
@@ -134,6 +142,7 @@ fn extract_keywords_skips_short_terms() {
             weight: 0.9,
         }],
         post_count: 10,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
 
     let terms = topic_search::extract_search_keywords(&fingerprint, 5);
@@ -7,7 +7,9 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use charcoal::toxicity::ensemble::{TwoStageToxicityScorer, VerdictSource};
-use charcoal::toxicity::traits::{ToxicityAttributes, ToxicityResult, ToxicityScorer};
+use charcoal::toxicity::traits::{
+    AttributeWeights, ToxicityAttributes, ToxicityResult, ToxicityScorer,
+};
 
 /// Test scorer that returns a fixed continuous toxicity score for any input.
 /// Used as the ONNX-equivalent primary scorer.
@@ -31,7 +33,10 @@ fn two_stage_no_zentropi(onnx_score: f64) -> TwoStageToxicityScorer {
 async fn onnx_below_clean_threshold_skips_zentropi() {
     // ONNX 0.05 < 0.10 clean threshold → cleared, is_toxic = false.
     let scorer = two_stage_no_zentropi(0.05);
-    let v = scorer.classify_post("benign text", None).await.unwrap();
+    let v = scorer
+        .classify_post("benign text", None, &AttributeWeights::default())
+        .await
+        .unwrap();
     assert!(!v.is_toxic);
     assert_eq!(v.source, VerdictSource::OnnxCleared);
     assert!(v.zentropi_confidence.is_none());
@@ -42,7 +47,10 @@ async fn onnx_below_clean_threshold_skips_zentropi() {
 async fn onnx_above_clean_threshold_no_zentropi_uses_fallback_threshold() {
     // ONNX 0.30, no Zentropi → falls back to 0.50 binary threshold → safe.
     let scorer = two_stage_no_zentropi(0.30);
-    let v = scorer.classify_post("ambiguous text", None).await.unwrap();
+    let v = scorer
+        .classify_post("ambiguous text", None, &AttributeWeights::default())
+        .await
+        .unwrap();
     assert!(!v.is_toxic);
     assert_eq!(v.source, VerdictSource::OnnxFallback);
 }
@@ -51,7 +59,10 @@ async fn onnx_above_clean_threshold_no_zentropi_uses_fallback_threshold() {
 async fn onnx_well_above_fallback_threshold_no_zentropi_is_toxic() {
     // ONNX 0.70 > 0.50 fallback → is_toxic = true via OnnxFallback.
     let scorer = two_stage_no_zentropi(0.70);
-    let v = scorer.classify_post("hostile text", None).await.unwrap();
+    let v = scorer
+        .classify_post("hostile text", None, &AttributeWeights::default())
+        .await
+        .unwrap();
     assert!(v.is_toxic);
     assert_eq!(v.source, VerdictSource::OnnxFallback);
 }
@@ -63,7 +74,10 @@ async fn classify_batch_preserves_input_order() {
     let scorer = two_stage_no_zentropi(0.05);
     let texts: Vec<String> = (0..16).map(|i| format!("post {}", i)).collect();
     let contexts: Vec<Option<String>> = vec![None; texts.len()];
-    let verdicts = scorer.classify_batch(&texts, &contexts).await.unwrap();
+    let verdicts = scorer
+        .classify_batch(&texts, &contexts, &AttributeWeights::default())
+        .await
+        .unwrap();
 
     assert_eq!(verdicts.len(), 16);
     for v in &verdicts {
@@ -77,7 +91,10 @@ async fn classify_batch_rejects_mismatched_lengths() {
     let scorer = two_stage_no_zentropi(0.05);
     let texts = vec!["a".to_string(), "b".to_string()];
     let contexts = vec![None];
-    let err = scorer.classify_batch(&texts, &contexts).await.unwrap_err();
+    let err = scorer
+        .classify_batch(&texts, &contexts, &AttributeWeights::default())
+        .await
+        .unwrap_err();
     assert!(format!("{err}").contains("texts.len()"));
 }
 
@@ -97,9 +114,14 @@ async fn classify_batch_via_trait_method_works() {
     let scorer = two_stage_no_zentropi(0.05);
     let texts = vec!["a".to_string(), "b".to_string()];
     let contexts: Vec<Option<String>> = vec![None, None];
-    let verdicts = ToxicityScorer::classify_batch_with_contexts(&scorer, &texts, &contexts)
-        .await
-        .unwrap();
+    let verdicts = ToxicityScorer::classify_batch_with_contexts(
+        &scorer,
+        &texts,
+        &contexts,
+        &AttributeWeights::default(),
+    )
+    .await
+    .unwrap();
 
     assert_eq!(verdicts.len(), 2);
     assert!(verdicts.iter().all(|v| !v.is_toxic));
@@ -113,7 +135,7 @@ async fn default_trait_classify_batch_uses_05_threshold() {
     let texts = vec!["x".to_string()];
     let contexts: Vec<Option<String>> = vec![None];
     let verdicts = scorer
-        .classify_batch_with_contexts(&texts, &contexts)
+        .classify_batch_with_contexts(&texts, &contexts, &AttributeWeights::default())
         .await
         .unwrap();
 
@@ -127,13 +149,68 @@ async fn default_trait_classify_batch_below_threshold_safe() {
     let texts = vec!["x".to_string()];
     let contexts: Vec<Option<String>> = vec![None];
     let verdicts = scorer
-        .classify_batch_with_contexts(&texts, &contexts)
+        .classify_batch_with_contexts(&texts, &contexts, &AttributeWeights::default())
         .await
         .unwrap();
 
     assert!(!verdicts[0].is_toxic);
 }
 
+/// A mild top-line score carrying a stronger identity attack.
+struct IdentityAttackScorer;
+
+#[async_trait]
+impl ToxicityScorer for IdentityAttackScorer {
+    async fn score_text(&self, _text: &str) -> Result<ToxicityResult> {
+        Ok(ToxicityResult {
+            toxicity: 0.30,
+            attributes: ToxicityAttributes {
+                identity_attack: Some(0.40),
+                ..Default::default()
+            },
+        })
+    }
+}
+
+#[tokio::test]
+async fn attribute_weights_move_the_default_verdict() {
+    let texts = vec!["x".to_string()];
+    let contexts: Vec<Option<String>> = vec![None];
+
+    // Default weights count identity attacks 1.5x: 0.40 * 1.5 = 0.60 >= 0.50
+    let weighted = IdentityAttackScorer
+        .classify_batch_with_contexts(&texts, &contexts, &AttributeWeights::default())
+        .await
+        .unwrap();
+    assert!(weighted[0].is_toxic);
+    // The raw score is still what's reported
+    assert!((weighted[0].onnx_score - 0.30).abs() < 1e-9);
+
+    // Zeroing the category leaves only the 0.30 top line
+    let ignored = AttributeWeights::parse("identity_attack=0").unwrap();
+    let unweighted = IdentityAttackScorer
+        .classify_batch_with_contexts(&texts, &contexts, &ignored)
+        .await
+        .unwrap();
+    assert!(!unweighted[0].is_toxic);
+}
+
+#[tokio::test]
+async fn attribute_weights_move_the_onnx_fallback_verdict() {
+    let scorer = TwoStageToxicityScorer::new(Box::new(IdentityAttackScorer), None);
+
+    let v = scorer
+        .classify_post("x", None, &AttributeWeights::default())
+        .await
+        .unwrap();
+    assert!(v.is_toxic);
+    assert_eq!(v.source, VerdictSource::OnnxFallback);
+
+    let ignored = AttributeWeights::parse("identity_attack=0").unwrap();
+    let v = scorer.classify_post("x", None, &ignored).await.unwrap();
+    assert!(!v.is_toxic);
+}
+
 #[tokio::test]
 async fn has_zentropi_reflects_construction() {
     let no_z = two_stage_no_zentropi(0.05);
@@ -0,0 +1,175 @@
+// Unit tests for the typed library errors (charcoal::error::Error).
+//
+// Covers how an anyhow error is classified on its way out of the library,
+// and that the HTTP clients and model loaders raise the right variant. A
+// throwaway local server stands in for the AppView and Constellation.
+
+use charcoal::bluesky::client::{xrpc_error, PublicAtpClient};
+use charcoal::config::OnnxProvider;
+use charcoal::constellation::client::ConstellationClient;
+use charcoal::error::Error;
+use reqwest::StatusCode;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serve one request on a fresh local port with `status` and a JSON `body`,
+/// returning the base URL to point a client at.
+async fn serve_once(status: &'static str, body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            head.extend_from_slice(&buf[..n]);
+        }
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+    });
+    base_url
+}
+
+// ============================================================
+// From<anyhow::Error>
+// ============================================================
+
+#[test]
+fn typed_error_survives_anyhow_context() {
+    let err = anyhow::Error::from(Error::NotFound("@gone.bsky.social".to_string()))
+        .context("Failed to resolve handle @gone.bsky.social")
+        .context("Scan failed");
+
+    assert!(matches!(Error::from(err), Error::NotFound(_)));
+}
+
+#[test]
+fn plain_anyhow_error_is_other() {
+    let err = Error::from(anyhow::anyhow!("something broke"));
+
+    assert!(matches!(err, Error::Other(_)));
+    assert_eq!(err.to_string(), "something broke");
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_error_is_db() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    let sql_err = conn.execute("SELECT * FROM no_such_table", []).unwrap_err();
+    let err = Error::from(anyhow::Error::from(sql_err).context("Failed to read scores"));
+
+    assert!(matches!(err, Error::Db(_)));
+    assert!(err.to_string().contains("no_such_table"), "got: {err}");
+}
+
+// ============================================================
+// XRPC classification
+// ============================================================
+
+#[test]
+fn xrpc_429_is_rate_limited() {
+    let err = xrpc_error(
+        "app.bsky.feed.getAuthorFeed",
+        StatusCode::TOO_MANY_REQUESTS,
+        r#"{"error":"RateLimitExceeded","message":"Rate Limit Exceeded"}"#,
+    );
+
+    assert!(matches!(err, Error::RateLimited { .. }));
+}
+
+#[test]
+fn xrpc_missing_accounts_are_not_found() {
+    for body in [
+        r#"{"error":"InvalidRequest","message":"Profile not found"}"#,
+        r#"{"error":"InvalidRequest","message":"Unable to resolve handle"}"#,
+        r#"{"error":"AccountNotFound","message":"Account does not exist"}"#,
+    ] {
+        let err = xrpc_error("app.bsky.actor.getProfile", StatusCode::BAD_REQUEST, body);
+        assert!(matches!(err, Error::NotFound(_)), "{body} gave {err}");
+    }
+}
+
+#[test]
+fn other_xrpc_failures_are_other() {
+    let err = xrpc_error(
+        "app.bsky.feed.getAuthorFeed",
+        StatusCode::BAD_GATEWAY,
+        "upstream unavailable",
+    );
+
+    assert!(matches!(err, Error::Other(_)));
+    assert!(err.to_string().contains("502"), "got: {err}");
+}
+
+#[tokio::test]
+async fn fetch_recent_posts_reports_rate_limit() {
+    let base_url = serve_once(
+        "429 Too Many Requests",
+        r#"{"error":"RateLimitExceeded","message":"Rate Limit Exceeded"}"#,
+    )
+    .await;
+    let client = PublicAtpClient::new(&base_url).unwrap();
+
+    let err = charcoal::bluesky::posts::fetch_recent_posts(&client, "someone.bsky.social", 10)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::RateLimited { .. }), "got: {err}");
+}
+
+#[tokio::test]
+async fn constellation_reports_rate_limit() {
+    let base_url = serve_once("429 Too Many Requests", r#"{"error":"slow down"}"#).await;
+    let client = ConstellationClient::new(&base_url).unwrap();
+
+    let err = client
+        .get_backlinks(
+            "at://did:plc:abc/app.bsky.feed.post/1",
+            charcoal::constellation::client::QUOTES_SOURCE,
+            10,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, Error::RateLimited { ref service, .. } if service == "Constellation"),
+        "got: {err}"
+    );
+}
+
+// ============================================================
+// Model loading
+// ============================================================
+
+#[test]
+fn missing_model_is_model_missing() {
+    let dir = std::env::temp_dir().join("charcoal-error-test-no-model");
+    let err = charcoal::toxicity::onnx::OnnxToxicityScorer::load(&dir, OnnxProvider::Cpu)
+        .err()
+        .unwrap();
+
+    match err {
+        Error::ModelMissing { path } => assert!(path.starts_with(&dir)),
+        other => panic!("expected ModelMissing, got: {other}"),
+    }
+}
+
+// ============================================================
+// Config
+// ============================================================
+
+#[test]
+fn missing_handle_is_config_error() {
+    let config =
+        charcoal::config::Config::from_sources(&charcoal::config::ConfigFile::default(), |_| None);
+
+    assert!(matches!(config.require_bluesky(), Err(Error::Config(_))));
+}
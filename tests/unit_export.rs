@@ -0,0 +1,345 @@
+// Unit tests for CSV and mute-list export of scored accounts, and for
+// importing exports back.
+//
+// Writes synthesized AccountScore values with output::csv, parses the file
+// back with a minimal RFC 4180 reader, and checks every column survives.
+// Mute lists are checked as built JSON documents. Imports are checked by
+// round-tripping ranked threats through an in-memory SQLite database.
+
+use charcoal::db::models::AccountScore;
+use charcoal::db::models::{ThreatTier, TierThresholds, ToxicPost};
+use charcoal::db::queries::{get_ranked_threats, upsert_account_scores};
+use charcoal::output::csv::{escape_field, write_account_scores, CSV_COLUMNS};
+use charcoal::output::import::{parse_csv_scores, parse_json_report_scores};
+use charcoal::output::json::build_json_report;
+use charcoal::output::mutelist::{build_mutelist, MutelistFormat, LISTITEM_TYPE};
+use charcoal::scoring::behavioral::BehavioralSignals;
+
+fn make_account(did: &str, handle: &str, threat: Option<f64>) -> AccountScore {
+    AccountScore {
+        did: did.to_string(),
+        handle: handle.to_string(),
+        toxicity_score: threat.map(|t| t / 100.0),
+        topic_overlap: threat.map(|_| 0.25),
+        threat_score: threat,
+        threat_tier: threat.map(|_| "Elevated".to_string()),
+        posts_analyzed: 20,
+        scored_at: "2026-03-01 12:00:00".to_string(),
+        ..Default::default()
+    }
+}
+
+/// Split CSV text into records of fields, honoring quoted fields with
+/// embedded commas, doubled quotes, and line breaks.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    records
+}
+
+fn temp_csv(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("charcoal-export-{name}-{}.csv", std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+// ============================================================
+// escape_field
+// ============================================================
+
+#[test]
+fn escape_field_leaves_plain_values_alone() {
+    assert_eq!(escape_field("alice.bsky.social"), "alice.bsky.social");
+    assert_eq!(escape_field(""), "");
+}
+
+#[test]
+fn escape_field_quotes_commas_quotes_and_newlines() {
+    assert_eq!(escape_field("a,b"), "\"a,b\"");
+    assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(escape_field("two\nlines"), "\"two\nlines\"");
+}
+
+// ============================================================
+// write_account_scores — round trip
+// ============================================================
+
+#[test]
+fn csv_round_trips_synthesized_scores() {
+    let mut with_signals = make_account("did:plc:aaa", "troll.bsky.social", Some(42.5));
+    with_signals.behavioral_signals = Some(
+        serde_json::to_string(&BehavioralSignals {
+            quote_ratio: 0.5,
+            reply_ratio: 0.25,
+            pile_on: true,
+            ..BehavioralSignals::default()
+        })
+        .unwrap(),
+    );
+    let awkward = make_account("did:plc:bbb", "comma,\"quoted\" handle", Some(8.0));
+    let mut unscored = make_account("did:plc:ccc", "quiet.bsky.social", None);
+    unscored.behavioral_signals = Some("{not json".to_string());
+
+    let path = temp_csv("roundtrip");
+    write_account_scores(&[with_signals, awkward, unscored], &path).unwrap();
+    let records = parse_csv(&std::fs::read_to_string(&path).unwrap());
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(records.len(), 4, "header plus one row per account");
+    assert_eq!(records[0], CSV_COLUMNS);
+    for record in &records {
+        assert_eq!(record.len(), CSV_COLUMNS.len());
+    }
+
+    assert_eq!(
+        records[1],
+        [
+            "did:plc:aaa",
+            "troll.bsky.social",
+            "0.425",
+            "0.25",
+            "42.5",
+            "Elevated",
+            "20",
+            "2026-03-01 12:00:00",
+            "0.5",
+            "0.25",
+            "true",
+        ]
+    );
+
+    // Commas and quotes in a handle come back intact
+    assert_eq!(records[2][1], "comma,\"quoted\" handle");
+    assert_eq!(records[2][4].parse::<f64>().unwrap(), 8.0);
+    // No signals → blank behavioral columns
+    assert_eq!(records[2][8..], ["", "", ""]);
+
+    // Missing scores and unparseable signals leave cells blank
+    assert_eq!(records[3][0], "did:plc:ccc");
+    assert_eq!(records[3][2..6], ["", "", "", ""]);
+    assert_eq!(records[3][8..], ["", "", ""]);
+}
+
+#[test]
+fn csv_with_no_accounts_is_just_the_header() {
+    let path = temp_csv("empty");
+    write_account_scores(&[], &path).unwrap();
+    let text = std::fs::read_to_string(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(text, format!("{}\n", CSV_COLUMNS.join(",")));
+}
+
+// ============================================================
+// Mute-list export
+// ============================================================
+
+#[test]
+fn mutelist_listitems_carry_subject_and_optional_list() {
+    let accounts = vec![
+        make_account("did:plc:aaa", "a.test", Some(40.0)),
+        make_account("did:plc:bbb", "b.test", Some(20.0)),
+        make_account("did:plc:aaa", "a.test", Some(40.0)),
+    ];
+
+    let items = build_mutelist(&accounts, MutelistFormat::ListItems, None);
+    let items = items.as_array().unwrap();
+    assert_eq!(items.len(), 2, "one entry per DID");
+    assert_eq!(items[0]["$type"], LISTITEM_TYPE);
+    assert_eq!(items[0]["subject"], "did:plc:aaa");
+    assert_eq!(items[1]["subject"], "did:plc:bbb");
+    assert!(items[0]["createdAt"].is_string());
+    assert!(items[0].get("list").is_none());
+
+    let list = "at://did:plc:me/app.bsky.graph.list/mutes";
+    let items = build_mutelist(&accounts, MutelistFormat::ListItems, Some(list));
+    assert_eq!(items[1]["list"], list);
+}
+
+#[test]
+fn mutelist_dids_is_a_plain_array() {
+    let accounts = vec![
+        make_account("did:plc:aaa", "a.test", Some(40.0)),
+        make_account("did:plc:bbb", "b.test", Some(20.0)),
+    ];
+    let dids = build_mutelist(&accounts, MutelistFormat::Dids, None);
+    assert_eq!(dids, serde_json::json!(["did:plc:aaa", "did:plc:bbb"]));
+    assert_eq!(
+        build_mutelist(&[], MutelistFormat::Dids, None),
+        serde_json::json!([])
+    );
+}
+
+#[test]
+fn tier_floor_is_the_tier_threshold() {
+    let thresholds = TierThresholds::DEFAULT;
+    assert_eq!(thresholds.floor(ThreatTier::Low), 0.0);
+    assert_eq!(thresholds.floor(ThreatTier::Watch), 8.0);
+    assert_eq!(thresholds.floor(ThreatTier::Elevated), 15.0);
+    assert_eq!(thresholds.floor(ThreatTier::High), 35.0);
+}
+
+// ============================================================
+// Import
+// ============================================================
+
+const TEST_USER: &str = "did:plc:importer";
+
+fn scored_db(accounts: &[AccountScore]) -> rusqlite::Connection {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    charcoal::db::schema::create_tables(&conn).unwrap();
+    upsert_account_scores(&conn, TEST_USER, accounts, None).unwrap();
+    conn
+}
+
+fn ranked(conn: &rusqlite::Connection) -> Vec<AccountScore> {
+    get_ranked_threats(conn, TEST_USER, 0.0, &TierThresholds::DEFAULT).unwrap()
+}
+
+/// The fields an export carries, for comparing ranked threats before and
+/// after a round trip.
+fn summary(accounts: &[AccountScore]) -> Vec<(String, String, String)> {
+    accounts
+        .iter()
+        .map(|a| {
+            (
+                a.did.clone(),
+                a.handle.clone(),
+                format!(
+                    "{:?} {:?} {:?} {:?} {} {} {:?}",
+                    a.toxicity_score,
+                    a.topic_overlap,
+                    a.threat_score,
+                    a.threat_tier,
+                    a.posts_analyzed,
+                    a.scored_at,
+                    a.behavioral_signals
+                        .as_deref()
+                        .and_then(|s| serde_json::from_str::<BehavioralSignals>(s).ok())
+                        .map(|s| (s.quote_ratio, s.reply_ratio, s.pile_on)),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn export_fixture() -> Vec<AccountScore> {
+    let mut high = make_account("did:plc:aaa", "troll.bsky.social", Some(42.5));
+    high.behavioral_signals = Some(
+        serde_json::to_string(&BehavioralSignals {
+            quote_ratio: 0.5,
+            reply_ratio: 0.25,
+            pile_on: true,
+            ..BehavioralSignals::default()
+        })
+        .unwrap(),
+    );
+    vec![
+        high,
+        make_account("did:plc:bbb", "comma,\"quoted\" handle", Some(8.0)),
+        make_account("did:plc:ccc", "low.bsky.social", Some(2.25)),
+    ]
+}
+
+#[test]
+fn csv_export_then_import_yields_equivalent_ranked_threats() {
+    let before = ranked(&scored_db(&export_fixture()));
+
+    let path = temp_csv("import");
+    write_account_scores(&before, &path).unwrap();
+    let imported = parse_csv_scores(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert!(imported.skipped.is_empty(), "{:?}", imported.skipped);
+    let after = ranked(&scored_db(&imported.scores));
+    assert_eq!(summary(&after), summary(&before));
+    // Restored scores keep their age, so they aren't skipped as fresh
+    assert!(after.iter().all(|a| a.scored_at == "2026-03-01 12:00:00"));
+}
+
+#[test]
+fn json_report_then_import_yields_equivalent_ranked_threats() {
+    let mut accounts = export_fixture();
+    accounts[0].top_toxic_posts = vec![ToxicPost {
+        text: "an evidence post".to_string(),
+        toxicity: 0.9,
+        uri: "at://did:plc:aaa/app.bsky.feed.post/1".to_string(),
+        attributes: None,
+        created_at: None,
+        hardblock_pattern: None,
+        flagged_for: None,
+    }];
+    let before = ranked(&scored_db(&accounts));
+
+    let report = build_json_report(&before, None, &[]).unwrap();
+    let imported = parse_json_report_scores(&report.to_string()).unwrap();
+
+    assert!(imported.skipped.is_empty(), "{:?}", imported.skipped);
+    let after = ranked(&scored_db(&imported.scores));
+    assert_eq!(summary(&after), summary(&before));
+    // Reports carry evidence posts, unlike CSV exports
+    assert_eq!(after[0].top_toxic_posts.len(), 1);
+    assert_eq!(after[0].top_toxic_posts[0].text, "an evidence post");
+}
+
+#[test]
+fn csv_import_skips_malformed_rows() {
+    let text = format!(
+        "{}\n\
+         did:plc:ok,ok.test,0.5,0.2,30,Elevated,12,2026-03-01 12:00:00,,,\n\
+         did:plc:score,score.test,0.5,0.2,140,High,12,2026-03-01 12:00:00,,,\n\
+         did:plc:tier,tier.test,0.5,0.2,30,Severe,12,2026-03-01 12:00:00,,,\n\
+         not-a-did,nodid.test,0.5,0.2,30,Elevated,12,2026-03-01 12:00:00,,,\n\
+         did:plc:short,short.test,0.5\n\
+         did:plc:nan,nan.test,abc,0.2,30,Elevated,12,2026-03-01 12:00:00,,,\n\
+         did:plc:when,when.test,0.5,0.2,30,Elevated,12,yesterday,,,\n\
+         \n",
+        CSV_COLUMNS.join(",")
+    );
+
+    let imported = parse_csv_scores(&text).unwrap();
+
+    assert_eq!(imported.scores.len(), 1);
+    assert_eq!(imported.scores[0].did, "did:plc:ok");
+    let lines: Vec<&str> = imported
+        .skipped
+        .iter()
+        .map(|s| s.location.as_str())
+        .collect();
+    assert_eq!(
+        lines,
+        ["line 3", "line 4", "line 5", "line 6", "line 7", "line 8"]
+    );
+    assert!(imported.skipped[0].reason.contains("threat_score"));
+    assert!(imported.skipped[1].reason.contains("Severe"));
+    assert!(imported.skipped[5].reason.contains("scored_at"));
+}
+
+#[test]
+fn import_rejects_files_that_are_not_exports() {
+    assert!(parse_csv_scores("").is_err());
+    assert!(parse_csv_scores("name,score\nalice,3\n").is_err());
+    assert!(parse_json_report_scores("{\"events\": []}").is_err());
+    assert!(parse_json_report_scores("not json").is_err());
+}
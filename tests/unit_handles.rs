@@ -0,0 +1,338 @@
+// Unit tests for handle and DID syntax validation and normalization.
+//
+// These checks run before any network call, so they must accept every
+// real-world handle shape (including pasted profile URLs) and reject the
+// common typos.
+
+use charcoal::bluesky::handle::normalize;
+use charcoal::bluesky::{
+    is_did, is_valid_handle, parse_actor, parse_actor_args, read_actor_list, Actor,
+};
+
+// ============================================================
+// is_valid_handle
+// ============================================================
+
+#[test]
+fn valid_handles_accepted() {
+    for handle in [
+        "someone.bsky.social",
+        "alice.com",
+        "a.co",
+        "xn--ls8h.test",
+        "john-doe.example.org",
+        "123.bsky.social",
+        "under.sub.domain.example.net",
+    ] {
+        assert!(is_valid_handle(handle), "{handle} should be valid");
+    }
+}
+
+#[test]
+fn invalid_handles_rejected() {
+    for handle in [
+        "",
+        "someone",
+        "@someone.bsky.social",
+        "some one.bsky.social",
+        "someone..bsky.social",
+        ".bsky.social",
+        "someone.bsky.social.",
+        "-someone.bsky.social",
+        "someone-.bsky.social",
+        "someone.bsky.123",
+        "someone_1.bsky.social",
+        "https://bsky.app/profile/someone.bsky.social",
+    ] {
+        assert!(!is_valid_handle(handle), "{handle:?} should be invalid");
+    }
+}
+
+#[test]
+fn overlong_handles_rejected() {
+    let long_segment = format!("{}.com", "a".repeat(64));
+    assert!(!is_valid_handle(&long_segment));
+    let long_total = format!("{}com", "abcdefghi.".repeat(26));
+    assert!(long_total.len() > 253);
+    assert!(!is_valid_handle(&long_total));
+}
+
+// ============================================================
+// is_did
+// ============================================================
+
+#[test]
+fn dids_recognized() {
+    assert!(is_did("did:plc:z72i7hdynmk6r22z27h6tvur"));
+    assert!(is_did("did:web:example.com"));
+    assert!(!is_did("did:plc:"));
+    assert!(!is_did("did::abc"));
+    assert!(!is_did("did:PLC:abc"));
+    assert!(!is_did("plc:abc"));
+    assert!(!is_did("someone.bsky.social"));
+}
+
+// ============================================================
+// normalize
+// ============================================================
+
+#[test]
+fn normalize_strips_profile_urls() {
+    for input in [
+        "https://bsky.app/profile/Someone.bsky.social",
+        "http://bsky.app/profile/someone.bsky.social/",
+        "bsky.app/profile/someone.bsky.social",
+        "https://www.bsky.app/profile/someone.bsky.social",
+        "https://bsky.app/profile/someone.bsky.social/post/3kabc123",
+        "https://bsky.app/profile/someone.bsky.social?ref=share",
+    ] {
+        assert_eq!(normalize(input).unwrap(), "someone.bsky.social", "{input}");
+    }
+}
+
+#[test]
+fn normalize_strips_at_and_lowercases() {
+    assert_eq!(normalize("@Alice.COM").unwrap(), "alice.com");
+    assert_eq!(normalize("  @alice.com\n").unwrap(), "alice.com");
+}
+
+#[test]
+fn normalize_passes_dids_through() {
+    assert_eq!(normalize("did:plc:abc123").unwrap(), "did:plc:abc123");
+    assert_eq!(
+        normalize("https://bsky.app/profile/did:plc:abc123/post/3k").unwrap(),
+        "did:plc:abc123"
+    );
+    // DIDs are case-sensitive, so they aren't lowercased
+    assert_eq!(
+        normalize("did:web:Example.com").unwrap(),
+        "did:web:Example.com"
+    );
+}
+
+#[test]
+fn normalize_rejects_malformed_input() {
+    let trailing_dot = normalize("someone.bsky.social.").unwrap_err().to_string();
+    assert!(trailing_dot.contains("ends with a dot"), "{trailing_dot}");
+
+    let empty = normalize("https://bsky.app/profile/")
+        .unwrap_err()
+        .to_string();
+    assert!(empty.contains("doesn't contain a handle"), "{empty}");
+    assert!(normalize("@").is_err());
+
+    for input in [
+        "someone",
+        "some one.bsky.social",
+        "-bad.bsky.social",
+        "bsky.app/feed/x",
+    ] {
+        let err = normalize(input).unwrap_err().to_string();
+        assert!(
+            err.contains("doesn't look like a valid handle"),
+            "{input}: {err}"
+        );
+    }
+}
+
+// ============================================================
+// parse_actor
+// ============================================================
+
+#[test]
+fn parse_actor_strips_at_and_lowercases() {
+    assert_eq!(
+        parse_actor("@Someone.Bsky.Social").unwrap(),
+        Actor::Handle("someone.bsky.social".to_string())
+    );
+    assert_eq!(
+        parse_actor("  alice.com ").unwrap(),
+        Actor::Handle("alice.com".to_string())
+    );
+}
+
+#[test]
+fn parse_actor_accepts_profile_urls() {
+    assert_eq!(
+        parse_actor("https://bsky.app/profile/Someone.bsky.social").unwrap(),
+        Actor::Handle("someone.bsky.social".to_string())
+    );
+    assert_eq!(
+        parse_actor("bsky.app/profile/did:plc:abc123").unwrap(),
+        Actor::Did("did:plc:abc123".to_string())
+    );
+}
+
+#[test]
+fn parse_actor_routes_dids() {
+    assert_eq!(
+        parse_actor("did:plc:abc123").unwrap(),
+        Actor::Did("did:plc:abc123".to_string())
+    );
+}
+
+#[test]
+fn parse_actor_rejects_typos_with_clear_message() {
+    let err = parse_actor("someone").unwrap_err().to_string();
+    assert!(err.contains("doesn't look like a valid handle"), "{err}");
+}
+
+// ============================================================
+// read_actor_list
+// ============================================================
+
+#[test]
+fn actor_list_normalizes_and_dedupes() {
+    let input = "@Alice.bsky.social\n\n# moderators\nbob.example.com\nalice.bsky.social\n  did:plc:abc123  \n";
+    let actors = read_actor_list(input.as_bytes()).unwrap();
+    assert_eq!(
+        actors,
+        vec![
+            Actor::Handle("alice.bsky.social".to_string()),
+            Actor::Handle("bob.example.com".to_string()),
+            Actor::Did("did:plc:abc123".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn actor_list_reports_bad_line_number() {
+    let err = read_actor_list("alice.bsky.social\nnot a handle\n".as_bytes()).unwrap_err();
+    assert!(format!("{err:#}").contains("Line 2"), "got: {err:#}");
+}
+
+#[test]
+fn empty_actor_list_is_an_error() {
+    for input in ["", "\n\n", "# only comments\n   \n"] {
+        let err = read_actor_list(input.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("No handles"), "got: {err}");
+    }
+}
+
+// ============================================================
+// parse_actor_args (score command arguments)
+// ============================================================
+
+fn args(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn actor_args_keep_order_and_dedupe() {
+    let actors = parse_actor_args(
+        &args(&["@Bob.example.com", "did:plc:abc123", "bob.example.com"]),
+        "".as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(
+        actors,
+        vec![
+            Actor::Handle("bob.example.com".to_string()),
+            Actor::Did("did:plc:abc123".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn actor_args_expand_stdin_in_place() {
+    let stdin = "carol.bsky.social\nalice.bsky.social\n";
+    let actors = parse_actor_args(
+        &args(&["alice.bsky.social", "-", "dave.bsky.social"]),
+        stdin.as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(
+        actors,
+        vec![
+            Actor::Handle("alice.bsky.social".to_string()),
+            Actor::Handle("carol.bsky.social".to_string()),
+            Actor::Handle("dave.bsky.social".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn actor_args_reject_any_bad_handle_or_repeated_stdin() {
+    let err =
+        parse_actor_args(&args(&["alice.bsky.social", "someone"]), "".as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("someone"), "got: {err}");
+
+    let err = parse_actor_args(&args(&["-", "-"]), "alice.bsky.social\n".as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("only be given once"), "got: {err}");
+}
+
+// ============================================================
+// coalesce_handles (report display)
+// ============================================================
+
+use charcoal::db::models::{AccountScore, AmplificationEvent};
+use charcoal::output::coalesce_handles;
+
+fn account(did: &str, handle: &str, scored_at: &str) -> AccountScore {
+    AccountScore {
+        did: did.to_string(),
+        handle: handle.to_string(),
+        toxicity_score: Some(0.4),
+        topic_overlap: Some(0.3),
+        threat_score: Some(20.0),
+        threat_tier: Some("Elevated".to_string()),
+        posts_analyzed: 20,
+        scored_at: scored_at.to_string(),
+        ..Default::default()
+    }
+}
+
+fn event(id: i64, did: &str, handle: &str, detected_at: &str) -> AmplificationEvent {
+    AmplificationEvent {
+        id,
+        event_type: "quote".to_string(),
+        amplifier_did: did.to_string(),
+        amplifier_handle: handle.to_string(),
+        original_post_uri: "at://did:plc:me/app.bsky.feed.post/1".to_string(),
+        amplifier_post_uri: None,
+        amplifier_text: Some("quoted".to_string()),
+        detected_at: detected_at.to_string(),
+        followers_fetched: false,
+        followers_scored: false,
+        original_post_text: None,
+        context_score: None,
+    }
+}
+
+#[test]
+fn split_did_shows_one_handle_everywhere() {
+    let did = "did:plc:split";
+    // Scored before the handle resolved; events under both representations
+    let mut accounts = vec![account(did, did, "2026-03-01 10:00:00")];
+    let mut events = vec![
+        event(2, did, "alice.bsky.social", "2026-03-02 10:00:00"),
+        event(1, did, did, "2026-03-01 09:00:00"),
+    ];
+
+    coalesce_handles(&mut accounts, &mut events);
+
+    assert_eq!(accounts[0].handle, "alice.bsky.social");
+    assert!(events
+        .iter()
+        .all(|e| e.amplifier_handle == "alice.bsky.social"));
+}
+
+#[test]
+fn coalesce_prefers_latest_handle_and_leaves_unresolved_dids() {
+    let renamed = "did:plc:renamed";
+    let unresolved = "did:plc:unresolved";
+    let mut accounts = vec![
+        account(renamed, "old.bsky.social", "2026-03-01 10:00:00"),
+        account(unresolved, unresolved, "2026-03-01 10:00:00"),
+    ];
+    let mut events = vec![
+        event(3, renamed, "new.bsky.social", "2026-03-05 10:00:00"),
+        event(2, unresolved, unresolved, "2026-03-04 10:00:00"),
+    ];
+
+    coalesce_handles(&mut accounts, &mut events);
+
+    assert_eq!(accounts[0].handle, "new.bsky.social");
+    assert_eq!(accounts[1].handle, unresolved);
+    assert_eq!(events[1].amplifier_handle, unresolved);
+}
@@ -0,0 +1,156 @@
+// Unit tests for the shared HTTP client setup.
+//
+// A throwaway local server stands in for the AppView so the request
+// headers and the number of requests in flight can be inspected without
+// network access.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use charcoal::bluesky::client::PublicAtpClient;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Accept one request on `listener`, answer it with `{}`, and return the
+/// raw request head.
+async fn capture_one_request(listener: TcpListener) -> String {
+    let (mut socket, _) = listener.accept().await.unwrap();
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = socket.read(&mut buf).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    socket
+        .write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+              Content-Length: 2\r\nConnection: close\r\n\r\n{}",
+        )
+        .await
+        .unwrap();
+    String::from_utf8_lossy(&head).to_lowercase()
+}
+
+#[test]
+fn default_user_agent_identifies_charcoal() {
+    let ua = charcoal::http::DEFAULT_USER_AGENT;
+    assert!(ua.starts_with(&format!("charcoal/{} ", env!("CARGO_PKG_VERSION"))));
+    assert!(ua.contains("https://github.com/musicjunkieg/charcoal"));
+}
+
+// The only test in this binary that sets the process-wide user-agent, so
+// it can't race another test's expectations.
+#[tokio::test]
+async fn clients_send_configured_user_agent() {
+    charcoal::http::set_user_agent("charcoal-test/1.0 (+https://example.test)");
+    assert_eq!(
+        charcoal::http::user_agent(),
+        "charcoal-test/1.0 (+https://example.test)"
+    );
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(capture_one_request(listener));
+
+    let client = PublicAtpClient::new(&base_url).unwrap();
+    let _: serde_json::Value = client
+        .xrpc_get("app.bsky.actor.getProfile", &[("actor", "someone.test")])
+        .await
+        .unwrap();
+
+    let head = server.await.unwrap();
+    assert!(
+        head.contains("\r\nuser-agent: charcoal-test/1.0 (+https://example.test)\r\n"),
+        "user-agent missing from request:\n{head}"
+    );
+}
+
+#[test]
+fn rate_limit_headers_need_both_values() {
+    use charcoal::bluesky::client::rate_limit_headers;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    let mut headers = HeaderMap::new();
+    headers.insert("ratelimit-limit", HeaderValue::from_static("3000"));
+    headers.insert("ratelimit-remaining", HeaderValue::from_static("12"));
+    assert_eq!(rate_limit_headers(&headers), None);
+
+    headers.insert("ratelimit-reset", HeaderValue::from_static("1760000000"));
+    assert_eq!(rate_limit_headers(&headers), Some((12, 1_760_000_000)));
+
+    headers.insert("ratelimit-remaining", HeaderValue::from_static("lots"));
+    assert_eq!(rate_limit_headers(&headers), None);
+}
+
+/// Answer every request on `listener` with `{}` after `delay`, recording
+/// the most requests that were open at once in `peak`.
+async fn serve_slowly(listener: TcpListener, delay: Duration, peak: Arc<AtomicUsize>) {
+    let open = Arc::new(AtomicUsize::new(0));
+    loop {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let open = Arc::clone(&open);
+        let peak = Arc::clone(&peak);
+        tokio::spawn(async move {
+            let now_open = open.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now_open, Ordering::SeqCst);
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            tokio::time::sleep(delay).await;
+            // Count the request closed before answering, so the client's
+            // next request can't be seen overlapping this one
+            open.fetch_sub(1, Ordering::SeqCst);
+            let _ = socket
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                      Content-Length: 2\r\nConnection: close\r\n\r\n{}",
+                )
+                .await;
+        });
+    }
+}
+
+#[tokio::test]
+async fn in_flight_requests_are_capped_across_tasks() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let peak = Arc::new(AtomicUsize::new(0));
+    // Slower than the rate limiter's spacing, so uncapped requests overlap
+    let server = tokio::spawn(serve_slowly(
+        listener,
+        Duration::from_millis(400),
+        Arc::clone(&peak),
+    ));
+
+    let client = PublicAtpClient::new(&base_url)
+        .unwrap()
+        .with_max_in_flight(2);
+    assert_eq!(client.max_in_flight(), 2);
+
+    let requests = (0..6).map(|_| {
+        client.xrpc_get::<serde_json::Value>("app.bsky.actor.getProfile", &[("actor", "a.test")])
+    });
+    for result in futures::future::join_all(requests).await {
+        result.unwrap();
+    }
+    server.abort();
+
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn in_flight_cap_is_at_least_one() {
+    let client = PublicAtpClient::new("http://127.0.0.1:1")
+        .unwrap()
+        .with_max_in_flight(0);
+    assert_eq!(client.max_in_flight(), 1);
+    assert_eq!(
+        PublicAtpClient::new("http://127.0.0.1:1")
+            .unwrap()
+            .max_in_flight(),
+        charcoal::bluesky::client::DEFAULT_MAX_IN_FLIGHT_REQUESTS
+    );
+}
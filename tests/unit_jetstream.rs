@@ -0,0 +1,125 @@
+// Unit tests for Jetstream firehose message handling.
+//
+// Covers subscription URLs and turning commit events into amplification
+// events — all without network access.
+
+use std::collections::HashSet;
+
+use charcoal::bluesky::jetstream::{amplification_event, parse_message, subscribe_url};
+
+const ME: &str = "did:plc:me";
+const WATCHED_POST: &str = "at://did:plc:me/app.bsky.feed.post/3mfyouuy62222";
+
+fn watched() -> HashSet<String> {
+    [WATCHED_POST.to_string()].into()
+}
+
+fn commit(did: &str, collection: &str, operation: &str, record: &str) -> String {
+    format!(
+        r#"{{"did":"{did}","time_us":1772366400000000,"kind":"commit",
+            "commit":{{"rev":"x","operation":"{operation}","collection":"{collection}",
+            "rkey":"3mg37dsgw2222","record":{record},"cid":"bafy"}}}}"#
+    )
+}
+
+#[test]
+fn subscribe_url_requests_posts_and_reposts() {
+    assert_eq!(
+        subscribe_url("wss://js.example/subscribe", None),
+        "wss://js.example/subscribe?wantedCollections=app.bsky.feed.post\
+         &wantedCollections=app.bsky.feed.repost"
+    );
+    assert!(
+        subscribe_url("wss://js.example/subscribe?compress=false", Some(42)).ends_with(
+            "?compress=false&wantedCollections=app.bsky.feed.post\
+                    &wantedCollections=app.bsky.feed.repost&cursor=42"
+        )
+    );
+}
+
+#[test]
+fn repost_of_watched_post_becomes_event() {
+    let text = commit(
+        "did:plc:amp",
+        "app.bsky.feed.repost",
+        "create",
+        &format!(r#"{{"subject":{{"uri":"{WATCHED_POST}","cid":"bafy"}}}}"#),
+    );
+    let message = parse_message(&text).unwrap();
+    let event = amplification_event(&message, &watched(), ME).unwrap();
+
+    assert_eq!(event.event_type, "repost");
+    assert_eq!(event.amplifier_did, "did:plc:amp");
+    assert_eq!(event.original_post_uri.as_deref(), Some(WATCHED_POST));
+    assert_eq!(
+        event.amplifier_post_uri,
+        "at://did:plc:amp/app.bsky.feed.repost/3mg37dsgw2222"
+    );
+    assert!(event.occurred_at.is_some());
+}
+
+#[test]
+fn quotes_with_and_without_media_become_events() {
+    let plain = format!(
+        r#"{{"text":"lol","embed":{{"$type":"app.bsky.embed.record",
+            "record":{{"uri":"{WATCHED_POST}","cid":"bafy"}}}}}}"#
+    );
+    let with_media = format!(
+        r#"{{"text":"lol","embed":{{"$type":"app.bsky.embed.recordWithMedia",
+            "record":{{"record":{{"uri":"{WATCHED_POST}","cid":"bafy"}}}},
+            "media":{{"$type":"app.bsky.embed.images","images":[]}}}}}}"#
+    );
+
+    for record in [plain, with_media] {
+        let text = commit("did:plc:amp", "app.bsky.feed.post", "create", &record);
+        let event = amplification_event(&parse_message(&text).unwrap(), &watched(), ME).unwrap();
+        assert_eq!(event.event_type, "quote");
+        assert_eq!(event.original_post_uri.as_deref(), Some(WATCHED_POST));
+    }
+}
+
+#[test]
+fn unrelated_messages_are_ignored() {
+    let repost_of = |uri: &str| format!(r#"{{"subject":{{"uri":"{uri}","cid":"bafy"}}}}"#);
+
+    // Someone else's post
+    let other = commit(
+        "did:plc:amp",
+        "app.bsky.feed.repost",
+        "create",
+        &repost_of("at://did:plc:other/app.bsky.feed.post/1"),
+    );
+    // The protected user reposting themselves
+    let own = commit(
+        ME,
+        "app.bsky.feed.repost",
+        "create",
+        &repost_of(WATCHED_POST),
+    );
+    // Deletes aren't new amplification
+    let delete = commit(
+        "did:plc:amp",
+        "app.bsky.feed.repost",
+        "delete",
+        &repost_of(WATCHED_POST),
+    );
+    // A plain post with no embed
+    let plain = commit(
+        "did:plc:amp",
+        "app.bsky.feed.post",
+        "create",
+        r#"{"text":"hello"}"#,
+    );
+
+    for text in [other, own, delete, plain] {
+        let message = parse_message(&text).unwrap();
+        assert!(amplification_event(&message, &watched(), ME).is_none());
+    }
+
+    let identity = r#"{"did":"did:plc:amp","time_us":1,"kind":"identity","identity":{}}"#;
+    let message = parse_message(identity).unwrap();
+    assert!(message.commit.is_none());
+    assert!(amplification_event(&message, &watched(), ME).is_none());
+
+    assert!(parse_message("not json").is_none());
+}
@@ -0,0 +1,158 @@
+// Unit tests for labeler verdicts: queryLabels parsing, which labels count
+// against an account, and the threat weight they carry.
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+use charcoal::bluesky::labels::{
+    label_weight, negative_labels, parse_query_labels_response, AccountLabel, DEFAULT_LABELER_DID,
+    LABEL_THREAT_WEIGHT,
+};
+
+fn now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-06-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn label(src: &str, val: &str, cts: &str) -> AccountLabel {
+    AccountLabel {
+        src: src.to_string(),
+        val: val.to_string(),
+        neg: false,
+        cts: cts.to_string(),
+        exp: None,
+    }
+}
+
+fn trusted() -> Vec<String> {
+    vec![DEFAULT_LABELER_DID.to_string()]
+}
+
+// ============================================================
+// queryLabels response parsing
+// ============================================================
+
+#[test]
+fn parses_labels_and_skips_incomplete_ones() {
+    let response = json!({
+        "cursor": "42",
+        "labels": [
+            {
+                "ver": 1,
+                "src": DEFAULT_LABELER_DID,
+                "uri": "did:plc:target",
+                "val": "spam",
+                "cts": "2026-05-01T12:00:00.000Z"
+            },
+            {
+                "src": DEFAULT_LABELER_DID,
+                "uri": "did:plc:target",
+                "val": "spam",
+                "neg": true,
+                "cts": "2026-05-02T12:00:00.000Z",
+                "exp": "2027-01-01T00:00:00.000Z"
+            },
+            { "src": DEFAULT_LABELER_DID, "uri": "did:plc:target" },
+            { "val": "rude", "uri": "did:plc:target" }
+        ]
+    });
+
+    let labels = parse_query_labels_response(&response);
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels[0].val, "spam");
+    assert!(!labels[0].neg);
+    assert!(labels[0].exp.is_none());
+    assert!(labels[1].neg);
+    assert_eq!(labels[1].exp.as_deref(), Some("2027-01-01T00:00:00.000Z"));
+}
+
+#[test]
+fn response_without_labels_is_empty() {
+    assert!(parse_query_labels_response(&json!({})).is_empty());
+    assert!(parse_query_labels_response(&json!({ "labels": [] })).is_empty());
+}
+
+// ============================================================
+// Which labels count
+// ============================================================
+
+#[test]
+fn only_negative_labels_from_trusted_labelers_count() {
+    let labels = vec![
+        label(DEFAULT_LABELER_DID, "spam", "2026-05-01T00:00:00Z"),
+        label(DEFAULT_LABELER_DID, "impersonation", "2026-05-01T00:00:00Z"),
+        // Content warnings aren't hostility
+        label(DEFAULT_LABELER_DID, "porn", "2026-05-01T00:00:00Z"),
+        // Nobody asked this labeler
+        label("did:plc:stranger", "intolerant", "2026-05-01T00:00:00Z"),
+    ];
+
+    assert_eq!(
+        negative_labels(&labels, &trusted(), now()),
+        vec!["impersonation".to_string(), "spam".to_string()]
+    );
+    assert!(negative_labels(&labels, &[], now()).is_empty());
+}
+
+#[test]
+fn later_negation_retracts_a_label() {
+    let retracted = AccountLabel {
+        neg: true,
+        ..label(DEFAULT_LABELER_DID, "spam", "2026-05-02T00:00:00Z")
+    };
+    // Out of order on purpose — creation time decides
+    let labels = vec![
+        retracted,
+        label(DEFAULT_LABELER_DID, "spam", "2026-05-01T00:00:00Z"),
+    ];
+    assert!(negative_labels(&labels, &trusted(), now()).is_empty());
+
+    // Re-applied after the negation, it counts again
+    let mut reapplied = labels.clone();
+    reapplied.push(label(DEFAULT_LABELER_DID, "spam", "2026-05-03T00:00:00Z"));
+    assert_eq!(
+        negative_labels(&reapplied, &trusted(), now()),
+        vec!["spam".to_string()]
+    );
+}
+
+#[test]
+fn expired_labels_do_not_count() {
+    let expired = AccountLabel {
+        exp: Some("2026-05-15T00:00:00.000Z".to_string()),
+        ..label(DEFAULT_LABELER_DID, "rude", "2026-05-01T00:00:00Z")
+    };
+    let current = AccountLabel {
+        exp: Some("2026-07-01T00:00:00.000Z".to_string()),
+        ..label(DEFAULT_LABELER_DID, "threat", "2026-05-01T00:00:00Z")
+    };
+    assert_eq!(
+        negative_labels(&[expired, current], &trusted(), now()),
+        vec!["threat".to_string()]
+    );
+}
+
+#[test]
+fn same_value_from_two_labelers_is_listed_once() {
+    let second = "did:plc:otherlabeler".to_string();
+    let labels = vec![
+        label(DEFAULT_LABELER_DID, "spam", "2026-05-01T00:00:00Z"),
+        label(&second, "spam", "2026-05-01T00:00:00Z"),
+    ];
+    let both = vec![DEFAULT_LABELER_DID.to_string(), second];
+    assert_eq!(
+        negative_labels(&labels, &both, now()),
+        vec!["spam".to_string()]
+    );
+}
+
+// ============================================================
+// Threat weight
+// ============================================================
+
+#[test]
+fn unlabeled_accounts_are_unweighted() {
+    assert_eq!(label_weight(&[]), 1.0);
+    assert_eq!(label_weight(&["spam".to_string()]), LABEL_THREAT_WEIGHT);
+}
@@ -78,13 +78,9 @@ fn account_score_has_context_score() {
         threat_score: Some(25.0),
         threat_tier: Some("Elevated".to_string()),
         posts_analyzed: 10,
-        top_toxic_posts: vec![],
         scored_at: "2026-03-19T12:00:00Z".to_string(),
-        behavioral_signals: None,
         context_score: Some(0.65),
-        graph_distance: None,
-        fingerprint_quality: None,
-        scoring_confidence: None,
+        ..Default::default()
     };
     assert_eq!(score.context_score, Some(0.65));
 }
@@ -94,18 +90,8 @@ fn account_score_context_score_defaults_none() {
     let score = AccountScore {
         did: "did:plc:test".to_string(),
         handle: "test.bsky.social".to_string(),
-        toxicity_score: None,
-        topic_overlap: None,
-        threat_score: None,
-        threat_tier: None,
-        posts_analyzed: 0,
-        top_toxic_posts: vec![],
         scored_at: "2026-03-19T12:00:00Z".to_string(),
-        behavioral_signals: None,
-        context_score: None,
-        graph_distance: None,
-        fingerprint_quality: None,
-        scoring_confidence: None,
+        ..Default::default()
     };
     assert!(score.context_score.is_none());
 }
@@ -323,13 +309,7 @@ fn make_score(did: &str, handle: &str, threat_score: f64, tier: &str) -> Account
         threat_score: Some(threat_score),
         threat_tier: Some(tier.to_string()),
         posts_analyzed: 10,
-        top_toxic_posts: vec![],
-        scored_at: String::new(),
-        behavioral_signals: None,
-        context_score: None,
-        graph_distance: None,
-        fingerprint_quality: None,
-        scoring_confidence: None,
+        ..Default::default()
     }
 }
 
@@ -406,18 +386,21 @@ async fn trait_get_unlabeled_accounts() {
     db.upsert_account_score(
         TEST_USER,
         &make_score("did:plc:a", "a.bsky.social", 40.0, "High"),
+        None,
     )
     .await
     .unwrap();
     db.upsert_account_score(
         TEST_USER,
         &make_score("did:plc:b", "b.bsky.social", 20.0, "Elevated"),
+        None,
     )
     .await
     .unwrap();
     db.upsert_account_score(
         TEST_USER,
         &make_score("did:plc:c", "c.bsky.social", 5.0, "Low"),
+        None,
     )
     .await
     .unwrap();
@@ -462,24 +445,28 @@ async fn trait_get_accuracy_metrics_mixed() {
     db.upsert_account_score(
         TEST_USER,
         &make_score("did:plc:a", "a.bsky.social", 40.0, "High"),
+        None,
     )
     .await
     .unwrap();
     db.upsert_account_score(
         TEST_USER,
         &make_score("did:plc:b", "b.bsky.social", 20.0, "Elevated"),
+        None,
     )
     .await
     .unwrap();
     db.upsert_account_score(
         TEST_USER,
         &make_score("did:plc:c", "c.bsky.social", 5.0, "Low"),
+        None,
     )
     .await
     .unwrap();
     db.upsert_account_score(
         TEST_USER,
         &make_score("did:plc:d", "d.bsky.social", 10.0, "Watch"),
+        None,
     )
     .await
     .unwrap();
@@ -0,0 +1,128 @@
+// tests/unit_language.rs
+//
+// Tests for post-level language filtering ahead of toxicity scoring.
+
+use charcoal::bluesky::posts::{Post, PostSample, ReplyPost};
+use charcoal::toxicity::language::{is_scorable, retain_scorable};
+
+fn post(text: &str, langs: &[&str]) -> Post {
+    Post {
+        uri: format!("at://did:plc:test/app.bsky.feed.post/{}", text.len()),
+        text: text.to_string(),
+        created_at: None,
+        like_count: 0,
+        repost_count: 0,
+        quote_count: 0,
+        is_quote: false,
+        langs: langs.iter().map(|l| l.to_string()).collect(),
+    }
+}
+
+// ============================================================
+// Single-post decisions
+// ============================================================
+
+#[test]
+fn declared_english_is_scorable() {
+    assert!(is_scorable(&post("hello there", &["en"])));
+    assert!(is_scorable(&post("hello there", &["en-US"])));
+    assert!(is_scorable(&post("hello there", &["EN-gb"])));
+}
+
+#[test]
+fn declared_other_language_is_skipped() {
+    // Declared langs win over the text itself
+    assert!(!is_scorable(&post("hello there", &["es"])));
+    assert!(!is_scorable(&post("こんにちは", &["ja"])));
+}
+
+#[test]
+fn any_declared_english_tag_is_enough() {
+    assert!(is_scorable(&post("hola, hello", &["es", "en"])));
+}
+
+#[test]
+fn undeclared_clear_spanish_is_skipped() {
+    let text = "No puedo creer lo que dijiste ayer en la reunión, de verdad \
+                me parece una falta de respeto hacia todos nosotros.";
+    assert!(!is_scorable(&post(text, &[])));
+}
+
+#[test]
+fn undeclared_clear_english_is_scorable() {
+    let text = "I can't believe what you said at the meeting yesterday, it \
+                honestly feels disrespectful to everyone who was there.";
+    assert!(is_scorable(&post(text, &[])));
+}
+
+#[test]
+fn undeclared_ambiguous_text_stays_scorable() {
+    // Too short to detect confidently — keep rather than thin out English accounts
+    assert!(is_scorable(&post("lol", &[])));
+    assert!(is_scorable(&post("", &[])));
+}
+
+// ============================================================
+// Mixed-language sample
+// ============================================================
+
+#[test]
+fn mixed_language_sample_keeps_only_english() {
+    let sample = PostSample {
+        originals: vec![
+            post("Great thread about transit policy", &["en"]),
+            post("Qué día tan bonito hace hoy en Madrid", &["es"]),
+            post(
+                "Me encanta pasar los domingos leyendo libros en el parque con mis amigos.",
+                &[],
+            ),
+        ],
+        replies: vec![
+            ReplyPost {
+                post: post("You clearly didn't read the article", &["en-US"]),
+                parent_uri: "at://did:plc:other/app.bsky.feed.post/1".to_string(),
+            },
+            ReplyPost {
+                post: post("No entiendes nada", &["es"]),
+                parent_uri: "at://did:plc:other/app.bsky.feed.post/2".to_string(),
+            },
+        ],
+        quotes: vec![post("This take is wild", &["en"])],
+        reply_ratio: 2.0 / 6.0,
+        quote_ratio: 1.0 / 6.0,
+        total_posts: 6,
+    };
+
+    let (scorable, skipped) = retain_scorable(&sample);
+
+    assert_eq!(skipped, 3);
+    assert_eq!(scorable.total_posts, 3);
+    assert_eq!(scorable.originals.len(), 1);
+    assert_eq!(scorable.replies.len(), 1);
+    assert_eq!(scorable.quotes.len(), 1);
+    assert_eq!(
+        scorable.replies[0].parent_uri,
+        "at://did:plc:other/app.bsky.feed.post/1"
+    );
+    // Ratios still describe the whole feed, not the filtered subset
+    assert!((scorable.reply_ratio - 2.0 / 6.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn all_foreign_sample_has_nothing_to_score() {
+    let sample = PostSample {
+        originals: vec![
+            post("Bom dia a todos", &["pt"]),
+            post("Guten Morgen", &["de"]),
+        ],
+        replies: vec![],
+        quotes: vec![],
+        reply_ratio: 0.0,
+        quote_ratio: 0.0,
+        total_posts: 2,
+    };
+
+    let (scorable, skipped) = retain_scorable(&sample);
+    assert_eq!(scorable.total_posts, 0);
+    assert_eq!(skipped, 2);
+}
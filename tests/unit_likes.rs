@@ -22,6 +22,7 @@ fn like_events_have_no_amplifier_post_uri() {
         original_post_uri: Some("at://did:plc:user/app.bsky.feed.post/abc".to_string()),
         amplifier_post_uri: String::new(), // no post URI for likes
         indexed_at: String::new(),
+        occurred_at: None,
     };
     assert_eq!(like.event_type, "like");
     assert!(like.amplifier_post_uri.is_empty());
@@ -0,0 +1,118 @@
+// Unit tests for loading the ONNX models: execution provider selection
+// (CHARCOAL_ONNX_PROVIDER) and retries of transient load failures.
+//
+// The CPU provider is always available, and a GPU provider that can't be
+// used (not compiled in, or no runtime on this machine) falls back to the
+// CPU rather than failing. Only allocation-type failures are retried; a
+// missing model fails straight away. The model smoke test needs the
+// downloaded models, so it's ignored by default.
+
+use std::cell::Cell;
+use std::time::Instant;
+
+use charcoal::config::OnnxProvider;
+use charcoal::toxicity::onnx::{
+    load_with_retry, session_builder, OnnxToxicityScorer, SESSION_LOAD_ATTEMPTS,
+    SESSION_LOAD_RETRY_DELAY,
+};
+use charcoal::toxicity::traits::ToxicityScorer;
+
+// ============================================================
+// session_builder
+// ============================================================
+
+#[test]
+fn cpu_session_builder_is_available() {
+    assert!(session_builder(OnnxProvider::Cpu).is_ok());
+}
+
+#[test]
+fn unusable_providers_fall_back_to_cpu() {
+    // CI has no GPU, so each of these either isn't compiled in or can't
+    // find its runtime — either way loading should carry on on the CPU
+    for provider in [
+        OnnxProvider::Cuda,
+        OnnxProvider::CoreMl,
+        OnnxProvider::DirectMl,
+    ] {
+        assert!(
+            session_builder(provider).is_ok(),
+            "{} should fall back to CPU",
+            provider.name()
+        );
+    }
+}
+
+// ============================================================
+// load_with_retry
+// ============================================================
+
+#[test]
+fn missing_model_fails_without_retrying() {
+    let dir = std::env::temp_dir().join("charcoal-onnx-test-no-model");
+    let started = Instant::now();
+
+    let err = OnnxToxicityScorer::load(&dir, OnnxProvider::Cpu)
+        .err()
+        .unwrap();
+
+    assert!(started.elapsed() < SESSION_LOAD_RETRY_DELAY);
+    assert!(
+        err.to_string().contains("charcoal download-model"),
+        "got: {err}"
+    );
+}
+
+#[test]
+fn transient_load_failures_are_retried() {
+    let calls = Cell::new(0);
+    let result = load_with_retry(|| {
+        calls.set(calls.get() + 1);
+        if calls.get() < SESSION_LOAD_ATTEMPTS {
+            anyhow::bail!("Failed to allocate memory for requested buffer of size 1024")
+        }
+        Ok("session")
+    });
+
+    assert_eq!(result.unwrap(), "session");
+    assert_eq!(calls.get(), SESSION_LOAD_ATTEMPTS);
+}
+
+#[test]
+fn retries_stop_after_the_last_attempt() {
+    let calls = Cell::new(0);
+    let result: anyhow::Result<()> = load_with_retry(|| {
+        calls.set(calls.get() + 1);
+        anyhow::bail!("std::bad_alloc")
+    });
+
+    assert!(result.is_err());
+    assert_eq!(calls.get(), SESSION_LOAD_ATTEMPTS);
+}
+
+#[test]
+fn other_load_failures_are_not_retried() {
+    let calls = Cell::new(0);
+    let result: anyhow::Result<()> = load_with_retry(|| {
+        calls.set(calls.get() + 1);
+        anyhow::bail!("Load model failed: Protobuf parsing failed")
+    });
+
+    assert!(result.is_err());
+    assert_eq!(calls.get(), 1);
+}
+
+// ============================================================
+// Model smoke test
+// ============================================================
+
+#[tokio::test]
+#[ignore = "requires the ONNX models — run `charcoal download-model` first"]
+async fn toxicity_model_scores_on_cpu() {
+    let model_dir = charcoal::toxicity::download::default_model_dir();
+    let scorer = OnnxToxicityScorer::load(&model_dir, OnnxProvider::Cpu).unwrap();
+
+    let result = scorer.score_text("Have a lovely day!").await.unwrap();
+
+    assert!((0.0..=1.0).contains(&result.toxicity));
+}
@@ -0,0 +1,121 @@
+// Unit tests for cursor pagination with prefetch.
+//
+// Drives bluesky::pagination::prefetch_pages with a mock listing whose page
+// requests and page processing both take time, then checks that the two
+// overlap, that exactly the target number of items is collected, and that
+// no page past the target is requested.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use charcoal::bluesky::pagination::{prefetch_pages, Page};
+
+const PAGE_LATENCY: Duration = Duration::from_millis(60);
+
+type PageFuture<'a> = Pin<Box<dyn Future<Output = anyhow::Result<Page<usize>>> + 'a>>;
+
+/// A listing of `pages` pages of `per_page` numbered items, where every
+/// request takes `PAGE_LATENCY`. Counts the requests made.
+fn mock_listing(
+    pages: usize,
+    per_page: usize,
+    requests: &AtomicUsize,
+) -> impl FnMut(Option<String>) -> PageFuture<'_> {
+    move |cursor| {
+        Box::pin(async move {
+            requests.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(PAGE_LATENCY).await;
+            let index: usize = cursor.map(|c| c.parse().unwrap()).unwrap_or(0);
+            let start = index * per_page;
+            Ok(Page {
+                items: (start..start + per_page).collect(),
+                cursor: (index + 1 < pages).then(|| (index + 1).to_string()),
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn next_page_is_fetched_while_the_current_one_is_processed() {
+    let requests = AtomicUsize::new(0);
+    let mut collected = Vec::new();
+
+    let started = Instant::now();
+    prefetch_pages(40, mock_listing(4, 10, &requests), |items| {
+        // Stand-in for decoding and cleaning a page of posts
+        std::thread::sleep(PAGE_LATENCY);
+        collected.extend(items);
+        collected.len()
+    })
+    .await
+    .unwrap();
+    let elapsed = started.elapsed();
+
+    assert_eq!(collected, (0..40).collect::<Vec<_>>());
+    assert_eq!(requests.load(Ordering::SeqCst), 4);
+    // Serially this is 4 requests + 4 pages processed = 8 × latency. With
+    // prefetch only the first request is waited on: about 5 × latency.
+    assert!(
+        elapsed < PAGE_LATENCY * 7,
+        "expected requests to overlap processing, took {elapsed:?}"
+    );
+}
+
+#[tokio::test]
+async fn collects_exactly_the_target_without_fetching_past_it() {
+    let requests = AtomicUsize::new(0);
+    let mut collected = Vec::new();
+
+    prefetch_pages(35, mock_listing(10, 10, &requests), |items| {
+        let room = 35 - collected.len();
+        collected.extend(items.into_iter().take(room));
+        collected.len()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(collected.len(), 35);
+    assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+    // A target on a page boundary stops right there, too
+    let requests = AtomicUsize::new(0);
+    let mut count = 0;
+    prefetch_pages(20, mock_listing(10, 10, &requests), |items| {
+        count += items.len();
+        count
+    })
+    .await
+    .unwrap();
+    assert_eq!(count, 20);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn filtered_pages_keep_paginating_until_the_listing_ends() {
+    let requests = AtomicUsize::new(0);
+    let mut kept = Vec::new();
+
+    // Only even items count, so 3 pages of 10 yield 15 — short of 100
+    prefetch_pages(100, mock_listing(3, 10, &requests), |items| {
+        kept.extend(items.into_iter().filter(|i| i % 2 == 0));
+        kept.len()
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(kept.len(), 15);
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn zero_target_makes_no_requests() {
+    let requests = AtomicUsize::new(0);
+    prefetch_pages(0, mock_listing(3, 10, &requests), |_| -> usize {
+        unreachable!()
+    })
+    .await
+    .unwrap();
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+}
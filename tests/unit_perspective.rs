@@ -0,0 +1,119 @@
+// Unit tests for Perspective batch scoring.
+//
+// PerspectiveScorer is pointed at a throwaway local server that answers
+// each comments:analyze request with the toxicity written in the comment
+// text itself ("0.25" scores 0.25), so results can be matched to inputs.
+// A comment of "fail" gets a 500 instead.
+
+use charcoal::toxicity::perspective::PerspectiveScorer;
+use charcoal::toxicity::traits::ToxicityScorer;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Read one HTTP request and return its body.
+async fn read_body(socket: &mut tokio::net::TcpStream) -> String {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        if let Some(end) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&data[..end]).to_ascii_lowercase();
+            let length: usize = head
+                .lines()
+                .find_map(|l| l.strip_prefix("content-length:"))
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(0);
+            if data.len() >= end + 4 + length {
+                return String::from_utf8_lossy(&data[end + 4..end + 4 + length]).into_owned();
+            }
+        }
+        let n = socket.read(&mut buf).await.unwrap();
+        if n == 0 {
+            return String::new();
+        }
+        data.extend_from_slice(&buf[..n]);
+    }
+}
+
+/// Serve Perspective-shaped responses on a local port. Returns the base URL.
+async fn mock_perspective() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let body = read_body(&mut socket).await;
+                let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+                let text = request["comment"]["text"].as_str().unwrap().to_string();
+
+                let (status, payload) = match text.parse::<f64>() {
+                    Ok(value) => (
+                        "200 OK",
+                        serde_json::json!({
+                            "attributeScores": {
+                                "TOXICITY": {"summaryScore": {"value": value}},
+                                "INSULT": {"summaryScore": {"value": value / 2.0}}
+                            }
+                        })
+                        .to_string(),
+                    ),
+                    Err(_) => ("500 Internal Server Error", "{}".to_string()),
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{payload}",
+                    payload.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    base_url
+}
+
+fn texts(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+// ============================================================
+// score_batch — ordering and partial failures
+// ============================================================
+
+#[tokio::test]
+async fn batch_returns_one_result_per_text_in_order() {
+    let base_url = mock_perspective().await;
+    let scorer = PerspectiveScorer::new("test-key".to_string())
+        .with_base_url(&base_url)
+        .with_concurrency(3);
+
+    let input = texts(&["0.9", "0.1", "0.5"]);
+    let results = scorer.score_batch(&input).await.unwrap();
+
+    let scores: Vec<f64> = results.iter().map(|r| r.toxicity).collect();
+    assert_eq!(scores, vec![0.9, 0.1, 0.5]);
+    assert_eq!(results[0].attributes.insult, Some(0.45));
+}
+
+#[tokio::test]
+async fn failed_text_errors_only_its_own_index() {
+    let base_url = mock_perspective().await;
+    let scorer = PerspectiveScorer::new("test-key".to_string())
+        .with_base_url(&base_url)
+        .with_concurrency(3);
+
+    let input = texts(&["0.2", "fail", "0.7"]);
+    let results = scorer.score_each(&input).await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().toxicity, 0.2);
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().toxicity, 0.7);
+
+    // Through the trait, the batch fails and says which text broke it
+    let err = scorer.score_batch(&input).await.unwrap_err();
+    assert!(
+        format!("{err:#}").contains("1 of 3 texts (indices [1])"),
+        "got: {err:#}"
+    );
+}
@@ -0,0 +1,140 @@
+// Unit tests for the post-recency window (`--max-post-age-days`).
+//
+// Tests retain_recent filtering, the sparse-activity weight and the labels
+// reports use to record the window.
+
+use chrono::{TimeZone, Utc};
+
+use charcoal::bluesky::posts::{Post, PostSample, ReplyPost};
+use charcoal::scoring::behavioral::BehavioralSignals;
+use charcoal::scoring::recency::{
+    post_window_note, recency_weight, report_window_label, retain_recent, MIN_RECENCY_WEIGHT,
+};
+
+fn post(id: &str, created_at: Option<&str>) -> Post {
+    Post {
+        uri: format!("at://did:plc:test/app.bsky.feed.post/{id}"),
+        text: format!("Test post {id}"),
+        created_at: created_at.map(str::to_string),
+        like_count: 0,
+        repost_count: 0,
+        quote_count: 0,
+        is_quote: false,
+        langs: vec![],
+    }
+}
+
+fn sample() -> PostSample {
+    PostSample {
+        originals: vec![
+            post("new", Some("2026-10-10T12:00:00.000Z")),
+            post("old", Some("2023-01-01T12:00:00.000Z")),
+            post("undated", None),
+        ],
+        replies: vec![ReplyPost {
+            post: post("old-reply", Some("2024-05-01T12:00:00Z")),
+            parent_uri: "at://did:plc:other/app.bsky.feed.post/1".to_string(),
+        }],
+        quotes: vec![post("new-quote", Some("2026-10-01T00:00:00+02:00"))],
+        reply_ratio: 0.2,
+        quote_ratio: 0.2,
+        total_posts: 5,
+    }
+}
+
+// ============================================================
+// retain_recent
+// ============================================================
+
+#[test]
+fn window_drops_posts_older_than_cutoff() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap();
+    let (recent, dropped) = retain_recent(&sample(), Some(90), now);
+
+    assert_eq!(dropped, 2);
+    assert_eq!(recent.total_posts, 3);
+    // Undated posts stay: nothing says they're old
+    let uris: Vec<&str> = recent.originals.iter().map(|p| p.uri.as_str()).collect();
+    assert!(uris.iter().any(|u| u.ends_with("/new")));
+    assert!(uris.iter().any(|u| u.ends_with("/undated")));
+    assert!(recent.replies.is_empty());
+    assert_eq!(recent.quotes.len(), 1);
+    // Ratios describe the posts kept
+    assert_eq!(recent.reply_ratio, 0.0);
+    assert!((recent.quote_ratio - 1.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn no_window_keeps_everything() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap();
+    let (all, dropped) = retain_recent(&sample(), None, now);
+    assert_eq!(dropped, 0);
+    assert_eq!(all.total_posts, 5);
+    assert_eq!(all.reply_ratio, 0.2);
+}
+
+#[test]
+fn window_past_the_earliest_time_keeps_everything() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).unwrap();
+    let (all, dropped) = retain_recent(&sample(), Some(u32::MAX), now);
+    assert_eq!(dropped, 0);
+    assert_eq!(all.total_posts, 5);
+}
+
+// ============================================================
+// recency_weight
+// ============================================================
+
+#[test]
+fn sparse_recent_activity_is_down_weighted() {
+    assert_eq!(recency_weight(0), MIN_RECENCY_WEIGHT);
+    assert!((recency_weight(5) - 0.75).abs() < 1e-9);
+    assert_eq!(recency_weight(10), 1.0);
+    assert_eq!(recency_weight(50), 1.0);
+}
+
+// ============================================================
+// Report labels
+// ============================================================
+
+#[test]
+fn window_note_records_window_and_effects() {
+    assert_eq!(post_window_note(&BehavioralSignals::default()), None);
+
+    let windowed = BehavioralSignals {
+        post_window_days: Some(90),
+        posts_outside_window: 12,
+        recency_weight: Some(0.85),
+        ..BehavioralSignals::default()
+    };
+    assert_eq!(
+        post_window_note(&windowed).as_deref(),
+        Some("last 90 days, 12 older posts left out, score ×0.85 for sparse recent activity")
+    );
+
+    let active = BehavioralSignals {
+        post_window_days: Some(30),
+        recency_weight: Some(1.0),
+        ..BehavioralSignals::default()
+    };
+    assert_eq!(post_window_note(&active).as_deref(), Some("last 30 days"));
+}
+
+#[test]
+fn report_label_summarizes_windows_across_accounts() {
+    let unlimited = BehavioralSignals::default();
+    let ninety = BehavioralSignals {
+        post_window_days: Some(90),
+        ..BehavioralSignals::default()
+    };
+
+    assert_eq!(report_window_label([&unlimited]), None);
+    assert_eq!(
+        report_window_label([&ninety, &ninety]).as_deref(),
+        Some("last 90 days")
+    );
+    assert_eq!(
+        report_window_label([&ninety, &unlimited]).as_deref(),
+        Some("last 90 days, unlimited (varies by account)")
+    );
+}
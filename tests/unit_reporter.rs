@@ -0,0 +1,38 @@
+// Unit tests for the --json status reporter.
+//
+// Checks the NDJSON line format: one object per line, `event` and
+// `timestamp` always present, and caller fields merged alongside them.
+
+use charcoal::output::reporter::{json_line, Reporter};
+use serde_json::{json, Value};
+
+#[test]
+fn json_line_merges_fields_after_event_and_timestamp() {
+    let line = json_line(
+        "event_detected",
+        json!({ "event_type": "quote", "amplifier_handle": "a.bsky.social" }),
+    );
+    assert!(!line.contains('\n'), "status events must be one line each");
+
+    let parsed: Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(parsed["event"], "event_detected");
+    assert_eq!(parsed["event_type"], "quote");
+    assert_eq!(parsed["amplifier_handle"], "a.bsky.social");
+    let timestamp = parsed["timestamp"].as_str().unwrap();
+    assert!(chrono::DateTime::parse_from_rfc3339(timestamp).is_ok());
+}
+
+#[test]
+fn json_line_keeps_non_object_fields_under_value() {
+    let parsed: Value = serde_json::from_str(&json_line("count", json!(3))).unwrap();
+    assert_eq!(parsed["value"], 3);
+
+    let parsed: Value = serde_json::from_str(&json_line("ping", Value::Null)).unwrap();
+    assert_eq!(parsed.as_object().unwrap().len(), 2);
+}
+
+#[test]
+fn reporter_hides_progress_bars_in_json_mode() {
+    assert!(Reporter::new(true).progress_bar(10, "{pos}").is_hidden());
+    assert!(!Reporter::human().is_json());
+}
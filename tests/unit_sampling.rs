@@ -124,6 +124,7 @@ fn parent_uri_deduplication() {
             repost_count: 0,
             quote_count: 0,
             is_quote: false,
+            langs: vec![],
         },
         parent_uri: "at://did:plc:other/app.bsky.feed.post/0".to_string(),
     });
@@ -136,6 +137,60 @@ fn parent_uri_deduplication() {
     );
 }
 
+#[test]
+fn last_post_at_picks_newest_across_post_types() {
+    let mut originals = make_posts(2);
+    originals[0].created_at = Some("2026-09-01T10:00:00.000Z".to_string());
+    let mut replies = make_reply_posts(1);
+    // Newest, but written with an offset — normalized to UTC
+    replies[0].post.created_at = Some("2026-10-02T01:30:00+02:00".to_string());
+    let mut quotes = make_posts(1);
+    quotes[0].created_at = Some("not a timestamp".to_string());
+
+    let sample = PostSample {
+        originals,
+        replies,
+        quotes,
+        reply_ratio: 0.25,
+        quote_ratio: 0.25,
+        total_posts: 4,
+    };
+    assert_eq!(
+        sample.last_post_at().as_deref(),
+        Some("2026-10-01T23:30:00Z")
+    );
+
+    let undated = PostSample {
+        originals: make_posts(3),
+        replies: vec![],
+        quotes: vec![],
+        reply_ratio: 0.0,
+        quote_ratio: 0.0,
+        total_posts: 3,
+    };
+    assert!(undated.last_post_at().is_none());
+}
+
+#[test]
+fn extra_replies_join_sample_without_duplicates() {
+    let sample = PostSample {
+        originals: make_posts(8),
+        replies: make_reply_posts(2),
+        quotes: vec![],
+        reply_ratio: 0.2,
+        quote_ratio: 0.0,
+        total_posts: 10,
+    };
+
+    // The first two extra replies are already in the sample
+    let merged = sample.with_extra_replies(make_reply_posts(5));
+    assert_eq!(merged.replies.len(), 5);
+    assert_eq!(merged.total_posts, 13);
+    assert_eq!(merged.originals.len(), 8);
+    // Ratios still describe the feed sample
+    assert!((merged.reply_ratio - 0.2).abs() < 0.001);
+}
+
 fn make_posts(n: usize) -> Vec<Post> {
     (0..n)
         .map(|i| Post {
@@ -146,6 +201,7 @@ fn make_posts(n: usize) -> Vec<Post> {
             repost_count: 0,
             quote_count: 0,
             is_quote: false,
+            langs: vec![],
         })
         .collect()
 }
@@ -161,6 +217,7 @@ fn make_reply_posts(n: usize) -> Vec<ReplyPost> {
                 repost_count: 0,
                 quote_count: 0,
                 is_quote: false,
+                langs: vec![],
             },
             parent_uri: format!("at://did:plc:other/app.bsky.feed.post/{}", i),
         })
@@ -2,13 +2,17 @@
 //
 // Tests isolated pure functions: ThreatTier::from_score boundary conditions,
 // compute_threat_score edge cases (gate logic, clamping, custom weights),
+// peak and weighted toxicity attributes, evidence strength, terminal bars,
 // and truncate_chars UTF-8 safety.
 
-use charcoal::db::models::ThreatTier;
+use charcoal::config::EvidenceOrder;
+use charcoal::db::models::{AccountScore, ThreatTier, TierThresholds};
 use charcoal::output::truncate_chars;
 use charcoal::scoring::threat::{
-    compute_threat_score, compute_threat_score_contextual, ThreatWeights,
+    compute_overlap_only_score, compute_threat_score, compute_threat_score_breakdown,
+    compute_threat_score_contextual, overlap_only_ceiling, ThreatWeights,
 };
+use charcoal::toxicity::traits::AttributeWeights;
 
 // ============================================================
 // ThreatTier::from_score — boundary conditions
@@ -65,6 +69,50 @@ fn tier_nan_falls_to_low() {
     assert_eq!(ThreatTier::from_score(f64::NAN), ThreatTier::Low);
 }
 
+// ============================================================
+// ThreatTier::from_score_with_thresholds — custom boundaries
+// ============================================================
+
+#[test]
+fn tier_custom_thresholds_move_boundaries() {
+    let strict = TierThresholds::new(4.0, 10.0, 20.0).unwrap();
+    assert_eq!(
+        ThreatTier::from_score_with_thresholds(3.9, &strict),
+        ThreatTier::Low
+    );
+    assert_eq!(
+        ThreatTier::from_score_with_thresholds(4.0, &strict),
+        ThreatTier::Watch
+    );
+    assert_eq!(
+        ThreatTier::from_score_with_thresholds(10.0, &strict),
+        ThreatTier::Elevated
+    );
+    assert_eq!(
+        ThreatTier::from_score_with_thresholds(20.0, &strict),
+        ThreatTier::High
+    );
+}
+
+#[test]
+fn tier_default_thresholds_match_from_score() {
+    for score in [0.0, 7.999, 8.0, 14.999, 15.0, 34.999, 35.0, 100.0] {
+        assert_eq!(
+            ThreatTier::from_score_with_thresholds(score, &TierThresholds::default()),
+            ThreatTier::from_score(score)
+        );
+    }
+}
+
+#[test]
+fn tier_thresholds_must_increase() {
+    assert!(TierThresholds::new(8.0, 15.0, 35.0).is_some());
+    assert!(TierThresholds::new(15.0, 8.0, 35.0).is_none());
+    assert!(TierThresholds::new(8.0, 15.0, 15.0).is_none());
+    assert!(TierThresholds::new(0.0, 15.0, 35.0).is_none());
+    assert!(TierThresholds::new(8.0, f64::NAN, 35.0).is_none());
+}
+
 // ============================================================
 // ThreatTier round-trip: from_score -> as_str -> Display
 // ============================================================
@@ -152,10 +200,11 @@ fn gate_just_above_threshold() {
 #[test]
 fn score_clamped_to_100() {
     let w = ThreatWeights::default();
-    let (score, tier) = compute_threat_score(1.5, 1.5, &w);
+    let b = compute_threat_score_breakdown(1.5, 1.5, &w);
     // 1.5 * 70 * (1 + 1.5 * 1.5) = 105 * 3.25 = 341.25 -> clamped to 100
-    assert_eq!(score, 100.0);
-    assert_eq!(tier, ThreatTier::High);
+    assert!((b.pre_clamp - 341.25).abs() < 1e-9);
+    assert_eq!(b.final_score, 100.0);
+    assert_eq!(b.tier, ThreatTier::High);
 }
 
 #[test]
@@ -183,8 +232,11 @@ fn gated_max_toxicity_caps_at_gate_max() {
 fn gated_above_one_still_caps() {
     let w = ThreatWeights::default();
     // toxicity=2.0, overlap=0 -> gated: min(2.0*25, 25) = min(50,25) = 25
-    let (score, _) = compute_threat_score(2.0, 0.0, &w);
-    assert!((score - 25.0).abs() < 0.1);
+    let b = compute_threat_score_breakdown(2.0, 0.0, &w);
+    assert!(b.gated);
+    assert!((b.toxicity_component - 50.0).abs() < 1e-9);
+    assert!((b.pre_clamp - 25.0).abs() < 0.1);
+    assert!((b.final_score - 25.0).abs() < 0.1);
 }
 
 // ============================================================
@@ -198,6 +250,7 @@ fn custom_weights_zero_produces_zero() {
         overlap_multiplier: 0.0,
         overlap_gate_threshold: 0.15,
         gate_max_score: 25.0,
+        toxicity_calibration: None,
     };
     let (score, tier) = compute_threat_score(0.9, 0.9, &w);
     assert_eq!(score, 0.0);
@@ -211,6 +264,7 @@ fn custom_weights_high_multiplier() {
         overlap_multiplier: 3.0,
         overlap_gate_threshold: 0.15,
         gate_max_score: 25.0,
+        toxicity_calibration: None,
     };
     let (score, _) = compute_threat_score(0.5, 0.5, &w);
     // 0.5 * 70 * (1 + 0.5 * 3.0) = 35 * 2.5 = 87.5
@@ -224,6 +278,7 @@ fn custom_gate_max_score() {
         overlap_multiplier: 1.5,
         overlap_gate_threshold: 0.15,
         gate_max_score: 10.0, // lower gate cap
+        toxicity_calibration: None,
     };
     let (score, _) = compute_threat_score(0.9, 0.0, &w);
     // Gated: min(0.9*10, 10) = 9.0
@@ -239,6 +294,314 @@ fn default_weights_match_documented_values() {
     assert_eq!(w.gate_max_score, 25.0);
 }
 
+// ============================================================
+// compute_overlap_only_score
+// ============================================================
+
+#[test]
+fn overlap_only_gates_irrelevant_accounts_to_zero() {
+    let w = ThreatWeights::default();
+    let (score, tier) = compute_overlap_only_score(0.10, 30.0, &w);
+    assert_eq!(score, 0.0);
+    assert_eq!(tier, ThreatTier::Low);
+}
+
+#[test]
+fn overlap_only_scales_with_overlap() {
+    let w = ThreatWeights::default();
+    // 0.5 * 30 = 15.0
+    let (score, tier) = compute_overlap_only_score(0.5, 30.0, &w);
+    assert!((score - 15.0).abs() < 0.1);
+    assert_eq!(tier, ThreatTier::Elevated);
+}
+
+#[test]
+fn overlap_only_never_reaches_high() {
+    let w = ThreatWeights::default();
+    let (score, tier) = compute_overlap_only_score(1.0, 30.0, &w);
+    assert!((score - 30.0).abs() < 0.1);
+    assert_ne!(tier, ThreatTier::High);
+}
+
+#[test]
+fn overlap_only_ceiling_stays_under_high() {
+    let thresholds = TierThresholds::DEFAULT;
+    let ceiling = overlap_only_ceiling(&thresholds);
+    // Full overlap through the largest stranger, label and context multipliers
+    let amplified = (30.0_f64 * 1.2 * 1.5 * 1.5).min(ceiling);
+    assert!(amplified < thresholds.high);
+    assert_eq!(
+        ThreatTier::from_score_with_thresholds(amplified, &thresholds),
+        ThreatTier::Elevated
+    );
+
+    // Custom tiers move the ceiling with them
+    let tight = TierThresholds::new(5.0, 10.0, 20.0).unwrap();
+    assert!(overlap_only_ceiling(&tight) < 20.0);
+    assert!(overlap_only_ceiling(&tight) >= 10.0);
+}
+
+// ============================================================
+// Peak toxicity attributes + terminal bars
+// ============================================================
+
+#[test]
+fn peak_attributes_takes_max_per_category() {
+    use charcoal::toxicity::traits::ToxicityAttributes;
+
+    let posts = [
+        ToxicityAttributes {
+            identity_attack: Some(0.81),
+            insult: Some(0.20),
+            ..Default::default()
+        },
+        ToxicityAttributes {
+            identity_attack: Some(0.10),
+            insult: Some(0.55),
+            threat: Some(0.05),
+            ..Default::default()
+        },
+    ];
+    let peak = ToxicityAttributes::peak(&posts);
+    assert_eq!(peak.identity_attack, Some(0.81));
+    assert_eq!(peak.insult, Some(0.55));
+    assert_eq!(peak.threat, Some(0.05));
+    // No post scored this category, so it stays unknown rather than 0.0
+    assert_eq!(peak.profanity, None);
+}
+
+#[test]
+fn peak_attributes_of_nothing_is_empty() {
+    use charcoal::toxicity::traits::ToxicityAttributes;
+
+    let peak = ToxicityAttributes::peak(&[]);
+    assert!(peak.is_empty());
+    assert!(peak.labeled().is_empty());
+}
+
+#[test]
+fn labeled_attributes_skip_missing_categories() {
+    use charcoal::toxicity::traits::ToxicityAttributes;
+
+    let attrs = ToxicityAttributes {
+        identity_attack: Some(0.81),
+        threat: Some(0.3),
+        ..Default::default()
+    };
+    assert_eq!(
+        attrs.labeled(),
+        vec![("identity_attack", 0.81), ("threat", 0.3)]
+    );
+}
+
+// ============================================================
+// Weighted attribute score
+// ============================================================
+
+fn scored(
+    toxicity: f64,
+    attributes: charcoal::toxicity::traits::ToxicityAttributes,
+) -> charcoal::toxicity::traits::ToxicityResult {
+    charcoal::toxicity::traits::ToxicityResult {
+        toxicity,
+        attributes,
+    }
+}
+
+#[test]
+fn weighted_score_without_attributes_is_top_line() {
+    use charcoal::toxicity::traits::{dominant_attribute, weighted_score};
+
+    let result = scored(0.42, Default::default());
+    let weights = AttributeWeights::default();
+    assert!((weighted_score(&result, &weights) - 0.42).abs() < 1e-9);
+    assert_eq!(dominant_attribute(&result, &weights), None);
+}
+
+#[test]
+fn identity_attack_outweighs_profanity() {
+    use charcoal::toxicity::traits::{dominant_attribute, weighted_score, ToxicityAttributes};
+
+    let weights = AttributeWeights::default();
+    let slur = scored(
+        0.4,
+        ToxicityAttributes {
+            identity_attack: Some(0.4),
+            ..Default::default()
+        },
+    );
+    let swearing = scored(
+        0.4,
+        ToxicityAttributes {
+            profanity: Some(0.8),
+            ..Default::default()
+        },
+    );
+    assert!((weighted_score(&slur, &weights) - 0.6).abs() < 1e-9);
+    assert_eq!(dominant_attribute(&slur, &weights), Some("identity_attack"));
+    // Profanity at half weight never beats the top-line score here
+    assert!((weighted_score(&swearing, &weights) - 0.4).abs() < 1e-9);
+    assert_eq!(dominant_attribute(&swearing, &weights), None);
+}
+
+#[test]
+fn weighted_score_caps_at_one() {
+    use charcoal::toxicity::traits::{dominant_attribute, weighted_score, ToxicityAttributes};
+
+    let result = scored(
+        0.9,
+        ToxicityAttributes {
+            identity_attack: Some(0.8),
+            threat: Some(0.95),
+            ..Default::default()
+        },
+    );
+    let weights = AttributeWeights::default();
+    assert_eq!(weighted_score(&result, &weights), 1.0);
+    assert_eq!(dominant_attribute(&result, &weights), Some("threat"));
+}
+
+#[test]
+fn attribute_weights_parse() {
+    let weights = AttributeWeights::parse(" identity_attack = 2 ,profanity=0,").unwrap();
+    assert_eq!(weights.identity_attack, 2.0);
+    assert_eq!(weights.profanity, 0.0);
+    assert_eq!(weights.threat, AttributeWeights::default().threat);
+    assert_eq!(
+        AttributeWeights::parse("").unwrap(),
+        AttributeWeights::default()
+    );
+
+    assert!(AttributeWeights::parse("obscenity=1").is_err());
+    assert!(AttributeWeights::parse("threat=-1").is_err());
+    assert!(AttributeWeights::parse("threat").is_err());
+}
+
+#[test]
+fn render_bar_fills_proportionally() {
+    use charcoal::output::terminal::render_bar;
+
+    assert_eq!(render_bar(0.0, 10), "          ");
+    assert_eq!(render_bar(0.4, 10), "████      ");
+    assert_eq!(render_bar(1.0, 10), "██████████");
+}
+
+#[test]
+fn sparkline_scales_to_max() {
+    use charcoal::output::terminal::sparkline;
+
+    assert_eq!(sparkline(&[0.0, 50.0, 100.0], 100.0), "▁▅█");
+    // Out-of-range values clamp
+    assert_eq!(sparkline(&[-5.0, 250.0], 100.0), "▁█");
+    assert_eq!(sparkline(&[], 100.0), "");
+}
+
+#[test]
+fn render_bar_clamps_out_of_range() {
+    use charcoal::output::terminal::render_bar;
+
+    assert_eq!(render_bar(1.7, 4), "████");
+    assert_eq!(render_bar(-0.5, 4), "    ");
+}
+
+// ============================================================
+// --oneline report format
+// ============================================================
+
+fn oneline_account(handle: &str, threat: Option<f64>, tier: Option<&str>) -> AccountScore {
+    AccountScore {
+        did: format!("did:plc:{handle}"),
+        handle: handle.to_string(),
+        toxicity_score: threat.map(|_| 0.456),
+        topic_overlap: threat.map(|_| 0.3),
+        threat_score: threat,
+        threat_tier: tier.map(str::to_string),
+        posts_analyzed: 20,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn oneline_has_five_tab_separated_fields() {
+    use charcoal::output::terminal::format_oneline;
+
+    let line = format_oneline(&oneline_account(
+        "troll.bsky.social",
+        Some(42.0),
+        Some("Elevated"),
+    ));
+    assert_eq!(line, "troll.bsky.social\t42.0\tElevated\t0.46\t0.30");
+}
+
+#[test]
+fn oneline_keeps_field_count_for_unscored_accounts() {
+    use charcoal::output::terminal::format_oneline;
+
+    let line = format_oneline(&oneline_account(
+        "quiet.bsky.social",
+        None,
+        Some("Insufficient Data"),
+    ));
+    assert_eq!(line, "quiet.bsky.social\t-\tInsufficient_Data\t-\t-");
+    // Whitespace splitting must agree with tab splitting
+    assert_eq!(line.split_whitespace().count(), 5);
+    assert_eq!(line.split('\t').count(), 5);
+}
+
+#[test]
+fn oneline_list_sorted_by_score_without_colors() {
+    use charcoal::output::terminal::format_oneline_list;
+
+    let accounts = vec![
+        oneline_account("low.bsky.social", Some(5.0), Some("Low")),
+        oneline_account("none.bsky.social", None, Some("Insufficient Data")),
+        oneline_account("high.bsky.social", Some(80.0), Some("High")),
+    ];
+    let out = format_oneline_list(&accounts);
+    let handles: Vec<&str> = out.lines().map(|l| l.split('\t').next().unwrap()).collect();
+    assert_eq!(
+        handles,
+        ["high.bsky.social", "low.bsky.social", "none.bsky.social"]
+    );
+    assert!(
+        !out.contains('\x1b'),
+        "oneline output must not contain ANSI escapes"
+    );
+}
+
+// ============================================================
+// scan --fail-on-new-high diff
+// ============================================================
+
+#[test]
+fn newly_high_reports_only_new_arrivals() {
+    use charcoal::pipeline::new_threats::newly_high;
+    use std::collections::HashSet;
+
+    let before: HashSet<String> = ["did:plc:old.bsky.social".to_string()].into();
+    let after = vec![
+        oneline_account("old.bsky.social", Some(90.0), Some("High")),
+        oneline_account("new.bsky.social", Some(40.0), Some("High")),
+        oneline_account("newer.bsky.social", Some(70.0), Some("High")),
+        oneline_account("mid.bsky.social", Some(34.9), Some("Elevated")),
+        oneline_account("none.bsky.social", None, Some("Insufficient Data")),
+    ];
+
+    let new = newly_high(&before, after);
+    let handles: Vec<&str> = new.iter().map(|s| s.handle.as_str()).collect();
+    assert_eq!(handles, vec!["newer.bsky.social", "new.bsky.social"]);
+}
+
+#[test]
+fn newly_high_is_empty_when_nothing_changed() {
+    use charcoal::pipeline::new_threats::newly_high;
+    use std::collections::HashSet;
+
+    let before: HashSet<String> = ["did:plc:old.bsky.social".to_string()].into();
+    let after = vec![oneline_account("old.bsky.social", Some(90.0), Some("High"))];
+    assert!(newly_high(&before, after).is_empty());
+}
+
 // ============================================================
 // truncate_chars — UTF-8 safe truncation
 // ============================================================
@@ -438,6 +801,275 @@ fn scoring_confidence_staleness_days() {
     assert_eq!(ScoringConfidence::High.staleness_days(), 14);
 }
 
+// ============================================================
+// compute_evidence_strength — volume, overlap method, toxicity spread
+// ============================================================
+
+#[test]
+fn evidence_is_full_for_a_full_consistent_embedding_sample() {
+    use charcoal::scoring::evidence::compute_evidence_strength;
+    use charcoal::topics::overlap::OverlapMethod;
+
+    let toxicities = vec![0.1; 50];
+    assert!(
+        (compute_evidence_strength(50, OverlapMethod::Embedding, &toxicities) - 1.0).abs() < 1e-9
+    );
+    // Posts beyond the full sample add nothing
+    assert!((compute_evidence_strength(200, OverlapMethod::Embedding, &[]) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn evidence_grows_with_the_square_root_of_posts() {
+    use charcoal::scoring::evidence::compute_evidence_strength;
+    use charcoal::topics::overlap::OverlapMethod;
+
+    // sqrt(4 / 50)
+    let few = compute_evidence_strength(4, OverlapMethod::Embedding, &[]);
+    assert!((few - 0.2828).abs() < 1e-3, "got {few}");
+    assert_eq!(
+        compute_evidence_strength(0, OverlapMethod::Embedding, &[]),
+        0.0
+    );
+    assert!(few < compute_evidence_strength(25, OverlapMethod::Embedding, &[]));
+}
+
+#[test]
+fn keyword_overlap_weakens_evidence() {
+    use charcoal::scoring::evidence::{compute_evidence_strength, KEYWORD_OVERLAP_FACTOR};
+    use charcoal::topics::overlap::OverlapMethod;
+
+    let keyword = compute_evidence_strength(50, OverlapMethod::Keyword, &[]);
+    assert!((keyword - KEYWORD_OVERLAP_FACTOR).abs() < 1e-9);
+}
+
+#[test]
+fn toxicity_spread_weakens_evidence() {
+    use charcoal::scoring::evidence::compute_evidence_strength;
+    use charcoal::topics::overlap::OverlapMethod;
+
+    // Half the posts at 0, half at 1: standard deviation 0.5
+    let split: Vec<f64> = (0..50).map(|i| (i % 2) as f64).collect();
+    let strength = compute_evidence_strength(50, OverlapMethod::Embedding, &split);
+    assert!((strength - 0.5).abs() < 1e-9, "got {strength}");
+
+    // A single post has no spread
+    assert!((compute_evidence_strength(50, OverlapMethod::Embedding, &[0.9]) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn evidence_labels_bucket_at_the_thresholds() {
+    use charcoal::scoring::evidence::evidence_label;
+
+    assert_eq!(evidence_label(0.0), "weak");
+    assert_eq!(evidence_label(0.39), "weak");
+    assert_eq!(evidence_label(0.4), "moderate");
+    assert_eq!(evidence_label(0.69), "moderate");
+    assert_eq!(evidence_label(0.7), "strong");
+    assert_eq!(evidence_label(1.0), "strong");
+}
+
+#[test]
+fn tier_shows_evidence_and_post_count() {
+    use charcoal::output::tier_with_evidence;
+
+    let mut account = oneline_account("troll.bsky.social", Some(40.0), Some("High"));
+    assert_eq!(tier_with_evidence(&account), "High");
+
+    account.posts_analyzed = 4;
+    account.evidence_strength = Some(0.28);
+    assert_eq!(
+        tier_with_evidence(&account),
+        "High (evidence: weak — 4 posts)"
+    );
+}
+
+// ============================================================
+// Minimum-posts filter
+// ============================================================
+
+#[test]
+fn min_posts_filter_skips_accounts_below_threshold() {
+    use charcoal::scoring::profile::has_insufficient_posts;
+
+    assert!(has_insufficient_posts(2, 5));
+    assert!(has_insufficient_posts(9, 10));
+    assert!(!has_insufficient_posts(10, 10));
+    assert!(!has_insufficient_posts(25, 10));
+}
+
+#[test]
+fn min_posts_filter_threshold_capped_at_stage1_sample() {
+    use charcoal::scoring::profile::has_insufficient_posts;
+
+    // Stage 1 only ever fetches 25 posts, so a threshold of 100 must not
+    // reject an account that filled the whole sample.
+    assert!(!has_insufficient_posts(25, 100));
+    assert!(has_insufficient_posts(24, 100));
+}
+
+#[test]
+fn min_posts_zero_never_skips() {
+    use charcoal::scoring::profile::has_insufficient_posts;
+
+    assert!(!has_insufficient_posts(0, 0));
+}
+
+// ============================================================
+// Minimum-overlap filter
+// ============================================================
+
+#[test]
+fn min_overlap_skips_accounts_below_threshold() {
+    use charcoal::scoring::profile::below_min_overlap;
+
+    assert!(below_min_overlap(Some(0.05), Some(0.1)));
+    assert!(!below_min_overlap(Some(0.1), Some(0.1)));
+    assert!(!below_min_overlap(Some(0.4), Some(0.1)));
+}
+
+#[test]
+fn min_overlap_off_or_unknown_never_skips() {
+    use charcoal::scoring::profile::below_min_overlap;
+
+    // No threshold set: the filter is off
+    assert!(!below_min_overlap(Some(0.0), None));
+    // Overlap unknown (TF-IDF extraction failed): can't call it irrelevant
+    assert!(!below_min_overlap(None, Some(0.5)));
+}
+
+#[test]
+fn min_overlap_off_by_default() {
+    use charcoal::scoring::profile::ScoringOptions;
+
+    assert_eq!(ScoringOptions::default().min_overlap, None);
+}
+
+#[test]
+fn scoring_options_load_hardblock_patterns() {
+    use charcoal::config::Config;
+    use charcoal::scoring::profile::ScoringOptions;
+
+    let path = std::env::temp_dir().join(format!(
+        "charcoal-hardblock-test-{}.txt",
+        std::process::id()
+    ));
+    std::fs::write(&path, "# test list\nfoo\n").unwrap();
+
+    let config = Config {
+        hardblock_patterns: Some(path.clone()),
+        ..Config::test_defaults()
+    };
+    let options = ScoringOptions::from_config(&config);
+    let hardblock = options.hardblock.expect("patterns loaded");
+    assert!(hardblock.contains_hardblock("FOO bar"));
+    let _ = std::fs::remove_file(&path);
+
+    // A missing file scores without overrides rather than failing
+    assert!(ScoringOptions::from_config(&config).hardblock.is_none());
+    assert!(ScoringOptions::from_config(&Config::test_defaults())
+        .hardblock
+        .is_none());
+}
+
+#[test]
+fn insufficient_data_score_has_no_threat_score() {
+    use charcoal::bluesky::relationships::GraphDistance;
+    use charcoal::scoring::profile::insufficient_data_score;
+
+    let score = insufficient_data_score(
+        "did:plc:sparse",
+        "sparse.bsky.social",
+        2,
+        Some(GraphDistance::Stranger),
+    );
+
+    // A skipped account must not look like a genuine Low score
+    assert!(score.threat_score.is_none());
+    assert!(score.toxicity_score.is_none());
+    assert!(score.topic_overlap.is_none());
+    assert_eq!(score.threat_tier.as_deref(), Some("Insufficient Data"));
+    assert_eq!(score.posts_analyzed, 2);
+    assert_eq!(score.graph_distance.as_deref(), Some("Stranger"));
+}
+
+#[test]
+fn unsupported_language_score_has_no_threat_score() {
+    use charcoal::scoring::profile::unsupported_language_score;
+
+    let score = unsupported_language_score("did:plc:es", "es.bsky.social", 30, None);
+
+    assert!(score.threat_score.is_none());
+    assert!(score.toxicity_score.is_none());
+    assert_eq!(score.threat_tier.as_deref(), Some("Unsupported Language"));
+    assert_eq!(score.posts_analyzed, 30);
+}
+
+#[test]
+fn whitelisted_score_is_low_and_marked() {
+    use charcoal::scoring::profile::whitelisted_score;
+
+    let score = whitelisted_score("did:plc:friend", "friend.bsky.social");
+
+    assert_eq!(score.did, "did:plc:friend");
+    assert_eq!(score.threat_score, Some(0.0));
+    assert_eq!(score.threat_tier.as_deref(), Some("Low"));
+    assert_eq!(score.posts_analyzed, 0);
+    assert!(score.whitelisted);
+    assert!(score.scoring_confidence.is_none());
+}
+
+// ============================================================
+// select_evidence — evidence ordering
+// ============================================================
+
+fn evidence_set() -> Vec<charcoal::db::models::ToxicPost> {
+    // (toxicity, created_at): the worst post is the oldest
+    [
+        (0.95, Some("2024-03-01T12:00:00Z")),
+        (0.60, Some("2026-09-30T08:00:00Z")),
+        (0.80, Some("2025-06-15T00:00:00Z")),
+        (0.70, None),
+        (0.55, Some("2026-10-01T09:30:00+02:00")),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(
+        |(i, (toxicity, created_at))| charcoal::db::models::ToxicPost {
+            text: format!("post {i}"),
+            toxicity,
+            uri: format!("at://did:plc:x/app.bsky.feed.post/{i}"),
+            attributes: None,
+            created_at: created_at.map(str::to_string),
+            hardblock_pattern: None,
+            flagged_for: None,
+        },
+    )
+    .collect()
+}
+
+#[test]
+fn evidence_by_toxicity_keeps_worst_posts() {
+    use charcoal::scoring::profile::select_evidence;
+
+    let picked = select_evidence(evidence_set(), EvidenceOrder::Toxicity, 3);
+    let scores: Vec<f64> = picked.iter().map(|p| p.toxicity).collect();
+    assert_eq!(scores, vec![0.95, 0.80, 0.70]);
+}
+
+#[test]
+fn evidence_by_recency_keeps_newest_posts() {
+    use charcoal::scoring::profile::select_evidence;
+
+    let picked = select_evidence(evidence_set(), EvidenceOrder::Recent, 3);
+    let texts: Vec<&str> = picked.iter().map(|p| p.text.as_str()).collect();
+    // Timestamps compare as instants, not strings; undated posts go last
+    assert_eq!(texts, vec!["post 4", "post 1", "post 2"]);
+
+    let all = select_evidence(evidence_set(), EvidenceOrder::Recent, 10);
+    assert_eq!(all.len(), 5);
+    assert!(all.last().unwrap().created_at.is_none());
+}
+
 // ============================================================
 // Adaptive sampling — stage decision functions
 // ============================================================
@@ -502,42 +1134,58 @@ fn no_early_exit_when_first_person_scores_empty() {
 
 #[test]
 fn scoring_confidence_near_boundary_is_low() {
+    use charcoal::db::models::TierThresholds;
     use charcoal::scoring::profile::should_continue_to_stage3;
 
     // Near Watch boundary (8.0 ± 5) → should re-score sooner
-    assert!(should_continue_to_stage3(10.0));
+    assert!(should_continue_to_stage3(10.0, &TierThresholds::DEFAULT));
     // Not near any boundary → standard confidence
-    assert!(!should_continue_to_stage3(22.0));
+    assert!(!should_continue_to_stage3(22.0, &TierThresholds::DEFAULT));
 }
 
 #[test]
 fn stage2_resolves_when_clear_signal() {
+    use charcoal::db::models::TierThresholds;
     use charcoal::scoring::profile::should_continue_to_stage3;
 
     // Score 22.0 is not near any boundary (8, 15, 35) ± 5
-    assert!(!should_continue_to_stage3(22.0));
+    assert!(!should_continue_to_stage3(22.0, &TierThresholds::DEFAULT));
 }
 
 #[test]
 fn stage2_continues_when_near_watch_boundary() {
+    use charcoal::db::models::TierThresholds;
     use charcoal::scoring::profile::should_continue_to_stage3;
 
     // Score 6.0 is within ±5 of Watch boundary at 8.0
-    assert!(should_continue_to_stage3(6.0));
+    assert!(should_continue_to_stage3(6.0, &TierThresholds::DEFAULT));
 }
 
 #[test]
 fn stage2_continues_when_near_elevated_boundary() {
+    use charcoal::db::models::TierThresholds;
     use charcoal::scoring::profile::should_continue_to_stage3;
 
     // Score 13.0 is within ±5 of Elevated boundary at 15.0
-    assert!(should_continue_to_stage3(13.0));
+    assert!(should_continue_to_stage3(13.0, &TierThresholds::DEFAULT));
 }
 
 #[test]
 fn stage2_continues_when_near_high_boundary() {
+    use charcoal::db::models::TierThresholds;
     use charcoal::scoring::profile::should_continue_to_stage3;
 
     // Score 37.0 is within ±5 of High boundary at 35.0
-    assert!(should_continue_to_stage3(37.0));
+    assert!(should_continue_to_stage3(37.0, &TierThresholds::DEFAULT));
+}
+
+#[test]
+fn stage2_boundaries_follow_configured_thresholds() {
+    use charcoal::db::models::TierThresholds;
+    use charcoal::scoring::profile::should_continue_to_stage3;
+
+    // A stricter Watch boundary at 3.0 moves the uncertain band with it
+    let strict = TierThresholds::new(3.0, 15.0, 35.0).unwrap();
+    assert!(should_continue_to_stage3(1.0, &strict));
+    assert!(!should_continue_to_stage3(1.0, &TierThresholds::DEFAULT));
 }
@@ -0,0 +1,268 @@
+// Unit tests for app-password sessions on the public AT Protocol client.
+//
+// A local server plays the PDS: it issues tokens from createSession and
+// refreshSession, and answers getProfile according to the bearer token it
+// receives, so token attachment and refresh-on-expiry can be checked
+// without network access.
+
+use std::sync::{Arc, Mutex};
+
+use charcoal::bluesky::client::{is_expired_session, PublicAtpClient};
+use reqwest::StatusCode;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// One request the mock PDS received: its request line and Authorization
+/// header (lowercased head, so `bearer ...`).
+#[derive(Debug, Clone)]
+struct Seen {
+    line: String,
+    authorization: Option<String>,
+}
+
+/// Read a whole request (head plus any Content-Length body) and return the
+/// lowercased head and the body.
+async fn read_request(socket: &mut TcpStream) -> (String, String) {
+    let mut data = Vec::new();
+    let mut buf = [0u8; 1024];
+    let head_end = loop {
+        if let Some(i) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i + 4;
+        }
+        let n = socket.read(&mut buf).await.unwrap();
+        if n == 0 {
+            break data.len();
+        }
+        data.extend_from_slice(&buf[..n]);
+    };
+    let head = String::from_utf8_lossy(&data[..head_end]).to_lowercase();
+    let body_len: usize = head
+        .lines()
+        .find_map(|l| l.strip_prefix("content-length:"))
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(0);
+    while data.len() < head_end + body_len {
+        let n = socket.read(&mut buf).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        data.extend_from_slice(&buf[..n]);
+    }
+    let body = String::from_utf8_lossy(&data[head_end..]).into_owned();
+    (head, body)
+}
+
+async fn respond(socket: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    socket.write_all(response.as_bytes()).await.unwrap();
+}
+
+/// Serve as a PDS whose first access token (`access-1`) is already expired
+/// when `first_token_expired`, recording every request in `seen`.
+async fn serve_pds(listener: TcpListener, first_token_expired: bool, seen: Arc<Mutex<Vec<Seen>>>) {
+    loop {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let (head, body) = read_request(&mut socket).await;
+        let line = head.lines().next().unwrap_or_default().to_string();
+        let authorization = head
+            .lines()
+            .find_map(|l| l.strip_prefix("authorization:"))
+            .map(|v| v.trim().to_string());
+        seen.lock().unwrap().push(Seen {
+            line: line.clone(),
+            authorization: authorization.clone(),
+        });
+
+        let auth = authorization.as_deref();
+        if line.contains("com.atproto.server.createsession") && body.contains("wrong-pass") {
+            respond(
+                &mut socket,
+                "401 Unauthorized",
+                r#"{"error":"AuthenticationRequired","message":"Invalid identifier or password"}"#,
+            )
+            .await;
+        } else if line.contains("com.atproto.server.createsession") {
+            respond(
+                &mut socket,
+                "200 OK",
+                r#"{"accessJwt":"access-1","refreshJwt":"refresh-1","did":"did:plc:me"}"#,
+            )
+            .await;
+        } else if line.contains("com.atproto.server.refreshsession") {
+            if auth == Some("bearer refresh-1") {
+                respond(
+                    &mut socket,
+                    "200 OK",
+                    r#"{"accessJwt":"access-2","refreshJwt":"refresh-2","did":"did:plc:me"}"#,
+                )
+                .await;
+            } else {
+                respond(
+                    &mut socket,
+                    "400 Bad Request",
+                    r#"{"error":"InvalidToken"}"#,
+                )
+                .await;
+            }
+        } else {
+            let valid = match auth {
+                Some("bearer access-1") => !first_token_expired,
+                Some("bearer access-2") => true,
+                _ => false,
+            };
+            if valid {
+                respond(&mut socket, "200 OK", r#"{"did":"did:plc:someone"}"#).await;
+            } else {
+                respond(
+                    &mut socket,
+                    "401 Unauthorized",
+                    r#"{"error":"ExpiredToken","message":"Token has expired"}"#,
+                )
+                .await;
+            }
+        }
+    }
+}
+
+async fn start_pds(first_token_expired: bool) -> (String, Arc<Mutex<Vec<Seen>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    tokio::spawn(serve_pds(listener, first_token_expired, Arc::clone(&seen)));
+    (base_url, seen)
+}
+
+async fn get_profile(client: &PublicAtpClient) -> anyhow::Result<serde_json::Value> {
+    client
+        .xrpc_get("app.bsky.actor.getProfile", &[("actor", "someone.test")])
+        .await
+}
+
+// ============================================================
+// Token attachment
+// ============================================================
+
+#[tokio::test]
+async fn unauthenticated_client_sends_no_token() {
+    let (base_url, seen) = start_pds(false).await;
+    let client = PublicAtpClient::new(&base_url).unwrap();
+    assert!(!client.is_authenticated());
+
+    // The mock rejects tokenless reads, which is the point: nothing was sent
+    assert!(get_profile(&client).await.is_err());
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1);
+    assert!(seen[0].authorization.is_none());
+}
+
+#[tokio::test]
+async fn session_token_is_attached_to_requests() {
+    let (base_url, seen) = start_pds(false).await;
+    let client = PublicAtpClient::new(&base_url)
+        .unwrap()
+        .with_app_password("me.test", "app-pass");
+    assert!(client.is_authenticated());
+
+    get_profile(&client).await.unwrap();
+    get_profile(&client).await.unwrap();
+
+    let seen = seen.lock().unwrap().clone();
+    let lines: Vec<&str> = seen.iter().map(|s| s.line.as_str()).collect();
+    // One login, reused for both reads
+    assert_eq!(lines.len(), 3, "{lines:?}");
+    assert!(lines[0].starts_with("post /xrpc/com.atproto.server.createsession"));
+    for read in &seen[1..] {
+        assert!(read.line.starts_with("get /xrpc/app.bsky.actor.getprofile"));
+        assert_eq!(read.authorization.as_deref(), Some("bearer access-1"));
+    }
+}
+
+// ============================================================
+// Refresh on expiry
+// ============================================================
+
+#[tokio::test]
+async fn expired_token_is_refreshed_and_request_retried() {
+    let (base_url, seen) = start_pds(true).await;
+    let client = PublicAtpClient::new(&base_url)
+        .unwrap()
+        .with_app_password("me.test", "app-pass");
+
+    let profile = get_profile(&client).await.unwrap();
+    assert_eq!(profile["did"], "did:plc:someone");
+
+    let seen = seen.lock().unwrap().clone();
+    let calls: Vec<(&str, Option<&str>)> = seen
+        .iter()
+        .map(|s| {
+            let path = s.line.split_whitespace().nth(1).unwrap_or_default();
+            let nsid = path.trim_start_matches("/xrpc/").split('?').next().unwrap();
+            (nsid, s.authorization.as_deref())
+        })
+        .collect();
+    assert_eq!(
+        calls,
+        vec![
+            ("com.atproto.server.createsession", None),
+            ("app.bsky.actor.getprofile", Some("bearer access-1")),
+            (
+                "com.atproto.server.refreshsession",
+                Some("bearer refresh-1")
+            ),
+            ("app.bsky.actor.getprofile", Some("bearer access-2")),
+        ]
+    );
+
+    // The refreshed token is kept for later requests
+    get_profile(&client).await.unwrap();
+}
+
+// ============================================================
+// Refused logins
+// ============================================================
+
+#[tokio::test]
+async fn refused_login_is_not_retried() {
+    let (base_url, seen) = start_pds(false).await;
+    let client = PublicAtpClient::new(&base_url)
+        .unwrap()
+        .with_app_password("me.test", "wrong-pass");
+
+    for _ in 0..3 {
+        let err = get_profile(&client).await.unwrap_err();
+        assert!(
+            format!("{err:#}").contains("Invalid identifier or password"),
+            "{err:#}"
+        );
+    }
+
+    // One createSession; the later requests fail without reaching the server
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1, "{seen:?}");
+    assert!(seen[0]
+        .line
+        .starts_with("post /xrpc/com.atproto.server.createsession"));
+}
+
+#[test]
+fn expired_session_responses() {
+    assert!(is_expired_session(StatusCode::UNAUTHORIZED, ""));
+    assert!(is_expired_session(
+        StatusCode::BAD_REQUEST,
+        r#"{"error":"ExpiredToken","message":"Token has expired"}"#
+    ));
+    assert!(is_expired_session(
+        StatusCode::BAD_REQUEST,
+        r#"{"error":"InvalidToken"}"#
+    ));
+    assert!(!is_expired_session(
+        StatusCode::BAD_REQUEST,
+        r#"{"error":"InvalidRequest","message":"Profile not found"}"#
+    ));
+    assert!(!is_expired_session(StatusCode::TOO_MANY_REQUESTS, ""));
+}
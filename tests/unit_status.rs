@@ -0,0 +1,94 @@
+// Unit tests for the `status --verbose` readiness checklist.
+//
+// Readiness::check runs against throwaway model directories with fake model
+// files, so no real models are needed.
+
+use charcoal::status::Readiness;
+use charcoal::topics::embeddings::EMBEDDING_DIM;
+use charcoal::toxicity::download::{embedding_files, model_files};
+
+fn fake_model_dir(name: &str, toxicity: bool, embedding: bool) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("charcoal-status-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut files = Vec::new();
+    if toxicity {
+        files.extend(model_files(&dir));
+    }
+    if embedding {
+        files.extend(embedding_files(&dir));
+    }
+    for file in files {
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, b"fake model bytes").unwrap();
+    }
+    dir
+}
+
+#[test]
+fn everything_present_is_ready() {
+    let dir = fake_model_dir("ready", true, true);
+    let readiness = Readiness::check(&dir, Some(EMBEDDING_DIM), Some(EMBEDDING_DIM));
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(readiness.model_dir_exists);
+    assert!(readiness.toxicity_model_ready());
+    assert!(readiness.embedding_model_ready());
+    assert!(readiness.embeddings_ready());
+    assert!(readiness
+        .embedding_files
+        .iter()
+        .all(|(_, size)| *size == Some(16)));
+}
+
+#[test]
+fn missing_embedding_model_falls_back_to_tfidf() {
+    let dir = fake_model_dir("no-embedder", true, false);
+    let readiness = Readiness::check(&dir, Some(EMBEDDING_DIM), Some(EMBEDDING_DIM));
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(readiness.toxicity_model_ready());
+    assert!(!readiness.embedding_model_ready());
+    assert!(!readiness.embeddings_ready());
+    assert!(readiness
+        .embedding_files
+        .iter()
+        .all(|(_, size)| size.is_none()));
+}
+
+#[test]
+fn stored_embedding_must_exist_with_matching_dimension() {
+    let dir = fake_model_dir("dims", false, true);
+    let none = Readiness::check(&dir, None, Some(EMBEDDING_DIM));
+    let wrong = Readiness::check(&dir, Some(EMBEDDING_DIM * 2), Some(EMBEDDING_DIM));
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(!none.embedding_dim_matches());
+    assert!(!none.embeddings_ready());
+    assert!(!wrong.embedding_dim_matches());
+    assert!(!wrong.embeddings_ready());
+    assert!(!none.toxicity_model_ready());
+}
+
+#[test]
+fn stored_embedding_is_compared_with_the_models_dimension() {
+    let dir = fake_model_dir("other-model", false, true);
+    let smaller = Readiness::check(&dir, Some(128), Some(128));
+    let unloaded = Readiness::check(&dir, Some(128), None);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // A 128-dim model with a 128-dim stored embedding is ready
+    assert!(smaller.embedding_dim_matches());
+    assert!(smaller.embeddings_ready());
+    // Without a loadable model there's nothing to match against
+    assert!(!unloaded.embedding_dim_matches());
+}
+
+#[test]
+fn missing_model_dir_is_reported() {
+    let dir = std::env::temp_dir().join("charcoal-status-nonexistent-model-dir");
+    let readiness = Readiness::check(&dir, None, None);
+    assert!(!readiness.model_dir_exists);
+    assert!(!readiness.toxicity_model_ready());
+    assert!(!readiness.embedding_model_ready());
+}
@@ -0,0 +1,45 @@
+// tests/unit_sweep.rs
+//
+// Tests for the graph sweep's dry-run cost estimate.
+
+use charcoal::pipeline::sweep::{
+    estimate_cost, PUBLIC_API_REQUESTS_PER_WINDOW, PUBLIC_API_WINDOW, REQUESTS_PER_SCORED_ACCOUNT,
+};
+
+#[test]
+fn estimate_counts_follower_pages_per_level() {
+    // 200 first-degree followers: 2 pages for them, then 1 page each at depth 50
+    let estimate = estimate_cost(200, 50);
+    assert_eq!(estimate.first_degree, 200);
+    assert_eq!(estimate.projected_pool, 10_000);
+    assert_eq!(estimate.follower_requests, 2 + 200);
+    assert_eq!(
+        estimate.scoring_requests,
+        10_000 * REQUESTS_PER_SCORED_ACCOUNT
+    );
+
+    // Depth past one page needs more than one request per follower
+    assert_eq!(estimate_cost(10, 250).follower_requests, 1 + 10 * 3);
+}
+
+#[test]
+fn estimate_spans_rate_limit_windows() {
+    let estimate = estimate_cost(200, 50);
+    let total = estimate.total_requests();
+    assert_eq!(total, 202 + 40_000);
+    assert_eq!(
+        estimate.rate_windows(),
+        total.div_ceil(PUBLIC_API_REQUESTS_PER_WINDOW)
+    );
+    let expected_secs =
+        PUBLIC_API_WINDOW.as_secs_f64() * total as f64 / PUBLIC_API_REQUESTS_PER_WINDOW as f64;
+    assert!((estimate.min_duration().as_secs_f64() - expected_secs).abs() < 1e-6);
+}
+
+#[test]
+fn estimate_with_no_followers_is_one_request() {
+    let estimate = estimate_cost(0, 50);
+    assert_eq!(estimate.projected_pool, 0);
+    assert_eq!(estimate.total_requests(), 1);
+    assert_eq!(estimate.rate_windows(), 1);
+}
@@ -1,13 +1,22 @@
 // Unit tests for topic extraction and overlap functions.
 //
 // Tests isolated pure functions: TopicFingerprint::keyword_weights edge cases,
-// cosine_from_weights numerical edge cases, and TfIdfExtractor::extract
-// invariant properties.
+// TopicFingerprint::is_degenerate, fingerprint staleness, stored-schema
+// upgrades and serde round trips, cosine_from_weights numerical edge cases,
+// PreparedFingerprint equivalence, TfIdfExtractor::extract invariant
+// properties, NgramExtractor phrases against single-word fingerprints,
+// building a fingerprint from a local posts export, and the minimum-posts
+// cutoff for embedding-based overlap.
 
 use std::collections::HashMap;
 
-use charcoal::topics::fingerprint::{TopicCluster, TopicFingerprint};
-use charcoal::topics::overlap::{cosine_from_weights, cosine_similarity};
+use charcoal::bluesky::posts::{load_posts_file, parse_posts_export};
+use charcoal::topics::fingerprint::{TopicCluster, TopicFingerprint, FINGERPRINT_SCHEMA_VERSION};
+use charcoal::topics::ngram::NgramExtractor;
+use charcoal::topics::overlap::{
+    choose_overlap_method, cosine_against_prepared, cosine_from_weights, cosine_similarity,
+    low_confidence_post_count, overlap_method_note, OverlapMethod, PreparedFingerprint,
+};
 use charcoal::topics::tfidf::TfIdfExtractor;
 use charcoal::topics::traits::TopicExtractor;
 
@@ -20,6 +29,7 @@ fn keyword_weights_empty_clusters() {
     let fp = TopicFingerprint {
         clusters: vec![],
         post_count: 0,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     assert!(fp.keyword_weights().is_empty());
 }
@@ -33,6 +43,7 @@ fn keyword_weights_single_cluster_single_keyword() {
             weight: 0.8,
         }],
         post_count: 10,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     let w = fp.keyword_weights();
     assert!((w["fat"] - 0.8).abs() < 0.001);
@@ -48,6 +59,7 @@ fn keyword_weights_distributed_evenly() {
             weight: 0.9,
         }],
         post_count: 10,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     let w = fp.keyword_weights();
     assert!((w["a"] - 0.3).abs() < 0.001);
@@ -72,6 +84,7 @@ fn keyword_weights_accumulates_across_clusters() {
             },
         ],
         post_count: 10,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     let w = fp.keyword_weights();
     assert!(
@@ -91,6 +104,7 @@ fn keyword_weights_empty_keywords_vec_produces_empty_map() {
             weight: 0.5,
         }],
         post_count: 10,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     assert!(fp.keyword_weights().is_empty());
 }
@@ -104,12 +118,178 @@ fn keyword_weights_zero_weight_cluster() {
             weight: 0.0,
         }],
         post_count: 10,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     let w = fp.keyword_weights();
     assert_eq!(w["a"], 0.0);
     assert_eq!(w["b"], 0.0);
 }
 
+// ============================================================
+// TopicFingerprint::is_degenerate
+// ============================================================
+
+#[test]
+fn degenerate_when_no_clusters() {
+    let fp = TopicFingerprint {
+        clusters: vec![],
+        post_count: 3,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
+    };
+    assert!(fp.is_degenerate());
+}
+
+#[test]
+fn degenerate_when_clusters_have_no_signal() {
+    let fp = TopicFingerprint {
+        clusters: vec![
+            TopicCluster {
+                label: "empty".to_string(),
+                keywords: vec![],
+                weight: 0.5,
+            },
+            TopicCluster {
+                label: "zero".to_string(),
+                keywords: vec!["a".to_string()],
+                weight: 0.0,
+            },
+        ],
+        post_count: 3,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
+    };
+    assert!(fp.is_degenerate());
+}
+
+#[test]
+fn not_degenerate_with_one_usable_cluster() {
+    let fp = TopicFingerprint {
+        clusters: vec![
+            TopicCluster {
+                label: "empty".to_string(),
+                keywords: vec![],
+                weight: 0.0,
+            },
+            TopicCluster {
+                label: "real".to_string(),
+                keywords: vec!["fatphobia".to_string()],
+                weight: 0.4,
+            },
+        ],
+        post_count: 200,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
+    };
+    assert!(!fp.is_degenerate());
+}
+
+// ============================================================
+// Fingerprint staleness
+// ============================================================
+
+#[test]
+fn stale_only_past_max_age() {
+    use charcoal::topics::fingerprint::is_stale;
+    assert!(!is_stale(10, 60));
+    assert!(!is_stale(60, 60));
+    assert!(is_stale(61, 60));
+}
+
+#[test]
+fn zero_max_age_disables_staleness() {
+    use charcoal::topics::fingerprint::is_stale;
+    assert!(!is_stale(10_000, 0));
+}
+
+#[test]
+fn refresh_due_at_interval() {
+    use charcoal::topics::fingerprint::is_refresh_due;
+    assert!(!is_refresh_due(6, 7));
+    assert!(is_refresh_due(7, 7));
+    assert!(is_refresh_due(30, 7));
+    // 0 disables scheduled refreshes
+    assert!(!is_refresh_due(10_000, 0));
+}
+
+#[test]
+fn unparseable_updated_at_has_no_age() {
+    assert!(charcoal::topics::fingerprint::age_days("not a timestamp").is_none());
+    assert!(charcoal::topics::fingerprint::age_days("2020-01-01 00:00:00").unwrap() > 365);
+}
+
+// ============================================================
+// Stored fingerprint schema — serde round trips and upgrades
+// ============================================================
+
+/// A blob as written before `schema_version` existed: no version field,
+/// clusters not necessarily in weight order.
+const LEGACY_FINGERPRINT_JSON: &str = r#"{
+    "clusters": [
+        {"label": "minor", "keywords": ["knitting"], "weight": 0.2},
+        {"label": "major", "keywords": ["fatlib", "diet"], "weight": 0.7}
+    ],
+    "post_count": 42
+}"#;
+
+#[test]
+fn legacy_fingerprint_deserializes_as_version_zero() {
+    let fp: TopicFingerprint = serde_json::from_str(LEGACY_FINGERPRINT_JSON).unwrap();
+    assert_eq!(fp.schema_version, 0);
+    assert_eq!(fp.post_count, 42);
+    assert_eq!(fp.clusters.len(), 2);
+}
+
+#[test]
+fn from_json_upgrades_legacy_fingerprint() {
+    let fp = TopicFingerprint::from_json(LEGACY_FINGERPRINT_JSON).unwrap();
+    assert_eq!(fp.schema_version, FINGERPRINT_SCHEMA_VERSION);
+    // v1 ranks clusters by weight, highest first
+    assert_eq!(fp.clusters[0].label, "major");
+    assert_eq!(fp.clusters[1].label, "minor");
+    // Upgrading doesn't change what the fingerprint says
+    let weights = fp.keyword_weights();
+    assert!((weights["fatlib"] - 0.35).abs() < 1e-9);
+    assert!((weights["knitting"] - 0.2).abs() < 1e-9);
+}
+
+#[test]
+fn upgraded_fingerprint_round_trips_unchanged() {
+    let upgraded = TopicFingerprint::from_json(LEGACY_FINGERPRINT_JSON).unwrap();
+    let json = serde_json::to_string(&upgraded).unwrap();
+    assert!(json.contains("\"schema_version\":1"));
+
+    let reloaded = TopicFingerprint::from_json(&json).unwrap();
+    assert_eq!(serde_json::to_string(&reloaded).unwrap(), json);
+}
+
+#[test]
+fn current_fingerprint_upgrade_is_a_no_op() {
+    let fp = fp_from(&[(&["queer"], 0.1), (&["fat"], 0.9)]);
+    let before = serde_json::to_string(&fp).unwrap();
+    // Already current — cluster order is left as built
+    let after = serde_json::to_string(&fp.upgrade()).unwrap();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn from_json_rejects_newer_schema_and_garbage() {
+    let future = format!(
+        r#"{{"clusters": [], "post_count": 1, "schema_version": {}}}"#,
+        FINGERPRINT_SCHEMA_VERSION + 1
+    );
+    assert!(TopicFingerprint::from_json(&future).is_err());
+    assert!(TopicFingerprint::from_json("{not json").is_err());
+    assert!(TopicFingerprint::from_json(r#"{"clusters": "oops"}"#).is_err());
+}
+
+#[test]
+fn tfidf_fingerprint_carries_current_schema_version() {
+    let extractor = TfIdfExtractor {
+        top_n_keywords: 20,
+        max_clusters: 5,
+    };
+    let fp = extractor.extract(&sample_posts()).unwrap();
+    assert_eq!(fp.schema_version, FINGERPRINT_SCHEMA_VERSION);
+}
+
 // ============================================================
 // cosine_from_weights — numerical edge cases
 // ============================================================
@@ -219,6 +399,7 @@ fn cosine_similarity_one_empty_fp() {
     let empty = TopicFingerprint {
         clusters: vec![],
         post_count: 0,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     let nonempty = TopicFingerprint {
         clusters: vec![TopicCluster {
@@ -227,11 +408,72 @@ fn cosine_similarity_one_empty_fp() {
             weight: 0.5,
         }],
         post_count: 10,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
     };
     assert_eq!(cosine_similarity(&empty, &nonempty), 0.0);
     assert_eq!(cosine_similarity(&nonempty, &empty), 0.0);
 }
 
+// ============================================================
+// PreparedFingerprint / cosine_against_prepared — equivalence
+// ============================================================
+
+fn fp_from(clusters: &[(&[&str], f64)]) -> TopicFingerprint {
+    TopicFingerprint {
+        clusters: clusters
+            .iter()
+            .map(|(kws, w)| TopicCluster {
+                label: kws.first().unwrap_or(&"empty").to_string(),
+                keywords: kws.iter().map(|k| k.to_string()).collect(),
+                weight: *w,
+            })
+            .collect(),
+        post_count: 50,
+        schema_version: FINGERPRINT_SCHEMA_VERSION,
+    }
+}
+
+#[test]
+fn prepared_matches_cosine_similarity() {
+    let protected = fp_from(&[
+        (&["fat", "liberation", "diet"], 0.4),
+        (&["queer", "trans"], 0.35),
+        (&["moderation", "governance", "trust"], 0.25),
+    ]);
+    let prepared = PreparedFingerprint::new(&protected);
+    let others = [
+        fp_from(&[(&["fat", "gaming"], 0.6), (&["trust"], 0.4)]),
+        fp_from(&[(&["sports", "gaming"], 1.0)]),
+        fp_from(&[(&["queer", "trans", "fat"], 0.9), (&["diet"], 0.1)]),
+        protected.clone(),
+        fp_from(&[]),
+    ];
+    for other in &others {
+        let expected = cosine_similarity(&protected, other);
+        let got = cosine_against_prepared(&prepared, other);
+        assert!(
+            (expected - got).abs() < 1e-12,
+            "prepared cosine {got} differs from direct cosine {expected}"
+        );
+    }
+}
+
+#[test]
+fn prepared_caches_weights_and_norm() {
+    let protected = fp_from(&[(&["a", "b"], 0.6), (&["c"], 0.8)]);
+    let prepared = PreparedFingerprint::from(&protected);
+    assert_eq!(prepared.weights(), &protected.keyword_weights());
+    // weights: a=0.3, b=0.3, c=0.8 → sqrt(0.09 + 0.09 + 0.64)
+    assert!((prepared.norm() - 0.82f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn prepared_empty_protected_scores_zero() {
+    let prepared = PreparedFingerprint::new(&fp_from(&[]));
+    let other = fp_from(&[(&["fat"], 0.5)]);
+    assert_eq!(cosine_against_prepared(&prepared, &other), 0.0);
+}
+
 // ============================================================
 // TfIdfExtractor::extract — invariant properties
 // ============================================================
@@ -382,3 +624,168 @@ fn tfidf_all_keywords_are_meaningful() {
         }
     }
 }
+
+// ============================================================
+// NgramExtractor — unigram vs bigram fingerprints
+// ============================================================
+
+// ============================================================
+// Fingerprint from a posts export
+// ============================================================
+
+#[test]
+fn posts_export_feeds_tfidf_extractor() {
+    let export = r#"[
+        {"text": "Fat liberation means fat bodies deserve respect", "uri": "at://did:plc:me/app.bsky.feed.post/1", "created_at": "2026-01-01T00:00:00Z"},
+        {"text": "Fat acceptance and body diversity in medicine"},
+        {"text": "Queer community organizing for fat liberation", "uri": "at://did:plc:me/app.bsky.feed.post/3"}
+    ]"#;
+    let posts = parse_posts_export(export).unwrap();
+    assert_eq!(posts.len(), 3);
+    assert_eq!(posts[0].created_at.as_deref(), Some("2026-01-01T00:00:00Z"));
+    assert_eq!(posts[1].uri, "");
+    assert_eq!(posts[1].like_count, 0);
+
+    let texts: Vec<String> = posts.into_iter().map(|p| p.text).collect();
+    let fp = TfIdfExtractor {
+        top_n_keywords: 20,
+        max_clusters: 5,
+    }
+    .extract(&texts)
+    .unwrap();
+    assert_eq!(fp.post_count, 3);
+    assert!(!all_keywords(&fp).is_empty());
+}
+
+#[test]
+fn posts_export_rejects_bad_shapes() {
+    let not_array = parse_posts_export(r#"{"text": "hi"}"#).unwrap_err();
+    assert!(format!("{not_array:#}").contains("JSON array"));
+
+    let missing_text = parse_posts_export(r#"[{"text": "ok"}, {"uri": "x"}]"#).unwrap_err();
+    assert!(format!("{missing_text:#}").contains("Post 2"));
+
+    assert!(parse_posts_export(r#"[{"text": "ok", "likes": 3}]"#).is_err());
+    assert!(parse_posts_export("not json").is_err());
+}
+
+#[test]
+fn posts_export_file_must_contain_posts() {
+    let path =
+        std::env::temp_dir().join(format!("charcoal-empty-export-{}.json", std::process::id()));
+    std::fs::write(&path, "[]").unwrap();
+    let err = load_posts_file(&path).unwrap_err();
+    std::fs::remove_file(&path).ok();
+    assert!(err.to_string().contains("contains no posts"));
+
+    assert!(load_posts_file(std::path::Path::new("/nonexistent/posts.json")).is_err());
+}
+
+// ============================================================
+// Minimum posts for embedding overlap
+// ============================================================
+
+#[test]
+fn few_posts_fall_back_to_keyword_overlap() {
+    assert_eq!(choose_overlap_method(3, true, 5), OverlapMethod::Keyword);
+    assert_eq!(choose_overlap_method(5, true, 5), OverlapMethod::Embedding);
+    assert_eq!(choose_overlap_method(50, true, 5), OverlapMethod::Embedding);
+    // Without embeddings loaded, post count doesn't matter
+    assert_eq!(choose_overlap_method(50, false, 5), OverlapMethod::Keyword);
+    // A cutoff of 0 trusts embeddings from any sample
+    assert_eq!(choose_overlap_method(1, true, 0), OverlapMethod::Embedding);
+}
+
+#[test]
+fn low_confidence_only_below_cutoff() {
+    assert_eq!(low_confidence_post_count(3, 5), Some(3));
+    assert_eq!(low_confidence_post_count(0, 5), Some(0));
+    assert_eq!(low_confidence_post_count(5, 5), None);
+    assert_eq!(low_confidence_post_count(3, 0), None);
+}
+
+#[test]
+fn overlap_note_mentions_post_count() {
+    assert_eq!(overlap_method_note(Some("embedding"), None), "embedding");
+    assert_eq!(overlap_method_note(None, None), "unknown");
+    assert_eq!(
+        overlap_method_note(Some("keyword"), Some(3)),
+        "keyword, overlap based on only 3 posts"
+    );
+    assert_eq!(
+        overlap_method_note(Some("keyword"), Some(1)),
+        "keyword, overlap based on only 1 post"
+    );
+}
+
+fn all_keywords(fp: &TopicFingerprint) -> Vec<&str> {
+    fp.clusters
+        .iter()
+        .flat_map(|c| c.keywords.iter().map(String::as_str))
+        .collect()
+}
+
+#[test]
+fn bigram_fingerprint_keeps_recurring_phrases_as_keywords() {
+    let unigram = TfIdfExtractor {
+        top_n_keywords: 40,
+        max_clusters: 7,
+    }
+    .extract(&sample_posts())
+    .unwrap();
+    let bigram = NgramExtractor {
+        top_n_keywords: 40,
+        max_clusters: 7,
+        max_ngram: 2,
+    }
+    .extract(&sample_posts())
+    .unwrap();
+
+    // Single-word TF-IDF splits "weight stigma" into two keywords
+    let unigram_keywords = all_keywords(&unigram);
+    assert!(unigram_keywords.iter().all(|k| !k.contains(' ')));
+    assert!(unigram_keywords.contains(&"stigma"));
+
+    // The bigram fingerprint keeps it as one, but not "diet culture", which
+    // only one post uses
+    let bigram_keywords = all_keywords(&bigram);
+    assert!(
+        bigram_keywords.contains(&"weight stigma"),
+        "expected the phrase in {bigram_keywords:?}"
+    );
+    assert!(!bigram_keywords.contains(&"diet culture"));
+
+    // Phrases are ordinary keys to the overlap math
+    let weights = bigram.keyword_weights();
+    assert!(weights["weight stigma"] > 0.0);
+    assert!((cosine_from_weights(&weights, &weights) - 1.0).abs() < 1e-9);
+
+    // The two share their single words, so they overlap, but not fully
+    let overlap = cosine_similarity(&unigram, &bigram);
+    assert!(overlap > 0.0 && overlap < 1.0, "overlap {overlap}");
+}
+
+#[test]
+fn ngram_extractor_finds_trigrams_and_respects_max_ngram() {
+    let posts = vec![
+        "Diet culture harms kids in every school cafeteria".to_string(),
+        "Ads selling diet culture harms to teens again".to_string(),
+        "Nutrition science has moved past calorie counting".to_string(),
+    ];
+    let trigram = NgramExtractor {
+        top_n_keywords: 30,
+        max_clusters: 5,
+        max_ngram: 3,
+    }
+    .extract(&posts)
+    .unwrap();
+    assert!(all_keywords(&trigram).contains(&"diet culture harms"));
+
+    let words_only = NgramExtractor {
+        max_ngram: 1,
+        ..NgramExtractor::default()
+    }
+    .extract(&posts)
+    .unwrap();
+    assert!(all_keywords(&words_only).iter().all(|k| !k.contains(' ')));
+}
@@ -0,0 +1,229 @@
+// Unit tests for High-tier webhook alerts.
+//
+// Tests is_new_high_tier and the alert payload directly, and points
+// post_alert and ScoreWriter at a throwaway local server that records the
+// JSON bodies it receives, so alerts can be checked without network access.
+
+use std::sync::{Arc, Mutex};
+
+use charcoal::db::models::{AccountScore, ScoreSmoothing, TierThresholds};
+use charcoal::db::sqlite::SqliteDatabase;
+use charcoal::output::webhook::{alert_payload, is_new_high_tier, post_alert};
+use charcoal::pipeline::score_writer::ScoreWriter;
+use rusqlite::Connection;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const TEST_USER: &str = "did:plc:testuser000000000000";
+
+fn score(did: &str, threat: f64, tier: &str) -> AccountScore {
+    AccountScore {
+        did: did.to_string(),
+        handle: format!("{}.test", did.trim_start_matches("did:plc:")),
+        toxicity_score: Some(0.6),
+        topic_overlap: Some(0.5),
+        threat_score: Some(threat),
+        threat_tier: Some(tier.to_string()),
+        posts_analyzed: 20,
+        ..Default::default()
+    }
+}
+
+/// Answer every request on a local port with `status`, keeping each
+/// request body. Returns the webhook URL and the bodies received.
+async fn webhook_server(status: &'static str) -> (String, Arc<Mutex<Vec<serde_json::Value>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&bodies);
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            let body_start = loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break i + 4;
+                }
+            };
+            let head = String::from_utf8_lossy(&request[..body_start]).to_lowercase();
+            let length: usize = head
+                .lines()
+                .find_map(|l| l.strip_prefix("content-length:"))
+                .map(|v| v.trim().parse().unwrap())
+                .unwrap_or(0);
+            while request.len() < body_start + length {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let body = serde_json::from_slice(&request[body_start..]).unwrap();
+            received.lock().unwrap().push(body);
+            let response =
+                format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    (url, bodies)
+}
+
+// ============================================================
+// is_new_high_tier
+// ============================================================
+
+#[test]
+fn alerts_only_on_crossing_into_high() {
+    let high = score("did:plc:a", 85.0, "High");
+    let elevated = score("did:plc:a", 20.0, "Elevated");
+
+    assert!(is_new_high_tier(&high, None));
+    assert!(is_new_high_tier(&high, Some(&elevated)));
+    assert!(!is_new_high_tier(&high, Some(&high)));
+    assert!(!is_new_high_tier(&elevated, None));
+}
+
+// ============================================================
+// Payload
+// ============================================================
+
+#[test]
+fn payload_works_for_slack_and_discord() {
+    let payload = alert_payload(&score("did:plc:troll", 85.0, "High"));
+    let text = payload["text"].as_str().unwrap();
+    assert_eq!(payload["content"], payload["text"]);
+    assert!(text.contains("@troll.test"));
+    assert!(text.contains("85.0"));
+    assert!(text.contains("https://bsky.app/profile/did:plc:troll"));
+    assert_eq!(payload["did"], "did:plc:troll");
+    assert_eq!(payload["threat_tier"], "High");
+}
+
+// ============================================================
+// post_alert — mock webhook
+// ============================================================
+
+#[tokio::test]
+async fn post_alert_sends_payload() {
+    let (url, bodies) = webhook_server("204 No Content").await;
+    let account = score("did:plc:troll", 85.0, "High");
+
+    post_alert(&url, &account).await.unwrap();
+
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(bodies.len(), 1);
+    assert_eq!(bodies[0], alert_payload(&account));
+}
+
+#[tokio::test]
+async fn post_alert_reports_rejected_alert() {
+    let (url, _) = webhook_server("500 Internal Server Error").await;
+    assert!(post_alert(&url, &score("did:plc:troll", 85.0, "High"))
+        .await
+        .is_err());
+}
+
+// ============================================================
+// ScoreWriter — alerts on newly High accounts
+// ============================================================
+
+#[tokio::test]
+async fn score_writer_alerts_once_per_newly_high_account() {
+    let (url, bodies) = webhook_server("200 OK").await;
+    let conn = Connection::open_in_memory().unwrap();
+    charcoal::db::schema::create_tables(&conn).unwrap();
+    let db = SqliteDatabase::new(conn);
+
+    // First scan: one High account, one Elevated
+    let mut writer = ScoreWriter::new(&db, TEST_USER, 10, None).with_alert_webhook(Some(&url));
+    writer
+        .push(score("did:plc:new", 85.0, "High"))
+        .await
+        .unwrap();
+    writer
+        .push(score("did:plc:mid", 20.0, "Elevated"))
+        .await
+        .unwrap();
+    writer.finish().await.unwrap();
+    assert_eq!(bodies.lock().unwrap().len(), 1);
+
+    // Rescan: the High account stays High, the Elevated one crosses over
+    let mut writer = ScoreWriter::new(&db, TEST_USER, 10, None).with_alert_webhook(Some(&url));
+    writer
+        .push(score("did:plc:new", 90.0, "High"))
+        .await
+        .unwrap();
+    writer
+        .push(score("did:plc:mid", 80.0, "High"))
+        .await
+        .unwrap();
+    writer.finish().await.unwrap();
+
+    let bodies = bodies.lock().unwrap();
+    let alerted: Vec<&str> = bodies.iter().map(|b| b["did"].as_str().unwrap()).collect();
+    assert_eq!(alerted, vec!["did:plc:new", "did:plc:mid"]);
+}
+
+#[tokio::test]
+async fn score_writer_alerts_on_the_smoothed_tier() {
+    let (url, bodies) = webhook_server("200 OK").await;
+    let conn = Connection::open_in_memory().unwrap();
+    charcoal::db::schema::create_tables(&conn).unwrap();
+    let db = SqliteDatabase::new(conn);
+    let smoothing = ScoreSmoothing {
+        alpha: 0.3,
+        thresholds: TierThresholds::DEFAULT,
+    };
+
+    let mut writer = ScoreWriter::new(&db, TEST_USER, 10, Some(smoothing));
+    writer
+        .push(score("did:plc:acct", 10.0, "Watch"))
+        .await
+        .unwrap();
+    writer.finish().await.unwrap();
+
+    // 0.3 * 50 + 0.7 * 10 = 22: the scan says High, the stored row Elevated
+    let mut writer =
+        ScoreWriter::new(&db, TEST_USER, 10, Some(smoothing)).with_alert_webhook(Some(&url));
+    writer
+        .push(score("did:plc:acct", 50.0, "High"))
+        .await
+        .unwrap();
+    writer.finish().await.unwrap();
+    assert!(bodies.lock().unwrap().is_empty());
+
+    // Enough consecutive High scans carry the stored score over the line
+    for _ in 0..3 {
+        let mut writer =
+            ScoreWriter::new(&db, TEST_USER, 10, Some(smoothing)).with_alert_webhook(Some(&url));
+        writer
+            .push(score("did:plc:acct", 90.0, "High"))
+            .await
+            .unwrap();
+        writer.finish().await.unwrap();
+    }
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(bodies.len(), 1);
+    assert!(bodies[0]["threat_score"].as_f64().unwrap() >= 35.0);
+}
+
+#[tokio::test]
+async fn failed_alert_does_not_fail_the_write() {
+    // Nothing listens on a port that was bound and then dropped
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    drop(listener);
+
+    let conn = Connection::open_in_memory().unwrap();
+    charcoal::db::schema::create_tables(&conn).unwrap();
+    let db = SqliteDatabase::new(conn);
+
+    let mut writer = ScoreWriter::new(&db, TEST_USER, 1, None).with_alert_webhook(Some(&url));
+    writer
+        .push(score("did:plc:new", 85.0, "High"))
+        .await
+        .unwrap();
+    assert_eq!(writer.finish().await.unwrap(), 1);
+}
@@ -0,0 +1,188 @@
+//! Integration tests for the paginated account list endpoint.
+//! Run: cargo test --features web --test web_accounts
+
+#[cfg(feature = "web")]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    use charcoal::db::models::AccountScore;
+    use charcoal::web::auth::{create_token, COOKIE_NAME};
+    use charcoal::web::test_helpers::{build_test_app_with_db, TEST_DID, TEST_SECRET};
+
+    fn session_cookie(did: &str) -> String {
+        format!("{}={}", COOKIE_NAME, create_token(TEST_SECRET, did))
+    }
+
+    async fn seed_account(
+        db: &std::sync::Arc<dyn charcoal::db::Database>,
+        did: &str,
+        handle: &str,
+        score: f64,
+    ) {
+        let account = AccountScore {
+            did: did.to_string(),
+            handle: handle.to_string(),
+            toxicity_score: Some(0.5),
+            topic_overlap: Some(0.3),
+            threat_score: Some(score),
+            posts_analyzed: 10,
+            scored_at: "2026-03-19T12:00:00Z".to_string(),
+            ..Default::default()
+        };
+        db.upsert_account_score(TEST_DID, &account, None)
+            .await
+            .unwrap();
+    }
+
+    /// Five accounts across the default tiers: two High, one Elevated, one
+    /// Watch and one Low.
+    async fn seed_accounts(db: &std::sync::Arc<dyn charcoal::db::Database>) {
+        db.upsert_user(TEST_DID, "test.bsky.social").await.unwrap();
+        seed_account(db, "did:plc:a", "alpha.bsky.social", 50.0).await;
+        seed_account(db, "did:plc:b", "bravo.bsky.social", 40.0).await;
+        seed_account(db, "did:plc:c", "charlie.bsky.social", 20.0).await;
+        seed_account(db, "did:plc:d", "delta.bsky.social", 10.0).await;
+        seed_account(db, "did:plc:e", "echo_100.bsky.social", 2.0).await;
+    }
+
+    async fn get_json(app: axum::Router, uri: &str) -> (StatusCode, Value) {
+        let res = app
+            .oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .header("cookie", session_cookie(TEST_DID))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = res.status();
+        let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn handles(json: &Value) -> Vec<&str> {
+        json["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["handle"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn list_accounts_defaults_to_first_page() {
+        let (app, db) = build_test_app_with_db();
+        seed_accounts(&db).await;
+
+        let (status, json) = get_json(app, "/api/accounts").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["total"], 5);
+        assert_eq!(json["has_more"], false);
+        assert_eq!(json["limit"], 50);
+        assert_eq!(json["offset"], 0);
+        assert_eq!(
+            handles(&json),
+            vec![
+                "alpha.bsky.social",
+                "bravo.bsky.social",
+                "charlie.bsky.social",
+                "delta.bsky.social",
+                "echo_100.bsky.social",
+            ]
+        );
+        assert_eq!(json["items"][0]["rank"], 1);
+    }
+
+    #[tokio::test]
+    async fn list_accounts_pages_with_limit_and_offset() {
+        let (app, db) = build_test_app_with_db();
+        seed_accounts(&db).await;
+
+        let (_, first) = get_json(app.clone(), "/api/accounts?limit=2").await;
+        assert_eq!(first["total"], 5);
+        assert_eq!(first["has_more"], true);
+        assert_eq!(
+            handles(&first),
+            vec!["alpha.bsky.social", "bravo.bsky.social"]
+        );
+
+        let (_, last) = get_json(app, "/api/accounts?limit=2&offset=4").await;
+        assert_eq!(last["total"], 5);
+        assert_eq!(last["has_more"], false);
+        assert_eq!(handles(&last), vec!["echo_100.bsky.social"]);
+        // Ranks continue from the offset
+        assert_eq!(last["items"][0]["rank"], 5);
+    }
+
+    #[tokio::test]
+    async fn list_accounts_filters_by_tier() {
+        let (app, db) = build_test_app_with_db();
+        seed_accounts(&db).await;
+
+        let (status, json) = get_json(app, "/api/accounts?tier=high").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["total"], 2);
+        assert_eq!(
+            handles(&json),
+            vec!["alpha.bsky.social", "bravo.bsky.social"]
+        );
+        assert!(json["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|a| a["threat_tier"] == "High"));
+    }
+
+    #[tokio::test]
+    async fn list_accounts_filters_by_min_score() {
+        let (app, db) = build_test_app_with_db();
+        seed_accounts(&db).await;
+
+        let (_, json) = get_json(app, "/api/accounts?min_score=15").await;
+
+        assert_eq!(json["total"], 3);
+        assert_eq!(
+            handles(&json),
+            vec![
+                "alpha.bsky.social",
+                "bravo.bsky.social",
+                "charlie.bsky.social",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn list_accounts_searches_handles_literally() {
+        let (app, db) = build_test_app_with_db();
+        seed_accounts(&db).await;
+
+        let (_, json) = get_json(app.clone(), "/api/accounts?q=BRAVO").await;
+        assert_eq!(json["total"], 1);
+        assert_eq!(handles(&json), vec!["bravo.bsky.social"]);
+
+        // `_` is a LIKE wildcard; here it must only match a literal underscore,
+        // not the "." in "bravo.bsky.social"
+        let (_, json) = get_json(app.clone(), "/api/accounts?q=o_b").await;
+        assert_eq!(json["total"], 0);
+        let (_, json) = get_json(app, "/api/accounts?q=o_1").await;
+        assert_eq!(handles(&json), vec!["echo_100.bsky.social"]);
+    }
+
+    #[tokio::test]
+    async fn list_accounts_rejects_unknown_tier() {
+        let (app, db) = build_test_app_with_db();
+        seed_accounts(&db).await;
+
+        let (status, _) = get_json(app, "/api/accounts?tier=extreme").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,114 @@
+//! Integration tests for response compression on the web router.
+//! Run: cargo test --features web --test web_compression
+
+#[cfg(feature = "web")]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use tower::ServiceExt;
+
+    use charcoal::db::models::AccountScore;
+    use charcoal::web::auth::{create_token, COOKIE_NAME};
+    use charcoal::web::test_helpers::{build_test_app_with_db, TEST_DID, TEST_SECRET};
+
+    fn session_cookie(did: &str) -> String {
+        format!("{}={}", COOKIE_NAME, create_token(TEST_SECRET, did))
+    }
+
+    async fn seed_accounts(db: &std::sync::Arc<dyn charcoal::db::Database>, count: usize) {
+        for i in 0..count {
+            let account = AccountScore {
+                did: format!("did:plc:target{i}"),
+                handle: format!("target{i}.bsky.social"),
+                toxicity_score: Some(0.5),
+                topic_overlap: Some(0.3),
+                threat_score: Some(40.0),
+                threat_tier: Some("High".to_string()),
+                posts_analyzed: 10,
+                scored_at: "2026-03-19T12:00:00Z".to_string(),
+                ..Default::default()
+            };
+            db.upsert_account_score(TEST_DID, &account, None)
+                .await
+                .unwrap();
+        }
+    }
+
+    async fn get_accounts(
+        app: axum::Router,
+        accept_encoding: Option<&str>,
+    ) -> axum::response::Response {
+        let mut req = Request::builder()
+            .uri("/api/accounts?limit=200")
+            .header("cookie", session_cookie(TEST_DID));
+        if let Some(enc) = accept_encoding {
+            req = req.header(header::ACCEPT_ENCODING, enc);
+        }
+        app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn large_accounts_response_is_gzipped() {
+        let (app, db) = build_test_app_with_db();
+        db.upsert_user(TEST_DID, "test.bsky.social").await.unwrap();
+        seed_accounts(&db, 100).await;
+
+        let plain = get_accounts(app.clone(), None).await;
+        assert_eq!(plain.status(), StatusCode::OK);
+        assert!(plain.headers().get(header::CONTENT_ENCODING).is_none());
+        let plain_body = axum::body::to_bytes(plain.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        // Over the SizeAbove threshold, or there'd be nothing to compress
+        assert!(
+            plain_body.len() > charcoal::web::MIN_COMPRESS_BYTES as usize,
+            "plain body is only {} bytes",
+            plain_body.len()
+        );
+
+        let gzipped = get_accounts(app, Some("gzip")).await;
+        assert_eq!(gzipped.status(), StatusCode::OK);
+        assert_eq!(
+            gzipped.headers().get(header::CONTENT_ENCODING).unwrap(),
+            "gzip"
+        );
+        let gzipped_body = axum::body::to_bytes(gzipped.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(
+            gzipped_body.len() < plain_body.len(),
+            "compressed {} bytes vs plain {}",
+            gzipped_body.len(),
+            plain_body.len()
+        );
+    }
+
+    #[tokio::test]
+    async fn brotli_is_used_when_preferred() {
+        let (app, db) = build_test_app_with_db();
+        db.upsert_user(TEST_DID, "test.bsky.social").await.unwrap();
+        seed_accounts(&db, 100).await;
+
+        let res = get_accounts(app, Some("br")).await;
+        assert_eq!(res.headers().get(header::CONTENT_ENCODING).unwrap(), "br");
+    }
+
+    #[tokio::test]
+    async fn small_response_is_not_compressed() {
+        let (app, _db) = build_test_app_with_db();
+
+        let res = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .header(header::ACCEPT_ENCODING, "gzip, br")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(res.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+}
@@ -31,15 +31,12 @@ mod tests {
             threat_score: Some(score),
             threat_tier: Some(tier.to_string()),
             posts_analyzed: 10,
-            top_toxic_posts: vec![],
             scored_at: "2026-03-19T12:00:00Z".to_string(),
-            behavioral_signals: None,
-            context_score: None,
-            graph_distance: None,
-            fingerprint_quality: None,
-            scoring_confidence: None,
+            ..Default::default()
         };
-        db.upsert_account_score(TEST_DID, &account).await.unwrap();
+        db.upsert_account_score(TEST_DID, &account, None)
+            .await
+            .unwrap();
     }
 
     // ---- POST /api/accounts/{did}/label ----
@@ -126,6 +126,24 @@ mod tests {
         assert_eq!(res.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn initiate_rejects_malformed_handle_before_resolving() {
+        let app = build_test_app();
+        let res = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/auth/initiate")
+                    .method("POST")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"handle": "someone.bsky.social."}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn initiate_rejects_missing_handle_field() {
         let app = build_test_app();
@@ -370,6 +388,45 @@ mod tests {
         );
     }
 
+    // ---- Fingerprint rebuild ----
+
+    #[tokio::test]
+    async fn fingerprint_rebuild_requires_auth() {
+        let app = build_test_app();
+        let res = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/fingerprint/rebuild")
+                    .method("POST")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn fingerprint_rebuild_fails_cleanly_when_user_not_registered() {
+        let app = build_test_app();
+        let rebuild = || {
+            Request::builder()
+                .uri("/api/fingerprint/rebuild")
+                .method("POST")
+                .header("cookie", session_cookie(TEST_DID))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let res = app.clone().oneshot(rebuild()).await.unwrap();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        // The failed attempt releases its claim, so a retry isn't a 409
+        let res = app.oneshot(rebuild()).await.unwrap();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
     // ---- Logout ----
 
     #[tokio::test]
@@ -88,16 +88,18 @@ export async function triggerScan(): Promise<void> {
 // ---- Accounts ----
 
 export async function getAccounts(params?: {
+	limit?: number;
+	offset?: number;
+	min_score?: number;
 	tier?: string;
 	q?: string;
-	page?: number;
-	per_page?: number;
 }): Promise<AccountsResponse> {
 	const qs = new URLSearchParams();
+	if (params?.limit) qs.set('limit', String(params.limit));
+	if (params?.offset) qs.set('offset', String(params.offset));
+	if (params?.min_score) qs.set('min_score', String(params.min_score));
 	if (params?.tier) qs.set('tier', params.tier);
 	if (params?.q) qs.set('q', params.q);
-	if (params?.page) qs.set('page', String(params.page));
-	if (params?.per_page) qs.set('per_page', String(params.per_page));
 	const query = qs.toString() ? `?${qs}` : '';
 	return apiFetch<AccountsResponse>(`/api/accounts${query}`);
 }
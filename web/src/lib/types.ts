@@ -21,6 +21,7 @@ export interface Account {
 	handle: string;
 	toxicity_score: number | null;
 	topic_overlap: number | null;
+	overlap_method: string | null; // "keyword" | "embedding" | "unknown" | null
 	threat_score: number | null;
 	threat_tier: string | null; // "High" | "Elevated" | "Watch" | "Low" | null
 	posts_analyzed: number;
@@ -57,10 +58,11 @@ export interface AmplificationEvent {
 }
 
 export interface AccountsResponse {
-	accounts: Account[];
+	items: Account[];
 	total: number;
-	page: number;
-	per_page: number;
+	has_more: boolean;
+	limit: number;
+	offset: number;
 }
 
 export interface EventsResponse {
@@ -98,6 +100,7 @@ export interface ReviewAccount {
 	handle: string;
 	toxicity_score: number | null;
 	topic_overlap: number | null;
+	overlap_method: string | null;
 	threat_score: number | null;
 	threat_tier: string | null;
 	posts_analyzed: number;